@@ -0,0 +1,30 @@
+// Package catalog holds the catalog queries shared by several checks: every user
+// table with its size and activity counters (CatalogTables) and every server
+// setting (CatalogSettings). pgdoctor.Run caches their results, so on a database
+// with tens of thousands of relations the sizes are computed once per run
+// instead of once per check. Checks call them through db.Queries like any other
+// query and filter the rows in Go.
+package catalog
+
+import (
+	_ "embed"
+
+	"github.com/emancu/pgdoctor/db"
+)
+
+// SQL is the text of the shared queries, for the Metadata.SQL of checks using them.
+//
+//go:embed query.sql
+var SQL string
+
+// Queries are the sqlc names of the shared queries, cached for the duration of a run.
+var Queries = []string{"CatalogTables", "CatalogSettings"}
+
+// Settings indexes CatalogSettings rows by setting name.
+func Settings(rows []db.CatalogSettingsRow) map[string]db.CatalogSettingsRow {
+	settings := make(map[string]db.CatalogSettingsRow, len(rows))
+	for _, row := range rows {
+		settings[row.Name.String] = row
+	}
+	return settings
+}
@@ -0,0 +1,25 @@
+package catalog_test
+
+import (
+	"testing"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestSettings(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.CatalogSettingsRow{
+		{Name: pgtype.Text{String: "shared_buffers", Valid: true}, Setting: pgtype.Text{String: "16384", Valid: true}},
+		{Name: pgtype.Text{String: "work_mem", Valid: true}, Setting: pgtype.Text{String: "4096", Valid: true}},
+	}
+
+	settings := catalog.Settings(rows)
+	assert.Len(t, settings, 2)
+	assert.Equal(t, "16384", settings["shared_buffers"].Setting.String)
+	_, ok := settings["fsync"]
+	assert.False(t, ok)
+}
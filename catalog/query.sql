@@ -0,0 +1,51 @@
+-- name: CatalogTables :many
+-- Every user table with its size and pg_stat_user_tables counters. Shared by
+-- several checks and cached for the run, so it is unfiltered: checks filter in Go.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , c.relkind::text AS relkind
+  , c.reltuples::bigint AS estimated_rows
+  , pg_table_size(c.oid) AS table_size_bytes
+  , pg_indexes_size(c.oid) AS indexes_size_bytes
+  , coalesce(s.seq_scan, 0) AS seq_scan
+  , coalesce(s.idx_scan, 0) AS idx_scan
+  , coalesce(s.n_live_tup, 0) AS n_live_tup
+  , coalesce(s.n_dead_tup, 0) AS n_dead_tup
+  , coalesce(s.n_tup_ins, 0) AS n_tup_ins
+  , coalesce(s.n_tup_upd, 0) AS n_tup_upd
+  , coalesce(s.n_tup_del, 0) AS n_tup_del
+  , coalesce(s.n_tup_hot_upd, 0) AS n_tup_hot_upd
+  , s.last_autovacuum
+  , s.last_autoanalyze
+  , (
+    SELECT o.option_value
+    FROM pg_options_to_table(c.reloptions) AS o
+    WHERE o.option_name = 'fillfactor'
+  )::int AS fillfactor
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+  AND n.nspname NOT LIKE 'pg\_toast\_temp\_%'
+ORDER BY
+  n.nspname
+  , c.relname;
+
+-- name: CatalogSettings :many
+-- Every server setting. Shared by the configuration checks and cached for the run.
+SELECT
+  name
+  , setting
+  , unit
+  , source
+  , boot_val
+  , reset_val
+  , context
+  , vartype
+  , pending_restart
+FROM pg_settings
+ORDER BY name;
@@ -3,6 +3,8 @@ package check
 
 import (
 	"context"
+	"fmt"
+	"strings"
 	"time"
 
 	"github.com/emancu/pgdoctor/db"
@@ -37,6 +39,25 @@ func (s Severity) String() string {
 	}
 }
 
+// ParseSeverity parses a severity name as rendered by String ("ok" is accepted for "pass").
+// "skip" is rejected: it describes a check that could not run, not a result.
+func ParseSeverity(name string) (Severity, error) {
+	switch strings.ToLower(strings.TrimSpace(name)) {
+	case "ok", "pass":
+		return SeverityOK, nil
+	case "warn":
+		return SeverityWarn, nil
+	case "fail":
+		return SeverityFail, nil
+	default:
+		return SeverityOK, fmt.Errorf("unknown severity %q (expected ok, warn or fail)", name)
+	}
+}
+
+// TagTriage marks checks worth running during an active incident: runtime health
+// and capacity signals, not slow schema-design audits.
+const TagTriage = "triage"
+
 type Category string
 
 const (
@@ -45,6 +66,7 @@ const (
 	CategoryVacuum      Category = "vacuum"
 	CategorySchema      Category = "schema"
 	CategoryPerformance Category = "performance"
+	CategorySecurity    Category = "security"
 )
 
 type Checker interface {
@@ -70,7 +92,21 @@ type Metadata struct {
 	Category    Category
 	Description string
 	Readme      string
-	SQL         string // SQL query used by this check
+	SQL         string   // SQL query used by this check
+	Tags        []string // Presets the check belongs to, e.g. TagTriage (--preset triage)
+	// PreferReplica marks expensive checks whose queries read only replicated data
+	// (catalogs, pg_stats), so their results on a streaming replica match the primary.
+	// Checks reading pg_stat_* activity counters must not set it: replicas keep their own.
+	PreferReplica bool
+	// MinPGVersion and MaxPGVersion bound the PostgreSQL major versions the check's
+	// queries support (e.g. 14 for pg_stat_database session statistics). Zero means
+	// no bound. The runner skips the check on servers outside the range.
+	MinPGVersion int
+	MaxPGVersion int
+	// Requires lists what the check's queries need beyond a plain connection. The
+	// runner verifies them before running the check and reports the unmet ones as
+	// a skip with instructions, instead of the permission or "does not exist" error.
+	Requires []Prerequisite
 }
 
 // Report holds check-level metadata and all subcheck findings for a single check.
@@ -80,6 +116,17 @@ type Report struct {
 	Severity Severity
 	Duration time.Duration
 	Results  []Finding
+	// Queries are the statements the check executed, in order, as recorded by
+	// pgdoctor.Run. Retries appear once per attempt.
+	Queries []ExecutedQuery
+}
+
+// ExecutedQuery is one statement a check ran and how long it took, including
+// reading its rows.
+type ExecutedQuery struct {
+	SQL      string
+	Duration time.Duration
+	Error    string // Empty on success
 }
 
 func NewReport(metadata Metadata) *Report {
@@ -98,6 +145,16 @@ func (r *Report) AddFinding(res Finding) {
 	}
 }
 
+// Finding returns the finding with the given ID, or nil when the report has none.
+func (r *Report) Finding(id string) *Finding {
+	for i := range r.Results {
+		if r.Results[i].ID == id {
+			return &r.Results[i]
+		}
+	}
+	return nil
+}
+
 // Finding is something to log during the check.
 // Keep multiple findings in one check when they're closely related and often
 // examined together. For example, a connection check might have findings
@@ -112,6 +169,28 @@ type Finding struct {
 	// Table contains optional structured tabular data.
 	// If set, the CLI will render this as a formatted table.
 	Table *Table
+	// Comparison contains optional expected-vs-actual values for settings findings.
+	// If set, the CLI renders it as an Actual/Expected/Threshold table.
+	Comparison *Comparison
+	// ReclaimableBytes estimates the disk space freed by acting on this finding
+	// (dropping unused or duplicate indexes, removing bloat). Zero when not applicable.
+	ReclaimableBytes int64
+	// Object identifies the database object the finding is about, when there is
+	// exactly one (a setting, a table, an index). Nil for findings spanning many objects.
+	Object *Object
+	// Metrics are the numbers behind the finding, kept apart from Details so that
+	// exporters and JSON consumers never have to parse prose.
+	Metrics []Metric
+	// Remediation is a short, actionable fix (a statement or a setting change).
+	// The check README carries the full explanation.
+	Remediation string
+	// FixSQL holds the statements that apply the remediation, one per line, for
+	// pgdoctor fix-script to collect. Empty when the fix is not SQL (a restart,
+	// an application change). pgdoctor never executes it.
+	FixSQL string
+	// OriginalSeverity is the severity the check reported before a severity
+	// override replaced it (see pgdoctor.Options.SeverityOverrides). Nil if not overridden.
+	OriginalSeverity *Severity
 	// Debug contains debug information like SQL queries, timing info, etc.
 	// Only shown when --debug flag is used.
 	Debug string
@@ -127,6 +206,61 @@ type TableRow struct {
 	Severity Severity
 }
 
+// Comparison describes how a configured value measures up against the recommendation.
+// Values are pre-formatted strings so each check controls units and precision.
+type Comparison struct {
+	Setting   string // Parameter name (e.g., "work_mem")
+	Actual    string // Value currently in effect (e.g., "2MB")
+	Expected  string // Recommended value or range (e.g., "16MB-64MB")
+	Threshold string // Boundary that triggered the finding (e.g., "< 4MB")
+}
+
+type ObjectKind string
+
+const (
+	ObjectDatabase  ObjectKind = "database"
+	ObjectSchema    ObjectKind = "schema"
+	ObjectTable     ObjectKind = "table"
+	ObjectIndex     ObjectKind = "index"
+	ObjectSequence  ObjectKind = "sequence"
+	ObjectRole      ObjectKind = "role"
+	ObjectSetting   ObjectKind = "setting"
+	ObjectExtension ObjectKind = "extension"
+)
+
+// Object names a database object. Schema is empty for objects that are not
+// schema-qualified (settings, roles, databases, extensions).
+type Object struct {
+	Kind   ObjectKind
+	Schema string
+	Name   string
+}
+
+// String renders the object as it appears in SQL, e.g. "public.users" or "work_mem".
+func (o Object) String() string {
+	if o.Schema == "" {
+		return o.Name
+	}
+	return o.Schema + "." + o.Name
+}
+
+type Unit string
+
+const (
+	UnitBytes   Unit = "bytes"
+	UnitPercent Unit = "percent" // 0-100
+	UnitCount   Unit = "count"
+	UnitSeconds Unit = "seconds"
+)
+
+// Metric is a named measurement backing a finding, e.g. {"wasted", 1.2e9, UnitBytes}.
+// Names are snake_case and stable across releases, since dashboards key on them.
+type Metric struct {
+	Name  string
+	Value float64
+	Unit  Unit
+}
+
 // InstanceMetadata contains database instance specifications and configuration.
 // This metadata is fetched once per pgdoctor run and made available to all checks
 // via context for enhanced recommendations and validation.
@@ -187,3 +321,27 @@ func InstanceMetadataFromContext(ctx context.Context) *InstanceMetadata {
 	}
 	return nil
 }
+
+type serverVersionKey struct{}
+
+// ContextWithServerVersion returns a new context carrying the server_version_num
+// of the server being checked (e.g. 170002).
+func ContextWithServerVersion(ctx context.Context, version int) context.Context {
+	return ContextWithServerVersionFunc(ctx, func(context.Context) int { return version })
+}
+
+// ContextWithServerVersionFunc is ContextWithServerVersion for a version read
+// when a check first asks for it, so runs that never need it don't query it.
+func ContextWithServerVersionFunc(ctx context.Context, version func(context.Context) int) context.Context {
+	return context.WithValue(ctx, serverVersionKey{}, version)
+}
+
+// ServerVersionFromContext returns the server_version_num attached by the runner,
+// or 0 if it is unknown. Checks that pick a query by version treat 0 as the
+// newest version.
+func ServerVersionFromContext(ctx context.Context) int {
+	if version, ok := ctx.Value(serverVersionKey{}).(func(context.Context) int); ok {
+		return version(ctx)
+	}
+	return 0
+}
@@ -0,0 +1,26 @@
+package check_test
+
+import (
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestReport_Finding(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "example"})
+	report.AddFinding(check.Finding{ID: "first", Severity: check.SeverityOK})
+	report.AddFinding(check.Finding{ID: "second", Severity: check.SeverityWarn})
+
+	finding := report.Finding("second")
+	require.NotNil(t, finding)
+	assert.Equal(t, check.SeverityWarn, finding.Severity)
+
+	finding.Details = "edited"
+	assert.Equal(t, "edited", report.Results[1].Details, "returns the finding in place")
+
+	assert.Nil(t, report.Finding("missing"))
+}
@@ -1,6 +1,7 @@
 package check
 
 import (
+	"context"
 	"fmt"
 	"math"
 	"strconv"
@@ -16,19 +17,79 @@ const (
 	GiB = MiB * 1024
 )
 
-// FormatBytes formats a byte count as a human-readable string (e.g., "1.5GiB").
+// ByteUnits selects how FormatBytes renders sizes.
+type ByteUnits int32
+
+const (
+	// UnitsBinary renders 1024-based IEC units (KiB, MiB, GiB). This is the default.
+	UnitsBinary ByteUnits = iota
+	// UnitsDecimal renders 1000-based SI units (kB, MB, GB), as cloud consoles bill storage.
+	UnitsDecimal
+)
+
+type byteUnitsKey struct{}
+
+// ContextWithByteUnits returns a copy of ctx carrying the units FormatSize renders.
+func ContextWithByteUnits(ctx context.Context, units ByteUnits) context.Context {
+	return context.WithValue(ctx, byteUnitsKey{}, units)
+}
+
+// ByteUnitsFromContext returns the units attached to ctx, UnitsBinary if none.
+func ByteUnitsFromContext(ctx context.Context) ByteUnits {
+	units, _ := ctx.Value(byteUnitsKey{}).(ByteUnits)
+	return units
+}
+
+// ParseByteUnits parses a --units value ("binary" or "decimal").
+func ParseByteUnits(value string) (ByteUnits, error) {
+	switch strings.ToLower(strings.TrimSpace(value)) {
+	case "binary":
+		return UnitsBinary, nil
+	case "decimal":
+		return UnitsDecimal, nil
+	default:
+		return UnitsBinary, fmt.Errorf("unknown units %q (expected binary or decimal)", value)
+	}
+}
+
+// GB returns the size of one gigabyte in these units (GiB for binary, GB for decimal).
+func (u ByteUnits) GB() int64 {
+	if u == UnitsDecimal {
+		return 1000 * 1000 * 1000
+	}
+	return GiB
+}
+
+// FormatBytes formats a byte count as a human-readable string in binary units (e.g., "1.5GiB").
 // Supports from bytes up to exbibytes (EiB) for large database objects.
 func FormatBytes(bytes int64) string {
-	const unit = 1024
+	return FormatBytesAs(bytes, UnitsBinary)
+}
+
+// FormatSize formats a byte count in the units the run asked for (pgdoctor.Options.ByteUnits),
+// e.g. "1.5GiB" or "1.6GB". Checks use it for the sizes they report.
+func FormatSize(ctx context.Context, bytes int64) string {
+	return FormatBytesAs(bytes, ByteUnitsFromContext(ctx))
+}
+
+// FormatBytesAs formats a byte count in the given units (e.g., "1.5GiB" or "1.6GB").
+func FormatBytesAs(bytes int64, units ByteUnits) string {
+	unit, suffix := int64(1024), "iB"
+	prefixes := "KMGTPE"
+	if units == UnitsDecimal {
+		unit, suffix = 1000, "B"
+		prefixes = "kMGTPE"
+	}
+
 	if bytes < unit {
 		return fmt.Sprintf("%dB", bytes)
 	}
-	div, exp := int64(unit), 0
+	div, exp := unit, 0
 	for n := bytes / unit; n >= unit; n /= unit {
 		div *= unit
 		exp++
 	}
-	return fmt.Sprintf("%.1f%ciB", float64(bytes)/float64(div), "KMGTPE"[exp])
+	return fmt.Sprintf("%.1f%c%s", float64(bytes)/float64(div), prefixes[exp], suffix)
 }
 
 // FormatNumber formats a large number as a human-readable string (e.g., "1.5M").
@@ -117,6 +178,52 @@ func isASCIILetter(b byte) bool {
 	return (b >= 'a' && b <= 'z') || (b >= 'A' && b <= 'Z')
 }
 
+// byteUnitFactors maps a size suffix to its byte multiplier. PostgreSQL memory
+// units (kB, MB, GB, TB) are 1024-based; IEC spellings are accepted as aliases.
+var byteUnitFactors = map[string]float64{
+	"":    1,
+	"B":   1,
+	"kB":  KiB,
+	"MB":  MiB,
+	"GB":  GiB,
+	"TB":  GiB * 1024,
+	"KiB": KiB,
+	"MiB": MiB,
+	"GiB": GiB,
+	"TiB": GiB * 1024,
+}
+
+// ParseBytes parses a size such as "10MB", "512kB" or "1.5GiB" into bytes.
+// Units follow PostgreSQL memory settings: kB, MB, GB and TB are 1024-based.
+// A bare number is interpreted as bytes.
+func ParseBytes(value string) (int64, error) {
+	value = strings.TrimSpace(value)
+	if value == "" {
+		return 0, fmt.Errorf("empty value")
+	}
+
+	i := len(value)
+	for i > 0 && isASCIILetter(value[i-1]) {
+		i--
+	}
+	numeric, suffix := strings.TrimSpace(value[:i]), value[i:]
+
+	factor, ok := byteUnitFactors[suffix]
+	if !ok {
+		return 0, fmt.Errorf("unknown size unit %q (expected B, kB, MB, GB or TB)", suffix)
+	}
+
+	n, err := strconv.ParseFloat(numeric, 64)
+	if err != nil {
+		return 0, fmt.Errorf("parsing numeric part %q: %w", numeric, err)
+	}
+	if n < 0 {
+		return 0, fmt.Errorf("size must not be negative")
+	}
+
+	return int64(math.Round(n * factor)), nil
+}
+
 // FormatDurationSec formats seconds as a human-readable duration (e.g., "2h" or "1d").
 func FormatDurationSec(seconds int64) string {
 	if seconds < 60 {
@@ -1,6 +1,7 @@
 package check_test
 
 import (
+	"context"
 	"testing"
 
 	"github.com/emancu/pgdoctor/check"
@@ -47,3 +48,87 @@ func TestParseDurationMs_Invalid(t *testing.T) {
 		require.Error(t, err, "expected error for %q", value)
 	}
 }
+
+func TestFormatBytesAs(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		bytes  int64
+		units  check.ByteUnits
+		expect string
+	}{
+		{name: "binary bytes", bytes: 512, units: check.UnitsBinary, expect: "512B"},
+		{name: "binary KiB", bytes: 1536, units: check.UnitsBinary, expect: "1.5KiB"},
+		{name: "binary GiB", bytes: 5 * check.GiB, units: check.UnitsBinary, expect: "5.0GiB"},
+		{name: "decimal bytes", bytes: 999, units: check.UnitsDecimal, expect: "999B"},
+		{name: "decimal kB", bytes: 1500, units: check.UnitsDecimal, expect: "1.5kB"},
+		{name: "decimal GB", bytes: 5 * check.GiB, units: check.UnitsDecimal, expect: "5.4GB"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			require.Equal(t, tt.expect, check.FormatBytesAs(tt.bytes, tt.units))
+		})
+	}
+}
+
+func TestFormatSize(t *testing.T) {
+	t.Parallel()
+
+	require.Equal(t, "5.0GiB", check.FormatSize(context.Background(), 5*check.GiB))
+
+	ctx := check.ContextWithByteUnits(context.Background(), check.UnitsDecimal)
+	require.Equal(t, "5.4GB", check.FormatSize(ctx, 5*check.GiB))
+	require.Equal(t, "5.0GiB", check.FormatBytes(5*check.GiB), "FormatBytes stays binary")
+}
+
+func TestParseByteUnits(t *testing.T) {
+	t.Parallel()
+
+	units, err := check.ParseByteUnits("decimal")
+	require.NoError(t, err)
+	require.Equal(t, check.UnitsDecimal, units)
+	require.Equal(t, int64(1_000_000_000), units.GB())
+
+	units, err = check.ParseByteUnits("Binary")
+	require.NoError(t, err)
+	require.Equal(t, check.UnitsBinary, units)
+	require.Equal(t, int64(check.GiB), units.GB())
+
+	_, err = check.ParseByteUnits("metric")
+	require.Error(t, err)
+}
+
+func TestParseBytes(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		value    string
+		expected int64
+	}{
+		{value: "0", expected: 0},
+		{value: "512", expected: 512},
+		{value: "8kB", expected: 8 * check.KiB},
+		{value: "10MB", expected: 10 * check.MiB},
+		{value: "10 MB", expected: 10 * check.MiB},
+		{value: "1.5GiB", expected: 3 * check.GiB / 2},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.value, func(t *testing.T) {
+			t.Parallel()
+
+			got, err := check.ParseBytes(tt.value)
+			require.NoError(t, err)
+			require.Equal(t, tt.expected, got)
+		})
+	}
+
+	for _, invalid := range []string{"", "ten MB", "10XB", "-1MB"} {
+		_, err := check.ParseBytes(invalid)
+		require.Error(t, err, invalid)
+	}
+}
@@ -0,0 +1,102 @@
+package check
+
+import (
+	"sort"
+	"strings"
+)
+
+// ObjectIssues merges rule violations by object (table, index, ...) so a check
+// that evaluates several rules against the same object reports it once: at the
+// worst severity, with every reason listed, instead of one blended message or
+// one row per rule. The zero value is ready to use.
+type ObjectIssues struct {
+	order   []string
+	objects map[string]*ObjectIssue
+}
+
+// ObjectIssue is the merged result for a single object.
+type ObjectIssue struct {
+	Object   string
+	Severity Severity // Maximum severity across all reasons
+	Reasons  []string // Distinct reasons, in the order they were added
+}
+
+// Add records that object violated a rule. Repeated reasons are deduplicated.
+func (o *ObjectIssues) Add(object string, severity Severity, reason string) {
+	if o.objects == nil {
+		o.objects = map[string]*ObjectIssue{}
+	}
+
+	issue, ok := o.objects[object]
+	if !ok {
+		issue = &ObjectIssue{Object: object, Severity: severity}
+		o.objects[object] = issue
+		o.order = append(o.order, object)
+	}
+
+	if severity > issue.Severity {
+		issue.Severity = severity
+	}
+	for _, existing := range issue.Reasons {
+		if existing == reason {
+			return
+		}
+	}
+	issue.Reasons = append(issue.Reasons, reason)
+}
+
+// Len returns the number of distinct objects with at least one issue.
+func (o *ObjectIssues) Len() int {
+	return len(o.order)
+}
+
+// Severity returns the worst severity across all objects, or SeverityOK if empty.
+func (o *ObjectIssues) Severity() Severity {
+	severity := SeverityOK
+	for _, object := range o.order {
+		if s := o.objects[object].Severity; s > severity {
+			severity = s
+		}
+	}
+	return severity
+}
+
+// Issues returns the merged issues, worst severity first.
+// Objects with equal severity keep the order in which they were first added.
+func (o *ObjectIssues) Issues() []ObjectIssue {
+	issues := make([]ObjectIssue, 0, len(o.order))
+	for _, object := range o.order {
+		issues = append(issues, *o.objects[object])
+	}
+	sort.SliceStable(issues, func(i, j int) bool {
+		return issues[i].Severity > issues[j].Severity
+	})
+	return issues
+}
+
+// Rows renders one table row per object with the cells {object, reasons}.
+// Use it with headers such as {"Table", "Reasons"}.
+func (o *ObjectIssues) Rows() []TableRow {
+	issues := o.Issues()
+	rows := make([]TableRow, 0, len(issues))
+	for _, issue := range issues {
+		rows = append(rows, TableRow{
+			Cells:    []string{issue.Object, issue.ReasonList()},
+			Severity: issue.Severity,
+		})
+	}
+	return rows
+}
+
+// ReasonList formats the reasons as a bullet list, one per line.
+// A single reason is returned as-is.
+func (i ObjectIssue) ReasonList() string {
+	if len(i.Reasons) == 1 {
+		return i.Reasons[0]
+	}
+	lines := make([]string, len(i.Reasons))
+	for n, reason := range i.Reasons {
+		lines[n] = "- " + reason
+	}
+	return strings.Join(lines, "\n")
+}
@@ -0,0 +1,42 @@
+package check_test
+
+import (
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/require"
+)
+
+func TestObjectIssues_MergesByObject(t *testing.T) {
+	t.Parallel()
+
+	var issues check.ObjectIssues
+	issues.Add("public.orders", check.SeverityWarn, "autovacuum disabled")
+	issues.Add("public.users", check.SeverityWarn, "never analyzed")
+	issues.Add("public.orders", check.SeverityFail, "dead tuples > 20%")
+	issues.Add("public.orders", check.SeverityWarn, "autovacuum disabled")
+
+	require.Equal(t, 2, issues.Len())
+	require.Equal(t, check.SeverityFail, issues.Severity())
+
+	merged := issues.Issues()
+	require.Equal(t, "public.orders", merged[0].Object, "worst severity first")
+	require.Equal(t, check.SeverityFail, merged[0].Severity)
+	require.Equal(t, []string{"autovacuum disabled", "dead tuples > 20%"}, merged[0].Reasons)
+
+	rows := issues.Rows()
+	require.Len(t, rows, 2)
+	require.Equal(t, []string{"public.orders", "- autovacuum disabled\n- dead tuples > 20%"}, rows[0].Cells)
+	require.Equal(t, []string{"public.users", "never analyzed"}, rows[1].Cells)
+	require.Equal(t, check.SeverityWarn, rows[1].Severity)
+}
+
+func TestObjectIssues_Empty(t *testing.T) {
+	t.Parallel()
+
+	var issues check.ObjectIssues
+
+	require.Zero(t, issues.Len())
+	require.Equal(t, check.SeverityOK, issues.Severity())
+	require.Empty(t, issues.Rows())
+}
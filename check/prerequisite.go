@@ -0,0 +1,82 @@
+package check
+
+import "fmt"
+
+// PrerequisiteKind is what a Prerequisite refers to.
+type PrerequisiteKind string
+
+const (
+	PrerequisiteExtension PrerequisiteKind = "extension" // Installed in the database
+	PrerequisiteRole      PrerequisiteKind = "role"      // Granted to the connecting user
+	PrerequisiteFunction  PrerequisiteKind = "function"  // EXECUTE privilege on the function
+	PrerequisiteRelation  PrerequisiteKind = "relation"  // Exists and is readable by the user
+)
+
+// Prerequisite is something a check needs from the server before its queries can
+// succeed. Declare them in Metadata.Requires.
+type Prerequisite struct {
+	Kind PrerequisiteKind
+	// Name is the extension or role name, the function signature
+	// (e.g. "pg_ls_tmpdir(oid)") or the optionally schema-qualified relation.
+	Name string
+	// Hint tells the user how to satisfy the prerequisite. When empty, the
+	// default instruction for the kind is used.
+	Hint string
+}
+
+// RequireExtension requires the extension to be installed in the database.
+func RequireExtension(name string) Prerequisite {
+	return Prerequisite{Kind: PrerequisiteExtension, Name: name}
+}
+
+// RequireRole requires the connecting user to be a member of the role, e.g. pg_monitor.
+// Superusers always satisfy it.
+func RequireRole(name string) Prerequisite {
+	return Prerequisite{Kind: PrerequisiteRole, Name: name}
+}
+
+// RequireFunction requires EXECUTE on the function, given by its signature.
+func RequireFunction(signature, hint string) Prerequisite {
+	return Prerequisite{Kind: PrerequisiteFunction, Name: signature, Hint: hint}
+}
+
+// RequireRelation requires the table or view to exist and be readable.
+func RequireRelation(name, hint string) Prerequisite {
+	return Prerequisite{Kind: PrerequisiteRelation, Name: name, Hint: hint}
+}
+
+// String describes the prerequisite, e.g. "the pg_stat_statements extension".
+func (p Prerequisite) String() string {
+	switch p.Kind {
+	case PrerequisiteExtension:
+		return fmt.Sprintf("the %s extension", p.Name)
+	case PrerequisiteRole:
+		return fmt.Sprintf("membership in %s", p.Name)
+	case PrerequisiteFunction:
+		return fmt.Sprintf("EXECUTE on %s", p.Name)
+	case PrerequisiteRelation:
+		return fmt.Sprintf("SELECT on %s", p.Name)
+	default:
+		return p.Name
+	}
+}
+
+// Instructions returns Hint, or the default way to satisfy the prerequisite for
+// the given user.
+func (p Prerequisite) Instructions(user string) string {
+	if p.Hint != "" {
+		return p.Hint
+	}
+	switch p.Kind {
+	case PrerequisiteExtension:
+		return fmt.Sprintf("CREATE EXTENSION %s; (some extensions must also be in shared_preload_libraries)", p.Name)
+	case PrerequisiteRole:
+		return fmt.Sprintf("GRANT %s TO %s;", p.Name, user)
+	case PrerequisiteFunction:
+		return fmt.Sprintf("GRANT EXECUTE ON FUNCTION %s TO %s;", p.Name, user)
+	case PrerequisiteRelation:
+		return fmt.Sprintf("GRANT SELECT ON %s TO %s;", p.Name, user)
+	default:
+		return ""
+	}
+}
@@ -0,0 +1,30 @@
+package check_test
+
+import (
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestPrerequisite_Instructions(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		req  check.Prerequisite
+		want string
+	}{
+		{"role", check.RequireRole("pg_read_all_stats"), "GRANT pg_read_all_stats TO doctor;"},
+		{"function", check.RequireFunction("pg_ls_waldir()", ""), "GRANT EXECUTE ON FUNCTION pg_ls_waldir() TO doctor;"},
+		{"relation", check.RequireRelation("pg_stat_io", ""), "GRANT SELECT ON pg_stat_io TO doctor;"},
+		{"hint wins", check.RequireRelation("pg_stat_io", "upgrade to PostgreSQL 16"), "upgrade to PostgreSQL 16"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+			assert.Equal(t, tt.want, tt.req.Instructions("doctor"))
+		})
+	}
+}
@@ -0,0 +1,95 @@
+package check
+
+import (
+	"context"
+	"fmt"
+)
+
+// Provider identifies where the server runs. Managed services reject ALTER SYSTEM
+// and file-level access, so checks use it to phrase fixes the user can apply.
+type Provider string
+
+const (
+	ProviderSelfManaged Provider = ""
+	ProviderRDS         Provider = "rds"
+	ProviderAurora      Provider = "aurora"
+	ProviderCloudSQL    Provider = "cloudsql"
+	ProviderAzure       Provider = "azure"
+)
+
+// Managed reports whether the server is a managed service.
+func (p Provider) Managed() bool {
+	return p != ProviderSelfManaged
+}
+
+func (p Provider) String() string {
+	switch p {
+	case ProviderSelfManaged:
+		return "self-managed"
+	case ProviderRDS:
+		return "Amazon RDS"
+	case ProviderAurora:
+		return "Amazon Aurora"
+	case ProviderCloudSQL:
+		return "Google Cloud SQL"
+	case ProviderAzure:
+		return "Azure Database for PostgreSQL"
+	default:
+		return string(p)
+	}
+}
+
+// SettingFix returns how to set a server parameter on this provider, and the
+// SQL that does it when ALTER SYSTEM is available (self-managed only).
+func (p Provider) SettingFix(name, value string) (remediation, fixSQL string) {
+	switch p {
+	case ProviderSelfManaged:
+		return fmt.Sprintf("Set %s to %s and reload the configuration", name, value),
+			fmt.Sprintf("ALTER SYSTEM SET %s = '%s';\nSELECT pg_reload_conf();", name, value)
+	case ProviderRDS:
+		return fmt.Sprintf("Set %s to %s in the DB parameter group", name, value), ""
+	case ProviderAurora:
+		return fmt.Sprintf("Set %s to %s in the DB cluster parameter group", name, value), ""
+	case ProviderCloudSQL:
+		return fmt.Sprintf("Set the %s database flag to %s", name, value), ""
+	case ProviderAzure:
+		return fmt.Sprintf("Set the %s server parameter to %s", name, value), ""
+	default:
+		return fmt.Sprintf("Set %s to %s in the provider's parameter settings", name, value), ""
+	}
+}
+
+// RestartSettingFix is SettingFix for parameters that only take effect after a
+// restart (postmaster context), such as shared_buffers.
+func (p Provider) RestartSettingFix(name, value string) (remediation, fixSQL string) {
+	if p.Managed() {
+		remediation, _ = p.SettingFix(name, value)
+		return remediation + ", then reboot the instance during a maintenance window", ""
+	}
+	return fmt.Sprintf("Set %s to %s and restart the server during a maintenance window", name, value),
+		fmt.Sprintf("ALTER SYSTEM SET %s = '%s';", name, value)
+}
+
+// RestartFix returns how to restart the server on this provider.
+func (p Provider) RestartFix() string {
+	if p.Managed() {
+		return fmt.Sprintf("Reboot the instance from the %s console during a maintenance window", p)
+	}
+	return "Restart the server during a maintenance window"
+}
+
+type providerKey struct{}
+
+// ContextWithProvider returns a new context carrying the server's provider.
+func ContextWithProvider(ctx context.Context, provider Provider) context.Context {
+	return context.WithValue(ctx, providerKey{}, provider)
+}
+
+// ProviderFromContext returns the provider attached by the runner,
+// or ProviderSelfManaged if none is known.
+func ProviderFromContext(ctx context.Context) Provider {
+	if provider, ok := ctx.Value(providerKey{}).(Provider); ok {
+		return provider
+	}
+	return ProviderSelfManaged
+}
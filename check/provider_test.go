@@ -0,0 +1,57 @@
+package check_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/require"
+)
+
+func TestProvider_SettingFix(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		provider    check.Provider
+		remediation string
+		hasSQL      bool
+	}{
+		{check.ProviderSelfManaged, "Set work_mem to 64MB and reload the configuration", true},
+		{check.ProviderRDS, "Set work_mem to 64MB in the DB parameter group", false},
+		{check.ProviderAurora, "Set work_mem to 64MB in the DB cluster parameter group", false},
+		{check.ProviderCloudSQL, "Set the work_mem database flag to 64MB", false},
+		{check.ProviderAzure, "Set the work_mem server parameter to 64MB", false},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.provider.String(), func(t *testing.T) {
+			t.Parallel()
+
+			remediation, fixSQL := tc.provider.SettingFix("work_mem", "64MB")
+			require.Equal(t, tc.remediation, remediation)
+			require.Equal(t, tc.hasSQL, fixSQL != "")
+			require.Equal(t, !tc.hasSQL, tc.provider.Managed())
+		})
+	}
+}
+
+func TestProvider_RestartSettingFix(t *testing.T) {
+	t.Parallel()
+
+	remediation, fixSQL := check.ProviderSelfManaged.RestartSettingFix("shared_buffers", "4GB")
+	require.Equal(t, "Set shared_buffers to 4GB and restart the server during a maintenance window", remediation)
+	require.Equal(t, "ALTER SYSTEM SET shared_buffers = '4GB';", fixSQL)
+
+	remediation, fixSQL = check.ProviderRDS.RestartSettingFix("shared_buffers", "4GB")
+	require.Equal(t, "Set shared_buffers to 4GB in the DB parameter group, then reboot the instance during a maintenance window", remediation)
+	require.Empty(t, fixSQL)
+}
+
+func TestProviderFromContext(t *testing.T) {
+	t.Parallel()
+
+	require.Equal(t, check.ProviderSelfManaged, check.ProviderFromContext(context.Background()))
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderCloudSQL)
+	require.Equal(t, check.ProviderCloudSQL, check.ProviderFromContext(ctx))
+}
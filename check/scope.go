@@ -0,0 +1,214 @@
+package check
+
+import (
+	"context"
+	"fmt"
+	"path"
+	"regexp"
+	"strings"
+)
+
+// Scope narrows which database objects table-level checks report on.
+// Schema and table filters compose: an object must pass both to be reported.
+// It is attached to the context once per run, so every check honors the same
+// filters without each one growing its own configuration keys.
+// A nil Scope includes everything.
+type Scope struct {
+	// Schemas lists the schemas to include (glob patterns allowed, e.g. "tenant_*").
+	// Empty means every non-system schema.
+	Schemas []string
+	// ExcludeSchemas lists schemas to skip (glob patterns allowed).
+	// Exclusions win over inclusions.
+	ExcludeSchemas []string
+	// IncludeTables lists the tables to include. Glob patterns containing a dot
+	// match "schema.table", others match the bare table name (e.g. "orders_*").
+	// Patterns wrapped in slashes are regular expressions (e.g. "/_p\d+$/").
+	// Empty means every table in the included schemas.
+	IncludeTables []string
+	// ExcludeTables lists tables to skip, using the same syntax as IncludeTables.
+	// Exclusions win over inclusions.
+	ExcludeTables []string
+	// MinRows skips tables with fewer estimated rows in checks that opt into
+	// floors (dead tuples, seq scans, vacuum health). Zero disables the floor.
+	MinRows int64
+	// MinSizeBytes skips tables smaller than this in the same checks. Zero disables the floor.
+	MinSizeBytes int64
+}
+
+type scopeKey struct{}
+
+// ContextWithScope returns a new context with the run scope attached.
+func ContextWithScope(ctx context.Context, scope *Scope) context.Context {
+	return context.WithValue(ctx, scopeKey{}, scope)
+}
+
+// ScopeFromContext retrieves the run scope from the context.
+// Returns nil if no scope is present, which includes everything.
+func ScopeFromContext(ctx context.Context) *Scope {
+	if scope, ok := ctx.Value(scopeKey{}).(*Scope); ok {
+		return scope
+	}
+	return nil
+}
+
+// IncludesSchema reports whether objects in the given schema should be checked.
+func (s *Scope) IncludesSchema(schema string) bool {
+	if s == nil {
+		return true
+	}
+	if matchesAny(s.ExcludeSchemas, schema) {
+		return false
+	}
+	return len(s.Schemas) == 0 || matchesAny(s.Schemas, schema)
+}
+
+// IncludesTable reports whether a schema-qualified relation name ("schema.table")
+// should be checked. Unqualified names are treated as living in "public".
+// Schema and table filters compose: the relation must pass both.
+func (s *Scope) IncludesTable(qualifiedName string) bool {
+	if s == nil {
+		return true
+	}
+	schema, table := SplitQualifiedName(qualifiedName)
+	if !s.IncludesSchema(schema) {
+		return false
+	}
+	qualified := schema + "." + table
+	if matchesAnyTable(s.ExcludeTables, table, qualified) {
+		return false
+	}
+	return len(s.IncludeTables) == 0 || matchesAnyTable(s.IncludeTables, table, qualified)
+}
+
+// FilterTables returns the rows whose relation is within the scope attached to ctx.
+// name extracts the schema-qualified relation name from a row.
+func FilterTables[T any](ctx context.Context, rows []T, name func(T) string) []T {
+	scope := ScopeFromContext(ctx)
+	if scope == nil {
+		return rows
+	}
+
+	filtered := make([]T, 0, len(rows))
+	for _, row := range rows {
+		if scope.IncludesTable(name(row)) {
+			filtered = append(filtered, row)
+		}
+	}
+	return filtered
+}
+
+// AboveFloors reports whether a table is large enough to be worth reporting.
+// Tables below either floor are skipped.
+func (s *Scope) AboveFloors(rows, sizeBytes int64) bool {
+	if s == nil {
+		return true
+	}
+	return rows >= s.MinRows && sizeBytes >= s.MinSizeBytes
+}
+
+// WithFloors returns a copy of the scope with the given floors.
+// Negative values keep the current floor. A nil scope yields a new one.
+func (s *Scope) WithFloors(minRows, minSizeBytes int64) *Scope {
+	scoped := Scope{}
+	if s != nil {
+		scoped = *s
+	}
+	if minRows >= 0 {
+		scoped.MinRows = minRows
+	}
+	if minSizeBytes >= 0 {
+		scoped.MinSizeBytes = minSizeBytes
+	}
+	return &scoped
+}
+
+// FilterSmallTables drops rows for tables below the floors of the scope attached to ctx.
+// stats extracts the estimated row count and total size in bytes from a row.
+// Use it in checks whose findings are noise on tiny lookup tables.
+func FilterSmallTables[T any](ctx context.Context, rows []T, stats func(T) (rows, sizeBytes int64)) []T {
+	scope := ScopeFromContext(ctx)
+	if scope == nil || (scope.MinRows == 0 && scope.MinSizeBytes == 0) {
+		return rows
+	}
+
+	filtered := make([]T, 0, len(rows))
+	for _, row := range rows {
+		if scope.AboveFloors(stats(row)) {
+			filtered = append(filtered, row)
+		}
+	}
+	return filtered
+}
+
+// SplitQualifiedName splits "schema.table" into its parts.
+// Names without a schema prefix are reported as belonging to "public".
+func SplitQualifiedName(qualifiedName string) (schema, table string) {
+	schema, table, found := strings.Cut(qualifiedName, ".")
+	if !found {
+		return "public", qualifiedName
+	}
+	return schema, table
+}
+
+// ValidatePatterns returns an error if any glob or /regex/ pattern is malformed.
+func ValidatePatterns(patterns []string) error {
+	for _, pattern := range patterns {
+		if expr, ok := regexPattern(pattern); ok {
+			if _, err := regexp.Compile(expr); err != nil {
+				return fmt.Errorf("invalid pattern %q: %w", pattern, err)
+			}
+			continue
+		}
+		if _, err := path.Match(pattern, ""); err != nil {
+			return fmt.Errorf("invalid pattern %q: %w", pattern, err)
+		}
+	}
+	return nil
+}
+
+func matchesAny(patterns []string, name string) bool {
+	for _, pattern := range patterns {
+		if matchPattern(pattern, name) {
+			return true
+		}
+	}
+	return false
+}
+
+// matchesAnyTable matches globs with a dot against the qualified name and the
+// rest against the bare table name. Regular expressions may match either.
+func matchesAnyTable(patterns []string, table, qualified string) bool {
+	for _, pattern := range patterns {
+		if _, ok := regexPattern(pattern); ok {
+			if matchPattern(pattern, table) || matchPattern(pattern, qualified) {
+				return true
+			}
+			continue
+		}
+		name := table
+		if strings.Contains(pattern, ".") {
+			name = qualified
+		}
+		if matchPattern(pattern, name) {
+			return true
+		}
+	}
+	return false
+}
+
+func matchPattern(pattern, name string) bool {
+	if expr, ok := regexPattern(pattern); ok {
+		re, err := regexp.Compile(expr)
+		return err == nil && re.MatchString(name)
+	}
+	ok, _ := path.Match(pattern, name)
+	return ok
+}
+
+// regexPattern unwraps a "/expr/" pattern into its regular expression.
+func regexPattern(pattern string) (string, bool) {
+	if len(pattern) >= 2 && strings.HasPrefix(pattern, "/") && strings.HasSuffix(pattern, "/") {
+		return pattern[1 : len(pattern)-1], true
+	}
+	return "", false
+}
@@ -0,0 +1,166 @@
+package check_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestScope_IncludesSchema(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		scope  *check.Scope
+		schema string
+		expect bool
+	}{
+		{name: "nil scope includes everything", scope: nil, schema: "billing", expect: true},
+		{name: "empty scope includes everything", scope: &check.Scope{}, schema: "billing", expect: true},
+		{name: "exact include", scope: &check.Scope{Schemas: []string{"billing"}}, schema: "billing", expect: true},
+		{name: "not in include list", scope: &check.Scope{Schemas: []string{"billing"}}, schema: "public", expect: false},
+		{name: "glob include", scope: &check.Scope{Schemas: []string{"tenant_*"}}, schema: "tenant_42", expect: true},
+		{name: "exact exclude", scope: &check.Scope{ExcludeSchemas: []string{"audit"}}, schema: "audit", expect: false},
+		{name: "glob exclude", scope: &check.Scope{ExcludeSchemas: []string{"tenant_*"}}, schema: "tenant_42", expect: false},
+		{
+			name:   "exclude wins over include",
+			scope:  &check.Scope{Schemas: []string{"tenant_*"}, ExcludeSchemas: []string{"tenant_archive"}},
+			schema: "tenant_archive",
+			expect: false,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			assert.Equal(t, tt.expect, tt.scope.IncludesSchema(tt.schema))
+		})
+	}
+}
+
+func TestScope_IncludesTable_UnqualifiedIsPublic(t *testing.T) {
+	t.Parallel()
+
+	scope := &check.Scope{Schemas: []string{"public"}}
+
+	assert.True(t, scope.IncludesTable("users"))
+	assert.True(t, scope.IncludesTable("public.users"))
+	assert.False(t, scope.IncludesTable("billing.invoices"))
+}
+
+func TestScope_IncludesTable(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		scope  *check.Scope
+		table  string
+		expect bool
+	}{
+		{name: "no table filters", scope: &check.Scope{}, table: "public.orders", expect: true},
+		{name: "glob exclude on bare name", scope: &check.Scope{ExcludeTables: []string{"*_archive"}}, table: "billing.orders_archive", expect: false},
+		{name: "glob include on bare name", scope: &check.Scope{IncludeTables: []string{"orders*"}}, table: "public.users", expect: false},
+		{name: "qualified glob", scope: &check.Scope{ExcludeTables: []string{"billing.*"}}, table: "public.invoices", expect: true},
+		{name: "qualified glob matches", scope: &check.Scope{ExcludeTables: []string{"billing.*"}}, table: "billing.invoices", expect: false},
+		{name: "regex exclude", scope: &check.Scope{ExcludeTables: []string{`/_p\d+$/`}}, table: "public.events_p2024", expect: false},
+		{name: "regex keeps non-matching", scope: &check.Scope{ExcludeTables: []string{`/_p\d+$/`}}, table: "public.events", expect: true},
+		{
+			name:   "exclude wins over include",
+			scope:  &check.Scope{IncludeTables: []string{"orders*"}, ExcludeTables: []string{"orders_archive"}},
+			table:  "public.orders_archive",
+			expect: false,
+		},
+		{
+			name:   "composes with schema filters",
+			scope:  &check.Scope{Schemas: []string{"billing"}, IncludeTables: []string{"orders"}},
+			table:  "public.orders",
+			expect: false,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			assert.Equal(t, tt.expect, tt.scope.IncludesTable(tt.table))
+		})
+	}
+}
+
+func TestFilterTables(t *testing.T) {
+	t.Parallel()
+
+	rows := []string{"public.users", "billing.invoices", "tenant_1.orders"}
+	name := func(r string) string { return r }
+
+	t.Run("no scope returns rows unchanged", func(t *testing.T) {
+		t.Parallel()
+
+		assert.Equal(t, rows, check.FilterTables(context.Background(), rows, name))
+	})
+
+	t.Run("scope filters rows", func(t *testing.T) {
+		t.Parallel()
+
+		ctx := check.ContextWithScope(context.Background(), &check.Scope{ExcludeSchemas: []string{"tenant_*"}})
+		assert.Equal(t, []string{"public.users", "billing.invoices"}, check.FilterTables(ctx, rows, name))
+	})
+}
+
+func TestValidatePatterns(t *testing.T) {
+	t.Parallel()
+
+	require.NoError(t, check.ValidatePatterns([]string{"public", "tenant_*", "app_[0-9]"}))
+	require.Error(t, check.ValidatePatterns([]string{"tenant_["}))
+	require.NoError(t, check.ValidatePatterns([]string{`/^events_p\d+$/`}))
+	require.Error(t, check.ValidatePatterns([]string{"/events_(/"}))
+}
+
+func TestFilterSmallTables(t *testing.T) {
+	t.Parallel()
+
+	type table struct {
+		name       string
+		rows, size int64
+	}
+	rows := []table{
+		{name: "countries", rows: 250, size: 64 * check.KiB},
+		{name: "orders", rows: 5_000_000, size: 2 * check.GiB},
+		{name: "wide_blobs", rows: 200, size: 50 * check.MiB},
+	}
+	stats := func(r table) (int64, int64) { return r.rows, r.size }
+
+	t.Run("no scope returns rows unchanged", func(t *testing.T) {
+		t.Parallel()
+
+		assert.Equal(t, rows, check.FilterSmallTables(context.Background(), rows, stats))
+	})
+
+	t.Run("tables below either floor are dropped", func(t *testing.T) {
+		t.Parallel()
+
+		ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000, MinSizeBytes: 10 * check.MiB})
+		filtered := check.FilterSmallTables(ctx, rows, stats)
+		require.Len(t, filtered, 1)
+		assert.Equal(t, "orders", filtered[0].name)
+	})
+}
+
+func TestScope_WithFloors(t *testing.T) {
+	t.Parallel()
+
+	base := &check.Scope{Schemas: []string{"billing"}, MinRows: 1000, MinSizeBytes: 10 * check.MiB}
+
+	scoped := base.WithFloors(0, -1)
+	assert.Equal(t, int64(0), scoped.MinRows)
+	assert.Equal(t, int64(10*check.MiB), scoped.MinSizeBytes)
+	assert.Equal(t, []string{"billing"}, scoped.Schemas)
+	assert.Equal(t, int64(1000), base.MinRows, "original scope is not modified")
+
+	var nilScope *check.Scope
+	assert.Equal(t, int64(500), nilScope.WithFloors(500, -1).MinRows)
+}
@@ -5,32 +5,78 @@ package pgdoctor
 
 import (
 	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/bgwriter"
+	"github.com/emancu/pgdoctor/checks/blockinglocks"
+	"github.com/emancu/pgdoctor/checks/brinindexes"
 	"github.com/emancu/pgdoctor/checks/cacheefficiency"
+	"github.com/emancu/pgdoctor/checks/checkpoints"
+	"github.com/emancu/pgdoctor/checks/columntypes"
 	"github.com/emancu/pgdoctor/checks/connectionefficiency"
 	"github.com/emancu/pgdoctor/checks/connectionhealth"
+	"github.com/emancu/pgdoctor/checks/datachecksums"
+	"github.com/emancu/pgdoctor/checks/deadlocks"
 	"github.com/emancu/pgdoctor/checks/duplicateindexes"
+	"github.com/emancu/pgdoctor/checks/durability"
+	"github.com/emancu/pgdoctor/checks/encodingcollation"
+	"github.com/emancu/pgdoctor/checks/extensions"
 	"github.com/emancu/pgdoctor/checks/freezeage"
+	"github.com/emancu/pgdoctor/checks/ginpendinglist"
+	"github.com/emancu/pgdoctor/checks/hugepages"
 	"github.com/emancu/pgdoctor/checks/indexbloat"
+	"github.com/emancu/pgdoctor/checks/indextableratio"
 	"github.com/emancu/pgdoctor/checks/indexusage"
 	"github.com/emancu/pgdoctor/checks/invalidindexes"
+	"github.com/emancu/pgdoctor/checks/leftovertables"
+	"github.com/emancu/pgdoctor/checks/logicalreplication"
+	"github.com/emancu/pgdoctor/checks/longrunningqueries"
+	"github.com/emancu/pgdoctor/checks/materializedviews"
+	"github.com/emancu/pgdoctor/checks/memoryovercommit"
+	"github.com/emancu/pgdoctor/checks/missingforeignkeys"
+	"github.com/emancu/pgdoctor/checks/multixactage"
+	"github.com/emancu/pgdoctor/checks/notvalidconstraints"
+	"github.com/emancu/pgdoctor/checks/objectownership"
+	"github.com/emancu/pgdoctor/checks/observabilityextensions"
+	"github.com/emancu/pgdoctor/checks/partialindexes"
 	"github.com/emancu/pgdoctor/checks/partitioning"
 	"github.com/emancu/pgdoctor/checks/partitionusage"
+	"github.com/emancu/pgdoctor/checks/passwordencryption"
 	"github.com/emancu/pgdoctor/checks/pgversion"
 	"github.com/emancu/pgdoctor/checks/pktypes"
+	"github.com/emancu/pgdoctor/checks/publicprivileges"
+	"github.com/emancu/pgdoctor/checks/queryspills"
+	"github.com/emancu/pgdoctor/checks/randompagecost"
 	"github.com/emancu/pgdoctor/checks/replicationlag"
 	"github.com/emancu/pgdoctor/checks/replicationslots"
+	"github.com/emancu/pgdoctor/checks/roleprivileges"
+	"github.com/emancu/pgdoctor/checks/schemachanges"
 	"github.com/emancu/pgdoctor/checks/sequencehealth"
+	"github.com/emancu/pgdoctor/checks/serialcolumns"
 	"github.com/emancu/pgdoctor/checks/sessionsettings"
+	"github.com/emancu/pgdoctor/checks/sharedbuffers"
+	"github.com/emancu/pgdoctor/checks/sslconnections"
 	"github.com/emancu/pgdoctor/checks/statisticsfreshness"
+	"github.com/emancu/pgdoctor/checks/statisticstarget"
 	"github.com/emancu/pgdoctor/checks/tableactivity"
 	"github.com/emancu/pgdoctor/checks/tablebloat"
+	"github.com/emancu/pgdoctor/checks/tablereloptions"
 	"github.com/emancu/pgdoctor/checks/tableseqscans"
+	"github.com/emancu/pgdoctor/checks/tableswithoutindexes"
 	"github.com/emancu/pgdoctor/checks/tablevacuumhealth"
+	"github.com/emancu/pgdoctor/checks/tempfiles"
 	"github.com/emancu/pgdoctor/checks/tempusage"
+	"github.com/emancu/pgdoctor/checks/timezone"
 	"github.com/emancu/pgdoctor/checks/toaststorage"
+	"github.com/emancu/pgdoctor/checks/topqueries"
+	"github.com/emancu/pgdoctor/checks/tracksettings"
+	"github.com/emancu/pgdoctor/checks/unindexedforeignkeys"
+	"github.com/emancu/pgdoctor/checks/unloggedtables"
+	"github.com/emancu/pgdoctor/checks/unuseddatabases"
 	"github.com/emancu/pgdoctor/checks/uuiddefaults"
 	"github.com/emancu/pgdoctor/checks/uuidtypes"
 	"github.com/emancu/pgdoctor/checks/vacuumsettings"
+	"github.com/emancu/pgdoctor/checks/walarchiving"
+	"github.com/emancu/pgdoctor/checks/walretention"
+	"github.com/emancu/pgdoctor/checks/widetables"
 	"github.com/emancu/pgdoctor/db"
 )
 
@@ -38,12 +84,42 @@ import (
 // Consumers call .Metadata() for check information or .New(conn, cfg) to instantiate checkers.
 func AllChecks() []check.Package {
 	return []check.Package{
+		{
+			Metadata: bgwriter.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return bgwriter.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: blockinglocks.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return blockinglocks.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: brinindexes.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return brinindexes.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: cacheefficiency.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return cacheefficiency.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: checkpoints.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return checkpoints.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: columntypes.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return columntypes.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: connectionefficiency.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -56,24 +132,72 @@ func AllChecks() []check.Package {
 				return connectionhealth.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: datachecksums.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return datachecksums.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: deadlocks.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return deadlocks.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: duplicateindexes.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return duplicateindexes.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: durability.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return durability.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: encodingcollation.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return encodingcollation.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: extensions.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return extensions.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: freezeage.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return freezeage.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: ginpendinglist.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return ginpendinglist.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: hugepages.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return hugepages.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: indexbloat.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return indexbloat.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: indextableratio.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return indextableratio.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: indexusage.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -86,6 +210,72 @@ func AllChecks() []check.Package {
 				return invalidindexes.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: leftovertables.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return leftovertables.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: logicalreplication.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return logicalreplication.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: longrunningqueries.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return longrunningqueries.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: materializedviews.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return materializedviews.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: memoryovercommit.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return memoryovercommit.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: missingforeignkeys.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return missingforeignkeys.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: multixactage.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return multixactage.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: notvalidconstraints.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return notvalidconstraints.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: objectownership.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return objectownership.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: observabilityextensions.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return observabilityextensions.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: partialindexes.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return partialindexes.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: partitioning.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -98,6 +288,12 @@ func AllChecks() []check.Package {
 				return partitionusage.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: passwordencryption.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return passwordencryption.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: pgversion.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -110,6 +306,24 @@ func AllChecks() []check.Package {
 				return pktypes.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: publicprivileges.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return publicprivileges.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: queryspills.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return queryspills.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: randompagecost.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return randompagecost.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: replicationlag.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -122,24 +336,60 @@ func AllChecks() []check.Package {
 				return replicationslots.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: roleprivileges.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return roleprivileges.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: schemachanges.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return schemachanges.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: sequencehealth.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return sequencehealth.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: serialcolumns.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return serialcolumns.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: sessionsettings.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return sessionsettings.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: sharedbuffers.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return sharedbuffers.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: sslconnections.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return sslconnections.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: statisticsfreshness.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return statisticsfreshness.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: statisticstarget.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return statisticstarget.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: tableactivity.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -152,30 +402,84 @@ func AllChecks() []check.Package {
 				return tablebloat.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: tablereloptions.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return tablereloptions.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: tableseqscans.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return tableseqscans.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: tableswithoutindexes.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return tableswithoutindexes.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: tablevacuumhealth.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return tablevacuumhealth.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: tempfiles.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return tempfiles.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: tempusage.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return tempusage.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: timezone.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return timezone.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: toaststorage.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
 				return toaststorage.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: topqueries.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return topqueries.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: tracksettings.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return tracksettings.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: unindexedforeignkeys.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return unindexedforeignkeys.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: unloggedtables.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return unloggedtables.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: unuseddatabases.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return unuseddatabases.New(db.New(conn), cfg)
+			},
+		},
 		{
 			Metadata: uuiddefaults.Metadata,
 			New: func(conn db.DBTX, cfg check.Config) check.Checker {
@@ -194,5 +498,23 @@ func AllChecks() []check.Package {
 				return vacuumsettings.New(db.New(conn), cfg)
 			},
 		},
+		{
+			Metadata: walarchiving.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return walarchiving.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: walretention.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return walretention.New(db.New(conn), cfg)
+			},
+		},
+		{
+			Metadata: widetables.Metadata,
+			New: func(conn db.DBTX, cfg check.Config) check.Checker {
+				return widetables.New(db.New(conn), cfg)
+			},
+		},
 	}
 }
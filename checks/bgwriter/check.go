@@ -0,0 +1,209 @@
+// Package bgwriter implements checks for background writer and backend buffer writes.
+package bgwriter
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// Backend writes are counted in pg_stat_io from PostgreSQL 17.
+	ioStatsVersion = 170000
+
+	// Fewer buffers written than this since the reset are too few to judge.
+	minBuffersWritten = 10_000
+
+	backendWriteWarnPercent = 20.0
+
+	// Share of cleaned buffers written in rounds cut short by
+	// bgwriter_lru_maxpages beyond which the limit holds the writer back.
+	maxwrittenWarnPercent = 50.0
+
+	defaultLruMaxpages = 100
+)
+
+// backendWriteRemediation starts from the background writer: it exists so
+// that backends find clean buffers and never write on their own.
+const backendWriteRemediation = "Let the background writer clean more buffers ahead of backends " +
+	"(raise bgwriter_lru_maxpages or bgwriter_lru_multiplier, lower bgwriter_delay), " +
+	"and check whether shared_buffers is too small for the working set"
+
+type BgwriterQueries interface {
+	BgwriterStats(context.Context) (db.BgwriterStatsRow, error)
+	BgwriterStatsLegacy(context.Context) (db.BgwriterStatsLegacyRow, error)
+}
+
+type checker struct {
+	queries BgwriterQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "bgwriter",
+		Name:        "Background Writer",
+		Description: "Detects backends writing and fsyncing buffers themselves instead of the background writer",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries BgwriterQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	stats, err := c.stats(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	total := stats.BuffersCheckpoint + stats.BuffersClean + stats.BuffersBackend
+	if total < minBuffersWritten {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Only %s buffers written since statistics were reset, too few to judge", check.FormatNumber(total)),
+		})
+		return report, nil
+	}
+
+	checkBackendWrites(stats, report)
+	checkMaxwritten(stats, check.ProviderFromContext(ctx), report)
+
+	return report, nil
+}
+
+// stats reads pg_stat_bgwriter and pg_stat_io, or only pg_stat_bgwriter
+// before PostgreSQL 17.
+func (c *checker) stats(ctx context.Context) (db.BgwriterStatsRow, error) {
+	if version := check.ServerVersionFromContext(ctx); version == 0 || version >= ioStatsVersion {
+		return c.queries.BgwriterStats(ctx)
+	}
+	legacy, err := c.queries.BgwriterStatsLegacy(ctx)
+	return db.BgwriterStatsRow(legacy), err
+}
+
+// checkBackendWrites warns when backends evict dirty buffers themselves, or
+// had to fsync because the checkpointer's request queue was full.
+func checkBackendWrites(stats db.BgwriterStatsRow, report *check.Report) {
+	total := stats.BuffersCheckpoint + stats.BuffersClean + stats.BuffersBackend
+	backendPercent := percent(stats.BuffersBackend, total)
+	metrics := []check.Metric{
+		{Name: "backend_writes", Value: backendPercent, Unit: check.UnitPercent},
+		{Name: "backend_fsyncs", Value: float64(stats.BuffersBackendFsync), Unit: check.UnitCount},
+	}
+
+	details := fmt.Sprintf("Backends wrote %.0f%% of %s buffers in %s (checkpoints %.0f%%, background writer %.0f%%)",
+		backendPercent, check.FormatNumber(total), check.FormatDurationSec(stats.SecondsSinceReset),
+		percent(stats.BuffersCheckpoint, total), percent(stats.BuffersClean, total))
+
+	if backendPercent < backendWriteWarnPercent && stats.BuffersBackendFsync == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "backend-writes",
+			Name:     "Backend Writes",
+			Severity: check.SeverityOK,
+			Details:  details,
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	remediation := backendWriteRemediation
+	if stats.BuffersBackendFsync > 0 {
+		details += fmt.Sprintf("; backends ran %s fsync(s) themselves because the checkpointer's request queue was full",
+			check.FormatNumber(stats.BuffersBackendFsync))
+		remediation += ". Backend fsyncs mean the checkpointer cannot keep up: check the storage's write latency"
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "backend-writes",
+		Name:        "Backend Writes",
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: remediation,
+		Metrics:     metrics,
+	})
+}
+
+// checkMaxwritten warns when the background writer keeps stopping at
+// bgwriter_lru_maxpages, or is disabled altogether.
+func checkMaxwritten(stats db.BgwriterStatsRow, provider check.Provider, report *check.Report) {
+	if stats.BgwriterLruMaxpages == 0 {
+		remediation, fixSQL := provider.SettingFix("bgwriter_lru_maxpages", strconv.Itoa(defaultLruMaxpages))
+		report.AddFinding(check.Finding{
+			ID:          "bgwriter-maxwritten",
+			Name:        "Background Writer Limit",
+			Severity:    check.SeverityWarn,
+			Details:     "bgwriter_lru_maxpages is 0: the background writer is disabled and backends clean buffers themselves",
+			Object:      &check.Object{Kind: check.ObjectSetting, Name: "bgwriter_lru_maxpages"},
+			Remediation: remediation,
+			FixSQL:      fixSQL,
+		})
+		return
+	}
+
+	// Each round cut short wrote bgwriter_lru_maxpages buffers.
+	cappedPercent := 0.0
+	if stats.BuffersClean > 0 {
+		cappedPercent = min(percent(stats.MaxwrittenClean*stats.BgwriterLruMaxpages, stats.BuffersClean), 100)
+	}
+
+	if cappedPercent < maxwrittenWarnPercent {
+		report.AddFinding(check.Finding{
+			ID:       "bgwriter-maxwritten",
+			Name:     "Background Writer Limit",
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("The background writer stopped at bgwriter_lru_maxpages (%d) %s time(s)",
+				stats.BgwriterLruMaxpages, check.FormatNumber(stats.MaxwrittenClean)),
+		})
+		return
+	}
+
+	suggested := strconv.FormatInt(stats.BgwriterLruMaxpages*2, 10)
+	remediation, fixSQL := provider.SettingFix("bgwriter_lru_maxpages", suggested)
+
+	report.AddFinding(check.Finding{
+		ID:       "bgwriter-maxwritten",
+		Name:     "Background Writer Limit",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("The background writer stopped at bgwriter_lru_maxpages (%d) %s time(s): %.0f%% of the buffers it cleaned were written in rounds cut short",
+			stats.BgwriterLruMaxpages, check.FormatNumber(stats.MaxwrittenClean), cappedPercent),
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "bgwriter_lru_maxpages"},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+		Comparison: &check.Comparison{
+			Setting:   "bgwriter_lru_maxpages",
+			Actual:    strconv.FormatInt(stats.BgwriterLruMaxpages, 10),
+			Expected:  suggested,
+			Threshold: fmt.Sprintf(">= %.0f%% of cleaned buffers in capped rounds", maxwrittenWarnPercent),
+		},
+	})
+}
+
+func percent(part, total int64) float64 {
+	if total == 0 {
+		return 0
+	}
+	return 100 * float64(part) / float64(total)
+}
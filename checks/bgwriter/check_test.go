@@ -0,0 +1,197 @@
+package bgwriter_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/bgwriter"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+const day = 86400
+
+type mockBgwriterQueryer struct {
+	version int32
+	stats   db.BgwriterStatsRow
+	err     error
+}
+
+func (m *mockBgwriterQueryer) BgwriterStats(context.Context) (db.BgwriterStatsRow, error) {
+	if m.err != nil {
+		return db.BgwriterStatsRow{}, m.err
+	}
+	if m.version < 170000 {
+		return db.BgwriterStatsRow{}, fmt.Errorf("relation \"pg_stat_checkpointer\" does not exist")
+	}
+	return m.stats, nil
+}
+
+func (m *mockBgwriterQueryer) BgwriterStatsLegacy(context.Context) (db.BgwriterStatsLegacyRow, error) {
+	if m.version >= 170000 {
+		return db.BgwriterStatsLegacyRow{}, fmt.Errorf("column \"buffers_backend\" does not exist")
+	}
+	return db.BgwriterStatsLegacyRow(m.stats), nil
+}
+
+func stats(checkpoint, clean, backend int64) db.BgwriterStatsRow {
+	return db.BgwriterStatsRow{
+		BuffersCheckpoint:   checkpoint,
+		BuffersClean:        clean,
+		MaxwrittenClean:     100,
+		BuffersBackend:      backend,
+		BuffersAlloc:        2 * (checkpoint + clean + backend),
+		SecondsSinceReset:   7 * day,
+		BgwriterLruMaxpages: 100,
+	}
+}
+
+func Test_Bgwriter_BackendWrites(t *testing.T) {
+	t.Parallel()
+
+	withFsyncs := stats(800_000, 150_000, 50_000)
+	withFsyncs.BuffersBackendFsync = 12
+
+	testCases := []struct {
+		Name             string
+		Stats            db.BgwriterStatsRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "background writer cleans ahead of backends - OK",
+			Stats:            stats(800_000, 150_000, 50_000),
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Backends wrote 5% of 1.0M buffers in 7d (checkpoints 80%, background writer 15%)",
+		},
+		{
+			Name:             "backends write their own buffers",
+			Stats:            stats(500_000, 100_000, 400_000),
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "Backends wrote 40% of 1.0M buffers in 7d",
+		},
+		{
+			Name:             "backends fsync their own writes",
+			Stats:            withFsyncs,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "backends ran 12 fsync(s) themselves because the checkpointer's request queue was full",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := bgwriter.New(&mockBgwriterQueryer{version: 170000, stats: tc.Stats})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "backend-writes")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_Bgwriter_Maxwritten(t *testing.T) {
+	t.Parallel()
+
+	row := stats(800_000, 150_000, 50_000)
+	report, err := bgwriter.New(&mockBgwriterQueryer{version: 170000, stats: row}).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "bgwriter-maxwritten").Severity)
+
+	row.MaxwrittenClean = 1000
+	report, err = bgwriter.New(&mockBgwriterQueryer{version: 170000, stats: row}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "bgwriter-maxwritten")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "stopped at bgwriter_lru_maxpages (100) 1.0K time(s): 67% of the buffers")
+	require.Equal(t, "200", finding.Comparison.Expected)
+	require.Equal(t, "ALTER SYSTEM SET bgwriter_lru_maxpages = '200';\nSELECT pg_reload_conf();", finding.FixSQL)
+}
+
+func Test_Bgwriter_Disabled(t *testing.T) {
+	t.Parallel()
+
+	row := stats(800_000, 0, 200_000)
+	row.MaxwrittenClean = 0
+	row.BgwriterLruMaxpages = 0
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := bgwriter.New(&mockBgwriterQueryer{version: 170000, stats: row}).Check(ctx)
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "bgwriter-maxwritten")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "the background writer is disabled")
+	require.Equal(t, "Set bgwriter_lru_maxpages to 100 in the DB parameter group", finding.Remediation)
+	require.Empty(t, finding.FixSQL)
+}
+
+func Test_Bgwriter_TooFewWrites(t *testing.T) {
+	t.Parallel()
+
+	checker := bgwriter.New(&mockBgwriterQueryer{version: 170000, stats: stats(4000, 500, 500)})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Contains(t, report.Results[0].Details, "Only 5.0K buffers written since statistics were reset")
+}
+
+func Test_Bgwriter_LegacyStats(t *testing.T) {
+	t.Parallel()
+
+	checker := bgwriter.New(&mockBgwriterQueryer{version: 150006, stats: stats(500_000, 100_000, 400_000)})
+	report, err := checker.Check(check.ContextWithServerVersion(context.Background(), 150006))
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "backend-writes").Severity)
+}
+
+func Test_Bgwriter_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("BgwriterStatsLegacy",
+			[]string{
+				"buffers_checkpoint int8", "buffers_clean int8", "maxwritten_clean int8", "buffers_backend int8",
+				"buffers_backend_fsync int8", "buffers_alloc int8", "seconds_since_reset int8", "bgwriter_lru_maxpages int8",
+			},
+			[]any{500000, 100000, 10, 400000, 0, 2000000, 7 * day, 100},
+		)
+
+	report, err := bgwriter.New(db.New(fx)).Check(check.ContextWithServerVersion(context.Background(), 160003))
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Contains(t, checktest.Finding(t, report, "backend-writes").Details, "Backends wrote 40%")
+}
+
+func Test_Bgwriter_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := bgwriter.New(&mockBgwriterQueryer{version: 170000, err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "bgwriter")
+}
+
+func Test_Bgwriter_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := bgwriter.Metadata()
+
+	require.Equal(t, "bgwriter", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
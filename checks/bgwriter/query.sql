@@ -0,0 +1,34 @@
+-- name: BgwriterStats :one
+-- Buffers written by checkpoints, the background writer and backends since
+-- statistics were reset (PostgreSQL 17+). Backend writes come from pg_stat_io.
+SELECT
+  c.buffers_written AS buffers_checkpoint
+  , b.buffers_clean
+  , b.maxwritten_clean
+  , COALESCE(io.writes, 0)::bigint AS buffers_backend
+  , COALESCE(io.fsyncs, 0)::bigint AS buffers_backend_fsync
+  , b.buffers_alloc
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , current_setting('bgwriter_lru_maxpages')::bigint AS bgwriter_lru_maxpages
+FROM pg_stat_bgwriter AS b
+CROSS JOIN pg_stat_checkpointer AS c
+CROSS JOIN (
+  SELECT
+    sum(writes) AS writes
+    , sum(fsyncs) AS fsyncs
+  FROM pg_stat_io
+  WHERE backend_type = 'client backend' AND object = 'relation'
+) AS io;
+
+-- name: BgwriterStatsLegacy :one
+-- Same as BgwriterStats, from pg_stat_bgwriter (PostgreSQL 16 and older).
+SELECT
+  b.buffers_checkpoint
+  , b.buffers_clean
+  , b.maxwritten_clean
+  , b.buffers_backend
+  , b.buffers_backend_fsync
+  , b.buffers_alloc
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , current_setting('bgwriter_lru_maxpages')::bigint AS bgwriter_lru_maxpages
+FROM pg_stat_bgwriter AS b;
@@ -0,0 +1,294 @@
+// Package blockinglocks implements a check for sessions blocked by other sessions' locks.
+package blockinglocks
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultWarnSeconds = 30  // Brief lock waits are normal
+	defaultFailSeconds = 300 // 5 minutes
+
+	// Chains this deep (a waits on b waits on c) fail regardless of wait time:
+	// every new session touching the rows joins the queue.
+	chainDepthWarn = 2
+	chainDepthFail = 3
+
+	maxQueryLength = 60
+)
+
+// remediation targets the root blocker: terminating the sessions in the
+// middle of a chain only lets the next one in line wait instead.
+const remediation = "Find out why the root blockers hold their locks (an open transaction, a long query, DDL); " +
+	"cancel or terminate them with pg_cancel_backend(pid) / pg_terminate_backend(pid), and set lock_timeout for DDL"
+
+type BlockingLocksQueries interface {
+	BlockingLocks(context.Context) ([]db.BlockingLocksRow, error)
+}
+
+type checker struct {
+	queries     BlockingLocksQueries
+	warnSeconds int64 // default: 30
+	failSeconds int64 // default: 300
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryPerformance,
+		CheckID:     "blocking-locks",
+		Name:        "Blocking Locks",
+		Description: "Detects sessions waiting on locks held by other sessions, and the chains they form",
+		Readme:      readme,
+		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
+	}
+}
+
+func New(queries BlockingLocksQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		warnSeconds: defaultWarnSeconds,
+		failSeconds: defaultFailSeconds,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["warn_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n >= 0 {
+					c.warnSeconds = n
+				}
+			}
+			if v, ok := myCfg["fail_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.failSeconds = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+// waiter is one blocked session and the sessions it waits on.
+type waiter struct {
+	row      db.BlockingLocksRow
+	blockers []int32
+	depth    int
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.BlockingLocks(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	waiters, order := groupWaiters(rows)
+	if len(waiters) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No sessions waiting on locks",
+		})
+		return report, nil
+	}
+
+	for _, pid := range order {
+		waiters[pid].depth = chainDepth(pid, waiters, map[int32]bool{})
+	}
+
+	severity := check.SeverityOK
+	roots := map[int32]bool{}
+	var tableRows []check.TableRow
+	var longest int64
+	maxDepth := 0
+	for _, pid := range order {
+		w := waiters[pid]
+		rowSeverity := c.severity(w)
+		if rowSeverity == check.SeverityOK {
+			continue
+		}
+		severity = max(severity, rowSeverity)
+		longest = max(longest, w.row.WaitSeconds)
+		maxDepth = max(maxDepth, w.depth)
+		for _, root := range rootBlockers(pid, waiters, map[int32]bool{}) {
+			roots[root] = true
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				strconv.Itoa(int(pid)),
+				check.FormatDurationSec(w.row.WaitSeconds),
+				formatLock(w.row),
+				formatPids(w.blockers),
+				strconv.Itoa(w.depth),
+				truncateQuery(w.row.BlockedQuery),
+				formatBlocker(w.row),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("%d session(s) waiting on locks for less than %s",
+				len(waiters), check.FormatDurationSec(c.warnSeconds)),
+		})
+		return report, nil
+	}
+
+	rootPids := make([]int32, 0, len(roots))
+	for pid := range roots {
+		rootPids = append(rootPids, pid)
+	}
+	slices.Sort(rootPids)
+
+	// Sessions waiting on each other in a cycle have no root: the deadlock
+	// detector cancels one of them after deadlock_timeout.
+	blockers := "in a lock cycle"
+	if len(rootPids) > 0 {
+		blockers = fmt.Sprintf("by %d root blocker(s) (pid %s)", len(rootPids), formatPids(rootPids))
+	}
+	details := fmt.Sprintf("%d session(s) blocked for up to %s %s, chains up to %d deep",
+		len(tableRows), check.FormatDurationSec(longest), blockers, maxDepth)
+
+	report.AddFinding(check.Finding{
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    severity,
+		Details:     details,
+		Remediation: remediation,
+		Metrics: []check.Metric{
+			{Name: "blocked_sessions", Value: float64(len(tableRows)), Unit: check.UnitCount},
+			{Name: "longest_lock_wait", Value: float64(longest), Unit: check.UnitSeconds},
+			{Name: "max_chain_depth", Value: float64(maxDepth), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Blocked PID", "Waiting", "Lock", "Blocked By", "Depth", "Query", "Blocker"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
+
+func (c *checker) severity(w *waiter) check.Severity {
+	switch {
+	case w.row.WaitSeconds >= c.failSeconds || w.depth >= chainDepthFail:
+		return check.SeverityFail
+	case w.row.WaitSeconds >= c.warnSeconds || w.depth >= chainDepthWarn:
+		return check.SeverityWarn
+	default:
+		return check.SeverityOK
+	}
+}
+
+// groupWaiters collapses the (blocked, blocker) pairs into one waiter per
+// blocked session, keeping the query's order (longest wait first).
+func groupWaiters(rows []db.BlockingLocksRow) (map[int32]*waiter, []int32) {
+	waiters := map[int32]*waiter{}
+	var order []int32
+	for _, row := range rows {
+		pid := row.BlockedPid.Int32
+		w, ok := waiters[pid]
+		if !ok {
+			w = &waiter{row: row}
+			waiters[pid] = w
+			order = append(order, pid)
+		}
+		w.blockers = append(w.blockers, row.BlockerPid.Int32)
+	}
+	return waiters, order
+}
+
+// chainDepth counts the sessions between pid and the end of its longest chain:
+// 1 when its blockers are not waiting themselves. Cycles (a deadlock the
+// detector has not broken yet) stop at the repeated session.
+func chainDepth(pid int32, waiters map[int32]*waiter, seen map[int32]bool) int {
+	w, ok := waiters[pid]
+	if !ok || seen[pid] {
+		return 0
+	}
+	seen[pid] = true
+	defer delete(seen, pid)
+
+	depth := 0
+	for _, blocker := range w.blockers {
+		depth = max(depth, chainDepth(blocker, waiters, seen))
+	}
+	return depth + 1
+}
+
+// rootBlockers returns the sessions at the end of pid's chains: blocking
+// others without waiting themselves.
+func rootBlockers(pid int32, waiters map[int32]*waiter, seen map[int32]bool) []int32 {
+	if seen[pid] {
+		return nil
+	}
+	seen[pid] = true
+	w, ok := waiters[pid]
+	if !ok {
+		return []int32{pid}
+	}
+	var roots []int32
+	for _, blocker := range w.blockers {
+		roots = append(roots, rootBlockers(blocker, waiters, seen)...)
+	}
+	return roots
+}
+
+func formatLock(row db.BlockingLocksRow) string {
+	if row.LockMode == "" {
+		return "-"
+	}
+	return row.LockMode + " on " + row.LockTarget
+}
+
+func formatPids(pids []int32) string {
+	parts := make([]string, len(pids))
+	for i, pid := range pids {
+		parts[i] = strconv.Itoa(int(pid))
+	}
+	return strings.Join(parts, ", ")
+}
+
+// formatBlocker shows the first blocker's state and query; sessions idle in
+// transaction show the last statement they ran, not one still running.
+func formatBlocker(row db.BlockingLocksRow) string {
+	if row.BlockerState == "" {
+		return "-"
+	}
+	return row.BlockerState + ": " + truncateQuery(row.BlockerQuery)
+}
+
+// truncateQuery collapses whitespace so multi-line statements fit in one cell.
+func truncateQuery(query string) string {
+	query = strings.Join(strings.Fields(query), " ")
+	if len(query) <= maxQueryLength {
+		return query
+	}
+	return strings.ToValidUTF8(query[:maxQueryLength-3], "") + "..."
+}
@@ -0,0 +1,210 @@
+package blockinglocks_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/blockinglocks"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockBlockingLocksQueryer struct {
+	rows []db.BlockingLocksRow
+	err  error
+}
+
+func (m *mockBlockingLocksQueryer) BlockingLocks(context.Context) ([]db.BlockingLocksRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func blocked(pid, blocker int32, seconds int64) db.BlockingLocksRow {
+	return db.BlockingLocksRow{
+		BlockedPid:   pgtype.Int4{Int32: pid, Valid: true},
+		BlockedUser:  "app",
+		BlockedQuery: "UPDATE orders SET status = 'shipped' WHERE id = 1",
+		WaitSeconds:  seconds,
+		LockMode:     "ShareLock",
+		LockTarget:   "transactionid",
+		BlockerPid:   pgtype.Int4{Int32: blocker, Valid: true},
+		BlockerUser:  "app",
+		BlockerState: "idle in transaction",
+		BlockerQuery: "UPDATE orders SET status = 'paid' WHERE id = 1",
+	}
+}
+
+func Test_BlockingLocks(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.BlockingLocksRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no blocked sessions - OK",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No sessions waiting on locks",
+		},
+		{
+			Name:             "brief lock wait - OK",
+			Rows:             []db.BlockingLocksRow{blocked(101, 100, 5)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "1 session(s) waiting on locks for less than 30s",
+		},
+		{
+			Name:             "wait past the warning threshold",
+			Rows:             []db.BlockingLocksRow{blocked(101, 100, 45)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 session(s) blocked for up to 45s by 1 root blocker(s) (pid 100), chains up to 1 deep",
+		},
+		{
+			Name:             "wait past the failure threshold",
+			Rows:             []db.BlockingLocksRow{blocked(101, 100, 600)},
+			ExpectedSeverity: check.SeverityFail,
+		},
+		{
+			Name: "two-level chain warns even when waits are short",
+			Rows: []db.BlockingLocksRow{
+				blocked(102, 101, 5),
+				blocked(101, 100, 5),
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 session(s) blocked for up to 5s by 1 root blocker(s) (pid 100), chains up to 2 deep",
+		},
+		{
+			Name: "three-level chain fails",
+			Rows: []db.BlockingLocksRow{
+				blocked(103, 102, 5),
+				blocked(102, 101, 5),
+				blocked(101, 100, 5),
+			},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "chains up to 3 deep",
+		},
+		{
+			Name: "session blocked by several root blockers",
+			Rows: []db.BlockingLocksRow{
+				blocked(101, 100, 60),
+				blocked(101, 99, 60),
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 session(s) blocked for up to 1m by 2 root blocker(s) (pid 99, 100)",
+		},
+		{
+			Name: "cycle does not loop",
+			Rows: []db.BlockingLocksRow{
+				blocked(101, 102, 5),
+				blocked(102, 101, 5),
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 session(s) blocked for up to 5s in a lock cycle, chains up to 2 deep",
+		},
+		{
+			Name:             "configured thresholds",
+			Rows:             []db.BlockingLocksRow{blocked(101, 100, 15)},
+			Config:           check.Config{"blocking-locks": {"warn_seconds": "5", "fail_seconds": "10"}},
+			ExpectedSeverity: check.SeverityFail,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := blockinglocks.New(&mockBlockingLocksQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_BlockingLocks_Table(t *testing.T) {
+	t.Parallel()
+
+	root := blocked(101, 100, 400)
+	root.LockMode = "AccessExclusiveLock"
+	root.LockTarget = "public.orders"
+	root.BlockedQuery = "ALTER TABLE orders\n  ADD COLUMN note text"
+	rows := []db.BlockingLocksRow{
+		root,
+		blocked(102, 101, 40),
+		blocked(103, 102, 10),
+	}
+
+	report, err := blockinglocks.New(&mockBlockingLocksQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityFail, finding.Severity)
+	require.Equal(t, []string{"Blocked PID", "Waiting", "Lock", "Blocked By", "Depth", "Query", "Blocker"}, finding.Table.Headers)
+	require.Len(t, finding.Table.Rows, 3)
+
+	require.Equal(t, []string{
+		"101", "6m", "AccessExclusiveLock on public.orders", "100", "1",
+		"ALTER TABLE orders ADD COLUMN note text",
+		"idle in transaction: UPDATE orders SET status = 'paid' WHERE id = 1",
+	}, finding.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityFail, finding.Table.Rows[0].Severity)
+	require.Equal(t, "2", finding.Table.Rows[1].Cells[4])
+	require.Equal(t, check.SeverityWarn, finding.Table.Rows[1].Severity)
+	require.Equal(t, "3", finding.Table.Rows[2].Cells[4])
+	require.Equal(t, check.SeverityFail, finding.Table.Rows[2].Severity)
+
+	require.Equal(t, 3.0, finding.Metrics[0].Value)
+	require.Equal(t, 400.0, finding.Metrics[1].Value)
+	require.Equal(t, 3.0, finding.Metrics[2].Value)
+}
+
+func Test_BlockingLocks_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BlockingLocks",
+		[]string{
+			"blocked_pid int4", "blocked_user text", "blocked_query text", "wait_seconds int8",
+			"lock_mode text", "lock_target text", "blocker_pid int4", "blocker_user text",
+			"blocker_state text", "blocker_query text",
+		},
+		[]any{4243, "app", "DELETE FROM orders WHERE id = 1", 120, "RowExclusiveLock", "public.orders", 4242, "admin", "active", "LOCK TABLE orders"},
+	)
+
+	report, err := blockinglocks.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "4242", report.Results[0].Table.Rows[0].Cells[3])
+}
+
+func Test_BlockingLocks_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := blockinglocks.New(&mockBlockingLocksQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "blocking-locks")
+}
+
+func Test_BlockingLocks_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := blockinglocks.Metadata()
+
+	require.Equal(t, "blocking-locks", metadata.CheckID)
+	require.Equal(t, check.CategoryPerformance, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,33 @@
+-- name: BlockingLocks :many
+-- One row per (blocked session, blocking session) pair from pg_blocking_pids(),
+-- with the lock each blocked session waits for. Chains are assembled in Go.
+WITH waited_locks AS (
+  SELECT DISTINCT ON (l.pid)
+    l.pid
+    , l.mode
+    , CASE
+      WHEN l.relation IS NOT NULL AND l.database = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+        THEN l.relation::regclass::text
+      ELSE l.locktype
+    END AS target
+  FROM pg_locks AS l
+  WHERE NOT l.granted
+  ORDER BY l.pid
+)
+
+SELECT
+  w.pid AS blocked_pid
+  , COALESCE(w.usename::text, '') AS blocked_user
+  , COALESCE(left(w.query, 200), '') AS blocked_query
+  , COALESCE(extract(EPOCH FROM (now() - w.query_start))::bigint, 0) AS wait_seconds
+  , COALESCE(wl.mode, '') AS lock_mode
+  , COALESCE(wl.target, '') AS lock_target
+  , bp.blocker_pid
+  , COALESCE(b.usename::text, '') AS blocker_user
+  , COALESCE(b.state, '') AS blocker_state
+  , COALESCE(left(b.query, 200), '') AS blocker_query
+FROM pg_stat_activity AS w
+CROSS JOIN LATERAL unnest(pg_blocking_pids(w.pid)) AS bp (blocker_pid)
+LEFT JOIN waited_locks AS wl ON w.pid = wl.pid
+LEFT JOIN pg_stat_activity AS b ON bp.blocker_pid = b.pid
+ORDER BY wait_seconds DESC, w.pid, bp.blocker_pid;
@@ -0,0 +1,216 @@
+// Package brinindexes implements checks for BRIN indexes on columns that do
+// not follow the physical row order, and for block ranges left unsummarized.
+package brinindexes
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"math"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// Below this absolute correlation, most block ranges span much of the
+	// column's values and a BRIN index excludes few of them.
+	minCorrelation = 0.8
+
+	// Ranges added since the last vacuum are not summarized, and every
+	// search reads them all; a few are cheap.
+	minUnsummarizedRanges = 10
+)
+
+const correlationRemediation = "Replace the BRIN index with a B-tree index on the column, " +
+	"or keep it only if the table is reloaded in the column's order (CLUSTER, or an INSERT ... ORDER BY) and stays that way"
+
+const unsummarizedRemediation = "Summarize the new ranges with brin_summarize_new_values(), " +
+	"and turn autosummarize on so ranges are summarized as they fill instead of waiting for vacuum"
+
+type BrinIndexesQueries interface {
+	BrinIndexes(context.Context) ([]db.BrinIndexesRow, error)
+}
+
+type checker struct {
+	queries BrinIndexesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryIndexes,
+		CheckID:      "brin-indexes",
+		Name:         "BRIN Indexes",
+		Description:  "Flags BRIN indexes on poorly correlated columns and with many unsummarized block ranges",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 13,
+	}
+}
+
+func New(queries BrinIndexesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.BrinIndexes(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.BrinIndexesRow) string { return r.SchemaName + "." + r.TableName })
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No BRIN indexes",
+		})
+		return report, nil
+	}
+
+	// A BRIN index on a small table is cheap whatever it does.
+	rows = check.FilterSmallTables(ctx, rows, func(r db.BrinIndexesRow) (int64, int64) {
+		return r.EstimatedRows, r.TableSizeBytes
+	})
+	checkCorrelation(ctx, rows, report)
+	checkUnsummarized(rows, report)
+
+	return report, nil
+}
+
+// checkCorrelation warns on BRIN indexes whose first column is poorly
+// correlated with the physical row order. Columns without statistics, and
+// expressions, are left out.
+func checkCorrelation(ctx context.Context, rows []db.BrinIndexesRow, report *check.Report) {
+	var poor []db.BrinIndexesRow
+	for _, row := range rows {
+		if row.Correlation.Valid && math.Abs(row.Correlation.Float64) < minCorrelation {
+			poor = append(poor, row)
+		}
+	}
+
+	if len(poor) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "brin-correlation",
+			Name:     "BRIN Column Correlation",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("All BRIN indexes are on columns correlated at %.1f or more with the row order", minCorrelation),
+		})
+		return
+	}
+
+	sort.SliceStable(poor, func(i, j int) bool {
+		return math.Abs(poor[i].Correlation.Float64) < math.Abs(poor[j].Correlation.Float64)
+	})
+	tableRows := make([]check.TableRow, 0, len(poor))
+	for _, row := range poor {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.IndexName,
+				row.TableName,
+				row.ColumnName,
+				strconv.FormatFloat(row.Correlation.Float64, 'f', 2, 64),
+				check.FormatSize(ctx, row.TableSizeBytes),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "brin-correlation",
+		Name:     "BRIN Column Correlation",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d BRIN index(es) on columns whose values are scattered across the table "+
+			"(correlation below %.1f): each block range covers most values, so searches still read most of the table",
+			len(poor), minCorrelation),
+		Remediation: correlationRemediation,
+		Table: &check.Table{
+			Headers: []string{"Index", "Table", "Column", "Correlation", "Table Size"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkUnsummarized warns on BRIN indexes without autosummarize whose table
+// has grown by many block ranges since it was last vacuumed. The number of
+// ranges is estimated from inserts since then and the average rows per page.
+func checkUnsummarized(rows []db.BrinIndexesRow, report *check.Report) {
+	var tableRows []check.TableRow
+	var fixes []string
+	for _, row := range rows {
+		if row.Autosummarize {
+			continue
+		}
+		ranges := unsummarizedRanges(row)
+		if ranges < minUnsummarizedRanges {
+			continue
+		}
+
+		index := pgx.Identifier{row.SchemaName, row.IndexName}.Sanitize()
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.IndexName,
+				row.TableName,
+				check.FormatNumber(row.NInsSinceVacuum),
+				"~" + check.FormatNumber(ranges),
+				strconv.Itoa(int(row.PagesPerRange)),
+			},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("SELECT brin_summarize_new_values('%s'::regclass);", strings.ReplaceAll(index, "'", "''")))
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "brin-unsummarized",
+			Name:     "Unsummarized BRIN Ranges",
+			Severity: check.SeverityOK,
+			Details:  "No BRIN index is missing summaries for many new block ranges",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "brin-unsummarized",
+		Name:     "Unsummarized BRIN Ranges",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d BRIN index(es) without autosummarize on tables that grew by %d or more block ranges since the last vacuum: "+
+			"unsummarized ranges match every search, so queries on recent rows read them all", len(tableRows), minUnsummarizedRanges),
+		Remediation: unsummarizedRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Index", "Table", "Inserts Since Vacuum", "New Ranges", "Pages per Range"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// unsummarizedRanges estimates the block ranges appended since the last
+// vacuum, which summarizes them.
+func unsummarizedRanges(row db.BrinIndexesRow) int64 {
+	if row.TablePages <= 0 || row.EstimatedRows <= 0 || row.PagesPerRange <= 0 {
+		return 0
+	}
+	rowsPerPage := float64(row.EstimatedRows) / float64(row.TablePages)
+	pages := float64(row.NInsSinceVacuum) / rowsPerPage
+	return int64(pages / float64(row.PagesPerRange))
+}
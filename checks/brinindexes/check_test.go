@@ -0,0 +1,184 @@
+package brinindexes_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/brinindexes"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockBrinIndexesQueryer struct {
+	indexes []db.BrinIndexesRow
+	err     error
+}
+
+func (m *mockBrinIndexesQueryer) BrinIndexes(context.Context) ([]db.BrinIndexesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.indexes, nil
+}
+
+// makeIndex returns a BRIN index on a 10M row table with 100 rows per page.
+func makeIndex(name string, correlation pgtype.Float8, autosummarize bool, insertsSinceVacuum int64) db.BrinIndexesRow {
+	return db.BrinIndexesRow{
+		SchemaName:      "public",
+		TableName:       "events",
+		IndexName:       name,
+		ColumnName:      "created_at",
+		Correlation:     correlation,
+		PagesPerRange:   128,
+		Autosummarize:   autosummarize,
+		IndexSizeBytes:  48 * check.KiB,
+		TableSizeBytes:  100_000 * 8 * check.KiB,
+		EstimatedRows:   10_000_000,
+		TablePages:      100_000,
+		NInsSinceVacuum: insertsSinceVacuum,
+	}
+}
+
+func corr(v float64) pgtype.Float8 {
+	return pgtype.Float8{Float64: v, Valid: true}
+}
+
+func Test_BrinIndexes(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		indexes  []db.BrinIndexesRow
+		expected map[string]check.Severity
+	}{
+		{
+			name:     "no BRIN indexes",
+			expected: map[string]check.Severity{"brin-indexes": check.SeverityOK},
+		},
+		{
+			name:    "well correlated and summarized",
+			indexes: []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(0.99), false, 0)},
+			expected: map[string]check.Severity{
+				"brin-correlation":  check.SeverityOK,
+				"brin-unsummarized": check.SeverityOK,
+			},
+		},
+		{
+			name:     "descending order is correlated too",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(-0.97), false, 0)},
+			expected: map[string]check.Severity{"brin-correlation": check.SeverityOK},
+		},
+		{
+			name:     "poorly correlated column",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_user_id_idx", corr(0.12), false, 0)},
+			expected: map[string]check.Severity{"brin-correlation": check.SeverityWarn},
+		},
+		{
+			name:     "column without statistics",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_created_at_idx", pgtype.Float8{}, false, 0)},
+			expected: map[string]check.Severity{"brin-correlation": check.SeverityOK},
+		},
+		{
+			// 200K rows at 100 rows per page fill 2000 pages, about 15 ranges.
+			name:     "many new ranges since vacuum",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(0.99), false, 200_000)},
+			expected: map[string]check.Severity{"brin-unsummarized": check.SeverityWarn},
+		},
+		{
+			name:     "few new ranges since vacuum",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(0.99), false, 50_000)},
+			expected: map[string]check.Severity{"brin-unsummarized": check.SeverityOK},
+		},
+		{
+			name:     "autosummarize on",
+			indexes:  []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(0.99), true, 200_000)},
+			expected: map[string]check.Severity{"brin-unsummarized": check.SeverityOK},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := brinindexes.New(&mockBrinIndexesQueryer{indexes: tt.indexes}).Check(context.Background())
+			require.NoError(t, err)
+
+			for id, severity := range tt.expected {
+				require.Equal(t, severity, checktest.Finding(t, report, id).Severity, id)
+			}
+		})
+	}
+}
+
+func Test_BrinIndexes_SmallTablesIgnored(t *testing.T) {
+	t.Parallel()
+
+	index := makeIndex("events_user_id_idx", corr(0.12), false, 0)
+	index.EstimatedRows, index.TableSizeBytes = 500, 64*check.KiB
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000})
+	report, err := brinindexes.New(&mockBrinIndexesQueryer{indexes: []db.BrinIndexesRow{index}}).Check(ctx)
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "brin-correlation").Severity)
+}
+
+func Test_BrinIndexes_UnsummarizedFix(t *testing.T) {
+	t.Parallel()
+
+	indexes := []db.BrinIndexesRow{makeIndex("events_created_at_idx", corr(0.99), false, 200_000)}
+	report, err := brinindexes.New(&mockBrinIndexesQueryer{indexes: indexes}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "brin-unsummarized")
+	require.Equal(t, `SELECT brin_summarize_new_values('"public"."events_created_at_idx"'::regclass);`, finding.FixSQL)
+	require.Equal(t, []string{"public.events_created_at_idx", "events", "200.0K", "~15", "128"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_BrinIndexes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BrinIndexes",
+		[]string{
+			"schema_name text", "table_name text", "index_name text", "column_name text", "correlation float8",
+			"pages_per_range int4", "autosummarize bool", "index_size_bytes int8", "table_size_bytes int8",
+			"estimated_rows int8", "table_pages int8", "n_ins_since_vacuum int8",
+		},
+		[]any{"public", "orders", "orders_customer_id_brin", "customer_id", 0.03, 128, false, 96 * check.KiB, 4 * check.GiB, 40_000_000, 524_288, 0},
+	)
+
+	report, err := brinindexes.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "brin-correlation")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"public.orders_customer_id_brin", "orders", "customer_id", "0.03", "4.0GiB"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_BrinIndexes_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := brinindexes.New(&mockBrinIndexesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "brin-indexes")
+}
+
+func Test_BrinIndexes_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := brinindexes.Metadata()
+
+	require.Equal(t, "brin-indexes", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.Equal(t, 13, metadata.MinPGVersion)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,47 @@
+-- name: BrinIndexes :many
+-- BRIN indexes with the planner's correlation between the first indexed
+-- column and the physical row order, their pages_per_range and autosummarize
+-- storage parameters, and what is needed to estimate how many block ranges
+-- were added since the table was last vacuumed: inserts since then, and rows
+-- per page. Expression columns have no correlation.
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , COALESCE(a.attname::text, '(expression)') AS column_name
+  , st.correlation::float8 AS correlation
+  , COALESCE(
+    (
+      SELECT o.option_value::int
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'pages_per_range'
+    )
+    , 128
+  ) AS pages_per_range
+  , COALESCE(
+    (
+      SELECT o.option_value IN ('on', 'true', 'yes', '1')
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'autosummarize'
+    )
+    , false
+  ) AS autosummarize
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , pg_table_size(t.oid) AS table_size_bytes
+  , GREATEST(t.reltuples, 0)::bigint AS estimated_rows
+  , t.relpages::bigint AS table_pages
+  , COALESCE(s.n_ins_since_vacuum, 0)::bigint AS n_ins_since_vacuum
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+LEFT JOIN pg_attribute AS a ON t.oid = a.attrelid AND i.indkey[0] = a.attnum AND i.indkey[0] <> 0
+LEFT JOIN pg_stats AS st
+  ON n.nspname = st.schemaname AND t.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN pg_stat_user_tables AS s ON t.oid = s.relid
+WHERE
+  am.amname = 'brin'
+  AND c.relkind = 'i'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY table_size_bytes DESC, n.nspname, c.relname;
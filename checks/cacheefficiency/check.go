@@ -5,6 +5,7 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"strconv"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
@@ -17,15 +18,32 @@ var querySQL string
 var readme string
 
 const (
-	cacheWarnThreshold = 90.0
+	defaultWarnPercent = 90.0
+	defaultFailPercent = 0.0 // The ratio is an advisory: no FAIL unless configured
+
+	// Databases smaller than this are cached by the OS page cache even when
+	// shared_buffers misses, so their ratio says little.
+	defaultMinDatabaseSizeMB = 1024
+
+	// maxTables caps table-cache-hit-ratio to the tables reading the most blocks.
+	maxTables = 20
 )
 
+// tableRemediation starts from the queries: a table missing from cache is
+// usually scanned, not merely large.
+const tableRemediation = "Look for sequential scans and missing indexes on these tables (see table-seq-scans), " +
+	"and consider a larger shared_buffers if their hot data should fit in memory"
+
 type CacheEfficiencyQueries interface {
 	DatabaseCacheEfficiency(context.Context) (db.DatabaseCacheEfficiencyRow, error)
+	TableCacheEfficiency(context.Context) ([]db.TableCacheEfficiencyRow, error)
 }
 
 type checker struct {
-	queries CacheEfficiencyQueries
+	queries         CacheEfficiencyQueries
+	warnPercent     float64 // default: 90
+	failPercent     float64 // default: 0 (never fails)
+	minDatabaseSize int64   // default: 1GiB
 }
 
 func Metadata() check.Metadata {
@@ -33,16 +51,42 @@ func Metadata() check.Metadata {
 		Category:    check.CategoryPerformance,
 		CheckID:     "cache-efficiency",
 		Name:        "Cache Efficiency",
-		Description: "Analyzes database-wide buffer cache hit ratio",
+		Description: "Analyzes buffer cache hit ratio for the database and its most-read tables",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
-func New(queries CacheEfficiencyQueries, _ ...check.Config) check.Checker {
-	return &checker{
-		queries: queries,
+func New(queries CacheEfficiencyQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:         queries,
+		warnPercent:     defaultWarnPercent,
+		failPercent:     defaultFailPercent,
+		minDatabaseSize: defaultMinDatabaseSizeMB * check.MiB,
 	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["warn_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 && f <= 100 {
+					c.warnPercent = f
+				}
+			}
+			if v, ok := myCfg["fail_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f >= 0 && f <= 100 {
+					c.failPercent = f
+				}
+			}
+			if v, ok := myCfg["min_database_size_mb"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n >= 0 {
+					c.minDatabaseSize = n * check.MiB
+				}
+			}
+		}
+	}
+
+	return c
 }
 
 func (c *checker) Metadata() check.Metadata {
@@ -57,12 +101,26 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
 	}
 
-	checkCacheHitRatio(row, report)
+	c.checkCacheHitRatio(ctx, row, report)
+
+	tables, err := c.queries.TableCacheEfficiency(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	tables = check.FilterTables(ctx, tables, func(r db.TableCacheEfficiencyRow) string { return r.SchemaName + "." + r.TableName })
+	tables = check.FilterSmallTables(ctx, tables, func(r db.TableCacheEfficiencyRow) (int64, int64) {
+		return r.EstimatedRows, r.TotalSizeBytes.Int64
+	})
+	if len(tables) > maxTables {
+		tables = tables[:maxTables]
+	}
+
+	c.checkTableCacheHitRatio(ctx, tables, report)
 
 	return report, nil
 }
 
-func checkCacheHitRatio(row db.DatabaseCacheEfficiencyRow, report *check.Report) {
+func (c *checker) checkCacheHitRatio(ctx context.Context, row db.DatabaseCacheEfficiencyRow, report *check.Report) {
 	if !row.CacheHitRatio.Valid {
 		report.AddFinding(check.Finding{
 			ID:       "cache-hit-ratio",
@@ -76,7 +134,7 @@ func checkCacheHitRatio(row db.DatabaseCacheEfficiencyRow, report *check.Report)
 	ratio, _ := row.CacheHitRatio.Float64Value()
 	cacheRatio := ratio.Float64
 
-	if cacheRatio >= cacheWarnThreshold {
+	if cacheRatio >= c.warnPercent {
 		report.AddFinding(check.Finding{
 			ID:       "cache-hit-ratio",
 			Name:     "Cache Hit Ratio",
@@ -86,13 +144,96 @@ func checkCacheHitRatio(row db.DatabaseCacheEfficiencyRow, report *check.Report)
 		return
 	}
 
-	details := fmt.Sprintf("Cache hit ratio: %.2f%% (below threshold)\nBlocks hit: %d\nBlocks read from disk: %d",
-		cacheRatio, row.BlksHit.Int64, row.BlksRead.Int64)
+	if row.DatabaseSizeBytes.Valid && row.DatabaseSizeBytes.Int64 < c.minDatabaseSize {
+		report.AddFinding(check.Finding{
+			ID:       "cache-hit-ratio",
+			Name:     "Cache Hit Ratio",
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("Cache hit ratio: %.2f%% (ignored: database is only %s)",
+				cacheRatio, check.FormatSize(ctx, row.DatabaseSizeBytes.Int64)),
+		})
+		return
+	}
+
+	severity := check.SeverityWarn
+	if cacheRatio < c.failPercent {
+		severity = check.SeverityFail
+	}
+
+	details := fmt.Sprintf("Cache hit ratio: %.2f%% (below %.0f%%)\nBlocks hit: %d\nBlocks read from disk: %d",
+		cacheRatio, c.warnPercent, row.BlksHit.Int64, row.BlksRead.Int64)
+	if row.SharedBuffersBytes.Valid && row.DatabaseSizeBytes.Valid {
+		details += fmt.Sprintf("\nshared_buffers: %s for a %s database",
+			check.FormatSize(ctx, row.SharedBuffersBytes.Int64), check.FormatSize(ctx, row.DatabaseSizeBytes.Int64))
+	}
 
 	report.AddFinding(check.Finding{
 		ID:       "cache-hit-ratio",
 		Name:     "Cache Hit Ratio",
-		Severity: check.SeverityWarn,
+		Severity: severity,
 		Details:  details,
+		Metrics: []check.Metric{
+			{Name: "cache_hit_ratio", Value: cacheRatio, Unit: check.UnitPercent},
+		},
 	})
 }
+
+// checkTableCacheHitRatio lists the most-read tables below the warning
+// threshold. A single cold table is not an emergency, so tables only warn.
+func (c *checker) checkTableCacheHitRatio(ctx context.Context, tables []db.TableCacheEfficiencyRow, report *check.Report) {
+	var tableRows []check.TableRow
+	for _, t := range tables {
+		hit := t.HeapBlksHit + t.IdxBlksHit
+		read := t.HeapBlksRead + t.IdxBlksRead
+		if hitRatio(hit, read) >= c.warnPercent {
+			continue
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				t.SchemaName + "." + t.TableName,
+				formatRatio(t.HeapBlksHit, t.HeapBlksRead),
+				formatRatio(t.IdxBlksHit, t.IdxBlksRead),
+				check.FormatNumber(read),
+				check.FormatSize(ctx, t.TotalSizeBytes.Int64),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "table-cache-hit-ratio",
+			Name:     "Table Cache Hit Ratio",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No frequently read tables below %.0f%% cache hit ratio", c.warnPercent),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "table-cache-hit-ratio",
+		Name:        "Table Cache Hit Ratio",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("%d frequently read table(s) below %.0f%% cache hit ratio", len(tableRows), c.warnPercent),
+		Remediation: tableRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Heap Hit", "Index Hit", "Blocks Read", "Size"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+func hitRatio(hit, read int64) float64 {
+	if hit+read == 0 {
+		return 100
+	}
+	return 100 * float64(hit) / float64(hit+read)
+}
+
+func formatRatio(hit, read int64) string {
+	if hit+read == 0 {
+		return "-"
+	}
+	return fmt.Sprintf("%.1f%%", hitRatio(hit, read))
+}
@@ -8,13 +8,16 @@ import (
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/checks/cacheefficiency"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/stretchr/testify/require"
 )
 
 type mockCacheEfficiencyQueryer struct {
-	row db.DatabaseCacheEfficiencyRow
-	err error
+	row    db.DatabaseCacheEfficiencyRow
+	tables []db.TableCacheEfficiencyRow
+	err    error
 }
 
 func (m *mockCacheEfficiencyQueryer) DatabaseCacheEfficiency(context.Context) (db.DatabaseCacheEfficiencyRow, error) {
@@ -24,6 +27,13 @@ func (m *mockCacheEfficiencyQueryer) DatabaseCacheEfficiency(context.Context) (d
 	return m.row, nil
 }
 
+func (m *mockCacheEfficiencyQueryer) TableCacheEfficiency(context.Context) ([]db.TableCacheEfficiencyRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.tables, nil
+}
+
 func newMockQueryer(row db.DatabaseCacheEfficiencyRow) *mockCacheEfficiencyQueryer {
 	return &mockCacheEfficiencyQueryer{row: row}
 }
@@ -102,7 +112,7 @@ func Test_CacheEfficiency(t *testing.T) {
 			require.NoError(t, err)
 
 			results := report.Results
-			require.Equal(t, 1, len(results), "Should have exactly 1 result")
+			require.Equal(t, 2, len(results), "Should have database and table results")
 
 			result := results[0]
 			require.Equal(t, tc.ExpectedID, result.ID, "Result ID should match")
@@ -128,7 +138,7 @@ func Test_CacheEfficiency_DetailsContent(t *testing.T) {
 	require.NoError(t, err)
 
 	results := report.Results
-	require.Equal(t, 1, len(results), "Should have exactly 1 result")
+	require.Equal(t, 2, len(results), "Should have database and table results")
 
 	result := results[0]
 	require.Equal(t, check.SeverityWarn, result.Severity)
@@ -154,7 +164,7 @@ func Test_CacheEfficiency_OKResult(t *testing.T) {
 	require.NoError(t, err)
 
 	results := report.Results
-	require.Equal(t, 1, len(results), "Should have exactly 1 result")
+	require.Equal(t, 2, len(results), "Should have database and table results")
 
 	result := results[0]
 	require.Equal(t, check.SeverityOK, result.Severity, "Should be OK when cache ratio is healthy")
@@ -238,7 +248,7 @@ func Test_CacheEfficiency_ThresholdBoundaries(t *testing.T) {
 			require.NoError(t, err)
 
 			results := report.Results
-			require.Equal(t, 1, len(results))
+			require.Equal(t, 2, len(results))
 
 			result := results[0]
 			require.Equal(t, tc.ExpectedSeverity, result.Severity, "Severity should match expected")
@@ -262,9 +272,174 @@ func Test_CacheEfficiency_NoActivityHandling(t *testing.T) {
 	require.NoError(t, err)
 
 	results := report.Results
-	require.Equal(t, 1, len(results))
+	require.Equal(t, 2, len(results))
 
 	result := results[0]
 	require.Equal(t, check.SeverityOK, result.Severity, "Should be OK when no cache activity")
 	require.Contains(t, result.Details, "Insufficient cache activity", "Details should explain no activity")
 }
+
+func Test_CacheEfficiency_DatabaseSize(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Ratio            float64
+		SizeBytes        int64
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "small database ignored - OK",
+			Ratio:            70.0,
+			SizeBytes:        200 * check.MiB,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Cache hit ratio: 70.00% (ignored: database is only 200.0MiB)",
+		},
+		{
+			Name:             "large database with low ratio - WARN",
+			Ratio:            70.0,
+			SizeBytes:        50 * check.GiB,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "shared_buffers: 128.0MiB for a 50.0GiB database",
+		},
+		{
+			Name:             "configured size floor",
+			Ratio:            85.0,
+			SizeBytes:        200 * check.MiB,
+			Config:           check.Config{"cache-efficiency": {"min_database_size_mb": "100"}},
+			ExpectedSeverity: check.SeverityWarn,
+		},
+		{
+			Name:             "configured thresholds",
+			Ratio:            97.0,
+			SizeBytes:        50 * check.GiB,
+			Config:           check.Config{"cache-efficiency": {"warn_percent": "99", "fail_percent": "98"}},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "Cache hit ratio: 97.00% (below 99%)",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			row := db.DatabaseCacheEfficiencyRow{
+				CacheHitRatio:      makeNumeric(tc.Ratio),
+				BlksHit:            pgtype.Int8{Int64: 700000, Valid: true},
+				BlksRead:           pgtype.Int8{Int64: 300000, Valid: true},
+				DatabaseSizeBytes:  pgtype.Int8{Int64: tc.SizeBytes, Valid: true},
+				SharedBuffersBytes: pgtype.Int8{Int64: 128 * check.MiB, Valid: true},
+			}
+
+			report, err := cacheefficiency.New(newMockQueryer(row), tc.Config).Check(context.Background())
+			require.NoError(t, err)
+
+			result := report.Results[0]
+			require.Equal(t, "cache-hit-ratio", result.ID)
+			require.Equal(t, tc.ExpectedSeverity, result.Severity)
+			require.Contains(t, result.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_CacheEfficiency_Tables(t *testing.T) {
+	t.Parallel()
+
+	queryer := newMockQueryer(db.DatabaseCacheEfficiencyRow{CacheHitRatio: makeNumeric(99.0)})
+	queryer.tables = []db.TableCacheEfficiencyRow{
+		{
+			SchemaName: "public", TableName: "events",
+			HeapBlksHit: 600000, HeapBlksRead: 400000, IdxBlksHit: 95000, IdxBlksRead: 5000,
+			EstimatedRows: 10000000, TotalSizeBytes: pgtype.Int8{Int64: 20 * check.GiB, Valid: true},
+		},
+		{
+			SchemaName: "public", TableName: "orders",
+			HeapBlksHit: 990000, HeapBlksRead: 10000,
+			EstimatedRows: 1000000, TotalSizeBytes: pgtype.Int8{Int64: 2 * check.GiB, Valid: true},
+		},
+	}
+
+	report, err := cacheefficiency.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+	require.Len(t, report.Results, 2)
+
+	result := report.Results[1]
+	require.Equal(t, "table-cache-hit-ratio", result.ID)
+	require.Equal(t, check.SeverityWarn, result.Severity)
+	require.Equal(t, "1 frequently read table(s) below 90% cache hit ratio", result.Details)
+	require.Equal(t, []string{"Table", "Heap Hit", "Index Hit", "Blocks Read", "Size"}, result.Table.Headers)
+	require.Equal(t, []string{"public.events", "60.0%", "95.0%", "405.0K", "20.0GiB"}, result.Table.Rows[0].Cells)
+	require.NotEmpty(t, result.Remediation)
+}
+
+func Test_CacheEfficiency_ScopeAppliedBeforeCap(t *testing.T) {
+	t.Parallel()
+
+	// Busier out-of-scope tables come first, as the query orders by blocks read.
+	queryer := newMockQueryer(db.DatabaseCacheEfficiencyRow{CacheHitRatio: makeNumeric(99.0)})
+	for i := range 25 {
+		queryer.tables = append(queryer.tables, db.TableCacheEfficiencyRow{
+			SchemaName: "archive", TableName: fmt.Sprintf("events_%d", i),
+			HeapBlksHit: 500000, HeapBlksRead: 500000,
+			EstimatedRows: 10000000, TotalSizeBytes: pgtype.Int8{Int64: 20 * check.GiB, Valid: true},
+		})
+	}
+	queryer.tables = append(queryer.tables, db.TableCacheEfficiencyRow{
+		SchemaName: "public", TableName: "orders",
+		HeapBlksHit: 600000, HeapBlksRead: 400000,
+		EstimatedRows: 1000000, TotalSizeBytes: pgtype.Int8{Int64: 2 * check.GiB, Valid: true},
+	})
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{ExcludeSchemas: []string{"archive"}})
+	report, err := cacheefficiency.New(queryer).Check(ctx)
+	require.NoError(t, err)
+
+	result := checktest.Finding(t, report, "table-cache-hit-ratio")
+	require.Equal(t, check.SeverityWarn, result.Severity)
+	require.Len(t, result.Table.Rows, 1)
+	require.Equal(t, "public.orders", result.Table.Rows[0].Cells[0])
+}
+
+func Test_CacheEfficiency_TablesCapped(t *testing.T) {
+	t.Parallel()
+
+	queryer := newMockQueryer(db.DatabaseCacheEfficiencyRow{CacheHitRatio: makeNumeric(99.0)})
+	for i := range 25 {
+		queryer.tables = append(queryer.tables, db.TableCacheEfficiencyRow{
+			SchemaName: "public", TableName: fmt.Sprintf("events_%d", i),
+			HeapBlksHit: 500000, HeapBlksRead: 500000,
+			EstimatedRows: 10000000, TotalSizeBytes: pgtype.Int8{Int64: 20 * check.GiB, Valid: true},
+		})
+	}
+
+	report, err := cacheefficiency.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+	require.Len(t, checktest.Finding(t, report, "table-cache-hit-ratio").Table.Rows, 20)
+}
+
+func Test_CacheEfficiency_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("DatabaseCacheEfficiency",
+			[]string{
+				"blks_hit int8", "blks_read int8", "stats_reset timestamptz", "cache_hit_ratio numeric",
+				"stats_age_days numeric", "database_size_bytes int8", "shared_buffers_bytes int8",
+			},
+			[]any{850000, 150000, nil, "85.00", "999", 10 * check.GiB, 128 * check.MiB},
+		).
+		Add("TableCacheEfficiency",
+			[]string{
+				"schema_name text", "table_name text", "heap_blks_hit int8", "heap_blks_read int8",
+				"idx_blks_hit int8", "idx_blks_read int8", "estimated_rows int8", "total_size_bytes int8",
+			},
+		)
+
+	report, err := cacheefficiency.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Contains(t, report.Results[0].Details, "shared_buffers: 128.0MiB for a 10.0GiB database")
+	require.Equal(t, check.SeverityOK, report.Results[1].Severity)
+}
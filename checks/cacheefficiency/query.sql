@@ -13,5 +13,42 @@ SELECT
     extract(EPOCH FROM (now() - stats_reset)) / 86400
     , 999
   ) AS stats_age_days
+  , pg_database_size(current_database()) AS database_size_bytes
+  , (
+    SELECT s.setting::bigint * current_setting('block_size')::bigint
+    FROM pg_settings AS s
+    WHERE s.name = 'shared_buffers'
+  ) AS shared_buffers_bytes
 FROM pg_stat_database
 WHERE datname = current_database();
+
+-- name: TableCacheEfficiency :many
+-- Returns the tables reading the most blocks from outside shared_buffers,
+-- with heap (including TOAST) and index blocks counted separately.
+-- Tables with fewer than 10,000 blocks read (80MB) are too cold to matter.
+-- Not limited: the check applies the table scope and size floors before capping the list.
+WITH table_io AS (
+  SELECT
+    s.relid
+    , s.schemaname::text AS schema_name
+    , s.relname::text AS table_name
+    , COALESCE(s.heap_blks_hit, 0) + COALESCE(s.toast_blks_hit, 0) AS heap_blks_hit
+    , COALESCE(s.heap_blks_read, 0) + COALESCE(s.toast_blks_read, 0) AS heap_blks_read
+    , COALESCE(s.idx_blks_hit, 0) + COALESCE(s.tidx_blks_hit, 0) AS idx_blks_hit
+    , COALESCE(s.idx_blks_read, 0) + COALESCE(s.tidx_blks_read, 0) AS idx_blks_read
+  FROM pg_statio_user_tables AS s
+)
+
+SELECT
+  t.schema_name
+  , t.table_name
+  , t.heap_blks_hit::bigint AS heap_blks_hit
+  , t.heap_blks_read::bigint AS heap_blks_read
+  , t.idx_blks_hit::bigint AS idx_blks_hit
+  , t.idx_blks_read::bigint AS idx_blks_read
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(t.relid) AS total_size_bytes
+FROM table_io AS t
+INNER JOIN pg_class AS c ON t.relid = c.oid
+WHERE t.heap_blks_read + t.idx_blks_read >= 10000
+ORDER BY t.heap_blks_read + t.idx_blks_read DESC;
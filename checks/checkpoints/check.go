@@ -0,0 +1,220 @@
+// Package checkpoints implements checks for checkpoint frequency and duration.
+package checkpoints
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"math"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// Checkpoint statistics live in pg_stat_checkpointer from PostgreSQL 17.
+	checkpointerVersion = 170000
+
+	// Fewer checkpoints than this since the reset are too few to judge.
+	minCheckpoints = 10
+
+	requestedWarnPercent = 50.0
+
+	// Average fsync time per checkpoint beyond which storage is slow to flush.
+	syncWarnSeconds = 10.0
+
+	// checkpoint_timeout worth suggesting when it is shorter: each page is
+	// written once per checkpoint however often it changes in between.
+	suggestedTimeoutSeconds = 900
+
+	// Bounds on how much max_wal_size is suggested to grow in one step.
+	minWalSizeGrowth = 2
+	maxWalSizeGrowth = 8
+)
+
+type CheckpointsQueries interface {
+	CheckpointStats(context.Context) (db.CheckpointStatsRow, error)
+	CheckpointStatsLegacy(context.Context) (db.CheckpointStatsLegacyRow, error)
+}
+
+type checker struct {
+	queries CheckpointsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "checkpoints",
+		Name:        "Checkpoints",
+		Description: "Compares requested and timed checkpoints and checkpoint sync times",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries CheckpointsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	stats, err := c.stats(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	total := stats.CheckpointsTimed + stats.CheckpointsRequested
+	if total < minCheckpoints || stats.SecondsSinceReset <= 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Only %d checkpoints since statistics were reset, too few to judge", total),
+		})
+		return report, nil
+	}
+
+	checkRequestedCheckpoints(stats, check.ProviderFromContext(ctx), report)
+	checkSyncTime(stats, report)
+
+	return report, nil
+}
+
+// stats reads pg_stat_checkpointer, or pg_stat_bgwriter before PostgreSQL 17.
+func (c *checker) stats(ctx context.Context) (db.CheckpointStatsRow, error) {
+	if version := check.ServerVersionFromContext(ctx); version == 0 || version >= checkpointerVersion {
+		return c.queries.CheckpointStats(ctx)
+	}
+	legacy, err := c.queries.CheckpointStatsLegacy(ctx)
+	return db.CheckpointStatsRow(legacy), err
+}
+
+// checkRequestedCheckpoints warns when WAL volume, not checkpoint_timeout,
+// triggers most checkpoints: max_wal_size is too small for the write load.
+func checkRequestedCheckpoints(stats db.CheckpointStatsRow, provider check.Provider, report *check.Report) {
+	total := stats.CheckpointsTimed + stats.CheckpointsRequested
+	requestedPercent := float64(stats.CheckpointsRequested) / float64(total) * 100
+	metrics := []check.Metric{
+		{Name: "requested_checkpoints", Value: requestedPercent, Unit: check.UnitPercent},
+	}
+
+	// PostgreSQL logs checkpoint_warning when checkpoints forced by WAL volume
+	// come closer together than the setting; the average interval estimates it.
+	var interval int64
+	if stats.CheckpointsRequested > 0 {
+		interval = stats.SecondsSinceReset / stats.CheckpointsRequested
+	}
+	warningFires := interval > 0 && interval < stats.CheckpointWarningSeconds
+
+	if requestedPercent < requestedWarnPercent && !warningFires {
+		report.AddFinding(check.Finding{
+			ID:       "requested-checkpoints",
+			Name:     "Requested Checkpoints",
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("%.0f%% of %d checkpoints were requested (max_wal_size %s)",
+				requestedPercent, total, formatMB(stats.MaxWalSizeMb)),
+			Metrics: metrics,
+		})
+		return
+	}
+
+	details := fmt.Sprintf("%.0f%% of %d checkpoints were requested rather than timed, one every %s on average (max_wal_size %s)",
+		requestedPercent, total, check.FormatDurationSec(interval), formatMB(stats.MaxWalSizeMb))
+	if warningFires {
+		details += fmt.Sprintf("; checkpoints this frequent trigger the checkpoint_warning (%s) log message",
+			check.FormatDurationSec(stats.CheckpointWarningSeconds))
+	}
+
+	suggested := suggestMaxWalSize(stats.MaxWalSizeMb, stats.CheckpointTimeoutSeconds, interval)
+	remediation, fixSQL := provider.SettingFix("max_wal_size", formatMB(suggested))
+	if stats.CheckpointTimeoutSeconds < suggestedTimeoutSeconds {
+		remediation += fmt.Sprintf(". Consider raising checkpoint_timeout from %s to %s as well",
+			check.FormatDurationSec(stats.CheckpointTimeoutSeconds), check.FormatDurationSec(suggestedTimeoutSeconds))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "requested-checkpoints",
+		Name:        "Requested Checkpoints",
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "max_wal_size"},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+		Metrics:     metrics,
+		Comparison: &check.Comparison{
+			Setting:   "max_wal_size",
+			Actual:    formatMB(stats.MaxWalSizeMb),
+			Expected:  formatMB(suggested),
+			Threshold: fmt.Sprintf(">= %.0f%% requested", requestedWarnPercent),
+		},
+	})
+}
+
+// checkSyncTime warns when checkpoints wait long on fsync. Write time is
+// spread over checkpoint_completion_target by design; sync time is not.
+func checkSyncTime(stats db.CheckpointStatsRow, report *check.Report) {
+	total := stats.CheckpointsTimed + stats.CheckpointsRequested
+	avgWrite := stats.WriteTimeMs / float64(total) / 1000
+	avgSync := stats.SyncTimeMs / float64(total) / 1000
+	metrics := []check.Metric{
+		{Name: "checkpoint_avg_sync_time", Value: avgSync, Unit: check.UnitSeconds},
+	}
+
+	if avgSync < syncWarnSeconds {
+		report.AddFinding(check.Finding{
+			ID:       "checkpoint-sync-time",
+			Name:     "Checkpoint Sync Time",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Checkpoints take %.0fs to write and %.1fs to sync on average", avgWrite, avgSync),
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "checkpoint-sync-time",
+		Name:     "Checkpoint Sync Time",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("Checkpoints spend %.1fs on average waiting for fsync (%s buffers written in total): storage is slow to flush",
+			avgSync, check.FormatNumber(stats.BuffersWritten)),
+		Remediation: "Check the storage's write latency and throughput limits during checkpoints; " +
+			"provisioned IOPS or a faster volume shorten the sync phase",
+		Metrics: metrics,
+	})
+}
+
+// suggestMaxWalSize scales max_wal_size so that WAL-triggered checkpoints come
+// no more often than checkpoint_timeout, within [2x, 8x] the current size.
+func suggestMaxWalSize(currentMB, timeoutSeconds, intervalSeconds int64) int64 {
+	factor := int64(minWalSizeGrowth)
+	if intervalSeconds > 0 {
+		needed := int64(math.Ceil(float64(timeoutSeconds) / float64(intervalSeconds)))
+		factor = min(max(factor, needed), maxWalSizeGrowth)
+	}
+	suggested := currentMB * factor
+	if suggested >= 1024 {
+		suggested = (suggested + 1023) / 1024 * 1024
+	}
+	return suggested
+}
+
+// formatMB renders a size in MB the way postgresql.conf spells it.
+func formatMB(mb int64) string {
+	if mb >= 1024 && mb%1024 == 0 {
+		return fmt.Sprintf("%dGB", mb/1024)
+	}
+	return fmt.Sprintf("%dMB", mb)
+}
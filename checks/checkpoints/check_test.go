@@ -0,0 +1,209 @@
+package checkpoints_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/checkpoints"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+const day = 86400
+
+type mockCheckpointsQueryer struct {
+	version int32
+	stats   db.CheckpointStatsRow
+	err     error
+}
+
+func (m *mockCheckpointsQueryer) CheckpointStats(context.Context) (db.CheckpointStatsRow, error) {
+	if m.err != nil {
+		return db.CheckpointStatsRow{}, m.err
+	}
+	if m.version < 170000 {
+		return db.CheckpointStatsRow{}, fmt.Errorf("relation \"pg_stat_checkpointer\" does not exist")
+	}
+	return m.stats, nil
+}
+
+func (m *mockCheckpointsQueryer) CheckpointStatsLegacy(context.Context) (db.CheckpointStatsLegacyRow, error) {
+	if m.version >= 170000 {
+		return db.CheckpointStatsLegacyRow{}, fmt.Errorf("column \"checkpoints_timed\" does not exist")
+	}
+	return db.CheckpointStatsLegacyRow(m.stats), nil
+}
+
+func stats(timed, requested, seconds int64) db.CheckpointStatsRow {
+	return db.CheckpointStatsRow{
+		CheckpointsTimed:         timed,
+		CheckpointsRequested:     requested,
+		WriteTimeMs:              float64(timed+requested) * 200_000,
+		SyncTimeMs:               float64(timed+requested) * 500,
+		BuffersWritten:           (timed + requested) * 10_000,
+		SecondsSinceReset:        seconds,
+		MaxWalSizeMb:             1024,
+		CheckpointTimeoutSeconds: 300,
+		CheckpointWarningSeconds: 30,
+	}
+}
+
+func Test_Checkpoints(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Stats            db.CheckpointStatsRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+		ExpectedExpected string
+	}{
+		{
+			Name:             "mostly timed checkpoints - OK",
+			Stats:            stats(90, 10, 30*day),
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "10% of 100 checkpoints were requested (max_wal_size 1GB)",
+		},
+		{
+			Name:             "mostly requested checkpoints warn",
+			Stats:            stats(20, 80, day),
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "80% of 100 checkpoints were requested rather than timed, one every 18m on average (max_wal_size 1GB)",
+			ExpectedExpected: "2GB",
+		},
+		{
+			Name:             "checkpoints closer than checkpoint_warning warn",
+			Stats:            stats(9000, 3000, day),
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "checkpoints this frequent trigger the checkpoint_warning (30s) log message",
+			ExpectedExpected: "8GB",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: tc.Stats})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Equal(t, tc.ExpectedSeverity, report.Severity)
+			finding := checktest.Finding(t, report, "requested-checkpoints")
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+			if tc.ExpectedExpected != "" {
+				require.Equal(t, "1GB", finding.Comparison.Actual)
+				require.Equal(t, tc.ExpectedExpected, finding.Comparison.Expected)
+			}
+		})
+	}
+}
+
+func Test_Checkpoints_TooFewCheckpoints(t *testing.T) {
+	t.Parallel()
+
+	checker := checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: stats(5, 2, day)})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Contains(t, report.Results[0].Details, "Only 7 checkpoints since statistics were reset")
+}
+
+func Test_Checkpoints_LegacyStats(t *testing.T) {
+	t.Parallel()
+
+	checker := checkpoints.New(&mockCheckpointsQueryer{version: 160004, stats: stats(20, 80, day)})
+	report, err := checker.Check(check.ContextWithServerVersion(context.Background(), 160004))
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "requested-checkpoints").Severity)
+}
+
+func Test_Checkpoints_Remediation(t *testing.T) {
+	t.Parallel()
+
+	row := stats(20, 80, day)
+	report, err := checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: row}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "requested-checkpoints")
+	require.Equal(t, "Set max_wal_size to 2GB and reload the configuration. "+
+		"Consider raising checkpoint_timeout from 5m to 15m as well", finding.Remediation)
+	require.Equal(t, "ALTER SYSTEM SET max_wal_size = '2GB';\nSELECT pg_reload_conf();", finding.FixSQL)
+
+	row.CheckpointTimeoutSeconds = 1800
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err = checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: row}).Check(ctx)
+	require.NoError(t, err)
+
+	finding = checktest.Finding(t, report, "requested-checkpoints")
+	require.Equal(t, "Set max_wal_size to 2GB in the DB parameter group", finding.Remediation)
+	require.Empty(t, finding.FixSQL)
+}
+
+func Test_Checkpoints_SyncTime(t *testing.T) {
+	t.Parallel()
+
+	row := stats(90, 10, 30*day)
+	report, err := checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: row}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "checkpoint-sync-time")
+	require.Equal(t, check.SeverityOK, finding.Severity)
+	require.Equal(t, "Checkpoints take 200s to write and 0.5s to sync on average", finding.Details)
+
+	row.SyncTimeMs = 1_500_000
+	report, err = checkpoints.New(&mockCheckpointsQueryer{version: 170000, stats: row}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding = checktest.Finding(t, report, "checkpoint-sync-time")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "Checkpoints spend 15.0s on average waiting for fsync (1.0M buffers written in total)")
+}
+
+func Test_Checkpoints_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CheckpointStats",
+			[]string{
+				"checkpoints_timed int8", "checkpoints_requested int8", "write_time_ms float8", "sync_time_ms float8",
+				"buffers_written int8", "seconds_since_reset int8", "max_wal_size_mb int8",
+				"checkpoint_timeout_seconds int8", "checkpoint_warning_seconds int8",
+			},
+			[]any{20, 80, 20000000.5, 50000.25, 1000000, day, 1024, 300, 30},
+		)
+
+	report, err := checkpoints.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "2GB", checktest.Finding(t, report, "requested-checkpoints").Comparison.Expected)
+}
+
+func Test_Checkpoints_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := checkpoints.New(&mockCheckpointsQueryer{version: 170000, err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "checkpoints")
+}
+
+func Test_Checkpoints_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := checkpoints.Metadata()
+
+	require.Equal(t, "checkpoints", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,28 @@
+-- name: CheckpointStats :one
+-- Checkpoint counters and timings since statistics were reset (PostgreSQL 17+),
+-- with the settings that drive checkpoint frequency.
+SELECT
+  c.num_timed AS checkpoints_timed
+  , c.num_requested AS checkpoints_requested
+  , c.write_time AS write_time_ms
+  , c.sync_time AS sync_time_ms
+  , c.buffers_written
+  , COALESCE(extract(EPOCH FROM (now() - c.stats_reset))::bigint, 0) AS seconds_since_reset
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_wal_size'), 0) AS max_wal_size_mb
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_timeout'), 0) AS checkpoint_timeout_seconds
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_warning'), 0) AS checkpoint_warning_seconds
+FROM pg_stat_checkpointer AS c;
+
+-- name: CheckpointStatsLegacy :one
+-- Same as CheckpointStats, from pg_stat_bgwriter (PostgreSQL 16 and older).
+SELECT
+  b.checkpoints_timed
+  , b.checkpoints_req AS checkpoints_requested
+  , b.checkpoint_write_time AS write_time_ms
+  , b.checkpoint_sync_time AS sync_time_ms
+  , b.buffers_checkpoint AS buffers_written
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_wal_size'), 0) AS max_wal_size_mb
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_timeout'), 0) AS checkpoint_timeout_seconds
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_warning'), 0) AS checkpoint_warning_seconds
+FROM pg_stat_bgwriter AS b;
@@ -0,0 +1,255 @@
+// Package columntypes implements checks for column types that are usually a
+// design mistake: char(n), money, timestamp next to timestamptz, and
+// varchar(255) used by default.
+package columntypes
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// varchar(255) becomes a pattern, rather than a few deliberate limits,
+	// once at least this many columns and half of all varchar(n) use it.
+	minVarchar255Columns = 10
+	varchar255Share      = 0.5
+)
+
+const charRemediation = "Use text, or varchar(n) where a limit is a real rule. char(n) pads values with spaces up to n, " +
+	"ignores trailing spaces in comparisons, and is no faster or smaller than text"
+
+const moneyRemediation = "Store amounts as numeric (or integer minor units) with the currency in its own column. " +
+	"money has a fixed scale, and its input and output depend on the lc_monetary setting of each session"
+
+const timestampRemediation = "Convert timestamp columns to timestamptz, setting the TimeZone the values were written in " +
+	"for the conversion: ALTER TABLE ... ALTER COLUMN ... TYPE timestamptz rewrites the table under an ACCESS EXCLUSIVE lock"
+
+const varcharRemediation = "Use text, and a CHECK constraint on length only where a limit is a real rule. " +
+	"The 255 comes from other databases; PostgreSQL stores text and varchar the same way, and raising a varchar limit later " +
+	"is a schema migration"
+
+type ColumnTypesQueries interface {
+	SuspiciousColumnTypes(context.Context) ([]db.SuspiciousColumnTypesRow, error)
+}
+
+type checker struct {
+	queries ColumnTypesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "column-types",
+		Name:        "Column Type Smells",
+		Description: "Flags char(n), money, timestamp mixed with timestamptz, and varchar(255) used everywhere",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries ColumnTypesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.SuspiciousColumnTypes(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.SuspiciousColumnTypesRow) string { return r.SchemaName + "." + r.TableName })
+
+	checkCharColumns(rows, report)
+	checkMoneyColumns(rows, report)
+	checkMixedTimestamps(rows, report)
+	checkVarchar255(rows, report)
+
+	return report, nil
+}
+
+// checkCharColumns warns on char(n) columns. char(1) is a common way to
+// store a one-letter code and is left alone.
+func checkCharColumns(rows []db.SuspiciousColumnTypesRow, report *check.Report) {
+	columns := filterColumns(rows, func(r db.SuspiciousColumnTypesRow) bool {
+		return r.TypeName == "bpchar" && r.MaxLength > 1
+	})
+	addColumnsFinding(report, columns, "char-columns", "char(n) Columns", "No char(n) columns",
+		"%d char(n) column(s): values are padded with spaces to the declared length", charRemediation)
+}
+
+func checkMoneyColumns(rows []db.SuspiciousColumnTypesRow, report *check.Report) {
+	columns := filterColumns(rows, func(r db.SuspiciousColumnTypesRow) bool { return r.TypeName == "money" })
+	addColumnsFinding(report, columns, "money-columns", "money Columns", "No money columns",
+		"%d money column(s): the type rounds to the locale's fraction digits and formats by lc_monetary", moneyRemediation)
+}
+
+// checkMixedTimestamps warns on timestamp columns in a database that also
+// uses timestamptz: the two compare and convert using the session TimeZone,
+// so mixing them gives results that depend on who runs the query.
+func checkMixedTimestamps(rows []db.SuspiciousColumnTypesRow, report *check.Report) {
+	withTZ := map[string]bool{}
+	for _, row := range rows {
+		if row.TypeName == "timestamptz" {
+			withTZ[row.SchemaName+"."+row.TableName] = true
+		}
+	}
+	columns := filterColumns(rows, func(r db.SuspiciousColumnTypesRow) bool { return r.TypeName == "timestamp" })
+
+	if len(withTZ) == 0 || len(columns) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "timestamp-without-time-zone",
+			Name:     "timestamp Next to timestamptz",
+			Severity: check.SeverityOK,
+			Details:  "The database does not mix timestamp and timestamptz columns",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(columns))
+	for _, col := range columns {
+		table := col.SchemaName + "." + col.TableName
+		sameTable := "no"
+		if withTZ[table] {
+			sameTable = "yes"
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{table, col.ColumnName, col.DataType, sameTable},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "timestamp-without-time-zone",
+		Name:     "timestamp Next to timestamptz",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d timestamp without time zone column(s) in a database that also uses timestamptz: "+
+			"comparing or converting them depends on each session's TimeZone", len(columns)),
+		Remediation: timestampRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Type", "Table Has timestamptz"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "timestamp_columns", Value: float64(len(columns)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// checkVarchar255 warns when varchar(255) is the default choice for text
+// columns rather than a few deliberate limits, listing the columns by table.
+func checkVarchar255(rows []db.SuspiciousColumnTypesRow, report *check.Report) {
+	limited, columns := 0, 0
+	var tables []string
+	byTable := map[string][]string{}
+	for _, row := range rows {
+		if row.TypeName != "varchar" || row.MaxLength < 0 {
+			continue
+		}
+		limited++
+		if row.MaxLength != 255 {
+			continue
+		}
+		columns++
+		table := row.SchemaName + "." + row.TableName
+		if _, ok := byTable[table]; !ok {
+			tables = append(tables, table)
+		}
+		byTable[table] = append(byTable[table], row.ColumnName)
+	}
+
+	if columns < minVarchar255Columns || float64(columns) < varchar255Share*float64(limited) {
+		report.AddFinding(check.Finding{
+			ID:       "varchar-255",
+			Name:     "varchar(255) Everywhere",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%d of %d varchar(n) column(s) are varchar(255)", columns, limited),
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(tables))
+	for _, table := range tables {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{table, strings.Join(byTable[table], ", ")},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "varchar-255",
+		Name:     "varchar(255) Everywhere",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d of %d varchar(n) column(s), on %d table(s), are varchar(255): a default carried over "+
+			"from other databases rather than a rule about the data", columns, limited, len(tables)),
+		Remediation: varcharRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Columns"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "varchar_255_columns", Value: float64(columns), Unit: check.UnitCount},
+		},
+	})
+}
+
+func filterColumns(rows []db.SuspiciousColumnTypesRow, keep func(db.SuspiciousColumnTypesRow) bool) []db.SuspiciousColumnTypesRow {
+	var columns []db.SuspiciousColumnTypesRow
+	for _, row := range rows {
+		if keep(row) {
+			columns = append(columns, row)
+		}
+	}
+	return columns
+}
+
+// addColumnsFinding reports one column per row, or an OK finding when there
+// are none.
+func addColumnsFinding(report *check.Report, columns []db.SuspiciousColumnTypesRow, id, name, okDetails, details, remediation string) {
+	if len(columns) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       id,
+			Name:     name,
+			Severity: check.SeverityOK,
+			Details:  okDetails,
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(columns))
+	for _, col := range columns {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{col.SchemaName + "." + col.TableName, col.ColumnName, col.DataType},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          id,
+		Name:        name,
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf(details, len(columns)),
+		Remediation: remediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Type"},
+			Rows:    tableRows,
+		},
+	})
+}
@@ -0,0 +1,192 @@
+package columntypes_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/columntypes"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockColumnTypesQueryer struct {
+	columns []db.SuspiciousColumnTypesRow
+	err     error
+}
+
+func (m *mockColumnTypesQueryer) SuspiciousColumnTypes(context.Context) ([]db.SuspiciousColumnTypesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.columns, nil
+}
+
+func column(table, name, typeName string, maxLength int32) db.SuspiciousColumnTypesRow {
+	dataType := map[string]string{
+		"bpchar":      fmt.Sprintf("character(%d)", maxLength),
+		"varchar":     fmt.Sprintf("character varying(%d)", maxLength),
+		"money":       "money",
+		"timestamp":   "timestamp without time zone",
+		"timestamptz": "timestamp with time zone",
+	}[typeName]
+	return db.SuspiciousColumnTypesRow{
+		SchemaName: "public",
+		TableName:  table,
+		ColumnName: name,
+		TypeName:   typeName,
+		DataType:   dataType,
+		MaxLength:  maxLength,
+	}
+}
+
+// varchars returns n varchar columns of the given length spread over two tables.
+func varchars(n int, length int32) []db.SuspiciousColumnTypesRow {
+	var columns []db.SuspiciousColumnTypesRow
+	for i := range n {
+		columns = append(columns, column([]string{"users", "orders"}[i%2], fmt.Sprintf("col_%d_%d", length, i), "varchar", length))
+	}
+	return columns
+}
+
+func Test_ColumnTypes(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		columns  []db.SuspiciousColumnTypesRow
+		expected map[string]check.Severity
+	}{
+		{
+			name: "no suspicious columns",
+			expected: map[string]check.Severity{
+				"char-columns":                check.SeverityOK,
+				"money-columns":               check.SeverityOK,
+				"timestamp-without-time-zone": check.SeverityOK,
+				"varchar-255":                 check.SeverityOK,
+			},
+		},
+		{
+			name:     "char(n) column",
+			columns:  []db.SuspiciousColumnTypesRow{column("users", "country_code", "bpchar", 3)},
+			expected: map[string]check.Severity{"char-columns": check.SeverityWarn},
+		},
+		{
+			name:     "char(1) column",
+			columns:  []db.SuspiciousColumnTypesRow{column("users", "gender", "bpchar", 1)},
+			expected: map[string]check.Severity{"char-columns": check.SeverityOK},
+		},
+		{
+			name:     "money column",
+			columns:  []db.SuspiciousColumnTypesRow{column("orders", "total", "money", -1)},
+			expected: map[string]check.Severity{"money-columns": check.SeverityWarn},
+		},
+		{
+			name:     "only timestamp without time zone",
+			columns:  []db.SuspiciousColumnTypesRow{column("orders", "created_at", "timestamp", -1)},
+			expected: map[string]check.Severity{"timestamp-without-time-zone": check.SeverityOK},
+		},
+		{
+			name: "timestamp mixed with timestamptz",
+			columns: []db.SuspiciousColumnTypesRow{
+				column("orders", "created_at", "timestamptz", -1),
+				column("orders", "shipped_at", "timestamp", -1),
+			},
+			expected: map[string]check.Severity{"timestamp-without-time-zone": check.SeverityWarn},
+		},
+		{
+			name:     "a few varchar(255) columns",
+			columns:  varchars(5, 255),
+			expected: map[string]check.Severity{"varchar-255": check.SeverityOK},
+		},
+		{
+			name:     "varchar(255) everywhere",
+			columns:  varchars(12, 255),
+			expected: map[string]check.Severity{"varchar-255": check.SeverityWarn},
+		},
+		{
+			name:     "varchar(255) among many deliberate limits",
+			columns:  append(varchars(12, 255), varchars(30, 64)...),
+			expected: map[string]check.Severity{"varchar-255": check.SeverityOK},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := columntypes.New(&mockColumnTypesQueryer{columns: tt.columns}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 4)
+			for id, severity := range tt.expected {
+				require.Equal(t, severity, checktest.Finding(t, report, id).Severity, id)
+			}
+		})
+	}
+}
+
+func Test_ColumnTypes_Details(t *testing.T) {
+	t.Parallel()
+
+	columns := append([]db.SuspiciousColumnTypesRow{
+		column("events", "occurred_at", "timestamp", -1),
+		column("orders", "created_at", "timestamptz", -1),
+		column("orders", "shipped_at", "timestamp", -1),
+	}, varchars(12, 255)...)
+	report, err := columntypes.New(&mockColumnTypesQueryer{columns: columns}).Check(context.Background())
+	require.NoError(t, err)
+
+	timestamps := checktest.Finding(t, report, "timestamp-without-time-zone")
+	require.Equal(t, []string{"public.events", "occurred_at", "timestamp without time zone", "no"}, timestamps.Table.Rows[0].Cells)
+	require.Equal(t, []string{"public.orders", "shipped_at", "timestamp without time zone", "yes"}, timestamps.Table.Rows[1].Cells)
+
+	varchar := checktest.Finding(t, report, "varchar-255")
+	require.Contains(t, varchar.Details, "12 of 12")
+	require.Len(t, varchar.Table.Rows, 2)
+	require.Equal(t, "public.users", varchar.Table.Rows[0].Cells[0])
+	require.Equal(t, "col_255_0, col_255_2, col_255_4, col_255_6, col_255_8, col_255_10", varchar.Table.Rows[0].Cells[1])
+}
+
+func Test_ColumnTypes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("SuspiciousColumnTypes",
+		[]string{
+			"schema_name text", "table_name text", "column_name text", "type_name text", "data_type text", "max_length int4",
+		},
+		[]any{"billing", "invoices", "amount", "money", "money", -1},
+		[]any{"billing", "invoices", "currency", "bpchar", "character(3)", 3},
+	)
+
+	report, err := columntypes.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, []string{"billing.invoices", "amount", "money"}, checktest.Finding(t, report, "money-columns").Table.Rows[0].Cells)
+	require.Equal(t, []string{"billing.invoices", "currency", "character(3)"}, checktest.Finding(t, report, "char-columns").Table.Rows[0].Cells)
+}
+
+func Test_ColumnTypes_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := columntypes.New(&mockColumnTypesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "column-types")
+}
+
+func Test_ColumnTypes_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := columntypes.Metadata()
+
+	require.Equal(t, "column-types", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,28 @@
+-- name: SuspiciousColumnTypes :many
+-- Columns of types that are often a design mistake: char(n), money,
+-- timestamp and varchar(n), plus timestamptz to tell whether the database
+-- mixes both timestamp types. Partitions repeat their parent's columns and
+-- are left out. max_length is the declared length of char and varchar
+-- columns, and -1 otherwise.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , t.typname::text AS type_name
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type
+  , CASE
+    WHEN t.typname IN ('bpchar', 'varchar') AND a.atttypmod > 4 THEN a.atttypmod - 4
+    ELSE -1
+  END AS max_length
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_type AS t ON a.atttypid = t.oid
+WHERE
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND c.relkind IN ('r', 'p')
+  AND NOT c.relispartition
+  AND t.typname IN ('bpchar', 'money', 'timestamp', 'timestamptz', 'varchar')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY n.nspname, c.relname, a.attnum;
@@ -38,12 +38,14 @@ type checker struct {
 
 func Metadata() check.Metadata {
 	return check.Metadata{
-		Category:    check.CategoryConfigs,
-		CheckID:     "connection-efficiency",
-		Name:        "Connection Efficiency",
-		Description: "Analyzes PostgreSQL 14+ session statistics for connection pool efficiency",
-		Readme:      readme,
-		SQL:         querySQL,
+		Category:     check.CategoryConfigs,
+		CheckID:      "connection-efficiency",
+		Name:         "Connection Efficiency",
+		Description:  "Analyzes PostgreSQL 14+ session statistics for connection pool efficiency",
+		Readme:       readme,
+		SQL:          querySQL,
+		Tags:         []string{check.TagTriage},
+		MinPGVersion: 14,
 	}
 }
 
@@ -60,19 +62,6 @@ func (c *checker) Metadata() check.Metadata {
 func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	report := check.NewReport(Metadata())
 
-	meta := check.InstanceMetadataFromContext(ctx)
-
-	// Skip for PostgreSQL < 14 (session statistics don't exist).
-	if meta == nil || meta.EngineVersionMajor < 14 {
-		report.AddFinding(check.Finding{
-			ID:       report.CheckID,
-			Name:     report.Name,
-			Severity: check.SeverityOK,
-			Details:  "Does not support session statistics (requires PG14+)",
-		})
-		return report, nil
-	}
-
 	stats, err := c.queryer.SessionStatistics(ctx)
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
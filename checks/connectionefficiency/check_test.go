@@ -22,15 +22,6 @@ func (m *mockQueries) SessionStatistics(context.Context) (db.SessionStatisticsRo
 	return m.stats, m.err
 }
 
-// ctxWithPgVersion creates a context with instance metadata containing the specified PG version.
-func ctxWithPgVersion(major int) context.Context {
-	return check.ContextWithInstanceMetadata(context.Background(), &check.InstanceMetadata{
-		EngineVersion:      fmt.Sprintf("%d.0", major),
-		EngineVersionMajor: major,
-		EngineVersionMinor: 0,
-	})
-}
-
 func float64Val(v float64) pgtype.Float8 {
 	return pgtype.Float8{Float64: v, Valid: true}
 }
@@ -74,6 +65,7 @@ func Test_ConnectionEfficiency_Metadata(t *testing.T) {
 	require.NotEmpty(t, meta.Description)
 	require.NotEmpty(t, meta.SQL)
 	require.NotEmpty(t, meta.Readme)
+	require.Equal(t, 14, meta.MinPGVersion)
 }
 
 func Test_ConnectionEfficiency_AllOK(t *testing.T) {
@@ -81,7 +73,7 @@ func Test_ConnectionEfficiency_AllOK(t *testing.T) {
 
 	mock := &mockQueries{stats: healthyStats()}
 	checker := connectionefficiency.New(mock)
-	report, err := checker.Check(ctxWithPgVersion(17))
+	report, err := checker.Check(context.Background())
 
 	require.NoError(t, err)
 	require.NotNil(t, report)
@@ -94,38 +86,6 @@ func Test_ConnectionEfficiency_AllOK(t *testing.T) {
 	require.True(t, hasResult(report.Results, "sessions-killed", check.SeverityOK))
 }
 
-func Test_ConnectionEfficiency_PostgreSQL13_Skipped(t *testing.T) {
-	t.Parallel()
-
-	mock := &mockQueries{stats: healthyStats()}
-	checker := connectionefficiency.New(mock)
-	report, err := checker.Check(ctxWithPgVersion(13))
-
-	require.NoError(t, err)
-	require.NotNil(t, report)
-
-	// Should return single OK finding explaining PG13 doesn't support session stats.
-	require.Len(t, report.Results, 1)
-	require.Equal(t, check.SeverityOK, report.Results[0].Severity)
-	require.Contains(t, report.Results[0].Details, "Does not support session statistics")
-	require.Contains(t, report.Results[0].Details, "requires PG14+")
-}
-
-func Test_ConnectionEfficiency_NoMetadata_Skipped(t *testing.T) {
-	t.Parallel()
-
-	mock := &mockQueries{stats: healthyStats()}
-	checker := connectionefficiency.New(mock)
-	report, err := checker.Check(context.Background()) // No instance metadata
-
-	require.NoError(t, err)
-	require.NotNil(t, report)
-
-	require.Len(t, report.Results, 1)
-	require.Equal(t, check.SeverityOK, report.Results[0].Severity)
-	require.Contains(t, report.Results[0].Details, "Does not support session statistics")
-}
-
 func Test_ConnectionEfficiency_NoSessions(t *testing.T) {
 	t.Parallel()
 
@@ -135,7 +95,7 @@ func Test_ConnectionEfficiency_NoSessions(t *testing.T) {
 		},
 	}
 	checker := connectionefficiency.New(mock)
-	report, err := checker.Check(ctxWithPgVersion(17))
+	report, err := checker.Check(context.Background())
 
 	require.NoError(t, err)
 	require.NotNil(t, report)
@@ -153,7 +113,7 @@ func Test_ConnectionEfficiency_QueryError(t *testing.T) {
 		err: fmt.Errorf("connection refused"),
 	}
 	checker := connectionefficiency.New(mock)
-	_, err := checker.Check(ctxWithPgVersion(17))
+	_, err := checker.Check(context.Background())
 
 	require.Error(t, err)
 	require.Contains(t, err.Error(), "connection refused")
@@ -204,7 +164,7 @@ func Test_ConnectionEfficiency_BusyRatio(t *testing.T) {
 
 			mock := &mockQueries{stats: stats}
 			checker := connectionefficiency.New(mock)
-			report, err := checker.Check(ctxWithPgVersion(17))
+			report, err := checker.Check(context.Background())
 
 			require.NoError(t, err)
 			require.True(t, hasResult(report.Results, "busy-ratio", tt.expectedSeverity))
@@ -257,7 +217,7 @@ func Test_ConnectionEfficiency_SessionsAbandoned(t *testing.T) {
 
 			mock := &mockQueries{stats: stats}
 			checker := connectionefficiency.New(mock)
-			report, err := checker.Check(ctxWithPgVersion(17))
+			report, err := checker.Check(context.Background())
 
 			require.NoError(t, err)
 			require.True(t, hasResult(report.Results, "sessions-abandoned", tt.expectedSeverity))
@@ -304,7 +264,7 @@ func Test_ConnectionEfficiency_SessionsFatal(t *testing.T) {
 
 			mock := &mockQueries{stats: stats}
 			checker := connectionefficiency.New(mock)
-			report, err := checker.Check(ctxWithPgVersion(17))
+			report, err := checker.Check(context.Background())
 
 			require.NoError(t, err)
 			require.True(t, hasResult(report.Results, "sessions-fatal", tt.expectedSeverity))
@@ -351,7 +311,7 @@ func Test_ConnectionEfficiency_SessionsKilled(t *testing.T) {
 
 			mock := &mockQueries{stats: stats}
 			checker := connectionefficiency.New(mock)
-			report, err := checker.Check(ctxWithPgVersion(17))
+			report, err := checker.Check(context.Background())
 
 			require.NoError(t, err)
 			require.True(t, hasResult(report.Results, "sessions-killed", tt.expectedSeverity))
@@ -375,7 +335,7 @@ func Test_ConnectionEfficiency_Prescriptions(t *testing.T) {
 
 	mock := &mockQueries{stats: stats}
 	checker := connectionefficiency.New(mock)
-	_, err := checker.Check(ctxWithPgVersion(17))
+	_, err := checker.Check(context.Background())
 
 	require.NoError(t, err)
 }
@@ -419,7 +379,7 @@ func Test_ConnectionEfficiency_ReportSeverity(t *testing.T) {
 
 			mock := &mockQueries{stats: tt.stats}
 			checker := connectionefficiency.New(mock)
-			report, err := checker.Check(ctxWithPgVersion(17))
+			report, err := checker.Check(context.Background())
 
 			require.NoError(t, err)
 			require.Equal(t, tt.expectedSeverity, report.Severity)
@@ -5,6 +5,7 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"slices"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
@@ -30,6 +31,9 @@ const (
 	longIdleWarnCount = 10
 	longIdleFailCount = 50
 
+	// Idle sessions per active one beyond which a pooler beats tuning pool sizes.
+	poolerIdleActiveRatio = 10
+
 	// Pool pressure thresholds - detect when queries may be waiting for connections.
 	poolPressureActivePercent = 90.0 // Warn when >90% of connections are active
 	poolPressureMinIdleWarn   = 3    // AND fewer than 3 idle connections
@@ -40,6 +44,9 @@ const (
 const (
 	// Fallback timeout when idle_in_transaction_session_timeout is disabled (0).
 	idleTxnDefaultTimeoutSeconds = int64(300) // 5 minutes
+
+	// Suggested idle_in_transaction_session_timeout when none is set.
+	idleTxnSuggestedTimeout = "5min"
 )
 
 type ConnectionHealthQueries interface {
@@ -60,6 +67,7 @@ func Metadata() check.Metadata {
 		Description: "Monitors connection pool saturation, idle ratios, and stuck transactions",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -96,8 +104,8 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	checkConnectionSaturation(stats, report)
 	checkPoolPressure(stats, report)
 	checkIdleRatio(stats, report)
-	checkIdleInTransaction(idleTxns, report)
-	checkLongIdleConnections(longIdle, report)
+	checkIdleInTransaction(idleTxns, check.ProviderFromContext(ctx), report)
+	checkLongIdleConnections(longIdle, stats, report)
 
 	return report, nil
 }
@@ -241,7 +249,7 @@ func checkIdleRatio(stats db.ConnectionStatsRow, report *check.Report) {
 	})
 }
 
-func checkIdleInTransaction(rows []db.IdleInTransactionRow, report *check.Report) {
+func checkIdleInTransaction(rows []db.IdleInTransactionRow, provider check.Provider, report *check.Report) {
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
 			ID:       "idle-in-transaction",
@@ -254,7 +262,8 @@ func checkIdleInTransaction(rows []db.IdleInTransactionRow, report *check.Report
 
 	// Get timeout from first row (same for all rows). If disabled (0), use 5 minute default.
 	timeoutSeconds := rows[0].TimeoutMs.Int64 / 1000
-	if timeoutSeconds == 0 {
+	timeoutDisabled := timeoutSeconds == 0
+	if timeoutDisabled {
 		timeoutSeconds = idleTxnDefaultTimeoutSeconds
 	}
 	warnThreshold := timeoutSeconds / 2
@@ -263,7 +272,7 @@ func checkIdleInTransaction(rows []db.IdleInTransactionRow, report *check.Report
 	// Filter rows that meet the warn threshold.
 	var problematic []db.IdleInTransactionRow
 	for _, row := range rows {
-		if row.TransactionDurationSeconds.Int64 >= warnThreshold {
+		if row.TransactionDurationSeconds.Int64 >= warnThreshold || row.BlockedSessions > 0 {
 			problematic = append(problematic, row)
 		}
 	}
@@ -280,14 +289,17 @@ func checkIdleInTransaction(rows []db.IdleInTransactionRow, report *check.Report
 
 	var tableRows []check.TableRow
 	severity := check.SeverityWarn
+	var blocking int64
 
 	for _, row := range problematic {
 		duration := row.TransactionDurationSeconds.Int64
 		rowSeverity := check.SeverityWarn
-		if duration >= failThreshold {
+		// Sessions others are queued behind are an outage in progress.
+		if duration >= failThreshold || row.BlockedSessions > 0 {
 			rowSeverity = check.SeverityFail
 			severity = check.SeverityFail
 		}
+		blocking += row.BlockedSessions
 
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
@@ -295,26 +307,43 @@ func checkIdleInTransaction(rows []db.IdleInTransactionRow, report *check.Report
 				row.Username.String,
 				row.DatabaseName.String,
 				formatDuration(duration),
+				formatDuration(row.IdleSeconds.Int64),
+				formatLocks(row),
+				fmt.Sprintf("%d", row.BlockedSessions),
 				truncateString(row.QueryPreview.String, 50),
 			},
 			Severity: rowSeverity,
 		})
 	}
 
+	details := fmt.Sprintf("Found %d connection(s) stuck in 'idle in transaction' state", len(problematic))
+	if blocking > 0 {
+		details += fmt.Sprintf(", blocking %d session(s)", blocking)
+	}
+	remediation := "Commit or roll back promptly in the application; terminate stuck sessions with pg_terminate_backend(pid)"
+	var fixSQL string
+	if timeoutDisabled {
+		details += "; idle_in_transaction_session_timeout is not set"
+		remediation, fixSQL = provider.SettingFix("idle_in_transaction_session_timeout", idleTxnSuggestedTimeout)
+	}
+
 	report.AddFinding(check.Finding{
-		ID:       "idle-in-transaction",
-		Name:     "Idle In Transaction",
-		Severity: severity,
-		Details:  fmt.Sprintf("Found %d connection(s) stuck in 'idle in transaction' state", len(problematic)),
+		ID:          "idle-in-transaction",
+		Name:        "Idle In Transaction",
+		Severity:    severity,
+		Details:     details,
+		Remediation: remediation,
+		FixSQL:      fixSQL,
 		Table: &check.Table{
-			Headers: []string{"PID", "User", "Database", "Duration", "Query"},
+			Headers: []string{"PID", "User", "Database", "Duration", "Idle", "Locks", "Blocking", "Query"},
 			Rows:    tableRows,
 		},
 	})
 }
 
-// checkLongIdleConnections detects connections idle for >30 minutes (potential connection leak).
-func checkLongIdleConnections(longIdle []db.LongIdleConnectionsRow, report *check.Report) {
+// checkLongIdleConnections detects connections idle for >30 minutes (potential connection leak),
+// grouped by application and user so the pool holding them can be found.
+func checkLongIdleConnections(longIdle []db.LongIdleConnectionsRow, stats db.ConnectionStatsRow, report *check.Report) {
 	count := len(longIdle)
 
 	if count < longIdleWarnCount {
@@ -332,11 +361,58 @@ func checkLongIdleConnections(longIdle []db.LongIdleConnectionsRow, report *chec
 		severity = check.SeverityFail
 	}
 
+	type idleGroup struct {
+		application, username string
+		sessions              int
+		longestSeconds        int64
+	}
+	groups := map[[2]string]*idleGroup{}
+	var order []*idleGroup
+	for _, row := range longIdle {
+		key := [2]string{row.ApplicationName.String, row.Username.String}
+		g, ok := groups[key]
+		if !ok {
+			g = &idleGroup{application: key[0], username: key[1]}
+			groups[key] = g
+			order = append(order, g)
+		}
+		g.sessions++
+		g.longestSeconds = max(g.longestSeconds, row.IdleDurationSeconds.Int64)
+	}
+	slices.SortStableFunc(order, func(a, b *idleGroup) int { return b.sessions - a.sessions })
+
+	tableRows := make([]check.TableRow, 0, len(order))
+	for _, g := range order {
+		application := g.application
+		if application == "" {
+			application = "(none)"
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{application, g.username, fmt.Sprintf("%d", g.sessions), formatDuration(g.longestSeconds)},
+			Severity: severity,
+		})
+	}
+
+	remediation := "Lower the minimum pool size or set an idle timeout in the applications holding these connections"
+	idle, active := stats.IdleConnections.Int64, stats.ActiveConnections.Int64
+	if idle >= poolerIdleActiveRatio*max(active, 1) {
+		remediation = fmt.Sprintf("%d idle connections for %d active: put a connection pooler such as PgBouncer "+
+			"(transaction mode) in front of the database so a few server connections serve all clients", idle, active)
+	}
+
 	report.AddFinding(check.Finding{
-		ID:       "long-idle",
-		Name:     "Long Idle Connections",
-		Severity: severity,
-		Details:  fmt.Sprintf("%d connections idle >30 minutes (potential connection leak)", count),
+		ID:          "long-idle",
+		Name:        "Long Idle Connections",
+		Severity:    severity,
+		Details:     fmt.Sprintf("%d connections idle >30 minutes (potential connection leak)", count),
+		Remediation: remediation,
+		Metrics: []check.Metric{
+			{Name: "long_idle_connections", Value: float64(count), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Application", "User", "Idle Sessions", "Longest Idle"},
+			Rows:    tableRows,
+		},
 	})
 }
 
@@ -352,6 +428,15 @@ func formatDuration(seconds int64) string {
 	return fmt.Sprintf("%dh %dm", hours, minutes)
 }
 
+// formatLocks shows the lock count and, for the current database, the
+// locked relations: "3 (orders, order_items)".
+func formatLocks(row db.IdleInTransactionRow) string {
+	if row.LockedRelations == "" {
+		return fmt.Sprintf("%d", row.LocksHeld)
+	}
+	return fmt.Sprintf("%d (%s)", row.LocksHeld, truncateString(row.LockedRelations, 40))
+}
+
 func truncateString(s string, maxLen int) string {
 	if len(s) <= maxLen {
 		return s
@@ -522,6 +522,63 @@ func Test_ConnectionHealth_LongIdle(t *testing.T) {
 	}
 }
 
+func Test_ConnectionHealth_IdleInTransactionLocks(t *testing.T) {
+	t.Parallel()
+
+	mock := &mockQueries{
+		stats: healthyStats(),
+		idleTxns: []db.IdleInTransactionRow{
+			{
+				Pid:                        int32Val(1234),
+				Username:                   textVal("app_rw"),
+				DatabaseName:               textVal("production"),
+				TransactionDurationSeconds: int64Val(30), // below the warn threshold, but blocking
+				IdleSeconds:                int64Val(25),
+				QueryPreview:               textVal("UPDATE orders SET status = 'paid' WHERE id = 1"),
+				TimeoutMs:                  int64Val(0),
+				LocksHeld:                  3,
+				LockedRelations:            "orders, orders_pkey",
+				BlockedSessions:            2,
+			},
+		},
+	}
+
+	report, err := connectionhealth.New(mock).Check(ctxWithPgVersion(17))
+	require.NoError(t, err)
+
+	finding := getFinding(report.Results, "idle-in-transaction")
+	require.NotNil(t, finding)
+	require.Equal(t, check.SeverityFail, finding.Severity)
+	require.Contains(t, finding.Details, "blocking 2 session(s)")
+	require.Contains(t, finding.Details, "idle_in_transaction_session_timeout is not set")
+	require.Contains(t, finding.FixSQL, "ALTER SYSTEM SET idle_in_transaction_session_timeout = '5min';")
+	require.Equal(t, []string{"1234", "app_rw", "production", "30s", "25s", "3 (orders, orders_pkey)", "2"}, finding.Table.Rows[0].Cells[:7])
+}
+
+func Test_ConnectionHealth_IdleInTransactionTimeoutSet(t *testing.T) {
+	t.Parallel()
+
+	mock := &mockQueries{
+		stats: healthyStats(),
+		idleTxns: []db.IdleInTransactionRow{
+			{
+				Pid:                        int32Val(1234),
+				TransactionDurationSeconds: int64Val(700),
+				TimeoutMs:                  int64Val(600000),
+			},
+		},
+	}
+
+	report, err := connectionhealth.New(mock).Check(ctxWithPgVersion(17))
+	require.NoError(t, err)
+
+	finding := getFinding(report.Results, "idle-in-transaction")
+	require.NotNil(t, finding)
+	require.NotContains(t, finding.Details, "not set")
+	require.Empty(t, finding.FixSQL)
+	require.Contains(t, finding.Remediation, "pg_terminate_backend")
+}
+
 func Test_ConnectionHealth_TableDetails(t *testing.T) {
 	t.Parallel()
 
@@ -552,7 +609,7 @@ func Test_ConnectionHealth_TableDetails(t *testing.T) {
 		finding := getFinding(report.Results, "idle-in-transaction")
 		require.NotNil(t, finding)
 		require.NotNil(t, finding.Table)
-		require.Equal(t, []string{"PID", "User", "Database", "Duration", "Query"}, finding.Table.Headers)
+		require.Equal(t, []string{"PID", "User", "Database", "Duration", "Idle", "Locks", "Blocking", "Query"}, finding.Table.Headers)
 	})
 
 	t.Run("connection-overview has no table (inline details)", func(t *testing.T) {
@@ -725,3 +782,45 @@ func Test_ConnectionHealth_ReportSeverity(t *testing.T) {
 		})
 	}
 }
+
+func Test_ConnectionHealth_LongIdleGroups(t *testing.T) {
+	t.Parallel()
+
+	longIdle := makeLongIdleRows(12)
+	for i := range 4 {
+		longIdle[i].ApplicationName = textVal("worker")
+		longIdle[i].Username = textVal("jobs")
+		longIdle[i].IdleDurationSeconds = int64Val(int64(7200 + i*60))
+	}
+
+	stats := healthyStats()
+	mock := &mockQueries{stats: stats, longIdle: longIdle}
+
+	report, err := connectionhealth.New(mock).Check(ctxWithPgVersion(17))
+	require.NoError(t, err)
+
+	var finding check.Finding
+	for _, r := range report.Results {
+		if r.ID == "long-idle" {
+			finding = r
+		}
+	}
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"Application", "User", "Idle Sessions", "Longest Idle"}, finding.Table.Headers)
+	require.Equal(t, []string{"myapp", "app_ro", "8", "1h 0m"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"worker", "jobs", "4", "2h 3m"}, finding.Table.Rows[1].Cells)
+	require.Contains(t, finding.Remediation, "idle timeout")
+
+	// 300 idle sessions for 5 active ones: pool sizes are not the fix.
+	stats.IdleConnections = int64Val(300)
+	stats.ActiveConnections = int64Val(5)
+	mock = &mockQueries{stats: stats, longIdle: longIdle}
+
+	report, err = connectionhealth.New(mock).Check(ctxWithPgVersion(17))
+	require.NoError(t, err)
+	for _, r := range report.Results {
+		if r.ID == "long-idle" {
+			require.Contains(t, r.Remediation, "300 idle connections for 5 active: put a connection pooler")
+		}
+	}
+}
@@ -13,7 +13,8 @@ FROM pg_stat_activity
 WHERE pid != pg_backend_pid();
 
 -- name: IdleInTransaction :many
--- Identifies connections stuck in 'idle in transaction' state.
+-- Identifies connections stuck in 'idle in transaction' state, with the locks
+-- they hold and how many sessions wait on them.
 -- Includes the timeout setting (in ms) for threshold calculation in Go.
 SELECT
   pg_stat_activity.pid
@@ -28,6 +29,30 @@ SELECT
     FROM pg_settings
     WHERE pg_settings.name = 'idle_in_transaction_session_timeout'
   ), 0) AS timeout_ms
+  , extract(EPOCH FROM (now() - pg_stat_activity.state_change))::bigint AS idle_seconds
+  , (
+    SELECT count(*)
+    FROM pg_locks AS l
+    WHERE
+      l.pid = pg_stat_activity.pid
+      AND l.granted
+      AND l.locktype NOT IN ('virtualxid', 'transactionid')
+  ) AS locks_held
+  -- Relation names resolve only for the current database
+  , coalesce((
+    SELECT string_agg(DISTINCT l.relation::regclass::text, ', ')
+    FROM pg_locks AS l
+    WHERE
+      l.pid = pg_stat_activity.pid
+      AND l.granted
+      AND l.locktype = 'relation'
+      AND l.database = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+  ), '') AS locked_relations
+  , (
+    SELECT count(*)
+    FROM pg_stat_activity AS waiting
+    WHERE pg_stat_activity.pid = ANY(pg_blocking_pids(waiting.pid))
+  ) AS blocked_sessions
 FROM pg_stat_activity
 WHERE
   pg_stat_activity.state IN ('idle in transaction', 'idle in transaction (aborted)')
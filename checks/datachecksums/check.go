@@ -0,0 +1,163 @@
+// Package datachecksums implements checks for data checksums: whether they
+// are enabled, and whether any page failed verification.
+package datachecksums
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+type DataChecksumsQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+	ChecksumFailures(context.Context) ([]db.ChecksumFailuresRow, error)
+}
+
+type checker struct {
+	queries DataChecksumsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryConfigs,
+		CheckID:      "data-checksums",
+		Name:         "Data Checksums",
+		Description:  "Reports whether data checksums are enabled and fails on pages that failed checksum verification",
+		Readme:       readme,
+		SQL:          querySQL + "\n" + catalog.SQL,
+		Tags:         []string{check.TagTriage},
+		MinPGVersion: 12,
+	}
+}
+
+func New(queries DataChecksumsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+
+	enabled := settings["data_checksums"].Setting.String == "on"
+	checkEnabled(enabled, report)
+
+	if !enabled {
+		report.AddFinding(check.Finding{
+			ID:       "checksum-failures",
+			Name:     "Checksum Failures",
+			Severity: check.SeveritySkip,
+			Details:  "Data checksums are disabled, so corrupted pages are not detected when read",
+		})
+		return report, nil
+	}
+
+	failures, err := c.queries.ChecksumFailures(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	checkFailures(failures, report)
+
+	return report, nil
+}
+
+// checkEnabled warns on clusters initialized without data checksums. They
+// can only be turned on with the server stopped, so there is no fix SQL.
+func checkEnabled(enabled bool, report *check.Report) {
+	if enabled {
+		report.AddFinding(check.Finding{
+			ID:       "checksums-enabled",
+			Name:     "Data Checksums Enabled",
+			Severity: check.SeverityOK,
+			Details:  "Data checksums are enabled: pages corrupted on disk are detected when read",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "checksums-enabled",
+		Name:     "Data Checksums Enabled",
+		Severity: check.SeverityWarn,
+		Details: "Data checksums are disabled: pages corrupted by storage, firmware or kernel bugs are read as valid, " +
+			"and the corruption spreads into query results, replicas and backups unnoticed",
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "data_checksums"},
+		Remediation: "Run pg_checksums --enable on the cleanly stopped cluster, or on a replica before failing over to it",
+		Comparison: &check.Comparison{
+			Setting:  "data_checksums",
+			Actual:   "off",
+			Expected: "on",
+		},
+	})
+}
+
+// checkFailures fails on any checksum failure: a page on disk differs from
+// what PostgreSQL wrote.
+func checkFailures(failures []db.ChecksumFailuresRow, report *check.Report) {
+	var total int64
+	tableRows := make([]check.TableRow, 0, len(failures))
+	for _, row := range failures {
+		total += row.ChecksumFailures
+		database := row.DatabaseName
+		if database == "" {
+			database = "(shared catalogs)"
+		}
+		lastFailure := ""
+		if row.LastFailure.Valid {
+			lastFailure = row.LastFailure.Time.Format("2006-01-02 15:04")
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{database, strconv.FormatInt(row.ChecksumFailures, 10), lastFailure},
+			Severity: check.SeverityFail,
+		})
+	}
+
+	metrics := []check.Metric{
+		{Name: "checksum_failures", Value: float64(total), Unit: check.UnitCount},
+	}
+	if total == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "checksum-failures",
+			Name:     "Checksum Failures",
+			Severity: check.SeverityOK,
+			Details:  "No checksum failures since the statistics were last reset",
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "checksum-failures",
+		Name:     "Checksum Failures",
+		Severity: check.SeverityFail,
+		Details: fmt.Sprintf("%d page read(s) failed checksum verification: data on disk is corrupted. "+
+			"Queries touching the pages error out, and the storage may keep corrupting more", total),
+		Remediation: "Check the storage and kernel logs, take a backup of what is readable, " +
+			"and restore the affected relations from a backup or a healthy replica",
+		Table: &check.Table{
+			Headers: []string{"Database", "Failures", "Last Failure"},
+			Rows:    tableRows,
+		},
+		Metrics: metrics,
+	})
+}
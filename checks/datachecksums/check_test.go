@@ -0,0 +1,156 @@
+package datachecksums_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/datachecksums"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockDataChecksumsQueryer struct {
+	checksums      string
+	failures       []db.ChecksumFailuresRow
+	failuresCalled bool
+	err            error
+}
+
+func (m *mockDataChecksumsQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return []db.CatalogSettingsRow{{
+		Name:    pgtype.Text{String: "data_checksums", Valid: true},
+		Setting: pgtype.Text{String: m.checksums, Valid: true},
+	}}, nil
+}
+
+func (m *mockDataChecksumsQueryer) ChecksumFailures(context.Context) ([]db.ChecksumFailuresRow, error) {
+	m.failuresCalled = true
+	return m.failures, nil
+}
+
+func Test_DataChecksums(t *testing.T) {
+	t.Parallel()
+
+	lastFailure := pgtype.Timestamptz{Time: time.Date(2026, 10, 2, 14, 30, 0, 0, time.UTC), Valid: true}
+
+	tests := []struct {
+		name      string
+		checksums string
+		failures  []db.ChecksumFailuresRow
+		enabled   check.Severity
+		failed    check.Severity
+	}{
+		{
+			name:      "enabled without failures",
+			checksums: "on",
+			enabled:   check.SeverityOK,
+			failed:    check.SeverityOK,
+		},
+		{
+			name:      "enabled with failures",
+			checksums: "on",
+			failures:  []db.ChecksumFailuresRow{{DatabaseName: "app", ChecksumFailures: 3, LastFailure: lastFailure}},
+			enabled:   check.SeverityOK,
+			failed:    check.SeverityFail,
+		},
+		{
+			name:      "disabled",
+			checksums: "off",
+			enabled:   check.SeverityWarn,
+			failed:    check.SeveritySkip,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockDataChecksumsQueryer{checksums: tt.checksums, failures: tt.failures}
+			report, err := datachecksums.New(queryer).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 2)
+			require.Equal(t, tt.enabled, checktest.Finding(t, report, "checksums-enabled").Severity)
+			require.Equal(t, tt.failed, checktest.Finding(t, report, "checksum-failures").Severity)
+			require.Equal(t, tt.checksums == "on", queryer.failuresCalled, "failures are only read with checksums enabled")
+		})
+	}
+}
+
+func Test_DataChecksums_Failures(t *testing.T) {
+	t.Parallel()
+
+	report, err := datachecksums.New(&mockDataChecksumsQueryer{
+		checksums: "on",
+		failures: []db.ChecksumFailuresRow{
+			{
+				DatabaseName:     "app",
+				ChecksumFailures: 3,
+				LastFailure:      pgtype.Timestamptz{Time: time.Date(2026, 10, 2, 14, 30, 0, 0, time.UTC), Valid: true},
+			},
+			{ChecksumFailures: 1},
+		},
+	}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "checksum-failures")
+	require.Contains(t, finding.Details, "4 page read(s)")
+	require.Equal(t, []string{"app", "3", "2026-10-02 14:30"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"(shared catalogs)", "1", ""}, finding.Table.Rows[1].Cells)
+	require.Equal(t, float64(4), finding.Metrics[0].Value)
+}
+
+func Test_DataChecksums_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogSettings",
+			[]string{
+				"name text", "setting text", "unit text", "source text", "boot_val text",
+				"reset_val text", "context text", "vartype text", "pending_restart bool",
+			},
+			[]any{"data_checksums", "on", nil, "default", "on", "on", "internal", "bool", "f"},
+		).
+		Add("ChecksumFailures",
+			[]string{"database_name text", "checksum_failures int8", "last_failure timestamptz"},
+			[]any{"app", 2, nil},
+		)
+
+	report, err := datachecksums.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "checksums-enabled").Severity)
+	require.Equal(t, []string{"app", "2", ""}, checktest.Finding(t, report, "checksum-failures").Table.Rows[0].Cells)
+}
+
+func Test_DataChecksums_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := datachecksums.New(&mockDataChecksumsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "data-checksums")
+}
+
+func Test_DataChecksums_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := datachecksums.Metadata()
+
+	require.Equal(t, "data-checksums", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.Equal(t, 12, metadata.MinPGVersion)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
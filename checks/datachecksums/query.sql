@@ -0,0 +1,12 @@
+-- name: ChecksumFailures :many
+-- Databases where a data checksum did not match when a page was read since
+-- the statistics were reset (PostgreSQL 12+). The row with a NULL datname
+-- counts failures in shared catalogs. checksum_failures is NULL when data
+-- checksums are disabled.
+SELECT
+  coalesce(d.datname, '')::text AS database_name
+  , d.checksum_failures::bigint AS checksum_failures
+  , d.checksum_last_failure AS last_failure
+FROM pg_catalog.pg_stat_database AS d
+WHERE d.checksum_failures > 0
+ORDER BY d.checksum_failures DESC, database_name;
@@ -0,0 +1,354 @@
+// Package deadlocks implements a check for deadlock and recovery conflict rates.
+package deadlocks
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// minWindowSeconds skips databases whose statistics were reset too
+	// recently for a rate to mean anything.
+	minWindowSeconds = 3600
+
+	defaultDeadlockWarnPerDay = 1  // Any regular deadlock is an application bug
+	defaultDeadlockFailPerDay = 24 // One an hour
+	defaultConflictWarnPerDay = 10
+	defaultConflictFailPerDay = 100
+
+	// max_standby_streaming_delay worth suggesting when conflicts cancel
+	// queries sooner: long enough for most reports, short enough to bound lag.
+	suggestedStreamingDelaySeconds = 300
+)
+
+// deadlockRemediation points at the server log: PostgreSQL logs both
+// statements of every deadlock it breaks.
+const deadlockRemediation = "Find the deadlocked statements in the server log (each deadlock is logged with the queries involved) " +
+	"and make the transactions lock rows and tables in a consistent order"
+
+// unboundedDelayRemediation restores a bound: with -1 a single long query
+// stops replay for as long as it runs.
+const unboundedDelayRemediation = "Set max_standby_streaming_delay to a bound the standby may lag by (e.g. 5min): " +
+	"queries running longer are cancelled instead of stopping replay"
+
+type DeadlocksQueries interface {
+	DeadlockStats(context.Context) ([]db.DeadlockStatsRow, error)
+}
+
+type checker struct {
+	queries            DeadlocksQueries
+	deadlockWarnPerDay float64 // default: 1
+	deadlockFailPerDay float64 // default: 24
+	conflictWarnPerDay float64 // default: 10
+	conflictFailPerDay float64 // default: 100
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryPerformance,
+		CheckID:     "deadlocks",
+		Name:        "Deadlocks and Conflicts",
+		Description: "Reports deadlock and standby recovery conflict rates since statistics were reset",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries DeadlocksQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:            queries,
+		deadlockWarnPerDay: defaultDeadlockWarnPerDay,
+		deadlockFailPerDay: defaultDeadlockFailPerDay,
+		conflictWarnPerDay: defaultConflictWarnPerDay,
+		conflictFailPerDay: defaultConflictFailPerDay,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["deadlock_warn_per_day"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.deadlockWarnPerDay = f
+				}
+			}
+			if v, ok := myCfg["deadlock_fail_per_day"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.deadlockFailPerDay = f
+				}
+			}
+			if v, ok := myCfg["conflict_warn_per_day"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.conflictWarnPerDay = f
+				}
+			}
+			if v, ok := myCfg["conflict_fail_per_day"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.conflictFailPerDay = f
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.DeadlockStats(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	var measured []db.DeadlockStatsRow
+	for _, row := range rows {
+		if row.SecondsSinceReset >= minWindowSeconds {
+			measured = append(measured, row)
+		}
+	}
+	if len(measured) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "Statistics reset too recently. Need at least 1 hour of data.",
+		})
+		return report, nil
+	}
+
+	provider := check.ProviderFromContext(ctx)
+	c.checkDeadlocks(measured, provider, report)
+	c.checkRecoveryConflicts(measured, provider, report)
+	if measured[0].IsStandby {
+		checkStandbySettings(measured[0], report)
+	}
+
+	return report, nil
+}
+
+func (c *checker) checkDeadlocks(rows []db.DeadlockStatsRow, provider check.Provider, report *check.Report) {
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	var total int64
+	maxRate := 0.0
+	for _, row := range rows {
+		if row.Deadlocks == 0 {
+			continue
+		}
+		rate := perDay(row.Deadlocks, row.SecondsSinceReset)
+		rowSeverity := rateSeverity(rate, c.deadlockWarnPerDay, c.deadlockFailPerDay)
+		severity = max(severity, rowSeverity)
+		total += row.Deadlocks
+		maxRate = max(maxRate, rate)
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.DatabaseName,
+				check.FormatNumber(row.Deadlocks),
+				formatRate(rate),
+				check.FormatDurationSec(row.SecondsSinceReset),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "deadlock-rate",
+			Name:     "Deadlock Rate",
+			Severity: check.SeverityOK,
+			Details:  "No deadlocks since statistics were reset",
+		})
+		return
+	}
+
+	// A few deadlocks over months stay listed, but only a rate raises severity.
+	details := fmt.Sprintf("%s deadlock(s) in %d database(s), up to %s",
+		check.FormatNumber(total), len(tableRows), formatRate(maxRate))
+
+	remediation, fixSQL := deadlockRemediation, ""
+	settings := rows[0]
+	if settings.LogLockWaits != "on" {
+		details += fmt.Sprintf("; log_lock_waits is off, so lock waits longer than deadlock_timeout (%s) are not logged",
+			settings.DeadlockTimeout)
+		var settingRemediation string
+		settingRemediation, fixSQL = provider.SettingFix("log_lock_waits", "on")
+		remediation += ". " + settingRemediation
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "deadlock-rate",
+		Name:        "Deadlock Rate",
+		Severity:    severity,
+		Details:     details,
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+		Metrics: []check.Metric{
+			{Name: "deadlocks", Value: float64(total), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Database", "Deadlocks", "Rate", "Since Reset"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkRecoveryConflicts reports queries cancelled on a standby because they
+// conflicted with WAL replay. Primaries never have any.
+func (c *checker) checkRecoveryConflicts(rows []db.DeadlockStatsRow, provider check.Provider, report *check.Report) {
+	if !rows[0].IsStandby {
+		report.AddFinding(check.Finding{
+			ID:       "recovery-conflict-rate",
+			Name:     "Recovery Conflict Rate",
+			Severity: check.SeveritySkip,
+			Details:  "Not a standby: recovery conflicts only happen during replay",
+		})
+		return
+	}
+
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	var total, snapshot int64
+	maxRate := 0.0
+	for _, row := range rows {
+		snapshot += row.ConflSnapshot
+		conflicts := row.ConflSnapshot + row.ConflLock + row.ConflBufferpin + row.ConflDeadlock + row.ConflTablespace
+		if conflicts == 0 {
+			continue
+		}
+		rate := perDay(conflicts, row.SecondsSinceReset)
+		rowSeverity := rateSeverity(rate, c.conflictWarnPerDay, c.conflictFailPerDay)
+		severity = max(severity, rowSeverity)
+		total += conflicts
+		maxRate = max(maxRate, rate)
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.DatabaseName,
+				check.FormatNumber(row.ConflSnapshot),
+				check.FormatNumber(row.ConflLock),
+				check.FormatNumber(row.ConflBufferpin),
+				check.FormatNumber(row.ConflDeadlock + row.ConflTablespace),
+				formatRate(rate),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "recovery-conflict-rate",
+			Name:     "Recovery Conflict Rate",
+			Severity: check.SeverityOK,
+			Details:  "No queries cancelled by recovery conflicts since statistics were reset",
+		})
+		return
+	}
+
+	details := fmt.Sprintf("%s quer(ies) cancelled by recovery conflicts in %d database(s), up to %s",
+		check.FormatNumber(total), len(tableRows), formatRate(maxRate))
+	remediation, fixSQL := conflictFix(rows[0], snapshot*2 > total, provider)
+
+	report.AddFinding(check.Finding{
+		ID:          "recovery-conflict-rate",
+		Name:        "Recovery Conflict Rate",
+		Severity:    severity,
+		Details:     details,
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+		Metrics: []check.Metric{
+			{Name: "recovery_conflicts", Value: float64(total), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Database", "Snapshot", "Lock", "Buffer Pin", "Other", "Rate"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// conflictFix tunes the standby for the dominant cause: hot_standby_feedback
+// prevents snapshot conflicts, while lock and buffer pin conflicts can only be
+// waited out with a longer max_standby_streaming_delay.
+func conflictFix(settings db.DeadlockStatsRow, mostlySnapshot bool, provider check.Provider) (remediation, fixSQL string) {
+	if mostlySnapshot && settings.HotStandbyFeedback != "on" {
+		remediation, fixSQL = provider.SettingFix("hot_standby_feedback", "on")
+		return "Most cancellations are snapshot conflicts: vacuum on the primary removed rows standby queries still read. " +
+			remediation + " (at the cost of some bloat on the primary while long standby queries run)", fixSQL
+	}
+
+	delaySeconds := settings.MaxStandbyStreamingDelayMs / 1000
+	suggested := max(delaySeconds*2, suggestedStreamingDelaySeconds)
+	remediation, fixSQL = provider.SettingFix("max_standby_streaming_delay", formatSetting(suggested))
+	return fmt.Sprintf("Replay cancels queries conflicting with it after max_standby_streaming_delay (%s). ",
+		check.FormatDurationSec(delaySeconds)) + remediation + " to let them finish, accepting that replay lags while they run", fixSQL
+}
+
+// checkStandbySettings warns when replay waits for conflicting queries
+// without limit, so one long query stops replication.
+func checkStandbySettings(settings db.DeadlockStatsRow, report *check.Report) {
+	delay := "-1 (wait forever)"
+	if settings.MaxStandbyStreamingDelayMs >= 0 {
+		delay = check.FormatDurationSec(settings.MaxStandbyStreamingDelayMs / 1000)
+	}
+	details := fmt.Sprintf("hot_standby_feedback = %s, max_standby_streaming_delay = %s", settings.HotStandbyFeedback, delay)
+
+	if settings.MaxStandbyStreamingDelayMs >= 0 {
+		report.AddFinding(check.Finding{
+			ID:       "standby-settings",
+			Name:     "Standby Settings",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "standby-settings",
+		Name:        "Standby Settings",
+		Severity:    check.SeverityWarn,
+		Details:     details + ": replay waits for conflicting queries however long they run, so the standby can fall behind without limit",
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "max_standby_streaming_delay"},
+		Remediation: unboundedDelayRemediation,
+	})
+}
+
+// formatSetting renders seconds the way postgresql.conf spells durations.
+func formatSetting(seconds int64) string {
+	if seconds%60 == 0 {
+		return fmt.Sprintf("%dmin", seconds/60)
+	}
+	return fmt.Sprintf("%ds", seconds)
+}
+
+func perDay(count, seconds int64) float64 {
+	return float64(count) * 86400 / float64(seconds)
+}
+
+func rateSeverity(rate, warn, fail float64) check.Severity {
+	switch {
+	case rate >= fail:
+		return check.SeverityFail
+	case rate >= warn:
+		return check.SeverityWarn
+	default:
+		return check.SeverityOK
+	}
+}
+
+func formatRate(rate float64) string {
+	return fmt.Sprintf("%.1f/day", rate)
+}
@@ -0,0 +1,302 @@
+package deadlocks_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/deadlocks"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+const day = 86400
+
+type mockDeadlocksQueryer struct {
+	rows []db.DeadlockStatsRow
+	err  error
+}
+
+func (m *mockDeadlocksQueryer) DeadlockStats(context.Context) ([]db.DeadlockStatsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func stats(name string, deadlocks, seconds int64) db.DeadlockStatsRow {
+	return db.DeadlockStatsRow{
+		DatabaseName:               name,
+		Deadlocks:                  deadlocks,
+		SecondsSinceReset:          seconds,
+		LogLockWaits:               "on",
+		DeadlockTimeout:            "1s",
+		HotStandbyFeedback:         "off",
+		MaxStandbyStreamingDelayMs: 30000,
+	}
+}
+
+func standby(row db.DeadlockStatsRow, snapshotConflicts, lockConflicts int64) db.DeadlockStatsRow {
+	row.IsStandby = true
+	row.ConflSnapshot = snapshotConflicts
+	row.ConflLock = lockConflicts
+	return row
+}
+
+func Test_Deadlocks(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.DeadlockStatsRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no deadlocks - OK",
+			Rows:             []db.DeadlockStatsRow{stats("app", 0, 30*day)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No deadlocks since statistics were reset",
+		},
+		{
+			Name:             "occasional deadlocks stay below the rate - OK",
+			Rows:             []db.DeadlockStatsRow{stats("app", 3, 30*day)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "3 deadlock(s) in 1 database(s), up to 0.1/day",
+		},
+		{
+			Name:             "daily deadlocks warn",
+			Rows:             []db.DeadlockStatsRow{stats("app", 14, 7*day)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "14 deadlock(s) in 1 database(s), up to 2.0/day",
+		},
+		{
+			Name: "hourly deadlocks fail",
+			Rows: []db.DeadlockStatsRow{
+				stats("app", 240, 5*day),
+				stats("reports", 1, 5*day),
+			},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "241 deadlock(s) in 2 database(s), up to 48.0/day",
+		},
+		{
+			Name:             "configured thresholds",
+			Rows:             []db.DeadlockStatsRow{stats("app", 3, 30*day)},
+			Config:           check.Config{"deadlocks": {"deadlock_warn_per_day": "0.05", "deadlock_fail_per_day": "0.1"}},
+			ExpectedSeverity: check.SeverityFail,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := deadlocks.New(&mockDeadlocksQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Equal(t, tc.ExpectedSeverity, report.Severity)
+			require.Contains(t, checktest.Finding(t, report, "deadlock-rate").Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_Deadlocks_StatsResetRecently(t *testing.T) {
+	t.Parallel()
+
+	checker := deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{stats("app", 5, 600)}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Contains(t, report.Results[0].Details, "Need at least 1 hour of data")
+}
+
+func Test_Deadlocks_LogLockWaits(t *testing.T) {
+	t.Parallel()
+
+	row := stats("app", 14, 7*day)
+	row.LogLockWaits = "off"
+
+	report, err := deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "deadlock-rate")
+	require.Contains(t, finding.Details, "log_lock_waits is off, so lock waits longer than deadlock_timeout (1s) are not logged")
+	require.Contains(t, finding.Remediation, "Set log_lock_waits to on")
+	require.Equal(t, "ALTER SYSTEM SET log_lock_waits = 'on';\nSELECT pg_reload_conf();", finding.FixSQL)
+	require.Equal(t, []string{"app", "14", "2.0/day", "7d"}, finding.Table.Rows[0].Cells)
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err = deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{row}}).Check(ctx)
+	require.NoError(t, err)
+	require.Empty(t, checktest.Finding(t, report, "deadlock-rate").FixSQL)
+}
+
+func Test_Deadlocks_RecoveryConflicts(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.DeadlockStatsRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "primary - skipped",
+			Rows:             []db.DeadlockStatsRow{stats("app", 0, 30*day)},
+			ExpectedSeverity: check.SeveritySkip,
+			ExpectedDetails:  "Not a standby",
+		},
+		{
+			Name:             "standby without conflicts - OK",
+			Rows:             []db.DeadlockStatsRow{standby(stats("app", 0, 30*day), 0, 0)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No queries cancelled by recovery conflicts",
+		},
+		{
+			Name:             "standby with frequent conflicts",
+			Rows:             []db.DeadlockStatsRow{standby(stats("app", 0, 2*day), 30, 10)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "40 quer(ies) cancelled by recovery conflicts in 1 database(s), up to 20.0/day",
+		},
+		{
+			Name:             "standby with constant conflicts",
+			Rows:             []db.DeadlockStatsRow{standby(stats("app", 0, day), 500, 0)},
+			ExpectedSeverity: check.SeverityFail,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := deadlocks.New(&mockDeadlocksQueryer{rows: tc.Rows}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "recovery-conflict-rate")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_Deadlocks_ConflictTuning(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name                string
+		Row                 db.DeadlockStatsRow
+		ExpectedRemediation string
+		ExpectedFixSQL      string
+	}{
+		{
+			Name:                "snapshot conflicts without feedback",
+			Row:                 standby(stats("app", 0, day), 30, 10),
+			ExpectedRemediation: "Most cancellations are snapshot conflicts",
+			ExpectedFixSQL:      "ALTER SYSTEM SET hot_standby_feedback = 'on';\nSELECT pg_reload_conf();",
+		},
+		{
+			Name:                "lock conflicts",
+			Row:                 standby(stats("app", 0, day), 5, 30),
+			ExpectedRemediation: "Replay cancels queries conflicting with it after max_standby_streaming_delay (30s). Set max_standby_streaming_delay to 5min",
+			ExpectedFixSQL:      "ALTER SYSTEM SET max_standby_streaming_delay = '5min';\nSELECT pg_reload_conf();",
+		},
+		{
+			Name: "snapshot conflicts with feedback on",
+			Row: func() db.DeadlockStatsRow {
+				row := standby(stats("app", 0, day), 30, 0)
+				row.HotStandbyFeedback = "on"
+				row.MaxStandbyStreamingDelayMs = 600000
+				return row
+			}(),
+			ExpectedRemediation: "Set max_standby_streaming_delay to 20min",
+			ExpectedFixSQL:      "ALTER SYSTEM SET max_standby_streaming_delay = '20min';\nSELECT pg_reload_conf();",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{tc.Row}}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "recovery-conflict-rate")
+			require.Contains(t, finding.Remediation, tc.ExpectedRemediation)
+			require.Equal(t, tc.ExpectedFixSQL, finding.FixSQL)
+		})
+	}
+}
+
+func Test_Deadlocks_StandbySettings(t *testing.T) {
+	t.Parallel()
+
+	primary, err := deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{stats("app", 0, day)}}).Check(context.Background())
+	require.NoError(t, err)
+	for _, f := range primary.Results {
+		require.NotEqual(t, "standby-settings", f.ID)
+	}
+
+	row := standby(stats("app", 0, day), 0, 0)
+	report, err := deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "standby-settings")
+	require.Equal(t, check.SeverityOK, finding.Severity)
+	require.Equal(t, "hot_standby_feedback = off, max_standby_streaming_delay = 30s", finding.Details)
+
+	row.MaxStandbyStreamingDelayMs = -1
+	report, err = deadlocks.New(&mockDeadlocksQueryer{rows: []db.DeadlockStatsRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding = checktest.Finding(t, report, "standby-settings")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "max_standby_streaming_delay = -1 (wait forever): replay waits for conflicting queries")
+}
+
+func Test_Deadlocks_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("DeadlockStats",
+		[]string{
+			"database_name text", "deadlocks int8", "confl_snapshot int8", "confl_lock int8",
+			"confl_bufferpin int8", "confl_deadlock int8", "confl_tablespace int8",
+			"seconds_since_reset int8", "is_standby bool", "log_lock_waits text", "deadlock_timeout text",
+			"hot_standby_feedback text", "max_standby_streaming_delay_ms int8",
+		},
+		[]any{"app", 14, 0, 0, 0, 0, 0, 7 * day, "f", "on", "1s", "off", 30000},
+	)
+
+	report, err := deadlocks.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "2.0/day", checktest.Finding(t, report, "deadlock-rate").Table.Rows[0].Cells[2])
+}
+
+func Test_Deadlocks_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := deadlocks.New(&mockDeadlocksQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "deadlocks")
+}
+
+func Test_Deadlocks_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := deadlocks.Metadata()
+
+	require.Equal(t, "deadlocks", metadata.CheckID)
+	require.Equal(t, check.CategoryPerformance, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
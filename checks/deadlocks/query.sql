@@ -0,0 +1,25 @@
+-- name: DeadlockStats :many
+-- Deadlocks and recovery conflicts per database since statistics were last reset.
+-- Databases never reset count from server start.
+SELECT
+  d.datname::text AS database_name
+  , COALESCE(d.deadlocks, 0)::bigint AS deadlocks
+  , COALESCE(c.confl_snapshot, 0)::bigint AS confl_snapshot
+  , COALESCE(c.confl_lock, 0)::bigint AS confl_lock
+  , COALESCE(c.confl_bufferpin, 0)::bigint AS confl_bufferpin
+  , COALESCE(c.confl_deadlock, 0)::bigint AS confl_deadlock
+  , COALESCE(c.confl_tablespace, 0)::bigint AS confl_tablespace
+  , COALESCE(
+    extract(EPOCH FROM (now() - COALESCE(d.stats_reset, pg_postmaster_start_time())))::bigint, 0
+  ) AS seconds_since_reset
+  , pg_is_in_recovery() AS is_standby
+  , current_setting('log_lock_waits') AS log_lock_waits
+  , current_setting('deadlock_timeout') AS deadlock_timeout
+  , current_setting('hot_standby_feedback') AS hot_standby_feedback
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_standby_streaming_delay'), 0) AS max_standby_streaming_delay_ms
+FROM pg_stat_database AS d
+LEFT JOIN pg_stat_database_conflicts AS c ON d.datid = c.datid
+WHERE
+  d.datname IS NOT NULL
+  AND d.datname NOT IN ('template0', 'template1')
+ORDER BY d.deadlocks DESC, d.datname;
@@ -9,6 +9,7 @@ import (
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 )
 
 //go:embed query.sql
@@ -57,6 +58,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.DuplicateIndexesRow) string { return r.TableName.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -67,30 +69,84 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkExactDuplicates(rows, report)
-	checkPrefixDuplicates(rows, report)
+	checkExactDuplicates(ctx, rows, report)
+	checkPrefixDuplicates(ctx, rows, report)
 
 	return report, nil
 }
 
-func checkExactDuplicates(rows []db.DuplicateIndexesRow, report *check.Report) {
+// dropCandidate picks the redundant index of a pair: the shorter index of a
+// prefix pair, and the newer copy of an exact pair unless only that one backs a
+// constraint. ok is false when the candidate backs a constraint, which has to
+// be dropped (or replaced) instead.
+func dropCandidate(row db.DuplicateIndexesRow) (name string, size int64, ok bool) {
+	if row.DuplicateType.String == "exact" && !row.ConstraintB.Bool {
+		return row.IndexNameB.String, row.SizeB.Int64, true
+	}
+	return row.IndexNameA.String, row.SizeA.Int64, !row.ConstraintA.Bool
+}
+
+// drops collects the redundant indexes of the pairs. The query returns one row
+// per pair, so an index can be picked by several pairs: with three identical
+// indexes A, B and C, both (A,C) and (B,C) drop C. Each counts once.
+type drops struct {
+	seen  map[string]bool
+	fixes []string // One DROP statement per index that can be dropped on its own
+	bytes int64    // Reclaimed by running fixes
+}
+
+func newDrops() *drops {
+	return &drops{seen: map[string]bool{}}
+}
+
+// count returns the number of distinct redundant indexes.
+func (d *drops) count() int {
+	return len(d.seen)
+}
+
+// add records the redundant index of row and reports whether it is new.
+func (d *drops) add(row db.DuplicateIndexesRow) bool {
+	name, size, ok := dropCandidate(row)
+	index := pgx.Identifier{row.SchemaName.String, name}.Sanitize()
+	if d.seen[index] {
+		return false
+	}
+	d.seen[index] = true
+	if ok {
+		d.fixes = append(d.fixes, fmt.Sprintf("DROP INDEX CONCURRENTLY IF EXISTS %s;", index))
+		d.bytes += size
+	}
+	return true
+}
+
+// dropAdvice describes which index of the pair to drop.
+func dropAdvice(row db.DuplicateIndexesRow) string {
+	name, _, ok := dropCandidate(row)
+	if !ok {
+		return "backs a constraint: drop the redundant constraint instead"
+	}
+	return "drop " + name
+}
+
+func checkExactDuplicates(ctx context.Context, rows []db.DuplicateIndexesRow, report *check.Report) {
 	var exactDuplicates []string
-	exactCount := 0
+	pairCount := 0
+	dropped := newDrops()
 
 	for _, row := range rows {
 		if row.DuplicateType.String != "exact" {
 			continue
 		}
 
-		exactCount++
+		pairCount++
+		dropped.add(row)
 		if len(exactDuplicates) < 10 {
-			sizeMB := float64(row.SizeA.Int64+row.SizeB.Int64) / (1024 * 1024)
-			exactDuplicates = append(exactDuplicates, fmt.Sprintf("%s: %s <-> %s (%.1f MB total)",
-				row.TableName.String, row.IndexNameA.String, row.IndexNameB.String, sizeMB))
+			exactDuplicates = append(exactDuplicates, fmt.Sprintf("%s: %s <-> %s (%s total), %s",
+				row.TableName.String, row.IndexNameA.String, row.IndexNameB.String, check.FormatSize(ctx, row.SizeA.Int64+row.SizeB.Int64), dropAdvice(row)))
 		}
 	}
 
-	if exactCount == 0 {
+	if pairCount == 0 {
 		report.AddFinding(check.Finding{
 			ID:       "exact-duplicates",
 			Name:     "Exact Duplicate Indexes",
@@ -99,26 +155,31 @@ func checkExactDuplicates(rows []db.DuplicateIndexesRow, report *check.Report) {
 		return
 	}
 
-	details := fmt.Sprintf("Found %d exact duplicate index pairs:\n%s",
-		exactCount,
+	details := fmt.Sprintf("Found %d exact duplicate indexes:\n%s",
+		dropped.count(),
 		strings.Join(exactDuplicates, "\n"),
 	)
-	if exactCount > len(exactDuplicates) {
-		details += fmt.Sprintf("\n... and %d more", exactCount-len(exactDuplicates))
+	if pairCount > len(exactDuplicates) {
+		details += fmt.Sprintf("\n... and %d more", pairCount-len(exactDuplicates))
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "exact-duplicates",
-		Name:     "Exact Duplicate Indexes",
-		Severity: check.SeverityWarn,
-		Details:  details,
+		ID:               "exact-duplicates",
+		Name:             "Exact Duplicate Indexes",
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		ReclaimableBytes: dropped.bytes,
+		Remediation:      "Drop one index of each pair with DROP INDEX CONCURRENTLY",
+		FixSQL:           strings.Join(dropped.fixes, "\n"),
 	})
 }
 
-func checkPrefixDuplicates(rows []db.DuplicateIndexesRow, report *check.Report) {
+func checkPrefixDuplicates(ctx context.Context, rows []db.DuplicateIndexesRow, report *check.Report) {
 	var prefixDuplicates []string
+	pairCount := 0
 	failCount := 0
 	warnCount := 0
+	dropped := newDrops()
 
 	for _, row := range rows {
 		if row.DuplicateType.String != "prefix" {
@@ -127,16 +188,19 @@ func checkPrefixDuplicates(rows []db.DuplicateIndexesRow, report *check.Report)
 
 		sizeMB := float64(row.SizeA.Int64) / (1024 * 1024)
 		isLarge := sizeMB > prefixLargeSizeThresholdMB
-
-		if isLarge {
-			failCount++
-		} else {
-			warnCount++
+		pairCount++
+
+		if dropped.add(row) {
+			if isLarge {
+				failCount++
+			} else {
+				warnCount++
+			}
 		}
 
 		if len(prefixDuplicates) < 10 {
-			prefixDuplicates = append(prefixDuplicates, fmt.Sprintf("%s: %s is prefix of %s (%.1f MB)",
-				row.TableName.String, row.IndexNameA.String, row.IndexNameB.String, sizeMB))
+			prefixDuplicates = append(prefixDuplicates, fmt.Sprintf("%s: %s is prefix of %s (%s), %s",
+				row.TableName.String, row.IndexNameA.String, row.IndexNameB.String, check.FormatSize(ctx, row.SizeA.Int64), dropAdvice(row)))
 		}
 	}
 
@@ -154,14 +218,17 @@ func checkPrefixDuplicates(rows []db.DuplicateIndexesRow, report *check.Report)
 		totalIssues,
 		strings.Join(prefixDuplicates, "\n"),
 	)
-	if totalIssues > len(prefixDuplicates) {
-		details += fmt.Sprintf("\n... and %d more", totalIssues-len(prefixDuplicates))
+	if pairCount > len(prefixDuplicates) {
+		details += fmt.Sprintf("\n... and %d more", pairCount-len(prefixDuplicates))
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "prefix-duplicates",
-		Name:     "Prefix Duplicate Indexes",
-		Severity: check.SeverityWarn,
-		Details:  details,
+		ID:               "prefix-duplicates",
+		Name:             "Prefix Duplicate Indexes",
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		ReclaimableBytes: dropped.bytes,
+		Remediation:      "Drop the shorter index with DROP INDEX CONCURRENTLY once its idx_scan shows the longer one covers its queries",
+		FixSQL:           strings.Join(dropped.fixes, "\n"),
 	})
 }
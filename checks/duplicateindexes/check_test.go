@@ -8,6 +8,7 @@ import (
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/checks/duplicateindexes"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/stretchr/testify/require"
 )
@@ -310,7 +311,12 @@ func Test_DuplicateIndexes_SizeFormatting(t *testing.T) {
 	}
 
 	require.NotNil(t, exactResult)
-	require.Contains(t, exactResult.Details, "200.0 MB", "Should format total size as MB")
+	require.Contains(t, exactResult.Details, "200.0MiB", "Should format total size with the shared byte formatter")
+
+	ctx := check.ContextWithByteUnits(context.Background(), check.UnitsDecimal)
+	report, err = checker.Check(ctx)
+	require.NoError(t, err)
+	require.Contains(t, checktest.Finding(t, report, exactDuplicatesID).Details, "209.7MB", "Should follow the run's byte units")
 }
 
 func Test_DuplicateIndexes_TruncationMessage(t *testing.T) {
@@ -346,6 +352,157 @@ func Test_DuplicateIndexes_TruncationMessage(t *testing.T) {
 	require.Contains(t, exactResult.Details, "... and 5 more", "Should show truncation message")
 }
 
+func Test_DuplicateIndexes_DropSuggestion(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name            string
+		Row             db.DuplicateIndexesRow
+		FindingID       string
+		ExpectedFix     string
+		ExpectedReclaim int64
+	}{
+		{
+			Name: "exact duplicate drops the newer copy",
+			Row: db.DuplicateIndexesRow{
+				IndexNameA:    pgtype.Text{String: "idx_users_email", Valid: true},
+				IndexNameB:    pgtype.Text{String: "idx_users_email_dup", Valid: true},
+				SizeA:         pgtype.Int8{Int64: 4096, Valid: true},
+				SizeB:         pgtype.Int8{Int64: 8192, Valid: true},
+				DuplicateType: pgtype.Text{String: "exact", Valid: true},
+			},
+			FindingID:       exactDuplicatesID,
+			ExpectedFix:     `DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_users_email_dup";`,
+			ExpectedReclaim: 8192,
+		},
+		{
+			Name: "exact duplicate keeps the copy backing a constraint",
+			Row: db.DuplicateIndexesRow{
+				IndexNameA:    pgtype.Text{String: "idx_users_email", Valid: true},
+				IndexNameB:    pgtype.Text{String: "users_email_key", Valid: true},
+				SizeA:         pgtype.Int8{Int64: 4096, Valid: true},
+				SizeB:         pgtype.Int8{Int64: 8192, Valid: true},
+				DuplicateType: pgtype.Text{String: "exact", Valid: true},
+				ConstraintB:   pgtype.Bool{Bool: true, Valid: true},
+			},
+			FindingID:       exactDuplicatesID,
+			ExpectedFix:     `DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_users_email";`,
+			ExpectedReclaim: 4096,
+		},
+		{
+			Name: "exact duplicate constraints get no statement",
+			Row: db.DuplicateIndexesRow{
+				IndexNameA:    pgtype.Text{String: "users_pkey", Valid: true},
+				IndexNameB:    pgtype.Text{String: "users_id_key", Valid: true},
+				DuplicateType: pgtype.Text{String: "exact", Valid: true},
+				ConstraintA:   pgtype.Bool{Bool: true, Valid: true},
+				ConstraintB:   pgtype.Bool{Bool: true, Valid: true},
+			},
+			FindingID: exactDuplicatesID,
+		},
+		{
+			Name: "prefix duplicate drops the shorter index",
+			Row: db.DuplicateIndexesRow{
+				IndexNameA:    pgtype.Text{String: "idx_orders_user", Valid: true},
+				IndexNameB:    pgtype.Text{String: "idx_orders_user_created", Valid: true},
+				SizeA:         pgtype.Int8{Int64: 4096, Valid: true},
+				SizeB:         pgtype.Int8{Int64: 8192, Valid: true},
+				DuplicateType: pgtype.Text{String: "prefix", Valid: true},
+			},
+			FindingID:       prefixDuplicatesID,
+			ExpectedFix:     `DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_orders_user";`,
+			ExpectedReclaim: 4096,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			tc.Row.TableName = pgtype.Text{String: "public.t", Valid: true}
+			tc.Row.SchemaName = pgtype.Text{String: "public", Valid: true}
+			checker := duplicateindexes.New(newMockQueryer([]db.DuplicateIndexesRow{tc.Row}))
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			var finding *check.Finding
+			for _, result := range report.Results {
+				if result.ID == tc.FindingID {
+					finding = &result
+					break
+				}
+			}
+
+			require.NotNil(t, finding)
+			require.Equal(t, tc.ExpectedFix, finding.FixSQL)
+			require.Equal(t, tc.ExpectedReclaim, finding.ReclaimableBytes)
+			if tc.ExpectedFix == "" {
+				require.Contains(t, finding.Details, "backs a constraint")
+			}
+		})
+	}
+}
+
+func Test_DuplicateIndexes_ThreeWayExactDuplicate(t *testing.T) {
+	t.Parallel()
+
+	sizes := map[string]int64{"idx_a": 1000, "idx_b": 2000, "idx_c": 4000}
+	pair := func(a, b string) db.DuplicateIndexesRow {
+		return db.DuplicateIndexesRow{
+			SchemaName:    pgtype.Text{String: "public", Valid: true},
+			TableName:     pgtype.Text{String: "public.users", Valid: true},
+			IndexNameA:    pgtype.Text{String: a, Valid: true},
+			IndexNameB:    pgtype.Text{String: b, Valid: true},
+			SizeA:         pgtype.Int8{Int64: sizes[a], Valid: true},
+			SizeB:         pgtype.Int8{Int64: sizes[b], Valid: true},
+			DuplicateType: pgtype.Text{String: "exact", Valid: true},
+		}
+	}
+
+	// One row per pair, as the query returns them: B and C are both redundant.
+	checker := duplicateindexes.New(newMockQueryer([]db.DuplicateIndexesRow{
+		pair("idx_a", "idx_b"),
+		pair("idx_a", "idx_c"),
+		pair("idx_b", "idx_c"),
+	}))
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, exactDuplicatesID)
+	require.Equal(t, `DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_b";`+"\n"+
+		`DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_c";`, finding.FixSQL)
+	require.Equal(t, int64(6000), finding.ReclaimableBytes)
+	require.Contains(t, finding.Details, "Found 2 exact duplicate indexes")
+}
+
+func Test_DuplicateIndexes_PrefixOfSeveralReclaimedOnce(t *testing.T) {
+	t.Parallel()
+
+	prefixOf := func(longer string) db.DuplicateIndexesRow {
+		return db.DuplicateIndexesRow{
+			SchemaName:    pgtype.Text{String: "public", Valid: true},
+			TableName:     pgtype.Text{String: "public.orders", Valid: true},
+			IndexNameA:    pgtype.Text{String: "idx_orders_user", Valid: true},
+			IndexNameB:    pgtype.Text{String: longer, Valid: true},
+			SizeA:         pgtype.Int8{Int64: 4096, Valid: true},
+			SizeB:         pgtype.Int8{Int64: 8192, Valid: true},
+			DuplicateType: pgtype.Text{String: "prefix", Valid: true},
+		}
+	}
+
+	checker := duplicateindexes.New(newMockQueryer([]db.DuplicateIndexesRow{
+		prefixOf("idx_orders_user_created"),
+		prefixOf("idx_orders_user_status"),
+	}))
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, prefixDuplicatesID)
+	require.Equal(t, int64(4096), finding.ReclaimableBytes)
+	require.Equal(t, `DROP INDEX CONCURRENTLY IF EXISTS "public"."idx_orders_user";`, finding.FixSQL)
+	require.Contains(t, finding.Details, "Found 1 prefix duplicate indexes")
+}
+
 func Test_DuplicateIndexes_QueryError(t *testing.T) {
 	t.Parallel()
 
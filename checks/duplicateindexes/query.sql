@@ -1,7 +1,11 @@
 -- name: DuplicateIndexes :many
 -- Identifies exact and prefix duplicate indexes on the same table.
--- Uses index column positions (indkey) for prefix detection.
--- Excludes: system schemas, invalid indexes, expression/partial indexes for prefix check.
+-- Exact: identical definitions apart from the name (columns, opclasses,
+-- collations, predicate, INCLUDE columns, uniqueness and access method).
+-- Prefix: a non-unique btree index whose key columns, opclasses, collations and
+-- sort options are the leading key columns of another btree index.
+-- Excludes: system schemas, invalid indexes, expression/partial indexes and
+-- indexes with INCLUDE columns for the prefix check.
 WITH index_columns AS (
   SELECT
     idx.indexrelid
@@ -9,11 +13,23 @@ WITH index_columns AS (
     , i.relname AS index_name
     , t.relname AS table_name
     , n.nspname AS schema_name
-    , idx.indkey::int [] AS column_positions
+    , am.amname AS access_method
+    -- Key columns only; slicing renumbers the arrays from 1 for comparison
+    , (idx.indkey::int [])[0:idx.indnkeyatts - 1] AS key_columns
+    , (idx.indclass::oid [])[0:idx.indnkeyatts - 1] AS opclasses
+    , (idx.indcollation::oid [])[0:idx.indnkeyatts - 1] AS collations
+    , (idx.indoption::int [])[0:idx.indnkeyatts - 1] AS sort_options
     , idx.indnkeyatts AS num_key_columns
-    -- Extract column list as array for prefix comparison
+    , idx.indnatts AS num_columns
+    , idx.indisunique AS is_unique
     , pg_get_indexdef(idx.indexrelid) AS index_def
     , pg_relation_size(i.oid) AS index_size_bytes
+    -- Indexes backing a primary key, unique or exclusion constraint cannot be
+    -- dropped on their own
+    , EXISTS (
+      SELECT 1 FROM pg_constraint AS con
+      WHERE con.conindid = idx.indexrelid AND con.contype IN ('p', 'u', 'x')
+    ) AS backs_constraint
     -- Detect expression/partial indexes (cannot reliably compare)
     , (idx.indexprs IS NOT NULL) AS is_expression_index
     , (idx.indpred IS NOT NULL) AS is_partial_index
@@ -21,6 +37,7 @@ WITH index_columns AS (
   INNER JOIN pg_class AS i ON idx.indexrelid = i.oid
   INNER JOIN pg_class AS t ON idx.indrelid = t.oid
   INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+  INNER JOIN pg_am AS am ON i.relam = am.oid
   WHERE
     i.relkind = 'i'
     AND idx.indisvalid
@@ -38,6 +55,8 @@ WITH index_columns AS (
     , b.index_size_bytes AS size_b
     , a.index_def AS definition_a
     , 'exact' AS duplicate_type
+    , a.backs_constraint AS constraint_a
+    , b.backs_constraint AS constraint_b
   FROM index_columns AS a
   INNER JOIN index_columns AS b ON
     a.indrelid = b.indrelid
@@ -48,7 +67,8 @@ WITH index_columns AS (
 
 , prefix_duplicates AS (
   -- Find indexes where one is a left-prefix of another
-  -- e.g., (a) is prefix of (a, b)
+  -- e.g., (a) is prefix of (a, b). A unique prefix enforces something the
+  -- longer index does not, so it is never redundant.
   SELECT
     a.schema_name
     , a.table_name
@@ -58,12 +78,21 @@ WITH index_columns AS (
     , b.index_size_bytes AS size_b
     , a.index_def AS definition_a
     , 'prefix' AS duplicate_type
+    , a.backs_constraint AS constraint_a
+    , b.backs_constraint AS constraint_b
   FROM index_columns AS a
   INNER JOIN index_columns AS b ON
     a.indrelid = b.indrelid
     AND a.indexrelid <> b.indexrelid
     AND a.num_key_columns < b.num_key_columns
-    AND a.column_positions = b.column_positions[0:a.num_key_columns]
+    AND a.key_columns = b.key_columns[1:a.num_key_columns]
+    AND a.opclasses = b.opclasses[1:a.num_key_columns]
+    AND a.collations = b.collations[1:a.num_key_columns]
+    AND a.sort_options = b.sort_options[1:a.num_key_columns]
+    AND a.access_method = 'btree'
+    AND b.access_method = 'btree'
+    AND NOT a.is_unique
+    AND a.num_columns = a.num_key_columns
     AND NOT a.is_expression_index
     AND NOT b.is_expression_index
     AND NOT a.is_partial_index
@@ -78,6 +107,9 @@ SELECT
   , size_b
   , definition_a::text
   , duplicate_type::text
+  , schema_name::text
+  , constraint_a
+  , constraint_b
 FROM (
   SELECT
     schema_name
@@ -88,6 +120,8 @@ FROM (
     , size_b
     , definition_a
     , duplicate_type
+    , constraint_a
+    , constraint_b
   FROM exact_duplicates
   UNION ALL
   SELECT
@@ -99,6 +133,8 @@ FROM (
     , size_b
     , definition_a
     , duplicate_type
+    , constraint_a
+    , constraint_b
   FROM prefix_duplicates
 ) AS all_duplicates
 ORDER BY
@@ -0,0 +1,165 @@
+// Package durability implements checks for settings that trade crash safety for speed.
+package durability
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+// durabilitySetting is a setting whose unsafe value risks data on a crash.
+type durabilitySetting struct {
+	name     string
+	id       string
+	title    string
+	unsafe   string
+	severity check.Severity
+	risk     string
+}
+
+var durabilitySettings = []durabilitySetting{
+	{
+		name:     "fsync",
+		id:       "fsync",
+		title:    "fsync",
+		unsafe:   "off",
+		severity: check.SeverityFail,
+		risk: "PostgreSQL does not force writes to disk, so a power loss or operating system crash " +
+			"can leave the data files corrupted beyond what WAL replay can repair",
+	},
+	{
+		name:     "full_page_writes",
+		id:       "full-page-writes",
+		title:    "Full Page Writes",
+		unsafe:   "off",
+		severity: check.SeverityWarn,
+		risk: "a crash in the middle of writing a page leaves a torn page that WAL replay cannot repair, " +
+			"unless the storage guarantees atomic page writes",
+	},
+	{
+		name:     "synchronous_commit",
+		id:       "synchronous-commit",
+		title:    "Synchronous Commit",
+		unsafe:   "off",
+		severity: check.SeverityWarn,
+		risk: "a crash loses the most recent transactions already reported as committed (up to three times wal_writer_delay), " +
+			"although the database stays consistent",
+	},
+}
+
+// scopedSources are pg_settings sources narrower than the whole cluster: a
+// role or database opting out of synchronous commit is a deliberate choice.
+var scopedSources = []string{"database", "user", "database user", "client", "session"}
+
+type DurabilityQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries      DurabilityQueries
+	acknowledged []string
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "durability",
+		Name:        "Durability Settings",
+		Description: "Flags fsync, full_page_writes and synchronous_commit turned off",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries DurabilityQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries: queries,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if acknowledged, ok := myCfg["acknowledged"]; ok {
+				for _, name := range strings.Split(acknowledged, ",") {
+					c.acknowledged = append(c.acknowledged, strings.TrimSpace(name))
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+	provider := check.ProviderFromContext(ctx)
+
+	for _, s := range durabilitySettings {
+		c.checkSetting(s, settings[s.name], provider, report)
+	}
+
+	return report, nil
+}
+
+// checkSetting flags a setting at its unsafe value. Acknowledging it in the
+// configuration lowers the severity one step, so fsync=off never reads as OK.
+func (c *checker) checkSetting(s durabilitySetting, row db.CatalogSettingsRow, provider check.Provider, report *check.Report) {
+	value := row.Setting.String
+	if value != s.unsafe || slices.Contains(scopedSources, row.Source.String) {
+		details := fmt.Sprintf("%s = %s", s.name, displayValue(value))
+		if value == s.unsafe {
+			details += fmt.Sprintf(" (set at %s level only)", row.Source.String)
+		}
+		report.AddFinding(check.Finding{
+			ID:       s.id,
+			Name:     s.title,
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	severity := s.severity
+	details := fmt.Sprintf("%s is %s: %s", s.name, value, s.risk)
+	if slices.Contains(c.acknowledged, s.name) {
+		severity = check.SeverityOK
+		if s.severity == check.SeverityFail {
+			severity = check.SeverityWarn
+		}
+		details += " (acknowledged in configuration)"
+	}
+
+	remediation, fixSQL := provider.SettingFix(s.name, "on")
+	report.AddFinding(check.Finding{
+		ID:          s.id,
+		Name:        s.title,
+		Severity:    severity,
+		Details:     details,
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: s.name},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+	})
+}
+
+func displayValue(value string) string {
+	if value == "" {
+		return "unknown"
+	}
+	return value
+}
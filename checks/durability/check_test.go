@@ -0,0 +1,181 @@
+package durability_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/durability"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockDurabilityQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockDurabilityQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func setting(name, value, source string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+		Source:  pgtype.Text{String: source, Valid: true},
+	}
+}
+
+func settings(fsync, fullPageWrites, synchronousCommit string) []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{
+		setting("fsync", fsync, "configuration file"),
+		setting("full_page_writes", fullPageWrites, "configuration file"),
+		setting("synchronous_commit", synchronousCommit, "configuration file"),
+	}
+}
+
+func Test_Durability(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Settings         []db.CatalogSettingsRow
+		Config           check.Config
+		FindingID        string
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "defaults - OK",
+			Settings:         settings("on", "on", "on"),
+			FindingID:        "fsync",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "fsync = on",
+		},
+		{
+			Name:             "fsync off fails",
+			Settings:         settings("off", "on", "on"),
+			FindingID:        "fsync",
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "fsync is off: PostgreSQL does not force writes to disk",
+		},
+		{
+			Name:             "acknowledged fsync off still warns",
+			Settings:         settings("off", "on", "on"),
+			Config:           check.Config{"durability": {"acknowledged": "fsync"}},
+			FindingID:        "fsync",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "(acknowledged in configuration)",
+		},
+		{
+			Name:             "full_page_writes off warns",
+			Settings:         settings("on", "off", "on"),
+			FindingID:        "full-page-writes",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "full_page_writes is off: a crash in the middle of writing a page leaves a torn page",
+		},
+		{
+			Name:             "synchronous_commit off cluster-wide warns",
+			Settings:         settings("on", "on", "off"),
+			FindingID:        "synchronous-commit",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "synchronous_commit is off: a crash loses the most recent transactions",
+		},
+		{
+			Name:             "acknowledged synchronous_commit off - OK",
+			Settings:         settings("on", "on", "off"),
+			Config:           check.Config{"durability": {"acknowledged": "full_page_writes, synchronous_commit"}},
+			FindingID:        "synchronous-commit",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "(acknowledged in configuration)",
+		},
+		{
+			Name: "synchronous_commit off for one role - OK",
+			Settings: []db.CatalogSettingsRow{
+				setting("fsync", "on", "default"),
+				setting("full_page_writes", "on", "default"),
+				setting("synchronous_commit", "off", "user"),
+			},
+			FindingID:        "synchronous-commit",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "synchronous_commit = off (set at user level only)",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := durability.New(&mockDurabilityQueryer{settings: tc.Settings}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 3)
+			finding := checktest.Finding(t, report, tc.FindingID)
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_Durability_Remediation(t *testing.T) {
+	t.Parallel()
+
+	report, err := durability.New(&mockDurabilityQueryer{settings: settings("off", "on", "on")}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "fsync")
+	require.Equal(t, "Set fsync to on and reload the configuration", finding.Remediation)
+	require.Equal(t, "ALTER SYSTEM SET fsync = 'on';\nSELECT pg_reload_conf();", finding.FixSQL)
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderCloudSQL)
+	report, err = durability.New(&mockDurabilityQueryer{settings: settings("off", "on", "on")}).Check(ctx)
+	require.NoError(t, err)
+	require.Empty(t, checktest.Finding(t, report, "fsync").FixSQL)
+}
+
+func Test_Durability_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"fsync", "on", nil, "default", "on", "on", "sighup", "bool", "f"},
+		[]any{"full_page_writes", "on", nil, "default", "on", "on", "sighup", "bool", "f"},
+		[]any{"synchronous_commit", "off", nil, "configuration file", "on", "off", "user", "enum", "f"},
+	)
+
+	report, err := durability.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "synchronous-commit").Severity)
+}
+
+func Test_Durability_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := durability.New(&mockDurabilityQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "durability")
+}
+
+func Test_Durability_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := durability.Metadata()
+
+	require.Equal(t, "durability", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
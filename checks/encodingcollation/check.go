@@ -0,0 +1,264 @@
+// Package encodingcollation implements checks for database encodings and
+// collations: SQL_ASCII databases, collation versions that changed under
+// existing indexes, and databases mixing several collations.
+package encodingcollation
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// pg_database records the version of its default collation from PostgreSQL 15.
+const databaseCollationVersion = 150000
+
+const sqlASCIIRemediation = "Dump the database and restore it into a new one created with ENCODING 'UTF8', " +
+	"fixing rows whose bytes are not valid UTF-8 first"
+
+const versionRemediation = "REINDEX the indexes on text columns that use the collation, then record the new version " +
+	"with ALTER COLLATION ... REFRESH VERSION or ALTER DATABASE ... REFRESH COLLATION VERSION"
+
+const mixedRemediation = "Use the database default collation unless a column needs different sorting, and add COLLATE to " +
+	"the queries that compare columns with different collations"
+
+var providerNames = map[string]string{
+	"b": "builtin",
+	"c": "libc",
+	"d": "default",
+	"i": "icu",
+}
+
+type EncodingCollationQueries interface {
+	DatabaseEncodings(context.Context) ([]db.DatabaseEncodingsRow, error)
+	DatabaseCollationVersions(context.Context) ([]db.DatabaseCollationVersionsRow, error)
+	CollationVersionMismatches(context.Context) ([]db.CollationVersionMismatchesRow, error)
+	ColumnCollations(context.Context) ([]db.ColumnCollationsRow, error)
+}
+
+type checker struct {
+	queries EncodingCollationQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryConfigs,
+		CheckID:      "encoding-collation",
+		Name:         "Encoding and Collation",
+		Description:  "Flags SQL_ASCII databases, collation version mismatches and mixed collations",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 10,
+	}
+}
+
+func New(queries EncodingCollationQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	databases, err := c.queries.DatabaseEncodings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	databaseVersions, err := c.databaseVersions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	collationVersions, err := c.queries.CollationVersionMismatches(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	collations, err := c.queries.ColumnCollations(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	checkSQLASCII(databases, report)
+	checkCollationVersions(databaseVersions, collationVersions, report)
+	checkMixedCollations(collations, report)
+
+	return report, nil
+}
+
+// databaseVersions reads the database collation versions, which only exist
+// from PostgreSQL 15.
+func (c *checker) databaseVersions(ctx context.Context) ([]db.DatabaseCollationVersionsRow, error) {
+	if version := check.ServerVersionFromContext(ctx); version != 0 && version < databaseCollationVersion {
+		return nil, nil
+	}
+	return c.queries.DatabaseCollationVersions(ctx)
+}
+
+// checkSQLASCII warns on databases using SQL_ASCII, which stores whatever
+// bytes clients send without validating or converting them.
+func checkSQLASCII(databases []db.DatabaseEncodingsRow, report *check.Report) {
+	var tableRows []check.TableRow
+	for _, database := range databases {
+		if database.Encoding != "SQL_ASCII" {
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{database.DatabaseName, database.Encoding, database.LcCollate},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "sql-ascii",
+			Name:     "SQL_ASCII Encoding",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("None of %d database(s) uses SQL_ASCII", len(databases)),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "sql-ascii",
+		Name:     "SQL_ASCII Encoding",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d database(s) use SQL_ASCII: text is stored as raw bytes without validation, "+
+			"so one table can hold several encodings and clients cannot get their data converted", len(tableRows)),
+		Remediation: sqlASCIIRemediation,
+		Table: &check.Table{
+			Headers: []string{"Database", "Encoding", "Collation"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkCollationVersions fails when a database default collation or a
+// collation in use reports a different version than the one recorded when
+// it was created: indexes sorted by the old rules can miss rows or accept
+// duplicates in unique indexes.
+func checkCollationVersions(databases []db.DatabaseCollationVersionsRow, collations []db.CollationVersionMismatchesRow, report *check.Report) {
+	if len(databases) == 0 && len(collations) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "collation-versions",
+			Name:     "Collation Versions",
+			Severity: check.SeverityOK,
+			Details:  "Every collation in use reports the version it was created with",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(databases)+len(collations))
+	for _, database := range databases {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{"database " + database.DatabaseName, database.RecordedVersion, actualVersion(database.ActualVersion), "default collation"},
+			Severity: check.SeverityFail,
+		})
+	}
+	for _, collation := range collations {
+		provider := providerNames[collation.Provider]
+		if provider == "" {
+			provider = collation.Provider
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				fmt.Sprintf("collation %s.%s (%s)", collation.SchemaName, collation.CollationName, provider),
+				collation.RecordedVersion,
+				actualVersion(collation.ActualVersion),
+				fmt.Sprintf("%d column(s), %d index(es)", collation.Columns, collation.Indexes),
+			},
+			Severity: check.SeverityFail,
+		})
+	}
+
+	var objects []string
+	if len(databases) > 0 {
+		objects = append(objects, fmt.Sprintf("%d database(s)", len(databases)))
+	}
+	if len(collations) > 0 {
+		objects = append(objects, fmt.Sprintf("%d collation(s)", len(collations)))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "collation-versions",
+		Name:     "Collation Versions",
+		Severity: check.SeverityFail,
+		Details: fmt.Sprintf("%s changed collation version since they were created, usually after an operating system "+
+			"or ICU upgrade: text indexes sorted by the old rules can return wrong results and let duplicates into unique indexes",
+			strings.Join(objects, " and ")),
+		Remediation: versionRemediation,
+		Table: &check.Table{
+			Headers: []string{"Object", "Recorded Version", "Actual Version", "Used By"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "collation_version_mismatches", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	})
+}
+
+func actualVersion(version string) string {
+	if version == "" {
+		return "unknown"
+	}
+	return version
+}
+
+// checkMixedCollations warns when columns use more than one collation:
+// comparing two of them needs an explicit COLLATE, and an index only helps
+// queries that sort or compare with its own collation.
+func checkMixedCollations(collations []db.ColumnCollationsRow, report *check.Report) {
+	if len(collations) <= 1 {
+		details := "No collatable columns"
+		if len(collations) == 1 {
+			details = fmt.Sprintf("All %d collatable column(s) use the %s collation", collations[0].Columns, collations[0].CollationName)
+		}
+		report.AddFinding(check.Finding{
+			ID:       "mixed-collations",
+			Name:     "Mixed Collations",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(collations))
+	for _, collation := range collations {
+		severity := check.SeverityWarn
+		if collation.CollationName == "default" {
+			severity = check.SeverityOK
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{collation.CollationName, check.FormatNumber(collation.Columns), collation.ExampleColumn},
+			Severity: severity,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "mixed-collations",
+		Name:     "Mixed Collations",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("Columns use %d different collations: comparing columns with different collations fails "+
+			"without an explicit COLLATE, and indexes only serve queries using their own collation", len(collations)),
+		Remediation: mixedRemediation,
+		Table: &check.Table{
+			Headers: []string{"Collation", "Columns", "Example"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "column_collations", Value: float64(len(collations)), Unit: check.UnitCount},
+		},
+	})
+}
@@ -0,0 +1,206 @@
+package encodingcollation_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/encodingcollation"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockEncodingCollationQueryer struct {
+	version           int
+	databases         []db.DatabaseEncodingsRow
+	databaseVersions  []db.DatabaseCollationVersionsRow
+	collationVersions []db.CollationVersionMismatchesRow
+	collations        []db.ColumnCollationsRow
+	err               error
+}
+
+func (m *mockEncodingCollationQueryer) DatabaseEncodings(context.Context) ([]db.DatabaseEncodingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.databases, nil
+}
+
+func (m *mockEncodingCollationQueryer) DatabaseCollationVersions(context.Context) ([]db.DatabaseCollationVersionsRow, error) {
+	if m.version < 150000 {
+		return nil, fmt.Errorf(`column "datcollversion" does not exist`)
+	}
+	return m.databaseVersions, nil
+}
+
+func (m *mockEncodingCollationQueryer) CollationVersionMismatches(context.Context) ([]db.CollationVersionMismatchesRow, error) {
+	return m.collationVersions, nil
+}
+
+func (m *mockEncodingCollationQueryer) ColumnCollations(context.Context) ([]db.ColumnCollationsRow, error) {
+	return m.collations, nil
+}
+
+func database(name, encoding string) db.DatabaseEncodingsRow {
+	return db.DatabaseEncodingsRow{DatabaseName: name, Encoding: encoding, LcCollate: "en_US.UTF-8", LcCtype: "en_US.UTF-8"}
+}
+
+func Test_EncodingCollation(t *testing.T) {
+	t.Parallel()
+
+	healthy := mockEncodingCollationQueryer{
+		version:    160000,
+		databases:  []db.DatabaseEncodingsRow{database("app", "UTF8"), database("postgres", "UTF8")},
+		collations: []db.ColumnCollationsRow{{CollationName: "default", Columns: 120, ExampleColumn: "public.users.email"}},
+	}
+
+	tests := []struct {
+		name     string
+		mutate   func(m *mockEncodingCollationQueryer)
+		expected map[string]check.Severity
+	}{
+		{
+			name:   "healthy database",
+			mutate: func(*mockEncodingCollationQueryer) {},
+			expected: map[string]check.Severity{
+				"sql-ascii":          check.SeverityOK,
+				"collation-versions": check.SeverityOK,
+				"mixed-collations":   check.SeverityOK,
+			},
+		},
+		{
+			name: "SQL_ASCII database",
+			mutate: func(m *mockEncodingCollationQueryer) {
+				m.databases = append(m.databases, database("legacy", "SQL_ASCII"))
+			},
+			expected: map[string]check.Severity{"sql-ascii": check.SeverityWarn},
+		},
+		{
+			name: "database collation version changed",
+			mutate: func(m *mockEncodingCollationQueryer) {
+				m.databaseVersions = []db.DatabaseCollationVersionsRow{{DatabaseName: "app", RecordedVersion: "2.31", ActualVersion: "2.36"}}
+			},
+			expected: map[string]check.Severity{"collation-versions": check.SeverityFail},
+		},
+		{
+			name: "database versions ignored before PostgreSQL 15",
+			mutate: func(m *mockEncodingCollationQueryer) {
+				m.version = 140000
+			},
+			expected: map[string]check.Severity{"collation-versions": check.SeverityOK},
+		},
+		{
+			name: "collation version changed",
+			mutate: func(m *mockEncodingCollationQueryer) {
+				m.version = 140000
+				m.collationVersions = []db.CollationVersionMismatchesRow{{
+					SchemaName: "pg_catalog", CollationName: "en-x-icu", Provider: "i",
+					RecordedVersion: "153.14", ActualVersion: "153.112", Columns: 3, Indexes: 2,
+				}}
+			},
+			expected: map[string]check.Severity{"collation-versions": check.SeverityFail},
+		},
+		{
+			name: "columns with an explicit collation",
+			mutate: func(m *mockEncodingCollationQueryer) {
+				m.collations = append(m.collations, db.ColumnCollationsRow{CollationName: `pg_catalog."C"`, Columns: 4, ExampleColumn: "public.skus.code"})
+			},
+			expected: map[string]check.Severity{"mixed-collations": check.SeverityWarn},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := healthy
+			tt.mutate(&queryer)
+			ctx := check.ContextWithServerVersion(context.Background(), queryer.version)
+			report, err := encodingcollation.New(&queryer).Check(ctx)
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 3)
+			for id, severity := range tt.expected {
+				require.Equal(t, severity, checktest.Finding(t, report, id).Severity, id)
+			}
+		})
+	}
+}
+
+func Test_EncodingCollation_VersionDetails(t *testing.T) {
+	t.Parallel()
+
+	report, err := encodingcollation.New(&mockEncodingCollationQueryer{
+		version:          170000,
+		databaseVersions: []db.DatabaseCollationVersionsRow{{DatabaseName: "app", RecordedVersion: "2.31"}},
+		collationVersions: []db.CollationVersionMismatchesRow{{
+			SchemaName: "public", CollationName: "german", Provider: "c",
+			RecordedVersion: "2.31", ActualVersion: "2.36", Columns: 3, Indexes: 2,
+		}},
+	}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "collation-versions")
+	require.Contains(t, finding.Details, "1 database(s) and 1 collation(s)")
+	require.Equal(t, []string{"database app", "2.31", "unknown", "default collation"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"collation public.german (libc)", "2.31", "2.36", "3 column(s), 2 index(es)"}, finding.Table.Rows[1].Cells)
+	require.NotEmpty(t, finding.Remediation)
+}
+
+func Test_EncodingCollation_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("DatabaseEncodings",
+			[]string{"database_name text", "encoding text", "lc_collate text", "lc_ctype text"},
+			[]any{"legacy", "SQL_ASCII", "C", "C"},
+		).
+		Add("DatabaseCollationVersions",
+			[]string{"database_name text", "recorded_version text", "actual_version text"},
+		).
+		Add("CollationVersionMismatches",
+			[]string{
+				"schema_name text", "collation_name text", "provider text", "recorded_version text",
+				"actual_version text", "columns int8", "indexes int8",
+			},
+		).
+		Add("ColumnCollations",
+			[]string{"collation_name text", "columns int8", "example_column text"},
+			[]any{"default", 15000, "public.users.email"},
+			[]any{`pg_catalog."C"`, 12, "public.skus.code"},
+		)
+
+	report, err := encodingcollation.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, []string{"legacy", "SQL_ASCII", "C"}, checktest.Finding(t, report, "sql-ascii").Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "collation-versions").Severity)
+	mixed := checktest.Finding(t, report, "mixed-collations")
+	require.Equal(t, []string{"default", "15.0K", "public.users.email"}, mixed.Table.Rows[0].Cells)
+	require.Equal(t, []check.Severity{check.SeverityOK, check.SeverityWarn}, []check.Severity{mixed.Table.Rows[0].Severity, mixed.Table.Rows[1].Severity})
+}
+
+func Test_EncodingCollation_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := encodingcollation.New(&mockEncodingCollationQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "encoding-collation")
+}
+
+func Test_EncodingCollation_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := encodingcollation.Metadata()
+
+	require.Equal(t, "encoding-collation", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,102 @@
+-- name: DatabaseEncodings :many
+-- Server encoding and default locale of every database that accepts
+-- connections, templates excluded.
+SELECT
+  d.datname::text AS database_name
+  , pg_catalog.pg_encoding_to_char(d.encoding)::text AS encoding
+  , d.datcollate::text AS lc_collate
+  , d.datctype::text AS lc_ctype
+FROM pg_catalog.pg_database AS d
+WHERE
+  d.datallowconn
+  AND NOT d.datistemplate
+ORDER BY d.datname;
+
+-- name: DatabaseCollationVersions :many
+-- Databases whose recorded default collation version differs from the one
+-- the collation provider reports now (PostgreSQL 15+). An empty actual
+-- version means the provider no longer reports one.
+SELECT
+  d.datname::text AS database_name
+  , d.datcollversion::text AS recorded_version
+  , COALESCE(pg_catalog.pg_database_collation_actual_version(d.oid), '')::text AS actual_version
+FROM pg_catalog.pg_database AS d
+WHERE
+  d.datallowconn
+  AND NOT d.datistemplate
+  AND d.datcollversion IS NOT NULL
+  AND pg_catalog.pg_database_collation_actual_version(d.oid) IS DISTINCT FROM d.datcollversion
+ORDER BY d.datname;
+
+-- name: CollationVersionMismatches :many
+-- Collations of the current database whose recorded version differs from
+-- the one the provider reports now, limited to those used by a column or an
+-- index: text indexes built with the old version may be ordered differently
+-- from what lookups expect.
+WITH mismatched AS (
+  SELECT
+    c.oid
+    , n.nspname AS schema_name
+    , c.collname AS collation_name
+    , c.collprovider
+    , c.collversion AS recorded_version
+    , COALESCE(pg_catalog.pg_collation_actual_version(c.oid), '') AS actual_version
+  FROM pg_catalog.pg_collation AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.collnamespace = n.oid
+  WHERE
+    c.collversion IS NOT NULL
+    AND pg_catalog.pg_collation_actual_version(c.oid) IS DISTINCT FROM c.collversion
+)
+
+SELECT
+  m.schema_name::text AS schema_name
+  , m.collation_name::text AS collation_name
+  , m.collprovider::text AS provider
+  , m.recorded_version::text AS recorded_version
+  , m.actual_version::text AS actual_version
+  , (
+    SELECT count(*)
+    FROM pg_catalog.pg_attribute AS a
+    INNER JOIN pg_catalog.pg_class AS t ON a.attrelid = t.oid
+    WHERE
+      a.attcollation = m.oid
+      AND a.attnum > 0
+      AND NOT a.attisdropped
+      AND t.relkind IN ('r', 'p', 'm')
+  ) AS columns
+  , (
+    SELECT count(DISTINCT i.indexrelid)
+    FROM pg_catalog.pg_index AS i
+    WHERE m.oid = ANY(i.indcollation::oid [])
+  ) AS indexes
+FROM mismatched AS m
+WHERE
+  EXISTS (SELECT 1 FROM pg_catalog.pg_attribute AS a WHERE a.attcollation = m.oid AND a.attnum > 0 AND NOT a.attisdropped)
+  OR EXISTS (SELECT 1 FROM pg_catalog.pg_index AS i WHERE m.oid = ANY(i.indcollation::oid []))
+ORDER BY m.schema_name, m.collation_name;
+
+-- name: ColumnCollations :many
+-- Collations used by the collatable columns of user tables and materialized
+-- views, with the number of columns and one example each. Columns without an
+-- explicit collation use the database default (oid 100). Partitions repeat
+-- their parent's columns and are left out.
+SELECT
+  CASE
+    WHEN a.attcollation = 100 THEN 'default'
+    ELSE pg_catalog.quote_ident(cn.nspname) || '.' || pg_catalog.quote_ident(co.collname)
+  END::text AS collation_name
+  , count(*) AS columns
+  , min(n.nspname || '.' || t.relname || '.' || a.attname)::text AS example_column
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS t ON a.attrelid = t.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON t.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_collation AS co ON a.attcollation = co.oid
+INNER JOIN pg_catalog.pg_namespace AS cn ON co.collnamespace = cn.oid
+WHERE
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND t.relkind IN ('r', 'p', 'm')
+  AND NOT t.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+GROUP BY 1
+ORDER BY count(*) DESC, 1;
@@ -0,0 +1,165 @@
+// Package extensions implements checks for installed extensions: versions
+// behind the one the server ships, and extensions installed in public.
+package extensions
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const outdatedRemediation = "Run ALTER EXTENSION ... UPDATE in every database that has the extension, " +
+	"after reading the extension's release notes; reinstall the server packages of extensions whose files are missing"
+
+const publicRemediation = "Move relocatable extensions to a dedicated schema with ALTER EXTENSION ... SET SCHEMA and add it to search_path; " +
+	"others have to be dropped and created again WITH SCHEMA"
+
+type ExtensionsQueries interface {
+	InstalledExtensions(context.Context) ([]db.InstalledExtensionsRow, error)
+}
+
+type checker struct {
+	queries ExtensionsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "extensions",
+		Name:        "Extensions",
+		Description: "Lists extensions older than the version available on the server and extensions installed in the public schema",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries ExtensionsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	extensions, err := c.queries.InstalledExtensions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	checkOutdated(extensions, report)
+	checkPublicSchema(extensions, report)
+
+	return report, nil
+}
+
+// checkOutdated warns on extensions whose installed version is not the
+// server's default: the packages were upgraded, but CREATE EXTENSION only
+// runs once and ALTER EXTENSION ... UPDATE was never run.
+func checkOutdated(extensions []db.InstalledExtensionsRow, report *check.Report) {
+	var tableRows []check.TableRow
+	var fixes []string
+	for _, ext := range extensions {
+		available := ext.DefaultVersion
+		switch {
+		case available == "":
+			available = "files not installed"
+		case ext.InstalledVersion == ext.DefaultVersion:
+			continue
+		default:
+			fixes = append(fixes, fmt.Sprintf("ALTER EXTENSION %s UPDATE;", pgx.Identifier{ext.ExtensionName}.Sanitize()))
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{ext.ExtensionName, ext.InstalledVersion, available},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "outdated-extensions",
+			Name:     "Outdated Extensions",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("All %d extension(s) are at the version available on the server", len(extensions)),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "outdated-extensions",
+		Name:     "Outdated Extensions",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d extension(s) are not at the version available on the server: "+
+			"the bug fixes and functions of the newer version only apply after ALTER EXTENSION ... UPDATE", len(tableRows)),
+		Remediation: outdatedRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Extension", "Installed", "Available"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "outdated_extensions", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// checkPublicSchema warns on extensions installed in public, where their
+// functions mix with application objects and can be shadowed by any role
+// allowed to create objects there.
+func checkPublicSchema(extensions []db.InstalledExtensionsRow, report *check.Report) {
+	var tableRows []check.TableRow
+	for _, ext := range extensions {
+		if ext.SchemaName != "public" {
+			continue
+		}
+		relocatable := "no"
+		if ext.Relocatable {
+			relocatable = "yes"
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{ext.ExtensionName, ext.InstalledVersion, relocatable},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "extensions-in-public",
+			Name:     "Extensions in public",
+			Severity: check.SeverityOK,
+			Details:  "No extension is installed in the public schema",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "extensions-in-public",
+		Name:     "Extensions in public",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d extension(s) are installed in the public schema: their objects mix with the application's, "+
+			"and a role that can create objects in public can shadow their functions", len(tableRows)),
+		Remediation: publicRemediation,
+		Table: &check.Table{
+			Headers: []string{"Extension", "Version", "Relocatable"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "extensions_in_public", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	})
+}
@@ -0,0 +1,142 @@
+package extensions_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/extensions"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockExtensionsQueryer struct {
+	extensions []db.InstalledExtensionsRow
+	err        error
+}
+
+func (m *mockExtensionsQueryer) InstalledExtensions(context.Context) ([]db.InstalledExtensionsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.extensions, nil
+}
+
+func extension(name, schema, installed, available string) db.InstalledExtensionsRow {
+	return db.InstalledExtensionsRow{
+		ExtensionName:    name,
+		SchemaName:       schema,
+		InstalledVersion: installed,
+		DefaultVersion:   available,
+		Relocatable:      true,
+	}
+}
+
+func Test_Extensions(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		extensions []db.InstalledExtensionsRow
+		outdated   check.Severity
+		public     check.Severity
+	}{
+		{
+			name:       "only plpgsql",
+			extensions: []db.InstalledExtensionsRow{extension("plpgsql", "pg_catalog", "1.0", "1.0")},
+			outdated:   check.SeverityOK,
+			public:     check.SeverityOK,
+		},
+		{
+			name:       "extension behind the available version",
+			extensions: []db.InstalledExtensionsRow{extension("pg_stat_statements", "monitoring", "1.9", "1.10")},
+			outdated:   check.SeverityWarn,
+			public:     check.SeverityOK,
+		},
+		{
+			name:       "extension files missing",
+			extensions: []db.InstalledExtensionsRow{extension("pg_partman", "partman", "4.7.0", "")},
+			outdated:   check.SeverityWarn,
+			public:     check.SeverityOK,
+		},
+		{
+			name:       "extension in public",
+			extensions: []db.InstalledExtensionsRow{extension("pgcrypto", "public", "1.3", "1.3")},
+			outdated:   check.SeverityOK,
+			public:     check.SeverityWarn,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := extensions.New(&mockExtensionsQueryer{extensions: tt.extensions}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 2)
+			require.Equal(t, tt.outdated, checktest.Finding(t, report, "outdated-extensions").Severity)
+			require.Equal(t, tt.public, checktest.Finding(t, report, "extensions-in-public").Severity)
+		})
+	}
+}
+
+func Test_Extensions_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	report, err := extensions.New(&mockExtensionsQueryer{extensions: []db.InstalledExtensionsRow{
+		extension("pg_partman", "partman", "4.7.0", ""),
+		extension("pg_stat_statements", "public", "1.9", "1.10"),
+		extension("uuid-ossp", "public", "1.0", "1.1"),
+	}}).Check(context.Background())
+	require.NoError(t, err)
+
+	outdated := checktest.Finding(t, report, "outdated-extensions")
+	require.Equal(t, []string{"pg_partman", "4.7.0", "files not installed"}, outdated.Table.Rows[0].Cells)
+	require.Equal(t, "ALTER EXTENSION \"pg_stat_statements\" UPDATE;\nALTER EXTENSION \"uuid-ossp\" UPDATE;", outdated.FixSQL)
+
+	public := checktest.Finding(t, report, "extensions-in-public")
+	require.Len(t, public.Table.Rows, 2)
+	require.Empty(t, public.FixSQL)
+}
+
+func Test_Extensions_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("InstalledExtensions",
+		[]string{"extension_name text", "schema_name text", "installed_version text", "default_version text", "relocatable bool"},
+		[]any{"plpgsql", "pg_catalog", "1.0", "1.0", "f"},
+		[]any{"postgis", "public", "3.3.2", "3.4.0", "f"},
+	)
+
+	report, err := extensions.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, []string{"postgis", "3.3.2", "3.4.0"}, checktest.Finding(t, report, "outdated-extensions").Table.Rows[0].Cells)
+	require.Equal(t, []string{"postgis", "3.3.2", "no"}, checktest.Finding(t, report, "extensions-in-public").Table.Rows[0].Cells)
+}
+
+func Test_Extensions_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := extensions.New(&mockExtensionsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "extensions")
+}
+
+func Test_Extensions_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := extensions.Metadata()
+
+	require.Equal(t, "extensions", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
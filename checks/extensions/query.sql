@@ -0,0 +1,14 @@
+-- name: InstalledExtensions :many
+-- Extensions installed in the current database, with the version the
+-- server's control file installs by default. default_version is empty when
+-- the extension's files are no longer installed on the server.
+SELECT
+  e.extname::text AS extension_name
+  , n.nspname::text AS schema_name
+  , e.extversion::text AS installed_version
+  , COALESCE(a.default_version, '')::text AS default_version
+  , e.extrelocatable AS relocatable
+FROM pg_catalog.pg_extension AS e
+INNER JOIN pg_catalog.pg_namespace AS n ON e.extnamespace = n.oid
+LEFT JOIN pg_catalog.pg_available_extensions AS a ON e.extname = a.name
+ORDER BY e.extname;
@@ -35,6 +35,9 @@ const (
 	// Table-level thresholds (lower since tables can be vacuumed individually).
 	tableAgeWarnThreshold = int64(400_000_000)
 	tableAgeFailThreshold = int64(800_000_000)
+
+	// maxListedTables caps the table-freeze-age table to the oldest tables.
+	maxListedTables = 50
 )
 
 func Metadata() check.Metadata {
@@ -45,6 +48,7 @@ func Metadata() check.Metadata {
 		Description: "Monitors transaction ID age to prevent wraparound issues",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -70,10 +74,11 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s (tables): %w", check.CategoryVacuum, report.CheckID, err)
 	}
+	tableRows = check.FilterTables(ctx, tableRows, func(r db.TableFreezeAgeRow) string { return r.TableName.String })
 
 	// Run subchecks.
 	checkDatabaseFreezeAge(dbRows, report)
-	checkTableFreezeAge(tableRows, report)
+	checkTableFreezeAge(ctx, tableRows, report)
 
 	return report, nil
 }
@@ -158,7 +163,7 @@ func checkDatabaseFreezeAge(rows []db.DatabaseFreezeAgeRow, report *check.Report
 	})
 }
 
-func checkTableFreezeAge(rows []db.TableFreezeAgeRow, report *check.Report) {
+func checkTableFreezeAge(ctx context.Context, rows []db.TableFreezeAgeRow, report *check.Report) {
 	var critical []db.TableFreezeAgeRow
 	var warning []db.TableFreezeAgeRow
 
@@ -188,7 +193,7 @@ func checkTableFreezeAge(rows []db.TableFreezeAgeRow, report *check.Report) {
 			Cells: []string{
 				row.TableName.String,
 				formatAge(int64(row.FreezeAge.Int32)),
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				formatVacuumTime(row),
 				fmt.Sprintf("%d", row.AutovacuumCount.Int64+row.VacuumCount.Int64),
 			},
@@ -201,7 +206,7 @@ func checkTableFreezeAge(rows []db.TableFreezeAgeRow, report *check.Report) {
 			Cells: []string{
 				row.TableName.String,
 				formatAge(int64(row.FreezeAge.Int32)),
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				formatVacuumTime(row),
 				fmt.Sprintf("%d", row.AutovacuumCount.Int64+row.VacuumCount.Int64),
 			},
@@ -214,11 +219,17 @@ func checkTableFreezeAge(rows []db.TableFreezeAgeRow, report *check.Report) {
 		severity = check.SeverityFail
 	}
 
+	details := fmt.Sprintf("Found %d table(s) with high transaction ID age", len(tableRows))
+	if len(tableRows) > maxListedTables {
+		details += fmt.Sprintf(", showing the oldest %d", maxListedTables)
+		tableRows = tableRows[:maxListedTables]
+	}
+
 	report.AddFinding(check.Finding{
 		ID:       "table-freeze-age",
 		Name:     "Table Freeze Age",
 		Severity: severity,
-		Details:  fmt.Sprintf("Found %d table(s) with high transaction ID age", len(critical)+len(warning)),
+		Details:  details,
 		Table: &check.Table{
 			Headers: []string{"Table", "Age", "Size", "Last Vacuum", "Vacuum Count"},
 			Rows:    tableRows,
@@ -230,6 +230,69 @@ func TestFreezeAge_TableWarning(t *testing.T) {
 	require.Equal(t, check.SeverityWarn, tableFinding.Table.Rows[0].Severity)
 }
 
+func TestFreezeAge_TableScopeAppliedBeforeCap(t *testing.T) {
+	t.Parallel()
+
+	// Older out-of-scope tables come first, as the query orders by age.
+	var tableRows []db.TableFreezeAgeRow
+	for i := range 60 {
+		tableRows = append(tableRows, makeTableRow("audit", fmt.Sprintf("log_%d", i), 900_000_000, 1024*1024, nil, nil, 0, 0))
+	}
+	tableRows = append(tableRows, makeTableRow("billing", "invoices", 500_000_000, 1024*1024, nil, nil, 0, 0))
+
+	queryer := &mockQueryer{
+		dbRows:    []db.DatabaseFreezeAgeRow{makeDatabaseRow("postgres", 100_000_000, 200_000_000)},
+		tableRows: tableRows,
+	}
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{Schemas: []string{"billing"}})
+	report, err := freezeage.New(queryer).Check(ctx)
+	require.NoError(t, err)
+
+	var tableFinding *check.Finding
+	for i := range report.Results {
+		if report.Results[i].ID == findingIDTableFreezeAge {
+			tableFinding = &report.Results[i]
+			break
+		}
+	}
+
+	require.NotNil(t, tableFinding)
+	assert.Equal(t, check.SeverityWarn, tableFinding.Severity)
+	require.Len(t, tableFinding.Table.Rows, 1)
+	assert.Equal(t, "billing.invoices", tableFinding.Table.Rows[0].Cells[0])
+}
+
+func TestFreezeAge_TableListCapped(t *testing.T) {
+	t.Parallel()
+
+	var tableRows []db.TableFreezeAgeRow
+	for i := range 60 {
+		tableRows = append(tableRows, makeTableRow("public", fmt.Sprintf("t_%d", i), 900_000_000, 1024*1024, nil, nil, 0, 0))
+	}
+
+	queryer := &mockQueryer{
+		dbRows:    []db.DatabaseFreezeAgeRow{makeDatabaseRow("postgres", 100_000_000, 200_000_000)},
+		tableRows: tableRows,
+	}
+
+	report, err := freezeage.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	var tableFinding *check.Finding
+	for i := range report.Results {
+		if report.Results[i].ID == findingIDTableFreezeAge {
+			tableFinding = &report.Results[i]
+			break
+		}
+	}
+
+	require.NotNil(t, tableFinding)
+	assert.Contains(t, tableFinding.Details, "Found 60 table(s)")
+	assert.Contains(t, tableFinding.Details, "showing the oldest 50")
+	assert.Len(t, tableFinding.Table.Rows, 50)
+}
+
 func TestFreezeAge_TableCritical(t *testing.T) {
 	t.Parallel()
 
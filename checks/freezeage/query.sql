@@ -13,7 +13,9 @@ WHERE datallowconn = true
 ORDER BY age(datfrozenxid) DESC;
 
 -- name: TableFreezeAge :many
--- Gets transaction ID age for tables with oldest frozen XIDs.
+-- Gets transaction ID age for all tables, oldest first.
+-- Not limited: the check applies the table scope (--schemas, --exclude-schemas,
+-- --include-tables, --exclude-tables) before capping the list.
 SELECT
   (n.nspname || '.' || c.relname)::text AS table_name
   , c.relfrozenxid::text AS frozen_xid
@@ -28,7 +30,6 @@ INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
 LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
 WHERE
   c.relkind = 'r'
-  AND n.nspname = 'public'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
   AND c.relfrozenxid != '0'
-ORDER BY age(c.relfrozenxid) DESC
-LIMIT 50;
+ORDER BY age(c.relfrozenxid) DESC;
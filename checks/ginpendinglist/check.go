@@ -0,0 +1,176 @@
+// Package ginpendinglist implements a check for GIN indexes whose pending
+// list of not yet merged entries has grown large.
+package ginpendinglist
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultWarnPercent = 50.0
+
+	// Pending lists smaller than this are cheap to scan, whatever the limit.
+	minPendingBytes = check.MiB
+)
+
+const pendingRemediation = "Merge the pending lists now with gin_clean_pending_list(). To keep them short, " +
+	"let autovacuum visit the tables more often (a lower autovacuum_vacuum_insert_scale_factor on PostgreSQL 13+), " +
+	"lower gin_pending_list_limit on the index, or turn fastupdate off where searches matter more than insert speed"
+
+type GinPendingListQueries interface {
+	GinPendingLists(context.Context) ([]db.GinPendingListsRow, error)
+}
+
+type checker struct {
+	queries     GinPendingListQueries
+	warnPercent float64 // default: 50
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryIndexes,
+		CheckID:     "gin-pending-list",
+		Name:        "GIN Pending Lists",
+		Description: "Flags GIN indexes whose pending list is large relative to gin_pending_list_limit",
+		Readme:      readme,
+		SQL:         querySQL,
+		Requires: []check.Prerequisite{
+			check.RequireExtension("pgstattuple"),
+		},
+	}
+}
+
+func New(queries GinPendingListQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		warnPercent: defaultWarnPercent,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["warn_percent"]; ok {
+				if n, err := strconv.ParseFloat(v, 64); err == nil && n > 0 {
+					c.warnPercent = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.GinPendingLists(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.GinPendingListsRow) string { return r.SchemaName + "." + r.TableName })
+
+	c.checkPendingLists(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkPendingLists warns on GIN indexes whose pending list is close to or
+// over its limit. Every search on the index reads the whole pending list.
+func (c *checker) checkPendingLists(ctx context.Context, rows []db.GinPendingListsRow, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No GIN indexes",
+		})
+		return
+	}
+
+	var tableRows []check.TableRow
+	var fixes []string
+	var totalPending int64
+	for _, row := range rows {
+		totalPending += row.PendingBytes
+		percent := pendingPercent(row)
+		if row.PendingBytes < minPendingBytes || percent < c.warnPercent {
+			continue
+		}
+
+		fastupdate := "on"
+		if !row.Fastupdate {
+			fastupdate = "off"
+		}
+		index := pgx.Identifier{row.SchemaName, row.IndexName}.Sanitize()
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.IndexName,
+				row.TableName,
+				check.FormatSize(ctx, row.PendingBytes),
+				check.FormatNumber(row.PendingTuples),
+				check.FormatSize(ctx, row.PendingListLimitBytes),
+				fmt.Sprintf("%.0f%%", percent),
+				fastupdate,
+			},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("SELECT gin_clean_pending_list('%s'::regclass);", strings.ReplaceAll(index, "'", "''")))
+	}
+
+	metrics := []check.Metric{
+		{Name: "gin_indexes", Value: float64(len(rows)), Unit: check.UnitCount},
+		{Name: "gin_pending_size", Value: float64(totalPending), Unit: check.UnitBytes},
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("No pending list of the %d GIN index(es) reaches %.0f%% of its gin_pending_list_limit",
+				len(rows), c.warnPercent),
+			Metrics: metrics,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d GIN index(es) have a pending list at %.0f%% or more of gin_pending_list_limit: "+
+			"every search scans these unsorted entries, and the insert that fills the list merges it while the client waits",
+			len(tableRows), c.warnPercent),
+		Remediation: pendingRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Index", "Table", "Pending", "Pending Tuples", "Limit", "Of Limit", "fastupdate"},
+			Rows:    tableRows,
+		},
+		Metrics: metrics,
+	})
+}
+
+func pendingPercent(row db.GinPendingListsRow) float64 {
+	if row.PendingListLimitBytes <= 0 {
+		return 0
+	}
+	return float64(row.PendingBytes) * 100 / float64(row.PendingListLimitBytes)
+}
@@ -0,0 +1,172 @@
+package ginpendinglist_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/ginpendinglist"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockGinPendingListQueryer struct {
+	indexes []db.GinPendingListsRow
+	err     error
+}
+
+func (m *mockGinPendingListQueryer) GinPendingLists(context.Context) ([]db.GinPendingListsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.indexes, nil
+}
+
+func makeIndex(name string, pending, limit int64) db.GinPendingListsRow {
+	return db.GinPendingListsRow{
+		SchemaName:            "public",
+		TableName:             "documents",
+		IndexName:             name,
+		IndexSizeBytes:        500 * check.MiB,
+		PendingBytes:          pending,
+		PendingTuples:         pending / 100,
+		PendingListLimitBytes: limit,
+		Fastupdate:            true,
+	}
+}
+
+func Test_GinPendingList(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		indexes  []db.GinPendingListsRow
+		cfg      check.Config
+		severity check.Severity
+		flagged  []string
+	}{
+		{
+			name:     "no GIN indexes",
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "empty pending list",
+			indexes:  []db.GinPendingListsRow{makeIndex("documents_body_idx", 0, 4*check.MiB)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "pending list below threshold",
+			indexes:  []db.GinPendingListsRow{makeIndex("documents_body_idx", 1536*check.KiB, 4*check.MiB)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "pending list near the limit",
+			indexes:  []db.GinPendingListsRow{makeIndex("documents_body_idx", 3*check.MiB, 4*check.MiB)},
+			severity: check.SeverityWarn,
+			flagged:  []string{"public.documents_body_idx"},
+		},
+		{
+			name:     "small limit set on purpose",
+			indexes:  []db.GinPendingListsRow{makeIndex("documents_tags_idx", 600*check.KiB, 640*check.KiB)},
+			severity: check.SeverityOK,
+		},
+		{
+			name: "only the large list is flagged",
+			indexes: []db.GinPendingListsRow{
+				makeIndex("documents_body_idx", 40*check.MiB, 64*check.MiB),
+				makeIndex("documents_tags_idx", 8*check.KiB, 4*check.MiB),
+			},
+			severity: check.SeverityWarn,
+			flagged:  []string{"public.documents_body_idx"},
+		},
+		{
+			name:     "configured threshold",
+			indexes:  []db.GinPendingListsRow{makeIndex("documents_body_idx", 3*check.MiB, 4*check.MiB)},
+			cfg:      check.Config{"gin-pending-list": {"warn_percent": "90"}},
+			severity: check.SeverityOK,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := ginpendinglist.New(&mockGinPendingListQueryer{indexes: tt.indexes}, tt.cfg)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.severity, finding.Severity)
+			if tt.severity == check.SeverityOK {
+				require.Nil(t, finding.Table)
+				return
+			}
+
+			var flagged []string
+			for _, row := range finding.Table.Rows {
+				flagged = append(flagged, row.Cells[0])
+			}
+			require.Equal(t, tt.flagged, flagged)
+			require.NotEmpty(t, finding.Remediation)
+		})
+	}
+}
+
+func Test_GinPendingList_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	indexes := []db.GinPendingListsRow{
+		makeIndex("documents_body_idx", 3*check.MiB, 4*check.MiB),
+		makeIndex("o'brien_idx", 3*check.MiB, 4*check.MiB),
+	}
+	report, err := ginpendinglist.New(&mockGinPendingListQueryer{indexes: indexes}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, "SELECT gin_clean_pending_list('\"public\".\"documents_body_idx\"'::regclass);\n"+
+		"SELECT gin_clean_pending_list('\"public\".\"o''brien_idx\"'::regclass);", report.Results[0].FixSQL)
+}
+
+func Test_GinPendingList_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("GinPendingLists",
+		[]string{
+			"schema_name text", "table_name text", "index_name text", "index_size_bytes int8",
+			"pending_bytes int8", "pending_tuples int8", "pending_list_limit_bytes int8", "fastupdate bool",
+		},
+		[]any{"public", "events", "events_payload_idx", 2 * check.GiB, 3 * check.MiB, 45000, 4 * check.MiB, true},
+	)
+
+	report, err := ginpendinglist.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"public.events_payload_idx", "events", "3.0MiB", "45.0K", "4.0MiB", "75%", "on"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_GinPendingList_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := ginpendinglist.New(&mockGinPendingListQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "gin-pending-list")
+}
+
+func Test_GinPendingList_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := ginpendinglist.Metadata()
+
+	require.Equal(t, "gin-pending-list", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+	require.Len(t, metadata.Requires, 1)
+}
@@ -0,0 +1,41 @@
+-- name: GinPendingLists :many
+-- GIN indexes with the size of their pending list, from pgstattuple, and the
+-- gin_pending_list_limit that applies to each: the index's storage parameter,
+-- or the server setting. Both are in kB and returned in bytes. Temporary
+-- indexes of other sessions cannot be read and are left out.
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , g.pending_pages::bigint * current_setting('block_size')::bigint AS pending_bytes
+  , g.pending_tuples::bigint AS pending_tuples
+  , COALESCE(
+    (
+      SELECT o.option_value::bigint
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'gin_pending_list_limit'
+    )
+    , (SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'gin_pending_list_limit')
+  ) * 1024 AS pending_list_limit_bytes
+  , COALESCE(
+    (
+      SELECT o.option_value
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'fastupdate'
+    ) NOT IN ('off', 'false', 'no', '0')
+    , true
+  ) AS fastupdate
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+CROSS JOIN LATERAL pgstatginindex(c.oid) AS g
+WHERE
+  am.amname = 'gin'
+  AND c.relkind = 'i'
+  AND c.relpersistence <> 't'
+  AND i.indisvalid
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY pending_bytes DESC, n.nspname, c.relname;
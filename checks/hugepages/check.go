@@ -0,0 +1,183 @@
+// Package hugepages implements a check for huge pages backing shared memory.
+package hugepages
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+const (
+	// Below this, the page tables mapping shared_buffers stay small enough
+	// that huge pages make little difference.
+	largeSharedBuffersBytes = 8 * check.GiB
+
+	// PostgreSQL's block size, the unit of shared_buffers unless reported otherwise.
+	defaultBlockSize = 8 * check.KiB
+)
+
+// reserveRemediation is for huge_pages = try falling back to regular pages,
+// which happens when the kernel has too few huge pages reserved.
+const reserveRemediation = "Reserve enough huge pages in the kernel (vm.nr_hugepages in /etc/sysctl.conf) and restart PostgreSQL; " +
+	"with huge_pages = try it silently falls back to regular pages when the reservation is short"
+
+type HugePagesQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries HugePagesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "huge-pages",
+		Name:        "Huge Pages",
+		Description: "Reports whether huge pages back shared memory on servers with large shared_buffers",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries HugePagesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+
+	hugePages := settings["huge_pages"].Setting.String
+	// huge_pages_status (PostgreSQL 17+) tells whether try got huge pages;
+	// older servers do not expose it.
+	status := settings["huge_pages_status"].Setting.String
+	sharedBuffers := settingBytes(settings["shared_buffers"])
+	// shared_memory_size_in_huge_pages (PostgreSQL 15+) is the reservation
+	// the server needs, or -1 where huge pages are unsupported.
+	needed, _ := strconv.ParseInt(settings["shared_memory_size_in_huge_pages"].Setting.String, 10, 64)
+
+	provider := check.ProviderFromContext(ctx)
+	current := fmt.Sprintf("huge_pages = %s", displayValue(hugePages))
+	if status != "" {
+		current += fmt.Sprintf(" (in use: %s)", status)
+	}
+	current += fmt.Sprintf(", shared_buffers = %s", check.FormatSize(ctx, sharedBuffers))
+
+	switch {
+	case hugePages == "on" || status == "on":
+		report.AddFinding(check.Finding{
+			ID:       "huge-pages",
+			Name:     "Huge Pages",
+			Severity: check.SeverityOK,
+			Details:  current,
+		})
+
+	case sharedBuffers < largeSharedBuffersBytes:
+		report.AddFinding(check.Finding{
+			ID:       "huge-pages",
+			Name:     "Huge Pages",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%s; below %s huge pages make little difference", current, check.FormatSize(ctx, largeSharedBuffersBytes)),
+		})
+
+	case hugePages == "off":
+		remediation, fixSQL := provider.RestartSettingFix("huge_pages", "try")
+		if !provider.Managed() {
+			remediation = reserveNote(needed) + ". " + remediation
+		}
+		report.AddFinding(check.Finding{
+			ID:       "huge-pages",
+			Name:     "Huge Pages",
+			Severity: check.SeverityWarn,
+			Details: fmt.Sprintf("%s: shared memory is mapped with 4kB pages, so every connection carries large page tables for it "+
+				"and the CPU spends more time on TLB misses", current),
+			Object: &check.Object{Kind: check.ObjectSetting, Name: "huge_pages"},
+			Comparison: &check.Comparison{
+				Setting:   "huge_pages",
+				Actual:    hugePages,
+				Expected:  "try or on",
+				Threshold: fmt.Sprintf("off with shared_buffers >= %s", check.FormatSize(ctx, largeSharedBuffersBytes)),
+			},
+			Remediation: remediation,
+			FixSQL:      fixSQL,
+		})
+
+	case status == "off":
+		remediation := reserveRemediation
+		if needed > 0 {
+			remediation = fmt.Sprintf("%s; this server needs vm.nr_hugepages >= %d", reserveRemediation, needed)
+		}
+		if provider.Managed() {
+			remediation = fmt.Sprintf("Huge pages are reserved by %s: check that the instance class supports them", provider)
+		}
+		report.AddFinding(check.Finding{
+			ID:          "huge-pages",
+			Name:        "Huge Pages",
+			Severity:    check.SeverityWarn,
+			Details:     fmt.Sprintf("%s: huge_pages = try fell back to regular pages at startup", current),
+			Object:      &check.Object{Kind: check.ObjectSetting, Name: "huge_pages"},
+			Remediation: remediation,
+		})
+
+	default:
+		// huge_pages = try on a server that cannot tell whether it got them.
+		report.AddFinding(check.Finding{
+			ID:       "huge-pages",
+			Name:     "Huge Pages",
+			Severity: check.SeverityOK,
+			Details: current + "; whether huge pages are in use is only reported from PostgreSQL 17, " +
+				"check HugePages_Rsvd in /proc/meminfo",
+		})
+	}
+
+	return report, nil
+}
+
+// reserveNote tells how many huge pages to reserve, when the server knows.
+func reserveNote(needed int64) string {
+	if needed > 0 {
+		return fmt.Sprintf("Reserve %d huge pages in the kernel (vm.nr_hugepages)", needed)
+	}
+	return "Reserve enough huge pages in the kernel (vm.nr_hugepages)"
+}
+
+// settingBytes converts a memory setting to bytes using its pg_settings unit,
+// e.g. shared_buffers is counted in 8kB blocks.
+func settingBytes(row db.CatalogSettingsRow) int64 {
+	value, err := strconv.ParseInt(row.Setting.String, 10, 64)
+	if err != nil {
+		return 0
+	}
+	unit, err := check.ParseBytes(row.Unit.String)
+	if err != nil || unit == 0 || !row.Unit.Valid {
+		unit = defaultBlockSize
+	}
+	return value * unit
+}
+
+func displayValue(value string) string {
+	if value == "" {
+		return "unknown"
+	}
+	return value
+}
@@ -0,0 +1,174 @@
+package hugepages_test
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/hugepages"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockHugePagesQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockHugePagesQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func setting(name, value, unit string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+		Unit:    pgtype.Text{String: unit, Valid: unit != ""},
+	}
+}
+
+// settings describes a server; an empty status leaves out huge_pages_status,
+// as before PostgreSQL 17.
+func settings(hugePages, status string, sharedBuffers int64) []db.CatalogSettingsRow {
+	rows := []db.CatalogSettingsRow{
+		setting("huge_pages", hugePages, ""),
+		setting("shared_buffers", strconv.FormatInt(sharedBuffers/8192, 10), "8kB"),
+		setting("shared_memory_size_in_huge_pages", "8400", ""),
+	}
+	if status != "" {
+		rows = append(rows, setting("huge_pages_status", status, ""))
+	}
+	return rows
+}
+
+func Test_HugePages(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		HugePages        string
+		Status           string
+		SharedBuffers    int64
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "in use - OK",
+			HugePages:        "try",
+			Status:           "on",
+			SharedBuffers:    16 * check.GiB,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "huge_pages = try (in use: on), shared_buffers = 16.0GiB",
+		},
+		{
+			Name:             "small shared_buffers - OK",
+			HugePages:        "off",
+			SharedBuffers:    2 * check.GiB,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "below 8.0GiB huge pages make little difference",
+		},
+		{
+			Name:             "off with large shared_buffers warns",
+			HugePages:        "off",
+			SharedBuffers:    16 * check.GiB,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "huge_pages = off, shared_buffers = 16.0GiB: shared memory is mapped with 4kB pages",
+		},
+		{
+			Name:             "try fell back to regular pages warns",
+			HugePages:        "try",
+			Status:           "off",
+			SharedBuffers:    16 * check.GiB,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "huge_pages = try fell back to regular pages",
+		},
+		{
+			Name:             "try before PostgreSQL 17 - OK",
+			HugePages:        "try",
+			SharedBuffers:    16 * check.GiB,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "only reported from PostgreSQL 17",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := hugepages.New(&mockHugePagesQueryer{settings: settings(tc.HugePages, tc.Status, tc.SharedBuffers)})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Results[0].Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_HugePages_Remediation(t *testing.T) {
+	t.Parallel()
+
+	report, err := hugepages.New(&mockHugePagesQueryer{settings: settings("off", "", 16*check.GiB)}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, "Reserve 8400 huge pages in the kernel (vm.nr_hugepages). "+
+		"Set huge_pages to try and restart the server during a maintenance window", finding.Remediation)
+	require.Equal(t, "ALTER SYSTEM SET huge_pages = 'try';", finding.FixSQL)
+
+	report, err = hugepages.New(&mockHugePagesQueryer{settings: settings("try", "off", 16*check.GiB)}).Check(context.Background())
+	require.NoError(t, err)
+	require.Contains(t, report.Results[0].Remediation, "this server needs vm.nr_hugepages >= 8400")
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err = hugepages.New(&mockHugePagesQueryer{settings: settings("off", "", 16*check.GiB)}).Check(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "Set huge_pages to try in the DB parameter group, then reboot the instance during a maintenance window",
+		report.Results[0].Remediation)
+}
+
+func Test_HugePages_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"huge_pages", "try", nil, "default", "try", "try", "postmaster", "enum", "f"},
+		[]any{"huge_pages_status", "off", nil, "default", "off", "off", "internal", "enum", "f"},
+		[]any{"shared_buffers", "2097152", "8kB", "configuration file", "16384", "2097152", "postmaster", "integer", "f"},
+	)
+
+	report, err := hugepages.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Contains(t, report.Results[0].Details, "shared_buffers = 16.0GiB")
+}
+
+func Test_HugePages_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := hugepages.New(&mockHugePagesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "huge-pages")
+}
+
+func Test_HugePages_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := hugepages.Metadata()
+
+	require.Equal(t, "huge-pages", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -26,12 +26,13 @@ type checker struct {
 
 func Metadata() check.Metadata {
 	return check.Metadata{
-		Category:    check.CategoryIndexes,
-		CheckID:     "index-bloat",
-		Name:        "Index Bloat",
-		Description: "Estimates B-tree index bloat to identify indexes needing maintenance",
-		Readme:      readme,
-		SQL:         querySQL,
+		Category:      check.CategoryIndexes,
+		CheckID:       "index-bloat",
+		Name:          "Index Bloat",
+		Description:   "Estimates B-tree index bloat to identify indexes needing maintenance",
+		Readme:        readme,
+		SQL:           querySQL,
+		PreferReplica: true,
 	}
 }
 
@@ -52,6 +53,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryIndexes, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.IndexBloatRow) string { return r.Schemaname.String + "." + r.Tablename.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -64,14 +66,14 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	}
 
 	// Run subchecks
-	checkHighBloatIndexes(rows, report)
-	checkLargeBloatedIndexes(rows, report)
+	checkHighBloatIndexes(ctx, rows, report)
+	checkLargeBloatedIndexes(ctx, rows, report)
 
 	return report, nil
 }
 
 // checkHighBloatIndexes identifies indexes with high bloat percentage.
-func checkHighBloatIndexes(rows []db.IndexBloatRow, report *check.Report) {
+func checkHighBloatIndexes(ctx context.Context, rows []db.IndexBloatRow, report *check.Report) {
 	var critical []db.IndexBloatRow // >70%
 	var warning []db.IndexBloatRow  // >50%
 
@@ -103,8 +105,8 @@ func checkHighBloatIndexes(rows []db.IndexBloatRow, report *check.Report) {
 				row.Tablename.String,
 				row.Indexname.String,
 				fmt.Sprintf("%.1f%%", getBloatPercent(row)),
-				check.FormatBytes(row.BloatBytes.Int64),
-				check.FormatBytes(row.ActualBytes.Int64),
+				check.FormatSize(ctx, row.BloatBytes.Int64),
+				check.FormatSize(ctx, row.ActualBytes.Int64),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -116,8 +118,8 @@ func checkHighBloatIndexes(rows []db.IndexBloatRow, report *check.Report) {
 				row.Tablename.String,
 				row.Indexname.String,
 				fmt.Sprintf("%.1f%%", getBloatPercent(row)),
-				check.FormatBytes(row.BloatBytes.Int64),
-				check.FormatBytes(row.ActualBytes.Int64),
+				check.FormatSize(ctx, row.BloatBytes.Int64),
+				check.FormatSize(ctx, row.ActualBytes.Int64),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -136,7 +138,7 @@ func checkHighBloatIndexes(rows []db.IndexBloatRow, report *check.Report) {
 }
 
 // checkLargeBloatedIndexes identifies large indexes with notable bloat.
-func checkLargeBloatedIndexes(rows []db.IndexBloatRow, report *check.Report) {
+func checkLargeBloatedIndexes(ctx context.Context, rows []db.IndexBloatRow, report *check.Report) {
 	const oneGB = int64(1024 * 1024 * 1024)
 	const oneHundredMB = int64(100 * 1024 * 1024)
 
@@ -177,9 +179,9 @@ func checkLargeBloatedIndexes(rows []db.IndexBloatRow, report *check.Report) {
 			Cells: []string{
 				row.Tablename.String,
 				row.Indexname.String,
-				check.FormatBytes(row.BloatBytes.Int64),
+				check.FormatSize(ctx, row.BloatBytes.Int64),
 				fmt.Sprintf("%.1f%%", getBloatPercent(row)),
-				check.FormatBytes(row.ActualBytes.Int64),
+				check.FormatSize(ctx, row.ActualBytes.Int64),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -190,9 +192,9 @@ func checkLargeBloatedIndexes(rows []db.IndexBloatRow, report *check.Report) {
 			Cells: []string{
 				row.Tablename.String,
 				row.Indexname.String,
-				check.FormatBytes(row.BloatBytes.Int64),
+				check.FormatSize(ctx, row.BloatBytes.Int64),
 				fmt.Sprintf("%.1f%%", getBloatPercent(row)),
-				check.FormatBytes(row.ActualBytes.Int64),
+				check.FormatSize(ctx, row.ActualBytes.Int64),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -204,10 +206,11 @@ func checkLargeBloatedIndexes(rows []db.IndexBloatRow, report *check.Report) {
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "large-bloat",
-		Name:     "Large Bloated Indexes",
-		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("Found %d index(es) wasting significant disk space (total: %s)", len(critical)+len(warning), check.FormatBytes(totalWasted)),
+		ID:               "large-bloat",
+		Name:             "Large Bloated Indexes",
+		Severity:         check.SeverityWarn,
+		Details:          fmt.Sprintf("Found %d index(es) wasting significant disk space (total: %s)", len(critical)+len(warning), check.FormatSize(ctx, totalWasted)),
+		ReclaimableBytes: totalWasted,
 		Table: &check.Table{
 			Headers: headers,
 			Rows:    tableRows,
@@ -0,0 +1,197 @@
+// Package indextableratio implements a check for tables whose indexes take
+// much more space than the table itself.
+package indextableratio
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"sort"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const defaultMaxRatio = 2.0
+
+const ratioRemediation = "Drop indexes that are never scanned (see index-usage) or that another index covers (see duplicate-indexes), " +
+	"and consider combining single-column indexes into fewer multi-column ones. " +
+	"Every insert, and every update that is not HOT, writes to all of a table's indexes"
+
+type IndexTableRatioQueries interface {
+	CatalogTables(context.Context) ([]db.CatalogTablesRow, error)
+	TableIndexSizes(context.Context) ([]db.TableIndexSizesRow, error)
+}
+
+type checker struct {
+	queries  IndexTableRatioQueries
+	maxRatio float64 // default: 2
+}
+
+// overIndexed is a table whose indexes exceed the ratio, with its indexes.
+type overIndexed struct {
+	table   db.CatalogTablesRow
+	ratio   float64
+	indexes []db.TableIndexSizesRow
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryIndexes,
+		CheckID:     "index-table-ratio",
+		Name:        "Index to Table Size Ratio",
+		Description: "Flags tables whose indexes are much larger than the table, with a breakdown per index",
+		Readme:      readme,
+		SQL:         querySQL + "\n" + catalog.SQL,
+	}
+}
+
+func New(queries IndexTableRatioQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:  queries,
+		maxRatio: defaultMaxRatio,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["max_ratio"]; ok {
+				if n, err := strconv.ParseFloat(v, 64); err == nil && n > 0 {
+					c.maxRatio = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	tables, err := c.queries.CatalogTables(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	indexes, err := c.queries.TableIndexSizes(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	tables = check.FilterTables(ctx, tables, func(r db.CatalogTablesRow) string { return r.SchemaName.String + "." + r.TableName.String })
+	tables = check.FilterSmallTables(ctx, tables, func(r db.CatalogTablesRow) (int64, int64) {
+		return max(check.Int8ToInt64(r.NLiveTup), check.Int8ToInt64(r.EstimatedRows), 0), check.Int8ToInt64(r.TableSizeBytes)
+	})
+
+	c.checkRatio(ctx, c.overIndexed(tables, indexes), report)
+
+	return report, nil
+}
+
+// overIndexed returns the tables whose indexes exceed maxRatio times the
+// table size, highest ratio first. Partitioned tables have no storage of
+// their own and are covered by their partitions.
+func (c *checker) overIndexed(tables []db.CatalogTablesRow, indexes []db.TableIndexSizesRow) []overIndexed {
+	byTable := map[string][]db.TableIndexSizesRow{}
+	for _, index := range indexes {
+		key := index.SchemaName + "." + index.TableName
+		byTable[key] = append(byTable[key], index)
+	}
+
+	var result []overIndexed
+	for _, table := range tables {
+		tableSize := check.Int8ToInt64(table.TableSizeBytes)
+		if table.Relkind.String == "p" || tableSize <= 0 {
+			continue
+		}
+		ratio := float64(check.Int8ToInt64(table.IndexesSizeBytes)) / float64(tableSize)
+		if ratio <= c.maxRatio {
+			continue
+		}
+		result = append(result, overIndexed{
+			table:   table,
+			ratio:   ratio,
+			indexes: byTable[table.SchemaName.String+"."+table.TableName.String],
+		})
+	}
+
+	sort.SliceStable(result, func(i, j int) bool { return result[i].ratio > result[j].ratio })
+	return result
+}
+
+// checkRatio warns on over-indexed tables, listing each of their indexes.
+// Indexes never scanned that back no constraint are the first to trim.
+func (c *checker) checkRatio(ctx context.Context, tables []overIndexed, report *check.Report) {
+	if len(tables) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No table has indexes larger than %.1f times its own size", c.maxRatio),
+		})
+		return
+	}
+
+	var tableRows []check.TableRow
+	var unusedSize int64
+	for _, t := range tables {
+		name := t.table.SchemaName.String + "." + t.table.TableName.String
+		for _, index := range t.indexes {
+			severity, usage := check.SeverityOK, check.FormatNumber(index.IdxScan)
+			switch {
+			case index.IsPrimary:
+				usage += " (primary key)"
+			case index.IsUnique:
+				usage += " (unique)"
+			case index.IdxScan == 0:
+				severity = check.SeverityWarn
+				unusedSize += index.IndexSizeBytes
+			}
+			tableRows = append(tableRows, check.TableRow{
+				Cells: []string{
+					name,
+					fmt.Sprintf("%.1fx", t.ratio),
+					index.IndexName,
+					check.FormatSize(ctx, index.IndexSizeBytes),
+					usage,
+				},
+				Severity: severity,
+			})
+		}
+	}
+
+	worst := tables[0]
+	details := fmt.Sprintf("%d table(s) with indexes larger than %.1f times the table; %s.%s has %s of indexes for %s of data",
+		len(tables), c.maxRatio, worst.table.SchemaName.String, worst.table.TableName.String,
+		check.FormatSize(ctx, check.Int8ToInt64(worst.table.IndexesSizeBytes)), check.FormatSize(ctx, check.Int8ToInt64(worst.table.TableSizeBytes)))
+	if unusedSize > 0 {
+		details += fmt.Sprintf("; %s of their indexes were never scanned", check.FormatSize(ctx, unusedSize))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: ratioRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Index/Table", "Index", "Size", "Scans"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "over_indexed_tables", Value: float64(len(tables)), Unit: check.UnitCount},
+			{Name: "unused_index_size", Value: float64(unusedSize), Unit: check.UnitBytes},
+		},
+	})
+}
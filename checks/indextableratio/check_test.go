@@ -0,0 +1,213 @@
+package indextableratio_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/indextableratio"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockIndexTableRatioQueryer struct {
+	tables  []db.CatalogTablesRow
+	indexes []db.TableIndexSizesRow
+	err     error
+}
+
+func (m *mockIndexTableRatioQueryer) CatalogTables(context.Context) ([]db.CatalogTablesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.tables, nil
+}
+
+func (m *mockIndexTableRatioQueryer) TableIndexSizes(context.Context) ([]db.TableIndexSizesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.indexes, nil
+}
+
+func makeTable(name, relkind string, size, indexesSize int64) db.CatalogTablesRow {
+	return db.CatalogTablesRow{
+		SchemaName:       pgtype.Text{String: "public", Valid: true},
+		TableName:        pgtype.Text{String: name, Valid: true},
+		Relkind:          pgtype.Text{String: relkind, Valid: true},
+		EstimatedRows:    pgtype.Int8{Int64: size / 100, Valid: true},
+		TableSizeBytes:   pgtype.Int8{Int64: size, Valid: true},
+		IndexesSizeBytes: pgtype.Int8{Int64: indexesSize, Valid: true},
+	}
+}
+
+func makeIndex(table, name string, size, scans int64) db.TableIndexSizesRow {
+	return db.TableIndexSizesRow{
+		SchemaName:     "public",
+		TableName:      table,
+		IndexName:      name,
+		IndexSizeBytes: size,
+		IdxScan:        scans,
+	}
+}
+
+func Test_IndexTableRatio(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		tables   []db.CatalogTablesRow
+		cfg      check.Config
+		severity check.Severity
+	}{
+		{
+			name:     "no tables",
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "indexes smaller than the table",
+			tables:   []db.CatalogTablesRow{makeTable("orders", "r", check.GiB, 400*check.MiB)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "indexes three times the table",
+			tables:   []db.CatalogTablesRow{makeTable("orders", "r", check.GiB, 3*check.GiB)},
+			severity: check.SeverityWarn,
+		},
+		{
+			name:     "partitioned parent",
+			tables:   []db.CatalogTablesRow{makeTable("events", "p", 0, 0)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "configured ratio",
+			tables:   []db.CatalogTablesRow{makeTable("orders", "r", check.GiB, 3*check.GiB)},
+			cfg:      check.Config{"index-table-ratio": {"max_ratio": "4"}},
+			severity: check.SeverityOK,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := indextableratio.New(&mockIndexTableRatioQueryer{tables: tt.tables}, tt.cfg)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tt.severity, report.Results[0].Severity)
+		})
+	}
+}
+
+func Test_IndexTableRatio_SmallTablesIgnored(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000})
+	checker := indextableratio.New(&mockIndexTableRatioQueryer{tables: []db.CatalogTablesRow{
+		makeTable("settings", "r", 16*check.KiB, 64*check.KiB),
+	}})
+	report, err := checker.Check(ctx)
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, report.Results[0].Severity)
+}
+
+func Test_IndexTableRatio_Breakdown(t *testing.T) {
+	t.Parallel()
+
+	primary := makeIndex("orders", "orders_pkey", 800*check.MiB, 0)
+	primary.IsPrimary = true
+	queryer := &mockIndexTableRatioQueryer{
+		tables: []db.CatalogTablesRow{
+			makeTable("orders", "r", check.GiB, 3*check.GiB),
+			makeTable("users", "r", check.GiB, 5*check.GiB),
+			makeTable("products", "r", check.GiB, 100*check.MiB),
+		},
+		indexes: []db.TableIndexSizesRow{
+			makeIndex("orders", "orders_customer_id_idx", 1200*check.MiB, 15_000),
+			makeIndex("orders", "orders_status_idx", 1024*check.MiB, 0),
+			primary,
+			makeIndex("products", "products_pkey", 100*check.MiB, 0),
+			makeIndex("users", "users_email_idx", 5*check.GiB, 2_000_000),
+		},
+	}
+
+	report, err := indextableratio.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "2 table(s)")
+	require.Contains(t, finding.Details, "public.users has 5.0GiB of indexes for 1.0GiB of data")
+	require.Contains(t, finding.Details, "1.0GiB of their indexes were never scanned")
+
+	var rows [][]string
+	var severities []check.Severity
+	for _, row := range finding.Table.Rows {
+		rows = append(rows, row.Cells)
+		severities = append(severities, row.Severity)
+	}
+	require.Equal(t, [][]string{
+		{"public.users", "5.0x", "users_email_idx", "5.0GiB", "2.0M"},
+		{"public.orders", "3.0x", "orders_customer_id_idx", "1.2GiB", "15.0K"},
+		{"public.orders", "3.0x", "orders_status_idx", "1.0GiB", "0"},
+		{"public.orders", "3.0x", "orders_pkey", "800.0MiB", "0 (primary key)"},
+	}, rows)
+	require.Equal(t, []check.Severity{check.SeverityOK, check.SeverityOK, check.SeverityWarn, check.SeverityOK}, severities)
+}
+
+func Test_IndexTableRatio_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogTables",
+			[]string{
+				"schema_name text", "table_name text", "relkind text", "estimated_rows int8", "table_size_bytes int8",
+				"indexes_size_bytes int8", "seq_scan int8", "idx_scan int8", "n_live_tup int8", "n_dead_tup int8",
+				"n_tup_ins int8", "n_tup_upd int8", "n_tup_del int8", "n_tup_hot_upd int8",
+				"last_autovacuum timestamptz", "last_autoanalyze timestamptz", "fillfactor int4",
+			},
+			[]any{"public", "events", "r", 2000000, 500 * check.MiB, 1500 * check.MiB, 10, 900000, 2000000, 0, 0, 0, 0, 0, nil, nil, nil},
+		).
+		Add("TableIndexSizes",
+			[]string{
+				"schema_name text", "table_name text", "index_name text", "index_size_bytes int8",
+				"idx_scan int8", "is_unique bool", "is_primary bool",
+			},
+			[]any{"public", "events", "events_payload_idx", 1500 * check.MiB, 0, false, false},
+		)
+
+	report, err := indextableratio.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"public.events", "3.0x", "events_payload_idx", "1.5GiB", "0"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_IndexTableRatio_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := indextableratio.New(&mockIndexTableRatioQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "index-table-ratio")
+}
+
+func Test_IndexTableRatio_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := indextableratio.Metadata()
+
+	require.Equal(t, "index-table-ratio", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,15 @@
+-- name: TableIndexSizes :many
+-- Every index on a user table with its size, how often it was scanned, and
+-- whether it backs a primary key or unique constraint, to break down the
+-- index size of the tables this check flags.
+SELECT
+  s.schemaname::text AS schema_name
+  , s.relname::text AS table_name
+  , s.indexrelname::text AS index_name
+  , pg_relation_size(s.indexrelid) AS index_size_bytes
+  , s.idx_scan::bigint AS idx_scan
+  , i.indisunique AS is_unique
+  , i.indisprimary AS is_primary
+FROM pg_stat_user_indexes AS s
+INNER JOIN pg_index AS i ON s.indexrelid = i.indexrelid
+ORDER BY s.schemaname, s.relname, index_size_bytes DESC;
@@ -63,6 +63,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.IndexUsageStatsRow) string { return r.TableName.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -73,16 +74,17 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkUnusedIndexes(rows, report)
+	checkUnusedIndexes(ctx, rows, report)
 	checkLowUsageIndexes(rows, report)
-	checkIndexCacheRatio(rows, report)
+	checkIndexCacheRatio(ctx, rows, report)
 
 	return report, nil
 }
 
-func checkUnusedIndexes(rows []db.IndexUsageStatsRow, report *check.Report) {
+func checkUnusedIndexes(ctx context.Context, rows []db.IndexUsageStatsRow, report *check.Report) {
 	var unusedIndexes []string
 	unusedCount := 0
+	unusedBytes := int64(0)
 
 	for _, row := range rows {
 		if row.IsPrimary || row.IsUnique {
@@ -94,8 +96,9 @@ func checkUnusedIndexes(rows []db.IndexUsageStatsRow, report *check.Report) {
 
 		if row.IdxScan.Int64 == 0 && sizeMB > unusedSizeThresholdMB {
 			unusedCount++
+			unusedBytes += sizeBytes.Int64
 			if len(unusedIndexes) < 10 {
-				unusedIndexes = append(unusedIndexes, fmt.Sprintf("%s.%s (%.1f MB)", row.TableName.String, row.IndexName.String, sizeMB))
+				unusedIndexes = append(unusedIndexes, fmt.Sprintf("%s.%s (%s)", row.TableName.String, row.IndexName.String, check.FormatSize(ctx, sizeBytes.Int64)))
 			}
 		}
 	}
@@ -119,10 +122,11 @@ func checkUnusedIndexes(rows []db.IndexUsageStatsRow, report *check.Report) {
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "unused-indexes",
-		Name:     "Unused Indexes",
-		Severity: check.SeverityWarn,
-		Details:  details,
+		ID:               "unused-indexes",
+		Name:             "Unused Indexes",
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		ReclaimableBytes: unusedBytes,
 	})
 }
 
@@ -169,7 +173,7 @@ func checkLowUsageIndexes(rows []db.IndexUsageStatsRow, report *check.Report) {
 	})
 }
 
-func checkIndexCacheRatio(rows []db.IndexUsageStatsRow, report *check.Report) {
+func checkIndexCacheRatio(ctx context.Context, rows []db.IndexUsageStatsRow, report *check.Report) {
 	var lowCacheIndexes []string
 	failCount := 0
 	warnCount := 0
@@ -186,14 +190,14 @@ func checkIndexCacheRatio(rows []db.IndexUsageStatsRow, report *check.Report) {
 		if cacheRatio.Float64 < cacheLowThreshold && sizeMB > cacheFailSizeMB {
 			failCount++
 			if len(lowCacheIndexes) < 10 {
-				lowCacheIndexes = append(lowCacheIndexes, fmt.Sprintf("%s.%s (%.1f%%, %.1f MB)",
-					row.TableName.String, row.IndexName.String, cacheRatio.Float64, sizeMB))
+				lowCacheIndexes = append(lowCacheIndexes, fmt.Sprintf("%s.%s (%.1f%%, %s)",
+					row.TableName.String, row.IndexName.String, cacheRatio.Float64, check.FormatSize(ctx, sizeBytes.Int64)))
 			}
 		} else if cacheRatio.Float64 < cacheWarnThreshold && sizeMB > cacheMinSizeMB {
 			warnCount++
 			if len(lowCacheIndexes) < 10 {
-				lowCacheIndexes = append(lowCacheIndexes, fmt.Sprintf("%s.%s (%.1f%%, %.1f MB)",
-					row.TableName.String, row.IndexName.String, cacheRatio.Float64, sizeMB))
+				lowCacheIndexes = append(lowCacheIndexes, fmt.Sprintf("%s.%s (%.1f%%, %s)",
+					row.TableName.String, row.IndexName.String, cacheRatio.Float64, check.FormatSize(ctx, sizeBytes.Int64)))
 			}
 		}
 	}
@@ -201,6 +201,7 @@ func Test_IndexUsage_UnusedIndexes(t *testing.T) {
 	require.Equal(t, check.SeverityWarn, unusedResult.Severity)
 	require.Contains(t, unusedResult.Details, "2 unused indexes")
 	require.Contains(t, unusedResult.Details, "idx_users_unused_1")
+	require.Equal(t, int64(20971520+31457280), unusedResult.ReclaimableBytes, "Both unused indexes can be dropped")
 }
 
 func Test_IndexUsage_LowUsageIndexes(t *testing.T) {
@@ -31,6 +31,6 @@ LEFT JOIN pg_class AS c ON psai.relid = c.oid
 LEFT JOIN pg_stat_user_tables AS ut ON tbl.oid = ut.relid
 LEFT JOIN pg_statio_user_indexes AS psaio ON psai.indexrelid = psaio.indexrelid
 WHERE
-  n.nspname = 'public'
+  n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
 ORDER BY
   pg_relation_size(psai.indexrelid) DESC;
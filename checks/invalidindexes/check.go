@@ -5,9 +5,11 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"strings"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 )
 
 //go:embed query.sql
@@ -32,6 +34,7 @@ func Metadata() check.Metadata {
 		Description: "Identifies indexes in invalid state that need rebuilding",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -52,6 +55,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryIndexes, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.BrokenIndexesRow) string { return r.SchemaName + "." + r.TableName })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -66,29 +70,57 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	// leftover are both "clean this up" work, not a 3am page. The Type column
 	// preserves the distinction; the fix for each lives in the README and
 	// `explain` output rather than inline, to keep the run summary terse.
-	var broken, leftover int
+	var broken, notReady, leftover int
+	var totalBytes, leftoverBytes int64
+	var fixes []string
 	tableRows := make([]check.TableRow, 0, len(rows))
 	for _, row := range rows {
-		kind := "broken"
-		if row.IsLeftover {
+		index := pgx.Identifier{row.SchemaName, row.IndexName}.Sanitize()
+		totalBytes += row.SizeBytes
+		var kind string
+		switch {
+		case row.IsLeftover:
 			kind = "leftover"
 			leftover++
-		} else {
+			leftoverBytes += row.SizeBytes
+			fixes = append(fixes, fmt.Sprintf("DROP INDEX CONCURRENTLY IF EXISTS %s;", index))
+		case row.IsNotReady:
+			kind = "not ready"
+			notReady++
+			fixes = append(fixes, fmt.Sprintf("REINDEX INDEX CONCURRENTLY %s;", index))
+		default:
+			kind = "broken"
 			broken++
+			fixes = append(fixes, fmt.Sprintf("REINDEX INDEX CONCURRENTLY %s;", index))
 		}
 		tableRows = append(tableRows, check.TableRow{
-			Cells:    []string{row.SchemaName, row.TableName, row.IndexName, kind},
+			Cells: []string{
+				row.SchemaName, row.TableName, row.IndexName, kind,
+				check.FormatSize(ctx, row.SizeBytes), check.FormatNumber(int64(row.XidAge)),
+			},
 			Severity: check.SeverityWarn,
 		})
 	}
 
+	details := fmt.Sprintf("%s (%d broken, %d leftover", pluralIndexes(len(rows)), broken, leftover)
+	if notReady > 0 {
+		details += fmt.Sprintf(", %d not ready", notReady)
+	}
+	details += fmt.Sprintf(") using %s", check.FormatSize(ctx, totalBytes))
+
 	report.AddFinding(check.Finding{
-		ID:       report.CheckID,
-		Name:     report.Name,
-		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("%s (%d broken, %d leftover)", pluralIndexes(len(rows)), broken, leftover),
+		ID:               report.CheckID,
+		Name:             report.Name,
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		FixSQL:           strings.Join(fixes, "\n"),
+		ReclaimableBytes: leftoverBytes,
+		Metrics: []check.Metric{
+			{Name: "invalid_indexes", Value: float64(len(rows)), Unit: check.UnitCount},
+			{Name: "invalid_index_bytes", Value: float64(totalBytes), Unit: check.UnitBytes},
+		},
 		Table: &check.Table{
-			Headers: []string{"Schema", "Table", "Index", "Type"},
+			Headers: []string{"Schema", "Table", "Index", "Type", "Size", "Age (xacts)"},
 			Rows:    tableRows,
 		},
 	})
@@ -8,6 +8,7 @@ import (
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/checks/invalidindexes"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
 	"github.com/stretchr/testify/require"
 )
 
@@ -33,11 +34,11 @@ func newMockQueryerWithError(err error) *mockInvalidIndexesQueryer {
 }
 
 func brokenIndex(schema, table, index string) db.BrokenIndexesRow {
-	return db.BrokenIndexesRow{SchemaName: schema, TableName: table, IndexName: index, IsLeftover: false}
+	return db.BrokenIndexesRow{SchemaName: schema, TableName: table, IndexName: index, IsLeftover: false, SizeBytes: 8192, XidAge: 1500}
 }
 
 func leftoverIndex(schema, table, index string) db.BrokenIndexesRow {
-	return db.BrokenIndexesRow{SchemaName: schema, TableName: table, IndexName: index, IsLeftover: true}
+	return db.BrokenIndexesRow{SchemaName: schema, TableName: table, IndexName: index, IsLeftover: true, SizeBytes: 16384, XidAge: 20}
 }
 
 // onlyFinding returns the single finding the check always emits.
@@ -138,15 +139,50 @@ func Test_InvalidIndexes_ClassifiesRowsByType(t *testing.T) {
 
 	// Table carries the broken/leftover distinction in a Type column.
 	require.NotNil(t, finding.Table)
-	require.Equal(t, []string{"Schema", "Table", "Index", "Type"}, finding.Table.Headers)
+	require.Equal(t, []string{"Schema", "Table", "Index", "Type", "Size", "Age (xacts)"}, finding.Table.Headers)
 	require.Len(t, finding.Table.Rows, 3)
-	require.Equal(t, []string{"public", "users", "idx_users_email", "broken"}, finding.Table.Rows[0].Cells)
-	require.Equal(t, []string{"app", "posts", "idx_posts_created_at_ccnew", "leftover"}, finding.Table.Rows[2].Cells)
+	require.Equal(t, []string{"public", "users", "idx_users_email", "broken", "8.0KiB", "1.5K"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"app", "posts", "idx_posts_created_at_ccnew", "leftover", "16.0KiB", "20"}, finding.Table.Rows[2].Cells)
 	for _, row := range finding.Table.Rows {
 		require.Equal(t, check.SeverityWarn, row.Severity)
 	}
 }
 
+func Test_InvalidIndexes_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	checker := invalidindexes.New(newMockQueryer([]db.BrokenIndexesRow{
+		brokenIndex("public", "users", "idx_users_email"),
+		leftoverIndex("App", "posts", "idx_posts_created_at_ccnew"),
+	}))
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t,
+		"REINDEX INDEX CONCURRENTLY \"public\".\"idx_users_email\";\n"+
+			"DROP INDEX CONCURRENTLY IF EXISTS \"App\".\"idx_posts_created_at_ccnew\";",
+		onlyFinding(t, report).FixSQL)
+}
+
+func Test_InvalidIndexes_NotReadyAndSize(t *testing.T) {
+	t.Parallel()
+
+	notReady := brokenIndex("public", "events", "idx_events_payload")
+	notReady.IsNotReady = true
+	checker := invalidindexes.New(newMockQueryer([]db.BrokenIndexesRow{
+		notReady,
+		brokenIndex("public", "users", "idx_users_email"),
+		leftoverIndex("public", "posts", "idx_posts_created_at_ccnew"),
+	}))
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := onlyFinding(t, report)
+	require.Contains(t, finding.Details, "(1 broken, 1 leftover, 1 not ready) using 32.0KiB")
+	require.Equal(t, "not ready", finding.Table.Rows[0].Cells[3])
+	require.Equal(t, int64(16384), finding.ReclaimableBytes, "only leftovers are dropped; the others are rebuilt")
+}
+
 func Test_InvalidIndexes_SingularPhrasing(t *testing.T) {
 	t.Parallel()
 
@@ -161,6 +197,22 @@ func Test_InvalidIndexes_SingularPhrasing(t *testing.T) {
 	require.NotContains(t, finding.Details, "invalid indexes")
 }
 
+func Test_InvalidIndexes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BrokenIndexes",
+		[]string{"schema_name text", "table_name text", "index_name text", "is_leftover bool", "is_not_ready bool", "size_bytes int8", "xid_age int4"},
+		[]any{"public", "users", "idx_users_email", "f", "f", 8192, 1500},
+		[]any{"public", "users", "idx_users_email_ccnew", "t", "t", 0, 20},
+	)
+
+	report, err := invalidindexes.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := onlyFinding(t, report)
+	require.Contains(t, finding.Details, "1 broken, 1 leftover")
+}
+
 func Test_InvalidIndexes_QueryError(t *testing.T) {
 	t.Parallel()
 
@@ -1,12 +1,18 @@
 -- name: BrokenIndexes :many
 -- Invalid indexes, flagging _ccnew/_ccold REINDEX CONCURRENTLY leftovers via
--- is_leftover. Excludes indexes a concurrent build is still working on (their
--- index_relid is in pg_stat_progress_create_index): in flight, not broken.
+-- is_leftover and indexes never marked ready for inserts via is_not_ready.
+-- Excludes indexes a concurrent build is still working on (their index_relid
+-- is in pg_stat_progress_create_index): in flight, not broken.
+-- xid_age is the number of transactions since the index's pg_index row was
+-- last written, i.e. since the build failed: PostgreSQL keeps no timestamp.
 SELECT
   n.nspname::text AS schema_name
   , tbl.relname::text AS table_name
   , idx.relname::text AS index_name
   , (idx.relname ~ '_cc(new|old)[0-9]*$') AS is_leftover
+  , (NOT i.indisready) AS is_not_ready
+  , pg_relation_size(idx.oid) AS size_bytes
+  , age(i.xmin) AS xid_age
 FROM pg_index AS i
 INNER JOIN pg_class AS idx ON i.indexrelid = idx.oid
 INNER JOIN pg_class AS tbl ON i.indrelid = tbl.oid
@@ -0,0 +1,154 @@
+// Package leftovertables implements a check for tables named like temporary
+// tables, backups or dated copies, which tend to be forgotten after use.
+package leftovertables
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// leftoverRemediation stops short of dropping: the names are a heuristic, and
+// a table nobody scanned since the statistics reset may still be read yearly.
+const leftoverRemediation = "Confirm with the owners that nothing reads these tables: no scans or writes since the statistics reset is a strong hint. " +
+	"Dump the ones worth keeping with pg_dump -t, then DROP TABLE them. Acknowledge tables that are in use in the configuration"
+
+type LeftoverTablesQueries interface {
+	LeftoverTables(context.Context) ([]db.LeftoverTablesRow, error)
+}
+
+type checker struct {
+	queries      LeftoverTablesQueries
+	acknowledged []string
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "leftover-tables",
+		Name:        "Leftover Tables",
+		Description: "Lists tables named like temporary tables, backups or dated copies, with their size and activity",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries LeftoverTablesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries: queries,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if acknowledged, ok := myCfg["acknowledged"]; ok {
+				for _, name := range strings.Split(acknowledged, ",") {
+					c.acknowledged = append(c.acknowledged, strings.TrimSpace(name))
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.LeftoverTables(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.LeftoverTablesRow) string { return r.SchemaName + "." + r.TableName })
+
+	c.checkLeftovers(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkLeftovers warns on every matching table not acknowledged as in use.
+// Scans, writes and the last vacuum or analyze tell abandoned copies apart.
+func (c *checker) checkLeftovers(ctx context.Context, rows []db.LeftoverTablesRow, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No tables named like temporary tables, backups or dated copies",
+		})
+		return
+	}
+
+	var tableRows []check.TableRow
+	var totalSize, warnSize int64
+	warned, idle := 0, 0
+	for _, row := range rows {
+		table := row.SchemaName + "." + row.TableName
+		totalSize += row.TotalSizeBytes
+
+		severity, status := check.SeverityWarn, "-"
+		if slices.Contains(c.acknowledged, table) {
+			severity, status = check.SeverityOK, "acknowledged"
+		} else {
+			warned++
+			warnSize += row.TotalSizeBytes
+			if row.Scans == 0 && row.Writes == 0 {
+				idle++
+				status = "idle"
+			}
+		}
+
+		lastActivity := "never"
+		if row.LastActivity.Valid {
+			lastActivity = row.LastActivity.Time.Format("2006-01-02 15:04")
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				table,
+				row.Pattern,
+				check.FormatSize(ctx, row.TotalSizeBytes),
+				check.FormatNumber(row.EstimatedRows),
+				check.FormatNumber(row.Scans),
+				check.FormatNumber(row.Writes),
+				lastActivity,
+				status,
+			},
+			Severity: severity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%d leftover-looking table(s) (%s), all acknowledged", len(rows), check.FormatSize(ctx, totalSize)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Pattern", "Size", "Rows", "Scans", "Writes", "Last Activity", "Status"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "leftover_tables", Value: float64(warned), Unit: check.UnitCount},
+			{Name: "leftover_size", Value: float64(warnSize), Unit: check.UnitBytes},
+		},
+	}
+	if warned > 0 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%d table(s) named like temporary tables, backups or dated copies take %s; "+
+			"%d had no scans or writes since the statistics reset", warned, check.FormatSize(ctx, warnSize), idle)
+		finding.Remediation = leftoverRemediation
+	}
+	report.AddFinding(finding)
+}
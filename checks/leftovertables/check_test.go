@@ -0,0 +1,158 @@
+package leftovertables_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/leftovertables"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockLeftoverTablesQueryer struct {
+	rows []db.LeftoverTablesRow
+	err  error
+}
+
+func (m *mockLeftoverTablesQueryer) LeftoverTables(context.Context) ([]db.LeftoverTablesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func leftover(table, pattern string, size, scans, writes int64) db.LeftoverTablesRow {
+	return db.LeftoverTablesRow{
+		SchemaName:     "public",
+		TableName:      table,
+		Pattern:        pattern,
+		TotalSizeBytes: size,
+		EstimatedRows:  1000,
+		Scans:          scans,
+		Writes:         writes,
+	}
+}
+
+func Test_LeftoverTables(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.LeftoverTablesRow{
+		leftover("orders_backup_2024", "backup", 120*check.GiB, 0, 0),
+		leftover("temp_readings", "temporary", 2*check.GiB, 50_000, 1_200),
+	}
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.LeftoverTablesRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no matching tables - OK",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No tables named like temporary tables, backups or dated copies",
+		},
+		{
+			Name:             "matching tables warn",
+			Rows:             rows,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 table(s) named like temporary tables, backups or dated copies take 122.0GiB; 1 had no scans or writes since the statistics reset",
+		},
+		{
+			Name:             "acknowledged tables are not counted",
+			Rows:             rows,
+			Config:           check.Config{"leftover-tables": {"acknowledged": "public.temp_readings"}},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 table(s) named like temporary tables, backups or dated copies take 120.0GiB; 1 had no scans or writes since the statistics reset",
+		},
+		{
+			Name:             "all acknowledged - OK",
+			Rows:             rows,
+			Config:           check.Config{"leftover-tables": {"acknowledged": "public.temp_readings, public.orders_backup_2024"}},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "2 leftover-looking table(s) (122.0GiB), all acknowledged",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := leftovertables.New(&mockLeftoverTablesQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Results[0].Severity)
+			require.Equal(t, tc.ExpectedDetails, report.Results[0].Details)
+		})
+	}
+}
+
+func Test_LeftoverTables_Table(t *testing.T) {
+	t.Parallel()
+
+	backup := leftover("orders_backup_2024", "backup", 120*check.GiB, 0, 0)
+	backup.LastActivity = pgtype.Timestamptz{Time: time.Date(2024, 3, 1, 2, 15, 0, 0, time.UTC), Valid: true}
+
+	checker := leftovertables.New(&mockLeftoverTablesQueryer{rows: []db.LeftoverTablesRow{
+		backup,
+		leftover("temp_readings", "temporary", 2*check.GiB, 50_000, 1_200),
+	}}, check.Config{"leftover-tables": {"acknowledged": "public.temp_readings"}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, []string{"public.orders_backup_2024", "backup", "120.0GiB", "1.0K", "0", "0", "2024-03-01 02:15", "idle"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityWarn, finding.Table.Rows[0].Severity)
+	require.Equal(t, []string{"public.temp_readings", "temporary", "2.0GiB", "1.0K", "50.0K", "1.2K", "never", "acknowledged"}, finding.Table.Rows[1].Cells)
+	require.Equal(t, check.SeverityOK, finding.Table.Rows[1].Severity)
+	require.Equal(t, float64(1), finding.Metrics[0].Value)
+	require.NotEmpty(t, finding.Remediation)
+	require.Empty(t, finding.FixSQL, "dropping tables is left to the user")
+}
+
+func Test_LeftoverTables_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("LeftoverTables",
+		[]string{
+			"schema_name text", "table_name text", "pattern text", "total_size_bytes int8",
+			"estimated_rows int8", "scans int8", "writes int8", "last_activity timestamptz",
+		},
+		[]any{"public", "users_20230115", "dated", 8 * check.GiB, 2000000, 0, 0, nil},
+	)
+
+	report, err := leftovertables.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "public.users_20230115", report.Results[0].Table.Rows[0].Cells[0])
+}
+
+func Test_LeftoverTables_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := leftovertables.New(&mockLeftoverTablesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "leftover-tables")
+}
+
+func Test_LeftoverTables_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := leftovertables.Metadata()
+
+	require.Equal(t, "leftover-tables", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
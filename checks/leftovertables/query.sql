@@ -0,0 +1,48 @@
+-- name: LeftoverTables :many
+-- User tables whose name looks like a temporary table, a backup or a dated
+-- copy, with their size and activity since the statistics were reset.
+-- last_activity is the latest vacuum or analyze, which follow writes.
+-- Partitions, often named after dates, and extension tables are left out.
+WITH named AS (
+  SELECT
+    c.oid
+    , n.nspname::text AS schema_name
+    , c.relname::text AS table_name
+    , CASE
+      WHEN c.relname ~* '^(tmp|temp)_|_(tmp|temp)\d*$' THEN 'temporary'
+      WHEN c.relname ~* '_(backup|bak|bkp)(\d*$|_)' THEN 'backup'
+      WHEN c.relname ~* '_old(\d*$|_)' THEN 'old'
+      WHEN c.relname ~* '_copy(\d*$|_)' THEN 'copy'
+      WHEN c.relname ~* '_(19|20)\d{2}_?(0[1-9]|1[0-2])(_?(0[1-9]|[12]\d|3[01]))?$' THEN 'dated'
+    END AS pattern
+    , greatest(c.reltuples, 0)::bigint AS estimated_rows
+  FROM pg_catalog.pg_class AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  WHERE
+    c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+SELECT
+  t.schema_name
+  , t.table_name
+  , t.pattern::text AS pattern
+  , pg_total_relation_size(t.oid) AS total_size_bytes
+  , t.estimated_rows
+  , (coalesce(s.seq_scan, 0) + coalesce(s.idx_scan, 0))::bigint AS scans
+  , (coalesce(s.n_tup_ins, 0) + coalesce(s.n_tup_upd, 0) + coalesce(s.n_tup_del, 0))::bigint AS writes
+  , greatest(s.last_vacuum, s.last_autovacuum, s.last_analyze, s.last_autoanalyze) AS last_activity
+FROM named AS t
+LEFT JOIN pg_catalog.pg_stat_user_tables AS s ON t.oid = s.relid
+WHERE t.pattern IS NOT NULL
+ORDER BY total_size_bytes DESC, t.schema_name, t.table_name;
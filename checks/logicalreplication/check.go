@@ -0,0 +1,315 @@
+// Package logicalreplication implements checks for logical replication
+// subscriptions and publications.
+package logicalreplication
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultLagWarnSeconds = 300
+	defaultLagFailSeconds = 3600
+)
+
+// workerRemediation starts from the log: a failing apply worker logs its
+// error and restarts in a loop, and the subscription stops moving.
+const workerRemediation = "Check the subscriber's log for apply worker errors (conflicts such as duplicate keys stop replication until resolved), " +
+	"and re-enable disabled subscriptions with ALTER SUBSCRIPTION ... ENABLE or drop them: their slot on the publisher retains WAL"
+
+const syncRemediation = "Tables stay in initial sync while their copy runs or keeps failing; check the subscriber's log for table synchronization worker errors " +
+	"and max_sync_workers_per_subscription if many tables wait"
+
+const lagRemediation = "Check the subscriber for long transactions or lock waits blocking the apply worker, " +
+	"and the publisher for large transactions being decoded"
+
+const publicationRemediation = "Drop publications nothing subscribes to any more, or find out why their subscribers disconnected; " +
+	"inactive slots retain WAL on this server (see replication-slots)"
+
+// syncStates names pg_subscription_rel.srsubstate values.
+var syncStates = map[string]string{
+	"i": "initializing",
+	"d": "copying data",
+	"f": "finished copy",
+	"s": "synchronized",
+}
+
+type LogicalReplicationQueries interface {
+	Subscriptions(context.Context) ([]db.SubscriptionsRow, error)
+	SubscriptionTablesNotReady(context.Context) ([]db.SubscriptionTablesNotReadyRow, error)
+	Publications(context.Context) ([]db.PublicationsRow, error)
+}
+
+type checker struct {
+	queries        LogicalReplicationQueries
+	lagWarnSeconds int64 // default: 300
+	lagFailSeconds int64 // default: 3600
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "logical-replication",
+		Name:        "Logical Replication",
+		Description: "Detects stopped or lagging subscriptions, tables stuck in sync, and publications without subscribers",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries LogicalReplicationQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:        queries,
+		lagWarnSeconds: defaultLagWarnSeconds,
+		lagFailSeconds: defaultLagFailSeconds,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["lag_warn_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.lagWarnSeconds = n
+				}
+			}
+			if v, ok := myCfg["lag_fail_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.lagFailSeconds = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	subscriptions, err := c.queries.Subscriptions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	publications, err := c.queries.Publications(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if len(subscriptions) == 0 && len(publications) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No subscriptions or publications in this database",
+		})
+		return report, nil
+	}
+
+	if len(subscriptions) > 0 {
+		tables, err := c.queries.SubscriptionTablesNotReady(ctx)
+		if err != nil {
+			return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+		}
+
+		checkWorkers(subscriptions, report)
+		checkTableSync(tables, report)
+		c.checkApplyLag(subscriptions, report)
+	}
+	if len(publications) > 0 {
+		checkPublications(publications, report)
+	}
+
+	return report, nil
+}
+
+// checkWorkers fails on enabled subscriptions without an apply worker, which
+// replicate nothing, and warns on disabled ones.
+func checkWorkers(subscriptions []db.SubscriptionsRow, report *check.Report) {
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	for _, sub := range subscriptions {
+		var rowSeverity check.Severity
+		var status string
+		switch {
+		case !sub.Enabled:
+			rowSeverity, status = check.SeverityWarn, "disabled"
+		case !sub.WorkerPid.Valid:
+			rowSeverity, status = check.SeverityFail, "not running"
+		default:
+			continue
+		}
+		severity = max(severity, rowSeverity)
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{sub.SubscriptionName, status, strconv.FormatInt(sub.TableCount, 10)},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "subscription-workers",
+			Name:     "Subscription Workers",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("All %d subscription(s) have a running apply worker", len(subscriptions)),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "subscription-workers",
+		Name:        "Subscription Workers",
+		Severity:    severity,
+		Details:     fmt.Sprintf("%d of %d subscription(s) are not replicating", len(tableRows), len(subscriptions)),
+		Remediation: workerRemediation,
+		Table: &check.Table{
+			Headers: []string{"Subscription", "Status", "Tables"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkTableSync lists subscribed tables that have not reached the ready
+// state. Their changes are applied only once the initial copy completes.
+func checkTableSync(tables []db.SubscriptionTablesNotReadyRow, report *check.Report) {
+	if len(tables) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "subscription-table-sync",
+			Name:     "Subscription Table Sync",
+			Severity: check.SeverityOK,
+			Details:  "All subscribed tables are ready",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(tables))
+	for _, t := range tables {
+		state := syncStates[t.SyncState]
+		if state == "" {
+			state = t.SyncState
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{t.SubscriptionName, t.SchemaName + "." + t.TableName, state},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "subscription-table-sync",
+		Name:        "Subscription Table Sync",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("%d subscribed table(s) not ready", len(tables)),
+		Remediation: syncRemediation,
+		Table: &check.Table{
+			Headers: []string{"Subscription", "Table", "State"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkApplyLag measures how long ago each apply worker last reported its
+// position to the publisher. Keepalives refresh it on an idle publisher.
+func (c *checker) checkApplyLag(subscriptions []db.SubscriptionsRow, report *check.Report) {
+	severity := check.SeverityOK
+	var lagging []string
+	var maxLag int64
+	for _, sub := range subscriptions {
+		if !sub.ApplyLagSeconds.Valid {
+			continue
+		}
+		lag := sub.ApplyLagSeconds.Int64
+		maxLag = max(maxLag, lag)
+		switch {
+		case lag >= c.lagFailSeconds:
+			severity = check.SeverityFail
+		case lag >= c.lagWarnSeconds:
+			severity = max(severity, check.SeverityWarn)
+		default:
+			continue
+		}
+		lagging = append(lagging, fmt.Sprintf("%s (%s)", sub.SubscriptionName, check.FormatDurationSec(lag)))
+	}
+
+	metrics := []check.Metric{
+		{Name: "max_apply_lag", Value: float64(maxLag), Unit: check.UnitSeconds},
+	}
+
+	if len(lagging) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "subscription-apply-lag",
+			Name:     "Subscription Apply Lag",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Apply lag up to %s", check.FormatDurationSec(maxLag)),
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "subscription-apply-lag",
+		Name:        "Subscription Apply Lag",
+		Severity:    severity,
+		Details:     fmt.Sprintf("%d subscription(s) behind by %s or more: %s", len(lagging), check.FormatDurationSec(c.lagWarnSeconds), strings.Join(lagging, ", ")),
+		Remediation: lagRemediation,
+		Metrics:     metrics,
+	})
+}
+
+// checkPublications warns when no subscriber is streaming from this
+// database. Subscribers do not say which publications they read, so a
+// publication is only known to be unused when no pgoutput slot is active.
+func checkPublications(publications []db.PublicationsRow, report *check.Report) {
+	activeSlots, slots := publications[0].ActiveSlotCount, publications[0].SlotCount
+	if activeSlots > 0 {
+		report.AddFinding(check.Finding{
+			ID:       "publication-subscribers",
+			Name:     "Publication Subscribers",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%d publication(s), %d active subscriber connection(s)", len(publications), activeSlots),
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(publications))
+	for _, p := range publications {
+		tables := strconv.FormatInt(p.TableCount, 10)
+		if p.AllTables {
+			tables = "all"
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{p.PublicationName, tables},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	details := fmt.Sprintf("%d publication(s) and no active subscriber in this database", len(publications))
+	if slots > 0 {
+		details += fmt.Sprintf("; %d inactive pgoutput slot(s) retain WAL", slots)
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "publication-subscribers",
+		Name:        "Publication Subscribers",
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: publicationRemediation,
+		Table: &check.Table{
+			Headers: []string{"Publication", "Tables"},
+			Rows:    tableRows,
+		},
+	})
+}
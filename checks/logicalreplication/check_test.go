@@ -0,0 +1,247 @@
+package logicalreplication_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/logicalreplication"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockLogicalReplicationQueryer struct {
+	subscriptions []db.SubscriptionsRow
+	tables        []db.SubscriptionTablesNotReadyRow
+	publications  []db.PublicationsRow
+	err           error
+}
+
+func (m *mockLogicalReplicationQueryer) Subscriptions(context.Context) ([]db.SubscriptionsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.subscriptions, nil
+}
+
+func (m *mockLogicalReplicationQueryer) SubscriptionTablesNotReady(context.Context) ([]db.SubscriptionTablesNotReadyRow, error) {
+	return m.tables, nil
+}
+
+func (m *mockLogicalReplicationQueryer) Publications(context.Context) ([]db.PublicationsRow, error) {
+	return m.publications, nil
+}
+
+func running(name string, lagSeconds int64) db.SubscriptionsRow {
+	return db.SubscriptionsRow{
+		SubscriptionName: name,
+		Enabled:          true,
+		WorkerPid:        pgtype.Int4{Int32: 4242, Valid: true},
+		ApplyLagSeconds:  pgtype.Int8{Int64: lagSeconds, Valid: true},
+		TableCount:       12,
+	}
+}
+
+func Test_LogicalReplication_Nothing(t *testing.T) {
+	t.Parallel()
+
+	report, err := logicalreplication.New(&mockLogicalReplicationQueryer{}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Equal(t, "No subscriptions or publications in this database", report.Results[0].Details)
+}
+
+func Test_LogicalReplication_Workers(t *testing.T) {
+	t.Parallel()
+
+	disabled := running("reporting", 0)
+	disabled.Enabled = false
+	disabled.WorkerPid = pgtype.Int4{}
+	disabled.ApplyLagSeconds = pgtype.Int8{}
+	crashed := running("orders", 0)
+	crashed.WorkerPid = pgtype.Int4{}
+	crashed.ApplyLagSeconds = pgtype.Int8{}
+
+	testCases := []struct {
+		Name             string
+		Subscriptions    []db.SubscriptionsRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "workers running - OK",
+			Subscriptions:    []db.SubscriptionsRow{running("orders", 2)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "All 1 subscription(s) have a running apply worker",
+		},
+		{
+			Name:             "disabled subscription warns",
+			Subscriptions:    []db.SubscriptionsRow{running("orders", 2), disabled},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 of 2 subscription(s) are not replicating",
+		},
+		{
+			Name:             "enabled subscription without worker fails",
+			Subscriptions:    []db.SubscriptionsRow{crashed, disabled},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "2 of 2 subscription(s) are not replicating",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := logicalreplication.New(&mockLogicalReplicationQueryer{subscriptions: tc.Subscriptions})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "subscription-workers")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedDetails, finding.Details)
+		})
+	}
+}
+
+func Test_LogicalReplication_TableSync(t *testing.T) {
+	t.Parallel()
+
+	checker := logicalreplication.New(&mockLogicalReplicationQueryer{
+		subscriptions: []db.SubscriptionsRow{running("orders", 2)},
+		tables: []db.SubscriptionTablesNotReadyRow{
+			{SubscriptionName: "orders", SchemaName: "public", TableName: "line_items", SyncState: "d"},
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "subscription-table-sync")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "1 subscribed table(s) not ready", finding.Details)
+	require.Equal(t, []string{"orders", "public.line_items", "copying data"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_LogicalReplication_ApplyLag(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Subscriptions    []db.SubscriptionsRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "caught up - OK",
+			Subscriptions:    []db.SubscriptionsRow{running("orders", 5)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Apply lag up to 5s",
+		},
+		{
+			Name:             "behind by minutes warns",
+			Subscriptions:    []db.SubscriptionsRow{running("orders", 5), running("events", 900)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 subscription(s) behind by 5m or more: events (15m)",
+		},
+		{
+			Name:             "behind by hours fails",
+			Subscriptions:    []db.SubscriptionsRow{running("orders", 7200), running("events", 900)},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "2 subscription(s) behind by 5m or more: orders (2h), events (15m)",
+		},
+		{
+			Name:             "configured thresholds",
+			Subscriptions:    []db.SubscriptionsRow{running("events", 900)},
+			Config:           check.Config{"logical-replication": {"lag_warn_seconds": "1800"}},
+			ExpectedSeverity: check.SeverityOK,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := logicalreplication.New(&mockLogicalReplicationQueryer{subscriptions: tc.Subscriptions}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "subscription-apply-lag")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_LogicalReplication_Publications(t *testing.T) {
+	t.Parallel()
+
+	publications := func(slots, active int64) []db.PublicationsRow {
+		return []db.PublicationsRow{
+			{PublicationName: "cdc", AllTables: true, SlotCount: slots, ActiveSlotCount: active},
+			{PublicationName: "orders_pub", TableCount: 3, SlotCount: slots, ActiveSlotCount: active},
+		}
+	}
+
+	report, err := logicalreplication.New(&mockLogicalReplicationQueryer{publications: publications(1, 1)}).Check(context.Background())
+	require.NoError(t, err)
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "publication-subscribers").Severity)
+
+	report, err = logicalreplication.New(&mockLogicalReplicationQueryer{publications: publications(2, 0)}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "publication-subscribers")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "2 publication(s) and no active subscriber in this database; 2 inactive pgoutput slot(s) retain WAL", finding.Details)
+	require.Equal(t, []string{"cdc", "all"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"orders_pub", "3"}, finding.Table.Rows[1].Cells)
+}
+
+func Test_LogicalReplication_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("Subscriptions",
+			[]string{"subscription_name text", "enabled bool", "worker_pid int4", "apply_lag_seconds int8", "table_count int8"},
+			[]any{"orders", "t", nil, nil, 12},
+		).
+		Add("SubscriptionTablesNotReady",
+			[]string{"subscription_name text", "schema_name text", "table_name text", "sync_state text"},
+		).
+		Add("Publications",
+			[]string{"publication_name text", "all_tables bool", "table_count int8", "slot_count int8", "active_slot_count int8"},
+		)
+
+	report, err := logicalreplication.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityFail, report.Severity)
+	require.Equal(t, []string{"orders", "not running", "12"}, checktest.Finding(t, report, "subscription-workers").Table.Rows[0].Cells)
+}
+
+func Test_LogicalReplication_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := logicalreplication.New(&mockLogicalReplicationQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "logical-replication")
+}
+
+func Test_LogicalReplication_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := logicalreplication.Metadata()
+
+	require.Equal(t, "logical-replication", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,57 @@
+-- name: Subscriptions :many
+-- Subscriptions of the current database with their apply worker, if running.
+-- Parallel apply workers (PostgreSQL 16+) also have no relid; they report no
+-- latest_end_time, so the leader sorts first.
+SELECT
+  s.subname::text AS subscription_name
+  , s.subenabled AS enabled
+  , w.pid AS worker_pid
+  , extract(EPOCH FROM (now() - w.latest_end_time))::bigint AS apply_lag_seconds
+  , (SELECT count(*) FROM pg_subscription_rel AS r WHERE r.srsubid = s.oid) AS table_count
+FROM pg_subscription AS s
+LEFT JOIN LATERAL (
+  SELECT
+    st.pid
+    , st.latest_end_time
+  FROM pg_stat_subscription AS st
+  WHERE st.subid = s.oid AND st.relid IS NULL
+  ORDER BY st.latest_end_time DESC NULLS LAST
+  LIMIT 1
+) AS w ON true
+WHERE s.subdbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+ORDER BY s.subname;
+
+-- name: SubscriptionTablesNotReady :many
+-- Subscribed tables still synchronizing (any state but 'r', ready).
+SELECT
+  s.subname::text AS subscription_name
+  , n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , r.srsubstate::text AS sync_state
+FROM pg_subscription_rel AS r
+INNER JOIN pg_subscription AS s ON s.oid = r.srsubid
+INNER JOIN pg_class AS c ON c.oid = r.srrelid
+INNER JOIN pg_namespace AS n ON n.oid = c.relnamespace
+WHERE
+  r.srsubstate <> 'r'
+  AND s.subdbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+ORDER BY s.subname, n.nspname, c.relname;
+
+-- name: Publications :many
+-- Publications of the current database. Which publications a subscriber reads
+-- is only known to its walsender, so subscribers are counted per database as
+-- pgoutput slots.
+SELECT
+  p.pubname::text AS publication_name
+  , p.puballtables AS all_tables
+  , (SELECT count(*) FROM pg_publication_tables AS pt WHERE pt.pubname = p.pubname) AS table_count
+  , (
+    SELECT count(*) FROM pg_replication_slots AS sl
+    WHERE sl.database = current_database() AND sl.slot_type = 'logical' AND sl.plugin = 'pgoutput'
+  ) AS slot_count
+  , (
+    SELECT count(*) FROM pg_replication_slots AS sl
+    WHERE sl.database = current_database() AND sl.slot_type = 'logical' AND sl.plugin = 'pgoutput' AND sl.active
+  ) AS active_slot_count
+FROM pg_publication AS p
+ORDER BY p.pubname;
@@ -0,0 +1,194 @@
+// Package longrunningqueries implements a check for statements running longer than expected.
+package longrunningqueries
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultWarnSeconds = 300  // 5 minutes
+	defaultFailSeconds = 3600 // 1 hour
+
+	// maxQueryLength keeps the query column readable in text output.
+	maxQueryLength = 80
+)
+
+// remediation points at cancelling, not terminating: the session survives.
+const remediation = "Check whether each query is expected to run this long; cancel runaway ones with " +
+	"pg_cancel_backend(pid) and set statement_timeout for the roles that issue them"
+
+// maintenanceBackends run long by design: autovacuum and replication.
+var maintenanceBackends = map[string]bool{
+	"autovacuum worker": true,
+	"walsender":         true,
+	"walreceiver":       true,
+}
+
+type LongRunningQueriesQueries interface {
+	LongRunningQueries(context.Context) ([]db.LongRunningQueriesRow, error)
+}
+
+type checker struct {
+	queries            LongRunningQueriesQueries
+	warnSeconds        int64 // default: 300
+	failSeconds        int64 // default: 3600
+	includeMaintenance bool  // default: false
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryPerformance,
+		CheckID:     "long-running-queries",
+		Name:        "Long-Running Queries",
+		Description: "Identifies active statements running longer than a threshold",
+		Readme:      readme,
+		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
+	}
+}
+
+func New(queries LongRunningQueriesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		warnSeconds: defaultWarnSeconds,
+		failSeconds: defaultFailSeconds,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["warn_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.warnSeconds = n
+				}
+			}
+			if v, ok := myCfg["fail_seconds"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.failSeconds = n
+				}
+			}
+			if v, ok := myCfg["include_maintenance"]; ok {
+				if b, err := strconv.ParseBool(v); err == nil {
+					c.includeMaintenance = b
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.LongRunningQueries(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	var longest int64
+	for _, row := range rows {
+		if row.DurationSeconds < c.warnSeconds || !c.includes(row) {
+			continue
+		}
+		rowSeverity := check.SeverityWarn
+		if row.DurationSeconds >= c.failSeconds {
+			rowSeverity = check.SeverityFail
+		}
+		severity = max(severity, rowSeverity)
+		longest = max(longest, row.DurationSeconds)
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				strconv.FormatInt(int64(row.Pid.Int32), 10),
+				row.Username,
+				row.ApplicationName,
+				check.FormatDurationSec(row.DurationSeconds),
+				formatWaitEvent(row),
+				truncateQuery(row.QueryPreview),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No queries running longer than %s", check.FormatDurationSec(c.warnSeconds)),
+		})
+		return report, nil
+	}
+
+	details := fmt.Sprintf("%d quer%s running longer than %s (longest: %s)",
+		len(tableRows), plural(len(tableRows)), check.FormatDurationSec(c.warnSeconds), check.FormatDurationSec(longest))
+
+	report.AddFinding(check.Finding{
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    severity,
+		Details:     details,
+		Remediation: remediation,
+		Metrics: []check.Metric{
+			{Name: "long_running_queries", Value: float64(len(tableRows)), Unit: check.UnitCount},
+			{Name: "longest_query_duration", Value: float64(longest), Unit: check.UnitSeconds},
+		},
+		Table: &check.Table{
+			Headers: []string{"PID", "User", "Application", "Duration", "Wait Event", "Query"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
+
+// includes filters out autovacuum and replication processes, unless configured
+// to keep them, and parallel workers, which repeat their leader's query.
+func (c *checker) includes(row db.LongRunningQueriesRow) bool {
+	if row.BackendType == "parallel worker" {
+		return false
+	}
+	return c.includeMaintenance || !maintenanceBackends[row.BackendType]
+}
+
+func formatWaitEvent(row db.LongRunningQueriesRow) string {
+	if row.WaitEventType == "" {
+		return "-"
+	}
+	return row.WaitEventType + ": " + row.WaitEvent
+}
+
+// truncateQuery collapses whitespace so multi-line statements fit in one cell.
+func truncateQuery(query string) string {
+	query = strings.Join(strings.Fields(query), " ")
+	if len(query) <= maxQueryLength {
+		return query
+	}
+	return strings.ToValidUTF8(query[:maxQueryLength-3], "") + "..."
+}
+
+func plural(n int) string {
+	if n == 1 {
+		return "y"
+	}
+	return "ies"
+}
@@ -0,0 +1,171 @@
+package longrunningqueries_test
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/longrunningqueries"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockLongRunningQueriesQueryer struct {
+	rows []db.LongRunningQueriesRow
+	err  error
+}
+
+func (m *mockLongRunningQueriesQueryer) LongRunningQueries(context.Context) ([]db.LongRunningQueriesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func query(pid int32, backendType string, seconds int64) db.LongRunningQueriesRow {
+	return db.LongRunningQueriesRow{
+		Pid:             pgtype.Int4{Int32: pid, Valid: true},
+		Username:        "app",
+		ApplicationName: "api",
+		BackendType:     backendType,
+		DurationSeconds: seconds,
+		QueryPreview:    "SELECT * FROM orders",
+	}
+}
+
+func Test_LongRunningQueries(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.LongRunningQueriesRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no active queries - OK",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No queries running longer than 5m",
+		},
+		{
+			Name:             "short queries - OK",
+			Rows:             []db.LongRunningQueriesRow{query(101, "client backend", 299)},
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name:             "query past the warning threshold",
+			Rows:             []db.LongRunningQueriesRow{query(101, "client backend", 300)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 query running longer than 5m (longest: 5m)",
+		},
+		{
+			Name: "query past the failure threshold",
+			Rows: []db.LongRunningQueriesRow{
+				query(101, "client backend", 7200),
+				query(102, "client backend", 600),
+			},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "2 queries running longer than 5m (longest: 2h)",
+		},
+		{
+			Name: "autovacuum, replication and parallel workers are skipped",
+			Rows: []db.LongRunningQueriesRow{
+				query(101, "autovacuum worker", 7200),
+				query(102, "walsender", 86400),
+				query(103, "parallel worker", 7200),
+			},
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name:             "maintenance included when configured",
+			Rows:             []db.LongRunningQueriesRow{query(101, "autovacuum worker", 7200)},
+			Config:           check.Config{"long-running-queries": {"include_maintenance": "true"}},
+			ExpectedSeverity: check.SeverityFail,
+		},
+		{
+			Name:             "configured thresholds",
+			Rows:             []db.LongRunningQueriesRow{query(101, "client backend", 90)},
+			Config:           check.Config{"long-running-queries": {"warn_seconds": "30", "fail_seconds": "60"}},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "1 query running longer than 30s",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := longrunningqueries.New(&mockLongRunningQueriesQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_LongRunningQueries_Table(t *testing.T) {
+	t.Parallel()
+
+	row := query(4242, "client backend", 1800)
+	row.WaitEventType = "Lock"
+	row.WaitEvent = "relation"
+	row.QueryPreview = "UPDATE orders\n   SET status = 'shipped'\n WHERE id IN (" + strings.Repeat("1, ", 40) + "1)"
+
+	report, err := longrunningqueries.New(&mockLongRunningQueriesQueryer{rows: []db.LongRunningQueriesRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, []string{"PID", "User", "Application", "Duration", "Wait Event", "Query"}, finding.Table.Headers)
+	cells := finding.Table.Rows[0].Cells
+	require.Equal(t, []string{"4242", "app", "api", "30m", "Lock: relation"}, cells[:5])
+	require.True(t, strings.HasPrefix(cells[5], "UPDATE orders SET status = 'shipped' WHERE id IN (1, "), cells[5])
+	require.Len(t, cells[5], 80)
+	require.Equal(t, 1800.0, finding.Metrics[1].Value)
+}
+
+func Test_LongRunningQueries_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("LongRunningQueries",
+		[]string{
+			"pid int4", "username text", "application_name text", "backend_type text",
+			"duration_seconds int8", "wait_event_type text", "wait_event text", "query_preview text",
+		},
+		[]any{4242, "app", "api", "client backend", 900, "", "", "SELECT pg_sleep(900)"},
+	)
+
+	report, err := longrunningqueries.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "-", report.Results[0].Table.Rows[0].Cells[4])
+}
+
+func Test_LongRunningQueries_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := longrunningqueries.New(&mockLongRunningQueriesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "long-running-queries")
+}
+
+func Test_LongRunningQueries_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := longrunningqueries.Metadata()
+
+	require.Equal(t, "long-running-queries", metadata.CheckID)
+	require.Equal(t, check.CategoryPerformance, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
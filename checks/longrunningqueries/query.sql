@@ -0,0 +1,18 @@
+-- name: LongRunningQueries :many
+-- Active statements with how long they have been running, longest first.
+-- Thresholds and the maintenance/replication filter are applied in Go.
+SELECT
+  pid
+  , COALESCE(usename::text, '') AS username
+  , COALESCE(application_name::text, '') AS application_name
+  , COALESCE(backend_type::text, '') AS backend_type
+  , COALESCE(extract(EPOCH FROM (now() - query_start))::bigint, 0) AS duration_seconds
+  , COALESCE(wait_event_type::text, '') AS wait_event_type
+  , COALESCE(wait_event::text, '') AS wait_event
+  , COALESCE(left(query, 200), '') AS query_preview
+FROM pg_stat_activity
+WHERE
+  state = 'active'
+  AND query_start IS NOT NULL
+  AND pid != pg_backend_pid()
+ORDER BY query_start ASC;
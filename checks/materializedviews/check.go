@@ -0,0 +1,293 @@
+// Package materializedviews implements checks for materialized views: empty
+// views, views that cannot be refreshed concurrently, and stale ones.
+package materializedviews
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgtype"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const defaultStaleDays = 7
+
+const unpopulatedRemediation = "Refresh the view, or drop it if nothing reads it: " +
+	"selecting from a view created WITH NO DATA fails until it is refreshed"
+
+const uniqueIndexRemediation = "Add a unique index on plain columns that identify each row, " +
+	"then refresh with REFRESH MATERIALIZED VIEW CONCURRENTLY so readers are not blocked while it runs"
+
+const staleRemediation = "Check that the job refreshing these views still runs (pg_cron, an application scheduler), " +
+	"or drop views nothing reads any more"
+
+type MaterializedViewsQueries interface {
+	MaterializedViews(context.Context) ([]db.MaterializedViewsRow, error)
+}
+
+type checker struct {
+	queries   MaterializedViewsQueries
+	staleDays int64 // default: 7
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "materialized-views",
+		Name:        "Materialized Views",
+		Description: "Lists materialized views and flags empty, stale ones and those that cannot be refreshed concurrently",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries MaterializedViewsQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:   queries,
+		staleDays: defaultStaleDays,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["stale_days"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.staleDays = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.MaterializedViews(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.MaterializedViewsRow) string { return r.SchemaName + "." + r.ViewName })
+
+	if !checkOverview(ctx, rows, report) {
+		return report, nil
+	}
+
+	checkUnpopulated(rows, report)
+	// Refreshing a small view takes milliseconds, so the lock does not matter.
+	checkUniqueIndex(ctx, check.FilterSmallTables(ctx, rows, func(r db.MaterializedViewsRow) (int64, int64) {
+		return r.EstimatedRows, r.TotalSizeBytes
+	}), report)
+	c.checkStale(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkOverview lists every materialized view. It returns false when there
+// are none, so the other subchecks can be skipped.
+func checkOverview(ctx context.Context, rows []db.MaterializedViewsRow, report *check.Report) bool {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No materialized views",
+		})
+		return false
+	}
+
+	var totalSize int64
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		totalSize += row.TotalSizeBytes
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.ViewName,
+				check.FormatSize(ctx, row.TotalSizeBytes),
+				check.FormatNumber(row.EstimatedRows),
+				yesNo(row.IsPopulated),
+				yesNo(row.HasUniqueIndex),
+				formatAge(row.RefreshAgeSeconds),
+			},
+			Severity: check.SeverityOK,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%d materialized view(s) (%s)", len(rows), check.FormatSize(ctx, totalSize)),
+		Table: &check.Table{
+			Headers: []string{"View", "Size", "Rows", "Populated", "Unique Index", "Last Written"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "materialized_views", Value: float64(len(rows)), Unit: check.UnitCount},
+			{Name: "materialized_views_size", Value: float64(totalSize), Unit: check.UnitBytes},
+		},
+	})
+	return true
+}
+
+// checkUnpopulated warns on views created or left WITH NO DATA.
+func checkUnpopulated(rows []db.MaterializedViewsRow, report *check.Report) {
+	var names, fixes []string
+	for _, row := range rows {
+		if row.IsPopulated {
+			continue
+		}
+		names = append(names, row.SchemaName+"."+row.ViewName)
+		fixes = append(fixes, fmt.Sprintf("REFRESH MATERIALIZED VIEW %s;", pgx.Identifier{row.SchemaName, row.ViewName}.Sanitize()))
+	}
+
+	if len(names) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "unpopulated",
+			Name:     "Unpopulated Views",
+			Severity: check.SeverityOK,
+			Details:  "All materialized views hold data",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "unpopulated",
+		Name:        "Unpopulated Views",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("%d materialized view(s) have never been refreshed and cannot be queried: %s", len(names), strings.Join(names, ", ")),
+		Remediation: unpopulatedRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+	})
+}
+
+// checkUniqueIndex warns on views without a unique index on plain columns,
+// which REFRESH MATERIALIZED VIEW CONCURRENTLY requires. Without it, every
+// refresh takes an ACCESS EXCLUSIVE lock that blocks readers until it ends.
+func checkUniqueIndex(ctx context.Context, rows []db.MaterializedViewsRow, report *check.Report) {
+	var tableRows []check.TableRow
+	for _, row := range rows {
+		if row.HasUniqueIndex {
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.ViewName,
+				check.FormatSize(ctx, row.TotalSizeBytes),
+				check.FormatNumber(row.EstimatedRows),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "no-unique-index",
+			Name:     "Concurrent Refresh",
+			Severity: check.SeverityOK,
+			Details:  "All materialized views above the size floors can be refreshed concurrently",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "no-unique-index",
+		Name:     "Concurrent Refresh",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d materialized view(s) have no unique index, so REFRESH ... CONCURRENTLY fails "+
+			"and every refresh blocks readers", len(tableRows)),
+		Remediation: uniqueIndexRemediation,
+		Table: &check.Table{
+			Headers: []string{"View", "Size", "Rows"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkStale warns on populated views whose data file has not been written
+// for longer than configured. Without the privilege to read file times, the
+// subcheck is skipped.
+func (c *checker) checkStale(ctx context.Context, rows []db.MaterializedViewsRow, report *check.Report) {
+	maxAge := c.staleDays * 86400
+	known := false
+	var tableRows []check.TableRow
+	for _, row := range rows {
+		if !row.RefreshAgeSeconds.Valid {
+			continue
+		}
+		known = true
+		if !row.IsPopulated || row.RefreshAgeSeconds.Int64 <= maxAge {
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.ViewName,
+				check.FormatSize(ctx, row.TotalSizeBytes),
+				check.FormatDurationSec(row.RefreshAgeSeconds.Int64),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if !known {
+		report.AddFinding(check.Finding{
+			ID:       "stale",
+			Name:     "Refresh Age",
+			Severity: check.SeveritySkip,
+			Details:  "Refresh times are unknown: reading data file times needs superuser or EXECUTE on pg_stat_file",
+		})
+		return
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "stale",
+			Name:     "Refresh Age",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("All materialized views were written in the last %d day(s)", c.staleDays),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "stale",
+		Name:     "Refresh Age",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d materialized view(s) not refreshed in the last %d day(s) keep serving old data",
+			len(tableRows), c.staleDays),
+		Remediation: staleRemediation,
+		Table: &check.Table{
+			Headers: []string{"View", "Size", "Last Written"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+func yesNo(b bool) string {
+	if b {
+		return "yes"
+	}
+	return "no"
+}
+
+func formatAge(age pgtype.Int8) string {
+	if !age.Valid {
+		return "-"
+	}
+	return check.FormatDurationSec(age.Int64) + " ago"
+}
@@ -0,0 +1,182 @@
+package materializedviews_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/materializedviews"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockMaterializedViewsQueryer struct {
+	views []db.MaterializedViewsRow
+	err   error
+}
+
+func (m *mockMaterializedViewsQueryer) MaterializedViews(context.Context) ([]db.MaterializedViewsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.views, nil
+}
+
+const day = int64(86400)
+
+func makeView(name string, rows int64, populated, unique bool, age pgtype.Int8) db.MaterializedViewsRow {
+	return db.MaterializedViewsRow{
+		SchemaName:        "public",
+		ViewName:          name,
+		IsPopulated:       populated,
+		EstimatedRows:     rows,
+		TotalSizeBytes:    rows * 100,
+		HasUniqueIndex:    unique,
+		RefreshAgeSeconds: age,
+	}
+}
+
+func age(seconds int64) pgtype.Int8 {
+	return pgtype.Int8{Int64: seconds, Valid: true}
+}
+
+func Test_MaterializedViews(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		views    []db.MaterializedViewsRow
+		cfg      check.Config
+		expected map[string]check.Severity
+	}{
+		{
+			name:     "no materialized views",
+			expected: map[string]check.Severity{"materialized-views": check.SeverityOK},
+		},
+		{
+			name:  "healthy view",
+			views: []db.MaterializedViewsRow{makeView("daily_sales", 50_000, true, true, age(3600))},
+			expected: map[string]check.Severity{
+				"materialized-views": check.SeverityOK,
+				"unpopulated":        check.SeverityOK,
+				"no-unique-index":    check.SeverityOK,
+				"stale":              check.SeverityOK,
+			},
+		},
+		{
+			name:  "unpopulated view",
+			views: []db.MaterializedViewsRow{makeView("daily_sales", 0, false, true, age(30*day))},
+			expected: map[string]check.Severity{
+				"unpopulated": check.SeverityWarn,
+				// Empty views are reported as unpopulated, not stale.
+				"stale": check.SeverityOK,
+			},
+		},
+		{
+			name:     "large view without unique index",
+			views:    []db.MaterializedViewsRow{makeView("daily_sales", 50_000, true, false, age(3600))},
+			expected: map[string]check.Severity{"no-unique-index": check.SeverityWarn},
+		},
+		{
+			name:     "small view without unique index",
+			views:    []db.MaterializedViewsRow{makeView("countries", 200, true, false, age(3600))},
+			expected: map[string]check.Severity{"no-unique-index": check.SeverityOK},
+		},
+		{
+			name:     "stale view",
+			views:    []db.MaterializedViewsRow{makeView("daily_sales", 50_000, true, true, age(30*day))},
+			expected: map[string]check.Severity{"stale": check.SeverityWarn},
+		},
+		{
+			name:     "configured staleness",
+			views:    []db.MaterializedViewsRow{makeView("daily_sales", 50_000, true, true, age(30*day))},
+			cfg:      check.Config{"materialized-views": {"stale_days": "60"}},
+			expected: map[string]check.Severity{"stale": check.SeverityOK},
+		},
+		{
+			name:     "refresh age unknown",
+			views:    []db.MaterializedViewsRow{makeView("daily_sales", 50_000, true, true, pgtype.Int8{})},
+			expected: map[string]check.Severity{"stale": check.SeveritySkip},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000})
+			checker := materializedviews.New(&mockMaterializedViewsQueryer{views: tt.views}, tt.cfg)
+			report, err := checker.Check(ctx)
+			require.NoError(t, err)
+
+			for id, severity := range tt.expected {
+				require.Equal(t, severity, checktest.Finding(t, report, id).Severity, id)
+			}
+		})
+	}
+}
+
+func Test_MaterializedViews_UnpopulatedFix(t *testing.T) {
+	t.Parallel()
+
+	views := []db.MaterializedViewsRow{
+		makeView("daily_sales", 50_000, true, true, age(3600)),
+		makeView("Monthly Sales", 0, false, true, age(3600)),
+	}
+	report, err := materializedviews.New(&mockMaterializedViewsQueryer{views: views}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "unpopulated")
+	require.Contains(t, finding.Details, "public.Monthly Sales")
+	require.NotContains(t, finding.Details, "daily_sales")
+	require.Equal(t, `REFRESH MATERIALIZED VIEW "public"."Monthly Sales";`, finding.FixSQL)
+}
+
+func Test_MaterializedViews_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("MaterializedViews",
+		[]string{
+			"schema_name text", "view_name text", "is_populated bool", "estimated_rows int8",
+			"total_size_bytes int8", "has_unique_index bool", "refresh_age_seconds int8",
+		},
+		[]any{"reporting", "daily_sales", true, 2_000_000, 3 * check.GiB, false, 10 * day},
+		[]any{"reporting", "countries", true, 250, 64 * check.KiB, true, nil},
+	)
+
+	report, err := materializedviews.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	overview := checktest.Finding(t, report, "materialized-views")
+	require.Contains(t, overview.Details, "2 materialized view(s)")
+	require.Equal(t, []string{"reporting.daily_sales", "3.0GiB", "2.0M", "yes", "no", "10d ago"}, overview.Table.Rows[0].Cells)
+	require.Equal(t, []string{"reporting.countries", "64.0KiB", "250", "yes", "yes", "-"}, overview.Table.Rows[1].Cells)
+
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "stale").Severity)
+}
+
+func Test_MaterializedViews_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := materializedviews.New(&mockMaterializedViewsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "materialized-views")
+}
+
+func Test_MaterializedViews_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := materializedviews.Metadata()
+
+	require.Equal(t, "materialized-views", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,33 @@
+-- name: MaterializedViews :many
+-- Materialized views with their size, whether they hold data, and whether a
+-- plain unique index allows REFRESH MATERIALIZED VIEW CONCURRENTLY.
+-- PostgreSQL does not record when a view was refreshed: the age of its data
+-- file is the closest thing, and later writes (hint bits, vacuum) can only
+-- make it look fresher. Reading it needs superuser or EXECUTE on
+-- pg_stat_file, so refresh_age_seconds is NULL without that privilege.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS view_name
+  , c.relispopulated AS is_populated
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(c.oid) AS total_size_bytes
+  , EXISTS (
+    SELECT 1
+    FROM pg_index AS i
+    WHERE
+      i.indrelid = c.oid
+      AND i.indisunique
+      AND i.indisvalid
+      AND i.indpred IS NULL
+      AND i.indexprs IS NULL
+  ) AS has_unique_index
+  , CASE
+    WHEN pg_catalog.has_function_privilege('pg_catalog.pg_stat_file(text, boolean)', 'EXECUTE')
+      THEN EXTRACT(EPOCH FROM now() - (pg_catalog.pg_stat_file(pg_catalog.pg_relation_filepath(c.oid), true)).modification)::bigint
+  END AS refresh_age_seconds
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE
+  c.relkind = 'm'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY total_size_bytes DESC, n.nspname, c.relname;
@@ -0,0 +1,220 @@
+// Package memoryovercommit implements a check estimating worst-case memory use.
+package memoryovercommit
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+// A work_mem below this is no fix: sorts and hashes spill to disk instead.
+const minUsefulWorkMemBytes = 4 * check.MiB
+
+// overcommitRemediation covers both sides of the product: fewer connections
+// or less memory per connection.
+const overcommitRemediation = "Lower max_connections and put a connection pooler such as PgBouncer in front, " +
+	"or lower work_mem and raise it only for the roles or sessions running large sorts and hashes (ALTER ROLE ... SET work_mem)"
+
+type MemoryOvercommitQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries MemoryOvercommitQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "memory-overcommit",
+		Name:        "Memory Overcommit",
+		Description: "Estimates worst-case memory use from connection and memory settings against system memory",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries MemoryOvercommitQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+// estimate is the worst case of the memory settings: every connection using
+// one work_mem allocation (scaled by hash_mem_multiplier for hashes) and every
+// autovacuum worker its full maintenance memory, on top of shared_buffers.
+type estimate struct {
+	maxConnections     int64
+	workMem            int64
+	hashMemMultiplier  float64
+	sharedBuffers      int64
+	autovacuumWorkers  int64
+	autovacuumWorkMem  int64
+	connectionsTotal   int64
+	autovacuumTotal    int64
+	total              int64
+	autovacuumMemLabel string
+}
+
+func newEstimate(settings map[string]db.CatalogSettingsRow) estimate {
+	e := estimate{
+		maxConnections:     settingInt(settings["max_connections"], 100),
+		workMem:            settingBytes(settings["work_mem"], 4*check.MiB),
+		hashMemMultiplier:  settingFloat(settings["hash_mem_multiplier"], 1), // absent before PostgreSQL 13
+		sharedBuffers:      settingBytes(settings["shared_buffers"], 128*check.MiB),
+		autovacuumWorkers:  settingInt(settings["autovacuum_max_workers"], 3),
+		autovacuumWorkMem:  settingBytes(settings["autovacuum_work_mem"], -1),
+		autovacuumMemLabel: "autovacuum_work_mem",
+	}
+	if e.autovacuumWorkMem <= 0 {
+		e.autovacuumWorkMem = settingBytes(settings["maintenance_work_mem"], 64*check.MiB)
+		e.autovacuumMemLabel = "maintenance_work_mem"
+	}
+	e.connectionsTotal = int64(float64(e.maxConnections*e.workMem) * e.hashMemMultiplier)
+	e.autovacuumTotal = e.autovacuumWorkers * e.autovacuumWorkMem
+	e.total = e.connectionsTotal + e.sharedBuffers + e.autovacuumTotal
+	return e
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	e := newEstimate(catalog.Settings(rows))
+
+	meta := check.InstanceMetadataFromContext(ctx)
+	if meta == nil || meta.MemoryGB <= 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeveritySkip,
+			Details: fmt.Sprintf("Worst-case memory use is %s; system memory is unknown, pass --system-memory to compare it",
+				check.FormatSize(ctx, e.total)),
+			Table: e.table(ctx),
+		})
+		return report, nil
+	}
+
+	checkOvercommit(ctx, e, int64(meta.MemoryGB*check.GiB), report)
+
+	return report, nil
+}
+
+// checkOvercommit warns when the worst case exceeds system memory. Not every
+// connection sorts at once, but when enough do the OOM killer ends the
+// postmaster's children and the server restarts.
+func checkOvercommit(ctx context.Context, e estimate, memory int64, report *check.Report) {
+	percent := float64(e.total) / float64(memory) * 100
+	summary := fmt.Sprintf("Worst-case memory use is %s, %.0f%% of %s system memory",
+		check.FormatSize(ctx, e.total), percent, check.FormatSize(ctx, memory))
+	metrics := []check.Metric{
+		{Name: "worst_case_memory", Value: float64(e.total), Unit: check.UnitBytes},
+		{Name: "worst_case_memory_ratio", Value: percent, Unit: check.UnitPercent},
+	}
+
+	if e.total <= memory {
+		report.AddFinding(check.Finding{
+			ID:       "memory-overcommit",
+			Name:     "Memory Overcommit",
+			Severity: check.SeverityOK,
+			Details:  summary,
+			Metrics:  metrics,
+			Table:    e.table(ctx),
+		})
+		return
+	}
+
+	details := summary + ": if enough connections sort or hash at once, the OOM killer ends a backend and PostgreSQL restarts every connection."
+	// The work_mem that would fit, with max_connections unchanged.
+	perConnection := float64(memory-e.sharedBuffers-e.autovacuumTotal) / (float64(e.maxConnections) * e.hashMemMultiplier)
+	if perConnection >= minUsefulWorkMemBytes {
+		details += fmt.Sprintf("\n\nA work_mem of %dMB would fit with max_connections = %d.", int64(perConnection)/check.MiB, e.maxConnections)
+	} else {
+		details += fmt.Sprintf("\n\nNo useful work_mem fits %d connections: reduce max_connections.", e.maxConnections)
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "memory-overcommit",
+		Name:     "Memory Overcommit",
+		Severity: check.SeverityWarn,
+		Details:  details,
+		Comparison: &check.Comparison{
+			Setting:   "worst-case memory",
+			Actual:    check.FormatSize(ctx, e.total),
+			Expected:  fmt.Sprintf("<= %s", check.FormatSize(ctx, memory)),
+			Threshold: "> system memory",
+		},
+		Metrics:     metrics,
+		Remediation: overcommitRemediation,
+		Table:       e.table(ctx),
+	})
+}
+
+// table breaks the estimate down by setting.
+func (e estimate) table(ctx context.Context) *check.Table {
+	connections := fmt.Sprintf("max_connections %d × work_mem %s", e.maxConnections, check.FormatSize(ctx, e.workMem))
+	if e.hashMemMultiplier != 1 {
+		connections += fmt.Sprintf(" × hash_mem_multiplier %s", strconv.FormatFloat(e.hashMemMultiplier, 'f', -1, 64))
+	}
+	return &check.Table{
+		Headers: []string{"Component", "Settings", "Memory"},
+		Rows: []check.TableRow{
+			{Cells: []string{"Connections", connections, check.FormatSize(ctx, e.connectionsTotal)}},
+			{Cells: []string{"Shared buffers", "shared_buffers", check.FormatSize(ctx, e.sharedBuffers)}},
+			{Cells: []string{
+				"Autovacuum",
+				fmt.Sprintf("autovacuum_max_workers %d × %s %s", e.autovacuumWorkers, e.autovacuumMemLabel, check.FormatSize(ctx, e.autovacuumWorkMem)),
+				check.FormatSize(ctx, e.autovacuumTotal),
+			}},
+		},
+	}
+}
+
+func settingInt(row db.CatalogSettingsRow, fallback int64) int64 {
+	value, err := strconv.ParseInt(row.Setting.String, 10, 64)
+	if err != nil {
+		return fallback
+	}
+	return value
+}
+
+func settingFloat(row db.CatalogSettingsRow, fallback float64) float64 {
+	value, err := strconv.ParseFloat(row.Setting.String, 64)
+	if err != nil || value <= 0 {
+		return fallback
+	}
+	return value
+}
+
+// settingBytes converts a memory setting to bytes using its pg_settings unit
+// (work_mem is in kB, shared_buffers in 8kB blocks). A negative value, such
+// as autovacuum_work_mem = -1, is returned as is.
+func settingBytes(row db.CatalogSettingsRow, fallback int64) int64 {
+	value, err := strconv.ParseInt(row.Setting.String, 10, 64)
+	if err != nil {
+		return fallback
+	}
+	if value < 0 {
+		return value
+	}
+	unit, err := check.ParseBytes(row.Unit.String)
+	if err != nil || unit == 0 {
+		unit = 1
+	}
+	return value * unit
+}
@@ -0,0 +1,168 @@
+package memoryovercommit_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/memoryovercommit"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockMemoryOvercommitQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockMemoryOvercommitQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func setting(name, value, unit string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+		Unit:    pgtype.Text{String: unit, Valid: unit != ""},
+	}
+}
+
+// settings are a server's memory settings in pg_settings units: work_mem and
+// maintenance_work_mem in kB, shared_buffers in 8kB blocks.
+func settings(maxConnections, workMemKB, sharedBuffersBlocks, autovacuumWorkMemKB string) []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{
+		setting("max_connections", maxConnections, ""),
+		setting("work_mem", workMemKB, "kB"),
+		setting("hash_mem_multiplier", "2", ""),
+		setting("shared_buffers", sharedBuffersBlocks, "8kB"),
+		setting("autovacuum_max_workers", "3", ""),
+		setting("autovacuum_work_mem", autovacuumWorkMemKB, "kB"),
+		setting("maintenance_work_mem", "1048576", "kB"),
+	}
+}
+
+func withMemory(memoryGB float64) context.Context {
+	return check.ContextWithInstanceMetadata(context.Background(), &check.InstanceMetadata{MemoryGB: memoryGB})
+}
+
+func Test_MemoryOvercommit(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Settings         []db.CatalogSettingsRow
+		MemoryGB         float64
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "fits in memory - OK",
+			Settings:         settings("100", "4096", "524288", "-1"),
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Worst-case memory use is 7.8GiB, 49% of 16.0GiB system memory",
+		},
+		{
+			Name:             "many connections with large work_mem warns",
+			Settings:         settings("500", "65536", "524288", "-1"),
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "Worst-case memory use is 69.5GiB, 434% of 16.0GiB system memory",
+		},
+		{
+			Name:             "suggests a work_mem that fits",
+			Settings:         settings("500", "65536", "524288", "-1"),
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "A work_mem of 9MB would fit with max_connections = 500.",
+		},
+		{
+			Name:             "too many connections for any useful work_mem",
+			Settings:         settings("2000", "4096", "131072", "131072"),
+			MemoryGB:         4,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "No useful work_mem fits 2000 connections: reduce max_connections.",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := memoryovercommit.New(&mockMemoryOvercommitQueryer{settings: tc.Settings})
+			report, err := checker.Check(withMemory(tc.MemoryGB))
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Results[0].Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_MemoryOvercommit_Table(t *testing.T) {
+	t.Parallel()
+
+	checker := memoryovercommit.New(&mockMemoryOvercommitQueryer{settings: settings("500", "65536", "524288", "524288")})
+	report, err := checker.Check(withMemory(16))
+	require.NoError(t, err)
+
+	rows := report.Results[0].Table.Rows
+	require.Equal(t, []string{"Connections", "max_connections 500 × work_mem 64.0MiB × hash_mem_multiplier 2", "62.5GiB"}, rows[0].Cells)
+	require.Equal(t, []string{"Shared buffers", "shared_buffers", "4.0GiB"}, rows[1].Cells)
+	require.Equal(t, []string{"Autovacuum", "autovacuum_max_workers 3 × autovacuum_work_mem 512.0MiB", "1.5GiB"}, rows[2].Cells)
+}
+
+func Test_MemoryOvercommit_UnknownMemory(t *testing.T) {
+	t.Parallel()
+
+	checker := memoryovercommit.New(&mockMemoryOvercommitQueryer{settings: settings("100", "4096", "16384", "-1")})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeveritySkip, report.Results[0].Severity)
+	require.Equal(t, "Worst-case memory use is 3.9GiB; system memory is unknown, pass --system-memory to compare it", report.Results[0].Details)
+}
+
+func Test_MemoryOvercommit_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"max_connections", "1000", nil, "configuration file", "100", "1000", "postmaster", "integer", "f"},
+		[]any{"work_mem", "32768", "kB", "configuration file", "4096", "32768", "user", "integer", "f"},
+	)
+
+	report, err := memoryovercommit.New(db.New(fx)).Check(withMemory(8))
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+}
+
+func Test_MemoryOvercommit_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := memoryovercommit.New(&mockMemoryOvercommitQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "memory-overcommit")
+}
+
+func Test_MemoryOvercommit_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := memoryovercommit.Metadata()
+
+	require.Equal(t, "memory-overcommit", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,205 @@
+// Package missingforeignkeys implements a check for columns that look like
+// references to another table but have no foreign key constraint.
+package missingforeignkeys
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const missingRemediation = "Add the foreign key as NOT VALID, then VALIDATE it: validation reads the table without blocking writes " +
+	"and fails on rows pointing at missing keys, which need cleaning up first"
+
+// Match confidence, from the three signals a candidate can have: it points
+// at a primary key, its type is the same as the key's, and both tables are
+// in the same schema.
+const (
+	confidenceLow = iota + 1
+	confidenceMedium
+	confidenceHigh
+)
+
+var confidenceNames = map[int]string{
+	confidenceLow:    "low",
+	confidenceMedium: "medium",
+	confidenceHigh:   "high",
+}
+
+// compatibleTypes are groups of types a foreign key can compare with each
+// other; any other pair of different types cannot be referenced.
+var compatibleTypes = map[string]string{
+	"smallint":          "integer",
+	"integer":           "integer",
+	"bigint":            "integer",
+	"text":              "text",
+	"character varying": "text",
+}
+
+type MissingForeignKeysQueries interface {
+	ImpliedForeignKeys(context.Context) ([]db.ImpliedForeignKeysRow, error)
+}
+
+type checker struct {
+	queries MissingForeignKeysQueries
+}
+
+// candidate is a column and the key it most likely references.
+type candidate struct {
+	row        db.ImpliedForeignKeysRow
+	confidence int
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "missing-foreign-keys",
+		Name:        "Missing Foreign Keys",
+		Description: "Finds *_id columns that match another table's key but have no foreign key constraint",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries MissingForeignKeysQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.ImpliedForeignKeys(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.ImpliedForeignKeysRow) string { return r.SchemaName + "." + r.TableName })
+
+	checkMissingForeignKeys(bestMatches(rows), report)
+
+	return report, nil
+}
+
+// bestMatches keeps the most confident key for each column, the first one on
+// a tie, and drops keys whose type the column cannot reference.
+func bestMatches(rows []db.ImpliedForeignKeysRow) []candidate {
+	var columns []string
+	best := map[string]candidate{}
+	for _, row := range rows {
+		group, ok := compatibleTypes[row.ColumnType]
+		if row.ColumnType != row.ReferencedType && (!ok || group != compatibleTypes[row.ReferencedType]) {
+			continue
+		}
+
+		score := 0
+		for _, signal := range []bool{row.IsPrimary, row.ColumnType == row.ReferencedType, row.SchemaName == row.ReferencedSchema} {
+			if signal {
+				score++
+			}
+		}
+		match := candidate{row: row, confidence: max(score, confidenceLow)}
+
+		key := row.SchemaName + "." + row.TableName + "." + row.ColumnName
+		current, ok := best[key]
+		if !ok {
+			columns = append(columns, key)
+		}
+		if !ok || match.confidence > current.confidence {
+			best[key] = match
+		}
+	}
+
+	result := make([]candidate, 0, len(columns))
+	for _, key := range columns {
+		result = append(result, best[key])
+	}
+	return result
+}
+
+// checkMissingForeignKeys lists every candidate with its confidence. Only
+// high-confidence matches raise a warning and get fix SQL; the others are
+// listed for review.
+func checkMissingForeignKeys(candidates []candidate, report *check.Report) {
+	if len(candidates) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "Every *_id column matching another table's key has a foreign key",
+		})
+		return
+	}
+
+	var tableRows []check.TableRow
+	var fixes []string
+	high := 0
+	for _, cand := range candidates {
+		row := cand.row
+		severity := check.SeverityOK
+		if cand.confidence == confidenceHigh {
+			severity = check.SeverityWarn
+			high++
+			fixes = append(fixes, foreignKeyFix(row))
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				row.ColumnName,
+				fmt.Sprintf("%s.%s(%s)", row.ReferencedSchema, row.ReferencedTable, row.ReferencedColumn),
+				confidenceNames[cand.confidence],
+			},
+			Severity: severity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityOK,
+		Details: fmt.Sprintf("%d column(s) look like references without a foreign key, none with high confidence: "+
+			"review them against the application's data model", len(candidates)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Probably References", "Confidence"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "missing_foreign_keys", Value: float64(len(candidates)), Unit: check.UnitCount},
+			{Name: "high_confidence_missing_foreign_keys", Value: float64(high), Unit: check.UnitCount},
+		},
+	}
+	if high > 0 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%d column(s) look like references without a foreign key, %d with high confidence: "+
+			"nothing stops rows from pointing at keys that do not exist", len(candidates), high)
+		finding.Remediation = missingRemediation
+		finding.FixSQL = strings.Join(fixes, "\n")
+	}
+
+	report.AddFinding(finding)
+}
+
+func foreignKeyFix(row db.ImpliedForeignKeysRow) string {
+	table := pgx.Identifier{row.SchemaName, row.TableName}.Sanitize()
+	constraint := pgx.Identifier{row.TableName + "_" + row.ColumnName + "_fkey"}.Sanitize()
+	return fmt.Sprintf("ALTER TABLE %s ADD CONSTRAINT %s FOREIGN KEY (%s) REFERENCES %s (%s) NOT VALID;\n"+
+		"ALTER TABLE %s VALIDATE CONSTRAINT %s;",
+		table, constraint, pgx.Identifier{row.ColumnName}.Sanitize(),
+		pgx.Identifier{row.ReferencedSchema, row.ReferencedTable}.Sanitize(), pgx.Identifier{row.ReferencedColumn}.Sanitize(),
+		table, constraint)
+}
@@ -0,0 +1,182 @@
+package missingforeignkeys_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/missingforeignkeys"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockMissingForeignKeysQueryer struct {
+	rows []db.ImpliedForeignKeysRow
+	err  error
+}
+
+func (m *mockMissingForeignKeysQueryer) ImpliedForeignKeys(context.Context) ([]db.ImpliedForeignKeysRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func match(table, column, referenced string) db.ImpliedForeignKeysRow {
+	return db.ImpliedForeignKeysRow{
+		SchemaName:       "public",
+		TableName:        table,
+		ColumnName:       column,
+		ColumnType:       "bigint",
+		ReferencedSchema: "public",
+		ReferencedTable:  referenced,
+		ReferencedColumn: "id",
+		ReferencedType:   "bigint",
+		IsPrimary:        true,
+	}
+}
+
+func withTypes(row db.ImpliedForeignKeysRow, columnType, referencedType string) db.ImpliedForeignKeysRow {
+	row.ColumnType = columnType
+	row.ReferencedType = referencedType
+	return row
+}
+
+func inSchema(row db.ImpliedForeignKeysRow, schema string) db.ImpliedForeignKeysRow {
+	row.ReferencedSchema = schema
+	return row
+}
+
+func Test_MissingForeignKeys(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		rows       []db.ImpliedForeignKeysRow
+		severity   check.Severity
+		confidence string
+	}{
+		{
+			name:     "no candidates",
+			severity: check.SeverityOK,
+		},
+		{
+			name:       "same type primary key in the same schema",
+			rows:       []db.ImpliedForeignKeysRow{match("orders", "customer_id", "customers")},
+			severity:   check.SeverityWarn,
+			confidence: "high",
+		},
+		{
+			name:       "integer column referencing a bigint key",
+			rows:       []db.ImpliedForeignKeysRow{withTypes(match("orders", "customer_id", "customers"), "integer", "bigint")},
+			severity:   check.SeverityOK,
+			confidence: "medium",
+		},
+		{
+			name: "unique key in another schema",
+			rows: func() []db.ImpliedForeignKeysRow {
+				row := inSchema(match("orders", "customer_id", "customers"), "crm")
+				row.IsPrimary = false
+				return []db.ImpliedForeignKeysRow{row}
+			}(),
+			severity:   check.SeverityOK,
+			confidence: "low",
+		},
+		{
+			name:     "incompatible types",
+			rows:     []db.ImpliedForeignKeysRow{withTypes(match("orders", "customer_id", "customers"), "uuid", "bigint")},
+			severity: check.SeverityOK,
+		},
+		{
+			name: "best match wins",
+			rows: []db.ImpliedForeignKeysRow{
+				inSchema(match("orders", "customer_id", "customers"), "crm"),
+				match("orders", "customer_id", "customers"),
+			},
+			severity:   check.SeverityWarn,
+			confidence: "high",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := missingforeignkeys.New(&mockMissingForeignKeysQueryer{rows: tt.rows}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.severity, finding.Severity)
+			if tt.confidence == "" {
+				require.Nil(t, finding.Table)
+				return
+			}
+			require.Len(t, finding.Table.Rows, 1)
+			require.Equal(t, tt.confidence, finding.Table.Rows[0].Cells[3])
+		})
+	}
+}
+
+func Test_MissingForeignKeys_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	report, err := missingforeignkeys.New(&mockMissingForeignKeysQueryer{rows: []db.ImpliedForeignKeysRow{
+		match("orders", "customer_id", "customers"),
+		withTypes(match("orders", "coupon_id", "coupons"), "integer", "bigint"),
+		match("order_items", "order_id", "orders"),
+	}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "3 column(s)")
+	require.Contains(t, finding.Details, "2 with high confidence")
+	require.Equal(t, `ALTER TABLE "public"."orders" ADD CONSTRAINT "orders_customer_id_fkey" FOREIGN KEY ("customer_id") REFERENCES "public"."customers" ("id") NOT VALID;
+ALTER TABLE "public"."orders" VALIDATE CONSTRAINT "orders_customer_id_fkey";
+ALTER TABLE "public"."order_items" ADD CONSTRAINT "order_items_order_id_fkey" FOREIGN KEY ("order_id") REFERENCES "public"."orders" ("id") NOT VALID;
+ALTER TABLE "public"."order_items" VALIDATE CONSTRAINT "order_items_order_id_fkey";`, finding.FixSQL)
+}
+
+func Test_MissingForeignKeys_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("ImpliedForeignKeys",
+		[]string{
+			"schema_name text", "table_name text", "column_name text", "column_type text",
+			"referenced_schema text", "referenced_table text", "referenced_column text", "referenced_type text", "is_primary bool",
+		},
+		[]any{"billing", "invoices", "account_id", "uuid", "billing", "accounts", "id", "uuid", true},
+	)
+
+	report, err := missingforeignkeys.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"billing.invoices", "account_id", "billing.accounts(id)", "high"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_MissingForeignKeys_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := missingforeignkeys.New(&mockMissingForeignKeysQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "missing-foreign-keys")
+}
+
+func Test_MissingForeignKeys_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := missingforeignkeys.Metadata()
+
+	require.Equal(t, "missing-foreign-keys", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
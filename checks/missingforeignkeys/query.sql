@@ -0,0 +1,87 @@
+-- name: ImpliedForeignKeys :many
+-- Columns named <name>_id that are not part of any foreign key, paired with
+-- the single-column primary keys and unique constraints they probably point
+-- at: a table called <name>, <name>s, <name>es or <name> with a trailing y
+-- turned into ies, keyed on id or on a column with the same name. A column
+-- can match tables in several schemas; the checker keeps the best match.
+-- Excludes: system schemas, partitions (covered by their parent), tables
+-- owned by extensions, and a key matching itself.
+WITH candidates AS (
+  SELECT
+    c.oid AS table_oid
+    , n.nspname AS schema_name
+    , c.relname AS table_name
+    , a.attnum
+    , a.attname AS column_name
+    , a.atttypid
+    , left(a.attname, -3) AS prefix
+  FROM pg_catalog.pg_attribute AS a
+  INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  WHERE
+    a.attnum > 0
+    AND NOT a.attisdropped
+    AND a.attname LIKE '_%\_id'
+    AND c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_constraint AS fk
+      WHERE
+        fk.conrelid = c.oid
+        AND fk.contype = 'f'
+        AND a.attnum = ANY(fk.conkey)
+    )
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+, referenced_keys AS (
+  SELECT
+    c.oid AS table_oid
+    , n.nspname AS schema_name
+    , c.relname AS table_name
+    , a.attname AS column_name
+    , a.atttypid
+    , con.contype = 'p' AS is_primary
+  FROM pg_catalog.pg_constraint AS con
+  INNER JOIN pg_catalog.pg_class AS c ON con.conrelid = c.oid
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  INNER JOIN pg_catalog.pg_attribute AS a ON con.conrelid = a.attrelid AND con.conkey[1] = a.attnum
+  WHERE
+    con.contype IN ('p', 'u')
+    AND cardinality(con.conkey) = 1
+    AND c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+)
+
+SELECT
+  cand.schema_name::text AS schema_name
+  , cand.table_name::text AS table_name
+  , cand.column_name::text AS column_name
+  , pg_catalog.format_type(cand.atttypid, NULL) AS column_type
+  , k.schema_name::text AS referenced_schema
+  , k.table_name::text AS referenced_table
+  , k.column_name::text AS referenced_column
+  , pg_catalog.format_type(k.atttypid, NULL) AS referenced_type
+  , k.is_primary
+FROM candidates AS cand
+INNER JOIN referenced_keys AS k
+  ON
+    k.table_name IN (
+      cand.prefix
+      , cand.prefix || 's'
+      , cand.prefix || 'es'
+      , regexp_replace(cand.prefix, 'y$', 'ies')
+    )
+    AND k.column_name IN ('id', cand.column_name)
+    AND NOT (k.table_oid = cand.table_oid AND k.column_name = cand.column_name)
+ORDER BY cand.schema_name, cand.table_name, cand.attnum, k.schema_name, k.table_name;
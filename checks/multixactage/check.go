@@ -0,0 +1,216 @@
+// Package multixactage implements checks for PostgreSQL multixact ID wraparound risk.
+package multixactage
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+type MultixactAgeQueries interface {
+	DatabaseMultixactAge(context.Context) ([]db.DatabaseMultixactAgeRow, error)
+	TableMultixactAge(context.Context) ([]db.TableMultixactAgeRow, error)
+}
+
+type checker struct {
+	queries MultixactAgeQueries
+}
+
+const (
+	// Multixact ID age thresholds, mirroring freeze-age: multixact IDs are
+	// 32-bit too and PostgreSQL stops assigning them at ~2 billion.
+	// autovacuum_multixact_freeze_max_age default is 400 million.
+	ageWarnThreshold = int64(500_000_000)
+	ageFailThreshold = int64(1_000_000_000)
+
+	// Table-level thresholds (lower since tables can be vacuumed individually).
+	tableAgeWarnThreshold = int64(400_000_000)
+	tableAgeFailThreshold = int64(800_000_000)
+
+	// wraparoundLimit is where PostgreSQL refuses to assign new multixacts.
+	wraparoundLimit = 2_000_000_000
+
+	// maxListedTables caps the table-multixact-age table to the oldest tables.
+	maxListedTables = 50
+)
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryVacuum,
+		CheckID:     "multixact-age",
+		Name:        "Multixact ID Age",
+		Description: "Monitors multixact ID age to prevent multixact wraparound",
+		Readme:      readme,
+		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
+	}
+}
+
+func New(queries MultixactAgeQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	dbRows, err := c.queries.DatabaseMultixactAge(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s (database): %w", report.Category, report.CheckID, err)
+	}
+
+	tableRows, err := c.queries.TableMultixactAge(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s (tables): %w", report.Category, report.CheckID, err)
+	}
+	tableRows = check.FilterTables(ctx, tableRows, func(r db.TableMultixactAgeRow) string { return r.SchemaName + "." + r.TableName })
+
+	checkDatabaseMultixactAge(dbRows, report)
+	checkTableMultixactAge(ctx, tableRows, report)
+
+	return report, nil
+}
+
+func severityFor(age, warn, fail int64) check.Severity {
+	switch {
+	case age >= fail:
+		return check.SeverityFail
+	case age >= warn:
+		return check.SeverityWarn
+	default:
+		return check.SeverityOK
+	}
+}
+
+func checkDatabaseMultixactAge(rows []db.DatabaseMultixactAgeRow, report *check.Report) {
+	severity := check.SeverityOK
+	var oldest db.DatabaseMultixactAgeRow
+	var tableRows []check.TableRow
+
+	for _, row := range rows {
+		if row.MultixactAge > oldest.MultixactAge {
+			oldest = row
+		}
+		rowSeverity := severityFor(row.MultixactAge, ageWarnThreshold, ageFailThreshold)
+		if rowSeverity == check.SeverityOK {
+			continue
+		}
+		severity = max(severity, rowSeverity)
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.DatabaseName,
+				check.FormatNumber(row.MultixactAge),
+				fmt.Sprintf("%.1f%%", float64(row.MultixactAge)/wraparoundLimit*100),
+				check.FormatNumber(row.FreezeMaxAge),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		details := "All databases within safe multixact ID age range"
+		if oldest.DatabaseName != "" {
+			details = fmt.Sprintf("All databases within safe range. Oldest: %s at %s multixacts",
+				oldest.DatabaseName, check.FormatNumber(oldest.MultixactAge))
+		}
+		report.AddFinding(check.Finding{
+			ID:       "database-multixact-age",
+			Name:     "Database Multixact Age",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "database-multixact-age",
+		Name:        "Database Multixact Age",
+		Severity:    severity,
+		Details:     fmt.Sprintf("Found %d database(s) with high multixact ID age", len(tableRows)),
+		Remediation: "Find what keeps autovacuum from advancing datminmxid (long-running transactions, failing vacuums) and VACUUM (FREEZE) the oldest tables",
+		Metrics: []check.Metric{
+			{Name: "max_multixact_age", Value: float64(oldest.MultixactAge), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Database", "Age", "% to Limit", "Freeze Max Age"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+func checkTableMultixactAge(ctx context.Context, rows []db.TableMultixactAgeRow, report *check.Report) {
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	var fixes []string
+
+	for _, row := range rows {
+		rowSeverity := severityFor(row.MultixactAge, tableAgeWarnThreshold, tableAgeFailThreshold)
+		if rowSeverity == check.SeverityOK {
+			continue
+		}
+		severity = max(severity, rowSeverity)
+		fixes = append(fixes, fmt.Sprintf("VACUUM (FREEZE, VERBOSE) %s;", pgx.Identifier{row.SchemaName, row.TableName}.Sanitize()))
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				check.FormatNumber(row.MultixactAge),
+				check.FormatSize(ctx, row.TableSizeBytes),
+				formatVacuumTime(row),
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "table-multixact-age",
+			Name:     "Table Multixact Age",
+			Severity: check.SeverityOK,
+			Details:  "All tables within safe multixact ID age range",
+		})
+		return
+	}
+
+	details := fmt.Sprintf("Found %d table(s) with high multixact ID age", len(tableRows))
+	if len(tableRows) > maxListedTables {
+		details += fmt.Sprintf(", showing the oldest %d", maxListedTables)
+		tableRows = tableRows[:maxListedTables]
+		fixes = fixes[:maxListedTables]
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "table-multixact-age",
+		Name:        "Table Multixact Age",
+		Severity:    severity,
+		Details:     details,
+		Remediation: "Run VACUUM (FREEZE) on these tables to advance their relminmxid",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Table", "Age", "Size", "Last Vacuum"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+func formatVacuumTime(row db.TableMultixactAgeRow) string {
+	if row.LastVacuum.Valid {
+		return row.LastVacuum.Time.Format("2006-01-02 15:04")
+	}
+	return "never"
+}
@@ -0,0 +1,211 @@
+package multixactage_test
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/multixactage"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockMultixactAgeQueryer struct {
+	dbRows    []db.DatabaseMultixactAgeRow
+	tableRows []db.TableMultixactAgeRow
+	err       error
+}
+
+func (m *mockMultixactAgeQueryer) DatabaseMultixactAge(context.Context) ([]db.DatabaseMultixactAgeRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.dbRows, nil
+}
+
+func (m *mockMultixactAgeQueryer) TableMultixactAge(context.Context) ([]db.TableMultixactAgeRow, error) {
+	return m.tableRows, nil
+}
+
+func Test_MultixactAge(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		DatabaseAge      int64
+		TableAge         int64
+		ExpectedDatabase check.Severity
+		ExpectedTable    check.Severity
+	}{
+		{
+			Name:             "young multixacts - OK",
+			DatabaseAge:      5_000_000,
+			TableAge:         5_000_000,
+			ExpectedDatabase: check.SeverityOK,
+			ExpectedTable:    check.SeverityOK,
+		},
+		{
+			Name:             "table at warning threshold",
+			DatabaseAge:      400_000_000,
+			TableAge:         400_000_000,
+			ExpectedDatabase: check.SeverityOK,
+			ExpectedTable:    check.SeverityWarn,
+		},
+		{
+			Name:             "database at warning threshold",
+			DatabaseAge:      500_000_000,
+			TableAge:         500_000_000,
+			ExpectedDatabase: check.SeverityWarn,
+			ExpectedTable:    check.SeverityWarn,
+		},
+		{
+			Name:             "critical",
+			DatabaseAge:      1_000_000_000,
+			TableAge:         1_000_000_000,
+			ExpectedDatabase: check.SeverityFail,
+			ExpectedTable:    check.SeverityFail,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := multixactage.New(&mockMultixactAgeQueryer{
+				dbRows: []db.DatabaseMultixactAgeRow{
+					{DatabaseName: "app", MultixactAge: tc.DatabaseAge, FreezeMaxAge: 400_000_000},
+				},
+				tableRows: []db.TableMultixactAgeRow{
+					{SchemaName: "public", TableName: "order_items", MultixactAge: tc.TableAge, TableSizeBytes: 64 * check.MiB},
+				},
+			})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 2)
+			require.Equal(t, tc.ExpectedDatabase, checktest.Finding(t, report, "database-multixact-age").Severity)
+			require.Equal(t, tc.ExpectedTable, checktest.Finding(t, report, "table-multixact-age").Severity)
+		})
+	}
+}
+
+func Test_MultixactAge_TableFixSQL(t *testing.T) {
+	t.Parallel()
+
+	vacuumed := pgtype.Timestamptz{Time: time.Date(2026, time.March, 1, 4, 30, 0, 0, time.UTC), Valid: true}
+	checker := multixactage.New(&mockMultixactAgeQueryer{
+		tableRows: []db.TableMultixactAgeRow{
+			{SchemaName: "public", TableName: "order_items", MultixactAge: 900_000_000, TableSizeBytes: 2 * check.MiB, LastVacuum: vacuumed},
+			{SchemaName: "Billing", TableName: "invoices", MultixactAge: 450_000_000},
+			{SchemaName: "public", TableName: "orders", MultixactAge: 1_000},
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "table-multixact-age")
+	require.Equal(t, check.SeverityFail, finding.Severity)
+	require.Equal(t, "VACUUM (FREEZE, VERBOSE) \"public\".\"order_items\";\n"+
+		"VACUUM (FREEZE, VERBOSE) \"Billing\".\"invoices\";", finding.FixSQL)
+	require.Equal(t, []string{"public.order_items", "900.0M", "2.0MiB", "2026-03-01 04:30"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, "never", finding.Table.Rows[1].Cells[3])
+}
+
+func Test_MultixactAge_RespectsTableScope(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{ExcludeSchemas: []string{"archive"}})
+	checker := multixactage.New(&mockMultixactAgeQueryer{
+		tableRows: []db.TableMultixactAgeRow{
+			{SchemaName: "archive", TableName: "orders_2019", MultixactAge: 900_000_000},
+		},
+	})
+	report, err := checker.Check(ctx)
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, report.Severity)
+}
+
+func Test_MultixactAge_ScopeAppliedBeforeCap(t *testing.T) {
+	t.Parallel()
+
+	// Older out-of-scope tables come first, as the query orders by age.
+	var rows []db.TableMultixactAgeRow
+	for i := range 60 {
+		rows = append(rows, db.TableMultixactAgeRow{SchemaName: "archive", TableName: fmt.Sprintf("orders_%d", i), MultixactAge: 900_000_000})
+	}
+	rows = append(rows, db.TableMultixactAgeRow{SchemaName: "public", TableName: "orders", MultixactAge: 450_000_000})
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{ExcludeSchemas: []string{"archive"}})
+	report, err := multixactage.New(&mockMultixactAgeQueryer{tableRows: rows}).Check(ctx)
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "table-multixact-age")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Len(t, finding.Table.Rows, 1)
+	require.Equal(t, "public.orders", finding.Table.Rows[0].Cells[0])
+}
+
+func Test_MultixactAge_TableListCapped(t *testing.T) {
+	t.Parallel()
+
+	var rows []db.TableMultixactAgeRow
+	for i := range 60 {
+		rows = append(rows, db.TableMultixactAgeRow{SchemaName: "public", TableName: fmt.Sprintf("t_%d", i), MultixactAge: 900_000_000})
+	}
+
+	report, err := multixactage.New(&mockMultixactAgeQueryer{tableRows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "table-multixact-age")
+	require.Contains(t, finding.Details, "Found 60 table(s)")
+	require.Contains(t, finding.Details, "showing the oldest 50")
+	require.Len(t, finding.Table.Rows, 50)
+	require.Len(t, strings.Split(finding.FixSQL, "\n"), 50)
+}
+
+func Test_MultixactAge_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("DatabaseMultixactAge",
+			[]string{"database_name text", "multixact_age int8", "freeze_max_age int8"},
+			[]any{"app", 600000000, 400000000},
+		).
+		Add("TableMultixactAge",
+			[]string{"schema_name text", "table_name text", "multixact_age int8", "table_size_bytes int8", "last_vacuum timestamptz"},
+			[]any{"public", "order_items", 600000000, 8192, nil},
+		)
+
+	report, err := multixactage.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, `VACUUM (FREEZE, VERBOSE) "public"."order_items";`, checktest.Finding(t, report, "table-multixact-age").FixSQL)
+}
+
+func Test_MultixactAge_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := multixactage.New(&mockMultixactAgeQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "multixact-age")
+}
+
+func Test_MultixactAge_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := multixactage.Metadata()
+
+	require.Equal(t, "multixact-age", metadata.CheckID)
+	require.Equal(t, check.CategoryVacuum, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,31 @@
+-- name: DatabaseMultixactAge :many
+-- Gets multixact ID age for all databases.
+SELECT
+  datname::text AS database_name
+  , mxid_age(datminmxid)::bigint AS multixact_age
+  , COALESCE((
+    SELECT s.setting::bigint FROM pg_settings AS s
+    WHERE s.name = 'autovacuum_multixact_freeze_max_age'
+  ), 400000000)::bigint AS freeze_max_age
+FROM pg_database
+WHERE datallowconn = true
+ORDER BY mxid_age(datminmxid) DESC;
+
+-- name: TableMultixactAge :many
+-- Gets multixact ID age for all tables, oldest first.
+-- Not limited: the check applies the table scope (--schemas, --exclude-schemas,
+-- --include-tables, --exclude-tables) before capping the list.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , mxid_age(c.relminmxid)::bigint AS multixact_age
+  , pg_total_relation_size(c.oid) AS table_size_bytes
+  , GREATEST(s.last_autovacuum, s.last_vacuum) AS last_vacuum
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND c.relminmxid != '0'
+ORDER BY mxid_age(c.relminmxid) DESC;
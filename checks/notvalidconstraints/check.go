@@ -0,0 +1,120 @@
+// Package notvalidconstraints implements a check for constraints left in NOT VALID state.
+package notvalidconstraints
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// constraintTypes names pg_constraint.contype values.
+var constraintTypes = map[string]string{
+	"f": "foreign key",
+	"c": "check",
+	"n": "not null",
+}
+
+type NotValidConstraintsQueries interface {
+	NotValidConstraints(context.Context) ([]db.NotValidConstraintsRow, error)
+}
+
+type checker struct {
+	queries NotValidConstraintsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "not-valid-constraints",
+		Name:        "NOT VALID Constraints",
+		Description: "Identifies foreign key and check constraints never validated against existing rows",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries NotValidConstraintsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.NotValidConstraints(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.NotValidConstraintsRow) string { return r.SchemaName + "." + r.TableName })
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+		})
+		return report, nil
+	}
+
+	fixes := make([]string, 0, len(rows))
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s VALIDATE CONSTRAINT %s;",
+			pgx.Identifier{row.SchemaName, row.TableName}.Sanitize(),
+			pgx.Identifier{row.ConstraintName}.Sanitize()))
+
+		kind, ok := constraintTypes[row.ConstraintType]
+		if !ok {
+			kind = row.ConstraintType
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				row.ConstraintName,
+				kind,
+				row.Definition,
+				check.FormatSize(ctx, row.TableSizeBytes),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	details := fmt.Sprintf("%d constraints are NOT VALID: existing rows were never checked and may violate them", len(rows))
+	if len(rows) == 1 {
+		details = "1 constraint is NOT VALID: existing rows were never checked and may violate it"
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: "ALTER TABLE ... VALIDATE CONSTRAINT ...; it does not block reads or writes",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Metrics: []check.Metric{
+			{Name: "not_valid_constraints", Value: float64(len(rows)), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Table", "Constraint", "Type", "Definition", "Table Size"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
@@ -0,0 +1,149 @@
+package notvalidconstraints_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/notvalidconstraints"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockNotValidConstraintsQueryer struct {
+	rows []db.NotValidConstraintsRow
+	err  error
+}
+
+func (m *mockNotValidConstraintsQueryer) NotValidConstraints(context.Context) ([]db.NotValidConstraintsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func Test_NotValidConstraints(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.NotValidConstraintsRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "all constraints validated - OK",
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name: "one NOT VALID foreign key",
+			Rows: []db.NotValidConstraintsRow{
+				{SchemaName: "public", TableName: "order_items", ConstraintName: "order_items_order_id_fkey", ConstraintType: "f"},
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 constraint is NOT VALID",
+		},
+		{
+			Name: "several NOT VALID constraints",
+			Rows: []db.NotValidConstraintsRow{
+				{SchemaName: "public", TableName: "order_items", ConstraintName: "order_items_order_id_fkey", ConstraintType: "f"},
+				{SchemaName: "public", TableName: "orders", ConstraintName: "orders_total_check", ConstraintType: "c"},
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 constraints are NOT VALID",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := notvalidconstraints.New(&mockNotValidConstraintsQueryer{rows: tc.Rows})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_NotValidConstraints_FixSQLAndTable(t *testing.T) {
+	t.Parallel()
+
+	checker := notvalidconstraints.New(&mockNotValidConstraintsQueryer{rows: []db.NotValidConstraintsRow{
+		{
+			SchemaName:     "public",
+			TableName:      "order_items",
+			ConstraintName: "order_items_order_id_fkey",
+			ConstraintType: "f",
+			Definition:     "FOREIGN KEY (order_id) REFERENCES orders(id) NOT VALID",
+			TableSizeBytes: 2 * check.MiB,
+		},
+		{SchemaName: "Billing", TableName: "invoices", ConstraintName: "positive total", ConstraintType: "c", Definition: "CHECK ((total >= 0)) NOT VALID"},
+	}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t,
+		"ALTER TABLE \"public\".\"order_items\" VALIDATE CONSTRAINT \"order_items_order_id_fkey\";\n"+
+			"ALTER TABLE \"Billing\".\"invoices\" VALIDATE CONSTRAINT \"positive total\";",
+		finding.FixSQL)
+	require.Equal(t, []string{"Table", "Constraint", "Type", "Definition", "Table Size"}, finding.Table.Headers)
+	require.Equal(t, []string{
+		"public.order_items", "order_items_order_id_fkey", "foreign key",
+		"FOREIGN KEY (order_id) REFERENCES orders(id) NOT VALID", "2.0MiB",
+	}, finding.Table.Rows[0].Cells)
+	require.Equal(t, "check", finding.Table.Rows[1].Cells[2])
+}
+
+func Test_NotValidConstraints_RespectsTableScope(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{ExcludeSchemas: []string{"archive"}})
+	checker := notvalidconstraints.New(&mockNotValidConstraintsQueryer{rows: []db.NotValidConstraintsRow{
+		{SchemaName: "archive", TableName: "orders_2019", ConstraintName: "orders_2019_total_check", ConstraintType: "c"},
+	}})
+	report, err := checker.Check(ctx)
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, report.Severity)
+}
+
+func Test_NotValidConstraints_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("NotValidConstraints",
+		[]string{"schema_name text", "table_name text", "constraint_name text", "constraint_type text", "definition text", "table_size_bytes int8"},
+		[]any{"public", "orders", "orders_total_check", "c", "CHECK ((total >= 0)) NOT VALID", 8192},
+	)
+
+	report, err := notvalidconstraints.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, `ALTER TABLE "public"."orders" VALIDATE CONSTRAINT "orders_total_check";`, report.Results[0].FixSQL)
+}
+
+func Test_NotValidConstraints_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := notvalidconstraints.New(&mockNotValidConstraintsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "not-valid-constraints")
+}
+
+func Test_NotValidConstraints_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := notvalidconstraints.Metadata()
+
+	require.Equal(t, "not-valid-constraints", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
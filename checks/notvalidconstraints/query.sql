@@ -0,0 +1,22 @@
+-- name: NotValidConstraints :many
+-- Foreign key, check and not-null constraints added NOT VALID and never
+-- validated: they apply to new and updated rows only, so existing rows may
+-- violate them.
+-- Excludes: system schemas and the copies of a partitioned table's constraint
+-- on its partitions (conparentid), reported once on the parent.
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , con.conname::text AS constraint_name
+  , con.contype::text AS constraint_type
+  , pg_get_constraintdef(con.oid)::text AS definition
+  , pg_total_relation_size(t.oid) AS table_size_bytes
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE
+  NOT con.convalidated
+  AND con.contype IN ('f', 'c', 'n')
+  AND con.conparentid = 0
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY n.nspname, t.relname, con.conname;
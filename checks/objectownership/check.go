@@ -0,0 +1,120 @@
+// Package objectownership implements a check for tables and functions owned
+// by the bootstrap superuser or by roles applications log in as.
+package objectownership
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const ownershipRemediation = "Create a NOLOGIN owner role, transfer the objects to it with ALTER ... OWNER TO " +
+	"(or REASSIGN OWNED BY for roles other than the bootstrap superuser), and run migrations with SET ROLE to it"
+
+type ObjectOwnershipQueries interface {
+	LoginOwnedObjects(context.Context) ([]db.LoginOwnedObjectsRow, error)
+}
+
+type checker struct {
+	queries ObjectOwnershipQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySecurity,
+		CheckID:     "object-ownership",
+		Name:        "Object Ownership",
+		Description: "Lists tables and functions owned by the bootstrap superuser or by roles that can log in",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries ObjectOwnershipQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+// Check warns when objects belong to a role something logs in as. An owner
+// can always drop, truncate or alter its objects, whatever was granted or
+// revoked, so an application connecting as the owner is one bad statement
+// away from losing a table.
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.LoginOwnedObjects(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "All user tables and functions are owned by roles that cannot log in",
+		})
+		return report, nil
+	}
+
+	var objects int64
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		objects += row.Tables + row.Functions
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.OwnerName,
+				ownerKind(row),
+				row.SchemaName,
+				fmt.Sprintf("%d", row.Tables),
+				fmt.Sprintf("%d", row.Functions),
+				row.ExampleObject,
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d table(s) and function(s) are owned by the bootstrap superuser or by roles that can log in: "+
+			"owners can drop and alter their objects regardless of grants, so a session connected as the owner "+
+			"can drop a table by mistake", objects),
+		Remediation: ownershipRemediation,
+		Table: &check.Table{
+			Headers: []string{"Owner", "Owner Kind", "Schema", "Tables", "Functions", "Example"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "login_owned_objects", Value: float64(objects), Unit: check.UnitCount},
+		},
+	})
+
+	return report, nil
+}
+
+func ownerKind(row db.LoginOwnedObjectsRow) string {
+	switch {
+	case row.IsBootstrap:
+		return "bootstrap superuser"
+	case row.IsSuperuser:
+		return "superuser, can log in"
+	default:
+		return "can log in"
+	}
+}
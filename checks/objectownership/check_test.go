@@ -0,0 +1,134 @@
+package objectownership_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/objectownership"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockObjectOwnershipQueryer struct {
+	rows []db.LoginOwnedObjectsRow
+	err  error
+}
+
+func (m *mockObjectOwnershipQueryer) LoginOwnedObjects(context.Context) ([]db.LoginOwnedObjectsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func Test_ObjectOwnership(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		rows     []db.LoginOwnedObjectsRow
+		expected check.Severity
+		kinds    []string
+	}{
+		{
+			name:     "owned by a NOLOGIN role",
+			expected: check.SeverityOK,
+		},
+		{
+			name: "owned by the bootstrap superuser",
+			rows: []db.LoginOwnedObjectsRow{
+				{OwnerName: "postgres", IsBootstrap: true, IsSuperuser: true, CanLogin: true, SchemaName: "public", Tables: 12, ExampleObject: "accounts"},
+			},
+			expected: check.SeverityWarn,
+			kinds:    []string{"bootstrap superuser"},
+		},
+		{
+			name: "owned by application roles",
+			rows: []db.LoginOwnedObjectsRow{
+				{OwnerName: "admin", IsSuperuser: true, CanLogin: true, SchemaName: "app", Functions: 2, ExampleObject: "audit"},
+				{OwnerName: "app", CanLogin: true, SchemaName: "app", Tables: 30, Functions: 4, ExampleObject: "orders"},
+			},
+			expected: check.SeverityWarn,
+			kinds:    []string{"superuser, can log in", "can log in"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := objectownership.New(&mockObjectOwnershipQueryer{rows: tt.rows}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.expected, finding.Severity)
+			if len(tt.kinds) == 0 {
+				require.Nil(t, finding.Table)
+				return
+			}
+			require.Len(t, finding.Table.Rows, len(tt.kinds))
+			for i, kind := range tt.kinds {
+				require.Equal(t, kind, finding.Table.Rows[i].Cells[1])
+			}
+		})
+	}
+}
+
+func Test_ObjectOwnership_Metrics(t *testing.T) {
+	t.Parallel()
+
+	report, err := objectownership.New(&mockObjectOwnershipQueryer{rows: []db.LoginOwnedObjectsRow{
+		{OwnerName: "app", CanLogin: true, SchemaName: "app", Tables: 30, Functions: 4, ExampleObject: "orders"},
+		{OwnerName: "app", CanLogin: true, SchemaName: "billing", Tables: 5, ExampleObject: "invoices"},
+	}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Contains(t, finding.Details, "39 table(s) and function(s)")
+	require.Equal(t, []string{"app", "can log in", "app", "30", "4", "orders"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, float64(39), finding.Metrics[0].Value)
+	require.Empty(t, finding.FixSQL)
+}
+
+func Test_ObjectOwnership_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("LoginOwnedObjects",
+		[]string{
+			"owner_name text", "is_bootstrap bool", "is_superuser bool", "can_login bool",
+			"schema_name text", "tables int8", "functions int8", "example_object text",
+		},
+		[]any{"postgres", "t", "t", "t", "public", 3, 1, "events"},
+	)
+
+	report, err := objectownership.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, []string{"postgres", "bootstrap superuser", "public", "3", "1", "events"}, report.Results[0].Table.Rows[0].Cells)
+}
+
+func Test_ObjectOwnership_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := objectownership.New(&mockObjectOwnershipQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "object-ownership")
+}
+
+func Test_ObjectOwnership_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := objectownership.Metadata()
+
+	require.Equal(t, "object-ownership", metadata.CheckID)
+	require.Equal(t, check.CategorySecurity, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
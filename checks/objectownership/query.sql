@@ -0,0 +1,59 @@
+-- name: LoginOwnedObjects :many
+-- Tables, views and functions owned by a role that can log in, or by the
+-- bootstrap superuser (oid 10), counted per owner and schema. Partitions,
+-- which follow their parent, and objects owned by extensions are left out.
+WITH owned AS (
+  SELECT
+    c.relowner AS owner_oid
+    , c.relnamespace AS schema_oid
+    , 'table' AS object_kind
+    , c.relname::text AS object_name
+  FROM pg_catalog.pg_class AS c
+  WHERE
+    c.relkind IN ('r', 'p', 'v', 'm')
+    AND NOT c.relispartition
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+  UNION ALL
+  SELECT
+    p.proowner AS owner_oid
+    , p.pronamespace AS schema_oid
+    , 'function' AS object_kind
+    , p.proname::text AS object_name
+  FROM pg_catalog.pg_proc AS p
+  WHERE
+    p.prokind IN ('f', 'p')
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_proc'::regclass
+        AND p.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+SELECT
+  r.rolname::text AS owner_name
+  , r.oid = 10 AS is_bootstrap
+  , r.rolsuper AS is_superuser
+  , r.rolcanlogin AS can_login
+  , n.nspname::text AS schema_name
+  , count(*) FILTER (WHERE o.object_kind = 'table')::bigint AS tables
+  , count(*) FILTER (WHERE o.object_kind = 'function')::bigint AS functions
+  , min(o.object_name)::text AS example_object
+FROM owned AS o
+INNER JOIN pg_catalog.pg_roles AS r ON o.owner_oid = r.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON o.schema_oid = n.oid
+WHERE
+  (r.rolcanlogin OR r.oid = 10)
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND n.nspname NOT LIKE 'pg\_%'
+GROUP BY r.rolname, r.oid, r.rolsuper, r.rolcanlogin, n.nspname
+ORDER BY r.rolname, n.nspname;
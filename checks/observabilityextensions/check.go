@@ -0,0 +1,219 @@
+// Package observabilityextensions implements a check reporting which of the
+// commonly recommended diagnostic extensions are available and installed.
+package observabilityextensions
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const contribRemediation = "Install the PostgreSQL contrib package for this server version; " +
+	"on managed services the extension is part of the supported list"
+
+// recommendedExtension is an extension worth having for diagnostics. usedBy
+// lists the pgdoctor checks that need it.
+type recommendedExtension struct {
+	name    string
+	id      string
+	preload bool // the library must be in shared_preload_libraries
+	purpose string
+	usedBy  []string
+}
+
+var recommendedExtensions = []recommendedExtension{
+	{
+		name:    "pg_stat_statements",
+		id:      "pg-stat-statements",
+		preload: true,
+		purpose: "execution counts, time and I/O per normalized statement",
+		usedBy:  []string{"top-queries", "query-spills"},
+	},
+	{
+		name:    "pgstattuple",
+		id:      "pgstattuple",
+		purpose: "exact table and index bloat, and GIN pending list sizes",
+		usedBy:  []string{"gin-pending-list"},
+	},
+	{
+		name:    "pg_buffercache",
+		id:      "pg-buffercache",
+		purpose: "which relations occupy shared_buffers right now",
+	},
+}
+
+type ObservabilityExtensionsQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+	ObservabilityExtensions(context.Context) ([]db.ObservabilityExtensionsRow, error)
+}
+
+type checker struct {
+	queries ObservabilityExtensionsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "observability-extensions",
+		Name:        "Observability Extensions",
+		Description: "Reports whether pg_stat_statements, auto_explain, pgstattuple and pg_buffercache are available and installed",
+		Readme:      readme,
+		SQL:         querySQL + "\n" + catalog.SQL,
+	}
+}
+
+func New(queries ObservabilityExtensionsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	extensions, err := c.queries.ObservabilityExtensions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+	preloaded := libraries(settings["shared_preload_libraries"].Setting.String)
+	provider := check.ProviderFromContext(ctx)
+
+	available := make(map[string]db.ObservabilityExtensionsRow, len(extensions))
+	for _, ext := range extensions {
+		available[ext.ExtensionName] = ext
+	}
+	for _, ext := range recommendedExtensions {
+		row, ok := available[ext.name]
+		checkExtension(ext, row, ok, preloaded, provider, report)
+	}
+	checkAutoExplain(settings, preloaded, provider, report)
+
+	return report, nil
+}
+
+// checkExtension reports an extension's status. Missing extensions are
+// informational; an installed extension whose library is not preloaded is
+// broken, and a warning.
+func checkExtension(ext recommendedExtension, row db.ObservabilityExtensionsRow, available bool,
+	preloaded []string, provider check.Provider, report *check.Report) {
+	finding := check.Finding{
+		ID:       ext.id,
+		Name:     ext.name,
+		Severity: check.SeverityOK,
+		Object:   &check.Object{Kind: check.ObjectExtension, Name: ext.name},
+	}
+	usedBy := ""
+	if len(ext.usedBy) > 0 {
+		usedBy = fmt.Sprintf("; needed by %s", strings.Join(ext.usedBy, ", "))
+	}
+	isPreloaded := slices.Contains(preloaded, ext.name)
+
+	switch {
+	case !available:
+		finding.Details = fmt.Sprintf("%s is not available on this server: it provides %s%s", ext.name, ext.purpose, usedBy)
+		finding.Remediation = contribRemediation
+	case row.InstalledVersion == "":
+		finding.Details = fmt.Sprintf("%s %s is available but not installed in this database: it provides %s%s",
+			ext.name, row.DefaultVersion, ext.purpose, usedBy)
+		finding.Remediation = fmt.Sprintf("Run CREATE EXTENSION %s in the databases to diagnose", ext.name)
+		finding.FixSQL = fmt.Sprintf("CREATE EXTENSION IF NOT EXISTS %s;", pgx.Identifier{ext.name}.Sanitize())
+		if ext.preload && !isPreloaded {
+			remediation, fixSQL := provider.RestartSettingFix("shared_preload_libraries", withLibrary(preloaded, ext.name))
+			finding.Remediation = fmt.Sprintf("%s, then run CREATE EXTENSION %s in the databases to diagnose", remediation, ext.name)
+			finding.FixSQL = strings.TrimSpace(fixSQL + "\n" + finding.FixSQL)
+		}
+	case ext.preload && !isPreloaded:
+		remediation, fixSQL := provider.RestartSettingFix("shared_preload_libraries", withLibrary(preloaded, ext.name))
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%s %s is installed but its library is not in shared_preload_libraries, "+
+			"so it records nothing and querying it fails", ext.name, row.InstalledVersion)
+		finding.Object = &check.Object{Kind: check.ObjectSetting, Name: "shared_preload_libraries"}
+		finding.Remediation = remediation
+		finding.FixSQL = fixSQL
+	default:
+		finding.Details = fmt.Sprintf("%s %s is installed%s", ext.name, row.InstalledVersion, usedBy)
+		if row.DefaultVersion != "" && row.DefaultVersion != row.InstalledVersion {
+			finding.Details += fmt.Sprintf(" (%s is available, see extensions)", row.DefaultVersion)
+		}
+	}
+
+	report.AddFinding(finding)
+}
+
+// checkAutoExplain reports whether auto_explain is preloaded, for all
+// sessions or for new ones, and whether it logs any plan at all. It is a
+// library rather than an extension, so it never shows up in pg_extension.
+func checkAutoExplain(settings map[string]db.CatalogSettingsRow, preloaded []string, provider check.Provider, report *check.Report) {
+	loaded := slices.Contains(preloaded, "auto_explain") ||
+		slices.Contains(libraries(settings["session_preload_libraries"].Setting.String), "auto_explain")
+
+	if !loaded {
+		remediation, fixSQL := provider.RestartSettingFix("shared_preload_libraries", withLibrary(preloaded, "auto_explain"))
+		report.AddFinding(check.Finding{
+			ID:       "auto-explain",
+			Name:     "auto_explain",
+			Severity: check.SeverityOK,
+			Details:  "auto_explain is not preloaded: slow queries are logged without the plan they ran with",
+			Object:   &check.Object{Kind: check.ObjectSetting, Name: "shared_preload_libraries"},
+			Remediation: remediation + ", and set auto_explain.log_min_duration to the slow query threshold " +
+				"(session_preload_libraries avoids the restart for new sessions)",
+			FixSQL: fixSQL,
+		})
+		return
+	}
+
+	details := "auto_explain is preloaded"
+	if row, ok := settings["auto_explain.log_min_duration"]; ok {
+		if row.Setting.String == "-1" {
+			details += ", but auto_explain.log_min_duration is -1 so it logs no plans"
+		} else {
+			details += fmt.Sprintf(" and logs the plan of statements over %s%s", row.Setting.String, row.Unit.String)
+		}
+	}
+	report.AddFinding(check.Finding{
+		ID:       "auto-explain",
+		Name:     "auto_explain",
+		Severity: check.SeverityOK,
+		Details:  details,
+	})
+}
+
+// libraries splits a *_preload_libraries setting into library names.
+func libraries(setting string) []string {
+	var names []string
+	for _, name := range strings.Split(setting, ",") {
+		name = strings.Trim(strings.TrimSpace(name), `"`)
+		if name != "" {
+			names = append(names, name)
+		}
+	}
+	return names
+}
+
+// withLibrary returns shared_preload_libraries with library added, keeping
+// the libraries already loaded.
+func withLibrary(preloaded []string, library string) string {
+	return strings.Join(append(slices.Clone(preloaded), library), ",")
+}
@@ -0,0 +1,222 @@
+package observabilityextensions_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/observabilityextensions"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockObservabilityExtensionsQueryer struct {
+	settings   []db.CatalogSettingsRow
+	extensions []db.ObservabilityExtensionsRow
+	err        error
+}
+
+func (m *mockObservabilityExtensionsQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func (m *mockObservabilityExtensionsQueryer) ObservabilityExtensions(context.Context) ([]db.ObservabilityExtensionsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.extensions, nil
+}
+
+func setting(name, value string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+	}
+}
+
+func extension(name, available, installed string) db.ObservabilityExtensionsRow {
+	return db.ObservabilityExtensionsRow{ExtensionName: name, DefaultVersion: available, InstalledVersion: installed}
+}
+
+func Test_ObservabilityExtensions_AllInstalled(t *testing.T) {
+	t.Parallel()
+
+	checker := observabilityextensions.New(&mockObservabilityExtensionsQueryer{
+		settings: []db.CatalogSettingsRow{
+			setting("shared_preload_libraries", "pg_stat_statements, auto_explain"),
+			{
+				Name:    pgtype.Text{String: "auto_explain.log_min_duration", Valid: true},
+				Setting: pgtype.Text{String: "500", Valid: true},
+				Unit:    pgtype.Text{String: "ms", Valid: true},
+			},
+		},
+		extensions: []db.ObservabilityExtensionsRow{
+			extension("pg_buffercache", "1.4", "1.4"),
+			extension("pg_stat_statements", "1.10", "1.10"),
+			extension("pgstattuple", "1.5", "1.5"),
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 4)
+	for _, finding := range report.Results {
+		require.Equal(t, check.SeverityOK, finding.Severity, finding.ID)
+	}
+	require.Equal(t, "pg_stat_statements 1.10 is installed; needed by top-queries, query-spills",
+		checktest.Finding(t, report, "pg-stat-statements").Details)
+	require.Equal(t, "auto_explain is preloaded and logs the plan of statements over 500ms", checktest.Finding(t, report, "auto-explain").Details)
+}
+
+func Test_ObservabilityExtensions(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		preload    string
+		extensions []db.ObservabilityExtensionsRow
+		id         string
+		severity   check.Severity
+		details    string
+		fixSQL     string
+	}{
+		{
+			name:     "not available",
+			id:       "pg-buffercache",
+			severity: check.SeverityOK,
+			details:  "pg_buffercache is not available on this server",
+		},
+		{
+			name:       "available but not installed",
+			extensions: []db.ObservabilityExtensionsRow{extension("pgstattuple", "1.5", "")},
+			id:         "pgstattuple",
+			severity:   check.SeverityOK,
+			details:    "pgstattuple 1.5 is available but not installed",
+			fixSQL:     `CREATE EXTENSION IF NOT EXISTS "pgstattuple";`,
+		},
+		{
+			name:       "available but neither preloaded nor installed",
+			preload:    "pg_cron",
+			extensions: []db.ObservabilityExtensionsRow{extension("pg_stat_statements", "1.10", "")},
+			id:         "pg-stat-statements",
+			severity:   check.SeverityOK,
+			details:    "is available but not installed",
+			fixSQL:     "ALTER SYSTEM SET shared_preload_libraries = 'pg_cron,pg_stat_statements';\nCREATE EXTENSION IF NOT EXISTS \"pg_stat_statements\";",
+		},
+		{
+			name:       "installed but not preloaded",
+			extensions: []db.ObservabilityExtensionsRow{extension("pg_stat_statements", "1.10", "1.10")},
+			id:         "pg-stat-statements",
+			severity:   check.SeverityWarn,
+			details:    "not in shared_preload_libraries",
+			fixSQL:     "ALTER SYSTEM SET shared_preload_libraries = 'pg_stat_statements';",
+		},
+		{
+			name:     "auto_explain not loaded",
+			preload:  "pg_stat_statements",
+			id:       "auto-explain",
+			severity: check.SeverityOK,
+			details:  "auto_explain is not preloaded",
+			fixSQL:   "ALTER SYSTEM SET shared_preload_libraries = 'pg_stat_statements,auto_explain';",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := observabilityextensions.New(&mockObservabilityExtensionsQueryer{
+				settings:   []db.CatalogSettingsRow{setting("shared_preload_libraries", tt.preload)},
+				extensions: tt.extensions,
+			})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, tt.id)
+			require.Equal(t, tt.severity, finding.Severity)
+			require.Contains(t, finding.Details, tt.details)
+			require.Equal(t, tt.fixSQL, finding.FixSQL)
+		})
+	}
+}
+
+func Test_ObservabilityExtensions_AutoExplainDisabled(t *testing.T) {
+	t.Parallel()
+
+	checker := observabilityextensions.New(&mockObservabilityExtensionsQueryer{
+		settings: []db.CatalogSettingsRow{
+			setting("session_preload_libraries", "auto_explain"),
+			setting("auto_explain.log_min_duration", "-1"),
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Contains(t, checktest.Finding(t, report, "auto-explain").Details, "logs no plans")
+}
+
+func Test_ObservabilityExtensions_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := observabilityextensions.New(&mockObservabilityExtensionsQueryer{
+		extensions: []db.ObservabilityExtensionsRow{extension("pg_stat_statements", "1.10", "")},
+	}).Check(ctx)
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "pg-stat-statements")
+	require.Equal(t, `CREATE EXTENSION IF NOT EXISTS "pg_stat_statements";`, finding.FixSQL)
+	require.Contains(t, finding.Remediation, "Set shared_preload_libraries to pg_stat_statements in the DB parameter group")
+}
+
+func Test_ObservabilityExtensions_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogSettings",
+			[]string{
+				"name text", "setting text", "unit text", "source text", "boot_val text",
+				"reset_val text", "context text", "vartype text", "pending_restart bool",
+			},
+			[]any{"shared_preload_libraries", "pg_stat_statements", nil, "configuration file", "", "pg_stat_statements", "postmaster", "string", "f"},
+		).
+		Add("ObservabilityExtensions",
+			[]string{"extension_name text", "default_version text", "installed_version text"},
+			[]any{"pg_stat_statements", "1.11", "1.10"},
+		)
+
+	report, err := observabilityextensions.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, "pg_stat_statements 1.10 is installed; needed by top-queries, query-spills (1.11 is available, see extensions)",
+		checktest.Finding(t, report, "pg-stat-statements").Details)
+}
+
+func Test_ObservabilityExtensions_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := observabilityextensions.New(&mockObservabilityExtensionsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "observability-extensions")
+}
+
+func Test_ObservabilityExtensions_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := observabilityextensions.Metadata()
+
+	require.Equal(t, "observability-extensions", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
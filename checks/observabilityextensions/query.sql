@@ -0,0 +1,11 @@
+-- name: ObservabilityExtensions :many
+-- The recommended observability extensions the server has packages for,
+-- with the installed version in the current database (empty when not
+-- installed). Extensions missing from the result are not available at all.
+SELECT
+  a.name::text AS extension_name
+  , COALESCE(a.default_version, '')::text AS default_version
+  , COALESCE(a.installed_version, '')::text AS installed_version
+FROM pg_catalog.pg_available_extensions AS a
+WHERE a.name IN ('pg_stat_statements', 'pgstattuple', 'pg_buffercache')
+ORDER BY a.name;
@@ -0,0 +1,217 @@
+// Package partialindexes implements a check suggesting partial indexes where
+// most entries of a full index are rows that queries rarely look for.
+package partialindexes
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultMinSaving = 100 * check.MiB
+
+	// Share of the index that must be dead weight before a partial index
+	// is worth the change to queries.
+	minDeadWeight = 0.5
+)
+
+const partialRemediation = "Create the partial index next to the full one and check with EXPLAIN that the queries using the index " +
+	"repeat its WHERE clause, which the planner needs to pick a partial index. Then drop the full index with DROP INDEX CONCURRENTLY"
+
+type PartialIndexesQueries interface {
+	PartialIndexCandidates(context.Context) ([]db.PartialIndexCandidatesRow, error)
+}
+
+type checker struct {
+	queries   PartialIndexesQueries
+	minSaving int64 // default: 100MB
+}
+
+// suggestion is a partial index predicate for one index and the share of
+// its entries the predicate would leave out.
+type suggestion struct {
+	row       db.PartialIndexCandidatesRow
+	predicate string
+	reason    string
+	share     float64
+}
+
+func (s suggestion) saving() int64 {
+	return int64(s.share * float64(s.row.IndexSizeBytes))
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryIndexes,
+		CheckID:     "partial-indexes",
+		Name:        "Partial Index Opportunities",
+		Description: "Suggests partial indexes where most entries of a full index are NULLs, one common value or soft-deleted rows",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries PartialIndexesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:   queries,
+		minSaving: defaultMinSaving,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["min_saving"]; ok {
+				if n, err := check.ParseBytes(v); err == nil && n > 0 {
+					c.minSaving = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.PartialIndexCandidates(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.PartialIndexCandidatesRow) string { return r.SchemaName + "." + r.TableName })
+
+	c.checkCandidates(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkCandidates suggests, for each index, the predicate leaving out the
+// largest share of its entries, when that saves at least minSaving.
+func (c *checker) checkCandidates(ctx context.Context, rows []db.PartialIndexCandidatesRow, report *check.Report) {
+	var suggestions []suggestion
+	for _, row := range rows {
+		if s, ok := bestSuggestion(row); ok && s.saving() >= c.minSaving {
+			suggestions = append(suggestions, s)
+		}
+	}
+
+	if len(suggestions) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No full index would shrink by %s or more as a partial index", check.FormatSize(ctx, c.minSaving)),
+		})
+		return
+	}
+
+	sort.SliceStable(suggestions, func(i, j int) bool { return suggestions[i].saving() > suggestions[j].saving() })
+	tableRows := make([]check.TableRow, 0, len(suggestions))
+	fixes := make([]string, 0, len(suggestions))
+	var totalSaving int64
+	for _, s := range suggestions {
+		totalSaving += s.saving()
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				s.row.SchemaName + "." + s.row.IndexName,
+				s.row.TableName,
+				check.FormatSize(ctx, s.row.IndexSizeBytes),
+				s.reason,
+				"WHERE " + s.predicate,
+				check.FormatSize(ctx, s.saving()),
+			},
+			Severity: check.SeverityWarn,
+		})
+		if fix, ok := partialIndexSQL(s); ok {
+			fixes = append(fixes, fix)
+		}
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d index(es) mostly hold entries that queries rarely search for; "+
+			"as partial indexes they would take about %s less", len(suggestions), check.FormatSize(ctx, totalSaving)),
+		Remediation: partialRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Index", "Table", "Size", "Mostly", "Partial Index", "Est. Saving"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "partial_index_saving", Value: float64(totalSaving), Unit: check.UnitBytes},
+		},
+	})
+}
+
+// bestSuggestion returns the predicate that leaves out the largest share of
+// the index's entries, if any leaves out at least minDeadWeight.
+func bestSuggestion(row db.PartialIndexCandidatesRow) (suggestion, bool) {
+	column := pgx.Identifier{row.ColumnName}.Sanitize()
+	var candidates []suggestion
+
+	candidates = append(candidates, suggestion{
+		row:       row,
+		predicate: column + " IS NOT NULL",
+		reason:    fmt.Sprintf("%.0f%% NULL %s", row.NullFrac*100, row.ColumnName),
+		share:     row.NullFrac,
+	})
+	if row.TopValue.Valid {
+		candidates = append(candidates, suggestion{
+			row:       row,
+			predicate: column + " <> " + quoteLiteral(row.TopValue.String),
+			reason:    fmt.Sprintf("%.0f%% %s = %s", row.TopValueFrac*100, row.ColumnName, row.TopValue.String),
+			share:     row.TopValueFrac,
+		})
+	}
+	if row.SoftDeleteColumn.Valid && row.SoftDeleteNullFrac.Valid {
+		deleted := 1 - row.SoftDeleteNullFrac.Float64
+		candidates = append(candidates, suggestion{
+			row:       row,
+			predicate: pgx.Identifier{row.SoftDeleteColumn.String}.Sanitize() + " IS NULL",
+			reason:    fmt.Sprintf("%.0f%% rows with %s set", deleted*100, row.SoftDeleteColumn.String),
+			share:     deleted,
+		})
+	}
+
+	best := candidates[0]
+	for _, s := range candidates[1:] {
+		if s.share > best.share {
+			best = s
+		}
+	}
+	return best, best.share >= minDeadWeight
+}
+
+// partialIndexSQL turns the full index's definition into the partial one,
+// named after it. pg_get_indexdef puts nothing after the column list that a
+// WHERE clause cannot follow.
+func partialIndexSQL(s suggestion) (string, bool) {
+	i := strings.Index(s.row.IndexDef, " ON ")
+	if !strings.HasPrefix(s.row.IndexDef, "CREATE INDEX ") || i < 0 {
+		return "", false
+	}
+	name := pgx.Identifier{s.row.IndexName + "_partial"}.Sanitize()
+	return fmt.Sprintf("CREATE INDEX CONCURRENTLY %s%s WHERE %s;", name, s.row.IndexDef[i:], s.predicate), true
+}
+
+func quoteLiteral(value string) string {
+	return "'" + strings.ReplaceAll(value, "'", "''") + "'"
+}
@@ -0,0 +1,200 @@
+package partialindexes_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/partialindexes"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockPartialIndexesQueryer struct {
+	indexes []db.PartialIndexCandidatesRow
+	err     error
+}
+
+func (m *mockPartialIndexesQueryer) PartialIndexCandidates(context.Context) ([]db.PartialIndexCandidatesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.indexes, nil
+}
+
+func makeIndex(name, column string, size int64) db.PartialIndexCandidatesRow {
+	return db.PartialIndexCandidatesRow{
+		SchemaName:     "public",
+		TableName:      "orders",
+		IndexName:      name,
+		IndexDef:       fmt.Sprintf("CREATE INDEX %s ON public.orders USING btree (%s)", name, column),
+		IndexSizeBytes: size,
+		ColumnName:     column,
+	}
+}
+
+func withNulls(row db.PartialIndexCandidatesRow, frac float64) db.PartialIndexCandidatesRow {
+	row.NullFrac = frac
+	return row
+}
+
+func withTopValue(row db.PartialIndexCandidatesRow, value string, frac float64) db.PartialIndexCandidatesRow {
+	row.TopValue = pgtype.Text{String: value, Valid: true}
+	row.TopValueFrac = frac
+	return row
+}
+
+func withSoftDelete(row db.PartialIndexCandidatesRow, column string, nullFrac float64) db.PartialIndexCandidatesRow {
+	row.SoftDeleteColumn = pgtype.Text{String: column, Valid: true}
+	row.SoftDeleteNullFrac = pgtype.Float8{Float64: nullFrac, Valid: true}
+	return row
+}
+
+func Test_PartialIndexes(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		indexes   []db.PartialIndexCandidatesRow
+		cfg       check.Config
+		severity  check.Severity
+		predicate string
+	}{
+		{
+			name:     "no indexes",
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "evenly distributed column",
+			indexes:  []db.PartialIndexCandidatesRow{withTopValue(makeIndex("orders_customer_id_idx", "customer_id", check.GiB), "42", 0.001)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:      "mostly NULL column",
+			indexes:   []db.PartialIndexCandidatesRow{withNulls(makeIndex("orders_coupon_id_idx", "coupon_id", check.GiB), 0.9)},
+			severity:  check.SeverityWarn,
+			predicate: `WHERE "coupon_id" IS NOT NULL`,
+		},
+		{
+			name:      "dominant value",
+			indexes:   []db.PartialIndexCandidatesRow{withTopValue(makeIndex("orders_status_idx", "status", check.GiB), "delivered", 0.85)},
+			severity:  check.SeverityWarn,
+			predicate: `WHERE "status" <> 'delivered'`,
+		},
+		{
+			name:      "mostly soft-deleted rows",
+			indexes:   []db.PartialIndexCandidatesRow{withSoftDelete(makeIndex("orders_customer_id_idx", "customer_id", check.GiB), "deleted_at", 0.3)},
+			severity:  check.SeverityWarn,
+			predicate: `WHERE "deleted_at" IS NULL`,
+		},
+		{
+			name:     "few soft-deleted rows",
+			indexes:  []db.PartialIndexCandidatesRow{withSoftDelete(makeIndex("orders_customer_id_idx", "customer_id", check.GiB), "deleted_at", 0.95)},
+			severity: check.SeverityOK,
+		},
+		{
+			name: "largest share wins",
+			indexes: []db.PartialIndexCandidatesRow{
+				withSoftDelete(withNulls(makeIndex("orders_coupon_id_idx", "coupon_id", check.GiB), 0.6), "deleted_at", 0.2),
+			},
+			severity:  check.SeverityWarn,
+			predicate: `WHERE "deleted_at" IS NULL`,
+		},
+		{
+			name:     "small index",
+			indexes:  []db.PartialIndexCandidatesRow{withNulls(makeIndex("orders_coupon_id_idx", "coupon_id", 50*check.MiB), 0.9)},
+			severity: check.SeverityOK,
+		},
+		{
+			name:      "configured minimum saving",
+			indexes:   []db.PartialIndexCandidatesRow{withNulls(makeIndex("orders_coupon_id_idx", "coupon_id", 50*check.MiB), 0.9)},
+			cfg:       check.Config{"partial-indexes": {"min_saving": "10MB"}},
+			severity:  check.SeverityWarn,
+			predicate: `WHERE "coupon_id" IS NOT NULL`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := partialindexes.New(&mockPartialIndexesQueryer{indexes: tt.indexes}, tt.cfg)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.severity, finding.Severity)
+			if tt.severity == check.SeverityOK {
+				require.Nil(t, finding.Table)
+				return
+			}
+			require.Equal(t, tt.predicate, finding.Table.Rows[0].Cells[4])
+			require.NotEmpty(t, finding.Remediation)
+		})
+	}
+}
+
+func Test_PartialIndexes_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	index := withTopValue(makeIndex("orders_status_idx", "status", check.GiB), "won't ship", 0.75)
+	index.IndexDef = "CREATE INDEX orders_status_idx ON public.orders USING btree (status, created_at) INCLUDE (total)"
+
+	report, err := partialindexes.New(&mockPartialIndexesQueryer{indexes: []db.PartialIndexCandidatesRow{index}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, `CREATE INDEX CONCURRENTLY "orders_status_idx_partial" ON public.orders USING btree (status, created_at) INCLUDE (total) `+
+		`WHERE "status" <> 'won''t ship';`, finding.FixSQL)
+	require.Equal(t, "768.0MiB", finding.Table.Rows[0].Cells[5])
+}
+
+func Test_PartialIndexes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("PartialIndexCandidates",
+		[]string{
+			"schema_name text", "table_name text", "index_name text", "index_def text", "index_size_bytes int8",
+			"column_name text", "null_frac float8", "top_value text", "top_value_frac float8",
+			"soft_delete_column text", "soft_delete_null_frac float8",
+		},
+		[]any{
+			"public", "invoices", "invoices_paid_at_idx", "CREATE INDEX invoices_paid_at_idx ON public.invoices USING btree (paid_at)",
+			2 * check.GiB, "paid_at", 0.75, nil, 0, nil, nil,
+		},
+	)
+
+	report, err := partialindexes.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"public.invoices_paid_at_idx", "invoices", "2.0GiB", "75% NULL paid_at", `WHERE "paid_at" IS NOT NULL`, "1.5GiB"},
+		finding.Table.Rows[0].Cells)
+}
+
+func Test_PartialIndexes_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := partialindexes.New(&mockPartialIndexesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "partial-indexes")
+}
+
+func Test_PartialIndexes_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := partialindexes.Metadata()
+
+	require.Equal(t, "partial-indexes", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,52 @@
+-- name: PartialIndexCandidates :many
+-- Non-unique, non-partial B-tree indexes with the statistics of their first
+-- column: the share of NULLs, and the most common value with its share. When
+-- the table has a soft-delete column (deleted_at, archived_at, discarded_at)
+-- that the index does not cover, its name and share of NULLs (rows not
+-- deleted) are included too. Indexes on expressions are left out.
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , pg_get_indexdef(c.oid) AS index_def
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , a.attname::text AS column_name
+  , COALESCE(st.null_frac, 0)::float8 AS null_frac
+  , (st.most_common_vals::text::text[])[1] AS top_value
+  , COALESCE(st.most_common_freqs[1], 0)::float8 AS top_value_frac
+  , sd.attname::text AS soft_delete_column
+  , sd.null_frac::float8 AS soft_delete_null_frac
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+INNER JOIN pg_attribute AS a ON t.oid = a.attrelid AND i.indkey[0] = a.attnum
+LEFT JOIN pg_stats AS st
+  ON n.nspname = st.schemaname AND t.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN LATERAL (
+  SELECT
+    s.attname
+    , s.null_frac
+  FROM pg_stats AS s
+  WHERE
+    s.schemaname = n.nspname
+    AND s.tablename = t.relname
+    AND NOT s.inherited
+    AND s.attname IN ('deleted_at', 'archived_at', 'discarded_at')
+    AND s.attname <> ALL (
+      SELECT ia.attname
+      FROM pg_attribute AS ia
+      WHERE ia.attrelid = t.oid AND ia.attnum = ANY (i.indkey::int2[])
+    )
+  ORDER BY s.attname
+  LIMIT 1
+) AS sd ON true
+WHERE
+  am.amname = 'btree'
+  AND c.relkind = 'i'
+  AND NOT i.indisunique
+  AND i.indpred IS NULL
+  AND i.indisvalid
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY index_size_bytes DESC, n.nspname, c.relname;
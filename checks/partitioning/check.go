@@ -5,6 +5,8 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"sort"
+	"strconv"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
@@ -18,10 +20,12 @@ var readme string
 
 type PartitioningQueries interface {
 	LargeTables(context.Context) ([]db.LargeTablesRow, error)
+	PartitionCandidates(context.Context) ([]db.PartitionCandidatesRow, error)
 }
 
 type checker struct {
-	queries PartitioningQueries
+	queries          PartitioningQueries
+	candidateMinSize int64 // default: 10GiB
 }
 
 const (
@@ -36,8 +40,15 @@ const (
 	// Activity thresholds for determining table write patterns.
 	insertHeavyRatio = 0.80 // >80% of DML operations are inserts
 	highDeleteRatio  = 0.20 // >20% deletes relative to inserts
+
+	// Size above which an ever-growing, time-keyed table should be partitioned.
+	defaultCandidateMinSize = 10 * check.GiB
 )
 
+const candidateRemediation = "Recreate the table with PARTITION BY RANGE on the key column (monthly partitions are a common start, " +
+	"managed by pg_partman) and move the rows over in batches. Queries filtering on the key then only read the matching partitions, " +
+	"and old data is removed with DETACH PARTITION and DROP TABLE instead of DELETE and vacuum"
+
 func Metadata() check.Metadata {
 	return check.Metadata{
 		Category:    check.CategorySchema,
@@ -49,10 +60,23 @@ func Metadata() check.Metadata {
 	}
 }
 
-func New(queries PartitioningQueries, _ ...check.Config) check.Checker {
-	return &checker{
-		queries: queries,
+func New(queries PartitioningQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:          queries,
+		candidateMinSize: defaultCandidateMinSize,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["candidate_min_size"]; ok {
+				if n, err := check.ParseBytes(v); err == nil && n > 0 {
+					c.candidateMinSize = n
+				}
+			}
+		}
 	}
+
+	return c
 }
 
 func (c *checker) Metadata() check.Metadata {
@@ -66,6 +90,15 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategorySchema, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.LargeTablesRow) string { return r.TableName.String })
+
+	candidates, err := c.queries.PartitionCandidates(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", check.CategorySchema, report.CheckID, err)
+	}
+	candidates = check.FilterTables(ctx, candidates, func(r db.PartitionCandidatesRow) string {
+		return r.SchemaName + "." + r.TableName
+	})
 
 	var largeUnpartitioned []db.LargeTablesRow
 	var transientUnpartitioned []db.LargeTablesRow
@@ -91,9 +124,10 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	}
 
 	// Run subchecks.
-	checkLargeUnpartitioned(largeUnpartitioned, report)
-	checkTransientUnpartitioned(transientUnpartitioned, report)
-	checkInefficientPartitions(inefficientPartitions, report)
+	checkLargeUnpartitioned(ctx, largeUnpartitioned, report)
+	checkTransientUnpartitioned(ctx, transientUnpartitioned, report)
+	checkInefficientPartitions(ctx, inefficientPartitions, report)
+	c.checkPartitionCandidates(ctx, candidates, report)
 
 	return report, nil
 }
@@ -136,7 +170,7 @@ func activityReason(row db.LargeTablesRow) string {
 	return "Large table"
 }
 
-func checkLargeUnpartitioned(rows []db.LargeTablesRow, report *check.Report) {
+func checkLargeUnpartitioned(ctx context.Context, rows []db.LargeTablesRow, report *check.Report) {
 	var critical []db.LargeTablesRow
 	var warning []db.LargeTablesRow
 
@@ -175,7 +209,7 @@ func checkLargeUnpartitioned(rows []db.LargeTablesRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				row.TableName.String,
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				check.FormatNumber(row.EstimatedRows.Int64),
 				activityReason(row),
 				"MUST partition",
@@ -188,7 +222,7 @@ func checkLargeUnpartitioned(rows []db.LargeTablesRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				row.TableName.String,
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				check.FormatNumber(row.EstimatedRows.Int64),
 				activityReason(row),
 				"Approaching threshold",
@@ -215,7 +249,7 @@ func checkLargeUnpartitioned(rows []db.LargeTablesRow, report *check.Report) {
 }
 
 // Identifies transient tables (outbox, inbox, jobs) without partitioning.
-func checkTransientUnpartitioned(rows []db.LargeTablesRow, report *check.Report) {
+func checkTransientUnpartitioned(ctx context.Context, rows []db.LargeTablesRow, report *check.Report) {
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
 			ID:       "transient-unpartitioned",
@@ -231,7 +265,7 @@ func checkTransientUnpartitioned(rows []db.LargeTablesRow, report *check.Report)
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				row.TableName.String,
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				check.FormatNumber(row.EstimatedRows.Int64),
 			},
 			Severity: check.SeverityFail,
@@ -251,7 +285,7 @@ func checkTransientUnpartitioned(rows []db.LargeTablesRow, report *check.Report)
 }
 
 // checkInefficientPartitions identifies partitions that are too large, indicating poor partition strategy.
-func checkInefficientPartitions(rows []db.LargeTablesRow, report *check.Report) {
+func checkInefficientPartitions(ctx context.Context, rows []db.LargeTablesRow, report *check.Report) {
 	if len(rows) == 0 {
 		return // No finding needed when there are no inefficient partitions
 	}
@@ -266,7 +300,7 @@ func checkInefficientPartitions(rows []db.LargeTablesRow, report *check.Report)
 			Cells: []string{
 				row.TableName.String,
 				parentTable,
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				check.FormatNumber(row.EstimatedRows.Int64),
 			},
 			Severity: check.SeverityWarn,
@@ -284,3 +318,56 @@ func checkInefficientPartitions(rows []db.LargeTablesRow, report *check.Report)
 		},
 	})
 }
+
+// checkPartitionCandidates flags large tables that keep growing along a time
+// or sequence column. The query already leaves out partitions and partitioned
+// parents, so every table here is a plain heap.
+func (c *checker) checkPartitionCandidates(ctx context.Context, rows []db.PartitionCandidatesRow, report *check.Report) {
+	var candidates []db.PartitionCandidatesRow
+	for _, row := range rows {
+		// Tables shrinking as fast as they grow are queues, not histories.
+		if row.TableSizeBytes >= c.candidateMinSize && row.NTupIns > row.NTupDel {
+			candidates = append(candidates, row)
+		}
+	}
+
+	if len(candidates) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "partition-candidates",
+			Name:     "Partitioning Candidates",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No unpartitioned table larger than %s grows along a time or sequence key", check.FormatSize(ctx, c.candidateMinSize)),
+		})
+		return
+	}
+
+	sort.SliceStable(candidates, func(i, j int) bool { return candidates[i].TableSizeBytes > candidates[j].TableSizeBytes })
+	tableRows := make([]check.TableRow, 0, len(candidates))
+	for _, row := range candidates {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				check.FormatSize(ctx, row.TableSizeBytes),
+				check.FormatNumber(row.EstimatedRows),
+				row.KeyColumn + " (" + row.KeyType + ")",
+				strconv.FormatFloat(row.Correlation, 'f', 2, 64),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	largest := candidates[0]
+	report.AddFinding(check.Finding{
+		ID:       "partition-candidates",
+		Name:     "Partitioning Candidates",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("Found %d unpartitioned table(s) larger than %s growing along a time or sequence key; "+
+			"the largest, %s, could be range partitioned by %s",
+			len(candidates), check.FormatSize(ctx, c.candidateMinSize), largest.SchemaName+"."+largest.TableName, largest.KeyColumn),
+		Remediation: candidateRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Size", "Est. Rows", "Partition Key", "Correlation"},
+			Rows:    tableRows,
+		},
+	})
+}
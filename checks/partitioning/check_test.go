@@ -8,14 +8,16 @@ import (
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/checks/partitioning"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/stretchr/testify/require"
 )
 
 // Mock queryer for testing.
 type mockQueryer struct {
-	tables []db.LargeTablesRow
-	err    error
+	tables     []db.LargeTablesRow
+	candidates []db.PartitionCandidatesRow
+	err        error
 }
 
 func (m *mockQueryer) LargeTables(context.Context) ([]db.LargeTablesRow, error) {
@@ -25,6 +27,13 @@ func (m *mockQueryer) LargeTables(context.Context) ([]db.LargeTablesRow, error)
 	return m.tables, nil
 }
 
+func (m *mockQueryer) PartitionCandidates(context.Context) ([]db.PartitionCandidatesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.candidates, nil
+}
+
 func newMockQueryer(tables []db.LargeTablesRow) *mockQueryer {
 	return &mockQueryer{tables: tables}
 }
@@ -38,6 +47,7 @@ const (
 	findingIDLargeUnpartitioned     = "large-unpartitioned"
 	findingIDTransientUnpartitioned = "transient-unpartitioned"
 	findingIDInefficientPartitions  = "inefficient-partitions"
+	findingIDPartitionCandidates    = "partition-candidates"
 )
 
 // Helper to create a LargeTablesRow with common defaults.
@@ -96,8 +106,8 @@ func Test_Partitioning_NoLargeTables(t *testing.T) {
 	report, err := checker.Check(context.Background())
 	require.NoError(t, err)
 
-	// Should have 2 findings (large-unpartitioned and transient-unpartitioned)
-	require.Equal(t, 2, len(report.Results))
+	// Should have 3 findings (large-unpartitioned, transient-unpartitioned and partition-candidates)
+	require.Equal(t, 3, len(report.Results))
 
 	// Both should be OK
 	for _, result := range report.Results {
@@ -120,7 +130,7 @@ func Test_Partitioning_AllPartitioned(t *testing.T) {
 	report, err := checker.Check(context.Background())
 	require.NoError(t, err)
 
-	require.Equal(t, 2, len(report.Results))
+	require.Equal(t, 3, len(report.Results))
 	for _, result := range report.Results {
 		require.Equal(t, check.SeverityOK, result.Severity)
 	}
@@ -224,7 +234,7 @@ func Test_Partitioning_MixedResults(t *testing.T) {
 	report, err := checker.Check(context.Background())
 	require.NoError(t, err)
 
-	require.Equal(t, 2, len(report.Results))
+	require.Equal(t, 3, len(report.Results))
 	require.Equal(t, check.SeverityFail, report.Severity)
 
 	// Check large-unpartitioned finding
@@ -282,6 +292,109 @@ func Test_Partitioning_InefficientPartitions(t *testing.T) {
 	require.Equal(t, "public.orders", inefficientFinding.Table.Rows[0].Cells[1]) // Parent table
 }
 
+// Helper to create a PartitionCandidatesRow keyed on a timestamp column.
+func makeCandidate(table string, size, ins, del int64) db.PartitionCandidatesRow {
+	return db.PartitionCandidatesRow{
+		SchemaName:     "public",
+		TableName:      table,
+		TableSizeBytes: size,
+		EstimatedRows:  size / 200,
+		NTupIns:        ins,
+		NTupDel:        del,
+		KeyColumn:      "created_at",
+		KeyType:        "timestamp with time zone",
+		Correlation:    0.99,
+	}
+}
+
+func Test_Partitioning_PartitionCandidates(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		candidates []db.PartitionCandidatesRow
+		cfg        check.Config
+		severity   check.Severity
+		tables     []string
+	}{
+		{
+			name:     "no candidates",
+			severity: check.SeverityOK,
+		},
+		{
+			name:       "growing table above the size threshold",
+			candidates: []db.PartitionCandidatesRow{makeCandidate("audit_log", 40*check.GiB, 900_000_000, 0)},
+			severity:   check.SeverityWarn,
+			tables:     []string{"public.audit_log"},
+		},
+		{
+			name:       "table below the size threshold",
+			candidates: []db.PartitionCandidatesRow{makeCandidate("audit_log", 2*check.GiB, 900_000_000, 0)},
+			severity:   check.SeverityOK,
+		},
+		{
+			name:       "table deleting as much as it inserts",
+			candidates: []db.PartitionCandidatesRow{makeCandidate("job_queue", 40*check.GiB, 1_000_000, 1_000_000)},
+			severity:   check.SeverityOK,
+		},
+		{
+			name: "sorted by size",
+			candidates: []db.PartitionCandidatesRow{
+				makeCandidate("page_views", 12*check.GiB, 500_000_000, 0),
+				makeCandidate("audit_log", 40*check.GiB, 900_000_000, 1_000),
+			},
+			severity: check.SeverityWarn,
+			tables:   []string{"public.audit_log", "public.page_views"},
+		},
+		{
+			name:       "configured size threshold",
+			candidates: []db.PartitionCandidatesRow{makeCandidate("audit_log", 2*check.GiB, 900_000_000, 0)},
+			cfg:        check.Config{"partitioning": {"candidate_min_size": "1GB"}},
+			severity:   check.SeverityWarn,
+			tables:     []string{"public.audit_log"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockQueryer{candidates: tt.candidates}
+			report, err := partitioning.New(queryer, tt.cfg).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, findingIDPartitionCandidates)
+			require.Equal(t, tt.severity, finding.Severity)
+			if tt.severity == check.SeverityOK {
+				require.Nil(t, finding.Table)
+				return
+			}
+
+			var tables []string
+			for _, row := range finding.Table.Rows {
+				tables = append(tables, row.Cells[0])
+			}
+			require.Equal(t, tt.tables, tables)
+			require.NotEmpty(t, finding.Remediation)
+		})
+	}
+}
+
+func Test_Partitioning_PartitionCandidatesContent(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{candidates: []db.PartitionCandidatesRow{makeCandidate("audit_log", 40*check.GiB, 900_000_000, 0)}}
+	report, err := partitioning.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, findingIDPartitionCandidates)
+	require.Contains(t, finding.Details, "public.audit_log")
+	require.Contains(t, finding.Details, "created_at")
+	require.Contains(t, finding.Remediation, "PARTITION BY RANGE")
+	require.Equal(t, []string{"public.audit_log", "40.0GiB", "214.7M", "created_at (timestamp with time zone)", "0.99"},
+		finding.Table.Rows[0].Cells)
+}
+
 func Test_Partitioning_QueryError(t *testing.T) {
 	t.Parallel()
 
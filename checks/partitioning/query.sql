@@ -31,3 +31,43 @@ WHERE
   c.relkind IN ('r', 'p')
   AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
   AND COALESCE(s.n_live_tup, 0) >= 10000000;
+
+-- name: PartitionCandidates :many
+-- Lists regular tables that are neither partitioned nor partitions, with the
+-- column most likely to serve as a range partition key: a timestamp or date
+-- column, or a sequence-backed integer, whose values follow the physical row
+-- order (correlation near 1), as they do when rows are only ever appended.
+-- Timestamp columns are preferred over sequences.
+SELECT DISTINCT ON (c.oid)
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , pg_catalog.pg_table_size(c.oid) AS table_size_bytes
+  , COALESCE(s.n_live_tup, 0)::bigint AS estimated_rows
+  , COALESCE(s.n_tup_ins, 0)::bigint AS n_tup_ins
+  , COALESCE(s.n_tup_del, 0)::bigint AS n_tup_del
+  , a.attname::text AS key_column
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS key_type
+  , st.correlation::float8 AS correlation
+FROM pg_catalog.pg_class AS c
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_attribute AS a
+  ON c.oid = a.attrelid AND a.attnum > 0 AND NOT a.attisdropped
+INNER JOIN pg_catalog.pg_stats AS st
+  ON n.nspname = st.schemaname AND c.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN pg_catalog.pg_attrdef AS d ON c.oid = d.adrelid AND a.attnum = d.adnum
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind = 'r'
+  AND NOT c.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
+  AND st.correlation >= 0.9
+  AND (
+    a.atttypid IN ('timestamptz'::regtype, 'timestamp'::regtype, 'date'::regtype)
+    OR a.attidentity <> ''
+    OR pg_catalog.pg_get_expr(d.adbin, d.adrelid) LIKE 'nextval(%'
+  )
+ORDER BY
+  c.oid
+  , (a.atttypid IN ('timestamptz'::regtype, 'timestamp'::regtype, 'date'::regtype)) DESC
+  , st.correlation DESC
+  , a.attnum;
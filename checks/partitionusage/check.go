@@ -68,6 +68,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s (partitioned tables): %w", report.Category, report.CheckID, err)
 	}
+	partitionedTables = check.FilterTables(ctx, partitionedTables, func(r db.PartitionedTablesWithKeysRow) string { return r.SchemaName.String + "." + r.TableName.String })
 
 	if len(partitionedTables) == 0 {
 		report.AddFinding(check.Finding{
@@ -0,0 +1,254 @@
+// Package passwordencryption implements checks for md5 password hashes that
+// block moving to SCRAM, and for expired or never-expiring login passwords.
+package passwordencryption
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"path"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const scram = "scram-sha-256"
+
+type PasswordEncryptionQueries interface {
+	PasswordEncryption(context.Context) (db.PasswordEncryptionRow, error)
+	Md5PasswordRoles(context.Context) ([]db.Md5PasswordRolesRow, error)
+	LoginRoleExpiry(context.Context) ([]db.LoginRoleExpiryRow, error)
+}
+
+type checker struct {
+	queries     PasswordEncryptionQueries
+	rotateRoles []string
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySecurity,
+		CheckID:     "password-encryption",
+		Name:        "Password Encryption",
+		Description: "Detects md5 password hashing, roles still storing md5 password verifiers, and expired or non-rotating login passwords",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries PasswordEncryptionQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries: queries,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if rotateRoles, ok := myCfg["rotate_roles"]; ok {
+				for _, pattern := range strings.Split(rotateRoles, ",") {
+					if pattern = strings.TrimSpace(pattern); pattern != "" {
+						c.rotateRoles = append(c.rotateRoles, pattern)
+					}
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	settings, err := c.queries.PasswordEncryption(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	expiry, err := c.queries.LoginRoleExpiry(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	provider := check.ProviderFromContext(ctx)
+	checkPasswordEncryptionSetting(settings, provider, report)
+	c.checkPasswordExpiry(expiry, report)
+
+	if !settings.CanReadAuthid {
+		details := "Reading stored password verifiers requires superuser (SELECT on pg_authid)"
+		if provider.Managed() {
+			// No role can read pg_authid on managed services.
+			details = fmt.Sprintf("Stored password verifiers are not readable on %s (pg_authid requires superuser)", provider)
+		}
+		report.AddFinding(check.Finding{
+			ID:       "md5-passwords",
+			Name:     "MD5 Password Verifiers",
+			Severity: check.SeveritySkip,
+			Details:  details,
+		})
+		return report, nil
+	}
+
+	roles, err := c.queries.Md5PasswordRoles(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	checkMd5Passwords(roles, report)
+
+	return report, nil
+}
+
+func checkPasswordEncryptionSetting(settings db.PasswordEncryptionRow, provider check.Provider, report *check.Report) {
+	if settings.PasswordEncryption == scram {
+		report.AddFinding(check.Finding{
+			ID:       "password-encryption-setting",
+			Name:     "Password Hashing Method",
+			Severity: check.SeverityOK,
+			Details:  "New passwords are hashed with scram-sha-256",
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.SettingFix("password_encryption", scram)
+	report.AddFinding(check.Finding{
+		ID:       "password-encryption-setting",
+		Name:     "Password Hashing Method",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("password_encryption is %q: every password set or changed is stored as a weak md5 hash. "+
+			"md5 is deprecated since PostgreSQL 18", settings.PasswordEncryption),
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "password_encryption"},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+		Comparison: &check.Comparison{
+			Setting:  "password_encryption",
+			Actual:   settings.PasswordEncryption,
+			Expected: scram,
+		},
+	})
+}
+
+// checkMd5Passwords lists roles that must reset their password before md5
+// authentication can be removed from pg_hba.conf. Login roles come first.
+func checkMd5Passwords(roles []db.Md5PasswordRolesRow, report *check.Report) {
+	if len(roles) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "md5-passwords",
+			Name:     "MD5 Password Verifiers",
+			Severity: check.SeverityOK,
+			Details:  "No roles store md5 password verifiers",
+		})
+		return
+	}
+
+	severity := check.SeverityOK
+	tableRows := make([]check.TableRow, 0, len(roles))
+	for _, role := range roles {
+		rowSeverity := check.SeverityOK
+		login := "no"
+		if role.CanLogin {
+			rowSeverity = check.SeverityWarn
+			login = "yes"
+		}
+		severity = max(severity, rowSeverity)
+
+		expires := "never"
+		if role.ValidUntil.Valid {
+			expires = role.ValidUntil.Time.Format("2006-01-02")
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName, login, expires},
+			Severity: rowSeverity,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "md5-passwords",
+		Name:     "MD5 Password Verifiers",
+		Severity: severity,
+		Details: fmt.Sprintf("%d role(s) still store an md5 password verifier. "+
+			"Reset their passwords with password_encryption = scram-sha-256 before removing md5 from pg_hba.conf", len(roles)),
+		Table: &check.Table{
+			Headers: []string{"Role", "Can Login", "Valid Until"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkPasswordExpiry warns on login roles whose password has expired, which
+// fail password authentication, and on roles matching rotate_roles whose
+// password never expires.
+func (c *checker) checkPasswordExpiry(roles []db.LoginRoleExpiryRow, report *check.Report) {
+	var tableRows []check.TableRow
+	expired := 0
+	for _, role := range roles {
+		validUntil := "never"
+		if role.HasExpiry {
+			validUntil = role.ValidUntil.Time.Format("2006-01-02")
+		}
+
+		var problem string
+		switch {
+		case role.Expired:
+			problem = "expired"
+			expired++
+		case !role.HasExpiry && c.mustRotate(role.RoleName):
+			problem = "no expiry, must rotate"
+		default:
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName, validUntil, problem},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		details := fmt.Sprintf("No login role has an expired password (%d login role(s))", len(roles))
+		if len(c.rotateRoles) == 0 {
+			details += "; set rotate_roles to require an expiry on service accounts"
+		}
+		report.AddFinding(check.Finding{
+			ID:       "password-expiry",
+			Name:     "Password Expiry",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "password-expiry",
+		Name:     "Password Expiry",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d login role(s) have an expired password, or no expiry although rotate_roles requires one. "+
+			"Roles with an expired password fail password authentication", len(tableRows)),
+		Remediation: "Rotate the password with ALTER ROLE ... PASSWORD ... VALID UNTIL, or make roles no longer in use NOLOGIN",
+		Table: &check.Table{
+			Headers: []string{"Role", "Valid Until", "Problem"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "expired_login_roles", Value: float64(expired), Unit: check.UnitCount},
+		},
+	})
+}
+
+// mustRotate reports whether a role matches one of the rotate_roles globs.
+func (c *checker) mustRotate(role string) bool {
+	for _, pattern := range c.rotateRoles {
+		if ok, _ := path.Match(pattern, role); ok {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,250 @@
+package passwordencryption_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/passwordencryption"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	settings    db.PasswordEncryptionRow
+	roles       []db.Md5PasswordRolesRow
+	expiry      []db.LoginRoleExpiryRow
+	rolesCalled bool
+	err         error
+}
+
+func (m *mockQueryer) PasswordEncryption(context.Context) (db.PasswordEncryptionRow, error) {
+	return m.settings, m.err
+}
+
+func (m *mockQueryer) Md5PasswordRoles(context.Context) ([]db.Md5PasswordRolesRow, error) {
+	m.rolesCalled = true
+	return m.roles, nil
+}
+
+func (m *mockQueryer) LoginRoleExpiry(context.Context) ([]db.LoginRoleExpiryRow, error) {
+	return m.expiry, nil
+}
+
+func Test_PasswordEncryption(t *testing.T) {
+	t.Parallel()
+
+	expiry := pgtype.Timestamptz{Time: time.Date(2027, 1, 1, 0, 0, 0, 0, time.UTC), Valid: true}
+
+	tests := []struct {
+		name            string
+		settings        db.PasswordEncryptionRow
+		roles           []db.Md5PasswordRolesRow
+		expectedSetting check.Severity
+		expectedMd5     check.Severity
+		expectedRows    int
+	}{
+		{
+			name:            "scram and no md5 roles",
+			settings:        db.PasswordEncryptionRow{PasswordEncryption: "scram-sha-256", CanReadAuthid: true},
+			expectedSetting: check.SeverityOK,
+			expectedMd5:     check.SeverityOK,
+		},
+		{
+			name:     "scram with lingering md5 login roles",
+			settings: db.PasswordEncryptionRow{PasswordEncryption: "scram-sha-256", CanReadAuthid: true},
+			roles: []db.Md5PasswordRolesRow{
+				{RoleName: "app_user", CanLogin: true, ValidUntil: expiry},
+				{RoleName: "readonly_group", CanLogin: false},
+			},
+			expectedSetting: check.SeverityOK,
+			expectedMd5:     check.SeverityWarn,
+			expectedRows:    2,
+		},
+		{
+			name:            "only non-login md5 roles",
+			settings:        db.PasswordEncryptionRow{PasswordEncryption: "scram-sha-256", CanReadAuthid: true},
+			roles:           []db.Md5PasswordRolesRow{{RoleName: "legacy_group", CanLogin: false}},
+			expectedSetting: check.SeverityOK,
+			expectedMd5:     check.SeverityOK,
+			expectedRows:    1,
+		},
+		{
+			name:            "md5 hashing without pg_authid access",
+			settings:        db.PasswordEncryptionRow{PasswordEncryption: "md5", CanReadAuthid: false},
+			expectedSetting: check.SeverityWarn,
+			expectedMd5:     check.SeveritySkip,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockQueryer{settings: tt.settings, roles: tt.roles}
+			report, err := passwordencryption.New(queryer).Check(context.Background())
+			require.NoError(t, err)
+
+			assert.Equal(t, tt.expectedSetting, checktest.Finding(t, report, "password-encryption-setting").Severity)
+
+			md5 := checktest.Finding(t, report, "md5-passwords")
+			assert.Equal(t, tt.expectedMd5, md5.Severity)
+			assert.Equal(t, tt.settings.CanReadAuthid, queryer.rolesCalled, "pg_authid is only queried when readable")
+			if tt.expectedRows > 0 {
+				require.NotNil(t, md5.Table)
+				assert.Len(t, md5.Table.Rows, tt.expectedRows)
+			}
+		})
+	}
+}
+
+func Test_PasswordEncryption_Comparison(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{settings: db.PasswordEncryptionRow{PasswordEncryption: "md5"}}
+	report, err := passwordencryption.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "password-encryption-setting")
+	require.NotNil(t, finding.Comparison)
+	assert.Equal(t, "md5", finding.Comparison.Actual)
+	assert.Equal(t, "scram-sha-256", finding.Comparison.Expected)
+}
+
+func Test_PasswordEncryption_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	t.Run("self-managed gets ALTER SYSTEM", func(t *testing.T) {
+		t.Parallel()
+
+		queryer := &mockQueryer{settings: db.PasswordEncryptionRow{PasswordEncryption: "md5", CanReadAuthid: false}}
+		report, err := passwordencryption.New(queryer).Check(context.Background())
+		require.NoError(t, err)
+
+		finding := checktest.Finding(t, report, "password-encryption-setting")
+		assert.Contains(t, finding.FixSQL, "ALTER SYSTEM SET password_encryption = 'scram-sha-256'")
+		assert.Contains(t, checktest.Finding(t, report, "md5-passwords").Details, "requires superuser")
+	})
+
+	t.Run("RDS gets a parameter group change", func(t *testing.T) {
+		t.Parallel()
+
+		ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+		queryer := &mockQueryer{settings: db.PasswordEncryptionRow{PasswordEncryption: "md5", CanReadAuthid: false}}
+		report, err := passwordencryption.New(queryer).Check(ctx)
+		require.NoError(t, err)
+
+		finding := checktest.Finding(t, report, "password-encryption-setting")
+		assert.Empty(t, finding.FixSQL, "ALTER SYSTEM is rejected on RDS")
+		assert.Equal(t, "Set password_encryption to scram-sha-256 in the DB parameter group", finding.Remediation)
+		assert.Contains(t, checktest.Finding(t, report, "md5-passwords").Details, "not readable on Amazon RDS")
+	})
+}
+
+func Test_PasswordEncryption_Expiry(t *testing.T) {
+	t.Parallel()
+
+	expired := db.LoginRoleExpiryRow{
+		RoleName:   "old_app",
+		ValidUntil: pgtype.Timestamptz{Time: time.Date(2024, 3, 1, 0, 0, 0, 0, time.UTC), Valid: true},
+		HasExpiry:  true,
+		Expired:    true,
+	}
+	rotated := db.LoginRoleExpiryRow{
+		RoleName:   "svc_billing",
+		ValidUntil: pgtype.Timestamptz{Time: time.Date(2027, 1, 1, 0, 0, 0, 0, time.UTC), Valid: true},
+		HasExpiry:  true,
+	}
+	neverExpires := db.LoginRoleExpiryRow{RoleName: "svc_reports"}
+	infinity := db.LoginRoleExpiryRow{
+		RoleName:   "svc_etl",
+		ValidUntil: pgtype.Timestamptz{InfinityModifier: pgtype.Infinity, Valid: true},
+	}
+	person := db.LoginRoleExpiryRow{RoleName: "alice"}
+
+	tests := []struct {
+		name     string
+		config   check.Config
+		expiry   []db.LoginRoleExpiryRow
+		expected check.Severity
+		rows     [][]string
+	}{
+		{
+			name:     "no expiry anywhere without rotate_roles",
+			expiry:   []db.LoginRoleExpiryRow{neverExpires, person},
+			expected: check.SeverityOK,
+		},
+		{
+			name:     "expired password",
+			expiry:   []db.LoginRoleExpiryRow{expired, person},
+			expected: check.SeverityWarn,
+			rows:     [][]string{{"old_app", "2024-03-01", "expired"}},
+		},
+		{
+			name:     "service accounts that must rotate",
+			config:   check.Config{"password-encryption": {"rotate_roles": "svc_*, deploy"}},
+			expiry:   []db.LoginRoleExpiryRow{person, infinity, rotated, neverExpires},
+			expected: check.SeverityWarn,
+			rows: [][]string{
+				{"svc_etl", "never", "no expiry, must rotate"},
+				{"svc_reports", "never", "no expiry, must rotate"},
+			},
+		},
+		{
+			name:     "service accounts rotating",
+			config:   check.Config{"password-encryption": {"rotate_roles": "svc_*"}},
+			expiry:   []db.LoginRoleExpiryRow{person, rotated},
+			expected: check.SeverityOK,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockQueryer{
+				settings: db.PasswordEncryptionRow{PasswordEncryption: "scram-sha-256"},
+				expiry:   tt.expiry,
+			}
+			report, err := passwordencryption.New(queryer, tt.config).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "password-expiry")
+			assert.Equal(t, tt.expected, finding.Severity)
+			if len(tt.rows) == 0 {
+				assert.Nil(t, finding.Table)
+				return
+			}
+			require.NotNil(t, finding.Table)
+			require.Len(t, finding.Table.Rows, len(tt.rows))
+			for i, row := range tt.rows {
+				assert.Equal(t, row, finding.Table.Rows[i].Cells)
+			}
+		})
+	}
+}
+
+func Test_PasswordEncryption_QueryError(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{err: errors.New("connection reset")}
+	_, err := passwordencryption.New(queryer).Check(context.Background())
+
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "security/password-encryption")
+}
+
+func Test_PasswordEncryption_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := passwordencryption.Metadata()
+	assert.Equal(t, "password-encryption", metadata.CheckID)
+	assert.Equal(t, check.CategorySecurity, metadata.Category)
+	assert.NotEmpty(t, metadata.SQL)
+	assert.NotEmpty(t, metadata.Readme)
+}
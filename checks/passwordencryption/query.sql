@@ -0,0 +1,31 @@
+-- name: PasswordEncryption :one
+-- Hashing method for newly set passwords, and whether stored password
+-- verifiers can be inspected. pg_authid is readable only by superusers, which
+-- most managed services (RDS, Cloud SQL, Azure) do not grant.
+SELECT
+  current_setting('password_encryption')::text AS password_encryption
+  , has_table_privilege('pg_catalog.pg_authid', 'SELECT') AS can_read_authid;
+
+-- name: Md5PasswordRoles :many
+-- Roles whose stored password verifier is still an md5 hash. Requires SELECT
+-- on pg_authid; only run when PasswordEncryption reports it is readable.
+SELECT
+  a.rolname::text AS role_name
+  , a.rolcanlogin AS can_login
+  , a.rolvaliduntil AS valid_until
+FROM pg_catalog.pg_authid AS a
+WHERE a.rolpassword LIKE 'md5%'
+ORDER BY a.rolcanlogin DESC, a.rolname;
+
+-- name: LoginRoleExpiry :many
+-- Password expiry of login roles, from pg_roles, which any role can read.
+-- rolvaliduntil of 'infinity' never expires, like NULL.
+SELECT
+  r.rolname::text AS role_name
+  , r.rolvaliduntil AS valid_until
+  , r.rolvaliduntil IS NOT NULL AND r.rolvaliduntil <> 'infinity' AS has_expiry
+  , coalesce(r.rolvaliduntil < now(), false) AS expired
+FROM pg_catalog.pg_roles AS r
+WHERE r.rolcanlogin
+  AND r.rolname NOT LIKE 'pg\_%'
+ORDER BY r.rolname;
@@ -5,6 +5,8 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"strconv"
+	"time"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
@@ -16,12 +18,34 @@ var querySQL string
 //go:embed README.md
 var readme string
 
+// defaultWarningMonths is how long before end of life the check starts warning.
+// A major upgrade needs planning, testing and a maintenance window.
+const defaultWarningMonths = 12
+
+// endOfLife is the final release date of each major version, per
+// https://www.postgresql.org/support/versioning/. 9.x versions report major 9.
+var endOfLife = map[int32]time.Time{
+	9:  date(2021, time.November, 11),
+	10: date(2022, time.November, 10),
+	11: date(2023, time.November, 9),
+	12: date(2024, time.November, 21),
+	13: date(2025, time.November, 13),
+	14: date(2026, time.November, 12),
+	15: date(2027, time.November, 11),
+	16: date(2028, time.November, 9),
+	17: date(2029, time.November, 8),
+	18: date(2030, time.November, 14),
+}
+
 type VersionQueries interface {
 	PGVersion(context.Context) (db.PGVersionRow, error)
 }
 
 type checker struct {
-	versioner VersionQueries
+	versioner     VersionQueries
+	minVersion    int32 // default: 0 (only the EOL schedule applies)
+	warningMonths int   // default: 12
+	now           func() time.Time
 }
 
 func Metadata() check.Metadata {
@@ -35,10 +59,27 @@ func Metadata() check.Metadata {
 	}
 }
 
-func New(versioner VersionQueries, _ ...check.Config) check.Checker {
-	return &checker{
-		versioner: versioner,
+func New(versioner VersionQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		versioner:     versioner,
+		warningMonths: defaultWarningMonths,
+		now:           time.Now,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["min_version"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 32); err == nil {
+					c.minVersion = int32(n)
+				}
+			}
+			if v, ok := myCfg["eol_warning_months"]; ok {
+				if n, err := strconv.Atoi(v); err == nil && n >= 0 {
+					c.warningMonths = n
+				}
+			}
+		}
 	}
+	return c
 }
 
 func (c *checker) Metadata() check.Metadata {
@@ -53,29 +94,56 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryConfigs, report.CheckID, err)
 	}
 
-	if version.Major >= 15 {
-		report.AddFinding(check.Finding{
-			Name:     report.Name,
-			ID:       report.CheckID,
-			Severity: check.SeverityOK,
-		})
-
-		return report, nil
-	}
-
-	// PostgreSQL versions below 15 are approaching or have reached end of life.
-	// See: https://www.postgresql.org/support/versioning/
-	severity := check.SeverityWarn
-	if version.Major < 14 {
-		severity = check.SeverityFail
-	}
-
+	severity, details := c.evaluate(version.Major)
 	report.AddFinding(check.Finding{
 		ID:       report.CheckID,
 		Name:     report.Name,
 		Severity: severity,
-		Details:  fmt.Sprintf("Running PostgreSQL %d which is approaching end of life. Upgrade to version 17+ recommended.\n", version.Major),
+		Details:  details,
 	})
 
 	return report, nil
 }
+
+// evaluate applies the configured minimum version first, then the EOL schedule.
+func (c *checker) evaluate(major int32) (check.Severity, string) {
+	latest := latestMajor()
+
+	if major < c.minVersion {
+		return check.SeverityFail, fmt.Sprintf("Running PostgreSQL %d, below the minimum version %d required by policy. Upgrade to PostgreSQL %d recommended.",
+			major, c.minVersion, latest)
+	}
+
+	eol, known := endOfLife[major]
+	if !known {
+		if major > latest {
+			return check.SeverityOK, fmt.Sprintf("PostgreSQL %d is newer than the bundled support schedule", major)
+		}
+		return check.SeverityFail, fmt.Sprintf("Running PostgreSQL %d which reached end of life years ago. Upgrade to PostgreSQL %d recommended.", major, latest)
+	}
+
+	today := c.now()
+	eolDate := eol.Format("2006-01-02")
+	switch {
+	case !today.Before(eol):
+		return check.SeverityFail, fmt.Sprintf("Running PostgreSQL %d which reached end of life on %s and no longer receives security fixes. Upgrade to PostgreSQL %d recommended.",
+			major, eolDate, latest)
+	case today.AddDate(0, c.warningMonths, 0).After(eol):
+		return check.SeverityWarn, fmt.Sprintf("Running PostgreSQL %d which reaches end of life on %s. Plan the upgrade to PostgreSQL %d.",
+			major, eolDate, latest)
+	default:
+		return check.SeverityOK, fmt.Sprintf("PostgreSQL %d is supported until %s", major, eolDate)
+	}
+}
+
+func latestMajor() int32 {
+	var latest int32
+	for major := range endOfLife {
+		latest = max(latest, major)
+	}
+	return latest
+}
+
+func date(year int, month time.Month, day int) time.Time {
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
@@ -1,37 +1,60 @@
-package pgversion_test
+package pgversion
 
 import (
 	"context"
 	"testing"
+	"time"
 
 	"github.com/emancu/pgdoctor/check"
-	"github.com/emancu/pgdoctor/checks/pgversion"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
 )
 
-func newStaticVersioner(row db.PGVersionRow) pgversion.VersionQueries {
+type pgVersioner func() db.PGVersionRow
+
+func (f pgVersioner) PGVersion(context.Context) (db.PGVersionRow, error) {
+	return f(), nil
+}
+
+func newStaticVersioner(row db.PGVersionRow) VersionQueries {
 	return pgVersioner(func() db.PGVersionRow {
 		return row
 	})
 }
 
-type pgVersioner func() db.PGVersionRow
-
-func (f pgVersioner) PGVersion(context.Context) (db.PGVersionRow, error) {
-	return f(), nil
+// newChecker pins the clock so results don't change as versions reach end of life.
+func newChecker(row db.PGVersionRow, today time.Time, cfg ...check.Config) *checker {
+	c := New(newStaticVersioner(row), cfg...).(*checker)
+	c.now = func() time.Time { return today }
+	return c
 }
 
 func Test_Version(t *testing.T) {
 	t.Parallel()
 
+	today := date(2026, time.June, 1)
+
 	type testCase struct {
 		Name             string
 		Row              db.PGVersionRow
+		Config           check.Config
 		ExpectedSeverity check.Severity
+		ExpectedDetails  string
 	}
 
 	testCases := []testCase{
+		{
+			Name:             "PG 9.6 - end of life",
+			Row:              db.PGVersionRow{Major: 9, Minor: 24},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "reached end of life on 2021-11-11",
+		},
+		{
+			Name:             "PG 8 - older than the schedule",
+			Row:              db.PGVersionRow{Major: 8, Minor: 0},
+			ExpectedSeverity: check.SeverityFail,
+		},
 		{
 			Name:             "PG 13 - end of life",
 			Row:              db.PGVersionRow{Major: 13, Minor: 0},
@@ -41,11 +64,13 @@ func Test_Version(t *testing.T) {
 			Name:             "PG 14 - approaching EOL",
 			Row:              db.PGVersionRow{Major: 14, Minor: 0},
 			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "reaches end of life on 2026-11-12",
 		},
 		{
 			Name:             "PG 15 - supported",
 			Row:              db.PGVersionRow{Major: 15, Minor: 0},
 			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "supported until 2027-11-11",
 		},
 		{
 			Name:             "PG 16 - supported",
@@ -57,14 +82,38 @@ func Test_Version(t *testing.T) {
 			Row:              db.PGVersionRow{Major: 17, Minor: 0},
 			ExpectedSeverity: check.SeverityOK,
 		},
+		{
+			Name:             "newer than the bundled schedule",
+			Row:              db.PGVersionRow{Major: 99, Minor: 0},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "newer than the bundled support schedule",
+		},
+		{
+			Name:             "PG 15 - wider warning window",
+			Row:              db.PGVersionRow{Major: 15, Minor: 0},
+			Config:           check.Config{"pg-version": {"eol_warning_months": "24"}},
+			ExpectedSeverity: check.SeverityWarn,
+		},
+		{
+			Name:             "PG 16 - below configured minimum",
+			Row:              db.PGVersionRow{Major: 16, Minor: 0},
+			Config:           check.Config{"pg-version": {"min_version": "17"}},
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "below the minimum version 17",
+		},
+		{
+			Name:             "invalid config is ignored",
+			Row:              db.PGVersionRow{Major: 16, Minor: 0},
+			Config:           check.Config{"pg-version": {"min_version": "latest", "eol_warning_months": "-1"}},
+			ExpectedSeverity: check.SeverityOK,
+		},
 	}
 
 	for _, tc := range testCases {
 		t.Run(tc.Name, func(t *testing.T) {
 			t.Parallel()
 
-			versioner := newStaticVersioner(tc.Row)
-			checker := pgversion.New(versioner)
+			checker := newChecker(tc.Row, today, tc.Config)
 
 			report, err := checker.Check(context.Background())
 			require.NoError(t, err)
@@ -72,6 +121,21 @@ func Test_Version(t *testing.T) {
 			results := report.Results
 			require.Equal(t, 1, len(results))
 			require.Equal(t, tc.ExpectedSeverity, results[0].Severity)
+			assert.Contains(t, results[0].Details, tc.ExpectedDetails)
 		})
 	}
 }
+
+func Test_Version_EOLDay(t *testing.T) {
+	t.Parallel()
+
+	row := db.PGVersionRow{Major: 14}
+
+	report, err := newChecker(row, date(2026, time.November, 11)).Check(context.Background())
+	require.NoError(t, err)
+	assert.Equal(t, check.SeverityWarn, report.Severity)
+
+	report, err = newChecker(row, date(2026, time.November, 12)).Check(context.Background())
+	require.NoError(t, err)
+	assert.Equal(t, check.SeverityFail, report.Severity)
+}
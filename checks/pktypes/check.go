@@ -28,6 +28,12 @@ const (
 	usagePercentFail = 50.0 // FAIL: >=50% of capacity used (urgent migration needed)
 )
 
+// remediation is the migration guidance; the column type, not the sequence,
+// is the ceiling, so raising the sequence's MAXVALUE does not help.
+const remediation = "Migrate the key columns, and every foreign key referencing them, to bigint before they reach " +
+	"the type's maximum, after which every INSERT fails. ALTER COLUMN ... TYPE bigint rewrites the table under " +
+	"an ACCESS EXCLUSIVE lock; large tables need a new-column swap instead"
+
 func Metadata() check.Metadata {
 	return check.Metadata{
 		Category:    check.CategorySchema,
@@ -56,6 +62,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("failed to check primary key types: %w", err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.InvalidPrimaryKeyTypesRow) string { return r.TableName.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -71,9 +78,11 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	maxSeverity := check.SeverityWarn
 	criticalCount := 0
 	warningCount := 0
+	maxUsage := 0.0
 
 	for _, row := range rows {
 		entry := analyzeRow(row)
+		maxUsage = max(maxUsage, entry.usagePct)
 
 		tableRows = append(tableRows, check.TableRow{
 			Cells:    entry.cells,
@@ -90,10 +99,15 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       report.CheckID,
-		Name:     report.Name,
-		Severity: maxSeverity,
-		Details:  formatDetails(criticalCount, warningCount),
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    maxSeverity,
+		Details:     formatDetails(criticalCount, warningCount),
+		Remediation: remediation,
+		Metrics: []check.Metric{
+			{Name: "integer_primary_keys", Value: float64(len(rows)), Unit: check.UnitCount},
+			{Name: "max_primary_key_usage", Value: maxUsage, Unit: check.UnitPercent},
+		},
 		Table: &check.Table{
 			Headers: []string{"Table", "Column", "Type", "Usage %", "Rows"},
 			Rows:    tableRows,
@@ -106,6 +120,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 type tableEntry struct {
 	cells    []string
 	severity check.Severity
+	usagePct float64
 }
 
 func analyzeRow(row db.InvalidPrimaryKeyTypesRow) tableEntry {
@@ -120,6 +135,7 @@ func analyzeRow(row db.InvalidPrimaryKeyTypesRow) tableEntry {
 			check.FormatNumber(row.EstimatedRows.Int64),
 		},
 		severity: determineSeverity(usagePct, row.EstimatedRows.Int64),
+		usagePct: usagePct,
 	}
 }
 
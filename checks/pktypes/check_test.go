@@ -7,6 +7,7 @@ import (
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -260,6 +261,42 @@ func TestPKTypes_UsageDisplay(t *testing.T) {
 	assert.Contains(t, usageCell, "~0.1%")
 }
 
+func TestPKTypes_RemediationAndMetrics(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.InvalidPrimaryKeyTypesRow{
+		makePKRowWithUsage("public.bookings", "id", "int4", 1_400_000_000, 1_495_000_000, 2_147_483_647, 0.696),
+		makePKRowWithUsage("public.kyc_statuses", "id", "int4", 1_200_000, 0, 2_147_483_647, 0.001),
+	}
+
+	report, err := New(&mockQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	assert.Contains(t, finding.Remediation, "bigint")
+	require.Len(t, finding.Metrics, 2)
+	assert.Equal(t, 2.0, finding.Metrics[0].Value)
+	assert.InDelta(t, 69.6, finding.Metrics[1].Value, 0.01)
+}
+
+func TestPKTypes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	// An application-assigned key: no sequence, usage from the sampled maximum.
+	fx := snapshot.NewFixture().Add("InvalidPrimaryKeyTypes",
+		[]string{
+			"table_name text", "column_name text", "column_type text", "estimated_rows int8",
+			"sequence_current int8", "column_max int8", "type_max_value int8", "usage_pct numeric",
+		},
+		[]any{"public.accounts", "id", "int4", 900000, nil, 1288490188, 2147483647, "0.6000"},
+	)
+
+	report, err := New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityFail, report.Severity)
+	require.Contains(t, report.Results[0].Table.Rows[0].Cells[3], "60.0%")
+}
+
 func TestFormatDetails(t *testing.T) {
 	t.Parallel()
 
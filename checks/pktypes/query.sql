@@ -1,5 +1,8 @@
 -- name: InvalidPrimaryKeyTypes :many
 -- Identifies tables with integer primary keys (int2/int4) that should use bigint.
+-- Usage is the highest value the column is known to hold, from its owning
+-- sequence (serial or identity) or the column statistics, against the type's
+-- maximum: the column type is the ceiling, whatever the sequence allows.
 WITH pk_tables AS (
   SELECT
     n.nspname::text AS schema_name
@@ -32,6 +35,8 @@ WITH pk_tables AS (
     )
 )
 
+-- Serial columns own their sequence (deptype 'a'), identity columns
+-- internally (deptype 'i')
 , sequence_values AS (
   SELECT
     d.refobjid AS table_oid
@@ -39,12 +44,37 @@ WITH pk_tables AS (
     , seq.last_value::bigint AS sequence_current
   FROM pg_depend AS d
   INNER JOIN pg_class AS seq_class ON d.objid = seq_class.oid
-  INNER JOIN pg_sequences AS seq ON seq_class.relname = seq.sequencename
+  INNER JOIN pg_namespace AS seq_ns ON seq_class.relnamespace = seq_ns.oid
+  INNER JOIN pg_sequences AS seq
+    ON
+      seq_ns.nspname = seq.schemaname
+      AND seq_class.relname = seq.sequencename
   WHERE
-    d.deptype = 'a'
+    d.classid = 'pg_class'::regclass
+    AND d.refclassid = 'pg_class'::regclass
+    AND d.deptype IN ('a', 'i')
     AND seq_class.relkind = 'S'
 )
 
+-- Highest value ANALYZE sampled, for keys set by the application or by a
+-- sequence that was reset
+, column_values AS (
+  SELECT
+    p.table_oid
+    , p.column_num
+    , (
+      SELECT max(v.value)
+      FROM unnest(st.histogram_bounds::text::bigint []) AS v (value)
+    ) AS column_max
+  FROM pk_tables AS p
+  INNER JOIN pg_stats AS st
+    ON
+      p.schema_name = st.schemaname
+      AND p.table_name = st.tablename
+      AND p.column_name = st.attname
+  WHERE st.histogram_bounds IS NOT NULL
+)
+
 , pk_with_usage AS (
   SELECT
     (p.schema_name || '.' || p.table_name)::text AS table_name
@@ -52,10 +82,11 @@ WITH pk_tables AS (
     , p.column_type
     , p.estimated_rows
     , sv.sequence_current
+    , cv.column_max
     , p.type_max_value
     , CASE
-      WHEN sv.sequence_current IS NOT NULL AND p.type_max_value > 0
-        THEN sv.sequence_current::numeric / p.type_max_value::numeric
+      WHEN COALESCE(sv.sequence_current, cv.column_max) IS NOT NULL AND p.type_max_value > 0
+        THEN GREATEST(sv.sequence_current, cv.column_max)::numeric / p.type_max_value::numeric
       WHEN p.estimated_rows > 0 AND p.type_max_value > 0
         THEN p.estimated_rows::numeric / p.type_max_value::numeric
       ELSE
@@ -66,6 +97,10 @@ WITH pk_tables AS (
     ON
       p.table_oid = sv.table_oid
       AND p.column_num = sv.column_num
+  LEFT JOIN column_values AS cv
+    ON
+      p.table_oid = cv.table_oid
+      AND p.column_num = cv.column_num
 )
 
 SELECT
@@ -74,6 +109,7 @@ SELECT
   , column_type
   , estimated_rows
   , sequence_current
+  , column_max
   , type_max_value
   , usage_pct
 FROM pk_with_usage
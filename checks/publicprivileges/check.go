@@ -0,0 +1,218 @@
+// Package publicprivileges implements checks for privileges granted to
+// PUBLIC: CREATE on schemas, grants on user objects and default privileges.
+package publicprivileges
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// defaultPrivilegeObjects maps pg_default_acl.defaclobjtype to the object
+// type name used by ALTER DEFAULT PRIVILEGES.
+var defaultPrivilegeObjects = map[string]string{
+	"r": "TABLES",
+	"S": "SEQUENCES",
+	"n": "SCHEMAS",
+}
+
+type PublicPrivilegesQueries interface {
+	PublicCreateSchemas(context.Context) ([]string, error)
+	PublicObjectGrants(context.Context) ([]db.PublicObjectGrantsRow, error)
+	PublicDefaultPrivileges(context.Context) ([]db.PublicDefaultPrivilegesRow, error)
+}
+
+type checker struct {
+	queries PublicPrivilegesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySecurity,
+		CheckID:     "public-privileges",
+		Name:        "PUBLIC Privileges",
+		Description: "Detects CREATE granted to PUBLIC on schemas, grants to PUBLIC on user objects, and default privileges granting PUBLIC access",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries PublicPrivilegesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	schemas, err := c.queries.PublicCreateSchemas(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	grants, err := c.queries.PublicObjectGrants(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	defaults, err := c.queries.PublicDefaultPrivileges(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	checkSchemaCreate(schemas, report)
+	checkObjectGrants(grants, report)
+	checkDefaultPrivileges(defaults, report)
+
+	return report, nil
+}
+
+// checkSchemaCreate warns on schemas where every role may create objects,
+// which lets any role shadow functions and operators that other roles find
+// through search_path (CVE-2018-1058).
+func checkSchemaCreate(schemas []string, report *check.Report) {
+	if len(schemas) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "public-schema-create",
+			Name:     "CREATE on Schemas for PUBLIC",
+			Severity: check.SeverityOK,
+			Details:  "No schema lets PUBLIC create objects",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(schemas))
+	fixes := make([]string, 0, len(schemas))
+	for _, schema := range schemas {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{schema},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("REVOKE CREATE ON SCHEMA %s FROM PUBLIC;", pgx.Identifier{schema}.Sanitize()))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "public-schema-create",
+		Name:     "CREATE on Schemas for PUBLIC",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d schema(s) let every role create objects: any role can then shadow functions and "+
+			"operators other roles resolve through search_path. This was the default for public before PostgreSQL 15", len(schemas)),
+		Remediation: "Revoke CREATE from PUBLIC and grant it to the roles that run migrations",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Schema"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkObjectGrants warns on user objects PUBLIC can access, which includes
+// every role created later.
+func checkObjectGrants(grants []db.PublicObjectGrantsRow, report *check.Report) {
+	if len(grants) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "public-grants",
+			Name:     "Grants to PUBLIC",
+			Severity: check.SeverityOK,
+			Details:  "No user table, view or sequence, and no SECURITY DEFINER function, is granted to PUBLIC",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(grants))
+	fixes := make([]string, 0, len(grants))
+	for _, grant := range grants {
+		keyword, signature := "TABLE", ""
+		switch grant.ObjectKind {
+		case "sequence":
+			keyword = "SEQUENCE"
+		case "function":
+			keyword, signature = "FUNCTION", "("+grant.Arguments+")"
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{grant.SchemaName + "." + grant.ObjectName + signature, grant.ObjectKind, grant.Privileges},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("REVOKE %s ON %s %s%s FROM PUBLIC;",
+			grant.Privileges, keyword, pgx.Identifier{grant.SchemaName, grant.ObjectName}.Sanitize(), signature))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "public-grants",
+		Name:     "Grants to PUBLIC",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d object(s) are granted to PUBLIC, so every current and future role can use them; "+
+			"SECURITY DEFINER functions run with their owner's privileges", len(grants)),
+		Remediation: "Revoke the privileges from PUBLIC and grant them to the roles that need them",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Object", "Kind", "Privileges"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "public_grants", Value: float64(len(grants)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// checkDefaultPrivileges warns on ALTER DEFAULT PRIVILEGES entries that grant
+// PUBLIC access to every table, sequence or schema a role creates from now on.
+func checkDefaultPrivileges(defaults []db.PublicDefaultPrivilegesRow, report *check.Report) {
+	if len(defaults) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "default-privileges",
+			Name:     "Default Privileges for PUBLIC",
+			Severity: check.SeverityOK,
+			Details:  "No ALTER DEFAULT PRIVILEGES entry grants PUBLIC access to new tables, sequences or schemas",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(defaults))
+	fixes := make([]string, 0, len(defaults))
+	for _, def := range defaults {
+		objects := defaultPrivilegeObjects[def.ObjectType]
+		schema := "all schemas"
+		inSchema := ""
+		if def.SchemaName != "" {
+			schema = def.SchemaName
+			inSchema = " IN SCHEMA " + pgx.Identifier{def.SchemaName}.Sanitize()
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{def.RoleName, schema, strings.ToLower(objects), def.Privileges},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("ALTER DEFAULT PRIVILEGES FOR ROLE %s%s REVOKE %s ON %s FROM PUBLIC;",
+			pgx.Identifier{def.RoleName}.Sanitize(), inSchema, def.Privileges, objects))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "default-privileges",
+		Name:     "Default Privileges for PUBLIC",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d default privilege entries grant PUBLIC access to objects created in the future, "+
+			"so every new table or sequence is readable or writable by every role", len(defaults)),
+		Remediation: "Revoke the default privileges from PUBLIC and grant them to a group role instead",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Role", "Schema", "Objects", "Privileges"},
+			Rows:    tableRows,
+		},
+	})
+}
@@ -0,0 +1,176 @@
+package publicprivileges_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/publicprivileges"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockPublicPrivilegesQueryer struct {
+	schemas  []string
+	grants   []db.PublicObjectGrantsRow
+	defaults []db.PublicDefaultPrivilegesRow
+	err      error
+}
+
+func (m *mockPublicPrivilegesQueryer) PublicCreateSchemas(context.Context) ([]string, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.schemas, nil
+}
+
+func (m *mockPublicPrivilegesQueryer) PublicObjectGrants(context.Context) ([]db.PublicObjectGrantsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.grants, nil
+}
+
+func (m *mockPublicPrivilegesQueryer) PublicDefaultPrivileges(context.Context) ([]db.PublicDefaultPrivilegesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.defaults, nil
+}
+
+func Test_PublicPrivileges(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		queryer  *mockPublicPrivilegesQueryer
+		schemas  check.Severity
+		grants   check.Severity
+		defaults check.Severity
+	}{
+		{
+			name:     "PostgreSQL 15+ defaults",
+			queryer:  &mockPublicPrivilegesQueryer{},
+			schemas:  check.SeverityOK,
+			grants:   check.SeverityOK,
+			defaults: check.SeverityOK,
+		},
+		{
+			name:     "public schema created before PostgreSQL 15",
+			queryer:  &mockPublicPrivilegesQueryer{schemas: []string{"public"}},
+			schemas:  check.SeverityWarn,
+			grants:   check.SeverityOK,
+			defaults: check.SeverityOK,
+		},
+		{
+			name: "table granted to PUBLIC",
+			queryer: &mockPublicPrivilegesQueryer{grants: []db.PublicObjectGrantsRow{
+				{ObjectKind: "table", SchemaName: "public", ObjectName: "users", Privileges: "SELECT"},
+			}},
+			schemas:  check.SeverityOK,
+			grants:   check.SeverityWarn,
+			defaults: check.SeverityOK,
+		},
+		{
+			name: "default privileges for PUBLIC",
+			queryer: &mockPublicPrivilegesQueryer{defaults: []db.PublicDefaultPrivilegesRow{
+				{RoleName: "app", ObjectType: "r", Privileges: "SELECT"},
+			}},
+			schemas:  check.SeverityOK,
+			grants:   check.SeverityOK,
+			defaults: check.SeverityWarn,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := publicprivileges.New(tt.queryer).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 3)
+			require.Equal(t, tt.schemas, checktest.Finding(t, report, "public-schema-create").Severity)
+			require.Equal(t, tt.grants, checktest.Finding(t, report, "public-grants").Severity)
+			require.Equal(t, tt.defaults, checktest.Finding(t, report, "default-privileges").Severity)
+		})
+	}
+}
+
+func Test_PublicPrivileges_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	report, err := publicprivileges.New(&mockPublicPrivilegesQueryer{
+		schemas: []string{"public", "Reporting"},
+		grants: []db.PublicObjectGrantsRow{
+			{ObjectKind: "function", SchemaName: "app", ObjectName: "reset_password", Arguments: "user_id bigint", Privileges: "EXECUTE"},
+			{ObjectKind: "sequence", SchemaName: "app", ObjectName: "orders_id_seq", Privileges: "SELECT, UPDATE, USAGE"},
+			{ObjectKind: "view", SchemaName: "app", ObjectName: "active_users", Privileges: "INSERT, SELECT"},
+		},
+		defaults: []db.PublicDefaultPrivilegesRow{
+			{RoleName: "migrator", ObjectType: "r", Privileges: "SELECT"},
+			{RoleName: "migrator", SchemaName: "app", ObjectType: "S", Privileges: "USAGE"},
+		},
+	}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, "REVOKE CREATE ON SCHEMA \"public\" FROM PUBLIC;\nREVOKE CREATE ON SCHEMA \"Reporting\" FROM PUBLIC;",
+		checktest.Finding(t, report, "public-schema-create").FixSQL)
+
+	grants := checktest.Finding(t, report, "public-grants")
+	require.Equal(t, []string{"app.reset_password(user_id bigint)", "function", "EXECUTE"}, grants.Table.Rows[0].Cells)
+	require.Equal(t, "REVOKE EXECUTE ON FUNCTION \"app\".\"reset_password\"(user_id bigint) FROM PUBLIC;\n"+
+		"REVOKE SELECT, UPDATE, USAGE ON SEQUENCE \"app\".\"orders_id_seq\" FROM PUBLIC;\n"+
+		"REVOKE INSERT, SELECT ON TABLE \"app\".\"active_users\" FROM PUBLIC;", grants.FixSQL)
+
+	defaults := checktest.Finding(t, report, "default-privileges")
+	require.Equal(t, []string{"migrator", "all schemas", "tables", "SELECT"}, defaults.Table.Rows[0].Cells)
+	require.Equal(t, "ALTER DEFAULT PRIVILEGES FOR ROLE \"migrator\" REVOKE SELECT ON TABLES FROM PUBLIC;\n"+
+		"ALTER DEFAULT PRIVILEGES FOR ROLE \"migrator\" IN SCHEMA \"app\" REVOKE USAGE ON SEQUENCES FROM PUBLIC;", defaults.FixSQL)
+}
+
+func Test_PublicPrivileges_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("PublicCreateSchemas", []string{"schema_name text"}, []any{"public"}).
+		Add("PublicObjectGrants",
+			[]string{"object_kind text", "schema_name text", "object_name text", "arguments text", "privileges text"},
+			[]any{"table", "public", "audit_log", "", "INSERT, SELECT"},
+		).
+		Add("PublicDefaultPrivileges",
+			[]string{"role_name text", "schema_name text", "object_type text", "privileges text"},
+		)
+
+	report, err := publicprivileges.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "public-schema-create").Severity)
+	require.Equal(t, []string{"public.audit_log", "table", "INSERT, SELECT"}, checktest.Finding(t, report, "public-grants").Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "default-privileges").Severity)
+}
+
+func Test_PublicPrivileges_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := publicprivileges.New(&mockPublicPrivilegesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "public-privileges")
+}
+
+func Test_PublicPrivileges_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := publicprivileges.Metadata()
+
+	require.Equal(t, "public-privileges", metadata.CheckID)
+	require.Equal(t, check.CategorySecurity, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,108 @@
+-- name: PublicCreateSchemas :many
+-- Schemas in which PUBLIC, that is every role, may create objects. Before
+-- PostgreSQL 15 the public schema grants CREATE to PUBLIC by default. A NULL
+-- ACL means the built-in defaults, which never include PUBLIC CREATE.
+SELECT n.nspname::text AS schema_name
+FROM pg_catalog.pg_namespace AS n
+CROSS JOIN LATERAL pg_catalog.aclexplode(coalesce(n.nspacl, pg_catalog.acldefault('n', n.nspowner))) AS acl
+WHERE
+  acl.grantee = 0
+  AND acl.privilege_type = 'CREATE'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_%'
+ORDER BY n.nspname;
+
+-- name: PublicObjectGrants :many
+-- User relations with privileges granted to PUBLIC, and SECURITY DEFINER
+-- functions PUBLIC may execute. EXECUTE to PUBLIC is the default for every
+-- function, so only those running with their owner's privileges are listed.
+-- Objects owned by extensions are left out.
+WITH relation_grants AS (
+  SELECT
+    CASE c.relkind
+      WHEN 'v' THEN 'view'
+      WHEN 'm' THEN 'materialized view'
+      WHEN 'f' THEN 'foreign table'
+      WHEN 'S' THEN 'sequence'
+      ELSE 'table'
+    END AS object_kind
+    , n.nspname::text AS schema_name
+    , c.relname::text AS object_name
+    , '' AS arguments
+    , acl.privilege_type
+  FROM pg_catalog.pg_class AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  CROSS JOIN LATERAL pg_catalog.aclexplode(c.relacl) AS acl
+  WHERE
+    acl.grantee = 0
+    AND c.relkind IN ('r', 'p', 'v', 'm', 'f', 'S')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+),
+
+function_grants AS (
+  SELECT
+    'function' AS object_kind
+    , n.nspname::text AS schema_name
+    , p.proname::text AS object_name
+    , pg_catalog.pg_get_function_identity_arguments(p.oid) AS arguments
+    , acl.privilege_type
+  FROM pg_catalog.pg_proc AS p
+  INNER JOIN pg_catalog.pg_namespace AS n ON p.pronamespace = n.oid
+  CROSS JOIN LATERAL pg_catalog.aclexplode(coalesce(p.proacl, pg_catalog.acldefault('f', p.proowner))) AS acl
+  WHERE
+    acl.grantee = 0
+    AND p.prosecdef
+    AND p.prokind IN ('f', 'p')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_proc'::regclass
+        AND p.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+SELECT
+  g.object_kind
+  , g.schema_name
+  , g.object_name
+  , g.arguments
+  , string_agg(g.privilege_type, ', ' ORDER BY g.privilege_type) AS privileges
+FROM (
+  SELECT * FROM relation_grants
+  UNION ALL
+  SELECT * FROM function_grants
+) AS g
+GROUP BY g.object_kind, g.schema_name, g.object_name, g.arguments
+ORDER BY g.schema_name, g.object_name, g.arguments;
+
+-- name: PublicDefaultPrivileges :many
+-- ALTER DEFAULT PRIVILEGES entries that grant PUBLIC access to tables,
+-- sequences or schemas created in the future. schema_name is empty for
+-- entries that apply to every schema.
+SELECT
+  r.rolname::text AS role_name
+  , coalesce(n.nspname, '')::text AS schema_name
+  , d.defaclobjtype::text AS object_type
+  , string_agg(acl.privilege_type, ', ' ORDER BY acl.privilege_type) AS privileges
+FROM pg_catalog.pg_default_acl AS d
+INNER JOIN pg_catalog.pg_roles AS r ON d.defaclrole = r.oid
+LEFT JOIN pg_catalog.pg_namespace AS n ON d.defaclnamespace = n.oid
+CROSS JOIN LATERAL pg_catalog.aclexplode(d.defaclacl) AS acl
+WHERE
+  acl.grantee = 0
+  AND d.defaclobjtype IN ('r', 'S', 'n')
+GROUP BY r.rolname, n.nspname, d.defaclobjtype
+ORDER BY r.rolname, schema_name, object_type;
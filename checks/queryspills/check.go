@@ -0,0 +1,208 @@
+// Package queryspills implements a check naming the statements that spill to
+// temporary files, from pg_stat_statements.
+package queryspills
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultMinSpillBytes = check.GiB
+
+	// Raising work_mem for a session is only reasonable up to this size;
+	// beyond it the statement needs an index or a rewrite instead.
+	maxSessionWorkMemBytes = check.GiB
+
+	topN = 10
+
+	// Query texts are cut to this many bytes in tables.
+	maxQueryLength = 60
+)
+
+const installHint = "Add pg_stat_statements to shared_preload_libraries and restart, then run CREATE EXTENSION pg_stat_statements; " +
+	"on managed services it is usually preloaded already"
+
+const spillRemediation = "Run EXPLAIN (ANALYZE, BUFFERS) on these statements: \"external merge\" sorts and hash batches above 1 " +
+	"show the operation that spills. Raise work_mem for the role or session running them rather than globally " +
+	"(see temp-usage and memory-overcommit), or avoid the sort with an index"
+
+type QuerySpillsQueries interface {
+	SpillingStatements(context.Context) ([]db.SpillingStatementsRow, error)
+}
+
+type checker struct {
+	queries       QuerySpillsQueries
+	minSpillBytes int64 // default: 1GB
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryPerformance,
+		CheckID:      "query-spills",
+		Name:         "Queries Spilling to Disk",
+		Description:  "Names the statements writing the most temporary files, from pg_stat_statements",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 13,
+		Requires: []check.Prerequisite{
+			{Kind: check.PrerequisiteExtension, Name: "pg_stat_statements", Hint: installHint},
+		},
+	}
+}
+
+func New(queries QuerySpillsQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:       queries,
+		minSpillBytes: defaultMinSpillBytes,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["min_spill"]; ok {
+				if n, err := check.ParseBytes(v); err == nil && n > 0 {
+					c.minSpillBytes = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.SpillingStatements(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "query-spills",
+			Name:     "Queries Spilling to Disk",
+			Severity: check.SeverityOK,
+			Details:  "No statement has written temporary files since pg_stat_statements was reset",
+		})
+		return report, nil
+	}
+
+	c.checkSpills(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkSpills warns on statements that wrote at least min_spill to
+// temporary files. Rows arrive sorted by bytes written.
+func (c *checker) checkSpills(ctx context.Context, rows []db.SpillingStatementsRow, report *check.Report) {
+	var total int64
+	spilling := 0
+	tableRows := make([]check.TableRow, 0, min(len(rows), topN))
+	for i, r := range rows {
+		total += r.TempBytesWritten
+		severity := check.SeverityOK
+		if r.TempBytesWritten >= c.minSpillBytes {
+			severity = check.SeverityWarn
+			spilling++
+		}
+		if i >= topN {
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				queryID(r),
+				truncateQuery(r.Query),
+				check.FormatNumber(r.Calls),
+				check.FormatSize(ctx, r.TempBytesWritten),
+				check.FormatSize(ctx, perCall(r)),
+			},
+			Severity: severity,
+		})
+	}
+
+	heaviest := rows[0]
+	metrics := []check.Metric{
+		{Name: "statement_temp_bytes", Value: float64(total), Unit: check.UnitBytes},
+		{Name: "spilling_statements", Value: float64(spilling), Unit: check.UnitCount},
+	}
+	table := &check.Table{
+		Headers: []string{"Query ID", "Query", "Calls", "Temp Written", "Per Call"},
+		Rows:    tableRows,
+	}
+
+	if spilling == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "query-spills",
+			Name:     "Queries Spilling to Disk",
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("%d statement(s) wrote temporary files, none %s or more; the heaviest wrote %s",
+				len(rows), check.FormatSize(ctx, c.minSpillBytes), check.FormatSize(ctx, heaviest.TempBytesWritten)),
+			Metrics: metrics,
+			Table:   table,
+		})
+		return
+	}
+
+	details := fmt.Sprintf("%d statement(s) wrote %s or more to temporary files; the heaviest, %s, wrote %s (%s per call, work_mem is %s)",
+		spilling, check.FormatSize(ctx, c.minSpillBytes), queryID(heaviest), check.FormatSize(ctx, heaviest.TempBytesWritten),
+		check.FormatSize(ctx, perCall(heaviest)), check.FormatSize(ctx, heaviest.WorkMemBytes))
+	// Data takes more room in memory than in a temporary file, so twice the
+	// spill per call is a first guess at the work_mem that avoids it.
+	if suggested := 2 * perCall(heaviest); suggested <= maxSessionWorkMemBytes {
+		details += fmt.Sprintf("\n\nTry SET work_mem = '%dMB' in the sessions running statement %s and compare EXPLAIN (ANALYZE, BUFFERS).",
+			max(suggested/check.MiB, 1), queryID(heaviest))
+	} else {
+		details += fmt.Sprintf("\n\nStatement %s spills more than work_mem can reasonably hold: look for a missing index or a rewrite that sorts or hashes less data.",
+			queryID(heaviest))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "query-spills",
+		Name:        "Queries Spilling to Disk",
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: spillRemediation,
+		Metrics:     metrics,
+		Table:       table,
+	})
+}
+
+func perCall(r db.SpillingStatementsRow) int64 {
+	if r.Calls <= 0 {
+		return r.TempBytesWritten
+	}
+	return r.TempBytesWritten / r.Calls
+}
+
+// queryID is the statement's pg_stat_statements queryid, hidden (0) from
+// users without pg_read_all_stats for other users' statements.
+func queryID(r db.SpillingStatementsRow) string {
+	if r.QueryID == 0 {
+		return "-"
+	}
+	return strconv.FormatInt(r.QueryID, 10)
+}
+
+func truncateQuery(query string) string {
+	if len(query) <= maxQueryLength {
+		return query
+	}
+	return strings.ToValidUTF8(query[:maxQueryLength-3], "") + "..."
+}
@@ -0,0 +1,155 @@
+package queryspills_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/queryspills"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQuerySpillsQueryer struct {
+	rows []db.SpillingStatementsRow
+	err  error
+}
+
+func (m *mockQuerySpillsQueryer) SpillingStatements(context.Context) ([]db.SpillingStatementsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func spill(id, calls, bytes int64) db.SpillingStatementsRow {
+	return db.SpillingStatementsRow{
+		QueryID:          id,
+		Query:            fmt.Sprintf("SELECT * FROM events ORDER BY created_at /* %d */", id),
+		Calls:            calls,
+		TempBytesWritten: bytes,
+		WorkMemBytes:     4 * check.MiB,
+	}
+}
+
+func Test_QuerySpills(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.SpillingStatementsRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no spills - OK",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No statement has written temporary files since pg_stat_statements was reset",
+		},
+		{
+			Name:             "small spills - OK",
+			Rows:             []db.SpillingStatementsRow{spill(11, 100, 100*check.MiB)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "1 statement(s) wrote temporary files, none 1.0GiB or more; the heaviest wrote 100.0MiB",
+		},
+		{
+			Name:             "heavy spill warns",
+			Rows:             []db.SpillingStatementsRow{spill(11, 100, 20*check.GiB), spill(12, 10, 100*check.MiB)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails: "1 statement(s) wrote 1.0GiB or more to temporary files; " +
+				"the heaviest, 11, wrote 20.0GiB (204.8MiB per call, work_mem is 4.0MiB)",
+		},
+		{
+			Name:             "suggests a session work_mem",
+			Rows:             []db.SpillingStatementsRow{spill(11, 100, 20*check.GiB)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "Try SET work_mem = '409MB' in the sessions running statement 11",
+		},
+		{
+			Name:             "spill too large for work_mem",
+			Rows:             []db.SpillingStatementsRow{spill(12, 2, 20*check.GiB)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "Statement 12 spills more than work_mem can reasonably hold",
+		},
+		{
+			Name:             "configured threshold",
+			Rows:             []db.SpillingStatementsRow{spill(11, 100, 20*check.GiB)},
+			Config:           check.Config{"query-spills": {"min_spill": "50GB"}},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "none 50.0GiB or more",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := queryspills.New(&mockQuerySpillsQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Results[0].Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_QuerySpills_Table(t *testing.T) {
+	t.Parallel()
+
+	var rows []db.SpillingStatementsRow
+	for i := range int64(12) {
+		rows = append(rows, spill(100+i, 10, (12-i)*check.GiB))
+	}
+	rows[11].QueryID = 0
+
+	report, err := queryspills.New(&mockQuerySpillsQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Contains(t, finding.Details, "12 statement(s) wrote 1.0GiB or more")
+	require.Len(t, finding.Table.Rows, 10)
+	require.Equal(t, []string{"100", "SELECT * FROM events ORDER BY created_at /* 100 */", "10", "12.0GiB", "1.2GiB"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityWarn, finding.Table.Rows[0].Severity)
+}
+
+func Test_QuerySpills_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("SpillingStatements",
+		[]string{"query_id int8", "query text", "calls int8", "temp_bytes_written int8", "total_exec_time_ms float8", "work_mem_bytes int8"},
+		[]any{9001, "SELECT * FROM orders ORDER BY total DESC", 40, 8 * check.GiB, 120000.0, 4 * check.MiB},
+	)
+
+	report, err := queryspills.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "9001", report.Results[0].Table.Rows[0].Cells[0])
+}
+
+func Test_QuerySpills_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := queryspills.New(&mockQuerySpillsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "query-spills")
+}
+
+func Test_QuerySpills_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := queryspills.Metadata()
+
+	require.Equal(t, "query-spills", metadata.CheckID)
+	require.Equal(t, check.CategoryPerformance, metadata.Category)
+	require.Equal(t, "pg_stat_statements", metadata.Requires[0].Name)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,16 @@
+-- name: SpillingStatements :many
+-- The statements of the current database that wrote the most temporary file
+-- data since pg_stat_statements was reset, with work_mem for comparison.
+SELECT
+  COALESCE(s.queryid, 0)::bigint AS query_id
+  , LEFT(REGEXP_REPLACE(s.query, '\s+', ' ', 'g'), 120)::text AS query
+  , s.calls::bigint AS calls
+  , (s.temp_blks_written * current_setting('block_size')::bigint)::bigint AS temp_bytes_written
+  , s.total_exec_time::double precision AS total_exec_time_ms
+  , (SELECT st.setting::bigint * 1024 FROM pg_settings AS st WHERE st.name = 'work_mem')::bigint AS work_mem_bytes
+FROM pg_stat_statements AS s
+WHERE
+  s.dbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+  AND s.temp_blks_written > 0
+ORDER BY s.temp_blks_written DESC
+LIMIT 20;
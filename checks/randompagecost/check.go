@@ -0,0 +1,226 @@
+// Package randompagecost implements checks for the planner's page cost settings.
+package randompagecost
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+const (
+	// random_page_cost / seq_page_cost at or above this assumes spinning disks.
+	spinningDiskRatio = 4.0
+
+	// SSD random reads cost little more than sequential ones.
+	ssdRatio = 1.1
+)
+
+// Storage kinds, from the storage config key or detected.
+const (
+	storageUnknown = ""
+	storageSSD     = "ssd"
+	storageHDD     = "hdd"
+)
+
+// hddStorageTypes are storage types backed by spinning disks; any other
+// known type (gp3, io2, pd-ssd, ...) is solid state.
+var hddStorageTypes = []string{"standard", "magnetic", "hdd", "pd-standard", "st1", "sc1"}
+
+const costRemediationNote = "Per-tablespace overrides (ALTER TABLESPACE ... SET (random_page_cost = ...)) take precedence for tables on slower or faster storage"
+
+type RandomPageCostQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries RandomPageCostQueries
+	storage string // default: detected
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "random-page-cost",
+		Name:        "Page Costs",
+		Description: "Flags random_page_cost left at the spinning-disk default on SSD storage, and inconsistent seq_page_cost",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries RandomPageCostQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries: queries,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["storage"]; ok {
+				if storage := strings.ToLower(strings.TrimSpace(v)); storage == storageSSD || storage == storageHDD {
+					c.storage = storage
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+	randomPageCost := settingFloat(settings["random_page_cost"], 4)
+	seqPageCost := settingFloat(settings["seq_page_cost"], 1)
+	provider := check.ProviderFromContext(ctx)
+
+	storage, reason := c.detectStorage(provider, check.InstanceMetadataFromContext(ctx))
+	checkRandomPageCost(randomPageCost, seqPageCost, storage, reason, provider, report)
+	checkSeqPageCost(randomPageCost, seqPageCost, provider, report)
+
+	return report, nil
+}
+
+// detectStorage tells whether the server likely runs on solid-state storage,
+// and why. The storage config key wins over instance metadata, and managed
+// services all default to SSD.
+func (c *checker) detectStorage(provider check.Provider, meta *check.InstanceMetadata) (storage, reason string) {
+	switch {
+	case c.storage == storageSSD:
+		return storageSSD, "storage configured as SSD"
+	case c.storage == storageHDD:
+		return storageHDD, "storage configured as spinning disks"
+	case meta != nil && meta.StorageType != "":
+		if slices.Contains(hddStorageTypes, strings.ToLower(meta.StorageType)) {
+			return storageHDD, fmt.Sprintf("%s storage", meta.StorageType)
+		}
+		return storageSSD, fmt.Sprintf("%s storage", meta.StorageType)
+	case provider.Managed():
+		return storageSSD, fmt.Sprintf("%s storage is SSD-backed", provider)
+	}
+	return storageUnknown, ""
+}
+
+// checkRandomPageCost flags the spinning-disk ratio of random to sequential
+// page cost on SSD storage, where it steers the planner away from indexes.
+func checkRandomPageCost(randomPageCost, seqPageCost float64, storage, reason string, provider check.Provider, report *check.Report) {
+	ratio := randomPageCost / seqPageCost
+	metrics := []check.Metric{
+		{Name: "random_page_cost", Value: randomPageCost, Unit: check.UnitCount},
+	}
+	current := fmt.Sprintf("random_page_cost = %s, seq_page_cost = %s", formatCost(randomPageCost), formatCost(seqPageCost))
+
+	switch {
+	case ratio < spinningDiskRatio:
+		report.AddFinding(check.Finding{
+			ID:       "random-page-cost",
+			Name:     "Random Page Cost",
+			Severity: check.SeverityOK,
+			Details:  current,
+			Metrics:  metrics,
+		})
+		return
+	case storage == storageUnknown:
+		report.AddFinding(check.Finding{
+			ID:       "random-page-cost",
+			Name:     "Random Page Cost",
+			Severity: check.SeverityOK,
+			Details: current + " (the spinning-disk default); storage type unknown, " +
+				"set random-page-cost.storage=ssd to check it against solid-state storage",
+			Metrics: metrics,
+		})
+		return
+	case storage == storageHDD:
+		report.AddFinding(check.Finding{
+			ID:       "random-page-cost",
+			Name:     "Random Page Cost",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%s, suited to %s", current, reason),
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	suggested := formatCost(ssdRatio * seqPageCost)
+	remediation, fixSQL := provider.SettingFix("random_page_cost", suggested)
+	report.AddFinding(check.Finding{
+		ID:       "random-page-cost",
+		Name:     "Random Page Cost",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%s on SSD storage (%s): the planner assumes random reads cost %s times sequential ones, "+
+			"as on spinning disks, and picks sequential scans over index scans that would be faster",
+			current, reason, formatCost(ratio)),
+		Object: &check.Object{Kind: check.ObjectSetting, Name: "random_page_cost"},
+		Comparison: &check.Comparison{
+			Setting:   "random_page_cost",
+			Actual:    formatCost(randomPageCost),
+			Expected:  suggested,
+			Threshold: fmt.Sprintf(">= %s × seq_page_cost on SSD", formatCost(spinningDiskRatio)),
+		},
+		Metrics:     metrics,
+		Remediation: remediation + ". " + costRemediationNote,
+		FixSQL:      fixSQL,
+	})
+}
+
+// checkSeqPageCost flags random reads priced below sequential ones, which no
+// storage justifies.
+func checkSeqPageCost(randomPageCost, seqPageCost float64, provider check.Provider, report *check.Report) {
+	if randomPageCost >= seqPageCost {
+		report.AddFinding(check.Finding{
+			ID:       "seq-page-cost",
+			Name:     "Sequential Page Cost",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("seq_page_cost = %s, not above random_page_cost", formatCost(seqPageCost)),
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.SettingFix("random_page_cost", formatCost(seqPageCost))
+	report.AddFinding(check.Finding{
+		ID:       "seq-page-cost",
+		Name:     "Sequential Page Cost",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("random_page_cost (%s) is below seq_page_cost (%s): the planner prices random reads below sequential ones "+
+			"and prefers index scans even when reading most of a table",
+			formatCost(randomPageCost), formatCost(seqPageCost)),
+		Object: &check.Object{Kind: check.ObjectSetting, Name: "random_page_cost"},
+		Comparison: &check.Comparison{
+			Setting:   "random_page_cost",
+			Actual:    formatCost(randomPageCost),
+			Expected:  fmt.Sprintf(">= %s", formatCost(seqPageCost)),
+			Threshold: "< seq_page_cost",
+		},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+	})
+}
+
+func settingFloat(row db.CatalogSettingsRow, fallback float64) float64 {
+	value, err := strconv.ParseFloat(row.Setting.String, 64)
+	if err != nil || value <= 0 {
+		return fallback
+	}
+	return value
+}
+
+// formatCost formats a cost with at most two decimals, e.g. "4", "1.1" or "0.25".
+func formatCost(cost float64) string {
+	return strconv.FormatFloat(float64(int64(cost*100+0.5))/100, 'f', -1, 64)
+}
@@ -0,0 +1,192 @@
+package randompagecost_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/randompagecost"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockRandomPageCostQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockRandomPageCostQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func costs(randomPageCost, seqPageCost string) []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{
+		{Name: pgtype.Text{String: "random_page_cost", Valid: true}, Setting: pgtype.Text{String: randomPageCost, Valid: true}},
+		{Name: pgtype.Text{String: "seq_page_cost", Valid: true}, Setting: pgtype.Text{String: seqPageCost, Valid: true}},
+	}
+}
+
+func Test_RandomPageCost(t *testing.T) {
+	t.Parallel()
+
+	gp3 := check.ContextWithInstanceMetadata(context.Background(), &check.InstanceMetadata{StorageType: "gp3"})
+	magnetic := check.ContextWithInstanceMetadata(
+		check.ContextWithProvider(context.Background(), check.ProviderRDS),
+		&check.InstanceMetadata{StorageType: "standard"},
+	)
+
+	testCases := []struct {
+		Name             string
+		Ctx              context.Context
+		RandomPageCost   string
+		SeqPageCost      string
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "tuned for SSD - OK",
+			Ctx:              check.ContextWithProvider(context.Background(), check.ProviderRDS),
+			RandomPageCost:   "1.1",
+			SeqPageCost:      "1",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "random_page_cost = 1.1, seq_page_cost = 1",
+		},
+		{
+			Name:             "default on a managed service warns",
+			Ctx:              check.ContextWithProvider(context.Background(), check.ProviderCloudSQL),
+			RandomPageCost:   "4",
+			SeqPageCost:      "1",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "random_page_cost = 4, seq_page_cost = 1 on SSD storage (Google Cloud SQL storage is SSD-backed)",
+		},
+		{
+			Name:             "default on SSD storage type warns",
+			Ctx:              gp3,
+			RandomPageCost:   "4",
+			SeqPageCost:      "1",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "(gp3 storage)",
+		},
+		{
+			Name:             "default on magnetic storage - OK",
+			Ctx:              magnetic,
+			RandomPageCost:   "4",
+			SeqPageCost:      "1",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "suited to standard storage",
+		},
+		{
+			Name:             "self-managed with unknown storage - OK",
+			Ctx:              context.Background(),
+			RandomPageCost:   "4",
+			SeqPageCost:      "1",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "storage type unknown",
+		},
+		{
+			Name:             "self-managed configured as SSD warns",
+			Ctx:              context.Background(),
+			RandomPageCost:   "4",
+			SeqPageCost:      "1",
+			Config:           check.Config{"random-page-cost": {"storage": "SSD"}},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "(storage configured as SSD)",
+		},
+		{
+			Name:             "both costs scaled keep the spinning-disk ratio",
+			Ctx:              gp3,
+			RandomPageCost:   "2",
+			SeqPageCost:      "0.5",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "random reads cost 4 times sequential ones",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := randompagecost.New(&mockRandomPageCostQueryer{settings: costs(tc.RandomPageCost, tc.SeqPageCost)}, tc.Config)
+			report, err := checker.Check(tc.Ctx)
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "random-page-cost")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_RandomPageCost_Remediation(t *testing.T) {
+	t.Parallel()
+
+	checker := randompagecost.New(&mockRandomPageCostQueryer{settings: costs("4", "0.5")}, check.Config{"random-page-cost": {"storage": "ssd"}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "random-page-cost")
+	require.Equal(t, "0.55", finding.Comparison.Expected)
+	require.Equal(t, "ALTER SYSTEM SET random_page_cost = '0.55';\nSELECT pg_reload_conf();", finding.FixSQL)
+}
+
+func Test_SeqPageCost(t *testing.T) {
+	t.Parallel()
+
+	report, err := randompagecost.New(&mockRandomPageCostQueryer{settings: costs("1.1", "1")}).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "seq-page-cost").Severity)
+
+	report, err = randompagecost.New(&mockRandomPageCostQueryer{settings: costs("1", "2")}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "seq-page-cost")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Contains(t, finding.Details, "random_page_cost (1) is below seq_page_cost (2)")
+	require.Equal(t, "ALTER SYSTEM SET random_page_cost = '2';\nSELECT pg_reload_conf();", finding.FixSQL)
+}
+
+func Test_RandomPageCost_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"random_page_cost", "4", nil, "default", "4", "4", "user", "real", "f"},
+		[]any{"seq_page_cost", "1", nil, "default", "1", "1", "user", "real", "f"},
+	)
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderAzure)
+	report, err := randompagecost.New(db.New(fx)).Check(ctx)
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Contains(t, checktest.Finding(t, report, "random-page-cost").Remediation, "Set the random_page_cost server parameter to 1.1")
+}
+
+func Test_RandomPageCost_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := randompagecost.New(&mockRandomPageCostQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "random-page-cost")
+}
+
+func Test_RandomPageCost_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := randompagecost.Metadata()
+
+	require.Equal(t, "random-page-cost", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
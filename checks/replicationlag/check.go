@@ -62,6 +62,7 @@ func Metadata() check.Metadata {
 		Description: "Monitors active replication streams for lag issues",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -109,17 +110,17 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	}
 
 	if len(physicalRows) > 0 {
-		checkPhysicalReplicationLag(physicalRows, report)
+		checkPhysicalReplicationLag(ctx, physicalRows, report)
 	}
 
 	if len(logicalRows) > 0 {
-		checkLogicalReplicationLag(logicalRows, report)
+		checkLogicalReplicationLag(ctx, logicalRows, report)
 	}
 
 	return report, nil
 }
 
-func checkPhysicalReplicationLag(rows []db.ReplicationLagRow, report *check.Report) {
+func checkPhysicalReplicationLag(ctx context.Context, rows []db.ReplicationLagRow, report *check.Report) {
 	var laggingRows []db.ReplicationLagRow
 	maxSeverity := check.SeverityOK
 
@@ -165,7 +166,7 @@ func checkPhysicalReplicationLag(rows []db.ReplicationLagRow, report *check.Repo
 				row.ApplicationName.String,
 				row.State.String,
 				fmt.Sprintf("%.2fs", lagSeconds),
-				check.FormatBytes(row.ReplayLagBytes.Int64),
+				check.FormatSize(ctx, row.ReplayLagBytes.Int64),
 				slotName,
 			},
 			Severity: severity,
@@ -225,7 +226,7 @@ func logicalRelativeSeverity(bytes, capBytes int64) check.Severity {
 	}
 }
 
-func checkLogicalReplicationLag(rows []db.ReplicationLagRow, report *check.Report) {
+func checkLogicalReplicationLag(ctx context.Context, rows []db.ReplicationLagRow, report *check.Report) {
 	var laggingRows []db.ReplicationLagRow
 	maxSeverity := check.SeverityOK
 
@@ -265,7 +266,7 @@ func checkLogicalReplicationLag(rows []db.ReplicationLagRow, report *check.Repor
 				row.ApplicationName.String,
 				row.State.String,
 				fmt.Sprintf("%.2fs", row.ReplayLagSeconds.Float64),
-				check.FormatBytes(row.ReplayLagBytes.Int64),
+				check.FormatSize(ctx, row.ReplayLagBytes.Int64),
 				slotName,
 			},
 			Severity: severity,
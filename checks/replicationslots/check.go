@@ -5,6 +5,7 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"strconv"
 	"strings"
 
 	"github.com/emancu/pgdoctor/check"
@@ -18,10 +19,15 @@ var querySQL string
 var readme string
 
 const (
-	lagWarnThreshold = 1 * check.GiB
-	lagFailThreshold = 5 * check.GiB
+	defaultLagWarnGB = 1
+	defaultLagFailGB = 5
 )
 
+// unlimitedRetentionRemediation trades one failure for another on purpose: an
+// invalidated slot needs its consumer re-synced, a full disk stops the server.
+const unlimitedRetentionRemediation = "Set max_slot_wal_keep_size below the free space of the WAL disk, and above the lag consumers normally reach. " +
+	"Slots that exceed it are invalidated and their consumer must be re-synced, instead of the primary running out of disk"
+
 type ReplicationSlotsQueries interface {
 	ReplicationSlots(context.Context) ([]db.ReplicationSlotsRow, error)
 	ReplicationSlotsPG15(context.Context) ([]db.ReplicationSlotsPG15Row, error)
@@ -29,6 +35,8 @@ type ReplicationSlotsQueries interface {
 
 type checker struct {
 	queryer ReplicationSlotsQueries
+	lagWarn int64 // default: 1GB
+	lagFail int64 // default: 5GB
 }
 
 func Metadata() check.Metadata {
@@ -39,11 +47,33 @@ func Metadata() check.Metadata {
 		Description: "Validates replication slot configuration and health status",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
-func New(queryer ReplicationSlotsQueries, _ ...check.Config) check.Checker {
-	return &checker{queryer: queryer}
+func New(queryer ReplicationSlotsQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queryer: queryer,
+		lagWarn: defaultLagWarnGB * check.GiB,
+		lagFail: defaultLagFailGB * check.GiB,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["lag_warn_gb"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.lagWarn = n * check.GiB
+				}
+			}
+			if v, ok := myCfg["lag_fail_gb"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.lagFail = n * check.GiB
+				}
+			}
+		}
+	}
+
+	return c
 }
 
 func (c *checker) Metadata() check.Metadata {
@@ -62,6 +92,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	var inactiveSlots []db.ReplicationSlotsRow
 	var conflictingSlots []db.ReplicationSlotsRow
 	var lostWALSlots []db.ReplicationSlotsRow
+	var unreservedWALSlots []db.ReplicationSlotsRow
 	var criticalLagSlots []db.ReplicationSlotsRow
 	var highLagSlots []db.ReplicationSlotsRow
 
@@ -78,11 +109,17 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 			continue
 		}
 
-		if slot.WalStatus.Valid && (slot.WalStatus.String == "lost" || slot.WalStatus.String == "unreserved") {
+		if slot.WalStatus.Valid && slot.WalStatus.String == "lost" {
 			lostWALSlots = append(lostWALSlots, slot)
 			continue
 		}
 
+		// Unreserved slots still have their WAL, but the next checkpoint removes it.
+		if slot.WalStatus.Valid && slot.WalStatus.String == "unreserved" {
+			unreservedWALSlots = append(unreservedWALSlots, slot)
+			continue
+		}
+
 		if !slot.Active.Bool {
 			inactiveSlots = append(inactiveSlots, slot)
 			continue
@@ -90,9 +127,9 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 
 		if slot.RestartLsnLagBytes.Valid {
 			lag := slot.RestartLsnLagBytes.Int64
-			if lag >= lagFailThreshold {
+			if lag >= c.lagFail {
 				criticalLagSlots = append(criticalLagSlots, slot)
-			} else if lag >= lagWarnThreshold {
+			} else if lag >= c.lagWarn {
 				highLagSlots = append(highLagSlots, slot)
 			}
 		}
@@ -100,10 +137,12 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 
 	reportInvalidSlots(report, invalidSlots)
 	reportLostWALSlots(report, lostWALSlots)
+	reportUnreservedWALSlots(report, unreservedWALSlots)
 	reportConflictingSlots(report, conflictingSlots)
-	reportInactiveSlots(report, inactiveSlots)
-	reportCriticalLagSlots(report, criticalLagSlots)
-	reportHighLagSlots(report, highLagSlots)
+	reportInactiveSlots(ctx, report, inactiveSlots, c.lagFail)
+	reportCriticalLagSlots(ctx, report, criticalLagSlots, c.lagFail)
+	reportHighLagSlots(ctx, report, highLagSlots, c.lagWarn)
+	reportUnlimitedRetention(report, slots)
 
 	// If no issues found
 	if len(report.Results) == 0 {
@@ -120,11 +159,9 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 
 // Retrieves replication slots using the appropriate query for the PG version.
 // PG17+ query has inactive_since, conflicting, invalidation_reason.
-// Falls back to PG15 query for older versions (returns NULLs for those columns).
+// Falls back to PG15 query for older or unknown versions (returns NULLs for those columns).
 func (c *checker) fetchSlots(ctx context.Context) ([]db.ReplicationSlotsRow, error) {
-	meta := check.InstanceMetadataFromContext(ctx)
-
-	if meta == nil || meta.EngineVersionMajor < 17 {
+	if check.ServerVersionFromContext(ctx) < 170000 {
 		pg15Slots, err := c.queryer.ReplicationSlotsPG15(ctx)
 		if err != nil {
 			return nil, err
@@ -193,20 +230,26 @@ func reportConflictingSlots(report *check.Report, slots []db.ReplicationSlotsRow
 	})
 }
 
-func reportInactiveSlots(report *check.Report, slots []db.ReplicationSlotsRow) {
+// reportInactiveSlots fails when an inactive slot already retains as much WAL
+// as the critical lag threshold: nothing will consume it.
+func reportInactiveSlots(ctx context.Context, report *check.Report, slots []db.ReplicationSlotsRow, lagFail int64) {
 	if len(slots) == 0 {
 		return
 	}
 
+	severity := check.SeverityWarn
 	lines := make([]string, 0, len(slots))
 	for _, slot := range slots {
+		if slot.RestartLsnLagBytes.Int64 >= lagFail {
+			severity = check.SeverityFail
+		}
 		inactiveFor := "unknown"
 		if slot.InactiveSeconds.Valid {
 			inactiveFor = check.FormatDurationSec(slot.InactiveSeconds.Int64)
 		}
 		lagBytes := "unknown"
 		if slot.RestartLsnLagBytes.Valid {
-			lagBytes = check.FormatBytes(slot.RestartLsnLagBytes.Int64)
+			lagBytes = check.FormatSize(ctx, slot.RestartLsnLagBytes.Int64)
 		}
 		lines = append(lines, fmt.Sprintf("  %s (inactive: %s, lag: %s)", slot.SlotName.String, inactiveFor, lagBytes))
 	}
@@ -214,43 +257,69 @@ func reportInactiveSlots(report *check.Report, slots []db.ReplicationSlotsRow) {
 	report.AddFinding(check.Finding{
 		ID:       "inactive-slots",
 		Name:     "Inactive Replication Slots",
-		Severity: check.SeverityWarn,
+		Severity: severity,
 		Details:  fmt.Sprintf("Found %d inactive slot(s):\n%s\n\nInactive slots prevent WAL cleanup and can fill disk.", len(slots), strings.Join(lines, "\n")),
 	})
 }
 
-func reportCriticalLagSlots(report *check.Report, slots []db.ReplicationSlotsRow) {
+func reportCriticalLagSlots(ctx context.Context, report *check.Report, slots []db.ReplicationSlotsRow, threshold int64) {
 	if len(slots) == 0 {
 		return
 	}
 
 	lines := make([]string, 0, len(slots))
 	for _, slot := range slots {
-		lines = append(lines, fmt.Sprintf("  %s (lag: %s)", slot.SlotName.String, check.FormatBytes(slot.RestartLsnLagBytes.Int64)))
+		lines = append(lines, fmt.Sprintf("  %s (lag: %s)", slot.SlotName.String, check.FormatSize(ctx, slot.RestartLsnLagBytes.Int64)))
 	}
 
 	report.AddFinding(check.Finding{
 		ID:       "critical-lag",
 		Name:     "Critical Replication Lag",
 		Severity: check.SeverityFail,
-		Details:  fmt.Sprintf("Found %d slot(s) with critical lag (>= 5GB):\n%s\n\nConsumers are severely behind and may never catch up.", len(slots), strings.Join(lines, "\n")),
+		Details:  fmt.Sprintf("Found %d slot(s) with critical lag (>= %s):\n%s\n\nConsumers are severely behind and may never catch up.", len(slots), formatGB(threshold), strings.Join(lines, "\n")),
 	})
 }
 
-func reportHighLagSlots(report *check.Report, slots []db.ReplicationSlotsRow) {
+func reportHighLagSlots(ctx context.Context, report *check.Report, slots []db.ReplicationSlotsRow, threshold int64) {
 	if len(slots) == 0 {
 		return
 	}
 
 	lines := make([]string, 0, len(slots))
 	for _, slot := range slots {
-		lines = append(lines, fmt.Sprintf("  %s (lag: %s)", slot.SlotName.String, check.FormatBytes(slot.RestartLsnLagBytes.Int64)))
+		lines = append(lines, fmt.Sprintf("  %s (lag: %s)", slot.SlotName.String, check.FormatSize(ctx, slot.RestartLsnLagBytes.Int64)))
 	}
 
 	report.AddFinding(check.Finding{
 		ID:       "high-lag",
 		Name:     "High Replication Lag",
 		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("Found %d slot(s) with high lag (>= 1GB):\n%s\n\nConsumers are falling behind.", len(slots), strings.Join(lines, "\n")),
+		Details:  fmt.Sprintf("Found %d slot(s) with high lag (>= %s):\n%s\n\nConsumers are falling behind.", len(slots), formatGB(threshold), strings.Join(lines, "\n")),
 	})
 }
+
+// reportUnlimitedRetention warns when nothing caps the WAL slots retain, so a
+// dead consumer fills the disk instead of losing its slot.
+func reportUnlimitedRetention(report *check.Report, slots []db.ReplicationSlotsRow) {
+	if len(slots) == 0 || slots[0].MaxSlotWalKeepSizeMb >= 0 {
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "unlimited-wal-retention",
+		Name:        "Unlimited WAL Retention",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("max_slot_wal_keep_size is -1 (unlimited): %d slot(s) can retain WAL until the disk fills if their consumer stops", len(slots)),
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "max_slot_wal_keep_size"},
+		Remediation: unlimitedRetentionRemediation,
+		Comparison: &check.Comparison{
+			Setting:  "max_slot_wal_keep_size",
+			Actual:   "-1",
+			Expected: "below the free space of the WAL disk",
+		},
+	})
+}
+
+func formatGB(bytes int64) string {
+	return fmt.Sprintf("%dGB", bytes/check.GiB)
+}
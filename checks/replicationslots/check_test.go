@@ -203,11 +203,12 @@ func TestCheck_LostWALSlots(t *testing.T) {
 	t.Parallel()
 
 	tests := []struct {
-		name      string
-		walStatus string
+		name       string
+		walStatus  string
+		expectedID string
 	}{
-		{"lost WAL", "lost"},
-		{"unreserved WAL", "unreserved"},
+		{"lost WAL", "lost", "lost-wal-slots"},
+		{"unreserved WAL", "unreserved", "unreserved-wal-slots"},
 	}
 
 	for _, tt := range tests {
@@ -226,12 +227,31 @@ func TestCheck_LostWALSlots(t *testing.T) {
 
 			require.Len(t, report.Results, 1)
 			assert.Equal(t, check.SeverityFail, report.Severity)
-			assert.Equal(t, "lost-wal-slots", report.Results[0].ID)
+			assert.Equal(t, tt.expectedID, report.Results[0].ID)
 			assert.Contains(t, report.Results[0].Details, tt.walStatus)
 		})
 	}
 }
 
+func TestCheck_UnreservedWALSlots(t *testing.T) {
+	t.Parallel()
+
+	slot := lostWALSlot("cdc", "unreserved")
+	slot.SafeWalSize = pgInt8(-2 * check.GiB)
+	slot.MaxSlotWalKeepSizeMb = 10 * 1024
+
+	queryer := &mockQueryer{
+		pg15Slots: []db.ReplicationSlotsPG15Row{db.ReplicationSlotsPG15Row(slot)},
+	}
+	report, err := replicationslots.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	assert.Equal(t, check.SeverityFail, report.Severity)
+	assert.Contains(t, report.Results[0].Details, "cdc (wal_status: unreserved, 2.0GiB over max_slot_wal_keep_size)")
+	assert.NotEmpty(t, report.Results[0].Remediation)
+}
+
 func TestCheck_InactiveSlots(t *testing.T) {
 	t.Parallel()
 
@@ -325,9 +345,7 @@ func TestCheck_QuerySelection_PG17(t *testing.T) {
 	}
 	checker := replicationslots.New(queryer)
 
-	// Create context with PG17 metadata
-	meta := &check.InstanceMetadata{EngineVersion: "17.0", EngineVersionMajor: 17, EngineVersionMinor: 0}
-	ctx := check.ContextWithInstanceMetadata(context.Background(), meta)
+	ctx := check.ContextWithServerVersion(context.Background(), 170000)
 
 	_, err := checker.Check(ctx)
 	require.NoError(t, err)
@@ -345,9 +363,7 @@ func TestCheck_QuerySelection_PG15(t *testing.T) {
 	}
 	checker := replicationslots.New(queryer)
 
-	// Create context with PG15 metadata
-	meta := &check.InstanceMetadata{EngineVersion: "15.4", EngineVersionMajor: 15, EngineVersionMinor: 4}
-	ctx := check.ContextWithInstanceMetadata(context.Background(), meta)
+	ctx := check.ContextWithServerVersion(context.Background(), 150004)
 
 	_, err := checker.Check(ctx)
 	require.NoError(t, err)
@@ -365,9 +381,7 @@ func TestCheck_QuerySelection_PG16(t *testing.T) {
 	}
 	checker := replicationslots.New(queryer)
 
-	// Create context with PG16 metadata
-	meta := &check.InstanceMetadata{EngineVersion: "16.2", EngineVersionMajor: 16, EngineVersionMinor: 2}
-	ctx := check.ContextWithInstanceMetadata(context.Background(), meta)
+	ctx := check.ContextWithServerVersion(context.Background(), 160002)
 
 	_, err := checker.Check(ctx)
 	require.NoError(t, err)
@@ -376,7 +390,7 @@ func TestCheck_QuerySelection_PG16(t *testing.T) {
 	assert.True(t, queryer.pg15Called, "Should use PG15 query for version 16")
 }
 
-func TestCheck_QuerySelection_NoMetadata(t *testing.T) {
+func TestCheck_QuerySelection_UnknownVersion(t *testing.T) {
 	t.Parallel()
 
 	queryer := &mockQueryer{
@@ -385,12 +399,12 @@ func TestCheck_QuerySelection_NoMetadata(t *testing.T) {
 	}
 	checker := replicationslots.New(queryer)
 
-	// No instance metadata in context - should fall back to PG15 query
+	// No server version in context - should fall back to PG15 query
 	_, err := checker.Check(context.Background())
 	require.NoError(t, err)
 
-	assert.False(t, queryer.pg17Called, "Should not use PG17 query when metadata is missing")
-	assert.True(t, queryer.pg15Called, "Should fall back to PG15 query when metadata is missing")
+	assert.False(t, queryer.pg17Called, "Should not use PG17 query when the version is unknown")
+	assert.True(t, queryer.pg15Called, "Should fall back to PG15 query when the version is unknown")
 }
 
 func TestCheck_QueryError(t *testing.T) {
@@ -598,6 +612,87 @@ func TestCheck_LagThresholdBoundary(t *testing.T) {
 	}
 }
 
+func TestCheck_LagThresholdsConfig(t *testing.T) {
+	t.Parallel()
+
+	slot := healthySlot("slot")
+	slot.RestartLsnLagBytes = pgInt8(12 * 1024 * 1024 * 1024) // 12GB
+
+	queryer := &mockQueryer{
+		pg15Slots: []db.ReplicationSlotsPG15Row{db.ReplicationSlotsPG15Row(slot)},
+	}
+	checker := replicationslots.New(queryer, check.Config{
+		"replication-slots": {"lag_warn_gb": "10", "lag_fail_gb": "50"},
+	})
+
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	assert.Equal(t, check.SeverityWarn, report.Severity)
+	assert.Equal(t, "high-lag", report.Results[0].ID)
+	assert.Contains(t, report.Results[0].Details, "high lag (>= 10GB)")
+}
+
+func TestCheck_InactiveSlotRetainingWAL(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{
+		pg15Slots: []db.ReplicationSlotsPG15Row{
+			db.ReplicationSlotsPG15Row(inactiveSlot("forgotten", 86400, 6*1024*1024*1024)),
+			db.ReplicationSlotsPG15Row(inactiveSlot("idle", 60, 1024)),
+		},
+	}
+	checker := replicationslots.New(queryer)
+
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	assert.Equal(t, check.SeverityFail, report.Severity)
+	assert.Equal(t, "inactive-slots", report.Results[0].ID)
+	assert.Contains(t, report.Results[0].Details, "forgotten (inactive: 1d, lag: 6.0GiB)")
+}
+
+func TestCheck_UnlimitedWALRetention(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		keepSizeMB int64
+		expectedID string
+		severity   check.Severity
+	}{
+		{name: "unlimited", keepSizeMB: -1, expectedID: "unlimited-wal-retention", severity: check.SeverityWarn},
+		{name: "limited", keepSizeMB: 100 * 1024, expectedID: "replication-slots", severity: check.SeverityOK},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			slot := healthySlot("slot1")
+			slot.MaxSlotWalKeepSizeMb = tt.keepSizeMB
+
+			queryer := &mockQueryer{
+				pg15Slots: []db.ReplicationSlotsPG15Row{db.ReplicationSlotsPG15Row(slot)},
+			}
+			checker := replicationslots.New(queryer)
+
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			assert.Equal(t, tt.severity, report.Severity)
+			assert.Equal(t, tt.expectedID, report.Results[0].ID)
+			if tt.severity == check.SeverityWarn {
+				require.NotNil(t, report.Results[0].Comparison)
+				assert.Equal(t, "-1", report.Results[0].Comparison.Actual)
+			}
+		})
+	}
+}
+
 func TestCheck_FormatDuration(t *testing.T) {
 	t.Parallel()
 
@@ -18,6 +18,7 @@ SELECT
     WHEN active THEN NULL
     ELSE EXTRACT(EPOCH FROM (NOW() - inactive_since))::BIGINT
   END AS inactive_seconds
+  , COALESCE((SELECT setting::BIGINT FROM pg_settings WHERE name = 'max_slot_wal_keep_size'), -1) AS max_slot_wal_keep_size_mb
 
 FROM pg_replication_slots
 ORDER BY
@@ -46,6 +47,7 @@ SELECT
   , PG_WAL_LSN_DIFF(PG_CURRENT_WAL_LSN(), restart_lsn)::BIGINT AS restart_lsn_lag_bytes
   , PG_WAL_LSN_DIFF(PG_CURRENT_WAL_LSN(), confirmed_flush_lsn)::BIGINT AS confirmed_flush_lsn_lag_bytes
   , NULL::BIGINT AS inactive_seconds
+  , COALESCE((SELECT setting::BIGINT FROM pg_settings WHERE name = 'max_slot_wal_keep_size'), -1) AS max_slot_wal_keep_size_mb
 
 FROM pg_replication_slots
 ORDER BY
@@ -0,0 +1,320 @@
+// Package roleprivileges implements checks for role privilege sprawl:
+// superusers, login roles with SUPERUSER, CREATEROLE or BYPASSRLS, login
+// roles without a connection limit and login roles without a password.
+package roleprivileges
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// providerRoles are created and used by managed services to operate the
+// instance; they are not the customer's to change.
+var providerRoles = map[string]bool{
+	"rdsadmin":        true,
+	"rdsrepladmin":    true,
+	"rdstopmgr":       true,
+	"cloudsqladmin":   true,
+	"cloudsqlagent":   true,
+	"cloudsqlreplica": true,
+	"azure_superuser": true,
+	"azuresu":         true,
+}
+
+const elevatedRemediation = "Remove the attributes from login roles and grant the predefined roles " +
+	"(pg_monitor, pg_read_all_data, pg_signal_backend, ...) or object privileges they need instead"
+
+type RolePrivilegesQueries interface {
+	RoleAttributes(context.Context) ([]db.RoleAttributesRow, error)
+	CanReadAuthid(context.Context) (bool, error)
+	PasswordlessLoginRoles(context.Context) ([]db.PasswordlessLoginRolesRow, error)
+}
+
+type checker struct {
+	queries RolePrivilegesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySecurity,
+		CheckID:     "role-privileges",
+		Name:        "Role Privileges",
+		Description: "Counts superusers and flags login roles with SUPERUSER, CREATEROLE or BYPASSRLS, without a connection limit, or without a password",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries RolePrivilegesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	roles, err := c.queries.RoleAttributes(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	canReadAuthid, err := c.queries.CanReadAuthid(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	roles = customerRoles(roles)
+	checkSuperusers(roles, report)
+	checkElevatedRoles(roles, report)
+	checkConnectionLimits(roles, report)
+
+	if !canReadAuthid {
+		details := "Reading stored passwords requires superuser (SELECT on pg_authid)"
+		if provider := check.ProviderFromContext(ctx); provider.Managed() {
+			details = fmt.Sprintf("Stored passwords are not readable on %s (pg_authid requires superuser)", provider)
+		}
+		report.AddFinding(check.Finding{
+			ID:       "passwordless-login-roles",
+			Name:     "Login Roles Without Password",
+			Severity: check.SeveritySkip,
+			Details:  details,
+		})
+		return report, nil
+	}
+
+	passwordless, err := c.queries.PasswordlessLoginRoles(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	checkPasswordless(passwordless, report)
+
+	return report, nil
+}
+
+// customerRoles drops the roles managed services use to operate the instance.
+func customerRoles(roles []db.RoleAttributesRow) []db.RoleAttributesRow {
+	filtered := make([]db.RoleAttributesRow, 0, len(roles))
+	for _, role := range roles {
+		if !providerRoles[role.RoleName] {
+			filtered = append(filtered, role)
+		}
+	}
+	return filtered
+}
+
+// checkSuperusers counts superuser roles. The bootstrap superuser is expected;
+// every other one bypasses all permission checks, row-level security and
+// most of the audit trail.
+func checkSuperusers(roles []db.RoleAttributesRow, report *check.Report) {
+	severity := check.SeverityOK
+	var tableRows []check.TableRow
+	for _, role := range roles {
+		if !role.Superuser {
+			continue
+		}
+		rowSeverity := check.SeverityOK
+		if !role.IsBootstrap {
+			rowSeverity = check.SeverityWarn
+		}
+		severity = max(severity, rowSeverity)
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName, yesNo(role.CanLogin), yesNo(role.IsBootstrap)},
+			Severity: rowSeverity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       "superusers",
+		Name:     "Superuser Roles",
+		Severity: severity,
+		Details:  fmt.Sprintf("%d superuser role(s)", len(tableRows)),
+		Metrics: []check.Metric{
+			{Name: "superuser_roles", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	}
+	if len(tableRows) > 0 {
+		finding.Table = &check.Table{
+			Headers: []string{"Role", "Can Login", "Bootstrap"},
+			Rows:    tableRows,
+		}
+	}
+	if severity == check.SeverityWarn {
+		finding.Details += ": superusers other than the bootstrap one bypass every permission check and row-level security policy"
+		finding.Remediation = elevatedRemediation
+	}
+	report.AddFinding(finding)
+}
+
+// checkElevatedRoles flags login roles other than the bootstrap superuser
+// holding an attribute that escapes ordinary privileges. CREATEROLE can grant
+// itself membership in any non-superuser role before PostgreSQL 16.
+func checkElevatedRoles(roles []db.RoleAttributesRow, report *check.Report) {
+	var tableRows []check.TableRow
+	var fixes []string
+	for _, role := range roles {
+		if !role.CanLogin || role.IsBootstrap {
+			continue
+		}
+		var attributes []string
+		if role.Superuser {
+			attributes = append(attributes, "SUPERUSER")
+		}
+		if role.CreateRole {
+			attributes = append(attributes, "CREATEROLE")
+		}
+		if role.BypassRls {
+			attributes = append(attributes, "BYPASSRLS")
+		}
+		if len(attributes) == 0 {
+			continue
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName, strings.Join(attributes, ", ")},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, fmt.Sprintf("ALTER ROLE %s NO%s;",
+			pgx.Identifier{role.RoleName}.Sanitize(), strings.Join(attributes, " NO")))
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "elevated-login-roles",
+			Name:     "Elevated Login Roles",
+			Severity: check.SeverityOK,
+			Details:  "No login role other than the bootstrap superuser has SUPERUSER, CREATEROLE or BYPASSRLS",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "elevated-login-roles",
+		Name:     "Elevated Login Roles",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d login role(s) have SUPERUSER, CREATEROLE or BYPASSRLS: "+
+			"an application or person connecting as them can read or change data beyond their own", len(tableRows)),
+		Remediation: elevatedRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Role", "Attributes"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "elevated_login_roles", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// checkConnectionLimits flags non-superuser login roles without a connection
+// limit. With a single such role there is nothing to protect; with several,
+// any of them can take every connection slot from the others.
+func checkConnectionLimits(roles []db.RoleAttributesRow, report *check.Report) {
+	var tableRows []check.TableRow
+	for _, role := range roles {
+		if !role.CanLogin || role.Superuser || role.ConnectionLimit != -1 {
+			continue
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) < 2 {
+		report.AddFinding(check.Finding{
+			ID:       "connection-limits",
+			Name:     "Role Connection Limits",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%d non-superuser login role(s) without a connection limit", len(tableRows)),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "connection-limits",
+		Name:     "Role Connection Limits",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d login roles have no connection limit: a connection leak or retry storm in one "+
+			"application can use every slot up to max_connections and lock the others out", len(tableRows)),
+		Remediation: "Set ALTER ROLE ... CONNECTION LIMIT on each application role to what its pool needs, with some headroom",
+		Table: &check.Table{
+			Headers: []string{"Role"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "unlimited_login_roles", Value: float64(len(tableRows)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// checkPasswordless lists login roles without a password. They are only safe
+// if pg_hba.conf never lets them in through trust. The bootstrap superuser
+// commonly has none and connects through peer authentication.
+func checkPasswordless(roles []db.PasswordlessLoginRolesRow, report *check.Report) {
+	severity := check.SeverityOK
+	tableRows := make([]check.TableRow, 0, len(roles))
+	for _, role := range roles {
+		if providerRoles[role.RoleName] {
+			continue
+		}
+		rowSeverity := check.SeverityWarn
+		if role.IsBootstrap {
+			rowSeverity = check.SeverityOK
+		}
+		severity = max(severity, rowSeverity)
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{role.RoleName, yesNo(role.Superuser)},
+			Severity: rowSeverity,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "passwordless-login-roles",
+			Name:     "Login Roles Without Password",
+			Severity: check.SeverityOK,
+			Details:  "Every login role has a password",
+		})
+		return
+	}
+
+	finding := check.Finding{
+		ID:       "passwordless-login-roles",
+		Name:     "Login Roles Without Password",
+		Severity: severity,
+		Details: fmt.Sprintf("%d login role(s) have no password and can only connect through an authentication method "+
+			"that does not check one (trust, peer, cert, ...)", len(tableRows)),
+		Table: &check.Table{
+			Headers: []string{"Role", "Superuser"},
+			Rows:    tableRows,
+		},
+	}
+	if severity == check.SeverityWarn {
+		finding.Remediation = "Set a password on roles that authenticate with one, or confirm pg_hba.conf admits them " +
+			"only through cert, peer or GSSAPI and never through trust"
+	}
+	report.AddFinding(finding)
+}
+
+func yesNo(b bool) string {
+	if b {
+		return "yes"
+	}
+	return "no"
+}
@@ -0,0 +1,273 @@
+package roleprivileges_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/roleprivileges"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockRolePrivilegesQueryer struct {
+	roles         []db.RoleAttributesRow
+	canReadAuthid bool
+	passwordless  []db.PasswordlessLoginRolesRow
+	authidCalled  bool
+	err           error
+}
+
+func (m *mockRolePrivilegesQueryer) RoleAttributes(context.Context) ([]db.RoleAttributesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.roles, nil
+}
+
+func (m *mockRolePrivilegesQueryer) CanReadAuthid(context.Context) (bool, error) {
+	return m.canReadAuthid, m.err
+}
+
+func (m *mockRolePrivilegesQueryer) PasswordlessLoginRoles(context.Context) ([]db.PasswordlessLoginRolesRow, error) {
+	m.authidCalled = true
+	return m.passwordless, m.err
+}
+
+var bootstrap = db.RoleAttributesRow{
+	RoleName:        "postgres",
+	IsBootstrap:     true,
+	Superuser:       true,
+	CreateRole:      true,
+	BypassRls:       true,
+	CanLogin:        true,
+	ConnectionLimit: -1,
+}
+
+func appRole(name string) db.RoleAttributesRow {
+	return db.RoleAttributesRow{RoleName: name, CanLogin: true, ConnectionLimit: 50}
+}
+
+func Test_RolePrivileges(t *testing.T) {
+	t.Parallel()
+
+	unlimited := func(name string) db.RoleAttributesRow {
+		role := appRole(name)
+		role.ConnectionLimit = -1
+		return role
+	}
+	superuser := appRole("admin")
+	superuser.Superuser = true
+	bypass := appRole("etl")
+	bypass.BypassRls = true
+	creator := db.RoleAttributesRow{RoleName: "role_admins", CreateRole: true, ConnectionLimit: -1}
+	rdsadmin := db.RoleAttributesRow{RoleName: "rdsadmin", Superuser: true, CanLogin: true, ConnectionLimit: -1}
+
+	tests := []struct {
+		name        string
+		roles       []db.RoleAttributesRow
+		superusers  check.Severity
+		elevated    check.Severity
+		connections check.Severity
+	}{
+		{
+			name:        "bootstrap superuser and a limited application role",
+			roles:       []db.RoleAttributesRow{bootstrap, appRole("app")},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityOK,
+			connections: check.SeverityOK,
+		},
+		{
+			name:        "second login superuser",
+			roles:       []db.RoleAttributesRow{bootstrap, superuser},
+			superusers:  check.SeverityWarn,
+			elevated:    check.SeverityWarn,
+			connections: check.SeverityOK,
+		},
+		{
+			name:        "BYPASSRLS application role",
+			roles:       []db.RoleAttributesRow{bootstrap, bypass},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityWarn,
+			connections: check.SeverityOK,
+		},
+		{
+			name:        "CREATEROLE group role without login",
+			roles:       []db.RoleAttributesRow{bootstrap, creator},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityOK,
+			connections: check.SeverityOK,
+		},
+		{
+			name:        "single unlimited application role",
+			roles:       []db.RoleAttributesRow{bootstrap, unlimited("app")},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityOK,
+			connections: check.SeverityOK,
+		},
+		{
+			name:        "several unlimited application roles",
+			roles:       []db.RoleAttributesRow{bootstrap, unlimited("api"), unlimited("worker")},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityOK,
+			connections: check.SeverityWarn,
+		},
+		{
+			name:        "managed service admin role",
+			roles:       []db.RoleAttributesRow{rdsadmin, appRole("app")},
+			superusers:  check.SeverityOK,
+			elevated:    check.SeverityOK,
+			connections: check.SeverityOK,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := roleprivileges.New(&mockRolePrivilegesQueryer{roles: tt.roles}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 4)
+			require.Equal(t, tt.superusers, checktest.Finding(t, report, "superusers").Severity)
+			require.Equal(t, tt.elevated, checktest.Finding(t, report, "elevated-login-roles").Severity)
+			require.Equal(t, tt.connections, checktest.Finding(t, report, "connection-limits").Severity)
+		})
+	}
+}
+
+func Test_RolePrivileges_ElevatedFixSQL(t *testing.T) {
+	t.Parallel()
+
+	admin := appRole("Admin")
+	admin.Superuser = true
+	admin.CreateRole = true
+	etl := appRole("etl")
+	etl.BypassRls = true
+
+	report, err := roleprivileges.New(&mockRolePrivilegesQueryer{roles: []db.RoleAttributesRow{bootstrap, admin, etl}}).
+		Check(context.Background())
+	require.NoError(t, err)
+
+	superusers := checktest.Finding(t, report, "superusers")
+	require.Contains(t, superusers.Details, "2 superuser role(s)")
+	require.Equal(t, []string{"postgres", "yes", "yes"}, superusers.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityOK, superusers.Table.Rows[0].Severity)
+
+	elevated := checktest.Finding(t, report, "elevated-login-roles")
+	require.Equal(t, []string{"Admin", "SUPERUSER, CREATEROLE"}, elevated.Table.Rows[0].Cells)
+	require.Equal(t, "ALTER ROLE \"Admin\" NOSUPERUSER NOCREATEROLE;\nALTER ROLE \"etl\" NOBYPASSRLS;", elevated.FixSQL)
+}
+
+func Test_RolePrivileges_Passwordless(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name          string
+		canReadAuthid bool
+		passwordless  []db.PasswordlessLoginRolesRow
+		expected      check.Severity
+	}{
+		{
+			name:          "every role has a password",
+			canReadAuthid: true,
+			expected:      check.SeverityOK,
+		},
+		{
+			name:          "bootstrap superuser using peer authentication",
+			canReadAuthid: true,
+			passwordless:  []db.PasswordlessLoginRolesRow{{RoleName: "postgres", IsBootstrap: true, Superuser: true}},
+			expected:      check.SeverityOK,
+		},
+		{
+			name:          "application role without password",
+			canReadAuthid: true,
+			passwordless:  []db.PasswordlessLoginRolesRow{{RoleName: "app"}},
+			expected:      check.SeverityWarn,
+		},
+		{
+			name:     "pg_authid not readable",
+			expected: check.SeveritySkip,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockRolePrivilegesQueryer{
+				roles:         []db.RoleAttributesRow{bootstrap},
+				canReadAuthid: tt.canReadAuthid,
+				passwordless:  tt.passwordless,
+			}
+			report, err := roleprivileges.New(queryer).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Equal(t, tt.expected, checktest.Finding(t, report, "passwordless-login-roles").Severity)
+			require.Equal(t, tt.canReadAuthid, queryer.authidCalled, "pg_authid is only queried when readable")
+		})
+	}
+}
+
+func Test_RolePrivileges_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := roleprivileges.New(&mockRolePrivilegesQueryer{roles: []db.RoleAttributesRow{appRole("app")}}).Check(ctx)
+	require.NoError(t, err)
+
+	require.Contains(t, checktest.Finding(t, report, "passwordless-login-roles").Details, "not readable on Amazon RDS")
+}
+
+func Test_RolePrivileges_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("RoleAttributes",
+			[]string{
+				"role_name text", "is_bootstrap bool", "superuser bool", "create_role bool",
+				"bypass_rls bool", "can_login bool", "connection_limit int4",
+			},
+			[]any{"postgres", "t", "t", "t", "t", "t", -1},
+			[]any{"api", "f", "f", "f", "f", "t", -1},
+			[]any{"worker", "f", "f", "t", "f", "t", -1},
+		).
+		Add("CanReadAuthid", []string{"can_read_authid bool"}, []any{"t"}).
+		Add("PasswordlessLoginRoles",
+			[]string{"role_name text", "is_bootstrap bool", "superuser bool"},
+			[]any{"postgres", "t", "t"},
+		)
+
+	report, err := roleprivileges.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "superusers").Severity)
+	require.Equal(t, []string{"worker", "CREATEROLE"}, checktest.Finding(t, report, "elevated-login-roles").Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "connection-limits").Severity)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "passwordless-login-roles").Severity)
+}
+
+func Test_RolePrivileges_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := roleprivileges.New(&mockRolePrivilegesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "role-privileges")
+}
+
+func Test_RolePrivileges_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := roleprivileges.Metadata()
+
+	require.Equal(t, "role-privileges", metadata.CheckID)
+	require.Equal(t, check.CategorySecurity, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,35 @@
+-- name: RoleAttributes :many
+-- Privilege attributes and connection limit of every role, from pg_roles,
+-- which any role can read. Predefined pg_* roles are excluded; oid 10 is the
+-- bootstrap superuser created by initdb.
+SELECT
+  r.rolname::text AS role_name
+  , r.oid = 10 AS is_bootstrap
+  , r.rolsuper AS superuser
+  , r.rolcreaterole AS create_role
+  , r.rolbypassrls AS bypass_rls
+  , r.rolcanlogin AS can_login
+  , r.rolconnlimit AS connection_limit
+FROM pg_catalog.pg_roles AS r
+WHERE r.rolname NOT LIKE 'pg\_%'
+ORDER BY r.rolname;
+
+-- name: CanReadAuthid :one
+-- Whether stored passwords can be inspected. pg_authid is readable only by
+-- superusers, which most managed services do not grant.
+SELECT has_table_privilege('pg_catalog.pg_authid', 'SELECT') AS can_read_authid;
+
+-- name: PasswordlessLoginRoles :many
+-- Login roles without a stored password. They can only authenticate through
+-- trust, peer, ident, cert, GSSAPI or another method in pg_hba.conf that does
+-- not check a password. Requires SELECT on pg_authid; only run when
+-- CanReadAuthid reports it is readable.
+SELECT
+  a.rolname::text AS role_name
+  , a.oid = 10 AS is_bootstrap
+  , a.rolsuper AS superuser
+FROM pg_catalog.pg_authid AS a
+WHERE a.rolcanlogin
+  AND a.rolpassword IS NULL
+  AND a.rolname NOT LIKE 'pg\_%'
+ORDER BY a.rolname;
@@ -0,0 +1,288 @@
+// Package schemachanges implements a check that detects schema changes between runs
+// by comparing digests of table, index and constraint definitions.
+package schemachanges
+
+import (
+	"context"
+	"crypto/sha256"
+	_ "embed"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// historyVersion is the history file format written by this version of pgdoctor.
+	historyVersion = 1
+	// maxListedChanges keeps the table readable after a large migration.
+	maxListedChanges = 20
+)
+
+type SchemaChangesQueries interface {
+	SchemaObjects(context.Context) ([]db.SchemaObjectsRow, error)
+}
+
+type checker struct {
+	queries  SchemaChangesQueries
+	history  string // Path of the history file; empty disables tracking
+	database string // Key of this database in the history file
+	now      func() time.Time
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "schema-changes",
+		Name:        "Schema Changes",
+		Description: "Detects tables, indexes and constraints changed since the previous run",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries SchemaChangesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:  queries,
+		database: "default",
+		now:      time.Now,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			c.history = myCfg["history"]
+			if v := myCfg["database"]; v != "" {
+				c.database = v
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+// historyFile stores the last schema seen for each database.
+type historyFile struct {
+	Version   int                  `json:"version"`
+	Databases map[string]schemaRun `json:"databases"`
+}
+
+type schemaRun struct {
+	CapturedAt time.Time         `json:"captured_at"`
+	Digest     string            `json:"digest"`
+	Objects    map[string]string `json:"objects"` // "index public.users_pkey" -> md5 of its definition
+}
+
+type change struct {
+	kind   string // added, removed, modified
+	object string
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.SchemaObjects(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.SchemaObjectsRow) string { return r.SchemaName + "." + r.TableName })
+
+	current := schemaRun{
+		CapturedAt: c.now().UTC(),
+		Objects:    make(map[string]string, len(rows)),
+	}
+	for _, row := range rows {
+		current.Objects[objectKey(row)] = row.Digest
+	}
+	current.Digest = digest(current.Objects)
+
+	if c.history == "" {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Schema digest %s (%d objects). Run with --history <file> to detect changes between runs.", short(current.Digest), len(current.Objects)),
+		})
+		return report, nil
+	}
+
+	history, err := readHistory(c.history)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	previous, seen := history.Databases[c.database]
+
+	history.Databases[c.database] = current
+	if err := writeHistory(c.history, history); err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	switch {
+	case !seen:
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Recorded baseline schema digest %s (%d objects)", short(current.Digest), len(current.Objects)),
+		})
+		return report, nil
+	case previous.Digest == current.Digest:
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No schema changes since %s", previous.CapturedAt.Format(time.RFC3339)),
+		})
+		return report, nil
+	}
+
+	changes := diff(previous.Objects, current.Objects)
+	counts := map[string]int{}
+	tableRows := make([]check.TableRow, 0, min(len(changes), maxListedChanges))
+	for i, ch := range changes {
+		counts[ch.kind]++
+		if i < maxListedChanges {
+			tableRows = append(tableRows, check.TableRow{
+				Cells:    []string{ch.kind, ch.object},
+				Severity: check.SeverityWarn,
+			})
+		}
+	}
+
+	details := fmt.Sprintf("Schema changed since %s: %d added, %d removed, %d modified (digest %s → %s)",
+		previous.CapturedAt.Format(time.RFC3339), counts["added"], counts["removed"], counts["modified"],
+		short(previous.Digest), short(current.Digest))
+	if len(changes) > maxListedChanges {
+		details += fmt.Sprintf("\n... and %d more", len(changes)-maxListedChanges)
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details:  details,
+		Table: &check.Table{
+			Headers: []string{"Change", "Object"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
+
+// objectKey names an object uniquely within a database. Constraint names are
+// only unique per table, so constraints are qualified with their table.
+func objectKey(row db.SchemaObjectsRow) string {
+	if row.Kind == "constraint" {
+		return fmt.Sprintf("%s %s.%s.%s", row.Kind, row.SchemaName, row.TableName, row.ObjectName)
+	}
+	return fmt.Sprintf("%s %s.%s", row.Kind, row.SchemaName, row.ObjectName)
+}
+
+// digest hashes every object definition in a stable order.
+func digest(objects map[string]string) string {
+	keys := make([]string, 0, len(objects))
+	for key := range objects {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	h := sha256.New()
+	for _, key := range keys {
+		fmt.Fprintf(h, "%s=%s\n", key, objects[key])
+	}
+	return hex.EncodeToString(h.Sum(nil))
+}
+
+// diff lists the objects added, removed or modified between two runs, sorted by object.
+func diff(previous, current map[string]string) []change {
+	var changes []change
+	for key, def := range current {
+		old, existed := previous[key]
+		switch {
+		case !existed:
+			changes = append(changes, change{kind: "added", object: key})
+		case old != def:
+			changes = append(changes, change{kind: "modified", object: key})
+		}
+	}
+	for key := range previous {
+		if _, exists := current[key]; !exists {
+			changes = append(changes, change{kind: "removed", object: key})
+		}
+	}
+
+	sort.Slice(changes, func(i, j int) bool {
+		return changes[i].object < changes[j].object
+	})
+	return changes
+}
+
+func short(digest string) string {
+	return digest[:min(len(digest), 12)]
+}
+
+func readHistory(path string) (*historyFile, error) {
+	history := &historyFile{Version: historyVersion, Databases: map[string]schemaRun{}}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return history, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("reading schema history: %w", err)
+	}
+
+	if err := json.Unmarshal(data, history); err != nil {
+		return nil, fmt.Errorf("decoding schema history %s: %w", path, err)
+	}
+	if history.Version != historyVersion {
+		return nil, fmt.Errorf("schema history %s has unsupported version %d", path, history.Version)
+	}
+	if history.Databases == nil {
+		history.Databases = map[string]schemaRun{}
+	}
+	return history, nil
+}
+
+// writeHistory replaces the history file atomically, so an interrupted run never
+// leaves a truncated file behind.
+func writeHistory(path string, history *historyFile) error {
+	data, err := json.MarshalIndent(history, "", "  ")
+	if err != nil {
+		return fmt.Errorf("encoding schema history: %w", err)
+	}
+
+	tmp, err := os.CreateTemp(filepath.Dir(path), ".schema-history-*")
+	if err != nil {
+		return fmt.Errorf("writing schema history: %w", err)
+	}
+	defer func() { _ = os.Remove(tmp.Name()) }() // No-op after the rename
+
+	if _, err := tmp.Write(data); err != nil {
+		_ = tmp.Close()
+		return fmt.Errorf("writing schema history: %w", err)
+	}
+	if err := tmp.Close(); err != nil {
+		return fmt.Errorf("writing schema history: %w", err)
+	}
+	if err := os.Rename(tmp.Name(), path); err != nil {
+		return fmt.Errorf("writing schema history: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,121 @@
+package schemachanges_test
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/schemachanges"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	rows []db.SchemaObjectsRow
+	err  error
+}
+
+func (m *mockQueryer) SchemaObjects(context.Context) ([]db.SchemaObjectsRow, error) {
+	return m.rows, m.err
+}
+
+func object(kind, table, name, digest string) db.SchemaObjectsRow {
+	return db.SchemaObjectsRow{SchemaName: "public", TableName: table, Kind: kind, ObjectName: name, Digest: digest}
+}
+
+func baseSchema() []db.SchemaObjectsRow {
+	return []db.SchemaObjectsRow{
+		object("table", "users", "users", "t1"),
+		object("index", "users", "users_pkey", "i1"),
+		object("constraint", "users", "users_pkey", "c1"),
+		object("table", "orders", "orders", "t2"),
+	}
+}
+
+func run(t *testing.T, rows []db.SchemaObjectsRow, cfg check.Config) check.Finding {
+	t.Helper()
+
+	report, err := schemachanges.New(&mockQueryer{rows: rows}, cfg).Check(context.Background())
+	require.NoError(t, err)
+	require.Len(t, report.Results, 1)
+	return report.Results[0]
+}
+
+func Test_SchemaChanges_WithoutHistory(t *testing.T) {
+	t.Parallel()
+
+	finding := run(t, baseSchema(), nil)
+	assert.Equal(t, check.SeverityOK, finding.Severity)
+	assert.Contains(t, finding.Details, "4 objects")
+	assert.Contains(t, finding.Details, "--history")
+}
+
+func Test_SchemaChanges_History(t *testing.T) {
+	t.Parallel()
+
+	history := filepath.Join(t.TempDir(), "history.json")
+	cfg := check.Config{"schema-changes": {"history": history, "database": "db1/app"}}
+
+	finding := run(t, baseSchema(), cfg)
+	assert.Equal(t, check.SeverityOK, finding.Severity)
+	assert.Contains(t, finding.Details, "Recorded baseline")
+
+	finding = run(t, baseSchema(), cfg)
+	assert.Equal(t, check.SeverityOK, finding.Severity)
+	assert.Contains(t, finding.Details, "No schema changes")
+
+	changed := baseSchema()
+	changed[0].Digest = "t1-with-new-column"
+	changed = append(changed[:3], object("index", "orders", "orders_created_at_idx", "i2"))
+	finding = run(t, changed, cfg)
+	assert.Equal(t, check.SeverityWarn, finding.Severity)
+	assert.Contains(t, finding.Details, "1 added, 1 removed, 1 modified")
+	require.NotNil(t, finding.Table)
+	assert.Equal(t, []check.TableRow{
+		{Cells: []string{"added", "index public.orders_created_at_idx"}, Severity: check.SeverityWarn},
+		{Cells: []string{"removed", "table public.orders"}, Severity: check.SeverityWarn},
+		{Cells: []string{"modified", "table public.users"}, Severity: check.SeverityWarn},
+	}, finding.Table.Rows)
+
+	// Each change is reported once: the new schema is the next baseline.
+	finding = run(t, changed, cfg)
+	assert.Equal(t, check.SeverityOK, finding.Severity)
+
+	// Databases sharing a history file are tracked separately.
+	other := check.Config{"schema-changes": {"history": history, "database": "db2/app"}}
+	finding = run(t, baseSchema(), other)
+	assert.Contains(t, finding.Details, "Recorded baseline")
+}
+
+func Test_SchemaChanges_ManyChangesTruncated(t *testing.T) {
+	t.Parallel()
+
+	history := filepath.Join(t.TempDir(), "history.json")
+	cfg := check.Config{"schema-changes": {"history": history}}
+	run(t, nil, cfg)
+
+	var rows []db.SchemaObjectsRow
+	for i := range 25 {
+		rows = append(rows, object("table", fmt.Sprintf("t%02d", i), fmt.Sprintf("t%02d", i), "x"))
+	}
+	finding := run(t, rows, cfg)
+	assert.Equal(t, check.SeverityWarn, finding.Severity)
+	assert.Len(t, finding.Table.Rows, 20)
+	assert.Contains(t, finding.Details, "... and 5 more")
+}
+
+func Test_SchemaChanges_Errors(t *testing.T) {
+	t.Parallel()
+
+	_, err := schemachanges.New(&mockQueryer{err: fmt.Errorf("connection refused")}).Check(context.Background())
+	require.ErrorContains(t, err, "schema-changes")
+
+	corrupt := filepath.Join(t.TempDir(), "history.json")
+	require.NoError(t, os.WriteFile(corrupt, []byte("not json"), 0o600))
+	_, err = schemachanges.New(&mockQueryer{}, check.Config{"schema-changes": {"history": corrupt}}).Check(context.Background())
+	require.ErrorContains(t, err, "decoding schema history")
+}
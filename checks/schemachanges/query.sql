@@ -0,0 +1,59 @@
+-- name: SchemaObjects :many
+-- One row per table, index and constraint in user schemas with an md5 of its
+-- definition. Definitions are rebuilt by the catalog (format_type, pg_get_*def),
+-- so cosmetic differences in the original DDL never register as changes.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , 'table'::text AS kind
+  , c.relname::text AS object_name
+  , MD5(
+    c.relkind::text || ':' || COALESCE((
+      SELECT
+        STRING_AGG(
+          a.attname || ' ' || FORMAT_TYPE(a.atttypid, a.atttypmod)
+          || CASE WHEN a.attnotnull THEN ' not null' ELSE '' END
+          || COALESCE(' default ' || PG_GET_EXPR(d.adbin, d.adrelid), '')
+          , ', ' ORDER BY a.attnum
+        )
+      FROM pg_attribute AS a
+      LEFT JOIN pg_attrdef AS d ON a.attrelid = d.adrelid AND a.attnum = d.adnum
+      WHERE a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+    ), '')
+  ) AS digest
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE c.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+UNION ALL
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , 'index'::text AS kind
+  , i.relname::text AS object_name
+  , MD5(PG_GET_INDEXDEF(i.oid)) AS digest
+FROM pg_index AS ix
+INNER JOIN pg_class AS i ON ix.indexrelid = i.oid
+INNER JOIN pg_class AS t ON ix.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE t.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+UNION ALL
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , 'constraint'::text AS kind
+  , con.conname::text AS object_name
+  , MD5(con.contype::text || ':' || PG_GET_CONSTRAINTDEF(con.oid)) AS digest
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE t.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+ORDER BY schema_name, table_name, kind, object_name;
@@ -5,6 +5,7 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"strconv"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
@@ -20,8 +21,17 @@ type SequenceHealthQueries interface {
 	SequenceHealth(context.Context) ([]db.SequenceHealthRow, error)
 }
 
+// Default near-exhaustion thresholds, in percent of the values a sequence can
+// still hand out before it or its owning column runs out.
+const (
+	defaultWarnPercent = 70
+	defaultFailPercent = 90
+)
+
 type checker struct {
-	queries SequenceHealthQueries
+	queries     SequenceHealthQueries
+	warnPercent float64 // default: 70
+	failPercent float64 // default: 90
 }
 
 func Metadata() check.Metadata {
@@ -35,10 +45,29 @@ func Metadata() check.Metadata {
 	}
 }
 
-func New(queries SequenceHealthQueries, _ ...check.Config) check.Checker {
-	return &checker{
-		queries: queries,
+func New(queries SequenceHealthQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		warnPercent: defaultWarnPercent,
+		failPercent: defaultFailPercent,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["warn_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 && f <= 100 {
+					c.warnPercent = f
+				}
+			}
+			if v, ok := myCfg["fail_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 && f <= 100 {
+					c.failPercent = f
+				}
+			}
+		}
 	}
+
+	return c
 }
 
 func (c *checker) Metadata() check.Metadata {
@@ -51,6 +80,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("failed to check sequence health: %w", err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.SequenceHealthRow) string { return r.SchemaName.String + "." + r.SequenceName.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -62,7 +92,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkNearExhaustion(rows, report)
+	c.checkNearExhaustion(rows, report)
 	checkIntegerShouldBeBigint(rows, report)
 	checkSequenceTypeMismatch(rows, report)
 
@@ -77,18 +107,18 @@ func getUsagePercent(row db.SequenceHealthRow) float64 {
 	return f.Float64
 }
 
-func checkNearExhaustion(rows []db.SequenceHealthRow, report *check.Report) {
-	var critical []db.SequenceHealthRow // >90%
-	var warning []db.SequenceHealthRow  // >75%
+func (c *checker) checkNearExhaustion(rows []db.SequenceHealthRow, report *check.Report) {
+	var critical []db.SequenceHealthRow // >= failPercent
+	var warning []db.SequenceHealthRow  // >= warnPercent
 
 	for _, row := range rows {
 		usage := getUsagePercent(row)
 		if row.IsCyclic.Bool {
 			continue // Cyclic sequences wrap around safely
 		}
-		if usage >= 90 {
+		if usage >= c.failPercent {
 			critical = append(critical, row)
-		} else if usage >= 75 {
+		} else if usage >= c.warnPercent {
 			warning = append(warning, row)
 		}
 	}
@@ -98,7 +128,7 @@ func checkNearExhaustion(rows []db.SequenceHealthRow, report *check.Report) {
 			ID:       "near-exhaustion",
 			Name:     "Sequence Exhaustion",
 			Severity: check.SeverityOK,
-			Details:  "All sequences have sufficient headroom (<75% used)",
+			Details:  fmt.Sprintf("All sequences have sufficient headroom (<%s%% used)", formatPercent(c.warnPercent)),
 		})
 		return
 	}
@@ -136,7 +166,8 @@ func checkNearExhaustion(rows []db.SequenceHealthRow, report *check.Report) {
 	details := fmt.Sprintf("Found %d sequence(s) nearing exhaustion", len(critical)+len(warning))
 	if len(critical) > 0 {
 		severity = check.SeverityFail
-		details = fmt.Sprintf("CRITICAL: %d sequence(s) at >90%% capacity! %d more at >75%%", len(critical), len(warning))
+		details = fmt.Sprintf("CRITICAL: %d sequence(s) at >%s%% capacity! %d more at >%s%%",
+			len(critical), formatPercent(c.failPercent), len(warning), formatPercent(c.warnPercent))
 	}
 
 	report.AddFinding(check.Finding{
@@ -270,3 +301,8 @@ func formatRemaining(remaining int64) int64 {
 	}
 	return remaining
 }
+
+// formatPercent drops the decimals of whole thresholds: 70, but 72.5.
+func formatPercent(p float64) string {
+	return strconv.FormatFloat(p, 'f', -1, 64)
+}
@@ -223,7 +223,7 @@ func TestSequenceHealth_NearExhaustion_MixedSeverity(t *testing.T) {
 	require.Equal(t, check.SeverityFail, exhaustionFinding.Severity)
 	require.Contains(t, exhaustionFinding.Details, "CRITICAL")
 	require.Contains(t, exhaustionFinding.Details, "1 sequence(s) at >90%")
-	require.Contains(t, exhaustionFinding.Details, "1 more at >75%")
+	require.Contains(t, exhaustionFinding.Details, "1 more at >70%")
 	require.NotNil(t, exhaustionFinding.Table)
 	require.Equal(t, 2, len(exhaustionFinding.Table.Rows))
 	require.Equal(t, check.SeverityFail, exhaustionFinding.Table.Rows[0].Severity)
@@ -519,8 +519,8 @@ func TestSequenceHealth_EdgeCase_ExactThresholds(t *testing.T) {
 		subcheckID       string
 	}{
 		{
-			name:             "exactly 75% - warning threshold",
-			usagePercent:     75.0,
+			name:             "exactly 70% - warning threshold",
+			usagePercent:     70.0,
 			expectedSeverity: check.SeverityWarn,
 			subcheckID:       findingIDNearExhaustion,
 		},
@@ -531,8 +531,8 @@ func TestSequenceHealth_EdgeCase_ExactThresholds(t *testing.T) {
 			subcheckID:       findingIDNearExhaustion,
 		},
 		{
-			name:             "just below 75%",
-			usagePercent:     74.99,
+			name:             "just below 70%",
+			usagePercent:     69.99,
 			expectedSeverity: check.SeverityOK,
 			subcheckID:       findingIDNearExhaustion,
 		},
@@ -580,6 +580,42 @@ func TestSequenceHealth_EdgeCase_ExactThresholds(t *testing.T) {
 	}
 }
 
+func TestSequenceHealth_NearExhaustion_ConfiguredThresholds(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.SequenceHealthRow{
+		makeSequenceRow(
+			"public", "orders_id_seq", "bigint", "orders", "id", "integer",
+			1610612735, 9223372036854775807, 1, 536870912, 2147483647,
+			75.0, false, true, true, true, 0,
+		),
+		makeSequenceRow(
+			"public", "events_id_seq", "bigint", "events", "id", "integer",
+			1825361100, 9223372036854775807, 1, 322122547, 2147483647,
+			85.0, false, true, true, true, 0,
+		),
+	}
+
+	cfg := check.Config{"sequence-health": {"warn_percent": "80", "fail_percent": "95"}}
+	checker := sequencehealth.New(&mockQueryer{rows: rows}, cfg)
+
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	var exhaustionFinding *check.Finding
+	for i := range report.Results {
+		if report.Results[i].ID == findingIDNearExhaustion {
+			exhaustionFinding = &report.Results[i]
+			break
+		}
+	}
+
+	require.NotNil(t, exhaustionFinding)
+	require.Equal(t, check.SeverityWarn, exhaustionFinding.Severity)
+	require.Len(t, exhaustionFinding.Table.Rows, 1)
+	require.Equal(t, "events_id_seq", exhaustionFinding.Table.Rows[0].Cells[0])
+}
+
 func TestSequenceHealth_TableFormatting_NearExhaustion(t *testing.T) {
 	t.Parallel()
 
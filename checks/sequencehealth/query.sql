@@ -1,20 +1,18 @@
 -- name: SequenceHealth :many
--- Identifies sequences approaching their maximum values and integer columns that should be bigint
+-- Identifies sequences approaching their maximum values and integer columns that should be bigint.
+-- Usage is measured against the tighter of the sequence's own limit and the
+-- owning column's type (an int4 column overflows long before a bigint
+-- sequence), towards min_value for descending sequences.
 WITH sequence_info AS (
   SELECT
     s.schemaname::text AS schema_name
     , s.sequencename::text AS sequence_name
     , s.data_type::text AS seq_data_type
+    , s.min_value
     , s.max_value
     , s.increment_by
     , s.cycle AS is_cyclic
     , COALESCE(s.last_value, s.start_value) AS current_value
-    , CASE
-      WHEN s.max_value > 0 AND COALESCE(s.last_value, s.start_value) > 0
-        THEN (COALESCE(s.last_value, s.start_value)::numeric / s.max_value::numeric) * 100
-      ELSE 0
-    END AS usage_percent
-    , (s.max_value - COALESCE(s.last_value, s.start_value)) / NULLIF(s.increment_by, 0) AS remaining_values
   FROM pg_sequences AS s
   WHERE s.schemaname NOT IN ('pg_catalog', 'information_schema')
 )
@@ -48,6 +46,41 @@ WITH sequence_info AS (
   AND seq_ns.nspname NOT IN ('pg_catalog', 'information_schema')
 )
 
+-- The limit the sequence runs into first, in the direction it moves
+, sequence_limits AS (
+  SELECT
+    si.*
+    , so.table_oid
+    , so.table_name
+    , so.column_name
+    , so.column_num
+    , so.column_type
+    , so.column_max_value
+    , CASE
+      WHEN si.increment_by > 0 THEN LEAST(si.max_value, COALESCE(so.column_max_value, si.max_value))
+      ELSE GREATEST(si.min_value, COALESCE(-so.column_max_value - 1, si.min_value))
+    END AS limit_value
+  FROM sequence_info AS si
+  LEFT JOIN sequence_owners AS so
+    ON
+      si.schema_name = so.seq_schema
+      AND si.sequence_name = so.seq_name
+)
+
+, sequence_usage AS (
+  SELECT
+    sl.*
+    , CASE
+      WHEN sl.increment_by > 0 AND sl.limit_value > 0 AND sl.current_value > 0
+        THEN (sl.current_value::numeric / sl.limit_value::numeric) * 100
+      WHEN sl.increment_by < 0 AND sl.limit_value < 0 AND sl.current_value < 0
+        THEN (sl.current_value::numeric / sl.limit_value::numeric) * 100
+      ELSE 0
+    END AS usage_percent
+    , (sl.limit_value - sl.current_value) / NULLIF(sl.increment_by, 0) AS remaining_values
+  FROM sequence_limits AS sl
+)
+
 -- Check if columns are primary keys
 , primary_keys AS (
   SELECT
@@ -78,30 +111,26 @@ SELECT
   , si.is_cyclic
   , si.remaining_values
   , ROUND(si.usage_percent::numeric, 2) AS usage_percent
-  , COALESCE(so.table_name, '') AS table_name
-  , COALESCE(so.column_name, '') AS column_name
-  , COALESCE(so.column_type, '') AS column_type
-  , COALESCE(so.column_max_value, 0) AS column_max_value
+  , COALESCE(si.table_name, '') AS table_name
+  , COALESCE(si.column_name, '') AS column_name
+  , COALESCE(si.column_type, '') AS column_type
+  , COALESCE(si.column_max_value, 0) AS column_max_value
   -- Flag if sequence can generate values that exceed column type
-  , (so.column_max_value IS NOT NULL AND si.max_value > so.column_max_value) AS sequence_exceeds_column
+  , (si.column_max_value IS NOT NULL AND si.max_value > si.column_max_value) AS sequence_exceeds_column
   -- Flag if this is an integer column that should probably be bigint
-  , (so.column_type != 'bigint' AND si.usage_percent > 50) AS should_be_bigint
+  , (si.column_type != 'bigint' AND si.usage_percent > 50) AS should_be_bigint
   -- Flag if column is a primary key
   , (pk.table_oid IS NOT NULL) AS is_primary_key
   -- Count of foreign keys referencing this column
   , COALESCE(fkr.fk_count, 0) AS fk_reference_count
 
-FROM sequence_info AS si
-LEFT JOIN sequence_owners AS so
-  ON
-    si.schema_name = so.seq_schema
-    AND si.sequence_name = so.seq_name
+FROM sequence_usage AS si
 LEFT JOIN primary_keys AS pk
   ON
-    so.table_oid = pk.table_oid
-    AND so.column_num = pk.column_num
+    si.table_oid = pk.table_oid
+    AND si.column_num = pk.column_num
 LEFT JOIN fk_references AS fkr
   ON
-    so.table_oid = fkr.referenced_table_oid
-    AND so.column_num = fkr.referenced_column_num
+    si.table_oid = fkr.referenced_table_oid
+    AND si.column_num = fkr.referenced_column_num
 ORDER BY si.usage_percent DESC, si.remaining_values ASC;
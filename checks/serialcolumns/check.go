@@ -0,0 +1,137 @@
+// Package serialcolumns implements a check for columns that still take their
+// values from a serial sequence instead of being identity columns.
+package serialcolumns
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const serialRemediation = "Convert each column to GENERATED BY DEFAULT AS IDENTITY, carrying over the sequence position, " +
+	"and drop the old sequence; each conversion runs in its own transaction"
+
+type SerialColumnsQueries interface {
+	SerialColumns(context.Context) ([]db.SerialColumnsRow, error)
+}
+
+type checker struct {
+	queries SerialColumnsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategorySchema,
+		CheckID:      "serial-columns",
+		Name:         "Serial Columns",
+		Description:  "Lists serial and bigserial columns that could be identity columns",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 10,
+	}
+}
+
+func New(queries SerialColumnsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.SerialColumns(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.SerialColumnsRow) string { return r.SchemaName + "." + r.TableName })
+
+	checkSerialColumns(rows, report)
+
+	return report, nil
+}
+
+func checkSerialColumns(rows []db.SerialColumnsRow, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No column takes its values from a serial sequence",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(rows))
+	fixes := make([]string, 0, len(rows))
+	for _, row := range rows {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{row.SchemaName + "." + row.TableName, row.ColumnName, row.DataType, row.SequenceSchema + "." + row.SequenceName},
+			Severity: check.SeverityWarn,
+		})
+		fixes = append(fixes, identityMigration(row))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d column(s) use serial sequences instead of identity columns: the sequence is a separate object "+
+			"with its own privileges, and the default can be overwritten or copied to other tables", len(rows)),
+		Remediation: serialRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Type", "Sequence"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "serial_columns", Value: float64(len(rows)), Unit: check.UnitCount},
+		},
+	})
+}
+
+// identityMigration turns a serial column into an identity column. The old
+// sequence is detached before the identity is added, so that
+// pg_get_serial_sequence finds only the new one, and its position is copied
+// over before it is dropped.
+func identityMigration(row db.SerialColumnsRow) string {
+	table := pgx.Identifier{row.SchemaName, row.TableName}.Sanitize()
+	column := pgx.Identifier{row.ColumnName}.Sanitize()
+	sequence := pgx.Identifier{row.SequenceSchema, row.SequenceName}.Sanitize()
+
+	statements := []string{
+		"BEGIN;",
+		fmt.Sprintf("ALTER TABLE %s ALTER COLUMN %s DROP DEFAULT;", table, column),
+	}
+	if !row.NotNull {
+		statements = append(statements, fmt.Sprintf("ALTER TABLE %s ALTER COLUMN %s SET NOT NULL;", table, column))
+	}
+	statements = append(statements,
+		fmt.Sprintf("ALTER SEQUENCE %s OWNED BY NONE;", sequence),
+		fmt.Sprintf("ALTER TABLE %s ALTER COLUMN %s ADD GENERATED BY DEFAULT AS IDENTITY;", table, column),
+		fmt.Sprintf("SELECT setval(pg_get_serial_sequence(%s, %s), last_value, is_called) FROM %s;",
+			literal(table), literal(row.ColumnName), sequence),
+		fmt.Sprintf("DROP SEQUENCE %s;", sequence),
+		"COMMIT;",
+	)
+	return strings.Join(statements, "\n")
+}
+
+func literal(value string) string {
+	return "'" + strings.ReplaceAll(value, "'", "''") + "'"
+}
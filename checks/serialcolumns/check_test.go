@@ -0,0 +1,151 @@
+package serialcolumns_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/serialcolumns"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockSerialColumnsQueryer struct {
+	columns []db.SerialColumnsRow
+	err     error
+}
+
+func (m *mockSerialColumnsQueryer) SerialColumns(context.Context) ([]db.SerialColumnsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.columns, nil
+}
+
+func column(table, name, dataType string) db.SerialColumnsRow {
+	return db.SerialColumnsRow{
+		SchemaName:     "public",
+		TableName:      table,
+		ColumnName:     name,
+		DataType:       dataType,
+		NotNull:        true,
+		SequenceSchema: "public",
+		SequenceName:   table + "_" + name + "_seq",
+	}
+}
+
+func Test_SerialColumns(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		columns  []db.SerialColumnsRow
+		severity check.Severity
+	}{
+		{
+			name:     "no serial columns",
+			severity: check.SeverityOK,
+		},
+		{
+			name:     "serial column",
+			columns:  []db.SerialColumnsRow{column("orders", "id", "integer")},
+			severity: check.SeverityWarn,
+		},
+		{
+			name:     "bigserial columns",
+			columns:  []db.SerialColumnsRow{column("orders", "id", "bigint"), column("users", "id", "bigint")},
+			severity: check.SeverityWarn,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := serialcolumns.New(&mockSerialColumnsQueryer{columns: tt.columns}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.severity, finding.Severity)
+			if tt.severity == check.SeverityOK {
+				require.Empty(t, finding.FixSQL)
+				return
+			}
+			require.Len(t, finding.Table.Rows, len(tt.columns))
+			require.NotEmpty(t, finding.Remediation)
+		})
+	}
+}
+
+func Test_SerialColumns_FixSQL(t *testing.T) {
+	t.Parallel()
+
+	nullable := column("order's", "id", "integer")
+	nullable.NotNull = false
+	report, err := serialcolumns.New(&mockSerialColumnsQueryer{columns: []db.SerialColumnsRow{
+		column("orders", "id", "bigint"),
+		nullable,
+	}}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, `BEGIN;
+ALTER TABLE "public"."orders" ALTER COLUMN "id" DROP DEFAULT;
+ALTER SEQUENCE "public"."orders_id_seq" OWNED BY NONE;
+ALTER TABLE "public"."orders" ALTER COLUMN "id" ADD GENERATED BY DEFAULT AS IDENTITY;
+SELECT setval(pg_get_serial_sequence('"public"."orders"', 'id'), last_value, is_called) FROM "public"."orders_id_seq";
+DROP SEQUENCE "public"."orders_id_seq";
+COMMIT;
+BEGIN;
+ALTER TABLE "public"."order's" ALTER COLUMN "id" DROP DEFAULT;
+ALTER TABLE "public"."order's" ALTER COLUMN "id" SET NOT NULL;
+ALTER SEQUENCE "public"."order's_id_seq" OWNED BY NONE;
+ALTER TABLE "public"."order's" ALTER COLUMN "id" ADD GENERATED BY DEFAULT AS IDENTITY;
+SELECT setval(pg_get_serial_sequence('"public"."order''s"', 'id'), last_value, is_called) FROM "public"."order's_id_seq";
+DROP SEQUENCE "public"."order's_id_seq";
+COMMIT;`, report.Results[0].FixSQL)
+}
+
+func Test_SerialColumns_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("SerialColumns",
+		[]string{
+			"schema_name text", "table_name text", "column_name text", "data_type text", "not_null bool",
+			"sequence_schema text", "sequence_name text",
+		},
+		[]any{"billing", "invoices", "id", "bigint", true, "billing", "invoices_id_seq"},
+	)
+
+	report, err := serialcolumns.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"billing.invoices", "id", "bigint", "billing.invoices_id_seq"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_SerialColumns_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := serialcolumns.New(&mockSerialColumnsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "serial-columns")
+}
+
+func Test_SerialColumns_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := serialcolumns.Metadata()
+
+	require.Equal(t, "serial-columns", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.Equal(t, 10, metadata.MinPGVersion)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,43 @@
+-- name: SerialColumns :many
+-- Columns whose default takes nextval() from a sequence the column owns, the
+-- way serial and bigserial create them, instead of identity columns.
+-- Partitioned tables and their partitions are left out, since identity
+-- columns need PostgreSQL 17 there, and so are tables owned by extensions.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type
+  , a.attnotnull AS not_null
+  , sn.nspname::text AS sequence_schema
+  , s.relname::text AS sequence_name
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_attrdef AS ad ON a.attrelid = ad.adrelid AND a.attnum = ad.adnum
+INNER JOIN pg_catalog.pg_depend AS d
+  ON
+    d.classid = 'pg_catalog.pg_class'::regclass
+    AND d.refclassid = 'pg_catalog.pg_class'::regclass
+    AND a.attrelid = d.refobjid
+    AND a.attnum = d.refobjsubid
+    AND d.deptype = 'a'
+INNER JOIN pg_catalog.pg_class AS s ON d.objid = s.oid AND s.relkind = 'S'
+INNER JOIN pg_catalog.pg_namespace AS sn ON s.relnamespace = sn.oid
+WHERE
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND a.attidentity = ''
+  AND c.relkind = 'r'
+  AND NOT c.relispartition
+  AND pg_catalog.pg_get_expr(ad.adbin, ad.adrelid) LIKE 'nextval(%'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND NOT EXISTS (
+    SELECT 1
+    FROM pg_catalog.pg_depend AS ext
+    WHERE
+      ext.classid = 'pg_catalog.pg_class'::regclass
+      AND c.oid = ext.objid
+      AND ext.deptype = 'e'
+  )
+ORDER BY n.nspname, c.relname, a.attnum;
@@ -0,0 +1,204 @@
+// Package sharedbuffers implements a check sizing shared_buffers against system memory.
+package sharedbuffers
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+const (
+	// The usual starting point is a quarter of system memory.
+	guidelinePercent = 25
+	lowPercent       = 10
+	highWarnPercent  = 40
+	highFailPercent  = 75
+
+	// Large instances rarely need a full quarter, so shared_buffers is only
+	// low when it is under 10% of memory and under this size.
+	lowCapBytes = 8 * check.GiB
+
+	// Below this, connections and the OS page cache need a larger share.
+	smallInstanceBytes = 4 * check.GiB
+	smallTargetPercent = 20
+
+	// Above this, raise shared_buffers in steps rather than to 25% at once.
+	largeInstanceBytes = 64 * check.GiB
+
+	// PostgreSQL's block size, the unit of shared_buffers unless reported otherwise.
+	defaultBlockSize = 8 * check.KiB
+)
+
+const smallInstanceNote = "On a small instance every connection's work_mem and the OS page cache compete for the same memory: " +
+	"aim for 15-20% and raise it only if cache-efficiency reports misses."
+
+const largeInstanceNote = "On a large instance the gains diminish past 16-32GB and checkpoints have more dirty buffers to write: " +
+	"raise it in steps and watch cache-efficiency between them."
+
+const auroraNote = "Aurora defaults shared_buffers to 75% of memory, since its storage layer has no OS page cache to share it with: " +
+	"reset the parameter to its default unless a lower value was chosen on purpose."
+
+type SharedBuffersQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries SharedBuffersQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "shared-buffers",
+		Name:        "Shared Buffers",
+		Description: "Compares shared_buffers against system memory",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries SharedBuffersQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	sharedBuffers := settingBytes(catalog.Settings(rows)["shared_buffers"])
+
+	meta := check.InstanceMetadataFromContext(ctx)
+	if meta == nil || meta.MemoryGB <= 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeveritySkip,
+			Details: fmt.Sprintf("shared_buffers is %s; system memory is unknown, pass --system-memory to compare it against the %d%% guideline",
+				check.FormatSize(ctx, sharedBuffers), guidelinePercent),
+		})
+		return report, nil
+	}
+
+	checkSharedBuffers(ctx, sharedBuffers, int64(meta.MemoryGB*check.GiB), check.ProviderFromContext(ctx), report)
+
+	return report, nil
+}
+
+// checkSharedBuffers flags shared_buffers far below the 25% guideline, or so
+// large that it starves everything else of memory.
+func checkSharedBuffers(ctx context.Context, sharedBuffers, memory int64, provider check.Provider, report *check.Report) {
+	percent := float64(sharedBuffers) / float64(memory) * 100
+	summary := fmt.Sprintf("shared_buffers is %s, %.1f%% of %s system memory",
+		check.FormatSize(ctx, sharedBuffers), percent, check.FormatSize(ctx, memory))
+	metrics := []check.Metric{
+		{Name: "shared_buffers", Value: float64(sharedBuffers), Unit: check.UnitBytes},
+		{Name: "shared_buffers_memory_ratio", Value: percent, Unit: check.UnitPercent},
+	}
+
+	// Aurora's storage does not go through the OS page cache, so its large
+	// default is deliberate.
+	aurora := provider == check.ProviderAurora
+
+	var severity check.Severity
+	var details, threshold string
+	switch {
+	case !aurora && percent >= highFailPercent:
+		severity, threshold = check.SeverityFail, fmt.Sprintf(">= %d%% of memory", highFailPercent)
+		details = summary + ": the server is likely to swap or be killed by the OOM killer under load. Check that the system memory is right."
+	case !aurora && percent > highWarnPercent:
+		severity, threshold = check.SeverityWarn, fmt.Sprintf("> %d%% of memory", highWarnPercent)
+		details = summary + ": little is left for work_mem, maintenance and the OS page cache, which PostgreSQL also reads through."
+	case float64(sharedBuffers) < float64(memory)*lowPercent/100 && sharedBuffers < lowCapBytes:
+		severity, threshold = check.SeverityWarn, fmt.Sprintf("< %d%% of memory", lowPercent)
+		details = fmt.Sprintf("%s, far below the usual %d%%: hot data is read through the OS page cache instead of staying in shared memory.",
+			summary, guidelinePercent)
+	default:
+		report.AddFinding(check.Finding{
+			ID:       "shared-buffers",
+			Name:     "Shared Buffers",
+			Severity: check.SeverityOK,
+			Details:  summary,
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	target := targetBytes(memory)
+	switch {
+	case aurora:
+		target = memory * 3 / 4 / (256 * check.MiB) * (256 * check.MiB)
+		details += "\n\n" + auroraNote
+	case memory < smallInstanceBytes:
+		details += "\n\n" + smallInstanceNote
+	case memory >= largeInstanceBytes:
+		details += "\n\n" + largeInstanceNote
+	}
+
+	remediation, fixSQL := provider.RestartSettingFix("shared_buffers", formatMB(target/check.MiB))
+	report.AddFinding(check.Finding{
+		ID:       "shared-buffers",
+		Name:     "Shared Buffers",
+		Severity: severity,
+		Details:  details,
+		Object:   &check.Object{Kind: check.ObjectSetting, Name: "shared_buffers"},
+		Comparison: &check.Comparison{
+			Setting:   "shared_buffers",
+			Actual:    check.FormatSize(ctx, sharedBuffers),
+			Expected:  formatMB(target / check.MiB),
+			Threshold: threshold,
+		},
+		Metrics:     metrics,
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+	})
+}
+
+// targetBytes suggests a quarter of memory, or a fifth on small instances,
+// rounded down to 256MB (64MB on small instances) and never below the
+// PostgreSQL default of 128MB.
+func targetBytes(memory int64) int64 {
+	target, step := memory*guidelinePercent/100, int64(256*check.MiB)
+	if memory < smallInstanceBytes {
+		target, step = memory*smallTargetPercent/100, 64*check.MiB
+	}
+	return max(target/step*step, 128*check.MiB)
+}
+
+// settingBytes converts a memory setting to bytes using its pg_settings unit,
+// e.g. shared_buffers is counted in 8kB blocks.
+func settingBytes(row db.CatalogSettingsRow) int64 {
+	value, err := strconv.ParseInt(row.Setting.String, 10, 64)
+	if err != nil {
+		return 0
+	}
+	unit, err := check.ParseBytes(row.Unit.String)
+	if err != nil || unit == 0 || !row.Unit.Valid {
+		unit = defaultBlockSize
+	}
+	return value * unit
+}
+
+// formatMB formats a size in megabytes as a setting value, e.g. "4GB" or "1536MB".
+func formatMB(mb int64) string {
+	if mb%1024 == 0 {
+		return fmt.Sprintf("%dGB", mb/1024)
+	}
+	return fmt.Sprintf("%dMB", mb)
+}
@@ -0,0 +1,207 @@
+package sharedbuffers_test
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/sharedbuffers"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockSharedBuffersQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockSharedBuffersQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+// sharedBuffers is the shared_buffers row for a size, counted in 8kB blocks.
+func sharedBuffers(bytes int64) []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{{
+		Name:    pgtype.Text{String: "shared_buffers", Valid: true},
+		Setting: pgtype.Text{String: strconv.FormatInt(bytes/8192, 10), Valid: true},
+		Unit:    pgtype.Text{String: "8kB", Valid: true},
+	}}
+}
+
+func withMemory(provider check.Provider, memoryGB float64) context.Context {
+	ctx := check.ContextWithProvider(context.Background(), provider)
+	return check.ContextWithInstanceMetadata(ctx, &check.InstanceMetadata{MemoryGB: memoryGB})
+}
+
+func Test_SharedBuffers(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		SharedBuffers    int64
+		MemoryGB         float64
+		Provider         check.Provider
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+		ExpectedTarget   string
+	}{
+		{
+			Name:             "quarter of memory - OK",
+			SharedBuffers:    4 * check.GiB,
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "shared_buffers is 4.0GiB, 25.0% of 16.0GiB system memory",
+		},
+		{
+			Name:             "default on a 16GB server warns",
+			SharedBuffers:    128 * check.MiB,
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "shared_buffers is 128.0MiB, 0.8% of 16.0GiB system memory, far below the usual 25%",
+			ExpectedTarget:   "4GB",
+		},
+		{
+			Name:             "half of memory warns",
+			SharedBuffers:    8 * check.GiB,
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "50.0% of 16.0GiB system memory: little is left for work_mem",
+			ExpectedTarget:   "4GB",
+		},
+		{
+			Name:             "nearly all memory fails",
+			SharedBuffers:    14 * check.GiB,
+			MemoryGB:         16,
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "the server is likely to swap",
+			ExpectedTarget:   "4GB",
+		},
+		{
+			Name:             "small instance gets its own guidance",
+			SharedBuffers:    128 * check.MiB,
+			MemoryGB:         2,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "On a small instance",
+			ExpectedTarget:   "384MB",
+		},
+		{
+			Name:             "large instance with 16GB - OK",
+			SharedBuffers:    16 * check.GiB,
+			MemoryGB:         256,
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name:             "large instance with 4GB warns",
+			SharedBuffers:    4 * check.GiB,
+			MemoryGB:         256,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "On a large instance",
+			ExpectedTarget:   "64GB",
+		},
+		{
+			Name:             "Aurora default - OK",
+			SharedBuffers:    12 * check.GiB,
+			MemoryGB:         16,
+			Provider:         check.ProviderAurora,
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name:             "Aurora lowered warns",
+			SharedBuffers:    128 * check.MiB,
+			MemoryGB:         16,
+			Provider:         check.ProviderAurora,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "Aurora defaults shared_buffers to 75% of memory",
+			ExpectedTarget:   "12GB",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := sharedbuffers.New(&mockSharedBuffersQueryer{settings: sharedBuffers(tc.SharedBuffers)})
+			report, err := checker.Check(withMemory(tc.Provider, tc.MemoryGB))
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+			if tc.ExpectedTarget != "" {
+				require.Equal(t, tc.ExpectedTarget, finding.Comparison.Expected)
+			}
+		})
+	}
+}
+
+func Test_SharedBuffers_Remediation(t *testing.T) {
+	t.Parallel()
+
+	checker := sharedbuffers.New(&mockSharedBuffersQueryer{settings: sharedBuffers(128 * check.MiB)})
+	report, err := checker.Check(withMemory(check.ProviderSelfManaged, 16))
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, "Set shared_buffers to 4GB and restart the server during a maintenance window", finding.Remediation)
+	require.Equal(t, "ALTER SYSTEM SET shared_buffers = '4GB';", finding.FixSQL)
+
+	report, err = checker.Check(withMemory(check.ProviderRDS, 16))
+	require.NoError(t, err)
+	require.Empty(t, report.Results[0].FixSQL)
+}
+
+func Test_SharedBuffers_UnknownMemory(t *testing.T) {
+	t.Parallel()
+
+	checker := sharedbuffers.New(&mockSharedBuffersQueryer{settings: sharedBuffers(128 * check.MiB)})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeveritySkip, report.Results[0].Severity)
+	require.Contains(t, report.Results[0].Details, "shared_buffers is 128.0MiB; system memory is unknown")
+}
+
+func Test_SharedBuffers_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"shared_buffers", "524288", "8kB", "configuration file", "16384", "524288", "postmaster", "integer", "f"},
+	)
+
+	report, err := sharedbuffers.New(db.New(fx)).Check(withMemory(check.ProviderSelfManaged, 16))
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Equal(t, "shared_buffers is 4.0GiB, 25.0% of 16.0GiB system memory", report.Results[0].Details)
+}
+
+func Test_SharedBuffers_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := sharedbuffers.New(&mockSharedBuffersQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "shared-buffers")
+}
+
+func Test_SharedBuffers_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := sharedbuffers.Metadata()
+
+	require.Equal(t, "shared-buffers", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
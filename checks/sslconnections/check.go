@@ -0,0 +1,249 @@
+// Package sslconnections implements checks for the encryption of current
+// client connections: whether ssl is enabled, which connections arrive in
+// plaintext over the network, and which TLS versions are in use.
+package sslconnections
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const minTLSVersion = "TLSv1.2"
+
+// deprecatedTLSVersions are protocol versions with known weaknesses, removed
+// from most TLS libraries' defaults.
+var deprecatedTLSVersions = map[string]bool{
+	"SSLv3":   true,
+	"TLSv1":   true,
+	"TLSv1.1": true,
+}
+
+type SSLConnectionsQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+	ClientConnectionSSL(context.Context) ([]db.ClientConnectionSSLRow, error)
+}
+
+type checker struct {
+	queries SSLConnectionsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySecurity,
+		CheckID:     "ssl-connections",
+		Name:        "SSL Connections",
+		Description: "Reports whether ssl is enabled, current plaintext connections from remote addresses, and the TLS versions in use",
+		Readme:      readme,
+		SQL:         querySQL + "\n" + catalog.SQL,
+	}
+}
+
+func New(queries SSLConnectionsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	connections, err := c.queries.ClientConnectionSSL(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+
+	checkSSLEnabled(settings, report)
+	checkPlaintextConnections(connections, report)
+	checkTLSVersions(connections, settings, check.ProviderFromContext(ctx), report)
+
+	return report, nil
+}
+
+// checkSSLEnabled warns when the server does not accept TLS at all. Turning
+// it on needs a certificate and key, so there is no fix SQL.
+func checkSSLEnabled(settings map[string]db.CatalogSettingsRow, report *check.Report) {
+	ssl := settings["ssl"].Setting.String
+	if ssl == "on" {
+		report.AddFinding(check.Finding{
+			ID:       "ssl-enabled",
+			Name:     "SSL Enabled",
+			Severity: check.SeverityOK,
+			Details:  "The server accepts TLS connections",
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "ssl-enabled",
+		Name:     "SSL Enabled",
+		Severity: check.SeverityWarn,
+		Details:  "ssl is off: every connection, including passwords and query results, crosses the network in plaintext",
+		Object:   &check.Object{Kind: check.ObjectSetting, Name: "ssl"},
+		Remediation: "Install a certificate and key (ssl_cert_file, ssl_key_file), set ssl = on and reload, " +
+			"then require TLS with hostssl lines in pg_hba.conf",
+		Comparison: &check.Comparison{
+			Setting:  "ssl",
+			Actual:   ssl,
+			Expected: "on",
+		},
+	})
+}
+
+// checkPlaintextConnections warns on unencrypted connections from anywhere
+// but the server itself. Unix sockets and loopback never leave the host.
+func checkPlaintextConnections(connections []db.ClientConnectionSSLRow, report *check.Report) {
+	var total, local, remote int64
+	var tableRows []check.TableRow
+	severity := check.SeverityOK
+	for _, conn := range connections {
+		total += conn.Connections
+		if conn.Ssl {
+			continue
+		}
+		if conn.IsLocal {
+			local += conn.Connections
+			continue
+		}
+
+		addr, rowSeverity := conn.ClientAddr, check.SeverityWarn
+		if conn.AddressHidden {
+			addr, rowSeverity = "not visible", check.SeverityOK
+		} else {
+			remote += conn.Connections
+		}
+		severity = max(severity, rowSeverity)
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{conn.UserName, addr, strconv.FormatInt(conn.Connections, 10)},
+			Severity: rowSeverity,
+		})
+	}
+
+	metrics := []check.Metric{
+		{Name: "plaintext_remote_connections", Value: float64(remote), Unit: check.UnitCount},
+	}
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "plaintext-connections",
+			Name:     "Plaintext Connections",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No remote connection is unencrypted (%d client connection(s), %d local without TLS)", total, local),
+			Metrics:  metrics,
+		})
+		return
+	}
+
+	details := fmt.Sprintf("%d of %d client connection(s) from remote addresses are not encrypted: "+
+		"passwords, queries and results can be read or altered on the network", remote, total)
+	if severity == check.SeverityOK {
+		details = "Some unencrypted connections come from addresses the connected role may not see; " +
+			"grant it pg_read_all_stats to tell whether they are remote"
+	}
+	report.AddFinding(check.Finding{
+		ID:          "plaintext-connections",
+		Name:        "Plaintext Connections",
+		Severity:    severity,
+		Details:     details,
+		Remediation: "Require TLS with hostssl lines (or hostnossl ... reject) in pg_hba.conf and set sslmode=verify-full in clients",
+		Table: &check.Table{
+			Headers: []string{"User", "Client Address", "Connections"},
+			Rows:    tableRows,
+		},
+		Metrics: metrics,
+	})
+}
+
+// checkTLSVersions summarizes the protocol versions and ciphers of encrypted
+// connections, and warns on versions older than TLS 1.2.
+func checkTLSVersions(connections []db.ClientConnectionSSLRow, settings map[string]db.CatalogSettingsRow,
+	provider check.Provider, report *check.Report) {
+	type tls struct{ version, cipher string }
+	counts := make(map[tls]int64)
+	var order []tls
+	deprecated := false
+	for _, conn := range connections {
+		if !conn.Ssl {
+			continue
+		}
+		key := tls{conn.TlsVersion, conn.Cipher}
+		if _, ok := counts[key]; !ok {
+			order = append(order, key)
+		}
+		counts[key] += conn.Connections
+		deprecated = deprecated || deprecatedTLSVersions[conn.TlsVersion]
+	}
+
+	if len(order) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "tls-versions",
+			Name:     "TLS Versions",
+			Severity: check.SeverityOK,
+			Details:  "No current connection uses TLS",
+		})
+		return
+	}
+
+	tableRows := make([]check.TableRow, 0, len(order))
+	for _, key := range order {
+		rowSeverity := check.SeverityOK
+		if deprecatedTLSVersions[key.version] {
+			rowSeverity = check.SeverityWarn
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{key.version, key.cipher, strconv.FormatInt(counts[key], 10)},
+			Severity: rowSeverity,
+		})
+	}
+	table := &check.Table{
+		Headers: []string{"TLS Version", "Cipher", "Connections"},
+		Rows:    tableRows,
+	}
+
+	if !deprecated {
+		report.AddFinding(check.Finding{
+			ID:       "tls-versions",
+			Name:     "TLS Versions",
+			Severity: check.SeverityOK,
+			Details:  "Encrypted connections use TLS 1.2 or later",
+			Table:    table,
+		})
+		return
+	}
+
+	finding := check.Finding{
+		ID:       "tls-versions",
+		Name:     "TLS Versions",
+		Severity: check.SeverityWarn,
+		Details: "Some connections use TLS 1.1 or older, which have known weaknesses; " +
+			"clients that cannot negotiate TLS 1.2 need their driver or OpenSSL upgraded",
+		Table: table,
+	}
+	// ssl_min_protocol_version exists since PostgreSQL 12.
+	if _, ok := settings["ssl_min_protocol_version"]; ok {
+		finding.Object = &check.Object{Kind: check.ObjectSetting, Name: "ssl_min_protocol_version"}
+		finding.Remediation, finding.FixSQL = provider.SettingFix("ssl_min_protocol_version", minTLSVersion)
+	} else {
+		finding.Remediation = "Upgrade the clients to TLS 1.2 and restrict ssl_ciphers on the server"
+	}
+	report.AddFinding(finding)
+}
@@ -0,0 +1,224 @@
+package sslconnections_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/sslconnections"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockSSLConnectionsQueryer struct {
+	settings    []db.CatalogSettingsRow
+	connections []db.ClientConnectionSSLRow
+	err         error
+}
+
+func (m *mockSSLConnectionsQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func (m *mockSSLConnectionsQueryer) ClientConnectionSSL(context.Context) ([]db.ClientConnectionSSLRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.connections, nil
+}
+
+func setting(name, value string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+	}
+}
+
+func encrypted(user, version string, n int64) db.ClientConnectionSSLRow {
+	return db.ClientConnectionSSLRow{
+		UserName:    user,
+		ClientAddr:  "10.0.0.5",
+		Ssl:         true,
+		TlsVersion:  version,
+		Cipher:      "TLS_AES_256_GCM_SHA384",
+		Connections: n,
+	}
+}
+
+func plaintext(user, addr string, n int64) db.ClientConnectionSSLRow {
+	return db.ClientConnectionSSLRow{UserName: user, ClientAddr: addr, Connections: n}
+}
+
+func Test_SSLConnections(t *testing.T) {
+	t.Parallel()
+
+	socket := db.ClientConnectionSSLRow{UserName: "postgres", IsLocal: true, Connections: 2}
+	hidden := db.ClientConnectionSSLRow{UserName: "app", AddressHidden: true, Connections: 4}
+
+	tests := []struct {
+		name        string
+		ssl         string
+		connections []db.ClientConnectionSSLRow
+		enabled     check.Severity
+		plaintext   check.Severity
+		versions    check.Severity
+	}{
+		{
+			name:        "all remote connections encrypted",
+			ssl:         "on",
+			connections: []db.ClientConnectionSSLRow{encrypted("app", "TLSv1.3", 20), socket},
+			enabled:     check.SeverityOK,
+			plaintext:   check.SeverityOK,
+			versions:    check.SeverityOK,
+		},
+		{
+			name:        "ssl off",
+			ssl:         "off",
+			connections: []db.ClientConnectionSSLRow{plaintext("app", "10.0.0.5", 20)},
+			enabled:     check.SeverityWarn,
+			plaintext:   check.SeverityWarn,
+			versions:    check.SeverityOK,
+		},
+		{
+			name:        "plaintext over the network while ssl is on",
+			ssl:         "on",
+			connections: []db.ClientConnectionSSLRow{encrypted("app", "TLSv1.3", 20), plaintext("batch", "10.0.3.7", 3)},
+			enabled:     check.SeverityOK,
+			plaintext:   check.SeverityWarn,
+			versions:    check.SeverityOK,
+		},
+		{
+			name:        "address not visible",
+			ssl:         "on",
+			connections: []db.ClientConnectionSSLRow{hidden},
+			enabled:     check.SeverityOK,
+			plaintext:   check.SeverityOK,
+			versions:    check.SeverityOK,
+		},
+		{
+			name:        "deprecated TLS version",
+			ssl:         "on",
+			connections: []db.ClientConnectionSSLRow{encrypted("app", "TLSv1.3", 20), encrypted("legacy", "TLSv1", 1)},
+			enabled:     check.SeverityOK,
+			plaintext:   check.SeverityOK,
+			versions:    check.SeverityWarn,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := sslconnections.New(&mockSSLConnectionsQueryer{
+				settings:    []db.CatalogSettingsRow{setting("ssl", tt.ssl)},
+				connections: tt.connections,
+			})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 3)
+			require.Equal(t, tt.enabled, checktest.Finding(t, report, "ssl-enabled").Severity)
+			require.Equal(t, tt.plaintext, checktest.Finding(t, report, "plaintext-connections").Severity)
+			require.Equal(t, tt.versions, checktest.Finding(t, report, "tls-versions").Severity)
+		})
+	}
+}
+
+func Test_SSLConnections_Details(t *testing.T) {
+	t.Parallel()
+
+	checker := sslconnections.New(&mockSSLConnectionsQueryer{
+		settings: []db.CatalogSettingsRow{setting("ssl", "on"), setting("ssl_min_protocol_version", "TLSv1")},
+		connections: []db.ClientConnectionSSLRow{
+			encrypted("app", "TLSv1.3", 20),
+			plaintext("batch", "10.0.3.7", 3),
+			encrypted("app", "TLSv1.3", 5),
+			encrypted("legacy", "TLSv1.1", 1),
+			{UserName: "postgres", IsLocal: true, Connections: 2},
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	plain := checktest.Finding(t, report, "plaintext-connections")
+	require.Contains(t, plain.Details, "3 of 31 client connection(s)")
+	require.Equal(t, []string{"batch", "10.0.3.7", "3"}, plain.Table.Rows[0].Cells)
+	require.Equal(t, float64(3), plain.Metrics[0].Value)
+
+	versions := checktest.Finding(t, report, "tls-versions")
+	require.Equal(t, []string{"TLSv1.3", "TLS_AES_256_GCM_SHA384", "25"}, versions.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityWarn, versions.Table.Rows[1].Severity)
+	require.Equal(t, "ALTER SYSTEM SET ssl_min_protocol_version = 'TLSv1.2';\nSELECT pg_reload_conf();", versions.FixSQL)
+}
+
+func Test_SSLConnections_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := sslconnections.New(&mockSSLConnectionsQueryer{
+		settings:    []db.CatalogSettingsRow{setting("ssl", "on"), setting("ssl_min_protocol_version", "TLSv1")},
+		connections: []db.ClientConnectionSSLRow{encrypted("legacy", "TLSv1", 1)},
+	}).Check(ctx)
+	require.NoError(t, err)
+
+	versions := checktest.Finding(t, report, "tls-versions")
+	require.Empty(t, versions.FixSQL)
+	require.Equal(t, "Set ssl_min_protocol_version to TLSv1.2 in the DB parameter group", versions.Remediation)
+}
+
+func Test_SSLConnections_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogSettings",
+			[]string{
+				"name text", "setting text", "unit text", "source text", "boot_val text",
+				"reset_val text", "context text", "vartype text", "pending_restart bool",
+			},
+			[]any{"ssl", "on", nil, "configuration file", "off", "on", "sighup", "bool", "f"},
+		).
+		Add("ClientConnectionSSL",
+			[]string{
+				"user_name text", "client_addr text", "address_hidden bool", "is_local bool",
+				"ssl bool", "tls_version text", "cipher text", "connections int8",
+			},
+			[]any{"app", "10.1.2.3", "f", "f", "t", "TLSv1.3", "TLS_AES_128_GCM_SHA256", 12},
+			[]any{"report", "192.168.4.20", "f", "f", "f", "", "", 2},
+		)
+
+	report, err := sslconnections.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "ssl-enabled").Severity)
+	require.Equal(t, []string{"report", "192.168.4.20", "2"}, checktest.Finding(t, report, "plaintext-connections").Table.Rows[0].Cells)
+	require.Equal(t, []string{"TLSv1.3", "TLS_AES_128_GCM_SHA256", "12"}, checktest.Finding(t, report, "tls-versions").Table.Rows[0].Cells)
+}
+
+func Test_SSLConnections_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := sslconnections.New(&mockSSLConnectionsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "ssl-connections")
+}
+
+func Test_SSLConnections_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := sslconnections.Metadata()
+
+	require.Equal(t, "ssl-connections", metadata.CheckID)
+	require.Equal(t, check.CategorySecurity, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,19 @@
+-- name: ClientConnectionSSL :many
+-- Current client connections grouped by user, client address and TLS
+-- parameters. client_port is -1 for Unix sockets and NULL when the connected
+-- role may not see the address of another role's session (it needs
+-- pg_read_all_stats); such connections are reported as not visible.
+SELECT
+  coalesce(a.usename, '')::text AS user_name
+  , coalesce(host(a.client_addr), '')::text AS client_addr
+  , a.client_port IS NULL AS address_hidden
+  , coalesce(a.client_port = -1 OR a.client_addr <<= '127.0.0.0/8'::inet OR a.client_addr = '::1'::inet, false) AS is_local
+  , s.ssl
+  , coalesce(s.version, '')::text AS tls_version
+  , coalesce(s.cipher, '')::text AS cipher
+  , count(*)::bigint AS connections
+FROM pg_stat_activity AS a
+INNER JOIN pg_stat_ssl AS s ON a.pid = s.pid
+WHERE a.backend_type = 'client backend'
+GROUP BY 1, 2, 3, 4, 5, 6, 7
+ORDER BY connections DESC, user_name, client_addr;
@@ -0,0 +1,210 @@
+// Package statisticstarget implements checks for the planner statistics
+// target, server-wide and per column.
+package statisticstarget
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultTarget = 100
+
+	// Below this, histograms and most-common-value lists are too coarse for
+	// skewed or large tables.
+	lowDefaultTarget = 50
+	// Above this, every ANALYZE samples hundreds of thousands of rows per
+	// table and the planner reads larger statistics for every query.
+	highDefaultTarget = 1000
+	// Per-column targets are meant to be raised, so only flag the far end.
+	highColumnTarget = 2500
+
+	// ANALYZE samples 300 rows per unit of statistics target.
+	rowsPerTarget = 300
+)
+
+const defaultRemediation = "Keep default_statistics_target near the default and raise the target only on the columns " +
+	"whose estimates are off, with ALTER TABLE ... ALTER COLUMN ... SET STATISTICS"
+
+const columnRemediation = "Reset overrides nobody can explain to the server default; " +
+	"a target of 0 leaves the planner guessing selectivity for the column"
+
+type StatisticsTargetQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+	ColumnStatisticsTargets(context.Context) ([]db.ColumnStatisticsTargetsRow, error)
+}
+
+type checker struct {
+	queries StatisticsTargetQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "statistics-target",
+		Name:        "Statistics Target",
+		Description: "Flags an extreme default_statistics_target and lists per-column statistics target overrides",
+		Readme:      readme,
+		SQL:         querySQL + "\n" + catalog.SQL,
+	}
+}
+
+func New(queries StatisticsTargetQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	settingRows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	columns, err := c.queries.ColumnStatisticsTargets(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	columns = check.FilterTables(ctx, columns, func(r db.ColumnStatisticsTargetsRow) string {
+		return r.SchemaName + "." + r.TableName
+	})
+
+	setting := catalog.Settings(settingRows)["default_statistics_target"]
+	target, err := strconv.ParseInt(setting.Setting.String, 10, 64)
+	if err != nil {
+		target = defaultTarget
+	}
+
+	checkDefaultTarget(target, check.ProviderFromContext(ctx), report)
+	checkColumnTargets(columns, target, report)
+
+	return report, nil
+}
+
+// checkDefaultTarget warns when the server-wide target is far from the
+// default in either direction.
+func checkDefaultTarget(target int64, provider check.Provider, report *check.Report) {
+	var details string
+	switch {
+	case target < lowDefaultTarget:
+		details = fmt.Sprintf("default_statistics_target = %d: histograms and most-common-value lists this small "+
+			"give poor row estimates on large or skewed tables", target)
+	case target > highDefaultTarget:
+		details = fmt.Sprintf("default_statistics_target = %d: ANALYZE samples %s rows per table, "+
+			"and planning reads the larger statistics for every query on every column", target, check.FormatNumber(target*rowsPerTarget))
+	default:
+		report.AddFinding(check.Finding{
+			ID:       "default-statistics-target",
+			Name:     "Default Statistics Target",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("default_statistics_target = %d", target),
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.SettingFix("default_statistics_target", strconv.Itoa(defaultTarget))
+	report.AddFinding(check.Finding{
+		ID:          "default-statistics-target",
+		Name:        "Default Statistics Target",
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "default_statistics_target"},
+		Remediation: remediation + ". " + defaultRemediation,
+		FixSQL:      fixSQL,
+		Comparison: &check.Comparison{
+			Setting:  "default_statistics_target",
+			Actual:   strconv.FormatInt(target, 10),
+			Expected: strconv.Itoa(defaultTarget),
+		},
+	})
+}
+
+// checkColumnTargets lists every per-column override so drift is visible,
+// warning on columns without statistics and on extreme targets.
+func checkColumnTargets(columns []db.ColumnStatisticsTargetsRow, serverTarget int64, report *check.Report) {
+	if len(columns) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "column-statistics-targets",
+			Name:     "Column Statistics Targets",
+			Severity: check.SeverityOK,
+			Details:  "No columns override the statistics target",
+		})
+		return
+	}
+
+	var warnRows, okRows []check.TableRow
+	var fixes []string
+	tables := map[string]bool{}
+	for _, col := range columns {
+		table := col.SchemaName + "." + col.TableName
+		tables[table] = true
+
+		target := int64(col.StatTarget)
+		severity := check.SeverityWarn
+		var note string
+		switch {
+		case target == 0:
+			note = "no statistics collected"
+		case target > highColumnTarget:
+			note = fmt.Sprintf("ANALYZE samples %s rows", check.FormatNumber(target*rowsPerTarget))
+		case target == serverTarget:
+			severity, note = check.SeverityOK, "same as default_statistics_target"
+		default:
+			severity, note = check.SeverityOK, "-"
+		}
+
+		row := check.TableRow{
+			Cells:    []string{table, col.ColumnName, col.DataType, strconv.FormatInt(target, 10), note},
+			Severity: severity,
+		}
+		if severity == check.SeverityOK {
+			okRows = append(okRows, row)
+			continue
+		}
+		warnRows = append(warnRows, row)
+		fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s ALTER COLUMN %s SET STATISTICS -1;",
+			pgx.Identifier{col.SchemaName, col.TableName}.Sanitize(),
+			pgx.Identifier{col.ColumnName}.Sanitize()))
+	}
+
+	finding := check.Finding{
+		ID:       "column-statistics-targets",
+		Name:     "Column Statistics Targets",
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%d column(s) on %d table(s) override the statistics target", len(columns), len(tables)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Type", "Target", "Note"},
+			Rows:    append(warnRows, okRows...),
+		},
+		Metrics: []check.Metric{
+			{Name: "statistics_target_overrides", Value: float64(len(columns)), Unit: check.UnitCount},
+		},
+	}
+	if len(warnRows) > 0 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%d of %d column statistics target override(s) disable statistics or exceed %d",
+			len(warnRows), len(columns), highColumnTarget)
+		finding.Remediation = columnRemediation
+		finding.FixSQL = strings.Join(fixes, "\n")
+	}
+	report.AddFinding(finding)
+}
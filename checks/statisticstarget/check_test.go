@@ -0,0 +1,181 @@
+package statisticstarget_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/statisticstarget"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockStatisticsTargetQueryer struct {
+	target  string
+	columns []db.ColumnStatisticsTargetsRow
+	err     error
+}
+
+func (m *mockStatisticsTargetQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return []db.CatalogSettingsRow{{
+		Name:    pgtype.Text{String: "default_statistics_target", Valid: true},
+		Setting: pgtype.Text{String: m.target, Valid: true},
+	}}, nil
+}
+
+func (m *mockStatisticsTargetQueryer) ColumnStatisticsTargets(context.Context) ([]db.ColumnStatisticsTargetsRow, error) {
+	return m.columns, nil
+}
+
+func column(table, name string, target int32) db.ColumnStatisticsTargetsRow {
+	return db.ColumnStatisticsTargetsRow{
+		SchemaName: "public",
+		TableName:  table,
+		ColumnName: name,
+		DataType:   "text",
+		StatTarget: target,
+	}
+}
+
+func Test_StatisticsTarget_Default(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Target           string
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "default - OK",
+			Target:           "100",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "default_statistics_target = 100",
+		},
+		{
+			Name:             "raised moderately - OK",
+			Target:           "500",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "default_statistics_target = 500",
+		},
+		{
+			Name:             "too low warns",
+			Target:           "10",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "default_statistics_target = 10: histograms and most-common-value lists this small",
+		},
+		{
+			Name:             "too high warns",
+			Target:           "5000",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "default_statistics_target = 5000: ANALYZE samples 1.5M rows per table",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := statisticstarget.New(&mockStatisticsTargetQueryer{target: tc.Target}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "default-statistics-target")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+			if tc.ExpectedSeverity == check.SeverityWarn {
+				require.Equal(t, "ALTER SYSTEM SET default_statistics_target = '100';\nSELECT pg_reload_conf();", finding.FixSQL)
+			}
+		})
+	}
+}
+
+func Test_StatisticsTarget_Columns(t *testing.T) {
+	t.Parallel()
+
+	report, err := statisticstarget.New(&mockStatisticsTargetQueryer{target: "100"}).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, "No columns override the statistics target", checktest.Finding(t, report, "column-statistics-targets").Details)
+
+	report, err = statisticstarget.New(&mockStatisticsTargetQueryer{
+		target:  "100",
+		columns: []db.ColumnStatisticsTargetsRow{column("orders", "status", 1000), column("orders", "note", 100)},
+	}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "column-statistics-targets")
+	require.Equal(t, check.SeverityOK, finding.Severity)
+	require.Equal(t, "2 column(s) on 1 table(s) override the statistics target", finding.Details)
+	require.Equal(t, []string{"public.orders", "note", "text", "100", "same as default_statistics_target"}, finding.Table.Rows[1].Cells)
+}
+
+func Test_StatisticsTarget_ExtremeColumns(t *testing.T) {
+	t.Parallel()
+
+	checker := statisticstarget.New(&mockStatisticsTargetQueryer{
+		target: "100",
+		columns: []db.ColumnStatisticsTargetsRow{
+			column("orders", "status", 500),
+			column("events", "Payload", 0),
+			column("events", "kind", 10000),
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "column-statistics-targets")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "2 of 3 column statistics target override(s) disable statistics or exceed 2500", finding.Details)
+	require.Equal(t, []string{"public.events", "Payload", "text", "0", "no statistics collected"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"public.events", "kind", "text", "10000", "ANALYZE samples 3.0M rows"}, finding.Table.Rows[1].Cells)
+	require.Equal(t, []string{"public.orders", "status", "text", "500", "-"}, finding.Table.Rows[2].Cells)
+	require.Equal(t, "ALTER TABLE \"public\".\"events\" ALTER COLUMN \"Payload\" SET STATISTICS -1;\n"+
+		"ALTER TABLE \"public\".\"events\" ALTER COLUMN \"kind\" SET STATISTICS -1;", finding.FixSQL)
+}
+
+func Test_StatisticsTarget_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogSettings",
+			[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+			[]any{"default_statistics_target", "100", nil, "default", "100", "100", "user", "integer", "f"},
+		).
+		Add("ColumnStatisticsTargets",
+			[]string{"schema_name text", "table_name text", "column_name text", "data_type text", "stat_target int4"},
+			[]any{"public", "orders", "status", "text", 0},
+		)
+
+	report, err := statisticstarget.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "default-statistics-target").Severity)
+}
+
+func Test_StatisticsTarget_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := statisticstarget.New(&mockStatisticsTargetQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "statistics-target")
+}
+
+func Test_StatisticsTarget_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := statisticstarget.Metadata()
+
+	require.Equal(t, "statistics-target", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
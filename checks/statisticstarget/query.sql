@@ -0,0 +1,19 @@
+-- name: ColumnStatisticsTargets :many
+-- Columns with a per-column statistics target (ALTER TABLE ... ALTER COLUMN
+-- ... SET STATISTICS). The default is -1 before PostgreSQL 17 and NULL since.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , format_type(a.atttypid, a.atttypmod)::text AS data_type
+  , a.attstattarget::int AS stat_target
+FROM pg_attribute AS a
+INNER JOIN pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+  AND a.attstattarget >= 0
+ORDER BY n.nspname, c.relname, a.attnum;
@@ -5,19 +5,26 @@ import (
 	"context"
 	_ "embed"
 	"fmt"
+	"sort"
+	"strings"
 
+	"github.com/emancu/pgdoctor/catalog"
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 )
 
-//go:embed query.sql
-var querySQL string
-
 //go:embed README.md
 var readme string
 
+// hotRemediation covers both reasons an update is not HOT: no free space on
+// the page, or a changed indexed column.
+const hotRemediation = "Updates are HOT only when no indexed column changes and the new row version fits on the same page. " +
+	"A lower fillfactor reserves that space on new pages (existing pages need a rewrite, e.g. pg_repack); " +
+	"if fillfactor is already lowered, drop or narrow indexes on frequently updated columns"
+
 type TableActivityQueries interface {
-	TableActivity(context.Context) ([]db.TableActivityRow, error)
+	CatalogTables(context.Context) ([]db.CatalogTablesRow, error)
 }
 
 type checker struct {
@@ -31,7 +38,7 @@ func Metadata() check.Metadata {
 		Name:        "Table Activity",
 		Description: "Analyzes table write activity to identify high-churn tables and HOT update efficiency issues",
 		Readme:      readme,
-		SQL:         querySQL,
+		SQL:         catalog.SQL,
 	}
 }
 
@@ -48,10 +55,12 @@ func (c *checker) Metadata() check.Metadata {
 func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	report := check.NewReport(Metadata())
 
-	rows, err := c.queries.TableActivity(ctx)
+	tables, err := c.queries.CatalogTables(ctx)
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryPerformance, report.CheckID, err)
 	}
+	rows := writtenTables(tables)
+	rows = check.FilterTables(ctx, rows, func(r db.CatalogTablesRow) string { return r.SchemaName.String + "." + r.TableName.String })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -63,20 +72,35 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkHighChurnTables(rows, report)
+	checkHighChurnTables(ctx, rows, report)
 	checkLowHOTRatio(rows, report)
 
 	return report, nil
 }
 
+// writtenTables returns the tables with any write activity, busiest first.
+func writtenTables(tables []db.CatalogTablesRow) []db.CatalogTablesRow {
+	var rows []db.CatalogTablesRow
+	for _, table := range tables {
+		if totalWrites(table) > 0 {
+			rows = append(rows, table)
+		}
+	}
+	sort.SliceStable(rows, func(i, j int) bool { return totalWrites(rows[i]) > totalWrites(rows[j]) })
+	return rows
+}
+
+func totalWrites(row db.CatalogTablesRow) int64 {
+	return check.Int8ToInt64(row.NTupIns) + check.Int8ToInt64(row.NTupUpd) + check.Int8ToInt64(row.NTupDel)
+}
+
 // checkHighChurnTables identifies tables with excessive write activity.
-func checkHighChurnTables(rows []db.TableActivityRow, report *check.Report) {
+func checkHighChurnTables(ctx context.Context, rows []db.CatalogTablesRow, report *check.Report) {
 	const highChurnThreshold = int64(1_000_000) // 1M writes
 
-	var highChurn []db.TableActivityRow
+	var highChurn []db.CatalogTablesRow
 	for _, row := range rows {
-		totalWrites := check.Int8ToInt64(row.NTupIns) + check.Int8ToInt64(row.NTupUpd) + check.Int8ToInt64(row.NTupDel)
-		if totalWrites > highChurnThreshold {
+		if totalWrites(row) > highChurnThreshold {
 			highChurn = append(highChurn, row)
 		}
 	}
@@ -95,16 +119,15 @@ func checkHighChurnTables(rows []db.TableActivityRow, report *check.Report) {
 	var tableRows []check.TableRow
 
 	for _, row := range highChurn {
-		totalWrites := check.Int8ToInt64(row.NTupIns) + check.Int8ToInt64(row.NTupUpd) + check.Int8ToInt64(row.NTupDel)
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
-				row.Schemaname.String,
-				row.Relname.String,
+				row.SchemaName.String,
+				row.TableName.String,
 				check.FormatNumber(check.Int8ToInt64(row.NTupIns)),
 				check.FormatNumber(check.Int8ToInt64(row.NTupUpd)),
 				check.FormatNumber(check.Int8ToInt64(row.NTupDel)),
-				check.FormatNumber(totalWrites),
-				check.FormatBytes(check.Int8ToInt64(row.TableSizeBytes)),
+				check.FormatNumber(totalWrites(row)),
+				check.FormatSize(ctx, check.Int8ToInt64(row.TableSizeBytes)),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -122,20 +145,28 @@ func checkHighChurnTables(rows []db.TableActivityRow, report *check.Report) {
 	})
 }
 
-// checkLowHOTRatio identifies tables with poor HOT update efficiency.
-func checkLowHOTRatio(rows []db.TableActivityRow, report *check.Report) {
+// checkLowHOTRatio identifies update-heavy tables with poor HOT update
+// efficiency. Tables still at the default fillfactor get a lower one
+// suggested; for the others, free space is not the limit, indexed columns are.
+func checkLowHOTRatio(rows []db.CatalogTablesRow, report *check.Report) {
 	const (
 		minRows     = int64(1_000_000) // Only check tables with >1M rows
 		minUpdates  = int64(1000)      // Need meaningful update count
 		lowHOTRatio = float64(50)      // Below 50% is concerning
+
+		// Tables this update-heavy are checked whatever their size: small
+		// queue and counter tables bloat fastest without HOT.
+		heavyUpdates = int64(1_000_000)
+
+		suggestedFillfactor = 90
 	)
 
-	var lowHOT []db.TableActivityRow
+	var lowHOT []db.CatalogTablesRow
 	for _, row := range rows {
 		liveTup := check.Int8ToInt64(row.NLiveTup)
 		nTupUpd := check.Int8ToInt64(row.NTupUpd)
 
-		if liveTup < minRows || nTupUpd < minUpdates {
+		if (liveTup < minRows || nTupUpd < minUpdates) && nTupUpd < heavyUpdates {
 			continue
 		}
 
@@ -150,34 +181,52 @@ func checkLowHOTRatio(rows []db.TableActivityRow, report *check.Report) {
 			ID:       "low-hot-ratio",
 			Name:     "HOT Update Efficiency",
 			Severity: check.SeverityOK,
-			Details:  "All large tables have acceptable HOT update ratio (>50%)",
+			Details:  "All update-heavy tables have acceptable HOT update ratio (>50%)",
 		})
 		return
 	}
 
-	headers := []string{"Schema", "Table", "HOT Ratio", "Updates", "HOT Updates", "Live Rows"}
+	// Most index-updating (non-HOT) updates first.
+	sort.SliceStable(lowHOT, func(i, j int) bool { return nonHOTUpdates(lowHOT[i]) > nonHOTUpdates(lowHOT[j]) })
+
+	headers := []string{"Schema", "Table", "HOT Ratio", "Updates", "HOT Updates", "Live Rows", "Fillfactor", "Suggestion"}
 	var tableRows []check.TableRow
+	var fixes []string
 
 	for _, row := range lowHOT {
 		hotRatio := calculateHOTRatio(row)
+		fillfactor := "100"
+		suggestion := fmt.Sprintf("Lower fillfactor to %d", suggestedFillfactor)
+		if row.Fillfactor.Valid && row.Fillfactor.Int32 < 100 {
+			fillfactor = fmt.Sprintf("%d", row.Fillfactor.Int32)
+			suggestion = "Review indexes on updated columns"
+		} else {
+			fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s SET (fillfactor = %d);",
+				pgx.Identifier{row.SchemaName.String, row.TableName.String}.Sanitize(), suggestedFillfactor))
+		}
+
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
-				row.Schemaname.String,
-				row.Relname.String,
+				row.SchemaName.String,
+				row.TableName.String,
 				fmt.Sprintf("%.1f%%", hotRatio),
 				check.FormatNumber(check.Int8ToInt64(row.NTupUpd)),
 				check.FormatNumber(check.Int8ToInt64(row.NTupHotUpd)),
 				check.FormatNumber(check.Int8ToInt64(row.NLiveTup)),
+				fillfactor,
+				suggestion,
 			},
 			Severity: check.SeverityWarn,
 		})
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "low-hot-ratio",
-		Name:     "HOT Update Efficiency",
-		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("Found %d large table(s) with low HOT update ratio (<50%%)", len(lowHOT)),
+		ID:          "low-hot-ratio",
+		Name:        "HOT Update Efficiency",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("Found %d update-heavy table(s) with low HOT update ratio (<50%%)", len(lowHOT)),
+		Remediation: hotRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
 		Table: &check.Table{
 			Headers: headers,
 			Rows:    tableRows,
@@ -185,7 +234,11 @@ func checkLowHOTRatio(rows []db.TableActivityRow, report *check.Report) {
 	})
 }
 
-func calculateHOTRatio(row db.TableActivityRow) float64 {
+func nonHOTUpdates(row db.CatalogTablesRow) int64 {
+	return check.Int8ToInt64(row.NTupUpd) - check.Int8ToInt64(row.NTupHotUpd)
+}
+
+func calculateHOTRatio(row db.CatalogTablesRow) float64 {
 	nTupUpd := check.Int8ToInt64(row.NTupUpd)
 	if nTupUpd == 0 {
 		return 100.0 // No updates = perfect ratio
@@ -0,0 +1,128 @@
+package tableactivity_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/tableactivity"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	tables []db.CatalogTablesRow
+}
+
+func (m *mockQueryer) CatalogTables(context.Context) ([]db.CatalogTablesRow, error) {
+	return m.tables, nil
+}
+
+func makeTable(name string, inserts, updates, hotUpdates, liveRows int64) db.CatalogTablesRow {
+	return db.CatalogTablesRow{
+		SchemaName: pgtype.Text{String: "public", Valid: true},
+		TableName:  pgtype.Text{String: name, Valid: true},
+		NTupIns:    pgtype.Int8{Int64: inserts, Valid: true},
+		NTupUpd:    pgtype.Int8{Int64: updates, Valid: true},
+		NTupHotUpd: pgtype.Int8{Int64: hotUpdates, Valid: true},
+		NLiveTup:   pgtype.Int8{Int64: liveRows, Valid: true},
+	}
+}
+
+func Test_TableActivity(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name           string
+		tables         []db.CatalogTablesRow
+		wantChurn      []string
+		wantLowHOT     bool
+		wantNoActivity bool
+	}{
+		{
+			name:           "only untouched tables",
+			tables:         []db.CatalogTablesRow{makeTable("archive", 0, 0, 0, 5_000_000)},
+			wantNoActivity: true,
+		},
+		{
+			name: "busiest tables first",
+			tables: []db.CatalogTablesRow{
+				makeTable("events", 1_500_000, 0, 0, 100),
+				makeTable("archive", 0, 0, 0, 5_000_000),
+				makeTable("orders", 500_000, 3_000_000, 2_900_000, 100),
+			},
+			wantChurn: []string{"orders", "events"},
+		},
+		{
+			name:       "large table with few HOT updates",
+			tables:     []db.CatalogTablesRow{makeTable("users", 0, 10_000, 1_000, 2_000_000)},
+			wantLowHOT: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := tableactivity.New(&mockQueryer{tables: tt.tables}).Check(context.Background())
+			require.NoError(t, err)
+
+			if tt.wantNoActivity {
+				require.Len(t, report.Results, 1)
+				assert.Equal(t, "No table activity data available", report.Results[0].Details)
+				return
+			}
+
+			byID := map[string]check.Finding{}
+			for _, finding := range report.Results {
+				byID[finding.ID] = finding
+			}
+
+			churn := byID["high-churn-tables"]
+			if len(tt.wantChurn) == 0 {
+				assert.Equal(t, check.SeverityOK, churn.Severity)
+			} else {
+				require.NotNil(t, churn.Table)
+				var names []string
+				for _, row := range churn.Table.Rows {
+					names = append(names, row.Cells[1])
+				}
+				assert.Equal(t, tt.wantChurn, names)
+			}
+
+			if tt.wantLowHOT {
+				assert.Equal(t, check.SeverityWarn, byID["low-hot-ratio"].Severity)
+			} else {
+				assert.Equal(t, check.SeverityOK, byID["low-hot-ratio"].Severity)
+			}
+		})
+	}
+}
+
+func Test_TableActivity_LowHOTRatio(t *testing.T) {
+	t.Parallel()
+
+	queue := makeTable("jobs", 0, 2_000_000, 100_000, 5_000)
+	users := makeTable("users", 0, 10_000, 1_000, 2_000_000)
+	users.Fillfactor = pgtype.Int4{Int32: 80, Valid: true}
+
+	report, err := tableactivity.New(&mockQueryer{tables: []db.CatalogTablesRow{users, queue}}).Check(context.Background())
+	require.NoError(t, err)
+
+	var finding check.Finding
+	for _, f := range report.Results {
+		if f.ID == "low-hot-ratio" {
+			finding = f
+		}
+	}
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	assert.Equal(t, "Found 2 update-heavy table(s) with low HOT update ratio (<50%)", finding.Details)
+
+	// Small but update-heavy tables are checked too, most non-HOT updates first.
+	require.Len(t, finding.Table.Rows, 2)
+	assert.Equal(t, []string{"public", "jobs", "5.0%", "2.0M", "100.0K", "5.0K", "100", "Lower fillfactor to 90"}, finding.Table.Rows[0].Cells)
+	assert.Equal(t, []string{"public", "users", "10.0%", "10.0K", "1.0K", "2.0M", "80", "Review indexes on updated columns"}, finding.Table.Rows[1].Cells)
+	assert.Equal(t, `ALTER TABLE "public"."jobs" SET (fillfactor = 90);`, finding.FixSQL)
+}
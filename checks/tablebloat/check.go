@@ -33,6 +33,7 @@ func Metadata() check.Metadata {
 		Description: "Identifies tables with high dead tuple percentages indicating vacuum issues",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -53,6 +54,10 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryVacuum, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.TableBloatRow) string { return r.TableName.String })
+	rows = check.FilterSmallTables(ctx, rows, func(r db.TableBloatRow) (int64, int64) {
+		return r.LiveTuples.Int64, r.TotalSizeBytes.Int64
+	})
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -64,9 +69,9 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkHighDeadTuples(rows, report)
+	checkHighDeadTuples(ctx, rows, report)
 	checkStaleVacuum(rows, report)
-	checkLargeBloatedTables(rows, report)
+	checkLargeBloatedTables(ctx, rows, report)
 
 	return report, nil
 }
@@ -80,7 +85,7 @@ func getDeadTuplePercent(row db.TableBloatRow) float64 {
 }
 
 // checkHighDeadTuples identifies tables with >20% dead tuples.
-func checkHighDeadTuples(rows []db.TableBloatRow, report *check.Report) {
+func checkHighDeadTuples(ctx context.Context, rows []db.TableBloatRow, report *check.Report) {
 	var critical []db.TableBloatRow // >40%
 	var warning []db.TableBloatRow  // >20%
 
@@ -113,7 +118,7 @@ func checkHighDeadTuples(rows []db.TableBloatRow, report *check.Report) {
 				fmt.Sprintf("%.1f%%", getDeadTuplePercent(row)),
 				formatNumber(row.DeadTuples.Int64),
 				formatNumber(row.LiveTuples.Int64),
-				check.FormatBytes(row.TotalSizeBytes.Int64),
+				check.FormatSize(ctx, row.TotalSizeBytes.Int64),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -126,7 +131,7 @@ func checkHighDeadTuples(rows []db.TableBloatRow, report *check.Report) {
 				fmt.Sprintf("%.1f%%", getDeadTuplePercent(row)),
 				formatNumber(row.DeadTuples.Int64),
 				formatNumber(row.LiveTuples.Int64),
-				check.FormatBytes(row.TotalSizeBytes.Int64),
+				check.FormatSize(ctx, row.TotalSizeBytes.Int64),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -227,7 +232,7 @@ func checkStaleVacuum(rows []db.TableBloatRow, report *check.Report) {
 }
 
 // checkLargeBloatedTables identifies large tables with notable bloat.
-func checkLargeBloatedTables(rows []db.TableBloatRow, report *check.Report) {
+func checkLargeBloatedTables(ctx context.Context, rows []db.TableBloatRow, report *check.Report) {
 	const oneGB = int64(1024 * 1024 * 1024)
 	const tenGB = int64(10 * 1024 * 1024 * 1024)
 
@@ -257,16 +262,18 @@ func checkLargeBloatedTables(rows []db.TableBloatRow, report *check.Report) {
 
 	headers := []string{"Table", "Size", "Dead %", "Wasted Space (est)"}
 	var tableRows []check.TableRow
+	totalWasted := int64(0)
 
 	for _, row := range critical {
 		pct := getDeadTuplePercent(row)
 		wastedBytes := int64(float64(row.TotalSizeBytes.Int64) * pct / 100)
+		totalWasted += wastedBytes
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				row.TableName.String,
-				check.FormatBytes(row.TotalSizeBytes.Int64),
+				check.FormatSize(ctx, row.TotalSizeBytes.Int64),
 				fmt.Sprintf("%.1f%%", pct),
-				check.FormatBytes(wastedBytes),
+				check.FormatSize(ctx, wastedBytes),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -275,22 +282,24 @@ func checkLargeBloatedTables(rows []db.TableBloatRow, report *check.Report) {
 	for _, row := range warning {
 		pct := getDeadTuplePercent(row)
 		wastedBytes := int64(float64(row.TotalSizeBytes.Int64) * pct / 100)
+		totalWasted += wastedBytes
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				row.TableName.String,
-				check.FormatBytes(row.TotalSizeBytes.Int64),
+				check.FormatSize(ctx, row.TotalSizeBytes.Int64),
 				fmt.Sprintf("%.1f%%", pct),
-				check.FormatBytes(wastedBytes),
+				check.FormatSize(ctx, wastedBytes),
 			},
 			Severity: check.SeverityWarn,
 		})
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "large-bloated-tables",
-		Name:     "Large Table Bloat",
-		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("Found %d large table(s) with significant bloat, wasting disk space", len(critical)+len(warning)),
+		ID:               "large-bloated-tables",
+		Name:             "Large Table Bloat",
+		Severity:         check.SeverityWarn,
+		Details:          fmt.Sprintf("Found %d large table(s) with significant bloat, wasting disk space", len(critical)+len(warning)),
+		ReclaimableBytes: totalWasted,
 		Table: &check.Table{
 			Headers: headers,
 			Rows:    tableRows,
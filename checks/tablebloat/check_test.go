@@ -359,6 +359,27 @@ func TestTableBloat_EmptyResult(t *testing.T) {
 	assert.Contains(t, report.Results[0].Details, "No tables with significant dead tuples found")
 }
 
+func TestTableBloat_SkipsTablesBelowFloors(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{
+		rows: []db.TableBloatRow{
+			makeTableRow("public.countries", 200, 150, 42.9, 64*1024, nil, nil, 0),
+		},
+	}
+
+	scope := &check.Scope{MinRows: 1000, MinSizeBytes: 10 * check.MiB}
+	ctx := check.ContextWithScope(context.Background(), scope)
+
+	checker := tablebloat.New(queryer)
+	report, err := checker.Check(ctx)
+
+	require.NoError(t, err)
+	assert.Equal(t, check.SeverityOK, report.Severity)
+	require.Len(t, report.Results, 1)
+	assert.Equal(t, "table-bloat", report.Results[0].ID)
+}
+
 func TestTableBloat_Metadata(t *testing.T) {
 	t.Parallel()
 
@@ -0,0 +1,306 @@
+// Package tablereloptions implements a check for contradictory, ignored, or redundant per-table storage parameters.
+package tablereloptions
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// minDeadTuples avoids flagging looser thresholds on tables with little garbage.
+	minDeadTuples = 10_000
+	toastPrefix   = "toast."
+)
+
+// serverSettings maps each autovacuum reloption to the server setting it overrides.
+var serverSettings = map[string]string{
+	"autovacuum_enabled":                    "autovacuum",
+	"autovacuum_vacuum_threshold":           "autovacuum_vacuum_threshold",
+	"autovacuum_vacuum_scale_factor":        "autovacuum_vacuum_scale_factor",
+	"autovacuum_vacuum_insert_threshold":    "autovacuum_vacuum_insert_threshold",
+	"autovacuum_vacuum_insert_scale_factor": "autovacuum_vacuum_insert_scale_factor",
+	"autovacuum_analyze_threshold":          "autovacuum_analyze_threshold",
+	"autovacuum_analyze_scale_factor":       "autovacuum_analyze_scale_factor",
+	"autovacuum_vacuum_cost_delay":          "autovacuum_vacuum_cost_delay",
+	"autovacuum_vacuum_cost_limit":          "autovacuum_vacuum_cost_limit",
+	"autovacuum_freeze_max_age":             "autovacuum_freeze_max_age",
+	"autovacuum_multixact_freeze_max_age":   "autovacuum_multixact_freeze_max_age",
+	"autovacuum_freeze_min_age":             "vacuum_freeze_min_age",
+	"autovacuum_freeze_table_age":           "vacuum_freeze_table_age",
+	"autovacuum_multixact_freeze_min_age":   "vacuum_multixact_freeze_min_age",
+	"autovacuum_multixact_freeze_table_age": "vacuum_multixact_freeze_table_age",
+	"log_autovacuum_min_duration":           "log_autovacuum_min_duration",
+}
+
+type TableReloptionsQueries interface {
+	TableReloptions(context.Context) ([]db.TableReloptionsRow, error)
+	ReloptionServerSettings(context.Context) ([]db.ReloptionServerSettingsRow, error)
+}
+
+type checker struct {
+	queries TableReloptionsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryVacuum,
+		CheckID:     "table-reloptions",
+		Name:        "Table Storage Parameters",
+		Description: "Flags contradictory, ignored, or redundant per-table storage parameters",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries TableReloptionsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.TableReloptions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.TableReloptionsRow) string { return r.TableName.String })
+
+	settingRows, err := c.queries.ReloptionServerSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	server := make(map[string]string, len(settingRows))
+	for _, s := range settingRows {
+		server[s.Name.String] = normalizeValue(s.Setting.String)
+	}
+
+	checkReloptions(rows, server, report)
+
+	return report, nil
+}
+
+func checkReloptions(rows []db.TableReloptionsRow, server map[string]string, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "table-reloptions",
+			Name:     "Table Storage Parameters",
+			Severity: check.SeverityOK,
+			Details:  "No tables override storage parameters",
+		})
+		return
+	}
+
+	var issues check.ObjectIssues
+	normalized := make(map[string]string, len(rows))
+	for _, row := range rows {
+		table := row.TableName.String
+		options := parseOptions(row.Reloptions.String, row.ToastReloptions.String)
+		normalized[table] = formatOptions(options)
+		evaluate(table, row, options, server, &issues)
+	}
+
+	// Tables with issues first (worst severity first), then the remaining overrides.
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, issue := range issues.Issues() {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{issue.Object, normalized[issue.Object], issue.ReasonList()},
+			Severity: issue.Severity,
+		})
+		delete(normalized, issue.Object)
+	}
+	for _, row := range rows {
+		table := row.TableName.String
+		if options, ok := normalized[table]; ok {
+			tableRows = append(tableRows, check.TableRow{
+				Cells:    []string{table, options, "-"},
+				Severity: check.SeverityOK,
+			})
+		}
+	}
+
+	severity := issues.Severity()
+	details := fmt.Sprintf("%d table(s) override storage parameters", len(rows))
+	if severity > check.SeverityOK {
+		details = fmt.Sprintf("%d of %d table(s) with storage parameter overrides have contradictory or ignored settings", countAtLeast(issues, check.SeverityWarn), len(rows))
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "table-reloptions",
+		Name:     "Table Storage Parameters",
+		Severity: severity,
+		Details:  details,
+		Table: &check.Table{
+			Headers: []string{"Table", "Options", "Issues"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// evaluate records every problem with one table's options.
+func evaluate(table string, row db.TableReloptionsRow, options map[string]string, server map[string]string, issues *check.ObjectIssues) {
+	// Tuning autovacuum on a table where it is disabled does nothing.
+	if options["autovacuum_enabled"] == "false" {
+		var tuned []string
+		for key := range options {
+			if key != "autovacuum_enabled" && strings.HasPrefix(key, "autovacuum_") {
+				tuned = append(tuned, key)
+			}
+		}
+		sort.Strings(tuned)
+		if len(tuned) > 0 {
+			issues.Add(table, check.SeverityWarn,
+				fmt.Sprintf("autovacuum_enabled=false makes %s ineffective", strings.Join(tuned, ", ")))
+		}
+	}
+
+	// PostgreSQL ignores per-table freeze max ages above the server-wide setting.
+	for _, key := range []string{"autovacuum_freeze_max_age", "autovacuum_multixact_freeze_max_age"} {
+		value, ok := parseFloat(options[key])
+		limit, hasLimit := parseFloat(server[key])
+		if ok && hasLimit && value > limit {
+			issues.Add(table, check.SeverityWarn,
+				fmt.Sprintf("%s=%s exceeds the server setting (%s) and is ignored", key, options[key], server[key]))
+		}
+	}
+
+	checkLooserVacuumTrigger(table, row, options, server, issues)
+
+	if !row.HasToast {
+		for key := range options {
+			if strings.HasPrefix(key, toastPrefix) || key == "toast_tuple_target" {
+				issues.Add(table, check.SeverityOK, "TOAST options have no effect: the table has no TOAST relation")
+				break
+			}
+		}
+	}
+
+	for _, key := range sortedKeys(options) {
+		name, ok := serverSettings[key]
+		if !ok || options[key] != server[name] {
+			continue
+		}
+		issues.Add(table, check.SeverityOK, fmt.Sprintf("%s=%s is the same as the server setting", key, options[key]))
+	}
+}
+
+// checkLooserVacuumTrigger flags tables whose overrides delay autovacuum past the
+// point where the server-wide settings would already have vacuumed them.
+func checkLooserVacuumTrigger(table string, row db.TableReloptionsRow, options map[string]string, server map[string]string, issues *check.ObjectIssues) {
+	if options["autovacuum_enabled"] == "false" {
+		return
+	}
+	if _, ok := options["autovacuum_vacuum_scale_factor"]; !ok {
+		if _, ok := options["autovacuum_vacuum_threshold"]; !ok {
+			return
+		}
+	}
+
+	serverThreshold, ok1 := parseFloat(server["autovacuum_vacuum_threshold"])
+	serverScale, ok2 := parseFloat(server["autovacuum_vacuum_scale_factor"])
+	if !ok1 || !ok2 {
+		return
+	}
+	tableThreshold, ok := parseFloat(options["autovacuum_vacuum_threshold"])
+	if !ok {
+		tableThreshold = serverThreshold
+	}
+	tableScale, ok := parseFloat(options["autovacuum_vacuum_scale_factor"])
+	if !ok {
+		tableScale = serverScale
+	}
+
+	rows := float64(row.EstimatedRows.Int64)
+	dead := float64(row.NDeadTup.Int64)
+	serverTrigger := serverThreshold + serverScale*rows
+	tableTrigger := tableThreshold + tableScale*rows
+
+	if tableTrigger > serverTrigger && dead >= serverTrigger && dead >= minDeadTuples {
+		issues.Add(table, check.SeverityWarn,
+			fmt.Sprintf("autovacuum triggers at %s dead tuples (server settings: %s) while %s have piled up",
+				check.FormatNumber(int64(tableTrigger)), check.FormatNumber(int64(serverTrigger)), check.FormatNumber(row.NDeadTup.Int64)))
+	}
+}
+
+// parseOptions merges table and TOAST reloptions, prefixing the latter with "toast.".
+func parseOptions(reloptions, toastReloptions string) map[string]string {
+	options := map[string]string{}
+	add := func(list, prefix string) {
+		for _, option := range strings.Split(list, ",") {
+			key, value, ok := strings.Cut(option, "=")
+			if !ok {
+				continue
+			}
+			options[prefix+strings.ToLower(strings.TrimSpace(key))] = normalizeValue(value)
+		}
+	}
+	add(reloptions, "")
+	add(toastReloptions, toastPrefix)
+	return options
+}
+
+// normalizeValue canonicalizes boolean spellings so reloptions and pg_settings compare equal.
+func normalizeValue(value string) string {
+	value = strings.TrimSpace(value)
+	switch strings.ToLower(value) {
+	case "on", "true", "yes":
+		return "true"
+	case "off", "false", "no":
+		return "false"
+	}
+	if f, err := strconv.ParseFloat(value, 64); err == nil {
+		return strconv.FormatFloat(f, 'f', -1, 64)
+	}
+	return value
+}
+
+func formatOptions(options map[string]string) string {
+	keys := sortedKeys(options)
+	lines := make([]string, len(keys))
+	for i, key := range keys {
+		lines[i] = key + "=" + options[key]
+	}
+	return strings.Join(lines, "\n")
+}
+
+func sortedKeys(options map[string]string) []string {
+	keys := make([]string, 0, len(options))
+	for key := range options {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+func parseFloat(value string) (float64, bool) {
+	f, err := strconv.ParseFloat(value, 64)
+	return f, err == nil
+}
+
+func countAtLeast(issues check.ObjectIssues, severity check.Severity) int {
+	count := 0
+	for _, issue := range issues.Issues() {
+		if issue.Severity >= severity {
+			count++
+		}
+	}
+	return count
+}
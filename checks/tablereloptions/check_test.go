@@ -0,0 +1,193 @@
+package tablereloptions_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/tablereloptions"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	rows     []db.TableReloptionsRow
+	settings []db.ReloptionServerSettingsRow
+	err      error
+}
+
+func (m *mockQueryer) TableReloptions(context.Context) ([]db.TableReloptionsRow, error) {
+	return m.rows, m.err
+}
+
+func (m *mockQueryer) ReloptionServerSettings(context.Context) ([]db.ReloptionServerSettingsRow, error) {
+	return m.settings, nil
+}
+
+func defaultSettings() []db.ReloptionServerSettingsRow {
+	defaults := [][2]string{
+		{"autovacuum", "on"},
+		{"autovacuum_vacuum_threshold", "50"},
+		{"autovacuum_vacuum_scale_factor", "0.2"},
+		{"autovacuum_freeze_max_age", "200000000"},
+		{"autovacuum_multixact_freeze_max_age", "400000000"},
+	}
+	settings := make([]db.ReloptionServerSettingsRow, 0, len(defaults))
+	for _, s := range defaults {
+		settings = append(settings, db.ReloptionServerSettingsRow{
+			Name:    pgtype.Text{String: s[0], Valid: true},
+			Setting: pgtype.Text{String: s[1], Valid: true},
+		})
+	}
+	return settings
+}
+
+func makeRow(table, reloptions, toastReloptions string, hasToast bool, rows, deadTuples int64) db.TableReloptionsRow {
+	return db.TableReloptionsRow{
+		TableName:       pgtype.Text{String: table, Valid: true},
+		Reloptions:      pgtype.Text{String: reloptions, Valid: reloptions != ""},
+		ToastReloptions: pgtype.Text{String: toastReloptions, Valid: toastReloptions != ""},
+		HasToast:        hasToast,
+		EstimatedRows:   pgtype.Int8{Int64: rows, Valid: true},
+		NDeadTup:        pgtype.Int8{Int64: deadTuples, Valid: true},
+	}
+}
+
+func Test_TableReloptions(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name             string
+		rows             []db.TableReloptionsRow
+		expectedSeverity check.Severity
+		expectedReasons  []string
+	}{
+		{
+			name:             "no overrides",
+			rows:             nil,
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "sensible override",
+			rows:             []db.TableReloptionsRow{makeRow("public.orders", "autovacuum_vacuum_scale_factor=0.02", "", true, 1_000_000, 50_000)},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "tuning with autovacuum disabled",
+			rows:             []db.TableReloptionsRow{makeRow("public.staging", "autovacuum_enabled=off,autovacuum_vacuum_scale_factor=0.01", "", true, 1000, 0)},
+			expectedSeverity: check.SeverityWarn,
+			expectedReasons:  []string{"makes autovacuum_vacuum_scale_factor ineffective"},
+		},
+		{
+			name:             "freeze max age above server setting",
+			rows:             []db.TableReloptionsRow{makeRow("public.events", "autovacuum_freeze_max_age=500000000", "", true, 1000, 0)},
+			expectedSeverity: check.SeverityWarn,
+			expectedReasons:  []string{"exceeds the server setting"},
+		},
+		{
+			name:             "looser threshold with dead tuples piling up",
+			rows:             []db.TableReloptionsRow{makeRow("public.logs", "autovacuum_vacuum_scale_factor=0.5", "", true, 1_000_000, 300_000)},
+			expectedSeverity: check.SeverityWarn,
+			expectedReasons:  []string{"autovacuum triggers at 500.1K dead tuples"},
+		},
+		{
+			name:             "looser threshold with few dead tuples",
+			rows:             []db.TableReloptionsRow{makeRow("public.logs", "autovacuum_vacuum_scale_factor=0.5", "", true, 1_000_000, 1000)},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "toast options without toast relation",
+			rows:             []db.TableReloptionsRow{makeRow("public.counters", "toast_tuple_target=256", "", false, 1000, 0)},
+			expectedSeverity: check.SeverityOK,
+			expectedReasons:  []string{"no TOAST relation"},
+		},
+		{
+			name:             "redundant options",
+			rows:             []db.TableReloptionsRow{makeRow("public.users", "autovacuum_enabled=true,autovacuum_vacuum_scale_factor=0.20", "", true, 1000, 0)},
+			expectedSeverity: check.SeverityOK,
+			expectedReasons: []string{
+				"autovacuum_enabled=true is the same as the server setting",
+				"autovacuum_vacuum_scale_factor=0.2 is the same as the server setting",
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockQueryer{rows: tt.rows, settings: defaultSettings()}
+			report, err := tablereloptions.New(queryer).Check(context.Background())
+			require.NoError(t, err)
+			require.Len(t, report.Results, 1)
+
+			finding := report.Results[0]
+			assert.Equal(t, "table-reloptions", finding.ID)
+			assert.Equal(t, tt.expectedSeverity, finding.Severity)
+
+			if len(tt.rows) == 0 {
+				assert.Nil(t, finding.Table)
+				return
+			}
+			require.NotNil(t, finding.Table)
+			require.Len(t, finding.Table.Rows, len(tt.rows))
+			for _, reason := range tt.expectedReasons {
+				assert.Contains(t, finding.Table.Rows[0].Cells[2], reason)
+			}
+		})
+	}
+}
+
+func Test_TableReloptions_NormalizesOptions(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{
+		rows: []db.TableReloptionsRow{
+			makeRow("public.orders", "fillfactor=90,Autovacuum_Enabled=ON", "autovacuum_enabled=off", true, 1000, 0),
+		},
+		settings: defaultSettings(),
+	}
+
+	report, err := tablereloptions.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	row := report.Results[0].Table.Rows[0]
+	assert.Equal(t, "public.orders", row.Cells[0])
+	assert.Equal(t, "autovacuum_enabled=true\nfillfactor=90\ntoast.autovacuum_enabled=false", row.Cells[1])
+}
+
+func Test_TableReloptions_WorstTablesFirst(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{
+		rows: []db.TableReloptionsRow{
+			makeRow("public.a_clean", "fillfactor=90", "", true, 1000, 0),
+			makeRow("public.b_redundant", "autovacuum_vacuum_threshold=50", "", true, 1000, 0),
+			makeRow("public.c_disabled", "autovacuum_enabled=false,autovacuum_vacuum_cost_delay=0", "", true, 1000, 0),
+		},
+		settings: defaultSettings(),
+	}
+
+	report, err := tablereloptions.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	rows := report.Results[0].Table.Rows
+	require.Len(t, rows, 3)
+	assert.Equal(t, "public.c_disabled", rows[0].Cells[0])
+	assert.Equal(t, check.SeverityWarn, rows[0].Severity)
+	assert.Equal(t, "public.b_redundant", rows[1].Cells[0])
+	assert.Equal(t, "public.a_clean", rows[2].Cells[0])
+	assert.Equal(t, "-", rows[2].Cells[2])
+}
+
+func Test_TableReloptions_QueryError(t *testing.T) {
+	t.Parallel()
+
+	queryer := &mockQueryer{err: errors.New("connection refused")}
+	_, err := tablereloptions.New(queryer).Check(context.Background())
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "vacuum/table-reloptions")
+}
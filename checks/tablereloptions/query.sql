@@ -0,0 +1,45 @@
+-- name: TableReloptions :many
+-- Tables with per-table storage parameters (reloptions) on the table or its
+-- TOAST relation, with the stats needed to judge autovacuum overrides.
+SELECT
+  (n.nspname || '.' || c.relname)::text AS table_name
+  , ARRAY_TO_STRING(c.reloptions, ',') AS reloptions
+  , ARRAY_TO_STRING(t.reloptions, ',') AS toast_reloptions
+  , (c.reltoastrelid <> 0) AS has_toast
+  , COALESCE(s.n_live_tup, GREATEST(c.reltuples, 0)::bigint) AS estimated_rows
+  , COALESCE(s.n_dead_tup, 0) AS n_dead_tup
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_class AS t ON c.reltoastrelid = t.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND (c.reloptions IS NOT NULL OR t.reloptions IS NOT NULL)
+ORDER BY n.nspname, c.relname;
+
+-- name: ReloptionServerSettings :many
+-- Server-wide settings that per-table autovacuum reloptions override.
+SELECT
+  name::text AS name
+  , setting::text AS setting
+FROM pg_settings
+WHERE
+  name IN (
+    'autovacuum'
+    , 'autovacuum_vacuum_threshold'
+    , 'autovacuum_vacuum_scale_factor'
+    , 'autovacuum_vacuum_insert_threshold'
+    , 'autovacuum_vacuum_insert_scale_factor'
+    , 'autovacuum_analyze_threshold'
+    , 'autovacuum_analyze_scale_factor'
+    , 'autovacuum_vacuum_cost_delay'
+    , 'autovacuum_vacuum_cost_limit'
+    , 'autovacuum_freeze_max_age'
+    , 'autovacuum_multixact_freeze_max_age'
+    , 'vacuum_freeze_min_age'
+    , 'vacuum_freeze_table_age'
+    , 'vacuum_multixact_freeze_min_age'
+    , 'vacuum_multixact_freeze_table_age'
+    , 'log_autovacuum_min_duration'
+  );
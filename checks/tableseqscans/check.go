@@ -22,6 +22,10 @@ const (
 	warnRatioThreshold = 10.0
 	failRowThreshold   = 50000
 	failRatioThreshold = 50.0
+
+	// minSeqTupRead skips tables whose sequential scans read few rows in
+	// total: scans stopped early by a LIMIT cost little even when frequent.
+	minSeqTupRead = 1_000_000
 )
 
 type TableSeqScansQueries interface {
@@ -60,6 +64,10 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.HighSeqScanTablesRow) string { return r.TableName.String })
+	rows = check.FilterSmallTables(ctx, rows, func(r db.HighSeqScanTablesRow) (int64, int64) {
+		return r.EstimatedRows.Int64, r.TableSizeBytes.Int64
+	})
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -70,19 +78,19 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 		return report, nil
 	}
 
-	checkHighSeqScans(rows, report)
+	checkHighSeqScans(ctx, rows, report)
 
 	return report, nil
 }
 
-func checkHighSeqScans(rows []db.HighSeqScanTablesRow, report *check.Report) {
+func checkHighSeqScans(ctx context.Context, rows []db.HighSeqScanTablesRow, report *check.Report) {
 	var failTables []string
 	var warnTables []string
 	failCount := 0
 	warnCount := 0
 
 	for _, row := range rows {
-		if row.IndexCount.Int64 == 0 {
+		if row.IndexCount.Int64 == 0 || row.SeqTupRead.Int64 < minSeqTupRead {
 			continue
 		}
 
@@ -94,19 +102,19 @@ func checkHighSeqScans(rows []db.HighSeqScanTablesRow, report *check.Report) {
 			ratio = 999999
 		}
 
-		sizeMB := float64(row.TableSizeBytes.Int64) / (1024 * 1024)
+		summary := fmt.Sprintf("%s (seq: %d, idx: %d, ratio: %.1f, rows read: %s, rows: %d, size: %s)",
+			row.TableName.String, row.SeqScan.Int64, row.IdxScan.Int64, ratio,
+			check.FormatNumber(row.SeqTupRead.Int64), row.EstimatedRows.Int64, check.FormatSize(ctx, row.TableSizeBytes.Int64))
 
 		if row.EstimatedRows.Int64 >= failRowThreshold && ratio >= failRatioThreshold {
 			failCount++
 			if len(failTables) < 10 {
-				failTables = append(failTables, fmt.Sprintf("%s (seq: %d, idx: %d, ratio: %.1f, rows: %d, size: %.1f MB)",
-					row.TableName.String, row.SeqScan.Int64, row.IdxScan.Int64, ratio, row.EstimatedRows.Int64, sizeMB))
+				failTables = append(failTables, summary)
 			}
 		} else if row.EstimatedRows.Int64 >= warnRowThreshold && ratio >= warnRatioThreshold {
 			warnCount++
 			if len(warnTables) < 10 {
-				warnTables = append(warnTables, fmt.Sprintf("%s (seq: %d, idx: %d, ratio: %.1f, rows: %d, size: %.1f MB)",
-					row.TableName.String, row.SeqScan.Int64, row.IdxScan.Int64, ratio, row.EstimatedRows.Int64, sizeMB))
+				warnTables = append(warnTables, summary)
 			}
 		}
 	}
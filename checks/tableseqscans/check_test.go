@@ -66,6 +66,7 @@ func Test_TableSeqScans(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "posts", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 5000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 75000000, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 200, Valid: true},
 					SeqToIdxRatio:  makeNumeric(25.0),
 					EstimatedRows:  pgtype.Int8{Int64: 15000, Valid: true},
@@ -82,6 +83,7 @@ func Test_TableSeqScans(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "orders", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 					SeqToIdxRatio:  makeNumeric(100.0),
 					EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -98,6 +100,7 @@ func Test_TableSeqScans(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "logs", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 50000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 5000000000, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 0, Valid: true},
 					SeqToIdxRatio:  pgtype.Numeric{Valid: false},
 					EstimatedRows:  pgtype.Int8{Int64: 100000, Valid: true},
@@ -114,6 +117,7 @@ func Test_TableSeqScans(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "orders", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 					SeqToIdxRatio:  makeNumeric(100.0),
 					EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -123,6 +127,7 @@ func Test_TableSeqScans(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "posts", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 5000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 75000000, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 200, Valid: true},
 					SeqToIdxRatio:  makeNumeric(25.0),
 					EstimatedRows:  pgtype.Int8{Int64: 15000, Valid: true},
@@ -160,6 +165,7 @@ func Test_TableSeqScans_HighSeqScans(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "orders", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 			SeqToIdxRatio:  makeNumeric(100.0),
 			EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -169,6 +175,7 @@ func Test_TableSeqScans_HighSeqScans(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "invoices", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 8000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 480000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 80, Valid: true},
 			SeqToIdxRatio:  makeNumeric(100.0),
 			EstimatedRows:  pgtype.Int8{Int64: 60000, Valid: true},
@@ -207,6 +214,7 @@ func Test_TableSeqScans_ModerateSeqScans(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "comments", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 3000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 36000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 150, Valid: true},
 			SeqToIdxRatio:  makeNumeric(20.0),
 			EstimatedRows:  pgtype.Int8{Int64: 12000, Valid: true},
@@ -279,6 +287,7 @@ func Test_TableSeqScans_ThresholdBoundaries(t *testing.T) {
 				{
 					TableName:      pgtype.Text{String: "test_table", Valid: true},
 					SeqScan:        pgtype.Int8{Int64: 5000, Valid: true},
+					SeqTupRead:     pgtype.Int8{Int64: 5000 * tc.EstimatedRows, Valid: true},
 					IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 					SeqToIdxRatio:  makeNumeric(tc.SeqToIdxRatio),
 					EstimatedRows:  pgtype.Int8{Int64: tc.EstimatedRows, Valid: true},
@@ -316,6 +325,7 @@ func Test_TableSeqScans_NoIndexCount(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "logs", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 50000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 5000000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 0, Valid: true},
 			SeqToIdxRatio:  pgtype.Numeric{Valid: false},
 			EstimatedRows:  pgtype.Int8{Int64: 100000, Valid: true},
@@ -344,6 +354,7 @@ func Test_TableSeqScans_InvalidRatio(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "new_table", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 0, Valid: true},
 			SeqToIdxRatio:  pgtype.Numeric{Valid: false},
 			EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -377,6 +388,7 @@ func Test_TableSeqScans_SizeFormatting(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "large_table", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 1000000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 			SeqToIdxRatio:  makeNumeric(100.0),
 			EstimatedRows:  pgtype.Int8{Int64: 100000, Valid: true},
@@ -411,6 +423,7 @@ func Test_TableSeqScans_TruncationMessage(t *testing.T) {
 		rows[i] = db.HighSeqScanTablesRow{
 			TableName:      pgtype.Text{String: fmt.Sprintf("table_%d", i), Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 			SeqToIdxRatio:  makeNumeric(100.0),
 			EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -490,6 +503,7 @@ func Test_TableSeqScans_NoOKResultWhenIssuesFound(t *testing.T) {
 		{
 			TableName:      pgtype.Text{String: "orders", Valid: true},
 			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 750000000, Valid: true},
 			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
 			SeqToIdxRatio:  makeNumeric(100.0),
 			EstimatedRows:  pgtype.Int8{Int64: 75000, Valid: true},
@@ -511,3 +525,41 @@ func Test_TableSeqScans_NoOKResultWhenIssuesFound(t *testing.T) {
 	require.Equal(t, "high-seq-scans", result.ID)
 	require.Equal(t, check.SeverityFail, result.Severity)
 }
+
+func Test_TableSeqScans_RowsRead(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.HighSeqScanTablesRow{
+		{
+			TableName:      pgtype.Text{String: "public.events", Valid: true},
+			SeqScan:        pgtype.Int8{Int64: 10000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 2500000000, Valid: true},
+			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
+			SeqToIdxRatio:  makeNumeric(100.0),
+			EstimatedRows:  pgtype.Int8{Int64: 250000, Valid: true},
+			TableSizeBytes: pgtype.Int8{Int64: 104857600, Valid: true},
+			IndexCount:     pgtype.Int8{Int64: 2, Valid: true},
+		},
+		{
+			// Frequent scans stopped early by a LIMIT read few rows.
+			TableName:      pgtype.Text{String: "public.jobs", Valid: true},
+			SeqScan:        pgtype.Int8{Int64: 50000, Valid: true},
+			SeqTupRead:     pgtype.Int8{Int64: 50000, Valid: true},
+			IdxScan:        pgtype.Int8{Int64: 100, Valid: true},
+			SeqToIdxRatio:  makeNumeric(500.0),
+			EstimatedRows:  pgtype.Int8{Int64: 100000, Valid: true},
+			TableSizeBytes: pgtype.Int8{Int64: 10485760, Valid: true},
+			IndexCount:     pgtype.Int8{Int64: 2, Valid: true},
+		},
+	}
+
+	report, err := tableseqscans.New(newMockQueryer(rows)).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	result := report.Results[0]
+	require.Equal(t, highSeqScansID, result.ID)
+	require.Contains(t, result.Details, "Found 1 tables")
+	require.Contains(t, result.Details, "public.events (seq: 10000, idx: 100, ratio: 100.0, rows read: 2.5B, rows: 250000")
+	require.NotContains(t, result.Details, "public.jobs")
+}
@@ -1,6 +1,7 @@
 -- name: HighSeqScanTables :many
 -- Identifies tables with excessive sequential scans relative to index scans.
 -- Excludes: small tables, system schemas, tables with no indexes.
+-- Ordered by rows read by sequential scans, the work an index could save.
 WITH table_indexes AS (
   SELECT
     idx.indrelid AS table_oid
@@ -13,6 +14,7 @@ WITH table_indexes AS (
 SELECT
   (n.nspname || '.' || c.relname)::text AS table_name
   , coalesce(s.seq_scan, 0) AS seq_scan
+  , coalesce(s.seq_tup_read, 0) AS seq_tup_read
   , coalesce(s.idx_scan, 0) AS idx_scan
   , CASE
     WHEN coalesce(s.idx_scan, 0) = 0 THEN NULL
@@ -27,8 +29,8 @@ LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
 LEFT JOIN table_indexes AS ti ON c.oid = ti.table_oid
 WHERE
   c.relkind IN ('r', 'p')
-  AND n.nspname = 'public'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
   AND coalesce(s.n_live_tup, 0) > 10000
   AND coalesce(s.seq_scan, 0) > 100
 ORDER BY
-  coalesce(s.seq_scan, 0) DESC;
+  coalesce(s.seq_tup_read, 0) DESC;
@@ -0,0 +1,150 @@
+// Package tableswithoutindexes implements a check for tables with no indexes
+// at all.
+package tableswithoutindexes
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"sort"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+const remediation = "Add a primary key, or an index on the columns the queries filter or join on. " +
+	"Use CREATE INDEX CONCURRENTLY on busy tables to avoid blocking writes"
+
+type TablesWithoutIndexesQueries interface {
+	CatalogTables(context.Context) ([]db.CatalogTablesRow, error)
+}
+
+type checker struct {
+	queries TablesWithoutIndexesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryIndexes,
+		CheckID:     "tables-without-indexes",
+		Name:        "Tables Without Indexes",
+		Description: "Flags non-trivial tables with no indexes, and whether they are being sequentially scanned",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries TablesWithoutIndexesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	tables, err := c.queries.CatalogTables(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows := unindexedTables(tables)
+	rows = check.FilterTables(ctx, rows, func(r db.CatalogTablesRow) string { return r.SchemaName.String + "." + r.TableName.String })
+	rows = check.FilterSmallTables(ctx, rows, func(r db.CatalogTablesRow) (int64, int64) {
+		return rowCount(r), check.Int8ToInt64(r.TableSizeBytes)
+	})
+
+	checkUnindexed(ctx, rows, report)
+
+	return report, nil
+}
+
+// unindexedTables returns the tables and materialized views without any
+// index, most sequentially scanned first. Every index has at least one page,
+// so a zero index size means there is none. Partitioned tables have no
+// storage of their own and are covered by their partitions.
+func unindexedTables(tables []db.CatalogTablesRow) []db.CatalogTablesRow {
+	var rows []db.CatalogTablesRow
+	for _, table := range tables {
+		if table.Relkind.String == "p" || check.Int8ToInt64(table.IndexesSizeBytes) > 0 {
+			continue
+		}
+		rows = append(rows, table)
+	}
+	sort.SliceStable(rows, func(i, j int) bool {
+		si, sj := check.Int8ToInt64(rows[i].SeqScan), check.Int8ToInt64(rows[j].SeqScan)
+		if si != sj {
+			return si > sj
+		}
+		return check.Int8ToInt64(rows[i].TableSizeBytes) > check.Int8ToInt64(rows[j].TableSizeBytes)
+	})
+	return rows
+}
+
+// rowCount prefers the statistics collector's live rows; reltuples is -1
+// until the table is first analyzed.
+func rowCount(row db.CatalogTablesRow) int64 {
+	return max(check.Int8ToInt64(row.NLiveTup), check.Int8ToInt64(row.EstimatedRows), 0)
+}
+
+// checkUnindexed warns on unindexed tables that are being scanned: every
+// query on them reads the whole table. Tables nobody reads are listed as OK.
+func checkUnindexed(ctx context.Context, rows []db.CatalogTablesRow, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "Every table above the size floors has at least one index",
+		})
+		return
+	}
+
+	scanned := 0
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		seqScans := check.Int8ToInt64(row.SeqScan)
+		severity := check.SeverityOK
+		if seqScans > 0 {
+			severity = check.SeverityWarn
+			scanned++
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName.String + "." + row.TableName.String,
+				check.FormatNumber(rowCount(row)),
+				check.FormatSize(ctx, check.Int8ToInt64(row.TableSizeBytes)),
+				check.FormatNumber(seqScans),
+			},
+			Severity: severity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%d table(s) without indexes, none read since statistics were reset", len(rows)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Rows", "Size", "Seq Scans"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "tables_without_indexes", Value: float64(len(rows)), Unit: check.UnitCount},
+		},
+	}
+	if scanned > 0 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%d table(s) without indexes, %d of them sequentially scanned since statistics were reset",
+			len(rows), scanned)
+		finding.Remediation = remediation
+	}
+	report.AddFinding(finding)
+}
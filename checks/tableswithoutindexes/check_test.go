@@ -0,0 +1,158 @@
+package tableswithoutindexes_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/tableswithoutindexes"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockTablesWithoutIndexesQueryer struct {
+	tables []db.CatalogTablesRow
+	err    error
+}
+
+func (m *mockTablesWithoutIndexesQueryer) CatalogTables(context.Context) ([]db.CatalogTablesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.tables, nil
+}
+
+func makeTable(name, relkind string, rows, size, indexesSize, seqScans int64) db.CatalogTablesRow {
+	return db.CatalogTablesRow{
+		SchemaName:       pgtype.Text{String: "public", Valid: true},
+		TableName:        pgtype.Text{String: name, Valid: true},
+		Relkind:          pgtype.Text{String: relkind, Valid: true},
+		EstimatedRows:    pgtype.Int8{Int64: rows, Valid: true},
+		TableSizeBytes:   pgtype.Int8{Int64: size, Valid: true},
+		IndexesSizeBytes: pgtype.Int8{Int64: indexesSize, Valid: true},
+		SeqScan:          pgtype.Int8{Int64: seqScans, Valid: true},
+	}
+}
+
+func Test_TablesWithoutIndexes(t *testing.T) {
+	t.Parallel()
+
+	indexed := makeTable("orders", "r", 5_000_000, 2*check.GiB, 300*check.MiB, 10)
+	partitioned := makeTable("events", "p", 0, 0, 0, 0)
+	archive := makeTable("audit_archive", "r", 2_000_000, 800*check.MiB, 0, 0)
+	scanned := makeTable("imports", "r", 50_000, 12*check.MiB, 0, 40_000)
+	tiny := makeTable("countries", "r", 200, 16*check.KiB, 0, 90_000)
+
+	testCases := []struct {
+		Name             string
+		Tables           []db.CatalogTablesRow
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+		ExpectedTables   []string
+	}{
+		{
+			Name:             "all indexed - OK",
+			Tables:           []db.CatalogTablesRow{indexed, partitioned},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Every table above the size floors has at least one index",
+		},
+		{
+			Name:             "unread tables - OK",
+			Tables:           []db.CatalogTablesRow{indexed, archive},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "1 table(s) without indexes, none read since statistics were reset",
+			ExpectedTables:   []string{"public.audit_archive"},
+		},
+		{
+			Name:             "scanned tables warn, most scanned first",
+			Tables:           []db.CatalogTablesRow{archive, scanned, tiny},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 table(s) without indexes, 1 of them sequentially scanned since statistics were reset",
+			ExpectedTables:   []string{"public.imports", "public.audit_archive"},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000})
+			report, err := tableswithoutindexes.New(&mockTablesWithoutIndexesQueryer{tables: tc.Tables}).Check(ctx)
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedDetails, finding.Details)
+			if tc.ExpectedTables == nil {
+				require.Nil(t, finding.Table)
+				return
+			}
+			var tables []string
+			for _, row := range finding.Table.Rows {
+				tables = append(tables, row.Cells[0])
+			}
+			require.Equal(t, tc.ExpectedTables, tables)
+		})
+	}
+}
+
+func Test_TablesWithoutIndexes_Row(t *testing.T) {
+	t.Parallel()
+
+	checker := tableswithoutindexes.New(&mockTablesWithoutIndexesQueryer{tables: []db.CatalogTablesRow{
+		makeTable("imports", "r", 50_000, 12*check.MiB, 0, 40_000),
+	}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	row := report.Results[0].Table.Rows[0]
+	require.Equal(t, []string{"public.imports", "50.0K", "12.0MiB", "40.0K"}, row.Cells)
+	require.Equal(t, check.SeverityWarn, row.Severity)
+}
+
+func Test_TablesWithoutIndexes_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogTables",
+		[]string{
+			"schema_name text", "table_name text", "relkind text", "estimated_rows int8", "table_size_bytes int8",
+			"indexes_size_bytes int8", "seq_scan int8", "idx_scan int8", "n_live_tup int8", "n_dead_tup int8",
+			"n_tup_ins int8", "n_tup_upd int8", "n_tup_del int8", "n_tup_hot_upd int8",
+			"last_autovacuum timestamptz", "last_autoanalyze timestamptz", "fillfactor int4",
+		},
+		[]any{"public", "imports", "r", -1, 12 * check.MiB, 0, 40000, 0, 50000, 0, 50000, 0, 0, 0, nil, nil, nil},
+		[]any{"public", "orders", "r", 5000000, 2 * check.GiB, 300 * check.MiB, 10, 900000, 5000000, 0, 0, 0, 0, 0, nil, nil, nil},
+	)
+
+	report, err := tableswithoutindexes.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Len(t, report.Results[0].Table.Rows, 1)
+	require.Equal(t, "50.0K", report.Results[0].Table.Rows[0].Cells[1])
+}
+
+func Test_TablesWithoutIndexes_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := tableswithoutindexes.New(&mockTablesWithoutIndexesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "tables-without-indexes")
+}
+
+func Test_TablesWithoutIndexes_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := tableswithoutindexes.Metadata()
+
+	require.Equal(t, "tables-without-indexes", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -10,6 +10,7 @@ import (
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 	"github.com/jackc/pgx/v5/pgtype"
 )
 
@@ -42,6 +43,10 @@ const (
 	// Analyze needed thresholds (modifications since last analyze).
 	analyzeNeededWarn = 100_000 // Warning at 100K modifications
 	analyzeNeededFail = 500_000 // Fail at 500K modifications
+
+	// Dead tuples on a table with autovacuum disabled that escalate to FAIL.
+	disabledDeadTuplesMin   = 10_000
+	disabledDeadTuplesRatio = 0.2
 )
 
 func Metadata() check.Metadata {
@@ -52,6 +57,7 @@ func Metadata() check.Metadata {
 		Description: "Monitors per-table autovacuum configuration and activity",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -72,10 +78,14 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("running %s/%s: %w", check.CategoryVacuum, report.CheckID, err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.TableVacuumHealthRow) string { return r.TableName.String })
+	rows = check.FilterSmallTables(ctx, rows, func(r db.TableVacuumHealthRow) (int64, int64) {
+		return r.EstimatedRows.Int64, r.TableSizeBytes.Int64
+	})
 
 	checkAutovacuumDisabled(rows, report)
-	checkLargeTableDefaults(rows, report)
-	checkVacuumStale(rows, report)
+	checkLargeTableDefaults(ctx, rows, report)
+	checkVacuumStale(ctx, rows, report)
 	checkAnalyzeNeeded(rows, report)
 
 	return report, nil
@@ -83,10 +93,44 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 
 func checkAutovacuumDisabled(rows []db.TableVacuumHealthRow, report *check.Report) {
 	var tableNames []string
+	var tableRows []check.TableRow
+	var fixes []string
+	severity := check.SeverityWarn
+
 	for _, row := range rows {
-		if hasAutovacuumDisabled(row.Reloptions.String) {
-			tableNames = append(tableNames, row.TableName.String)
+		onTable := hasAutovacuumDisabled(row.Reloptions.String)
+		onToast := hasAutovacuumDisabled(row.ToastReloptions.String)
+		if !onTable && !onToast {
+			continue
+		}
+		tableNames = append(tableNames, row.TableName.String)
+
+		var disabledOn, reset []string
+		if onTable {
+			disabledOn = append(disabledOn, "table")
+			reset = append(reset, "autovacuum_enabled")
 		}
+		if onToast {
+			disabledOn = append(disabledOn, "TOAST")
+			reset = append(reset, "toast.autovacuum_enabled")
+		}
+		fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s RESET (%s);",
+			pgx.Identifier{row.SchemaName.String, row.Relname.String}.Sanitize(), strings.Join(reset, ", ")))
+
+		rowSeverity := check.SeverityWarn
+		if deadTuplesPilingUp(row) {
+			rowSeverity = check.SeverityFail
+			severity = check.SeverityFail
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.TableName.String,
+				strings.Join(disabledOn, ", "),
+				check.FormatNumber(row.NDeadTup.Int64),
+				formatTimestamp(row.LastVacuumAny),
+			},
+			Severity: rowSeverity,
+		})
 	}
 
 	if len(tableNames) == 0 {
@@ -100,14 +144,32 @@ func checkAutovacuumDisabled(rows []db.TableVacuumHealthRow, report *check.Repor
 	}
 
 	report.AddFinding(check.Finding{
-		ID:       "autovacuum-disabled",
-		Name:     "Autovacuum Disabled Tables",
-		Severity: check.SeverityWarn,
-		Details:  fmt.Sprintf("Found %d table(s) with autovacuum disabled: %s", len(tableNames), strings.Join(tableNames, ", ")),
+		ID:          "autovacuum-disabled",
+		Name:        "Autovacuum Disabled Tables",
+		Severity:    severity,
+		Details:     fmt.Sprintf("Found %d table(s) with autovacuum disabled: %s", len(tableNames), strings.Join(tableNames, ", ")),
+		Remediation: disabledRemediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Table", "Disabled On", "Dead Tuples", "Last Vacuum"},
+			Rows:    tableRows,
+		},
 	})
 }
 
-func checkLargeTableDefaults(rows []db.TableVacuumHealthRow, report *check.Report) {
+// disabledRemediation explains why a disabled autovacuum is not "no vacuum":
+// PostgreSQL still forces anti-wraparound vacuums on the table.
+const disabledRemediation = "Re-enable autovacuum unless a scheduled VACUUM maintains the table. " +
+	"Disabling it stops only routine vacuums: anti-wraparound vacuums still run, late and aggressive"
+
+// deadTuplesPilingUp reports a table where a disabled autovacuum already
+// shows: a fifth of its rows are dead.
+func deadTuplesPilingUp(row db.TableVacuumHealthRow) bool {
+	dead := row.NDeadTup.Int64
+	return dead >= disabledDeadTuplesMin && float64(dead) >= disabledDeadTuplesRatio*float64(row.EstimatedRows.Int64)
+}
+
+func checkLargeTableDefaults(ctx context.Context, rows []db.TableVacuumHealthRow, report *check.Report) {
 	var tablesUsingDefaults []db.TableVacuumHealthRow
 	for _, row := range rows {
 		if row.EstimatedRows.Int64 >= largeTableMinRows && isUsingDefaultSettings(row.Reloptions.String) {
@@ -139,7 +201,7 @@ func checkLargeTableDefaults(rows []db.TableVacuumHealthRow, report *check.Repor
 			Cells: []string{
 				row.TableName.String,
 				formatRowCount(row.EstimatedRows.Int64),
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				formatRowCount(pendingWork),
 				formatTimestamp(row.LastAutovacuum),
 				fmt.Sprintf("%d", row.AutovacuumCount.Int64),
@@ -160,7 +222,7 @@ func checkLargeTableDefaults(rows []db.TableVacuumHealthRow, report *check.Repor
 	})
 }
 
-func checkVacuumStale(rows []db.TableVacuumHealthRow, report *check.Report) {
+func checkVacuumStale(ctx context.Context, rows []db.TableVacuumHealthRow, report *check.Report) {
 	now := time.Now()
 	warnThreshold := now.Add(-time.Duration(staleVacuumWarnDays) * 24 * time.Hour)
 	failThreshold := now.Add(-time.Duration(staleVacuumFailDays) * 24 * time.Hour)
@@ -214,7 +276,7 @@ func checkVacuumStale(rows []db.TableVacuumHealthRow, report *check.Report) {
 			Cells: []string{
 				row.TableName.String,
 				formatRowCount(row.EstimatedRows.Int64),
-				check.FormatBytes(row.TableSizeBytes.Int64),
+				check.FormatSize(ctx, row.TableSizeBytes.Int64),
 				formatRowCount(pendingWork),
 				formatTimeSince(lastVacuum),
 				formatTimeSince(lastAnalyze),
@@ -291,8 +353,20 @@ func checkAnalyzeNeeded(rows []db.TableVacuumHealthRow, report *check.Report) {
 
 // Helper functions.
 
+// hasAutovacuumDisabled reports autovacuum_enabled set to any boolean false
+// spelling PostgreSQL accepts; reloptions keep the value as it was written.
 func hasAutovacuumDisabled(reloptions string) bool {
-	return strings.Contains(strings.ToLower(reloptions), "autovacuum_enabled=false")
+	for _, option := range strings.Split(reloptions, ",") {
+		key, value, ok := strings.Cut(option, "=")
+		if !ok || !strings.EqualFold(strings.TrimSpace(key), "autovacuum_enabled") {
+			continue
+		}
+		switch strings.ToLower(strings.TrimSpace(value)) {
+		case "false", "off", "no", "0", "f", "n":
+			return true
+		}
+	}
+	return false
 }
 
 func isUsingDefaultSettings(reloptions string) bool {
@@ -3,6 +3,7 @@ package tablevacuumhealth_test
 import (
 	"context"
 	"fmt"
+	"strings"
 	"testing"
 	"time"
 
@@ -38,9 +39,12 @@ type rowBuilder struct {
 }
 
 func makeRow(tableName string) *rowBuilder {
+	schema, relname, _ := strings.Cut(tableName, ".")
 	return &rowBuilder{
 		row: db.TableVacuumHealthRow{
 			TableName:        pgtype.Text{String: tableName, Valid: true},
+			SchemaName:       pgtype.Text{String: schema, Valid: true},
+			Relname:          pgtype.Text{String: relname, Valid: true},
 			EstimatedRows:    pgtype.Int8{Int64: 0, Valid: true},
 			TableSizeBytes:   pgtype.Int8{Int64: 0, Valid: true},
 			NDeadTup:         pgtype.Int8{Int64: 0, Valid: true},
@@ -73,6 +77,11 @@ func (b *rowBuilder) withReloptions(reloptions string) *rowBuilder {
 	return b
 }
 
+func (b *rowBuilder) withToastReloptions(reloptions string) *rowBuilder {
+	b.row.ToastReloptions = pgtype.Text{String: reloptions, Valid: reloptions != ""}
+	return b
+}
+
 func (b *rowBuilder) withLastAutovacuum(t time.Time) *rowBuilder {
 	b.row.LastAutovacuum = pgtype.Timestamptz{Time: t, Valid: true}
 	return b
@@ -211,6 +220,57 @@ func TestTableVacuumHealth_AutovacuumDisabled_Found(t *testing.T) {
 	assert.Contains(t, disabledFinding.Details, "public.staging_table")
 }
 
+func TestTableVacuumHealth_AutovacuumDisabled_ToastAndDeadTuples(t *testing.T) {
+	t.Parallel()
+
+	recentTime := time.Now().Add(-1 * time.Hour)
+	queryer := &mockQueryer{
+		rows: []db.TableVacuumHealthRow{
+			makeRow("public.events").
+				withRows(100_000).
+				withDeadTuples(50_000).
+				withReloptions("fillfactor=90,autovacuum_enabled=off").
+				withLastVacuumAny(recentTime).
+				withLastAnalyzeAny(recentTime).
+				build(),
+			makeRow("public.documents").
+				withRows(10_000).
+				withToastReloptions("autovacuum_enabled=false").
+				withLastVacuumAny(recentTime).
+				withLastAnalyzeAny(recentTime).
+				build(),
+			makeRow("public.orders").
+				withRows(10_000).
+				withReloptions("autovacuum_enabled=true").
+				withLastVacuumAny(recentTime).
+				withLastAnalyzeAny(recentTime).
+				build(),
+		},
+	}
+
+	report, err := tablevacuumhealth.New(queryer).Check(context.Background())
+	require.NoError(t, err)
+
+	var disabledFinding *check.Finding
+	for i := range report.Results {
+		if report.Results[i].ID == findingIDAutovacuumDisabled {
+			disabledFinding = &report.Results[i]
+			break
+		}
+	}
+
+	require.NotNil(t, disabledFinding)
+	assert.Equal(t, check.SeverityFail, disabledFinding.Severity, "a fifth of the rows are dead")
+	assert.Contains(t, disabledFinding.Details, "2 table(s)")
+	require.Len(t, disabledFinding.Table.Rows, 2)
+	assert.Equal(t, []string{"public.events", "table", "50.0K"}, disabledFinding.Table.Rows[0].Cells[:3])
+	assert.Equal(t, check.SeverityFail, disabledFinding.Table.Rows[0].Severity)
+	assert.Equal(t, "TOAST", disabledFinding.Table.Rows[1].Cells[1])
+	assert.Equal(t, check.SeverityWarn, disabledFinding.Table.Rows[1].Severity)
+	assert.Equal(t, "ALTER TABLE \"public\".\"events\" RESET (autovacuum_enabled);\n"+
+		"ALTER TABLE \"public\".\"documents\" RESET (toast.autovacuum_enabled);", disabledFinding.FixSQL)
+}
+
 func TestTableVacuumHealth_LargeTableDefaults_NoTables(t *testing.T) {
 	t.Parallel()
 
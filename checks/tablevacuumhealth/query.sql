@@ -16,10 +16,15 @@ SELECT
   , COALESCE(s.autoanalyze_count, 0) AS autoanalyze_count
   -- PG14+ columns for insert tracking (will be 0 on older versions via COALESCE)
   , COALESCE(s.n_ins_since_vacuum, 0) AS n_ins_since_vacuum
+  -- Overrides on the TOAST relation (toast.autovacuum_enabled and friends)
+  , ARRAY_TO_STRING(t.reloptions, ',') AS toast_reloptions
+  , n.nspname::text AS schema_name
+  , c.relname::text AS relname
 FROM pg_class AS c
 INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_class AS t ON c.reltoastrelid = t.oid
 LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
 WHERE
   c.relkind IN ('r', 'p')
-  AND n.nspname = 'public'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
 ORDER BY COALESCE(s.n_live_tup, c.reltuples::bigint) DESC;
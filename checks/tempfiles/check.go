@@ -0,0 +1,180 @@
+// Package tempfiles implements a check for temporary files on disk, including files orphaned by crashed backends.
+package tempfiles
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// inUseWarnBytes flags live spills large enough to threaten disk headroom.
+	inUseWarnBytes = 10 * check.GiB
+	// maxListedFiles keeps the table readable when a crash leaves thousands of files.
+	maxListedFiles = 20
+)
+
+type TempFilesQueries interface {
+	TempFiles(context.Context) ([]db.TempFilesRow, error)
+}
+
+type checker struct {
+	queries TempFilesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "temp-files",
+		Name:        "Temporary Files on Disk",
+		Description: "Measures disk used by temporary files and finds files orphaned by crashed backends",
+		Readme:      readme,
+		SQL:         querySQL,
+		// pg_ls_tmpdir() is restricted to superusers and members of pg_monitor.
+		Requires: []check.Prerequisite{
+			check.RequireFunction("pg_ls_tmpdir(oid)", "connect as a superuser or GRANT pg_monitor TO the pgdoctor user"),
+		},
+	}
+}
+
+func New(queries TempFilesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.TempFiles(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	var inUse, orphaned []db.TempFilesRow
+	for _, row := range rows {
+		if row.BackendAlive {
+			inUse = append(inUse, row)
+		} else {
+			orphaned = append(orphaned, row)
+		}
+	}
+
+	checkOrphanedFiles(ctx, orphaned, check.ProviderFromContext(ctx), report)
+	checkInUseFiles(ctx, inUse, report)
+
+	return report, nil
+}
+
+// checkOrphanedFiles reports files whose backend is gone. PostgreSQL deletes
+// temporary files when the query ends and only sweeps leftovers at restart, so
+// these are remains of crashed sorts, hashes and index builds.
+func checkOrphanedFiles(ctx context.Context, rows []db.TempFilesRow, provider check.Provider, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "orphaned-temp-files",
+			Name:     "Orphaned Temporary Files",
+			Severity: check.SeverityOK,
+			Details:  "No temporary files left behind by terminated backends",
+		})
+		return
+	}
+
+	total := totalBytes(rows)
+	details := fmt.Sprintf("%d temporary file(s) (%s) belong to backends that no longer exist. "+
+		"They are invisible to size queries and are only removed when the server restarts.", len(rows), check.FormatSize(ctx, total))
+	details += moreFiles(rows)
+
+	report.AddFinding(check.Finding{
+		ID:               "orphaned-temp-files",
+		Name:             "Orphaned Temporary Files",
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		ReclaimableBytes: total,
+		Metrics: []check.Metric{
+			{Name: "orphaned_files", Value: float64(len(rows)), Unit: check.UnitCount},
+			{Name: "orphaned_bytes", Value: float64(total), Unit: check.UnitBytes},
+		},
+		Remediation: provider.RestartFix(),
+		Table:       fileTable(ctx, rows, check.SeverityWarn),
+	})
+}
+
+// checkInUseFiles reports disk currently taken by running queries' spills.
+func checkInUseFiles(ctx context.Context, rows []db.TempFilesRow, report *check.Report) {
+	total := totalBytes(rows)
+	if total < inUseWarnBytes {
+		details := "No queries are spilling to disk"
+		if len(rows) > 0 {
+			details = fmt.Sprintf("%d temporary file(s) in use by running queries (%s)", len(rows), check.FormatSize(ctx, total))
+		}
+		report.AddFinding(check.Finding{
+			ID:       "temp-files-in-use",
+			Name:     "Temporary Files in Use",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	details := fmt.Sprintf("Running queries hold %s in %d temporary file(s). "+
+		"Large spills compete with WAL and data files for disk; see temp-usage for the long-term rate.", check.FormatSize(ctx, total), len(rows))
+	details += moreFiles(rows)
+
+	report.AddFinding(check.Finding{
+		ID:       "temp-files-in-use",
+		Name:     "Temporary Files in Use",
+		Severity: check.SeverityWarn,
+		Details:  details,
+		Table:    fileTable(ctx, rows, check.SeverityWarn),
+	})
+}
+
+// fileTable lists the largest files first (the query sorts by size).
+// Callers note the files left out beyond maxListedFiles.
+func fileTable(ctx context.Context, rows []db.TempFilesRow, severity check.Severity) *check.Table {
+	tableRows := make([]check.TableRow, 0, min(len(rows), maxListedFiles))
+	for _, row := range rows[:min(len(rows), maxListedFiles)] {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.Tablespace,
+				row.FileName.String,
+				check.FormatSize(ctx, row.SizeBytes.Int64),
+				check.FormatDurationSec(row.AgeSeconds.Int64),
+			},
+			Severity: severity,
+		})
+	}
+	return &check.Table{
+		Headers: []string{"Tablespace", "File", "Size", "Age"},
+		Rows:    tableRows,
+	}
+}
+
+func totalBytes(rows []db.TempFilesRow) int64 {
+	var total int64
+	for _, row := range rows {
+		total += row.SizeBytes.Int64
+	}
+	return total
+}
+
+func moreFiles(rows []db.TempFilesRow) string {
+	if len(rows) <= maxListedFiles {
+		return ""
+	}
+	return fmt.Sprintf("\n... and %d more", len(rows)-maxListedFiles)
+}
@@ -0,0 +1,121 @@
+package tempfiles_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/tempfiles"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	rows []db.TempFilesRow
+	err  error
+}
+
+func (m *mockQueryer) TempFiles(context.Context) ([]db.TempFilesRow, error) {
+	return m.rows, m.err
+}
+
+func makeRow(name string, sizeBytes, ageSeconds int64, alive bool) db.TempFilesRow {
+	return db.TempFilesRow{
+		Tablespace:   "pg_default",
+		FileName:     pgtype.Text{String: name, Valid: true},
+		SizeBytes:    pgtype.Int8{Int64: sizeBytes, Valid: true},
+		AgeSeconds:   pgtype.Int8{Int64: ageSeconds, Valid: true},
+		BackendAlive: alive,
+	}
+}
+
+func Test_TempFiles(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name             string
+		rows             []db.TempFilesRow
+		expectedOrphaned check.Severity
+		expectedInUse    check.Severity
+		expectedBytes    int64
+	}{
+		{
+			name:             "empty temp directory",
+			expectedOrphaned: check.SeverityOK,
+			expectedInUse:    check.SeverityOK,
+		},
+		{
+			name:             "small spills of running queries",
+			rows:             []db.TempFilesRow{makeRow("pgsql_tmp4242.0", 200*check.MiB, 30, true)},
+			expectedOrphaned: check.SeverityOK,
+			expectedInUse:    check.SeverityOK,
+		},
+		{
+			name:             "large spills of running queries",
+			rows:             []db.TempFilesRow{makeRow("pgsql_tmp4242.0", 8*check.GiB, 600, true), makeRow("pgsql_tmp4243.0", 4*check.GiB, 60, true)},
+			expectedOrphaned: check.SeverityOK,
+			expectedInUse:    check.SeverityWarn,
+		},
+		{
+			name:             "files left by a crashed backend",
+			rows:             []db.TempFilesRow{makeRow("pgsql_tmp1001.3", 3*check.GiB, 86400*4, false), makeRow("pgsql_tmp4242.0", check.MiB, 5, true)},
+			expectedOrphaned: check.SeverityWarn,
+			expectedInUse:    check.SeverityOK,
+			expectedBytes:    3 * check.GiB,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := tempfiles.New(&mockQueryer{rows: tt.rows}).Check(context.Background())
+			require.NoError(t, err)
+			require.Len(t, report.Results, 2)
+
+			orphaned := checktest.Finding(t, report, "orphaned-temp-files")
+			assert.Equal(t, tt.expectedOrphaned, orphaned.Severity)
+			assert.Equal(t, tt.expectedBytes, orphaned.ReclaimableBytes)
+			assert.Equal(t, tt.expectedInUse, checktest.Finding(t, report, "temp-files-in-use").Severity)
+		})
+	}
+}
+
+func Test_TempFiles_ListsLargestFiles(t *testing.T) {
+	t.Parallel()
+
+	var rows []db.TempFilesRow
+	for range 25 {
+		rows = append(rows, makeRow("pgsql_tmp1001.0", check.MiB, 3600, false))
+	}
+
+	report, err := tempfiles.New(&mockQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	orphaned := checktest.Finding(t, report, "orphaned-temp-files")
+	require.NotNil(t, orphaned.Table)
+	assert.Len(t, orphaned.Table.Rows, 20)
+	assert.Equal(t, []string{"pg_default", "pgsql_tmp1001.0", "1.0MiB", "1h"}, orphaned.Table.Rows[0].Cells)
+	assert.Contains(t, orphaned.Details, "... and 5 more")
+}
+
+func Test_TempFiles_RequiresPgLsTmpdir(t *testing.T) {
+	t.Parallel()
+
+	requires := tempfiles.Metadata().Requires
+	require.Len(t, requires, 1)
+	assert.Equal(t, check.PrerequisiteFunction, requires[0].Kind)
+	assert.Equal(t, "pg_ls_tmpdir(oid)", requires[0].Name)
+}
+
+func Test_TempFiles_QueryError(t *testing.T) {
+	t.Parallel()
+
+	_, err := tempfiles.New(&mockQueryer{err: errors.New("boom")}).Check(context.Background())
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "configs/temp-files")
+}
@@ -0,0 +1,15 @@
+-- name: TempFiles :many
+-- Temporary files in every tablespace's pgsql_tmp directory, with whether the
+-- backend that created them (pgsql_tmp<PID>.<N>) is still running.
+SELECT
+  ts.spcname::text AS tablespace
+  , f.name::text AS file_name
+  , f.size AS size_bytes
+  , EXTRACT(EPOCH FROM (NOW() - f.modification))::bigint AS age_seconds
+  , (a.pid IS NOT NULL) AS backend_alive
+FROM pg_tablespace AS ts
+CROSS JOIN LATERAL pg_ls_tmpdir(ts.oid) AS f
+LEFT JOIN pg_stat_activity AS a
+  ON SUBSTRING(f.name FROM '^pgsql_tmp([0-9]+)')::integer = a.pid
+WHERE ts.spcname <> 'pg_global'
+ORDER BY f.size DESC;
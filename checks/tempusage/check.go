@@ -33,6 +33,7 @@ func Metadata() check.Metadata {
 		Description: "Monitors temporary file creation indicating work_mem exhaustion",
 		Readme:      readme,
 		SQL:         querySQL,
+		Tags:        []string{check.TagTriage},
 	}
 }
 
@@ -67,8 +68,8 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	}
 
 	// Run all subchecks
-	checkTempFileRate(row, report)
-	checkTempVolumeRate(row, report)
+	checkTempFileRate(ctx, row, report)
+	checkTempVolumeRate(ctx, row, report)
 
 	return report, nil
 }
@@ -100,7 +101,7 @@ func getTempBytesPerHour(row db.TempUsageRow) float64 {
 // checkTempFileRate identifies high temp file creation rates.
 // Thresholds are tuned for production scale based on observed baselines (~0.3 files/hour).
 // These catch regressions (query plan changes, work_mem resets) rather than absolute badness.
-func checkTempFileRate(row db.TempUsageRow, report *check.Report) {
+func checkTempFileRate(ctx context.Context, row db.TempUsageRow, report *check.Report) {
 	rate := getTempFilesPerHour(row)
 
 	// Threshold: 5 files/hour is ~20x typical production baseline
@@ -135,7 +136,7 @@ func checkTempFileRate(row db.TempUsageRow, report *check.Report) {
 			"High temp file creation rate: %.1f files/hour%s\n\nTotal temp files: %d\nTotal temp data: %s",
 			rate, statsResetInfo,
 			row.TempFiles.Int64,
-			check.FormatBytes(row.TempBytes.Int64),
+			check.FormatSize(ctx, row.TempBytes.Int64),
 		),
 	})
 }
@@ -143,7 +144,7 @@ func checkTempFileRate(row db.TempUsageRow, report *check.Report) {
 // checkTempVolumeRate identifies high temp data volume.
 // Thresholds are tuned for production scale based on observed baselines (~124MB/hour).
 // These catch significant increases in disk spilling rather than absolute usage.
-func checkTempVolumeRate(row db.TempUsageRow, report *check.Report) {
+func checkTempVolumeRate(ctx context.Context, row db.TempUsageRow, report *check.Report) {
 	const oneGB = float64(1024 * 1024 * 1024)
 	const fiveGB = float64(5 * 1024 * 1024 * 1024)
 
@@ -156,7 +157,7 @@ func checkTempVolumeRate(row db.TempUsageRow, report *check.Report) {
 			ID:       "temp-volume-rate",
 			Name:     "Temp Data Volume Rate",
 			Severity: check.SeverityOK,
-			Details:  fmt.Sprintf("Temp data volume is acceptable: %s/hour", check.FormatBytes(int64(bytesPerHour))),
+			Details:  fmt.Sprintf("Temp data volume is acceptable: %s/hour", check.FormatSize(ctx, int64(bytesPerHour))),
 		})
 		return
 	}
@@ -174,7 +175,7 @@ func checkTempVolumeRate(row db.TempUsageRow, report *check.Report) {
 		Severity: severity,
 		Details: fmt.Sprintf(
 			"High temp data volume: %s/hour\n\nThis causes significant disk I/O and slows queries.",
-			check.FormatBytes(int64(bytesPerHour)),
+			check.FormatSize(ctx, int64(bytesPerHour)),
 		),
 	})
 }
@@ -0,0 +1,358 @@
+// Package timezone implements checks for the TimeZone and log_timezone
+// settings: names that are abbreviations, fixed offsets or deprecated
+// aliases, and a server log written in a different time zone than sessions.
+package timezone
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const namesRemediation = "Use Area/Location names from the IANA database, such as Etc/UTC or America/New_York, " +
+	"for the server settings and every ALTER ROLE or ALTER DATABASE override"
+
+// deprecatedZones maps the backward-compatibility links of the IANA time zone
+// database that are still common in configurations to the zone they point to.
+var deprecatedZones = map[string]string{
+	"US/Alaska":         "America/Anchorage",
+	"US/Aleutian":       "America/Adak",
+	"US/Arizona":        "America/Phoenix",
+	"US/Central":        "America/Chicago",
+	"US/East-Indiana":   "America/Indiana/Indianapolis",
+	"US/Eastern":        "America/New_York",
+	"US/Hawaii":         "Pacific/Honolulu",
+	"US/Indiana-Starke": "America/Indiana/Knox",
+	"US/Michigan":       "America/Detroit",
+	"US/Mountain":       "America/Denver",
+	"US/Pacific":        "America/Los_Angeles",
+	"US/Samoa":          "Pacific/Pago_Pago",
+
+	"Canada/Atlantic":     "America/Halifax",
+	"Canada/Central":      "America/Winnipeg",
+	"Canada/Eastern":      "America/Toronto",
+	"Canada/Mountain":     "America/Edmonton",
+	"Canada/Newfoundland": "America/St_Johns",
+	"Canada/Pacific":      "America/Vancouver",
+	"Canada/Saskatchewan": "America/Regina",
+	"Canada/Yukon":        "America/Whitehorse",
+	"Brazil/East":         "America/Sao_Paulo",
+	"Chile/Continental":   "America/Santiago",
+	"Mexico/General":      "America/Mexico_City",
+
+	"America/Buenos_Aires": "America/Argentina/Buenos_Aires",
+	"America/Godthab":      "America/Nuuk",
+	"America/Indianapolis": "America/Indiana/Indianapolis",
+	"America/Montreal":     "America/Toronto",
+	"Asia/Calcutta":        "Asia/Kolkata",
+	"Asia/Chongqing":       "Asia/Shanghai",
+	"Asia/Dacca":           "Asia/Dhaka",
+	"Asia/Harbin":          "Asia/Shanghai",
+	"Asia/Istanbul":        "Europe/Istanbul",
+	"Asia/Katmandu":        "Asia/Kathmandu",
+	"Asia/Macao":           "Asia/Macau",
+	"Asia/Rangoon":         "Asia/Yangon",
+	"Asia/Saigon":          "Asia/Ho_Chi_Minh",
+	"Asia/Tel_Aviv":        "Asia/Jerusalem",
+	"Asia/Thimbu":          "Asia/Thimphu",
+	"Asia/Ulan_Bator":      "Asia/Ulaanbaatar",
+	"Atlantic/Faeroe":      "Atlantic/Faroe",
+	"Australia/ACT":        "Australia/Sydney",
+	"Australia/NSW":        "Australia/Sydney",
+	"Europe/Belfast":       "Europe/London",
+	"Europe/Kiev":          "Europe/Kyiv",
+	"Pacific/Samoa":        "Pacific/Pago_Pago",
+
+	"Cuba":      "America/Havana",
+	"Egypt":     "Africa/Cairo",
+	"Eire":      "Europe/Dublin",
+	"GB":        "Europe/London",
+	"Hongkong":  "Asia/Hong_Kong",
+	"Iceland":   "Atlantic/Reykjavik",
+	"Iran":      "Asia/Tehran",
+	"Israel":    "Asia/Jerusalem",
+	"Jamaica":   "America/Jamaica",
+	"Japan":     "Asia/Tokyo",
+	"Libya":     "Africa/Tripoli",
+	"Navajo":    "America/Denver",
+	"NZ":        "Pacific/Auckland",
+	"Poland":    "Europe/Warsaw",
+	"Portugal":  "Europe/Lisbon",
+	"PRC":       "Asia/Shanghai",
+	"ROC":       "Asia/Taipei",
+	"ROK":       "Asia/Seoul",
+	"Singapore": "Asia/Singapore",
+	"Turkey":    "Europe/Istanbul",
+	"W-SU":      "Europe/Moscow",
+
+	"CET":     "Europe/Brussels",
+	"EET":     "Europe/Athens",
+	"MET":     "Europe/Brussels",
+	"WET":     "Europe/Lisbon",
+	"EST":     "America/Panama",
+	"HST":     "Pacific/Honolulu",
+	"MST":     "America/Phoenix",
+	"EST5EDT": "America/New_York",
+	"CST6CDT": "America/Chicago",
+	"MST7MDT": "America/Denver",
+	"PST8PDT": "America/Los_Angeles",
+
+	"Etc/Greenwich": "Etc/UTC",
+	"Etc/UCT":       "Etc/UTC",
+	"Etc/Universal": "Etc/UTC",
+	"Etc/Zulu":      "Etc/UTC",
+	"GMT0":          "Etc/UTC",
+	"Greenwich":     "Etc/UTC",
+	"UCT":           "Etc/UTC",
+	"Universal":     "Etc/UTC",
+	"Zulu":          "Etc/UTC",
+}
+
+// utcZones are the current names for UTC, which are all equivalent.
+var utcZones = []string{"UTC", "Etc/UTC", "GMT", "Etc/GMT"}
+
+var (
+	// abbreviationPattern matches names that are an abbreviation rather than
+	// a location, such as PST.
+	abbreviationPattern = regexp.MustCompile(`^[A-Za-z]{3,5}$`)
+	// offsetPattern matches POSIX-style zones and Etc/GMT offsets, such as
+	// UTC+3, <+03>-3 or Etc/GMT-3. Their sign is the opposite of ISO 8601.
+	offsetPattern = regexp.MustCompile(`[0-9]`)
+)
+
+type TimezoneQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+	TimezoneOverrides(context.Context) ([]db.TimezoneOverridesRow, error)
+}
+
+type checker struct {
+	queries TimezoneQueries
+}
+
+// zoneSetting is one place a time zone is configured.
+type zoneSetting struct {
+	setting  string
+	scope    string
+	value    string
+	override db.TimezoneOverridesRow
+	server   bool
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "timezone",
+		Name:        "Time Zone Settings",
+		Description: "Flags abbreviated, fixed-offset or deprecated time zone names and a log_timezone different from TimeZone",
+		Readme:      readme,
+		SQL:         querySQL + "\n" + catalog.SQL,
+	}
+}
+
+func New(queries TimezoneQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	overrides, err := c.queries.TimezoneOverrides(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+	timeZone := settings["TimeZone"].Setting.String
+	logTimeZone := settings["log_timezone"].Setting.String
+
+	zones := []zoneSetting{
+		{setting: "TimeZone", scope: "server", value: timeZone, server: true},
+		{setting: "log_timezone", scope: "server", value: logTimeZone, server: true},
+	}
+	for _, override := range overrides {
+		zones = append(zones, zoneSetting{setting: "TimeZone", scope: overrideScope(override), value: override.SettingValue, override: override})
+	}
+
+	provider := check.ProviderFromContext(ctx)
+	checkZoneNames(zones, provider, report)
+	checkLogTimezone(timeZone, logTimeZone, provider, report)
+
+	return report, nil
+}
+
+// checkZoneNames warns on time zones that are not a current location name:
+// they hide or misstate daylight saving time, or may disappear from future
+// tzdata releases.
+func checkZoneNames(zones []zoneSetting, provider check.Provider, report *check.Report) {
+	var tableRows []check.TableRow
+	var fixes []string
+	remediation := namesRemediation
+	for _, zone := range zones {
+		problem, replacement := zoneProblem(zone.value)
+		if problem == "" {
+			continue
+		}
+		if replacement != "" {
+			fix := overrideFix(zone.override, replacement)
+			if zone.server {
+				var settingRemediation string
+				settingRemediation, fix = provider.SettingFix(zone.setting, replacement)
+				if fix == "" {
+					remediation = settingRemediation + ". " + namesRemediation
+				}
+			}
+			if fix != "" {
+				fixes = append(fixes, fix)
+			}
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{zone.setting, zone.scope, zone.value, problem, replacement},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "timezone-names",
+			Name:     "Time Zone Names",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%d time zone setting(s) use location names", len(zones)),
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "timezone-names",
+		Name:     "Time Zone Names",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d time zone setting(s) use abbreviations, fixed offsets or deprecated names: "+
+			"timestamps read differently from what the name suggests around daylight saving changes, or the name "+
+			"may be dropped by a future tzdata release", len(tableRows)),
+		Remediation: remediation,
+		FixSQL:      strings.Join(fixes, "\n"),
+		Table: &check.Table{
+			Headers: []string{"Setting", "Scope", "Value", "Problem", "Use Instead"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkLogTimezone warns when the server log is written in a different time
+// zone than sessions use, so timestamps from the log and from queries do not
+// line up when reconstructing an incident.
+func checkLogTimezone(timeZone, logTimeZone string, provider check.Provider, report *check.Report) {
+	if canonicalZone(timeZone) == canonicalZone(logTimeZone) {
+		report.AddFinding(check.Finding{
+			ID:       "timezone-mismatch",
+			Name:     "Log Time Zone",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("TimeZone (%s) and log_timezone (%s) match", timeZone, logTimeZone),
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.SettingFix("log_timezone", canonicalZone(timeZone))
+	report.AddFinding(check.Finding{
+		ID:       "timezone-mismatch",
+		Name:     "Log Time Zone",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("TimeZone is %s but log_timezone is %s: times in the server log and in query results "+
+			"differ by the offset between the two", timeZone, logTimeZone),
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "log_timezone"},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+	})
+}
+
+// zoneProblem says what is wrong with a time zone name, and which name to use
+// instead when there is a direct equivalent.
+func zoneProblem(name string) (problem, replacement string) {
+	if name == "" || isUTC(name) {
+		return "", ""
+	}
+	for alias, zone := range deprecatedZones {
+		if strings.EqualFold(alias, name) {
+			return "deprecated alias", zone
+		}
+	}
+	switch {
+	case strings.EqualFold(name, "localtime"):
+		return "follows the host's /etc/localtime", ""
+	case offsetPattern.MatchString(name):
+		return "fixed offset, with the sign inverted and no daylight saving time", ""
+	case abbreviationPattern.MatchString(name):
+		return "abbreviation, not a location", ""
+	}
+	return "", ""
+}
+
+// canonicalZone resolves deprecated aliases and spells every UTC name the same
+// way, so equivalent settings compare equal.
+func canonicalZone(name string) string {
+	for alias, zone := range deprecatedZones {
+		if strings.EqualFold(alias, name) {
+			name = zone
+			break
+		}
+	}
+	if isUTC(name) {
+		return "Etc/UTC"
+	}
+	return name
+}
+
+func isUTC(name string) bool {
+	for _, utc := range utcZones {
+		if strings.EqualFold(utc, name) {
+			return true
+		}
+	}
+	return false
+}
+
+func overrideScope(row db.TimezoneOverridesRow) string {
+	switch {
+	case row.RoleName != "" && row.DatabaseName != "":
+		return fmt.Sprintf("role %s in database %s", row.RoleName, row.DatabaseName)
+	case row.RoleName != "":
+		return "role " + row.RoleName
+	case row.DatabaseName != "":
+		return "database " + row.DatabaseName
+	}
+	return "all roles"
+}
+
+func overrideFix(row db.TimezoneOverridesRow, zone string) string {
+	switch {
+	case row.RoleName != "" && row.DatabaseName != "":
+		return fmt.Sprintf("ALTER ROLE %s IN DATABASE %s SET timezone = '%s';",
+			pgx.Identifier{row.RoleName}.Sanitize(), pgx.Identifier{row.DatabaseName}.Sanitize(), zone)
+	case row.RoleName != "":
+		return fmt.Sprintf("ALTER ROLE %s SET timezone = '%s';", pgx.Identifier{row.RoleName}.Sanitize(), zone)
+	case row.DatabaseName != "":
+		return fmt.Sprintf("ALTER DATABASE %s SET timezone = '%s';", pgx.Identifier{row.DatabaseName}.Sanitize(), zone)
+	}
+	return fmt.Sprintf("ALTER ROLE ALL SET timezone = '%s';", zone)
+}
@@ -0,0 +1,208 @@
+package timezone_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/timezone"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockTimezoneQueryer struct {
+	settings  []db.CatalogSettingsRow
+	overrides []db.TimezoneOverridesRow
+	err       error
+}
+
+func (m *mockTimezoneQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func (m *mockTimezoneQueryer) TimezoneOverrides(context.Context) ([]db.TimezoneOverridesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.overrides, nil
+}
+
+func zones(timeZone, logTimeZone string) []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{
+		{Name: pgtype.Text{String: "TimeZone", Valid: true}, Setting: pgtype.Text{String: timeZone, Valid: true}},
+		{Name: pgtype.Text{String: "log_timezone", Valid: true}, Setting: pgtype.Text{String: logTimeZone, Valid: true}},
+	}
+}
+
+func Test_Timezone(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		settings  []db.CatalogSettingsRow
+		overrides []db.TimezoneOverridesRow
+		names     check.Severity
+		mismatch  check.Severity
+	}{
+		{
+			name:     "UTC everywhere",
+			settings: zones("Etc/UTC", "Etc/UTC"),
+			names:    check.SeverityOK,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:     "UTC spelled differently",
+			settings: zones("UTC", "Etc/UTC"),
+			names:    check.SeverityOK,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:     "location names",
+			settings: zones("Europe/Berlin", "Europe/Berlin"),
+			names:    check.SeverityOK,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:     "log in a different zone",
+			settings: zones("America/New_York", "Etc/UTC"),
+			names:    check.SeverityOK,
+			mismatch: check.SeverityWarn,
+		},
+		{
+			name:     "deprecated alias",
+			settings: zones("US/Eastern", "America/New_York"),
+			names:    check.SeverityWarn,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:     "abbreviation",
+			settings: zones("PDT", "PDT"),
+			names:    check.SeverityWarn,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:     "POSIX offset",
+			settings: zones("UTC+3", "UTC+3"),
+			names:    check.SeverityWarn,
+			mismatch: check.SeverityOK,
+		},
+		{
+			name:      "role override with a deprecated name",
+			settings:  zones("Etc/UTC", "Etc/UTC"),
+			overrides: []db.TimezoneOverridesRow{{RoleName: "reporting", SettingValue: "Asia/Calcutta"}},
+			names:     check.SeverityWarn,
+			mismatch:  check.SeverityOK,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := timezone.New(&mockTimezoneQueryer{settings: tt.settings, overrides: tt.overrides})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 2)
+			require.Equal(t, tt.names, checktest.Finding(t, report, "timezone-names").Severity)
+			require.Equal(t, tt.mismatch, checktest.Finding(t, report, "timezone-mismatch").Severity)
+		})
+	}
+}
+
+func Test_Timezone_Fixes(t *testing.T) {
+	t.Parallel()
+
+	checker := timezone.New(&mockTimezoneQueryer{
+		settings: zones("US/Pacific", "EST"),
+		overrides: []db.TimezoneOverridesRow{
+			{RoleName: "reporting", DatabaseName: "app", SettingValue: "Asia/Calcutta"},
+			{DatabaseName: "legacy", SettingValue: "Etc/GMT-3"},
+			{SettingValue: "Europe/Berlin"},
+		},
+	})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	names := checktest.Finding(t, report, "timezone-names")
+	require.Equal(t, []string{"TimeZone", "server", "US/Pacific", "deprecated alias", "America/Los_Angeles"}, names.Table.Rows[0].Cells)
+	require.Equal(t, []string{"log_timezone", "server", "EST", "deprecated alias", "America/Panama"}, names.Table.Rows[1].Cells)
+	require.Equal(t, []string{"TimeZone", "role reporting in database app", "Asia/Calcutta", "deprecated alias", "Asia/Kolkata"},
+		names.Table.Rows[2].Cells)
+	require.Equal(t, "database legacy", names.Table.Rows[3].Cells[1])
+	require.Contains(t, names.Table.Rows[3].Cells[3], "fixed offset")
+	require.Len(t, names.Table.Rows, 4)
+	require.Equal(t, "ALTER SYSTEM SET TimeZone = 'America/Los_Angeles';\nSELECT pg_reload_conf();\n"+
+		"ALTER SYSTEM SET log_timezone = 'America/Panama';\nSELECT pg_reload_conf();\n"+
+		`ALTER ROLE "reporting" IN DATABASE "app" SET timezone = 'Asia/Kolkata';`, names.FixSQL)
+
+	mismatch := checktest.Finding(t, report, "timezone-mismatch")
+	require.Equal(t, check.SeverityWarn, mismatch.Severity)
+	require.Equal(t, "ALTER SYSTEM SET log_timezone = 'America/Los_Angeles';\nSELECT pg_reload_conf();", mismatch.FixSQL)
+}
+
+func Test_Timezone_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := timezone.New(&mockTimezoneQueryer{settings: zones("US/Eastern", "US/Eastern")}).Check(ctx)
+	require.NoError(t, err)
+
+	names := checktest.Finding(t, report, "timezone-names")
+	require.Empty(t, names.FixSQL)
+	require.Contains(t, names.Remediation, "Set TimeZone to America/New_York in the DB parameter group")
+}
+
+func Test_Timezone_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().
+		Add("CatalogSettings",
+			[]string{
+				"name text", "setting text", "unit text", "source text", "boot_val text",
+				"reset_val text", "context text", "vartype text", "pending_restart bool",
+			},
+			[]any{"TimeZone", "Europe/Kiev", nil, "configuration file", "GMT", "Europe/Kiev", "user", "string", "f"},
+			[]any{"log_timezone", "Europe/Kyiv", nil, "configuration file", "GMT", "Europe/Kyiv", "sighup", "string", "f"},
+		).
+		Add("TimezoneOverrides",
+			[]string{"role_name text", "database_name text", "setting_value text"},
+			[]any{"", "", "Japan"},
+		)
+
+	report, err := timezone.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	names := checktest.Finding(t, report, "timezone-names")
+	require.Equal(t, []string{"TimeZone", "all roles", "Japan", "deprecated alias", "Asia/Tokyo"}, names.Table.Rows[1].Cells)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "timezone-mismatch").Severity)
+}
+
+func Test_Timezone_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := timezone.New(&mockTimezoneQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "timezone")
+}
+
+func Test_Timezone_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := timezone.Metadata()
+
+	require.Equal(t, "timezone", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
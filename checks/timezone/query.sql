@@ -0,0 +1,14 @@
+-- name: TimezoneOverrides :many
+-- TimeZone set with ALTER ROLE or ALTER DATABASE, for any role and database.
+-- role_name and database_name are empty when the setting applies to all
+-- roles or to all databases. log_timezone can only be set server-wide.
+SELECT
+  COALESCE(r.rolname, '')::text AS role_name
+  , COALESCE(d.datname, '')::text AS database_name
+  , substring(cfg FROM position('=' IN cfg) + 1)::text AS setting_value
+FROM pg_db_role_setting AS drs
+CROSS JOIN LATERAL unnest(coalesce(drs.setconfig, ARRAY[]::text [])) AS cfg
+LEFT JOIN pg_roles AS r ON drs.setrole = r.oid
+LEFT JOIN pg_database AS d ON drs.setdatabase = d.oid
+WHERE lower(split_part(cfg, '=', 1)) = 'timezone'
+ORDER BY database_name, role_name;
@@ -9,6 +9,7 @@ import (
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 )
 
 //go:embed query.sql
@@ -20,6 +21,8 @@ var readme string
 // ToastStorageQueries defines the database queries needed by this check.
 type ToastStorageQueries interface {
 	ToastStorage(context.Context) ([]db.ToastStorageRow, error)
+	ToastCompressionSupport(context.Context) (db.ToastCompressionSupportRow, error)
+	ColumnStorageOverrides(context.Context) ([]db.ColumnStorageOverridesRow, error)
 }
 
 type checker struct {
@@ -37,6 +40,17 @@ const (
 	wideColumnTextThreshold  = 10000 // 10KB
 )
 
+// storageNames maps pg_attribute.attstorage codes to the names ALTER TABLE ... SET STORAGE takes.
+var storageNames = map[string]string{
+	"p": "PLAIN",
+	"e": "EXTERNAL",
+	"x": "EXTENDED",
+	"m": "MAIN",
+}
+
+const storageRemediation = "Restore the type's default storage with ALTER TABLE ... ALTER COLUMN ... SET STORAGE. " +
+	"The new strategy applies to values written afterwards; existing rows keep theirs until updated or the table is rewritten"
+
 func Metadata() check.Metadata {
 	return check.Metadata{
 		Category:    check.CategorySchema,
@@ -65,6 +79,13 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("failed to analyze TOAST storage: %w", err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.ToastStorageRow) string { return r.SchemaName.String + "." + r.TableName.String })
+
+	overrides, err := c.queries.ColumnStorageOverrides(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("failed to analyze TOAST storage: %w", err)
+	}
+	overrides = check.FilterTables(ctx, overrides, func(r db.ColumnStorageOverridesRow) string { return r.SchemaName + "." + r.TableName })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -73,15 +94,22 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 			Severity: check.SeverityOK,
 			Details:  "No tables with significant TOAST storage found",
 		})
+		checkStorageStrategy(overrides, report)
 		return report, nil
 	}
 
+	support, err := c.queries.ToastCompressionSupport(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("failed to analyze TOAST storage: %w", err)
+	}
+
 	// Run all subchecks
-	checkExcessiveToastRatio(rows, report)
-	checkLargeToastTables(rows, report)
-	checkToastBloat(rows, report)
-	checkWideColumns(rows, report)
-	checkCompressionAlgorithm(ctx, rows, report)
+	checkExcessiveToastRatio(ctx, rows, report)
+	checkLargeToastTables(ctx, rows, report)
+	checkToastBloat(ctx, rows, report)
+	checkWideColumns(ctx, rows, report)
+	checkCompressionAlgorithm(ctx, rows, support, report)
+	checkStorageStrategy(overrides, report)
 
 	return report, nil
 }
@@ -96,7 +124,7 @@ func getToastPercent(row db.ToastStorageRow) float64 {
 }
 
 // checkExcessiveToastRatio identifies tables where TOAST dominates storage.
-func checkExcessiveToastRatio(rows []db.ToastStorageRow, report *check.Report) {
+func checkExcessiveToastRatio(ctx context.Context, rows []db.ToastStorageRow, report *check.Report) {
 	var critical []db.ToastStorageRow
 	var warning []db.ToastStorageRow
 
@@ -127,9 +155,9 @@ func checkExcessiveToastRatio(rows []db.ToastStorageRow, report *check.Report) {
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
 				fmt.Sprintf("%.1f%%", getToastPercent(row)),
-				check.FormatBytes(row.ToastSize.Int64),
-				check.FormatBytes(row.MainTableSize.Int64),
-				check.FormatBytes(row.TotalSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
+				check.FormatSize(ctx, row.MainTableSize.Int64),
+				check.FormatSize(ctx, row.TotalSize.Int64),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -140,9 +168,9 @@ func checkExcessiveToastRatio(rows []db.ToastStorageRow, report *check.Report) {
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
 				fmt.Sprintf("%.1f%%", getToastPercent(row)),
-				check.FormatBytes(row.ToastSize.Int64),
-				check.FormatBytes(row.MainTableSize.Int64),
-				check.FormatBytes(row.TotalSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
+				check.FormatSize(ctx, row.MainTableSize.Int64),
+				check.FormatSize(ctx, row.TotalSize.Int64),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -161,7 +189,7 @@ func checkExcessiveToastRatio(rows []db.ToastStorageRow, report *check.Report) {
 }
 
 // checkLargeToastTables identifies tables with very large TOAST storage.
-func checkLargeToastTables(rows []db.ToastStorageRow, report *check.Report) {
+func checkLargeToastTables(ctx context.Context, rows []db.ToastStorageRow, report *check.Report) {
 	var critical []db.ToastStorageRow
 	var warning []db.ToastStorageRow
 
@@ -191,7 +219,7 @@ func checkLargeToastTables(rows []db.ToastStorageRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
-				check.FormatBytes(row.ToastSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
 				fmt.Sprintf("%.1f%%", getToastPercent(row)),
 				formatWideColumns(row.WideColumns),
 			},
@@ -203,7 +231,7 @@ func checkLargeToastTables(rows []db.ToastStorageRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
-				check.FormatBytes(row.ToastSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
 				fmt.Sprintf("%.1f%%", getToastPercent(row)),
 				formatWideColumns(row.WideColumns),
 			},
@@ -224,7 +252,7 @@ func checkLargeToastTables(rows []db.ToastStorageRow, report *check.Report) {
 }
 
 // checkToastBloat identifies TOAST tables with high dead tuple ratio.
-func checkToastBloat(rows []db.ToastStorageRow, report *check.Report) {
+func checkToastBloat(ctx context.Context, rows []db.ToastStorageRow, report *check.Report) {
 	const bloatFailPercent = 50 // >50% dead tuples is critical
 	const bloatWarnPercent = 30 // >30% dead tuples needs attention
 
@@ -270,7 +298,7 @@ func checkToastBloat(rows []db.ToastStorageRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
-				check.FormatBytes(row.ToastSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
 				fmt.Sprintf("%.1f%%", bloatPercent),
 				check.FormatNumber(row.ToastDeadTuples.Int64),
 				check.FormatNumber(row.ToastLiveTuples.Int64),
@@ -286,7 +314,7 @@ func checkToastBloat(rows []db.ToastStorageRow, report *check.Report) {
 		tableRows = append(tableRows, check.TableRow{
 			Cells: []string{
 				fmt.Sprintf("%s.%s", row.SchemaName.String, row.TableName.String),
-				check.FormatBytes(row.ToastSize.Int64),
+				check.FormatSize(ctx, row.ToastSize.Int64),
 				fmt.Sprintf("%.1f%%", bloatPercent),
 				check.FormatNumber(row.ToastDeadTuples.Int64),
 				check.FormatNumber(row.ToastLiveTuples.Int64),
@@ -308,7 +336,7 @@ func checkToastBloat(rows []db.ToastStorageRow, report *check.Report) {
 }
 
 // checkWideColumns identifies tables with columns likely causing TOAST usage.
-func checkWideColumns(rows []db.ToastStorageRow, report *check.Report) {
+func checkWideColumns(ctx context.Context, rows []db.ToastStorageRow, report *check.Report) {
 	type wideColumnInfo struct {
 		tableName  string
 		columnName string
@@ -368,9 +396,9 @@ func checkWideColumns(rows []db.ToastStorageRow, report *check.Report) {
 			Cells: []string{
 				col.tableName,
 				col.columnName,
-				check.FormatBytes(int64(col.avgWidth)),
+				check.FormatSize(ctx, int64(col.avgWidth)),
 				col.columnType,
-				check.FormatBytes(col.toastSize),
+				check.FormatSize(ctx, col.toastSize),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -381,9 +409,9 @@ func checkWideColumns(rows []db.ToastStorageRow, report *check.Report) {
 			Cells: []string{
 				col.tableName,
 				col.columnName,
-				check.FormatBytes(int64(col.avgWidth)),
+				check.FormatSize(ctx, int64(col.avgWidth)),
 				col.columnType,
-				check.FormatBytes(col.toastSize),
+				check.FormatSize(ctx, col.toastSize),
 			},
 			Severity: check.SeverityWarn,
 		})
@@ -402,19 +430,14 @@ func checkWideColumns(rows []db.ToastStorageRow, report *check.Report) {
 }
 
 // checkCompressionAlgorithm identifies columns using suboptimal compression (pglz instead of lz4).
-func checkCompressionAlgorithm(ctx context.Context, rows []db.ToastStorageRow, report *check.Report) {
-	// Check PostgreSQL version - LZ4 compression is only available in PG14+
-	meta := check.InstanceMetadataFromContext(ctx)
-	pgVersion := 14 // Default assumption
-	if meta != nil && meta.EngineVersion != "" {
-		// Parse major version from "15.4" or "17.0"
-		_, _ = fmt.Sscanf(meta.EngineVersion, "%d", &pgVersion)
-	}
-
-	// If PG < 14, skip this check
-	if pgVersion < 14 {
+// Columns without an explicit method use default_toast_compression, so they
+// only count as pglz while the server default is pglz.
+func checkCompressionAlgorithm(ctx context.Context, rows []db.ToastStorageRow, support db.ToastCompressionSupportRow, report *check.Report) {
+	// LZ4 compression needs PG14+ built with lz4; the server reports both.
+	if !support.Lz4Available {
 		return
 	}
+	defaultIsLZ4 := support.DefaultCompression == "lz4"
 
 	type compressionIssue struct {
 		tableName          string
@@ -443,7 +466,7 @@ func checkCompressionAlgorithm(ctx context.Context, rows []db.ToastStorageRow, r
 
 			// Flag columns using default (pglz) or explicitly set to pglz
 			// LZ4 is better for jsonb, text, and most use cases
-			if compressionAlgo == "default" || compressionAlgo == "pglz" {
+			if compressionAlgo == "pglz" || (compressionAlgo == "default" && !defaultIsLZ4) {
 				action := "SET COMPRESSION lz4"
 				if colType == "bytea" && storageStrategy == "EXTENDED" {
 					// For binary data, consider EXTERNAL (no compression)
@@ -489,7 +512,7 @@ func checkCompressionAlgorithm(ctx context.Context, rows []db.ToastStorageRow, r
 		})
 	}
 
-	report.AddFinding(check.Finding{
+	finding := check.Finding{
 		ID:       "compression-algorithm",
 		Name:     "TOAST Compression Algorithm",
 		Severity: check.SeverityWarn,
@@ -498,7 +521,93 @@ func checkCompressionAlgorithm(ctx context.Context, rows []db.ToastStorageRow, r
 			Headers: headers,
 			Rows:    tableRows,
 		},
-	})
+	}
+	if !defaultIsLZ4 {
+		// New columns and columns without an explicit method follow the default.
+		finding.Details += fmt.Sprintf("; lz4 is available but default_toast_compression is %s", support.DefaultCompression)
+		finding.Object = &check.Object{Kind: check.ObjectSetting, Name: "default_toast_compression"}
+		finding.Remediation, finding.FixSQL = check.ProviderFromContext(ctx).SettingFix("default_toast_compression", "lz4")
+	}
+	report.AddFinding(finding)
+}
+
+// checkStorageStrategy lists columns whose storage strategy differs from their
+// type's default. PLAIN on a TOAST-able type makes large rows fail to insert,
+// and EXTERNAL on json or jsonb stores documents uncompressed for no benefit:
+// substring access, EXTERNAL's reason to exist, does not apply to them.
+func checkStorageStrategy(overrides []db.ColumnStorageOverridesRow, report *check.Report) {
+	if len(overrides) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "storage-strategy",
+			Name:     "Column Storage Strategy",
+			Severity: check.SeverityOK,
+			Details:  "All columns use their type's default storage strategy",
+		})
+		return
+	}
+
+	var fixes []string
+	severity := check.SeverityOK
+	risky := 0
+	tableRows := make([]check.TableRow, 0, len(overrides))
+	for _, col := range overrides {
+		rowSeverity := check.SeverityOK
+		note := "-"
+		switch {
+		case col.Storage == "p":
+			rowSeverity, note = check.SeverityWarn, "values are never compressed or moved out: rows above about 8kB fail to insert"
+		case col.Storage == "e" && (col.DataType == "json" || col.DataType == "jsonb"):
+			rowSeverity, note = check.SeverityWarn, "stored uncompressed"
+		case col.Storage == "e":
+			note = "stored uncompressed, for fast substring access or pre-compressed data"
+		case col.Storage == "m":
+			note = "compressed and kept inline while the row fits"
+		}
+
+		if rowSeverity > check.SeverityOK {
+			severity = rowSeverity
+			risky++
+			fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s ALTER COLUMN %s SET STORAGE %s;",
+				pgx.Identifier{col.SchemaName, col.TableName}.Sanitize(),
+				pgx.Identifier{col.ColumnName}.Sanitize(),
+				storageName(col.TypeStorage)))
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				col.SchemaName + "." + col.TableName,
+				col.ColumnName,
+				col.DataType,
+				storageName(col.Storage),
+				storageName(col.TypeStorage),
+				note,
+			},
+			Severity: rowSeverity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       "storage-strategy",
+		Name:     "Column Storage Strategy",
+		Severity: severity,
+		Details:  fmt.Sprintf("%d column(s) override their type's storage strategy", len(overrides)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Column", "Type", "Storage", "Type Default", "Note"},
+			Rows:    tableRows,
+		},
+	}
+	if risky > 0 {
+		finding.Details = fmt.Sprintf("%d of %d column(s) with a storage strategy override risk failed inserts or wasted space", risky, len(overrides))
+		finding.Remediation = storageRemediation
+		finding.FixSQL = strings.Join(fixes, "\n")
+	}
+	report.AddFinding(finding)
+}
+
+func storageName(code string) string {
+	if name, ok := storageNames[code]; ok {
+		return name
+	}
+	return code
 }
 
 // Helper functions
@@ -8,6 +8,7 @@ import (
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/checks/toaststorage"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/stretchr/testify/require"
 )
@@ -18,11 +19,14 @@ const (
 	findingIDToastBloat           = "toast-bloat"
 	findingIDWideColumns          = "wide-columns"
 	findingIDCompressionAlgorithm = "compression-algorithm"
+	findingIDStorageStrategy      = "storage-strategy"
 )
 
 type mockQueryer struct {
-	rows []db.ToastStorageRow
-	err  error
+	rows      []db.ToastStorageRow
+	support   *db.ToastCompressionSupportRow
+	overrides []db.ColumnStorageOverridesRow
+	err       error
 }
 
 func (m *mockQueryer) ToastStorage(context.Context) ([]db.ToastStorageRow, error) {
@@ -32,6 +36,19 @@ func (m *mockQueryer) ToastStorage(context.Context) ([]db.ToastStorageRow, error
 	return m.rows, nil
 }
 
+// ToastCompressionSupport defaults to a PostgreSQL 14+ server built with lz4
+// and the stock pglz default.
+func (m *mockQueryer) ToastCompressionSupport(context.Context) (db.ToastCompressionSupportRow, error) {
+	if m.support != nil {
+		return *m.support, nil
+	}
+	return db.ToastCompressionSupportRow{DefaultCompression: "pglz", Lz4Available: true}, nil
+}
+
+func (m *mockQueryer) ColumnStorageOverrides(context.Context) ([]db.ColumnStorageOverridesRow, error) {
+	return m.overrides, nil
+}
+
 func makeToastRow(schema, table, toastTable string, mainSize, toastSize, totalSize int64, toastPercent float64) db.ToastStorageRow {
 	percentNumeric := &pgtype.Numeric{}
 	_ = percentNumeric.Scan(fmt.Sprintf("%.2f", toastPercent))
@@ -62,8 +79,9 @@ func Test_ToastStorage_NoIssues(t *testing.T) {
 
 	require.NoError(t, err)
 	require.Equal(t, check.SeverityOK, report.Severity)
-	require.Equal(t, 1, len(report.Results))
+	require.Equal(t, 2, len(report.Results))
 	require.Contains(t, report.Results[0].Details, "No tables with significant TOAST storage")
+	require.Equal(t, "All columns use their type's default storage strategy", checktest.Finding(t, report, findingIDStorageStrategy).Details)
 }
 
 func Test_ToastStorage_ExcessiveRatio_FAIL(t *testing.T) {
@@ -413,12 +431,12 @@ func Test_ToastStorage_CompressionAlgorithm_SkipsOnPG13(t *testing.T) {
 		"payload:default:EXTENDED:jsonb",
 	}
 
-	// PostgreSQL 13 (no LZ4 support)
+	// PostgreSQL 13 has no default_toast_compression (no LZ4 support)
 	ctx := check.ContextWithInstanceMetadata(context.Background(), &check.InstanceMetadata{
 		EngineVersion: "13.11",
 	})
 
-	queryer := &mockQueryer{rows: []db.ToastStorageRow{row}}
+	queryer := &mockQueryer{rows: []db.ToastStorageRow{row}, support: &db.ToastCompressionSupportRow{}}
 	checker := toaststorage.New(queryer)
 
 	report, err := checker.Check(ctx)
@@ -465,6 +483,103 @@ func Test_ToastStorage_CompressionAlgorithm_ByteaExternal(t *testing.T) {
 	require.Contains(t, compressionFinding.Table.Rows[0].Cells[5], "EXTERNAL")
 }
 
+func Test_ToastStorage_CompressionAlgorithm_DefaultSetting(t *testing.T) {
+	t.Parallel()
+
+	row := makeToastRow("public", "events", "pg_toast.pg_toast_90123", 10*check.GiB, 15*check.GiB, 25*check.GiB, 60.0)
+	row.ColumnCompressionInfo = []string{
+		"payload:default:EXTENDED:jsonb",
+		"archived:pglz:EXTENDED:jsonb",
+	}
+
+	// lz4 available but not the default: default columns are pglz, and the fix is the setting.
+	report, err := toaststorage.New(&mockQueryer{rows: []db.ToastStorageRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, findingIDCompressionAlgorithm)
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "Found 2 column(s) using suboptimal compression (pglz instead of lz4); "+
+		"lz4 is available but default_toast_compression is pglz", finding.Details)
+	require.Equal(t, "ALTER SYSTEM SET default_toast_compression = 'lz4';\nSELECT pg_reload_conf();", finding.FixSQL)
+
+	// With an lz4 default, only the column pinned to pglz remains.
+	lz4Default := &db.ToastCompressionSupportRow{DefaultCompression: "lz4", Lz4Available: true}
+	report, err = toaststorage.New(&mockQueryer{rows: []db.ToastStorageRow{row}, support: lz4Default}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding = checktest.Finding(t, report, findingIDCompressionAlgorithm)
+	require.Equal(t, "Found 1 column(s) using suboptimal compression (pglz instead of lz4)", finding.Details)
+	require.Equal(t, "archived", finding.Table.Rows[0].Cells[1])
+	require.Empty(t, finding.FixSQL)
+}
+
+func Test_ToastStorage_StorageStrategy(t *testing.T) {
+	t.Parallel()
+
+	override := func(column, dataType, storage, typeStorage string) db.ColumnStorageOverridesRow {
+		return db.ColumnStorageOverridesRow{
+			SchemaName:  "public",
+			TableName:   "documents",
+			ColumnName:  column,
+			DataType:    dataType,
+			Storage:     storage,
+			TypeStorage: typeStorage,
+		}
+	}
+
+	testCases := []struct {
+		Name             string
+		Override         db.ColumnStorageOverridesRow
+		ExpectedSeverity check.Severity
+		ExpectedNote     string
+	}{
+		{
+			Name:             "PLAIN on text warns",
+			Override:         override("body", "text", "p", "x"),
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedNote:     "values are never compressed or moved out: rows above about 8kB fail to insert",
+		},
+		{
+			Name:             "EXTERNAL on jsonb warns",
+			Override:         override("payload", "jsonb", "e", "x"),
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedNote:     "stored uncompressed",
+		},
+		{
+			Name:             "EXTERNAL on bytea - OK",
+			Override:         override("thumbnail", "bytea", "e", "x"),
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedNote:     "stored uncompressed, for fast substring access or pre-compressed data",
+		},
+		{
+			Name:             "MAIN on text - OK",
+			Override:         override("title", "character varying(200)", "m", "x"),
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedNote:     "compressed and kept inline while the row fits",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			queryer := &mockQueryer{overrides: []db.ColumnStorageOverridesRow{tc.Override}}
+			report, err := toaststorage.New(queryer).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, findingIDStorageStrategy)
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedNote, finding.Table.Rows[0].Cells[5])
+			if tc.ExpectedSeverity == check.SeverityWarn {
+				require.Equal(t, "1 of 1 column(s) with a storage strategy override risk failed inserts or wasted space", finding.Details)
+				require.Equal(t, fmt.Sprintf(`ALTER TABLE "public"."documents" ALTER COLUMN "%s" SET STORAGE EXTENDED;`, tc.Override.ColumnName), finding.FixSQL)
+			} else {
+				require.Equal(t, "1 column(s) override their type's storage strategy", finding.Details)
+			}
+		})
+	}
+}
+
 func Test_ToastStorage_MultipleTables_MultipleSeverities(t *testing.T) {
 	t.Parallel()
 
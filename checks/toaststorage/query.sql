@@ -114,3 +114,41 @@ SELECT
   ) AS column_compression_info
 FROM toast_info AS ti
 ORDER BY ti.toast_size DESC;
+
+-- name: ToastCompressionSupport :one
+-- The default TOAST compression and whether the server was built with lz4.
+-- default_toast_compression exists since PostgreSQL 14: earlier servers return '' and false.
+SELECT
+  coalesce(
+    (SELECT s.setting FROM pg_settings AS s WHERE s.name = 'default_toast_compression')
+    , ''
+  )::text AS default_compression
+  , coalesce(
+    (SELECT 'lz4' = ANY(s.enumvals) FROM pg_settings AS s WHERE s.name = 'default_toast_compression')
+    , false
+  ) AS lz4_available;
+
+-- name: ColumnStorageOverrides :many
+-- Columns of TOAST-able types whose storage strategy was changed from their
+-- type's default with ALTER TABLE ... SET STORAGE. Partitions are left out:
+-- they inherit the parent's strategy.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , format_type(a.atttypid, a.atttypmod)::text AS data_type
+  , a.attstorage::text AS storage
+  , t.typstorage::text AS type_storage
+FROM pg_attribute AS a
+INNER JOIN pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_type AS t ON a.atttypid = t.oid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND NOT c.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+  AND t.typstorage <> 'p'
+  AND a.attstorage <> t.typstorage
+ORDER BY n.nspname, c.relname, a.attnum;
@@ -0,0 +1,310 @@
+// Package topqueries implements checks on the heaviest statements recorded by
+// pg_stat_statements.
+package topqueries
+
+import (
+	"cmp"
+	"context"
+	_ "embed"
+	"errors"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultDominantPercent = 50
+	defaultSlowMeanMs      = 1000
+
+	// Statements called fewer times are one-offs (migrations, manual queries)
+	// whose mean time says little.
+	minCallsForMean = 10
+
+	topN = 10
+
+	// Query texts are cut to this many bytes in tables.
+	maxQueryLength = 60
+
+	// objectNotInPrerequisiteState is raised when the extension exists but its
+	// library is not in shared_preload_libraries.
+	objectNotInPrerequisiteState = "55000"
+)
+
+const installHint = "Add pg_stat_statements to shared_preload_libraries and restart, then run CREATE EXTENSION pg_stat_statements; " +
+	"on managed services it is usually preloaded already"
+
+const notLoadedRemediation = "Add pg_stat_statements to shared_preload_libraries and restart the server"
+
+const totalTimeRemediation = "Run EXPLAIN (ANALYZE, BUFFERS) on the dominant statement: an index or a rewrite there " +
+	"frees more database time than tuning anything else"
+
+const slowMeanRemediation = "Run EXPLAIN (ANALYZE, BUFFERS) on these statements with typical parameters " +
+	"and look for sequential scans on large tables, row estimates far from the actual rows, and sorts spilling to disk"
+
+const callsRemediation = "A single statement making up most calls is often an N+1 pattern in the application or an aggressive health check: " +
+	"batch the lookups (WHERE id = ANY($1)) or cache the result"
+
+type TopQueriesQueries interface {
+	TopStatements(context.Context) ([]db.TopStatementsRow, error)
+}
+
+type checker struct {
+	queries         TopQueriesQueries
+	dominantPercent float64 // default: 50
+	slowMeanMs      float64 // default: 1000
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryPerformance,
+		CheckID:      "top-queries",
+		Name:         "Top Queries",
+		Description:  "Reports the heaviest statements by total time, mean time and calls from pg_stat_statements",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 13,
+		Requires: []check.Prerequisite{
+			{Kind: check.PrerequisiteExtension, Name: "pg_stat_statements", Hint: installHint},
+		},
+	}
+}
+
+func New(queries TopQueriesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:         queries,
+		dominantPercent: defaultDominantPercent,
+		slowMeanMs:      defaultSlowMeanMs,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["dominant_percent"]; ok {
+				if n, err := strconv.ParseFloat(v, 64); err == nil && n > 0 && n <= 100 {
+					c.dominantPercent = n
+				}
+			}
+			if v, ok := myCfg["slow_mean_ms"]; ok {
+				if n, err := strconv.ParseFloat(v, 64); err == nil && n > 0 {
+					c.slowMeanMs = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.TopStatements(ctx)
+	if err != nil {
+		// The extension is created but its library was never preloaded.
+		var pgErr *pgconn.PgError
+		if errors.As(err, &pgErr) && pgErr.Code == objectNotInPrerequisiteState {
+			report.AddFinding(check.Finding{
+				ID:          report.CheckID,
+				Name:        report.Name,
+				Severity:    check.SeverityWarn,
+				Details:     "The pg_stat_statements extension is installed but not loaded, so no statements are recorded",
+				Object:      &check.Object{Kind: check.ObjectSetting, Name: "shared_preload_libraries"},
+				Remediation: notLoadedRemediation,
+			})
+			return report, nil
+		}
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "pg_stat_statements has recorded no statements in this database yet",
+		})
+		return report, nil
+	}
+
+	c.checkTotalTime(rows, report)
+	c.checkMeanTime(rows, report)
+	c.checkCalls(rows, report)
+
+	return report, nil
+}
+
+// checkTotalTime lists the statements taking the most database time, and
+// warns when one of them takes most of it.
+func (c *checker) checkTotalTime(rows []db.TopStatementsRow, report *check.Report) {
+	top := topBy(rows, func(r db.TopStatementsRow) float64 { return r.TotalExecTimeMs })
+	allTime := rows[0].AllExecTimeMs
+	share := percent(top[0].TotalExecTimeMs, allTime)
+
+	finding := check.Finding{
+		ID:       "top-total-time",
+		Name:     "Top Queries by Total Time",
+		Severity: check.SeverityOK,
+		Details: fmt.Sprintf("The top statement takes %.0f%% of %s execution time in this database",
+			share, check.FormatDurationMs(allTime)),
+		Metrics: []check.Metric{
+			{Name: "top_statement_time_share", Value: share, Unit: check.UnitPercent},
+		},
+		Table: statementTable(top, check.SeverityOK, func(r db.TopStatementsRow) string {
+			return fmt.Sprintf("%.0f%%", percent(r.TotalExecTimeMs, allTime))
+		}),
+	}
+	if share >= c.dominantPercent && len(rows) > 1 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("Statement %s takes %.0f%% of %s execution time in this database",
+			queryID(top[0]), share, check.FormatDurationMs(allTime))
+		finding.Table.Rows[0].Severity = check.SeverityWarn
+		finding.Remediation = totalTimeRemediation
+	}
+	report.AddFinding(finding)
+}
+
+// checkMeanTime lists the slowest statements run repeatedly, and warns on
+// those slower than slow_mean_ms on average.
+func (c *checker) checkMeanTime(rows []db.TopStatementsRow, report *check.Report) {
+	var repeated []db.TopStatementsRow
+	for _, r := range rows {
+		if r.Calls >= minCallsForMean {
+			repeated = append(repeated, r)
+		}
+	}
+	if len(repeated) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "top-mean-time",
+			Name:     "Top Queries by Mean Time",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No statement has run %d times yet", minCallsForMean),
+		})
+		return
+	}
+
+	top := topBy(repeated, func(r db.TopStatementsRow) float64 { return r.MeanExecTimeMs })
+	table := statementTable(top, check.SeverityOK, nil)
+	slow := 0
+	for i, r := range top {
+		if r.MeanExecTimeMs >= c.slowMeanMs {
+			table.Rows[i].Severity = check.SeverityWarn
+			slow++
+		}
+	}
+
+	if slow == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "top-mean-time",
+			Name:     "Top Queries by Mean Time",
+			Severity: check.SeverityOK,
+			Details: fmt.Sprintf("The slowest repeated statement takes %s on average",
+				check.FormatDurationMs(top[0].MeanExecTimeMs)),
+			Table: table,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "top-mean-time",
+		Name:     "Top Queries by Mean Time",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d repeated statement(s) take %s or more on average; the slowest, %s, takes %s",
+			slow, check.FormatDurationMs(c.slowMeanMs), queryID(top[0]), check.FormatDurationMs(top[0].MeanExecTimeMs)),
+		Remediation: slowMeanRemediation,
+		Table:       table,
+	})
+}
+
+// checkCalls lists the most frequent statements, and warns when one of them
+// makes up most calls.
+func (c *checker) checkCalls(rows []db.TopStatementsRow, report *check.Report) {
+	top := topBy(rows, func(r db.TopStatementsRow) float64 { return float64(r.Calls) })
+	allCalls := rows[0].AllCalls
+	share := percent(float64(top[0].Calls), float64(allCalls))
+
+	finding := check.Finding{
+		ID:       "top-calls",
+		Name:     "Top Queries by Calls",
+		Severity: check.SeverityOK,
+		Details: fmt.Sprintf("The most frequent statement makes up %.0f%% of %s calls in this database",
+			share, check.FormatNumber(allCalls)),
+		Table: statementTable(top, check.SeverityOK, func(r db.TopStatementsRow) string {
+			return fmt.Sprintf("%.0f%%", percent(float64(r.Calls), float64(allCalls)))
+		}),
+	}
+	if share >= c.dominantPercent && len(rows) > 1 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("Statement %s makes up %.0f%% of %s calls in this database",
+			queryID(top[0]), share, check.FormatNumber(allCalls))
+		finding.Table.Rows[0].Severity = check.SeverityWarn
+		finding.Remediation = callsRemediation
+	}
+	report.AddFinding(finding)
+}
+
+// topBy returns up to topN statements in descending order of key.
+func topBy(rows []db.TopStatementsRow, key func(db.TopStatementsRow) float64) []db.TopStatementsRow {
+	sorted := slices.Clone(rows)
+	slices.SortStableFunc(sorted, func(a, b db.TopStatementsRow) int { return cmp.Compare(key(b), key(a)) })
+	return sorted[:min(len(sorted), topN)]
+}
+
+// statementTable renders statements with an optional share column.
+func statementTable(rows []db.TopStatementsRow, severity check.Severity, share func(db.TopStatementsRow) string) *check.Table {
+	headers := []string{"Query ID", "Query", "Calls", "Total", "Mean"}
+	if share != nil {
+		headers = append(headers, "Share")
+	}
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, r := range rows {
+		cells := []string{
+			queryID(r),
+			truncateQuery(r.Query),
+			check.FormatNumber(r.Calls),
+			check.FormatDurationMs(r.TotalExecTimeMs),
+			check.FormatDurationMs(r.MeanExecTimeMs),
+		}
+		if share != nil {
+			cells = append(cells, share(r))
+		}
+		tableRows = append(tableRows, check.TableRow{Cells: cells, Severity: severity})
+	}
+	return &check.Table{Headers: headers, Rows: tableRows}
+}
+
+// queryID is the statement's pg_stat_statements queryid, hidden (0) from
+// users without pg_read_all_stats for other users' statements.
+func queryID(r db.TopStatementsRow) string {
+	if r.QueryID == 0 {
+		return "-"
+	}
+	return strconv.FormatInt(r.QueryID, 10)
+}
+
+func truncateQuery(query string) string {
+	if len(query) <= maxQueryLength {
+		return query
+	}
+	return strings.ToValidUTF8(query[:maxQueryLength-3], "") + "..."
+}
+
+func percent(part, whole float64) float64 {
+	if whole <= 0 {
+		return 0
+	}
+	return part / whole * 100
+}
@@ -0,0 +1,225 @@
+package topqueries_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/topqueries"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/stretchr/testify/require"
+)
+
+type mockTopQueriesQueryer struct {
+	rows []db.TopStatementsRow
+	err  error
+}
+
+func (m *mockTopQueriesQueryer) TopStatements(context.Context) ([]db.TopStatementsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func statement(id int64, query string, calls int64, totalMs float64) db.TopStatementsRow {
+	return db.TopStatementsRow{
+		QueryID:         id,
+		Query:           query,
+		Calls:           calls,
+		TotalExecTimeMs: totalMs,
+		MeanExecTimeMs:  totalMs / float64(calls),
+	}
+}
+
+// statements fills in the database-wide totals, as the query does.
+func statements(rows ...db.TopStatementsRow) []db.TopStatementsRow {
+	var allTime float64
+	var allCalls int64
+	for _, r := range rows {
+		allTime += r.TotalExecTimeMs
+		allCalls += r.Calls
+	}
+	for i := range rows {
+		rows[i].AllExecTimeMs = allTime
+		rows[i].AllCalls = allCalls
+	}
+	return rows
+}
+
+// balanced is a workload without outliers: no statement dominates time or
+// calls, and none is slow on average.
+func balanced() []db.TopStatementsRow {
+	return statements(
+		statement(101, "SELECT * FROM orders WHERE id = $1", 40000, 40000),
+		statement(102, "SELECT * FROM users WHERE id = $1", 35000, 35000),
+		statement(103, "UPDATE orders SET status = $1 WHERE id = $2", 25000, 30000),
+	)
+}
+
+func Test_TopQueries_Balanced(t *testing.T) {
+	t.Parallel()
+
+	report, err := topqueries.New(&mockTopQueriesQueryer{rows: balanced()}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 3)
+	require.Equal(t, check.SeverityOK, report.Severity)
+
+	total := checktest.Finding(t, report, "top-total-time")
+	require.Equal(t, "The top statement takes 38% of 1.8m execution time in this database", total.Details)
+	require.Equal(t, []string{"101", "SELECT * FROM orders WHERE id = $1", "40.0K", "40.0s", "1ms", "38%"}, total.Table.Rows[0].Cells)
+
+	mean := checktest.Finding(t, report, "top-mean-time")
+	require.Equal(t, "The slowest repeated statement takes 1ms on average", mean.Details)
+	require.Equal(t, "103", mean.Table.Rows[0].Cells[0])
+
+	calls := checktest.Finding(t, report, "top-calls")
+	require.Equal(t, "The most frequent statement makes up 40% of 100.0K calls in this database", calls.Details)
+}
+
+func Test_TopQueries_DominantTotalTime(t *testing.T) {
+	t.Parallel()
+
+	rows := statements(
+		statement(201, "SELECT count(*) FROM events WHERE created_at > $1", 500, 900000),
+		statement(202, "SELECT * FROM users WHERE id = $1", 60000, 60000),
+	)
+	report, err := topqueries.New(&mockTopQueriesQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "top-total-time")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "Statement 201 takes 94% of 16.0m execution time in this database", finding.Details)
+	require.Equal(t, check.SeverityWarn, finding.Table.Rows[0].Severity)
+	require.Equal(t, check.SeverityOK, finding.Table.Rows[1].Severity)
+
+	mean := checktest.Finding(t, report, "top-mean-time")
+	require.Equal(t, check.SeverityWarn, mean.Severity)
+	require.Equal(t, "1 repeated statement(s) take 1.0s or more on average; the slowest, 201, takes 1.8s", mean.Details)
+}
+
+func Test_TopQueries_SlowMean(t *testing.T) {
+	t.Parallel()
+
+	rows := append(balanced(), statement(301, "SELECT * FROM report_monthly($1)", 20, 50000))
+	rows = statements(rows...)
+
+	testCases := []struct {
+		Name             string
+		Config           check.Config
+		ExpectedSeverity check.Severity
+	}{
+		{Name: "default threshold warns", ExpectedSeverity: check.SeverityWarn},
+		{Name: "configured threshold", Config: check.Config{"top-queries": {"slow_mean_ms": "5000"}}, ExpectedSeverity: check.SeverityOK},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := topqueries.New(&mockTopQueriesQueryer{rows: rows}, tc.Config).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "top-mean-time")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, "301", finding.Table.Rows[0].Cells[0])
+		})
+	}
+}
+
+func Test_TopQueries_OneOffsIgnoredForMean(t *testing.T) {
+	t.Parallel()
+
+	rows := statements(append(balanced(), statement(401, "CREATE INDEX CONCURRENTLY ...", 1, 600000))...)
+	report, err := topqueries.New(&mockTopQueriesQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	mean := checktest.Finding(t, report, "top-mean-time")
+	require.Equal(t, check.SeverityOK, mean.Severity)
+	require.Len(t, mean.Table.Rows, 3)
+}
+
+func Test_TopQueries_DominantCalls(t *testing.T) {
+	t.Parallel()
+
+	rows := statements(
+		statement(0, "<insufficient privilege>", 900000, 90000),
+		statement(501, "SELECT * FROM users WHERE id = $1", 100000, 100000),
+	)
+	report, err := topqueries.New(&mockTopQueriesQueryer{rows: rows}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "top-calls")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "Statement - makes up 90% of 1.0M calls in this database", finding.Details)
+}
+
+func Test_TopQueries_Empty(t *testing.T) {
+	t.Parallel()
+
+	report, err := topqueries.New(&mockTopQueriesQueryer{}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityOK, report.Severity)
+}
+
+func Test_TopQueries_NotLoaded(t *testing.T) {
+	t.Parallel()
+
+	err := &pgconn.PgError{Code: "55000", Message: "pg_stat_statements must be loaded via \"shared_preload_libraries\""}
+	report, checkErr := topqueries.New(&mockTopQueriesQueryer{err: err}).Check(context.Background())
+	require.NoError(t, checkErr)
+
+	require.Len(t, report.Results, 1)
+	require.Equal(t, check.SeverityWarn, report.Results[0].Severity)
+	require.Contains(t, report.Results[0].Details, "installed but not loaded")
+}
+
+func Test_TopQueries_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("TopStatements",
+		[]string{
+			"query_id int8", "query text", "calls int8", "total_exec_time_ms float8",
+			"mean_exec_time_ms float8", "rows_returned int8", "all_exec_time_ms float8", "all_calls int8",
+		},
+		[]any{7001, "SELECT * FROM events WHERE tenant_id = $1", 1200, 720000.0, 600.0, 1200, 750000.0, 1500},
+		[]any{7002, "SELECT 1", 300, 30000.0, 100.0, 300, 750000.0, 1500},
+	)
+
+	report, err := topqueries.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "top-total-time").Severity)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "top-mean-time").Severity)
+}
+
+func Test_TopQueries_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := topqueries.New(&mockTopQueriesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "top-queries")
+}
+
+func Test_TopQueries_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := topqueries.Metadata()
+
+	require.Equal(t, "top-queries", metadata.CheckID)
+	require.Equal(t, check.CategoryPerformance, metadata.Category)
+	require.Equal(t, 13, metadata.MinPGVersion)
+	require.Equal(t, check.PrerequisiteExtension, metadata.Requires[0].Kind)
+	require.Equal(t, "pg_stat_statements", metadata.Requires[0].Name)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
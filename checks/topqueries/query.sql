@@ -0,0 +1,33 @@
+-- name: TopStatements :many
+-- The statements of the current database ranking in the top 10 by total
+-- execution time, mean execution time (among those called at least 10 times)
+-- or calls, with the database-wide totals to compute each one's share.
+WITH statements AS (
+  SELECT
+    s.queryid
+    , s.query
+    , s.calls
+    , s.total_exec_time
+    , s.mean_exec_time
+    , s.rows
+    , SUM(s.total_exec_time) OVER () AS all_exec_time
+    , SUM(s.calls) OVER () AS all_calls
+    , RANK() OVER (ORDER BY s.total_exec_time DESC) AS total_rank
+    , RANK() OVER (ORDER BY CASE WHEN s.calls >= 10 THEN s.mean_exec_time END DESC NULLS LAST) AS mean_rank
+    , RANK() OVER (ORDER BY s.calls DESC) AS calls_rank
+  FROM pg_stat_statements AS s
+  WHERE s.dbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+)
+
+SELECT
+  COALESCE(queryid, 0)::bigint AS query_id
+  , LEFT(REGEXP_REPLACE(query, '\s+', ' ', 'g'), 120)::text AS query
+  , calls::bigint AS calls
+  , total_exec_time::double precision AS total_exec_time_ms
+  , mean_exec_time::double precision AS mean_exec_time_ms
+  , rows::bigint AS rows_returned
+  , all_exec_time::double precision AS all_exec_time_ms
+  , all_calls::bigint AS all_calls
+FROM statements
+WHERE total_rank <= 10 OR mean_rank <= 10 OR calls_rank <= 10
+ORDER BY total_exec_time DESC;
@@ -0,0 +1,168 @@
+// Package tracksettings implements checks for the statistics collection settings.
+package tracksettings
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/catalog"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed README.md
+var readme string
+
+const (
+	// The default of 1024 bytes cuts most ORM-generated queries short.
+	minQuerySizeBytes       = 2048
+	suggestedQuerySizeBytes = 4096
+)
+
+// timingNote is the one caveat of the timing settings: they read the clock
+// twice per I/O, which is only slow on unusual clock sources.
+const timingNote = "Timing costs two clock reads per I/O; run pg_test_timing first on virtual machines with a slow clock source"
+
+type TrackSettingsQueries interface {
+	CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error)
+}
+
+type checker struct {
+	queries TrackSettingsQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "track-settings",
+		Name:        "Statistics Collection",
+		Description: "Recommends cheap statistics collection settings that unlock I/O timing, function and full query diagnostics",
+		Readme:      readme,
+		SQL:         catalog.SQL,
+	}
+}
+
+func New(queries TrackSettingsQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.CatalogSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	settings := catalog.Settings(rows)
+	provider := check.ProviderFromContext(ctx)
+
+	checkIOTiming(settings["track_io_timing"], provider, report)
+	checkQuerySize(ctx, settings["track_activity_query_size"], provider, report)
+	checkFunctions(settings["track_functions"], report)
+	// track_wal_io_timing is new in PostgreSQL 14.
+	if row, ok := settings["track_wal_io_timing"]; ok {
+		checkWalIOTiming(row, report)
+	}
+
+	return report, nil
+}
+
+// checkIOTiming warns when I/O timing is off: without it, pg_stat_statements
+// and EXPLAIN (ANALYZE, BUFFERS) cannot tell slow storage from slow queries.
+func checkIOTiming(row db.CatalogSettingsRow, provider check.Provider, report *check.Report) {
+	if row.Setting.String != "off" {
+		report.AddFinding(check.Finding{
+			ID:       "track-io-timing",
+			Name:     "I/O Timing",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("track_io_timing = %s", row.Setting.String),
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.SettingFix("track_io_timing", "on")
+	report.AddFinding(check.Finding{
+		ID:       "track-io-timing",
+		Name:     "I/O Timing",
+		Severity: check.SeverityWarn,
+		Details: "track_io_timing is off: pg_stat_statements, pg_stat_database and EXPLAIN (ANALYZE, BUFFERS) " +
+			"report how many blocks were read but not how long reading them took",
+		Object:      &check.Object{Kind: check.ObjectSetting, Name: "track_io_timing"},
+		Remediation: remediation + ". " + timingNote,
+		FixSQL:      fixSQL,
+	})
+}
+
+// checkQuerySize warns when pg_stat_activity truncates query texts at the
+// default size, which hides the part of the query that matters.
+func checkQuerySize(ctx context.Context, row db.CatalogSettingsRow, provider check.Provider, report *check.Report) {
+	size, err := strconv.ParseInt(row.Setting.String, 10, 64)
+	if err != nil {
+		return
+	}
+	if size >= minQuerySizeBytes {
+		report.AddFinding(check.Finding{
+			ID:       "track-activity-query-size",
+			Name:     "Activity Query Size",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("track_activity_query_size = %s", check.FormatSize(ctx, size)),
+		})
+		return
+	}
+
+	remediation, fixSQL := provider.RestartSettingFix("track_activity_query_size", strconv.Itoa(suggestedQuerySizeBytes))
+	report.AddFinding(check.Finding{
+		ID:       "track-activity-query-size",
+		Name:     "Activity Query Size",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("track_activity_query_size is %s: pg_stat_activity cuts running queries after that many bytes, "+
+			"often before the WHERE clause", check.FormatSize(ctx, size)),
+		Object: &check.Object{Kind: check.ObjectSetting, Name: "track_activity_query_size"},
+		Comparison: &check.Comparison{
+			Setting:   "track_activity_query_size",
+			Actual:    strconv.FormatInt(size, 10),
+			Expected:  strconv.Itoa(suggestedQuerySizeBytes),
+			Threshold: fmt.Sprintf("< %d", minQuerySizeBytes),
+		},
+		Remediation: remediation,
+		FixSQL:      fixSQL,
+	})
+}
+
+// checkFunctions suggests tracking PL functions, which costs little unless
+// the workload calls tiny functions millions of times.
+func checkFunctions(row db.CatalogSettingsRow, report *check.Report) {
+	details := fmt.Sprintf("track_functions = %s", row.Setting.String)
+	if row.Setting.String == "none" {
+		details += "; set it to 'pl' to see calls and time per function in pg_stat_user_functions"
+	}
+	report.AddFinding(check.Finding{
+		ID:       "track-functions",
+		Name:     "Function Tracking",
+		Severity: check.SeverityOK,
+		Details:  details,
+	})
+}
+
+// checkWalIOTiming suggests WAL write timing, which shows whether commits
+// wait on the disk in pg_stat_wal (pg_stat_io from PostgreSQL 16).
+func checkWalIOTiming(row db.CatalogSettingsRow, report *check.Report) {
+	details := fmt.Sprintf("track_wal_io_timing = %s", row.Setting.String)
+	if row.Setting.String == "off" {
+		details += "; turn it on to measure WAL write and sync time, e.g. when commits are slow"
+	}
+	report.AddFinding(check.Finding{
+		ID:       "track-wal-io-timing",
+		Name:     "WAL I/O Timing",
+		Severity: check.SeverityOK,
+		Details:  details,
+	})
+}
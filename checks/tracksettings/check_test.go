@@ -0,0 +1,136 @@
+package tracksettings_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/tracksettings"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockTrackSettingsQueryer struct {
+	settings []db.CatalogSettingsRow
+	err      error
+}
+
+func (m *mockTrackSettingsQueryer) CatalogSettings(context.Context) ([]db.CatalogSettingsRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.settings, nil
+}
+
+func setting(name, value string) db.CatalogSettingsRow {
+	return db.CatalogSettingsRow{
+		Name:    pgtype.Text{String: name, Valid: true},
+		Setting: pgtype.Text{String: value, Valid: true},
+	}
+}
+
+// defaults are the PostgreSQL 14+ defaults.
+func defaults() []db.CatalogSettingsRow {
+	return []db.CatalogSettingsRow{
+		setting("track_io_timing", "off"),
+		setting("track_activity_query_size", "1024"),
+		setting("track_functions", "none"),
+		setting("track_wal_io_timing", "off"),
+	}
+}
+
+func Test_TrackSettings_Defaults(t *testing.T) {
+	t.Parallel()
+
+	report, err := tracksettings.New(&mockTrackSettingsQueryer{settings: defaults()}).Check(context.Background())
+	require.NoError(t, err)
+	require.Len(t, report.Results, 4)
+
+	ioTiming := checktest.Finding(t, report, "track-io-timing")
+	require.Equal(t, check.SeverityWarn, ioTiming.Severity)
+	require.Equal(t, "ALTER SYSTEM SET track_io_timing = 'on';\nSELECT pg_reload_conf();", ioTiming.FixSQL)
+
+	querySize := checktest.Finding(t, report, "track-activity-query-size")
+	require.Equal(t, check.SeverityWarn, querySize.Severity)
+	require.Contains(t, querySize.Details, "track_activity_query_size is 1.0KiB")
+	require.Equal(t, "ALTER SYSTEM SET track_activity_query_size = '4096';", querySize.FixSQL)
+
+	functions := checktest.Finding(t, report, "track-functions")
+	require.Equal(t, check.SeverityOK, functions.Severity)
+	require.Contains(t, functions.Details, "set it to 'pl'")
+
+	walTiming := checktest.Finding(t, report, "track-wal-io-timing")
+	require.Equal(t, check.SeverityOK, walTiming.Severity)
+	require.Contains(t, walTiming.Details, "turn it on")
+}
+
+func Test_TrackSettings_Tuned(t *testing.T) {
+	t.Parallel()
+
+	report, err := tracksettings.New(&mockTrackSettingsQueryer{settings: []db.CatalogSettingsRow{
+		setting("track_io_timing", "on"),
+		setting("track_activity_query_size", "4096"),
+		setting("track_functions", "pl"),
+	}}).Check(context.Background())
+	require.NoError(t, err)
+
+	// track_wal_io_timing does not exist before PostgreSQL 14.
+	require.Len(t, report.Results, 3)
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Equal(t, "track_activity_query_size = 4.0KiB", checktest.Finding(t, report, "track-activity-query-size").Details)
+	require.Equal(t, "track_functions = pl", checktest.Finding(t, report, "track-functions").Details)
+}
+
+func Test_TrackSettings_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderCloudSQL)
+	report, err := tracksettings.New(&mockTrackSettingsQueryer{settings: defaults()}).Check(ctx)
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "track-activity-query-size")
+	require.Empty(t, finding.FixSQL)
+	require.Equal(t, "Set the track_activity_query_size database flag to 4096, then reboot the instance during a maintenance window", finding.Remediation)
+}
+
+func Test_TrackSettings_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("CatalogSettings",
+		[]string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+		[]any{"track_io_timing", "on", nil, "configuration file", "off", "on", "superuser", "bool", "f"},
+		[]any{"track_activity_query_size", "1024", "B", "default", "1024", "1024", "postmaster", "integer", "f"},
+		[]any{"track_functions", "none", nil, "default", "none", "none", "superuser", "enum", "f"},
+	)
+
+	report, err := tracksettings.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "track-io-timing").Severity)
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "track-activity-query-size").Severity)
+}
+
+func Test_TrackSettings_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := tracksettings.New(&mockTrackSettingsQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "track-settings")
+}
+
+func Test_TrackSettings_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := tracksettings.Metadata()
+
+	require.Equal(t, "track-settings", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
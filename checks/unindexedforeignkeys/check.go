@@ -0,0 +1,139 @@
+// Package unindexedforeignkeys implements a check for foreign keys without an index on the referencing columns.
+package unindexedforeignkeys
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// maxIdentifierLength is PostgreSQL's NAMEDATALEN - 1.
+const maxIdentifierLength = 63
+
+type UnindexedForeignKeysQueries interface {
+	UnindexedForeignKeys(context.Context) ([]db.UnindexedForeignKeysRow, error)
+}
+
+type checker struct {
+	queries UnindexedForeignKeysQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryIndexes,
+		CheckID:     "unindexed-foreign-keys",
+		Name:        "Unindexed Foreign Keys",
+		Description: "Identifies foreign keys whose referencing columns have no supporting index",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries UnindexedForeignKeysQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.UnindexedForeignKeys(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.UnindexedForeignKeysRow) string { return r.SchemaName + "." + r.TableName })
+	// Scanning a tiny referencing table is cheap; the missing index only hurts on large ones.
+	rows = check.FilterSmallTables(ctx, rows, func(r db.UnindexedForeignKeysRow) (int64, int64) {
+		return r.EstimatedRows, r.TableSizeBytes
+	})
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+		})
+		return report, nil
+	}
+
+	fixes := make([]string, 0, len(rows))
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		fixes = append(fixes, createIndexSQL(row))
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				row.ConstraintName,
+				strings.Join(row.Columns, ", "),
+				row.ReferencedTable,
+				check.FormatSize(ctx, row.TableSizeBytes),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	details := fmt.Sprintf("%d foreign keys have no index on their referencing columns: "+
+		"deletes and key updates on the referenced tables scan the referencing tables", len(rows))
+	if len(rows) == 1 {
+		details = "1 foreign key has no index on its referencing columns: " +
+			"deletes and key updates on the referenced table scan the referencing table"
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          report.CheckID,
+		Name:        report.Name,
+		Severity:    check.SeverityWarn,
+		Details:     details,
+		Remediation: "Create an index on the referencing columns of each foreign key",
+		FixSQL:      strings.Join(fixes, "\n"),
+		Metrics: []check.Metric{
+			{Name: "unindexed_foreign_keys", Value: float64(len(rows)), Unit: check.UnitCount},
+		},
+		Table: &check.Table{
+			Headers: []string{"Table", "Constraint", "Columns", "References", "Table Size"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
+
+// createIndexSQL returns the statement that indexes the referencing columns.
+// Partitioned tables cannot be indexed CONCURRENTLY; their statement builds
+// the index on every partition under a lock.
+func createIndexSQL(row db.UnindexedForeignKeysRow) string {
+	columns := make([]string, len(row.Columns))
+	for i, column := range row.Columns {
+		columns[i] = pgx.Identifier{column}.Sanitize()
+	}
+
+	name := row.TableName + "_" + strings.Join(row.Columns, "_") + "_idx"
+	if len(name) > maxIdentifierLength {
+		name = strings.TrimRight(strings.ToValidUTF8(name[:maxIdentifierLength-4], ""), "_") + "_idx"
+	}
+
+	create := "CREATE INDEX CONCURRENTLY"
+	if row.Relkind == "p" {
+		create = "CREATE INDEX"
+	}
+	return fmt.Sprintf("%s IF NOT EXISTS %s ON %s (%s);", create,
+		pgx.Identifier{name}.Sanitize(),
+		pgx.Identifier{row.SchemaName, row.TableName}.Sanitize(),
+		strings.Join(columns, ", "))
+}
@@ -0,0 +1,171 @@
+package unindexedforeignkeys_test
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/unindexedforeignkeys"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockUnindexedForeignKeysQueryer struct {
+	rows []db.UnindexedForeignKeysRow
+	err  error
+}
+
+func (m *mockUnindexedForeignKeysQueryer) UnindexedForeignKeys(context.Context) ([]db.UnindexedForeignKeysRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func foreignKey(table, constraint string, columns ...string) db.UnindexedForeignKeysRow {
+	return db.UnindexedForeignKeysRow{
+		SchemaName:      "public",
+		TableName:       table,
+		Relkind:         "r",
+		ConstraintName:  constraint,
+		ReferencedTable: "public.orders",
+		Columns:         columns,
+		EstimatedRows:   1_000_000,
+		TableSizeBytes:  512 * check.MiB,
+	}
+}
+
+func Test_UnindexedForeignKeys(t *testing.T) {
+	t.Parallel()
+
+	partitioned := foreignKey("events", "events_order_id_fkey", "order_id")
+	partitioned.Relkind = "p"
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.UnindexedForeignKeysRow
+		ExpectedSeverity check.Severity
+		ExpectedFixSQL   string
+	}{
+		{
+			Name:             "all foreign keys indexed - OK",
+			ExpectedSeverity: check.SeverityOK,
+		},
+		{
+			Name:             "single column foreign key",
+			Rows:             []db.UnindexedForeignKeysRow{foreignKey("order_items", "order_items_order_id_fkey", "order_id")},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedFixSQL:   `CREATE INDEX CONCURRENTLY IF NOT EXISTS "order_items_order_id_idx" ON "public"."order_items" ("order_id");`,
+		},
+		{
+			Name:             "composite foreign key keeps the column order",
+			Rows:             []db.UnindexedForeignKeysRow{foreignKey("shipments", "shipments_fkey", "tenant_id", "OrderID")},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedFixSQL:   `CREATE INDEX CONCURRENTLY IF NOT EXISTS "shipments_tenant_id_OrderID_idx" ON "public"."shipments" ("tenant_id", "OrderID");`,
+		},
+		{
+			Name:             "partitioned table cannot be indexed concurrently",
+			Rows:             []db.UnindexedForeignKeysRow{partitioned},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedFixSQL:   `CREATE INDEX IF NOT EXISTS "events_order_id_idx" ON "public"."events" ("order_id");`,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := unindexedforeignkeys.New(&mockUnindexedForeignKeysQueryer{rows: tc.Rows})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedFixSQL, finding.FixSQL)
+		})
+	}
+}
+
+func Test_UnindexedForeignKeys_Table(t *testing.T) {
+	t.Parallel()
+
+	checker := unindexedforeignkeys.New(&mockUnindexedForeignKeysQueryer{rows: []db.UnindexedForeignKeysRow{
+		foreignKey("order_items", "order_items_order_id_fkey", "order_id"),
+		foreignKey("refunds", "refunds_order_id_fkey", "order_id"),
+	}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Contains(t, finding.Details, "2 foreign keys have no index")
+	require.Equal(t, []string{"Table", "Constraint", "Columns", "References", "Table Size"}, finding.Table.Headers)
+	require.Equal(t, []string{"public.order_items", "order_items_order_id_fkey", "order_id", "public.orders", "512.0MiB"}, finding.Table.Rows[0].Cells)
+	require.Len(t, strings.Split(finding.FixSQL, "\n"), 2)
+}
+
+func Test_UnindexedForeignKeys_LongIndexName(t *testing.T) {
+	t.Parallel()
+
+	row := foreignKey(strings.Repeat("t", 40), "long_fkey", strings.Repeat("c", 30))
+	report, err := unindexedforeignkeys.New(&mockUnindexedForeignKeysQueryer{rows: []db.UnindexedForeignKeysRow{row}}).Check(context.Background())
+	require.NoError(t, err)
+
+	name := strings.Split(report.Results[0].FixSQL, `"`)[1]
+	require.Len(t, name, 63, "index names are truncated to PostgreSQL's identifier limit")
+	require.True(t, strings.HasSuffix(name, "_idx"))
+}
+
+func Test_UnindexedForeignKeys_SkipsSmallTables(t *testing.T) {
+	t.Parallel()
+
+	small := foreignKey("order_notes", "order_notes_order_id_fkey", "order_id")
+	small.EstimatedRows = 100
+	small.TableSizeBytes = 16 * check.KiB
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000, MinSizeBytes: 10 * check.MiB})
+	report, err := unindexedforeignkeys.New(&mockUnindexedForeignKeysQueryer{rows: []db.UnindexedForeignKeysRow{small}}).Check(ctx)
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityOK, report.Severity)
+}
+
+func Test_UnindexedForeignKeys_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("UnindexedForeignKeys",
+		[]string{
+			"schema_name text", "table_name text", "relkind text", "constraint_name text",
+			"referenced_table text", "columns _text", "estimated_rows int8", "table_size_bytes int8",
+		},
+		[]any{"public", "order_items", "r", "order_items_order_id_fkey", "public.orders", "{order_id}", 5000, 1048576},
+	)
+
+	report, err := unindexedforeignkeys.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, `CREATE INDEX CONCURRENTLY IF NOT EXISTS "order_items_order_id_idx" ON "public"."order_items" ("order_id");`, report.Results[0].FixSQL)
+}
+
+func Test_UnindexedForeignKeys_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := unindexedforeignkeys.New(&mockUnindexedForeignKeysQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "unindexed-foreign-keys")
+}
+
+func Test_UnindexedForeignKeys_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := unindexedforeignkeys.Metadata()
+
+	require.Equal(t, "unindexed-foreign-keys", metadata.CheckID)
+	require.Equal(t, check.CategoryIndexes, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
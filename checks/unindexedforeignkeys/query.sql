@@ -0,0 +1,41 @@
+-- name: UnindexedForeignKeys :many
+-- Foreign keys whose referencing columns are not the leading columns of any
+-- valid, non-partial index on the referencing table (in any order).
+-- Without one, every DELETE or key UPDATE on the referenced table scans the
+-- referencing table to check or cascade, and so does ON DELETE CASCADE.
+-- Excludes: system schemas and the copies of a partitioned table's foreign key
+-- on its partitions (conparentid), reported once on the parent.
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , t.relkind::text AS relkind
+  , con.conname::text AS constraint_name
+  , (rn.nspname || '.' || rt.relname)::text AS referenced_table
+  , (
+    SELECT array_agg(a.attname::text ORDER BY k.ord)
+    FROM unnest(con.conkey) WITH ORDINALITY AS k (attnum, ord)
+    INNER JOIN pg_attribute AS a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+  )::text [] AS columns
+  , greatest(t.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(t.oid) AS table_size_bytes
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+INNER JOIN pg_class AS rt ON con.confrelid = rt.oid
+INNER JOIN pg_namespace AS rn ON rt.relnamespace = rn.oid
+WHERE
+  con.contype = 'f'
+  AND con.conparentid = 0
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND NOT EXISTS (
+    SELECT 1
+    FROM pg_index AS i
+    WHERE
+      i.indrelid = con.conrelid
+      AND i.indisvalid
+      AND i.indpred IS NULL
+      AND i.indnkeyatts >= cardinality(con.conkey)
+      AND (i.indkey::int2 [])[0:cardinality(con.conkey) - 1] @> con.conkey
+      AND (i.indkey::int2 [])[0:cardinality(con.conkey) - 1] <@ con.conkey
+  )
+ORDER BY table_size_bytes DESC, n.nspname, t.relname, con.conname;
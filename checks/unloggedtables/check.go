@@ -0,0 +1,148 @@
+// Package unloggedtables implements a check for unlogged tables, whose
+// contents are lost on a crash and never reach standbys.
+package unloggedtables
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+// unloggedRemediation covers the cost of the fix: SET LOGGED rewrites the
+// table and writes all of it to WAL under an exclusive lock.
+const unloggedRemediation = "Make tables the application depends on durable with ALTER TABLE ... SET LOGGED. " +
+	"It rewrites the table and writes it all to WAL under an ACCESS EXCLUSIVE lock, so run it in a maintenance window " +
+	"and expect replicas to fall behind while they receive it. Acknowledge real scratch tables in the configuration"
+
+type UnloggedTablesQueries interface {
+	UnloggedTables(context.Context) ([]db.UnloggedTablesRow, error)
+}
+
+type checker struct {
+	queries      UnloggedTablesQueries
+	acknowledged []string
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "unlogged-tables",
+		Name:        "Unlogged Tables",
+		Description: "Lists unlogged tables, which are emptied after a crash and not replicated",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries UnloggedTablesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries: queries,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if acknowledged, ok := myCfg["acknowledged"]; ok {
+				for _, name := range strings.Split(acknowledged, ",") {
+					c.acknowledged = append(c.acknowledged, strings.TrimSpace(name))
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.UnloggedTables(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.UnloggedTablesRow) string { return r.SchemaName + "." + r.TableName })
+
+	c.checkUnlogged(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkUnlogged warns on every unlogged table not acknowledged as scratch
+// data. Size and reads show which ones became load-bearing.
+func (c *checker) checkUnlogged(ctx context.Context, rows []db.UnloggedTablesRow, report *check.Report) {
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  "No unlogged tables",
+		})
+		return
+	}
+
+	var tableRows []check.TableRow
+	var fixes []string
+	var totalSize, warnSize int64
+	warned := 0
+	for _, row := range rows {
+		table := row.SchemaName + "." + row.TableName
+		totalSize += row.TotalSizeBytes
+
+		severity, status := check.SeverityWarn, "-"
+		if slices.Contains(c.acknowledged, table) {
+			severity, status = check.SeverityOK, "acknowledged"
+		} else {
+			warned++
+			warnSize += row.TotalSizeBytes
+			fixes = append(fixes, fmt.Sprintf("ALTER TABLE %s SET LOGGED;",
+				pgx.Identifier{row.SchemaName, row.TableName}.Sanitize()))
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				table,
+				check.FormatSize(ctx, row.TotalSizeBytes),
+				check.FormatNumber(row.EstimatedRows),
+				check.FormatNumber(row.Scans),
+				status,
+			},
+			Severity: severity,
+		})
+	}
+
+	finding := check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%d unlogged table(s) (%s), all acknowledged", len(rows), check.FormatSize(ctx, totalSize)),
+		Table: &check.Table{
+			Headers: []string{"Table", "Size", "Rows", "Scans", "Status"},
+			Rows:    tableRows,
+		},
+		Metrics: []check.Metric{
+			{Name: "unlogged_tables", Value: float64(len(rows)), Unit: check.UnitCount},
+			{Name: "unlogged_size", Value: float64(totalSize), Unit: check.UnitBytes},
+		},
+	}
+	if warned > 0 {
+		finding.Severity = check.SeverityWarn
+		finding.Details = fmt.Sprintf("%d unlogged table(s) (%s): their contents are emptied after a crash "+
+			"and left out of replication and base backups", warned, check.FormatSize(ctx, warnSize))
+		finding.Remediation = unloggedRemediation
+		finding.FixSQL = strings.Join(fixes, "\n")
+	}
+	report.AddFinding(finding)
+}
@@ -0,0 +1,146 @@
+package unloggedtables_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/unloggedtables"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockUnloggedTablesQueryer struct {
+	rows []db.UnloggedTablesRow
+	err  error
+}
+
+func (m *mockUnloggedTablesQueryer) UnloggedTables(context.Context) ([]db.UnloggedTablesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func unlogged(table string, size, rows, scans int64) db.UnloggedTablesRow {
+	return db.UnloggedTablesRow{
+		SchemaName:     "public",
+		TableName:      table,
+		EstimatedRows:  rows,
+		TotalSizeBytes: size,
+		Scans:          scans,
+	}
+}
+
+func Test_UnloggedTables(t *testing.T) {
+	t.Parallel()
+
+	rows := []db.UnloggedTablesRow{
+		unlogged("session_cache", 12*check.GiB, 40_000_000, 2_500_000),
+		unlogged("import_staging", 64*check.MiB, 1000, 3),
+	}
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.UnloggedTablesRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "no unlogged tables - OK",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No unlogged tables",
+		},
+		{
+			Name:             "unlogged tables warn",
+			Rows:             rows,
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "2 unlogged table(s) (12.1GiB): their contents are emptied after a crash and left out of replication and base backups",
+		},
+		{
+			Name:             "acknowledged tables are not counted",
+			Rows:             rows,
+			Config:           check.Config{"unlogged-tables": {"acknowledged": "public.import_staging"}},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 unlogged table(s) (12.0GiB): their contents are emptied after a crash and left out of replication and base backups",
+		},
+		{
+			Name:             "all acknowledged - OK",
+			Rows:             rows,
+			Config:           check.Config{"unlogged-tables": {"acknowledged": "public.import_staging, public.session_cache"}},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "2 unlogged table(s) (12.1GiB), all acknowledged",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := unloggedtables.New(&mockUnloggedTablesQueryer{rows: tc.Rows}, tc.Config)
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.ExpectedSeverity, report.Results[0].Severity)
+			require.Equal(t, tc.ExpectedDetails, report.Results[0].Details)
+		})
+	}
+}
+
+func Test_UnloggedTables_Table(t *testing.T) {
+	t.Parallel()
+
+	checker := unloggedtables.New(&mockUnloggedTablesQueryer{rows: []db.UnloggedTablesRow{
+		unlogged("session_cache", 12*check.GiB, 40_000_000, 2_500_000),
+		unlogged("import_staging", 64*check.MiB, 1000, 3),
+	}}, check.Config{"unlogged-tables": {"acknowledged": "public.import_staging"}})
+	report, err := checker.Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, []string{"public.session_cache", "12.0GiB", "40.0M", "2.5M", "-"}, finding.Table.Rows[0].Cells)
+	require.Equal(t, check.SeverityWarn, finding.Table.Rows[0].Severity)
+	require.Equal(t, []string{"public.import_staging", "64.0MiB", "1.0K", "3", "acknowledged"}, finding.Table.Rows[1].Cells)
+	require.Equal(t, check.SeverityOK, finding.Table.Rows[1].Severity)
+	require.Equal(t, `ALTER TABLE "public"."session_cache" SET LOGGED;`, finding.FixSQL)
+}
+
+func Test_UnloggedTables_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("UnloggedTables",
+		[]string{"schema_name text", "table_name text", "estimated_rows int8", "total_size_bytes int8", "scans int8"},
+		[]any{"public", "session_cache", 40000000, 12 * check.GiB, 2500000},
+	)
+
+	report, err := unloggedtables.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityWarn, report.Severity)
+	require.Equal(t, "public.session_cache", report.Results[0].Table.Rows[0].Cells[0])
+}
+
+func Test_UnloggedTables_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := unloggedtables.New(&mockUnloggedTablesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "unlogged-tables")
+}
+
+func Test_UnloggedTables_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := unloggedtables.Metadata()
+
+	require.Equal(t, "unlogged-tables", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
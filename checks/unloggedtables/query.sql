@@ -0,0 +1,17 @@
+-- name: UnloggedTables :many
+-- Unlogged tables with their size and how much they are read, to tell scratch
+-- tables from ones the application depends on.
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(c.oid) AS total_size_bytes
+  , (coalesce(s.seq_scan, 0) + coalesce(s.idx_scan, 0))::bigint AS scans
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relpersistence = 'u'
+  AND c.relkind = 'r'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY total_size_bytes DESC, n.nspname, c.relname;
@@ -0,0 +1,131 @@
+// Package unuseddatabases implements checks for databases with no activity.
+package unuseddatabases
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// minStatsAgeDays is how much history is needed before calling a database unused.
+	// A database used weekly would otherwise look abandoned right after a reset.
+	minStatsAgeDays = 7
+)
+
+type UnusedDatabasesQueries interface {
+	UnusedDatabases(context.Context) ([]db.UnusedDatabasesRow, error)
+}
+
+type checker struct {
+	queries UnusedDatabasesQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryConfigs,
+		CheckID:      "unused-databases",
+		Name:         "Unused Databases",
+		Description:  "Detects databases with no connections since statistics were reset",
+		Readme:       readme,
+		SQL:          querySQL,
+		MinPGVersion: 14,
+	}
+}
+
+func New(queries UnusedDatabasesQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.UnusedDatabases(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	checkUnusedDatabases(ctx, rows, report)
+
+	return report, nil
+}
+
+// checkUnusedDatabases flags databases nobody has connected to during the stats
+// window. Background work (autovacuum) still commits transactions, so sessions,
+// not transactions, are the signal; transactions are shown for context.
+func checkUnusedDatabases(ctx context.Context, rows []db.UnusedDatabasesRow, report *check.Report) {
+	var tableRows []check.TableRow
+	var totalBytes int64
+	tooRecent := 0
+
+	for _, row := range rows {
+		// Our own session makes the current database look used, so it can't be judged.
+		if row.IsCurrent.Bool || row.Connections.Int64 > 0 || row.Sessions.Int64 > 0 {
+			continue
+		}
+		if row.StatsAgeDays.Int64 < minStatsAgeDays {
+			tooRecent++
+			continue
+		}
+
+		size := "unknown (no CONNECT privilege)"
+		if row.SizeBytes.Valid {
+			size = check.FormatSize(ctx, row.SizeBytes.Int64)
+			totalBytes += row.SizeBytes.Int64
+		}
+
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.DatabaseName.String,
+				size,
+				check.FormatNumber(row.Transactions.Int64),
+				fmt.Sprintf("%dd", row.StatsAgeDays.Int64),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	if len(tableRows) == 0 {
+		details := "Every database has had connections since statistics were reset"
+		if tooRecent > 0 {
+			details = fmt.Sprintf("%d database(s) had no connections, but statistics cover less than %d days", tooRecent, minStatsAgeDays)
+		}
+		report.AddFinding(check.Finding{
+			ID:       "unused-databases",
+			Name:     "Unused Databases",
+			Severity: check.SeverityOK,
+			Details:  details,
+		})
+		return
+	}
+
+	details := fmt.Sprintf("%d database(s) had no connections since statistics were reset (%s total). "+
+		"Confirm with the owners, then archive (pg_dump) and drop them.", len(tableRows), check.FormatSize(ctx, totalBytes))
+
+	report.AddFinding(check.Finding{
+		ID:               "unused-databases",
+		Name:             "Unused Databases",
+		Severity:         check.SeverityWarn,
+		Details:          details,
+		ReclaimableBytes: totalBytes,
+		Table: &check.Table{
+			Headers: []string{"Database", "Size", "Transactions", "Stats Age"},
+			Rows:    tableRows,
+		},
+	})
+}
@@ -0,0 +1,135 @@
+package unuseddatabases_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/unuseddatabases"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	rows []db.UnusedDatabasesRow
+	err  error
+}
+
+func (m *mockQueryer) UnusedDatabases(context.Context) ([]db.UnusedDatabasesRow, error) {
+	return m.rows, m.err
+}
+
+func makeRow(name string, sizeBytes, transactions, sessions, connections, statsAgeDays int64) db.UnusedDatabasesRow {
+	return db.UnusedDatabasesRow{
+		DatabaseName: pgtype.Text{String: name, Valid: true},
+		IsCurrent:    pgtype.Bool{Bool: false, Valid: true},
+		SizeBytes:    pgtype.Int8{Int64: sizeBytes, Valid: true},
+		Transactions: pgtype.Int8{Int64: transactions, Valid: true},
+		Sessions:     pgtype.Int8{Int64: sessions, Valid: true},
+		Connections:  pgtype.Int8{Int64: connections, Valid: true},
+		StatsAgeDays: pgtype.Int8{Int64: statsAgeDays, Valid: true},
+	}
+}
+
+func Test_UnusedDatabases(t *testing.T) {
+	t.Parallel()
+
+	current := makeRow("app", 10*check.GiB, 0, 0, 0, 30)
+	current.IsCurrent = pgtype.Bool{Bool: true, Valid: true}
+
+	noPrivilege := makeRow("secret", 0, 0, 0, 0, 30)
+	noPrivilege.SizeBytes = pgtype.Int8{}
+
+	tests := []struct {
+		name             string
+		rows             []db.UnusedDatabasesRow
+		expectedSeverity check.Severity
+		expectedRows     []string
+		reclaimable      int64
+	}{
+		{
+			name:             "all databases active",
+			rows:             []db.UnusedDatabasesRow{makeRow("app", check.GiB, 50000, 120, 3, 30)},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "no sessions over the stats window",
+			rows:             []db.UnusedDatabasesRow{makeRow("app", check.GiB, 50000, 120, 3, 30), makeRow("legacy", 2*check.GiB, 340, 0, 0, 30)},
+			expectedSeverity: check.SeverityWarn,
+			expectedRows:     []string{"legacy"},
+			reclaimable:      2 * check.GiB,
+		},
+		{
+			name:             "open connection counts as activity",
+			rows:             []db.UnusedDatabasesRow{makeRow("reporting", check.GiB, 0, 0, 1, 30)},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "stats too recent",
+			rows:             []db.UnusedDatabasesRow{makeRow("legacy", check.GiB, 0, 0, 0, 2)},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "current database is never flagged",
+			rows:             []db.UnusedDatabasesRow{current},
+			expectedSeverity: check.SeverityOK,
+		},
+		{
+			name:             "unknown size still flagged",
+			rows:             []db.UnusedDatabasesRow{noPrivilege},
+			expectedSeverity: check.SeverityWarn,
+			expectedRows:     []string{"secret"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := unuseddatabases.New(&mockQueryer{rows: tt.rows})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			assert.Equal(t, "unused-databases", finding.ID)
+			assert.Equal(t, tt.expectedSeverity, finding.Severity)
+			assert.Equal(t, tt.reclaimable, finding.ReclaimableBytes)
+
+			if tt.expectedRows == nil {
+				assert.Nil(t, finding.Table)
+				return
+			}
+			require.NotNil(t, finding.Table)
+			var names []string
+			for _, row := range finding.Table.Rows {
+				names = append(names, row.Cells[0])
+			}
+			assert.Equal(t, tt.expectedRows, names)
+		})
+	}
+}
+
+func Test_UnusedDatabases_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := unuseddatabases.New(&mockQueryer{err: errors.New("permission denied")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "configs/unused-databases")
+}
+
+func Test_UnusedDatabases_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := unuseddatabases.Metadata()
+	assert.Equal(t, "unused-databases", metadata.CheckID)
+	assert.Equal(t, check.CategoryConfigs, metadata.Category)
+	assert.Equal(t, 14, metadata.MinPGVersion)
+	assert.NotEmpty(t, metadata.SQL)
+	assert.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,23 @@
+-- name: UnusedDatabases :many
+-- Per-database activity since the last statistics reset. Stats are kept per
+-- database, so this sees every database in the cluster from one connection.
+-- Sizes need CONNECT privilege on the database and are NULL otherwise.
+-- Without a recorded reset, the stats window is measured from server start.
+SELECT
+  d.datname::text AS database_name
+  , (d.datname = current_database()) AS is_current
+  , CASE
+    WHEN has_database_privilege(d.oid, 'CONNECT') THEN pg_database_size(d.oid)
+  END AS size_bytes
+  , (s.xact_commit + s.xact_rollback)::bigint AS transactions
+  , s.sessions::bigint AS sessions
+  , s.numbackends::bigint AS connections
+  , (
+    EXTRACT(EPOCH FROM (now() - coalesce(s.stats_reset, pg_postmaster_start_time()))) / 86400
+  )::bigint AS stats_age_days
+FROM pg_database AS d
+INNER JOIN pg_stat_database AS s ON d.oid = s.datid
+WHERE d.datallowconn
+  AND NOT d.datistemplate
+  AND d.datname NOT IN ('postgres', 'rdsadmin', 'cloudsqladmin', 'azure_maintenance', 'azure_sys')
+ORDER BY size_bytes DESC NULLS LAST, d.datname;
@@ -53,6 +53,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("failed to check UUID column defaults: %w", err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.UuidColumnDefaultsRow) string { return r.TableName.String })
 
 	var indexedRandomUUIDs []db.UuidColumnDefaultsRow
 
@@ -52,6 +52,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	if err != nil {
 		return nil, fmt.Errorf("failed to check UUID column types: %w", err)
 	}
+	rows = check.FilterTables(ctx, rows, func(r db.UuidColumnsAsStringRow) string { return r.TableName })
 
 	if len(rows) == 0 {
 		report.AddFinding(check.Finding{
@@ -70,7 +71,7 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 				row.TableName,
 				row.ColumnName,
 				row.ColumnType,
-				check.FormatBytes(row.TableSizeBytes),
+				check.FormatSize(ctx, row.TableSizeBytes),
 			},
 			Severity: check.SeverityFail,
 		})
@@ -80,6 +80,15 @@ func (c *checker) Check(ctx context.Context) (*check.Report, error) {
 	return report, nil
 }
 
+// instanceName names the instance in details by its class, or as "the server"
+// when only its memory is known (--system-memory).
+func instanceName(meta *check.InstanceMetadata) string {
+	if meta.InstanceClass == "" {
+		return "the server"
+	}
+	return meta.InstanceClass
+}
+
 func checkAutovacuumScaleFactors(s dbVacuumSettings, report *check.Report) {
 	// Check autovacuum_analyze_scale_factor
 	analyzeScaleStr, fetchErr := s.fetch("autovacuum_analyze_scale_factor")
@@ -94,12 +103,14 @@ func checkAutovacuumScaleFactors(s dbVacuumSettings, report *check.Report) {
 	if analyzeScale > 0.1 {
 		report.AddFinding(check.Finding{Name: "Default autovacuum_analyze_scale_factor",
 			ID: "autovacuum_analyze_scale_factor", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("autovacuum_analyze_scale_factor too high: %.2f (recommend 0.05-0.1)", analyzeScale),
+			Details:    "Too high: planner statistics go stale on large tables between analyzes",
+			Comparison: scaleFactorComparison("autovacuum_analyze_scale_factor", analyzeScale, "0.05-0.1", "> 0.1"),
 		})
 	} else if analyzeScale < 0.01 {
 		report.AddFinding(check.Finding{Name: "Default autovacuum_analyze_scale_factor",
 			ID: "autovacuum_analyze_scale_factor", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("autovacuum_analyze_scale_factor too low: %.2f (may cause excessive analyze)", analyzeScale),
+			Details:    "Too low: may cause excessive analyze",
+			Comparison: scaleFactorComparison("autovacuum_analyze_scale_factor", analyzeScale, "0.05-0.1", "< 0.01"),
 		})
 	}
 
@@ -116,12 +127,14 @@ func checkAutovacuumScaleFactors(s dbVacuumSettings, report *check.Report) {
 	if vacuumScale > 0.2 {
 		report.AddFinding(check.Finding{Name: "Default autovacuum_vacuum_scale_factor",
 			ID: "autovacuum_vacuum_scale_factor", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("autovacuum_vacuum_scale_factor too high: %.2f (recommend 0.1-0.2)", vacuumScale),
+			Details:    "Too high: dead tuples pile up on large tables before autovacuum triggers",
+			Comparison: scaleFactorComparison("autovacuum_vacuum_scale_factor", vacuumScale, "0.1-0.2", "> 0.2"),
 		})
 	} else if vacuumScale < 0.02 {
 		report.AddFinding(check.Finding{Name: "Default autovacuum_vacuum_scale_factor",
 			ID: "autovacuum_vacuum_scale_factor", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("autovacuum_vacuum_scale_factor too low: %.2f (may cause excessive vacuum)", vacuumScale),
+			Details:    "Too low: may cause excessive vacuum",
+			Comparison: scaleFactorComparison("autovacuum_vacuum_scale_factor", vacuumScale, "0.1-0.2", "< 0.02"),
 		})
 	}
 }
@@ -137,6 +150,7 @@ func checkAutovacuumWorkers(s dbVacuumSettings, report *check.Report, meta *chec
 			Severity: check.SeverityFail,
 			Details: "autovacuum_max_workers is 0 (autovacuum disabled)\n\n" +
 				"This will cause table bloat and transaction ID wraparound.",
+			Comparison: workersComparison(workers, "= 0"),
 		})
 		return
 	}
@@ -148,6 +162,7 @@ func checkAutovacuumWorkers(s dbVacuumSettings, report *check.Report, meta *chec
 			Severity: check.SeverityWarn,
 			Details: "autovacuum_max_workers is 1 (critically low)\n\n" +
 				"Single worker cannot keep up with multiple busy tables.",
+			Comparison: workersComparison(workers, "= 1"),
 		})
 		return
 	}
@@ -160,6 +175,7 @@ func checkAutovacuumWorkers(s dbVacuumSettings, report *check.Report, meta *chec
 			Details: fmt.Sprintf("autovacuum_max_workers is %d (unusually high)\n\n"+
 				"Too many workers cause I/O contention and waste resources.",
 				workers),
+			Comparison: workersComparison(workers, "> 10"),
 		})
 		return
 	}
@@ -172,10 +188,16 @@ func checkAutovacuumWorkers(s dbVacuumSettings, report *check.Report, meta *chec
 			Name:     "Low autovacuum workers for large instance",
 			ID:       "autovacuum_max_workers",
 			Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("autovacuum_max_workers is 3 on very large instance %s (%d vCPU)\n\n"+
+			Details: fmt.Sprintf("autovacuum_max_workers is 3 on %s, a very large instance (%d vCPU)\n\n"+
 				"With %d vCPU cores and likely many concurrent tables, 3 workers may be a bottleneck.\n"+
 				"Consider %d workers for better parallelism on this instance size.",
-				meta.InstanceClass, meta.VCPUCores, meta.VCPUCores, recommended),
+				instanceName(meta), meta.VCPUCores, meta.VCPUCores, recommended),
+			Comparison: &check.Comparison{
+				Setting:   "autovacuum_max_workers",
+				Actual:    "3",
+				Expected:  strconv.Itoa(recommended),
+				Threshold: "= 3 with >= 32 vCPU",
+			},
 		})
 	}
 }
@@ -193,6 +215,12 @@ func checkMaintenanceWorkMem(s dbVacuumSettings, report *check.Report, meta *che
 			ID:       "maintenance_work_mem",
 			Severity: check.SeverityWarn,
 			Details:  fmt.Sprintf("maintenance_work_mem is %dMB (below half the PostgreSQL default)\n\nMay cause slow VACUUM operations requiring multiple passes.", maintenanceMemMB),
+			Comparison: &check.Comparison{
+				Setting:   "maintenance_work_mem",
+				Actual:    fmt.Sprintf("%dMB", maintenanceMemMB),
+				Expected:  "64MB-2048MB",
+				Threshold: "< 32MB",
+			},
 		})
 		return
 	}
@@ -206,6 +234,12 @@ func checkMaintenanceWorkMem(s dbVacuumSettings, report *check.Report, meta *che
 				"Values above 2GB show diminishing returns for VACUUM performance.\n"+
 				"PostgreSQL has a 1GB limit for tracking dead tuples (though >1GB helps CREATE INDEX).",
 				maintenanceMemMB),
+			Comparison: &check.Comparison{
+				Setting:   "maintenance_work_mem",
+				Actual:    fmt.Sprintf("%dMB", maintenanceMemMB),
+				Expected:  "64MB-2048MB",
+				Threshold: "> 4096MB",
+			},
 		})
 		return
 	}
@@ -233,9 +267,10 @@ func checkMaintenanceWorkMem(s dbVacuumSettings, report *check.Report, meta *che
 				"  Your config: %dMB × %d workers = %dMB\n\n"+
 				"This can cause memory pressure. Keep total under 25%% RAM.\n"+
 				"Manual VACUUM and CREATE INDEX operations also use this memory.",
-				maintenanceMemMB, meta.InstanceClass, meta.MemoryGB, autovacuumMaxWorkers,
+				maintenanceMemMB, instanceName(meta), meta.MemoryGB, autovacuumMaxWorkers,
 				totalBudgetMB, budgetPercent,
 				maintenanceMemMB, autovacuumMaxWorkers, totalBudgetMB),
+			Comparison: budgetComparison("maintenance_work_mem", maintenanceMemMB, budgetPercent, "≤ 12.5% RAM", "> 25% RAM"),
 		})
 		return
 	}
@@ -250,9 +285,10 @@ func checkMaintenanceWorkMem(s dbVacuumSettings, report *check.Report, meta *che
 				"Total RAM = maintenance_work_mem × autovacuum_max_workers\n"+
 				"Your config: %dMB × %d workers = %dMB\n\n"+
 				"While not critical, consider keeping total under 12.5%% RAM (1/8 of total).",
-				maintenanceMemMB, meta.InstanceClass, meta.MemoryGB, autovacuumMaxWorkers,
+				maintenanceMemMB, instanceName(meta), meta.MemoryGB, autovacuumMaxWorkers,
 				totalBudgetMB, budgetPercent,
 				maintenanceMemMB, autovacuumMaxWorkers, totalBudgetMB),
+			Comparison: budgetComparison("maintenance_work_mem", maintenanceMemMB, budgetPercent, "≤ 12.5% RAM", "> 12.5% RAM"),
 		})
 		return
 	}
@@ -267,15 +303,21 @@ func checkMaintenanceWorkMem(s dbVacuumSettings, report *check.Report, meta *che
 			Name:     "Low maintenance_work_mem for large instance",
 			ID:       "maintenance_work_mem",
 			Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("maintenance_work_mem is 64MB on very large instance %s (%.0fGB RAM)\n\n"+
+			Details: fmt.Sprintf("maintenance_work_mem is 64MB on %s, a very large instance (%.0fGB RAM)\n\n"+
 				"Large instances typically have large tables with more dead tuples.\n"+
 				"64MB can track only ~400K dead tuples (may require multiple VACUUM passes).\n"+
 				"Consider %dMB (can track ~6M dead tuples in one pass).\n\n"+
 				"Current total budget: 64MB × %d workers = %dMB (%.1f%% RAM)\n"+
 				"Recommended total: %dMB × %d workers = %dMB (%.1f%% RAM)",
-				meta.InstanceClass, meta.MemoryGB, recommendedMB,
+				instanceName(meta), meta.MemoryGB, recommendedMB,
 				autovacuumMaxWorkers, 64*autovacuumMaxWorkers, (float64(64*autovacuumMaxWorkers)/float64(availableRAMMB))*100,
 				recommendedMB, autovacuumMaxWorkers, newTotalBudgetMB, newBudgetPercent),
+			Comparison: &check.Comparison{
+				Setting:   "maintenance_work_mem",
+				Actual:    "64MB",
+				Expected:  fmt.Sprintf("%dMB", recommendedMB),
+				Threshold: "= 64MB with >= 64GB RAM",
+			},
 		})
 	}
 }
@@ -287,7 +329,13 @@ func checkVacuumCostSettings(s dbVacuumSettings, report *check.Report) {
 	if costDelay > 20 {
 		report.AddFinding(check.Finding{Name: "Default vacuum_cost_delay",
 			ID: "vacuum_cost_delay", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("vacuum_cost_delay too high: %dms (may slow vacuum, recommend 0-10ms)", costDelay),
+			Details: "Too high: may slow vacuum",
+			Comparison: &check.Comparison{
+				Setting:   "vacuum_cost_delay",
+				Actual:    fmt.Sprintf("%dms", costDelay),
+				Expected:  "0-10ms",
+				Threshold: "> 20ms",
+			},
 		})
 	}
 
@@ -297,12 +345,14 @@ func checkVacuumCostSettings(s dbVacuumSettings, report *check.Report) {
 	if costLimit < 200 {
 		report.AddFinding(check.Finding{Name: "Default vacuum_cost_limit",
 			ID: "vacuum_cost_limit", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("vacuum_cost_limit too low: %d (may slow vacuum, default 200)", costLimit),
+			Details:    "Too low: may slow vacuum",
+			Comparison: costLimitComparison(costLimit, "< 200"),
 		})
 	} else if costLimit > 10000 {
 		report.AddFinding(check.Finding{Name: "Default vacuum_cost_limit",
 			ID: "vacuum_cost_limit", Severity: check.SeverityWarn,
-			Details: fmt.Sprintf("vacuum_cost_limit very high: %d (may cause I/O spikes)", costLimit),
+			Details:    "Very high: may cause I/O spikes",
+			Comparison: costLimitComparison(costLimit, "> 10000"),
 		})
 	}
 }
@@ -322,6 +372,12 @@ func checkWorkMem(s dbVacuumSettings, report *check.Report, meta *check.Instance
 			Details: fmt.Sprintf("work_mem is %dMB (critically low)\n\n"+
 				"Will cause excessive temporary file usage for sorts and hash operations.",
 				workMemMB),
+			Comparison: &check.Comparison{
+				Setting:   "work_mem",
+				Actual:    fmt.Sprintf("%dMB", workMemMB),
+				Expected:  ">= 4MB",
+				Threshold: "< 4MB",
+			},
 		})
 		return
 	}
@@ -352,9 +408,10 @@ func checkWorkMem(s dbVacuumSettings, report *check.Report, meta *check.Instance
 				"Current active connections: %d using ~%dMB (%.1f%%)\n\n"+
 				"This configuration can cause out-of-memory errors when connections spike.\n"+
 				"Note: Each query operation (sort/hash) can use work_mem multiple times.",
-				workMemMB, meta.InstanceClass, meta.MemoryGB, maxConnections,
+				workMemMB, instanceName(meta), meta.MemoryGB, maxConnections,
 				worstCaseRAMMB, worstCasePercent,
 				activeConnections, typicalRAMMB, typicalPercent),
+			Comparison: budgetComparison("work_mem", workMemMB, worstCasePercent, "≤ 50% RAM worst case", "> 80% RAM worst case"),
 		})
 		return
 	}
@@ -368,9 +425,10 @@ func checkWorkMem(s dbVacuumSettings, report *check.Report, meta *check.Instance
 				"Worst-case RAM usage: %dMB (%.1f%% of available RAM)\n"+
 				"Current active connections: %d using ~%dMB (%.1f%%)\n\n"+
 				"While currently safe, connection spikes could cause memory pressure.",
-				workMemMB, meta.InstanceClass, meta.MemoryGB, maxConnections,
+				workMemMB, instanceName(meta), meta.MemoryGB, maxConnections,
 				worstCaseRAMMB, worstCasePercent,
 				activeConnections, typicalRAMMB, typicalPercent),
+			Comparison: budgetComparison("work_mem", workMemMB, worstCasePercent, "≤ 50% RAM worst case", "> 50% RAM worst case"),
 		})
 		return
 	}
@@ -384,14 +442,54 @@ func checkWorkMem(s dbVacuumSettings, report *check.Report, meta *check.Instance
 				"Current RAM usage: ~%dMB (%.1f%% of available RAM)\n"+
 				"Worst-case with max connections (%d): %dMB (%.1f%%)\n\n"+
 				"High memory usage from current connections. Monitor for memory pressure.",
-				workMemMB, activeConnections, meta.InstanceClass, meta.MemoryGB,
+				workMemMB, activeConnections, instanceName(meta), meta.MemoryGB,
 				typicalRAMMB, typicalPercent,
 				maxConnections, worstCaseRAMMB, worstCasePercent),
+			Comparison: budgetComparison("work_mem", workMemMB, typicalPercent, "≤ 40% RAM in use", "> 40% RAM in use"),
 		})
 		return
 	}
 }
 
+// Comparison builders
+
+func scaleFactorComparison(setting string, actual float64, expected, threshold string) *check.Comparison {
+	return &check.Comparison{
+		Setting:   setting,
+		Actual:    fmt.Sprintf("%.2f", actual),
+		Expected:  expected,
+		Threshold: threshold,
+	}
+}
+
+func workersComparison(workers int64, threshold string) *check.Comparison {
+	return &check.Comparison{
+		Setting:   "autovacuum_max_workers",
+		Actual:    strconv.FormatInt(workers, 10),
+		Expected:  "3-10",
+		Threshold: threshold,
+	}
+}
+
+func costLimitComparison(costLimit int64, threshold string) *check.Comparison {
+	return &check.Comparison{
+		Setting:   "vacuum_cost_limit",
+		Actual:    strconv.FormatInt(costLimit, 10),
+		Expected:  "200-10000",
+		Threshold: threshold,
+	}
+}
+
+// budgetComparison reports a per-operation memory setting by the share of RAM it can claim.
+func budgetComparison(setting string, valueMB int64, ramPercent float64, expected, threshold string) *check.Comparison {
+	return &check.Comparison{
+		Setting:   setting,
+		Actual:    fmt.Sprintf("%dMB (%.1f%% RAM)", valueMB, ramPercent),
+		Expected:  expected,
+		Threshold: threshold,
+	}
+}
+
 // Type functions
 
 func (s dbVacuumSettings) fetch(name string) (string, error) {
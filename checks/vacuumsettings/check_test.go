@@ -248,6 +248,15 @@ func Test_VacuumSettings_MultipleIssues(t *testing.T) {
 
 	// Should detect all 7 issues
 	require.Equal(t, len(results), 7, "Should detect all configuration issues")
+
+	// Every settings finding carries a structured comparison
+	for _, result := range results {
+		require.NotNil(t, result.Comparison, "finding %s should have a comparison", result.ID)
+		require.Equal(t, result.ID, result.Comparison.Setting)
+		require.NotEmpty(t, result.Comparison.Actual)
+		require.NotEmpty(t, result.Comparison.Expected)
+		require.NotEmpty(t, result.Comparison.Threshold)
+	}
 }
 
 func Test_VacuumSettings_DefaultsUsedOnParseError(t *testing.T) {
@@ -277,3 +286,20 @@ func Test_VacuumSettings_DefaultsUsedOnParseError(t *testing.T) {
 	require.Equal(t, "PostgreSQL Vacuum & Maintenance Configs", results[0].Name)
 	require.Equal(t, check.SeverityOK, results[0].Severity)
 }
+
+func Test_VacuumSettings_MemoryOnlyMetadata(t *testing.T) {
+	t.Parallel()
+
+	// --system-memory gives the memory without an instance class.
+	ctx := check.ContextWithInstanceMetadata(context.Background(), &check.InstanceMetadata{MemoryGB: 64})
+	checker := vacuumsettings.New(&mockVacuumSettingsQueries{rows: overrideOptimalWith("maintenance_work_mem", "65536")})
+	report, err := checker.Check(ctx)
+	require.NoError(t, err)
+
+	require.True(t, hasResult(report.Results, "maintenance_work_mem", check.SeverityWarn))
+	for _, result := range report.Results {
+		if result.ID == "maintenance_work_mem" {
+			require.Contains(t, result.Details, "maintenance_work_mem is 64MB on the server, a very large instance (64GB RAM)")
+		}
+	}
+}
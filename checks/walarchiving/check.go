@@ -0,0 +1,305 @@
+// Package walarchiving implements checks for WAL archiving health.
+package walarchiving
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	// Failures this recent still warn after archiving recovered.
+	recentFailureSeconds = 86400
+
+	pendingWarnSegments = 10
+	pendingFailSegments = 100
+
+	// A segment waiting this long means the archiver is stuck, not slow.
+	stuckSeconds = 3600
+)
+
+// placeholderCommands succeed without copying the segment anywhere, so
+// PostgreSQL recycles WAL that was never archived.
+var placeholderCommands = []string{"true", "/bin/true", "/usr/bin/true", "exit 0", ":", "cd .", "echo"}
+
+// configRemediation covers both ways out: archive for real, or stop
+// pretending to.
+const configRemediation = "Set archive_command (or archive_library) to copy WAL to durable storage, " +
+	"or turn archive_mode off if point-in-time recovery relies on other backups (requires a restart)"
+
+// failureRemediation starts from the log: PostgreSQL logs the command's exit
+// status and output on every failed attempt.
+const failureRemediation = "Check the server log for the archive_command's error output and run the command by hand as the postgres user. " +
+	"WAL is kept in pg_wal until it succeeds, so the disk fills up while archiving fails"
+
+const lagRemediation = "Make archiving keep up with WAL generation: use an archiver with asynchronous, parallel uploads " +
+	"(pgBackRest, WAL-G) or check why the archive_command stalls"
+
+type WalArchivingQueries interface {
+	WalArchiverStatus(context.Context) (db.WalArchiverStatusRow, error)
+}
+
+type checker struct {
+	queries WalArchivingQueries
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategoryConfigs,
+		CheckID:     "wal-archiving",
+		Name:        "WAL Archiving",
+		Description: "Detects failing, lagging or misconfigured WAL archiving",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries WalArchivingQueries, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	row, err := c.queries.WalArchiverStatus(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if row.ArchiveMode == "off" {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeveritySkip,
+			Details:  "WAL archiving is disabled (archive_mode = off)",
+		})
+		return report, nil
+	}
+	if row.IsStandby && row.ArchiveMode != "always" {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeveritySkip,
+			Details:  fmt.Sprintf("Standby with archive_mode = %s: only archive_mode = always archives during recovery", row.ArchiveMode),
+		})
+		return report, nil
+	}
+
+	checkArchiveConfig(row, check.ProviderFromContext(ctx), report)
+	checkArchiveFailures(row, report)
+	checkArchiveLag(ctx, row, report)
+
+	return report, nil
+}
+
+// checkArchiveConfig flags archive_mode enabled without a command that
+// actually archives.
+func checkArchiveConfig(row db.WalArchiverStatusRow, provider check.Provider, report *check.Report) {
+	if provider.Managed() {
+		report.AddFinding(check.Finding{
+			ID:       "archive-config",
+			Name:     "Archive Configuration",
+			Severity: check.SeveritySkip,
+			Details:  fmt.Sprintf("WAL archiving is configured by %s", provider),
+		})
+		return
+	}
+
+	if row.ArchiveLibrary != "" {
+		report.AddFinding(check.Finding{
+			ID:       "archive-config",
+			Name:     "Archive Configuration",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Archiving with archive_library %s", row.ArchiveLibrary),
+		})
+		return
+	}
+
+	command := strings.TrimSpace(row.ArchiveCommand)
+	switch {
+	case command == "":
+		report.AddFinding(check.Finding{
+			ID:       "archive-config",
+			Name:     "Archive Configuration",
+			Severity: check.SeverityFail,
+			Details: fmt.Sprintf("archive_mode is %s but archive_command is empty: nothing is archived and WAL accumulates in pg_wal until a command is set",
+				row.ArchiveMode),
+			Object:      &check.Object{Kind: check.ObjectSetting, Name: "archive_command"},
+			Remediation: configRemediation,
+		})
+	case slices.Contains(placeholderCommands, command):
+		report.AddFinding(check.Finding{
+			ID:       "archive-config",
+			Name:     "Archive Configuration",
+			Severity: check.SeverityWarn,
+			Details: fmt.Sprintf("archive_command is %q: WAL segments are reported as archived but discarded, so point-in-time recovery is impossible",
+				command),
+			Object:      &check.Object{Kind: check.ObjectSetting, Name: "archive_command"},
+			Remediation: configRemediation,
+		})
+	default:
+		report.AddFinding(check.Finding{
+			ID:       "archive-config",
+			Name:     "Archive Configuration",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Archiving with archive_command: %s", command),
+		})
+	}
+}
+
+// checkArchiveFailures fails while the latest attempt failed, and warns on
+// failures in the last day even if archiving recovered since.
+func checkArchiveFailures(row db.WalArchiverStatusRow, report *check.Report) {
+	if row.FailedCount == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "archive-failures",
+			Name:     "Archive Failures",
+			Severity: check.SeverityOK,
+			Details:  "No failed archive attempts since statistics were reset",
+		})
+		return
+	}
+
+	metrics := []check.Metric{
+		{Name: "failed_archive_attempts", Value: float64(row.FailedCount), Unit: check.UnitCount},
+	}
+	lastFailure := fmt.Sprintf("%s failed attempt(s), the last for %s %s ago",
+		check.FormatNumber(row.FailedCount), row.LastFailedWal.String, check.FormatDurationSec(row.SecondsSinceFailed.Int64))
+
+	failing := row.SecondsSinceFailed.Valid &&
+		(!row.SecondsSinceArchived.Valid || row.SecondsSinceFailed.Int64 < row.SecondsSinceArchived.Int64)
+	if failing {
+		lastSuccess := "never"
+		if row.SecondsSinceArchived.Valid {
+			lastSuccess = fmt.Sprintf("%s (%s ago)", row.LastArchivedWal.String, check.FormatDurationSec(row.SecondsSinceArchived.Int64))
+		}
+		report.AddFinding(check.Finding{
+			ID:          "archive-failures",
+			Name:        "Archive Failures",
+			Severity:    check.SeverityFail,
+			Details:     fmt.Sprintf("Archiving is failing: %s; last archived: %s", lastFailure, lastSuccess),
+			Remediation: failureRemediation,
+			Metrics:     metrics,
+		})
+		return
+	}
+
+	if row.SecondsSinceFailed.Valid && row.SecondsSinceFailed.Int64 < recentFailureSeconds {
+		report.AddFinding(check.Finding{
+			ID:          "archive-failures",
+			Name:        "Archive Failures",
+			Severity:    check.SeverityWarn,
+			Details:     lastFailure + "; archiving has recovered since",
+			Remediation: failureRemediation,
+			Metrics:     metrics,
+		})
+		return
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       "archive-failures",
+		Name:     "Archive Failures",
+		Severity: check.SeverityOK,
+		Details:  fmt.Sprintf("%s failed attempt(s) since statistics were reset, none in the last day", check.FormatNumber(row.FailedCount)),
+		Metrics:  metrics,
+	})
+}
+
+// checkArchiveLag counts the completed WAL segments between the last one
+// archived and the one being written, which wait in pg_wal until archived.
+func checkArchiveLag(ctx context.Context, row db.WalArchiverStatusRow, report *check.Report) {
+	if !row.CurrentWalFile.Valid {
+		report.AddFinding(check.Finding{
+			ID:       "archive-lag",
+			Name:     "Archive Lag",
+			Severity: check.SeveritySkip,
+			Details:  "Segments waiting to be archived are only known on a primary",
+		})
+		return
+	}
+	if !row.LastArchivedWal.Valid {
+		report.AddFinding(check.Finding{
+			ID:       "archive-lag",
+			Name:     "Archive Lag",
+			Severity: check.SeverityOK,
+			Details:  "No WAL segment archived since statistics were reset",
+		})
+		return
+	}
+
+	since := check.FormatDurationSec(row.SecondsSinceArchived.Int64)
+	current, okCurrent := segmentNumber(row.CurrentWalFile.String, row.WalSegmentSizeBytes)
+	archived, okArchived := segmentNumber(row.LastArchivedWal.String, row.WalSegmentSizeBytes)
+	if !okCurrent || !okArchived {
+		// Timeline history and backup label files carry no segment number.
+		report.AddFinding(check.Finding{
+			ID:       "archive-lag",
+			Name:     "Archive Lag",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("Last archived %s %s ago", row.LastArchivedWal.String, since),
+		})
+		return
+	}
+
+	pending := max(current-archived-1, 0)
+	severity := check.SeverityOK
+	switch {
+	case pending >= pendingFailSegments:
+		severity = check.SeverityFail
+	case pending > 0 && row.SecondsSinceArchived.Int64 >= stuckSeconds:
+		severity = check.SeverityFail
+	case pending >= pendingWarnSegments:
+		severity = check.SeverityWarn
+	}
+
+	finding := check.Finding{
+		ID:       "archive-lag",
+		Name:     "Archive Lag",
+		Severity: severity,
+		Details: fmt.Sprintf("%d WAL segment(s) (%s) waiting to be archived; last archived %s %s ago",
+			pending, check.FormatSize(ctx, pending*row.WalSegmentSizeBytes), row.LastArchivedWal.String, since),
+		Metrics: []check.Metric{
+			{Name: "pending_wal_segments", Value: float64(pending), Unit: check.UnitCount},
+			{Name: "seconds_since_archived", Value: float64(row.SecondsSinceArchived.Int64), Unit: check.UnitSeconds},
+		},
+	}
+	if severity > check.SeverityOK {
+		finding.Remediation = lagRemediation
+	}
+	report.AddFinding(finding)
+}
+
+// segmentNumber parses a WAL file name (timeline, log and segment, 8 hex
+// digits each) into an absolute segment number, ignoring the timeline.
+func segmentNumber(name string, segmentSize int64) (int64, bool) {
+	if len(name) != 24 || segmentSize <= 0 {
+		return 0, false
+	}
+	log, err := strconv.ParseInt(name[8:16], 16, 64)
+	if err != nil {
+		return 0, false
+	}
+	seg, err := strconv.ParseInt(name[16:24], 16, 64)
+	if err != nil {
+		return 0, false
+	}
+	return log*(0x100000000/segmentSize) + seg, true
+}
@@ -0,0 +1,321 @@
+package walarchiving_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/walarchiving"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+const day = 86400
+
+type mockWalArchivingQueryer struct {
+	row db.WalArchiverStatusRow
+	err error
+}
+
+func (m *mockWalArchivingQueryer) WalArchiverStatus(context.Context) (db.WalArchiverStatusRow, error) {
+	if m.err != nil {
+		return db.WalArchiverStatusRow{}, m.err
+	}
+	return m.row, nil
+}
+
+func textVal(s string) pgtype.Text {
+	return pgtype.Text{String: s, Valid: true}
+}
+
+func int64Val(n int64) pgtype.Int8 {
+	return pgtype.Int8{Int64: n, Valid: true}
+}
+
+// status is a primary archiving with pgBackRest, one segment behind the
+// segment being written.
+func status() db.WalArchiverStatusRow {
+	return db.WalArchiverStatusRow{
+		ArchiveMode:          "on",
+		ArchiveCommand:       "pgbackrest --stanza=main archive-push %p",
+		ArchivedCount:        5000,
+		LastArchivedWal:      textVal("000000010000000A000000FE"),
+		SecondsSinceArchived: int64Val(60),
+		CurrentWalFile:       textVal("000000010000000A000000FF"),
+		WalSegmentSizeBytes:  16 * check.MiB,
+	}
+}
+
+func Test_WalArchiving_Healthy(t *testing.T) {
+	t.Parallel()
+
+	report, err := walarchiving.New(&mockWalArchivingQueryer{row: status()}).Check(context.Background())
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, report.Severity)
+	require.Equal(t, "Archiving with archive_command: pgbackrest --stanza=main archive-push %p",
+		checktest.Finding(t, report, "archive-config").Details)
+	require.Equal(t, "0 WAL segment(s) (0B) waiting to be archived; last archived 000000010000000A000000FE 1m ago",
+		checktest.Finding(t, report, "archive-lag").Details)
+}
+
+func Test_WalArchiving_NotArchiving(t *testing.T) {
+	t.Parallel()
+
+	off := status()
+	off.ArchiveMode = "off"
+	standby := status()
+	standby.IsStandby = true
+
+	testCases := []struct {
+		Name            string
+		Row             db.WalArchiverStatusRow
+		ExpectedDetails string
+	}{
+		{Name: "archive_mode off", Row: off, ExpectedDetails: "WAL archiving is disabled (archive_mode = off)"},
+		{Name: "standby without archive_mode always", Row: standby, ExpectedDetails: "Standby with archive_mode = on"},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := walarchiving.New(&mockWalArchivingQueryer{row: tc.Row}).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, check.SeveritySkip, report.Results[0].Severity)
+			require.Contains(t, report.Results[0].Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_WalArchiving_Config(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Command          string
+		Library          string
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "empty archive_command fails",
+			Command:          "",
+			ExpectedSeverity: check.SeverityFail,
+			ExpectedDetails:  "archive_mode is on but archive_command is empty",
+		},
+		{
+			Name:             "placeholder archive_command warns",
+			Command:          " /bin/true ",
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  `archive_command is "/bin/true": WAL segments are reported as archived but discarded`,
+		},
+		{
+			Name:             "archive_library - OK",
+			Command:          "",
+			Library:          "basic_archive",
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "Archiving with archive_library basic_archive",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			row := status()
+			row.ArchiveCommand = tc.Command
+			row.ArchiveLibrary = tc.Library
+
+			report, err := walarchiving.New(&mockWalArchivingQueryer{row: row}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "archive-config")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_WalArchiving_ManagedProvider(t *testing.T) {
+	t.Parallel()
+
+	row := status()
+	row.ArchiveCommand = "/etc/rds/dbbin/pgscripts/rds_wal_archive %p"
+
+	ctx := check.ContextWithProvider(context.Background(), check.ProviderRDS)
+	report, err := walarchiving.New(&mockWalArchivingQueryer{row: row}).Check(ctx)
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "archive-config")
+	require.Equal(t, check.SeveritySkip, finding.Severity)
+	require.Equal(t, "WAL archiving is configured by Amazon RDS", finding.Details)
+}
+
+func Test_WalArchiving_Failures(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name               string
+		SecondsSinceFailed int64
+		ExpectedSeverity   check.Severity
+		ExpectedDetails    string
+	}{
+		{
+			Name:               "latest attempt failed",
+			SecondsSinceFailed: 30,
+			ExpectedSeverity:   check.SeverityFail,
+			ExpectedDetails: "Archiving is failing: 3 failed attempt(s), the last for 000000010000000A000000FF 30s ago; " +
+				"last archived: 000000010000000A000000FE (1h ago)",
+		},
+		{
+			Name:               "recovered from a recent failure",
+			SecondsSinceFailed: 7200,
+			ExpectedSeverity:   check.SeverityWarn,
+			ExpectedDetails:    "3 failed attempt(s), the last for 000000010000000A000000FF 2h ago; archiving has recovered since",
+		},
+		{
+			Name:               "old failures - OK",
+			SecondsSinceFailed: 3 * day,
+			ExpectedSeverity:   check.SeverityOK,
+			ExpectedDetails:    "3 failed attempt(s) since statistics were reset, none in the last day",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			row := status()
+			row.SecondsSinceArchived = int64Val(3600)
+			row.CurrentWalFile = textVal("000000010000000B00000000")
+			row.FailedCount = 3
+			row.LastFailedWal = textVal("000000010000000A000000FF")
+			row.SecondsSinceFailed = int64Val(tc.SecondsSinceFailed)
+
+			report, err := walarchiving.New(&mockWalArchivingQueryer{row: row}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "archive-failures")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedDetails, finding.Details)
+		})
+	}
+}
+
+func Test_WalArchiving_Lag(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name                 string
+		LastArchived         string
+		Current              string
+		SecondsSinceArchived int64
+		ExpectedSeverity     check.Severity
+		ExpectedDetails      string
+	}{
+		{
+			Name:                 "falling behind across a log boundary warns",
+			LastArchived:         "000000010000000A000000F0",
+			Current:              "000000010000000B00000004",
+			SecondsSinceArchived: 120,
+			ExpectedSeverity:     check.SeverityWarn,
+			ExpectedDetails:      "19 WAL segment(s) (304.0MiB) waiting to be archived",
+		},
+		{
+			Name:                 "far behind fails",
+			LastArchived:         "000000010000000A00000000",
+			Current:              "000000010000000A000000C8",
+			SecondsSinceArchived: 120,
+			ExpectedSeverity:     check.SeverityFail,
+			ExpectedDetails:      "199 WAL segment(s)",
+		},
+		{
+			Name:                 "stuck on one segment fails",
+			LastArchived:         "000000010000000A000000FD",
+			Current:              "000000010000000A000000FF",
+			SecondsSinceArchived: 7200,
+			ExpectedSeverity:     check.SeverityFail,
+			ExpectedDetails:      "1 WAL segment(s) (16.0MiB) waiting to be archived; last archived 000000010000000A000000FD 2h ago",
+		},
+		{
+			Name:                 "idle server - OK",
+			LastArchived:         "000000010000000A000000FE",
+			Current:              "000000010000000A000000FF",
+			SecondsSinceArchived: 3 * day,
+			ExpectedSeverity:     check.SeverityOK,
+		},
+		{
+			Name:                 "timeline history file",
+			LastArchived:         "00000002.history",
+			Current:              "000000020000000A000000FF",
+			SecondsSinceArchived: 10,
+			ExpectedSeverity:     check.SeverityOK,
+			ExpectedDetails:      "Last archived 00000002.history 10s ago",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			row := status()
+			row.LastArchivedWal = textVal(tc.LastArchived)
+			row.CurrentWalFile = textVal(tc.Current)
+			row.SecondsSinceArchived = int64Val(tc.SecondsSinceArchived)
+
+			report, err := walarchiving.New(&mockWalArchivingQueryer{row: row}).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "archive-lag")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Contains(t, finding.Details, tc.ExpectedDetails)
+		})
+	}
+}
+
+func Test_WalArchiving_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("WalArchiverStatus",
+		[]string{
+			"archive_mode text", "archive_command text", "archive_library text", "is_standby bool",
+			"archived_count int8", "failed_count int8", "last_archived_wal text", "seconds_since_archived int8",
+			"last_failed_wal text", "seconds_since_failed int8", "current_wal_file text", "wal_segment_size_bytes int8",
+		},
+		[]any{"on", "", "", "f", 0, 0, nil, nil, nil, nil, "000000010000000000000003", 16 * check.MiB},
+	)
+
+	report, err := walarchiving.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+	require.Equal(t, check.SeverityFail, checktest.Finding(t, report, "archive-config").Severity)
+	require.Equal(t, "No WAL segment archived since statistics were reset", checktest.Finding(t, report, "archive-lag").Details)
+}
+
+func Test_WalArchiving_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := walarchiving.New(&mockWalArchivingQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "wal-archiving")
+}
+
+func Test_WalArchiving_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := walarchiving.Metadata()
+
+	require.Equal(t, "wal-archiving", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
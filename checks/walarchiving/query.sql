@@ -0,0 +1,21 @@
+-- name: WalArchiverStatus :one
+-- Archiver counters from pg_stat_archiver with the archiving settings. The
+-- current WAL file is only known on a primary (pg_walfile_name() fails during
+-- recovery), and lets the check count segments waiting to be archived.
+SELECT
+  current_setting('archive_mode') AS archive_mode
+  , current_setting('archive_command') AS archive_command
+  , COALESCE(current_setting('archive_library', true), '') AS archive_library
+  , pg_is_in_recovery() AS is_standby
+  , a.archived_count
+  , a.failed_count
+  , a.last_archived_wal
+  , extract(EPOCH FROM (now() - a.last_archived_time))::bigint AS seconds_since_archived
+  , a.last_failed_wal
+  , extract(EPOCH FROM (now() - a.last_failed_time))::bigint AS seconds_since_failed
+  , CASE
+    WHEN pg_is_in_recovery() THEN NULL
+    ELSE pg_walfile_name(pg_current_wal_lsn())
+  END AS current_wal_file
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'wal_segment_size'), 0) AS wal_segment_size_bytes
+FROM pg_stat_archiver AS a;
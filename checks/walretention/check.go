@@ -0,0 +1,197 @@
+// Package walretention implements a check for WAL kept on disk by
+// replication slots, wal_keep_size and segments waiting to be archived.
+package walretention
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultWarnPercent = 25
+	defaultFailPercent = 50
+)
+
+type WalRetentionQueries interface {
+	WalRetention(context.Context) (db.WalRetentionRow, error)
+}
+
+type checker struct {
+	queries     WalRetentionQueries
+	volumeSize  int64 // bytes of the pg_wal volume; 0 when unknown
+	warnPercent float64
+	failPercent float64
+}
+
+// retentionSource is one reason WAL is kept beyond the next checkpoint.
+type retentionSource struct {
+	name        string
+	bytes       int64
+	remediation string
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:     check.CategoryConfigs,
+		CheckID:      "wal-retention",
+		Name:         "WAL Retention",
+		Description:  "Measures WAL retained by replication slots, wal_keep_size and unarchived segments against max_wal_size and the WAL volume",
+		Readme:       readme,
+		SQL:          querySQL,
+		Tags:         []string{check.TagTriage},
+		MinPGVersion: 13,
+	}
+}
+
+func New(queries WalRetentionQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		warnPercent: defaultWarnPercent,
+		failPercent: defaultFailPercent,
+	}
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["volume_size"]; ok {
+				if n, err := check.ParseBytes(v); err == nil && n > 0 {
+					c.volumeSize = n
+				}
+			}
+			if v, ok := myCfg["warn_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.warnPercent = f
+				}
+			}
+			if v, ok := myCfg["fail_percent"]; ok {
+				if f, err := strconv.ParseFloat(v, 64); err == nil && f > 0 {
+					c.failPercent = f
+				}
+			}
+		}
+	}
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	row, err := c.queries.WalRetention(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	sources := []retentionSource{
+		{
+			name:        "replication slots",
+			bytes:       row.SlotRetainedBytes,
+			remediation: "Advance or drop the lagging slot (see replication-slots) and cap it with max_slot_wal_keep_size",
+		},
+		{
+			name:        "wal_keep_size",
+			bytes:       row.WalKeepSizeBytes,
+			remediation: "Lower wal_keep_size: replication slots or a WAL archive keep standbys in sync without it",
+		},
+	}
+	if row.ReadyToArchive.Valid {
+		sources = append(sources, retentionSource{
+			name:        "waiting to be archived",
+			bytes:       row.ReadyToArchive.Int64 * row.WalSegmentSizeBytes,
+			remediation: "Fix the archive_command or archive_library so the backlog drains (see wal-archiving)",
+		})
+	}
+	if row.OldestSlotName != "" {
+		sources[0].name = fmt.Sprintf("replication slot %s", row.OldestSlotName)
+	}
+
+	// The sources overlap: WAL is kept back to the oldest segment any of them
+	// needs, so the largest one is what pg_wal holds beyond max_wal_size.
+	largest := sources[0]
+	tableRows := make([]check.TableRow, 0, len(sources)+2)
+	for _, source := range sources {
+		if source.bytes > largest.bytes {
+			largest = source
+		}
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{source.name, check.FormatSize(ctx, source.bytes)},
+		})
+	}
+	tableRows = append(tableRows, check.TableRow{
+		Cells: []string{"max_wal_size", check.FormatSize(ctx, row.MaxWalSizeBytes)},
+	})
+	if row.WalDirBytes.Valid {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{"pg_wal total", check.FormatSize(ctx, row.WalDirBytes.Int64)},
+		})
+	}
+
+	metrics := []check.Metric{
+		{Name: "wal_retained_bytes", Value: float64(largest.bytes), Unit: check.UnitBytes},
+	}
+	if row.WalDirBytes.Valid {
+		metrics = append(metrics, check.Metric{Name: "wal_dir_bytes", Value: float64(row.WalDirBytes.Int64), Unit: check.UnitBytes})
+	}
+
+	severity, details := c.evaluate(ctx, row, largest)
+	finding := check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: severity,
+		Details:  details,
+		Table: &check.Table{
+			Headers: []string{"Retained By", "Size"},
+			Rows:    tableRows,
+		},
+		Metrics: metrics,
+	}
+	if severity > check.SeverityOK {
+		finding.Remediation = largest.remediation
+	}
+	report.AddFinding(finding)
+
+	return report, nil
+}
+
+// evaluate compares retained WAL with the volume when its size is configured,
+// and otherwise with max_wal_size, beyond which only retention grows pg_wal.
+func (c *checker) evaluate(ctx context.Context, row db.WalRetentionRow, largest retentionSource) (check.Severity, string) {
+	if c.volumeSize > 0 {
+		used := largest.bytes
+		if row.WalDirBytes.Valid && row.WalDirBytes.Int64 > used {
+			used = row.WalDirBytes.Int64
+		}
+		percent := float64(used) / float64(c.volumeSize) * 100
+		details := fmt.Sprintf("WAL takes %s, %.0f%% of the %s volume; the largest retention is %s (%s)",
+			check.FormatSize(ctx, used), percent, check.FormatSize(ctx, c.volumeSize), largest.name, check.FormatSize(ctx, largest.bytes))
+		switch {
+		case percent >= c.failPercent:
+			return check.SeverityFail, details + ". The server stops when the volume fills up"
+		case percent >= c.warnPercent:
+			return check.SeverityWarn, details
+		default:
+			return check.SeverityOK, details
+		}
+	}
+
+	if largest.bytes > row.MaxWalSizeBytes {
+		return check.SeverityWarn, fmt.Sprintf("%s retains %s of WAL, more than max_wal_size (%s): "+
+			"pg_wal keeps growing while it lags, until the volume fills up and the server stops. "+
+			"Set volume_size to compare with the WAL volume instead",
+			largest.name, check.FormatSize(ctx, largest.bytes), check.FormatSize(ctx, row.MaxWalSizeBytes))
+	}
+	return check.SeverityOK, fmt.Sprintf("Retained WAL (%s, by %s) is within max_wal_size (%s)",
+		check.FormatSize(ctx, largest.bytes), largest.name, check.FormatSize(ctx, row.MaxWalSizeBytes))
+}
@@ -0,0 +1,181 @@
+package walretention_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/walretention"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/require"
+)
+
+type mockWalRetentionQueryer struct {
+	row db.WalRetentionRow
+	err error
+}
+
+func (m *mockWalRetentionQueryer) WalRetention(context.Context) (db.WalRetentionRow, error) {
+	return m.row, m.err
+}
+
+func retention(slotGB, keepGB, readySegments, walDirGB int64) db.WalRetentionRow {
+	return db.WalRetentionRow{
+		MaxWalSizeBytes:     1 * check.GiB,
+		WalKeepSizeBytes:    keepGB * check.GiB,
+		WalSegmentSizeBytes: 16 * check.MiB,
+		OldestSlotName:      "replica_1",
+		SlotRetainedBytes:   slotGB * check.GiB,
+		WalDirBytes:         pgtype.Int8{Int64: walDirGB * check.GiB, Valid: true},
+		ReadyToArchive:      pgtype.Int8{Int64: readySegments, Valid: true},
+	}
+}
+
+func Test_WalRetention(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name        string
+		row         db.WalRetentionRow
+		config      check.Config
+		expected    check.Severity
+		remediation string
+	}{
+		{
+			name:     "within max_wal_size",
+			row:      retention(0, 0, 2, 1),
+			expected: check.SeverityOK,
+		},
+		{
+			name:        "lagging slot beyond max_wal_size",
+			row:         retention(40, 0, 0, 41),
+			expected:    check.SeverityWarn,
+			remediation: "max_slot_wal_keep_size",
+		},
+		{
+			name:        "wal_keep_size beyond max_wal_size",
+			row:         retention(0, 8, 0, 9),
+			expected:    check.SeverityWarn,
+			remediation: "Lower wal_keep_size",
+		},
+		{
+			name:        "archive backlog beyond max_wal_size",
+			row:         retention(0, 0, 640, 11),
+			expected:    check.SeverityWarn,
+			remediation: "wal-archiving",
+		},
+		{
+			name:     "small fraction of the volume",
+			row:      retention(40, 0, 0, 41),
+			config:   check.Config{"wal-retention": {"volume_size": "500GB"}},
+			expected: check.SeverityOK,
+		},
+		{
+			name:        "large fraction of the volume",
+			row:         retention(40, 0, 0, 41),
+			config:      check.Config{"wal-retention": {"volume_size": "100GB"}},
+			expected:    check.SeverityWarn,
+			remediation: "max_slot_wal_keep_size",
+		},
+		{
+			name:        "most of the volume",
+			row:         retention(40, 0, 0, 41),
+			config:      check.Config{"wal-retention": {"volume_size": "100GB", "warn_percent": "10", "fail_percent": "40"}},
+			expected:    check.SeverityFail,
+			remediation: "max_slot_wal_keep_size",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := walretention.New(&mockWalRetentionQueryer{row: tt.row}, tt.config).Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			finding := report.Results[0]
+			require.Equal(t, tt.expected, finding.Severity)
+			if tt.remediation == "" {
+				require.Empty(t, finding.Remediation)
+			} else {
+				require.Contains(t, finding.Remediation, tt.remediation)
+			}
+		})
+	}
+}
+
+func Test_WalRetention_Table(t *testing.T) {
+	t.Parallel()
+
+	report, err := walretention.New(&mockWalRetentionQueryer{row: retention(40, 0, 64, 41)}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Contains(t, finding.Details, "replication slot replica_1 retains "+check.FormatBytes(40*check.GiB))
+	require.Equal(t, []string{"replication slot replica_1", check.FormatBytes(40 * check.GiB)}, finding.Table.Rows[0].Cells)
+	require.Equal(t, []string{"waiting to be archived", check.FormatBytes(check.GiB)}, finding.Table.Rows[2].Cells)
+	require.Equal(t, []string{"pg_wal total", check.FormatBytes(41 * check.GiB)}, finding.Table.Rows[4].Cells)
+	require.Len(t, finding.Metrics, 2)
+}
+
+func Test_WalRetention_WithoutPgMonitor(t *testing.T) {
+	t.Parallel()
+
+	row := retention(0, 0, 0, 0)
+	row.OldestSlotName = ""
+	row.WalDirBytes = pgtype.Int8{}
+	row.ReadyToArchive = pgtype.Int8{}
+
+	report, err := walretention.New(&mockWalRetentionQueryer{row: row}).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityOK, finding.Severity)
+	require.Len(t, finding.Table.Rows, 3, "slots, wal_keep_size and max_wal_size only")
+	require.Len(t, finding.Metrics, 1)
+}
+
+func Test_WalRetention_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("WalRetention",
+		[]string{
+			"is_standby bool", "max_wal_size_bytes int8", "wal_keep_size_bytes int8", "wal_segment_size_bytes int8",
+			"oldest_slot_name text", "slot_retained_bytes int8", "wal_dir_bytes int8", "ready_to_archive int8",
+		},
+		[]any{"f", 2 * check.GiB, 0, 16 * check.MiB, "debezium", 12 * check.GiB, 13 * check.GiB, nil},
+	)
+
+	report, err := walretention.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := report.Results[0]
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, "replication slot debezium", finding.Table.Rows[0].Cells[0])
+}
+
+func Test_WalRetention_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := walretention.New(&mockWalRetentionQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "wal-retention")
+}
+
+func Test_WalRetention_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := walretention.Metadata()
+
+	require.Equal(t, "wal-retention", metadata.CheckID)
+	require.Equal(t, check.CategoryConfigs, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
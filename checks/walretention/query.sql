@@ -0,0 +1,42 @@
+-- name: WalRetention :one
+-- WAL held back by replication slots, wal_keep_size and segments waiting to
+-- be archived, next to the size of pg_wal and max_wal_size. Listing pg_wal and
+-- its archive_status directory needs pg_monitor (or superuser); without it
+-- those sizes are NULL. On a standby, slots are measured from the last
+-- replayed LSN.
+WITH position AS (
+  SELECT
+    CASE
+      WHEN pg_is_in_recovery() THEN pg_last_wal_replay_lsn()
+      ELSE pg_current_wal_lsn()
+    END AS lsn
+),
+
+oldest_slot AS (
+  SELECT
+    s.slot_name::text AS slot_name
+    , pg_wal_lsn_diff(p.lsn, s.restart_lsn)::bigint AS retained_bytes
+  FROM pg_replication_slots AS s
+  CROSS JOIN position AS p
+  WHERE
+    s.restart_lsn IS NOT NULL
+    AND s.wal_status IS DISTINCT FROM 'lost'
+  ORDER BY s.restart_lsn
+  LIMIT 1
+)
+
+SELECT
+  pg_is_in_recovery() AS is_standby
+  , pg_size_bytes(current_setting('max_wal_size')) AS max_wal_size_bytes
+  , pg_size_bytes(current_setting('wal_keep_size')) AS wal_keep_size_bytes
+  , pg_size_bytes(current_setting('wal_segment_size')) AS wal_segment_size_bytes
+  , coalesce((SELECT o.slot_name FROM oldest_slot AS o), '')::text AS oldest_slot_name
+  , coalesce((SELECT o.retained_bytes FROM oldest_slot AS o), 0)::bigint AS slot_retained_bytes
+  , CASE
+    WHEN has_function_privilege('pg_ls_waldir()', 'EXECUTE')
+      THEN (SELECT coalesce(sum(w.size), 0) FROM pg_ls_waldir() AS w)::bigint
+  END AS wal_dir_bytes
+  , CASE
+    WHEN has_function_privilege('pg_ls_archive_statusdir()', 'EXECUTE')
+      THEN (SELECT count(*) FROM pg_ls_archive_statusdir() AS a WHERE a.name LIKE '%.ready')::bigint
+  END AS ready_to_archive;
@@ -0,0 +1,231 @@
+// Package widetables implements checks for tables with too many columns or
+// very wide rows.
+package widetables
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+const (
+	defaultMaxColumns = 100
+	// Rows above about 2kB are compressed or moved out to TOAST.
+	defaultMaxRowWidth = 2 * check.KiB
+
+	// PostgreSQL's hard limit on columns per table, dropped columns included.
+	columnLimit = 1600
+)
+
+const columnsRemediation = "Split rarely used or optional columns into a separate table sharing the primary key (vertical partitioning), " +
+	"or move sparse attributes into a jsonb column. Dropped columns still count towards the 1600 limit until the table is rewritten"
+
+const widthRemediation = "Move the widest columns, if they are not read by most queries, into a separate table sharing the primary key, " +
+	"and select only the columns a query needs: values above about 2kB are stored in TOAST and fetched separately, " +
+	"but narrower ones stay inline and make every page hold fewer rows"
+
+type WideTablesQueries interface {
+	WideTables(context.Context) ([]db.WideTablesRow, error)
+}
+
+type checker struct {
+	queries     WideTablesQueries
+	maxColumns  int64 // default: 100
+	maxRowWidth int64 // default: 2KiB
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    check.CategorySchema,
+		CheckID:     "wide-tables",
+		Name:        "Wide Tables",
+		Description: "Flags tables with too many columns or a very large average row width",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries WideTablesQueries, cfg ...check.Config) check.Checker {
+	c := &checker{
+		queries:     queries,
+		maxColumns:  defaultMaxColumns,
+		maxRowWidth: defaultMaxRowWidth,
+	}
+
+	if len(cfg) > 0 && cfg[0] != nil {
+		if myCfg, ok := cfg[0][Metadata().CheckID]; ok {
+			if v, ok := myCfg["max_columns"]; ok {
+				if n, err := strconv.ParseInt(v, 10, 64); err == nil && n > 0 {
+					c.maxColumns = n
+				}
+			}
+			if v, ok := myCfg["max_row_width"]; ok {
+				if n, err := check.ParseBytes(v); err == nil && n > 0 {
+					c.maxRowWidth = n
+				}
+			}
+		}
+	}
+
+	return c
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.WideTables(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.WideTablesRow) string { return r.SchemaName + "." + r.TableName })
+
+	c.checkColumnCount(ctx, rows, report)
+	// Row width only matters on tables with enough rows to read.
+	c.checkRowWidth(ctx, check.FilterSmallTables(ctx, rows, func(r db.WideTablesRow) (int64, int64) {
+		return r.EstimatedRows, r.TableSizeBytes
+	}), report)
+
+	return report, nil
+}
+
+// checkColumnCount warns on tables with more columns than configured.
+func (c *checker) checkColumnCount(ctx context.Context, rows []db.WideTablesRow, report *check.Report) {
+	var wide []db.WideTablesRow
+	for _, row := range rows {
+		if row.ColumnCount > c.maxColumns {
+			wide = append(wide, row)
+		}
+	}
+
+	if len(wide) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "column-count",
+			Name:     "Column Count",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No table has more than %d columns", c.maxColumns),
+		})
+		return
+	}
+
+	sort.SliceStable(wide, func(i, j int) bool { return wide[i].ColumnCount > wide[j].ColumnCount })
+	tableRows := make([]check.TableRow, 0, len(wide))
+	for _, row := range wide {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				strconv.FormatInt(row.ColumnCount, 10),
+				formatWidth(ctx, row.AvgRowWidth),
+				formatColumns(ctx, row.WidestColumns),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:          "column-count",
+		Name:        "Column Count",
+		Severity:    check.SeverityWarn,
+		Details:     fmt.Sprintf("%d table(s) with more than %d columns (the limit is %d)", len(wide), c.maxColumns, columnLimit),
+		Remediation: columnsRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Columns", "Avg Row Width", "Widest Columns"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// checkRowWidth warns on tables whose average row, from the planner's
+// statistics, is wider than configured. Tables never analyzed have no width.
+func (c *checker) checkRowWidth(ctx context.Context, rows []db.WideTablesRow, report *check.Report) {
+	var wide []db.WideTablesRow
+	for _, row := range rows {
+		if row.AvgRowWidth > c.maxRowWidth {
+			wide = append(wide, row)
+		}
+	}
+
+	if len(wide) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       "row-width",
+			Name:     "Row Width",
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("No table has an average row wider than %s", check.FormatSize(ctx, c.maxRowWidth)),
+		})
+		return
+	}
+
+	sort.SliceStable(wide, func(i, j int) bool { return wide[i].AvgRowWidth > wide[j].AvgRowWidth })
+	tableRows := make([]check.TableRow, 0, len(wide))
+	for _, row := range wide {
+		tableRows = append(tableRows, check.TableRow{
+			Cells: []string{
+				row.SchemaName + "." + row.TableName,
+				formatWidth(ctx, row.AvgRowWidth),
+				check.FormatNumber(row.EstimatedRows),
+				check.FormatSize(ctx, row.TableSizeBytes),
+				formatColumns(ctx, row.WidestColumns),
+			},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	widest := wide[0]
+	report.AddFinding(check.Finding{
+		ID:       "row-width",
+		Name:     "Row Width",
+		Severity: check.SeverityWarn,
+		Details: fmt.Sprintf("%d table(s) with an average row wider than %s; the widest, %s, averages %s per row, mostly in %s",
+			len(wide), check.FormatSize(ctx, c.maxRowWidth), widest.SchemaName+"."+widest.TableName,
+			formatWidth(ctx, widest.AvgRowWidth), formatColumns(ctx, widest.WidestColumns)),
+		Remediation: widthRemediation,
+		Table: &check.Table{
+			Headers: []string{"Table", "Avg Row Width", "Rows", "Size", "Widest Columns"},
+			Rows:    tableRows,
+		},
+	})
+}
+
+// formatColumns turns "name:width" entries into "name (width)". Column names
+// may contain colons, so the width is after the last one.
+func formatColumns(ctx context.Context, columns []string) string {
+	if len(columns) == 0 {
+		return "-"
+	}
+	formatted := make([]string, 0, len(columns))
+	for _, col := range columns {
+		i := strings.LastIndex(col, ":")
+		if i < 0 {
+			formatted = append(formatted, col)
+			continue
+		}
+		name := col[:i]
+		if n, err := strconv.ParseInt(col[i+1:], 10, 64); err == nil {
+			name += " (" + check.FormatSize(ctx, n) + ")"
+		}
+		formatted = append(formatted, name)
+	}
+	return strings.Join(formatted, ", ")
+}
+
+func formatWidth(ctx context.Context, width int64) string {
+	if width == 0 {
+		return "-"
+	}
+	return check.FormatSize(ctx, width)
+}
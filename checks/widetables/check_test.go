@@ -0,0 +1,187 @@
+package widetables_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/widetables"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/internal/checktest"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/require"
+)
+
+type mockWideTablesQueryer struct {
+	rows []db.WideTablesRow
+	err  error
+}
+
+func (m *mockWideTablesQueryer) WideTables(context.Context) ([]db.WideTablesRow, error) {
+	if m.err != nil {
+		return nil, m.err
+	}
+	return m.rows, nil
+}
+
+func table(name string, columns, width, rows int64, widest ...string) db.WideTablesRow {
+	return db.WideTablesRow{
+		SchemaName:     "public",
+		TableName:      name,
+		ColumnCount:    columns,
+		AvgRowWidth:    width,
+		EstimatedRows:  rows,
+		TableSizeBytes: rows * max(width, 100),
+		WidestColumns:  widest,
+	}
+}
+
+func Test_WideTables_ColumnCount(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.WideTablesRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "narrow tables - OK",
+			Rows:             []db.WideTablesRow{table("orders", 12, 120, 1_000_000)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No table has more than 100 columns",
+		},
+		{
+			Name:             "too many columns warns",
+			Rows:             []db.WideTablesRow{table("orders", 12, 120, 1_000_000), table("customer_profile", 240, 900, 50_000)},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails:  "1 table(s) with more than 100 columns (the limit is 1600)",
+		},
+		{
+			Name:             "configured limit",
+			Rows:             []db.WideTablesRow{table("customer_profile", 240, 900, 50_000)},
+			Config:           check.Config{"wide-tables": {"max_columns": "250"}},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No table has more than 250 columns",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := widetables.New(&mockWideTablesQueryer{rows: tc.Rows}, tc.Config).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "column-count")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedDetails, finding.Details)
+		})
+	}
+}
+
+func Test_WideTables_RowWidth(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name             string
+		Rows             []db.WideTablesRow
+		Config           check.Config
+		ExpectedSeverity check.Severity
+		ExpectedDetails  string
+	}{
+		{
+			Name:             "narrow rows - OK",
+			Rows:             []db.WideTablesRow{table("orders", 12, 120, 1_000_000)},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No table has an average row wider than 2.0KiB",
+		},
+		{
+			Name: "wide rows warn, widest first",
+			Rows: []db.WideTablesRow{
+				table("documents", 8, 3000, 200_000, "body:2400", "title:80"),
+				table("events", 10, 6000, 5_000_000, "payload:5200", "headers:600", "source:40"),
+			},
+			ExpectedSeverity: check.SeverityWarn,
+			ExpectedDetails: "2 table(s) with an average row wider than 2.0KiB; the widest, public.events, averages 5.9KiB per row, " +
+				"mostly in payload (5.1KiB), headers (600B), source (40B)",
+		},
+		{
+			Name:             "configured width",
+			Rows:             []db.WideTablesRow{table("documents", 8, 3000, 200_000, "body:2400")},
+			Config:           check.Config{"wide-tables": {"max_row_width": "4kB"}},
+			ExpectedSeverity: check.SeverityOK,
+			ExpectedDetails:  "No table has an average row wider than 4.0KiB",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := widetables.New(&mockWideTablesQueryer{rows: tc.Rows}, tc.Config).Check(context.Background())
+			require.NoError(t, err)
+
+			finding := checktest.Finding(t, report, "row-width")
+			require.Equal(t, tc.ExpectedSeverity, finding.Severity)
+			require.Equal(t, tc.ExpectedDetails, finding.Details)
+		})
+	}
+}
+
+func Test_WideTables_RowWidthSkipsSmallTables(t *testing.T) {
+	t.Parallel()
+
+	ctx := check.ContextWithScope(context.Background(), &check.Scope{MinRows: 1000})
+	checker := widetables.New(&mockWideTablesQueryer{rows: []db.WideTablesRow{
+		table("settings", 120, 8000, 10, "blob:7000"),
+	}})
+	report, err := checker.Check(ctx)
+	require.NoError(t, err)
+
+	require.Equal(t, check.SeverityOK, checktest.Finding(t, report, "row-width").Severity)
+	require.Equal(t, check.SeverityWarn, checktest.Finding(t, report, "column-count").Severity)
+}
+
+func Test_WideTables_GeneratedQuery(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("WideTables",
+		[]string{
+			"schema_name text", "table_name text", "column_count int8", "avg_row_width int8",
+			"estimated_rows int8", "table_size_bytes int8", "widest_columns _text",
+		},
+		[]any{"public", "events", 10, 6000, 5000000, 30 * check.GiB, "{payload:5200,headers:600}"},
+	)
+
+	report, err := widetables.New(db.New(fx)).Check(context.Background())
+	require.NoError(t, err)
+
+	finding := checktest.Finding(t, report, "row-width")
+	require.Equal(t, check.SeverityWarn, finding.Severity)
+	require.Equal(t, []string{"public.events", "5.9KiB", "5.0M", "30.0GiB", "payload (5.1KiB), headers (600B)"}, finding.Table.Rows[0].Cells)
+}
+
+func Test_WideTables_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := widetables.New(&mockWideTablesQueryer{err: fmt.Errorf("database connection error")})
+	_, err := checker.Check(context.Background())
+
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "wide-tables")
+}
+
+func Test_WideTables_Metadata(t *testing.T) {
+	t.Parallel()
+
+	metadata := widetables.Metadata()
+
+	require.Equal(t, "wide-tables", metadata.CheckID)
+	require.Equal(t, check.CategorySchema, metadata.Category)
+	require.NotEmpty(t, metadata.Description)
+	require.NotEmpty(t, metadata.SQL)
+	require.NotEmpty(t, metadata.Readme)
+}
@@ -0,0 +1,44 @@
+-- name: WideTables :many
+-- Column count and average row width (sum of the planner's per-column average
+-- widths) for every table, with its three widest columns as name:width.
+-- Partitions share their parent's columns and are left out.
+WITH table_columns AS (
+  SELECT
+    c.oid
+    , n.nspname::text AS schema_name
+    , c.relname::text AS table_name
+    , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+    , a.attname::text AS column_name
+    , ps.avg_width
+  FROM pg_class AS c
+  INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+  INNER JOIN pg_attribute AS a ON c.oid = a.attrelid
+  LEFT JOIN pg_stats AS ps
+    ON
+      n.nspname = ps.schemaname
+      AND c.relname = ps.tablename
+      AND a.attname = ps.attname
+      AND ps.inherited = (c.relkind = 'p')
+  WHERE
+    c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND a.attnum > 0
+    AND NOT a.attisdropped
+)
+
+SELECT
+  tc.schema_name
+  , tc.table_name
+  , count(*)::bigint AS column_count
+  , coalesce(sum(tc.avg_width), 0)::bigint AS avg_row_width
+  , tc.estimated_rows
+  , pg_table_size(tc.oid) AS table_size_bytes
+  , coalesce(
+    (array_agg(tc.column_name || ':' || tc.avg_width::text ORDER BY tc.avg_width DESC)
+      FILTER (WHERE tc.avg_width IS NOT NULL))[1:3]
+    , ARRAY[]::text []
+  ) AS widest_columns
+FROM table_columns AS tc
+GROUP BY tc.oid, tc.schema_name, tc.table_name, tc.estimated_rows
+ORDER BY tc.schema_name, tc.table_name;
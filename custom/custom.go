@@ -0,0 +1,377 @@
+// Package custom runs user-defined checks declared in a JSON file: a SQL query
+// plus rules that map column values of each returned row to a severity, e.g.
+// "rows where pending > 1000 are WARN". They run alongside the built-in checks
+// with their own IDs and categories.
+//
+//	{
+//	  "checks": [
+//	    {
+//	      "id": "orders-backlog",
+//	      "name": "Orders Backlog",
+//	      "category": "application",
+//	      "description": "Unprocessed orders per status",
+//	      "sql": "SELECT status, count(*) AS pending FROM orders WHERE processed_at IS NULL GROUP BY status",
+//	      "rules": [
+//	        {"column": "pending", "op": ">", "value": 1000, "severity": "warn"},
+//	        {"column": "pending", "op": ">", "value": 10000, "severity": "fail"}
+//	      ]
+//	    }
+//	  ]
+//	}
+//
+// The query runs as a subquery, so PostgreSQL rejects data-modifying statements;
+// run pgdoctor with a read-only role to rule out side effects of volatile functions.
+package custom
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"math"
+	"os"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgtype"
+)
+
+// File is the custom checks file format.
+type File struct {
+	Checks []Definition `json:"checks"`
+}
+
+// Definition declares one custom check.
+type Definition struct {
+	ID          string   `json:"id"`
+	Name        string   `json:"name"`
+	Category    string   `json:"category"`
+	Description string   `json:"description"`
+	SQL         string   `json:"sql"`
+	Rules       []Rule   `json:"rules"`
+	Tags        []string `json:"tags,omitempty"` // e.g. "triage" to join --preset triage
+}
+
+// Rule flags rows whose column compares true against value.
+// Numeric values support all operators; string values only = and !=.
+type Rule struct {
+	Column   string `json:"column"`
+	Op       string `json:"op"` // >, >=, <, <=, =, !=
+	Value    any    `json:"value"`
+	Severity string `json:"severity"` // warn or fail
+
+	severity check.Severity
+	number   float64
+	text     string
+	isText   bool
+}
+
+var idPattern = regexp.MustCompile(`^[a-z][a-z0-9]*(-[a-z0-9]+)*$`)
+
+// LoadFile reads and validates a custom checks file.
+func LoadFile(path string) ([]Definition, error) {
+	f, err := os.Open(path)
+	if err != nil {
+		return nil, fmt.Errorf("opening custom checks: %w", err)
+	}
+	defer f.Close()
+
+	return Load(f)
+}
+
+// Load reads and validates custom check definitions.
+func Load(r io.Reader) ([]Definition, error) {
+	dec := json.NewDecoder(r)
+	dec.DisallowUnknownFields()
+
+	var file File
+	if err := dec.Decode(&file); err != nil {
+		return nil, fmt.Errorf("decoding custom checks: %w", err)
+	}
+
+	seen := map[string]struct{}{}
+	for i := range file.Checks {
+		def := &file.Checks[i]
+		if err := def.validate(); err != nil {
+			return nil, fmt.Errorf("custom check %d (%q): %w", i+1, def.ID, err)
+		}
+		if _, dup := seen[def.ID]; dup {
+			return nil, fmt.Errorf("custom check %q is defined twice", def.ID)
+		}
+		seen[def.ID] = struct{}{}
+	}
+	return file.Checks, nil
+}
+
+func (d *Definition) validate() error {
+	if !idPattern.MatchString(d.ID) {
+		return fmt.Errorf("id must be lowercase words separated by dashes")
+	}
+	if !idPattern.MatchString(d.Category) {
+		return fmt.Errorf("category must be lowercase words separated by dashes")
+	}
+	d.SQL = strings.TrimRight(strings.TrimSpace(d.SQL), "; \n\t")
+	if d.SQL == "" {
+		return fmt.Errorf("sql is required")
+	}
+	if d.Name == "" {
+		d.Name = d.ID
+	}
+	if len(d.Rules) == 0 {
+		return fmt.Errorf("at least one rule is required")
+	}
+
+	for i := range d.Rules {
+		if err := d.Rules[i].compile(); err != nil {
+			return fmt.Errorf("rule %d: %w", i+1, err)
+		}
+	}
+	return nil
+}
+
+func (r *Rule) compile() error {
+	if r.Column == "" {
+		return fmt.Errorf("column is required")
+	}
+
+	severity, err := check.ParseSeverity(r.Severity)
+	if err != nil || severity == check.SeverityOK {
+		return fmt.Errorf("severity must be warn or fail, got %q", r.Severity)
+	}
+	r.severity = severity
+
+	switch value := r.Value.(type) {
+	case float64:
+		r.number = value
+	case string:
+		if r.Op != "=" && r.Op != "!=" {
+			return fmt.Errorf("string values only support = and !=")
+		}
+		r.text, r.isText = value, true
+	default:
+		return fmt.Errorf("value must be a number or a string")
+	}
+
+	switch r.Op {
+	case ">", ">=", "<", "<=", "=", "!=":
+		return nil
+	default:
+		return fmt.Errorf("unknown op %q (expected >, >=, <, <=, = or !=)", r.Op)
+	}
+}
+
+// String renders the rule as shown in reports, e.g. "pending > 1000".
+func (r Rule) String() string {
+	if r.isText {
+		return fmt.Sprintf("%s %s %q", r.Column, r.Op, r.text)
+	}
+	return fmt.Sprintf("%s %s %s", r.Column, r.Op, strconv.FormatFloat(r.number, 'f', -1, 64))
+}
+
+// matches reports whether the rule flags a column value. NULL never matches.
+func (r Rule) matches(value any) bool {
+	if value == nil {
+		return false
+	}
+	if r.isText {
+		equal := formatValue(value) == r.text
+		return equal == (r.Op == "=")
+	}
+
+	n, ok := toFloat(value)
+	if !ok {
+		return false
+	}
+	switch r.Op {
+	case ">":
+		return n > r.number
+	case ">=":
+		return n >= r.number
+	case "<":
+		return n < r.number
+	case "<=":
+		return n <= r.number
+	case "=":
+		return n == r.number
+	default:
+		return n != r.number
+	}
+}
+
+// Packages turns definitions into check packages that pgdoctor.Run accepts.
+func Packages(defs []Definition) []check.Package {
+	pkgs := make([]check.Package, 0, len(defs))
+	for _, def := range defs {
+		meta := def.metadata()
+		pkgs = append(pkgs, check.Package{
+			Metadata: func() check.Metadata { return meta },
+			New: func(conn db.DBTX, _ check.Config) check.Checker {
+				return &checker{def: def, meta: meta, conn: conn}
+			},
+		})
+	}
+	return pkgs
+}
+
+func (d Definition) metadata() check.Metadata {
+	var readme strings.Builder
+	fmt.Fprintf(&readme, "# %s\n\nCustom check.", d.Name)
+	if d.Description != "" {
+		fmt.Fprintf(&readme, " %s", d.Description)
+	}
+	readme.WriteString("\n\n## Rules\n\n")
+	for _, rule := range d.Rules {
+		fmt.Fprintf(&readme, "- `%s` → %s\n", rule, strings.ToUpper(rule.severity.String()))
+	}
+
+	return check.Metadata{
+		CheckID:     d.ID,
+		Name:        d.Name,
+		Category:    check.Category(d.Category),
+		Description: d.Description,
+		Readme:      readme.String(),
+		SQL:         d.SQL,
+		Tags:        d.Tags,
+	}
+}
+
+type checker struct {
+	def  Definition
+	meta check.Metadata
+	conn db.DBTX
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return c.meta
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(c.meta)
+
+	// As a subquery, the statement cannot modify data (PostgreSQL rejects
+	// INSERT/UPDATE/DELETE there, including in CTEs).
+	rows, err := c.conn.Query(ctx, "SELECT * FROM (\n"+c.def.SQL+"\n) AS custom_check")
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+	defer rows.Close()
+
+	fields := rows.FieldDescriptions()
+	headers := make([]string, 0, len(fields)+1)
+	columns := make(map[string]int, len(fields))
+	for i, field := range fields {
+		headers = append(headers, field.Name)
+		columns[field.Name] = i
+	}
+	headers = append(headers, "Rule")
+
+	for _, rule := range c.def.Rules {
+		if _, ok := columns[rule.Column]; !ok {
+			return nil, fmt.Errorf("running %s/%s: rule column %q is not in the query result", report.Category, report.CheckID, rule.Column)
+		}
+	}
+
+	var tableRows []check.TableRow
+	total := 0
+	severity := check.SeverityOK
+	for rows.Next() {
+		values, err := rows.Values()
+		if err != nil {
+			return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+		}
+		total++
+
+		rowSeverity := check.SeverityOK
+		var matched []string
+		for _, rule := range c.def.Rules {
+			if rule.matches(values[columns[rule.Column]]) {
+				rowSeverity = max(rowSeverity, rule.severity)
+				matched = append(matched, rule.String())
+			}
+		}
+		if rowSeverity == check.SeverityOK {
+			continue
+		}
+
+		cells := make([]string, 0, len(values)+1)
+		for _, value := range values {
+			cells = append(cells, formatValue(value))
+		}
+		cells = append(cells, strings.Join(matched, "\n"))
+		tableRows = append(tableRows, check.TableRow{Cells: cells, Severity: rowSeverity})
+		severity = max(severity, rowSeverity)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", report.Category, report.CheckID, err)
+	}
+
+	if len(tableRows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+			Details:  fmt.Sprintf("%d row(s) checked, none matched a rule", total),
+		})
+		return report, nil
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: severity,
+		Details:  fmt.Sprintf("%d of %d row(s) matched a rule", len(tableRows), total),
+		Table: &check.Table{
+			Headers: headers,
+			Rows:    tableRows,
+		},
+	})
+	return report, nil
+}
+
+func toFloat(value any) (float64, bool) {
+	switch v := value.(type) {
+	case int16:
+		return float64(v), true
+	case int32:
+		return float64(v), true
+	case int64:
+		return float64(v), true
+	case float32:
+		return float64(v), true
+	case float64:
+		return v, true
+	case pgtype.Numeric:
+		f, err := v.Float64Value()
+		if err != nil || !f.Valid {
+			return 0, false
+		}
+		return f.Float64, true
+	case string:
+		f, err := strconv.ParseFloat(v, 64)
+		return f, err == nil
+	default:
+		return 0, false
+	}
+}
+
+func formatValue(value any) string {
+	switch v := value.(type) {
+	case nil:
+		return "NULL"
+	case string:
+		return v
+	case time.Time:
+		return v.Format(time.RFC3339)
+	case pgtype.Numeric:
+		if f, ok := toFloat(v); ok && !math.IsInf(f, 0) {
+			return strconv.FormatFloat(f, 'f', -1, 64)
+		}
+		return "NaN"
+	default:
+		return fmt.Sprint(v)
+	}
+}
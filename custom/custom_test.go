@@ -0,0 +1,175 @@
+package custom_test
+
+import (
+	"context"
+	"strings"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/custom"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const backlogChecks = `{
+  "checks": [
+    {
+      "id": "orders-backlog",
+      "name": "Orders Backlog",
+      "category": "application",
+      "sql": "SELECT status, pending, ratio FROM backlog;",
+      "rules": [
+        {"column": "pending", "op": ">", "value": 1000, "severity": "warn"},
+        {"column": "pending", "op": ">", "value": 10000, "severity": "fail"},
+        {"column": "status", "op": "=", "value": "stuck", "severity": "warn"}
+      ]
+    }
+  ]
+}`
+
+// The check wraps the SQL (trailing semicolon removed) in a subquery.
+const backlogSQL = "SELECT * FROM (\nSELECT status, pending, ratio FROM backlog\n) AS custom_check"
+
+func replayer(rows ...[]string) *snapshot.Replayer {
+	query := snapshot.Query{
+		SQL: backlogSQL,
+		Fields: []snapshot.Field{
+			{Name: "status", OID: 25},
+			{Name: "pending", OID: 20},
+			{Name: "ratio", OID: 1700},
+		},
+	}
+	for _, row := range rows {
+		raw := make([][]byte, len(row))
+		for i, value := range row {
+			if value != "NULL" {
+				raw[i] = []byte(value)
+			}
+		}
+		query.Rows = append(query.Rows, raw)
+	}
+	return snapshot.NewReplayer(&snapshot.Snapshot{Version: snapshot.FormatVersion, Queries: []snapshot.Query{query}})
+}
+
+func TestCheck(t *testing.T) {
+	t.Parallel()
+
+	defs, err := custom.Load(strings.NewReader(backlogChecks))
+	require.NoError(t, err)
+	pkgs := custom.Packages(defs)
+	require.Len(t, pkgs, 1)
+
+	meta := pkgs[0].Metadata()
+	assert.Equal(t, "orders-backlog", meta.CheckID)
+	assert.Equal(t, check.Category("application"), meta.Category)
+	assert.Contains(t, meta.Readme, "`pending > 10000` → FAIL")
+
+	testCases := []struct {
+		Name     string
+		Rows     [][]string
+		Severity check.Severity
+		Matched  int
+	}{
+		{
+			Name:     "no rows match - OK",
+			Rows:     [][]string{{"new", "10", "0.5"}, {"paid", "NULL", "0.1"}},
+			Severity: check.SeverityOK,
+		},
+		{
+			Name:     "numeric threshold - WARN",
+			Rows:     [][]string{{"new", "1500", "0.5"}, {"paid", "3", "0.1"}},
+			Severity: check.SeverityWarn,
+			Matched:  1,
+		},
+		{
+			Name:     "highest matching rule wins - FAIL",
+			Rows:     [][]string{{"new", "20000", "0.5"}, {"stuck", "1", "0.1"}},
+			Severity: check.SeverityFail,
+			Matched:  2,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			report, err := pkgs[0].New(replayer(tc.Rows...), nil).Check(context.Background())
+			require.NoError(t, err)
+			require.Len(t, report.Results, 1)
+
+			finding := report.Results[0]
+			assert.Equal(t, tc.Severity, finding.Severity)
+			if tc.Matched == 0 {
+				assert.Nil(t, finding.Table)
+				return
+			}
+			require.NotNil(t, finding.Table)
+			assert.Equal(t, []string{"status", "pending", "ratio", "Rule"}, finding.Table.Headers)
+			assert.Len(t, finding.Table.Rows, tc.Matched)
+		})
+	}
+}
+
+func TestCheck_UnknownRuleColumn(t *testing.T) {
+	t.Parallel()
+
+	defs, err := custom.Load(strings.NewReader(strings.Replace(backlogChecks, `"column": "status"`, `"column": "state"`, 1)))
+	require.NoError(t, err)
+
+	_, err = custom.Packages(defs)[0].New(replayer(), nil).Check(context.Background())
+	require.ErrorContains(t, err, `rule column "state" is not in the query result`)
+}
+
+func TestLoad_Invalid(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name  string
+		JSON  string
+		Error string
+	}{
+		{
+			Name:  "bad id",
+			JSON:  `{"checks": [{"id": "Orders", "category": "app", "sql": "SELECT 1", "rules": [{"column": "x", "op": ">", "value": 1, "severity": "warn"}]}]}`,
+			Error: "id must be",
+		},
+		{
+			Name:  "no rules",
+			JSON:  `{"checks": [{"id": "orders", "category": "app", "sql": "SELECT 1"}]}`,
+			Error: "at least one rule",
+		},
+		{
+			Name:  "ok severity",
+			JSON:  `{"checks": [{"id": "orders", "category": "app", "sql": "SELECT 1", "rules": [{"column": "x", "op": ">", "value": 1, "severity": "ok"}]}]}`,
+			Error: "severity must be warn or fail",
+		},
+		{
+			Name:  "string with ordering op",
+			JSON:  `{"checks": [{"id": "orders", "category": "app", "sql": "SELECT 1", "rules": [{"column": "x", "op": ">", "value": "a", "severity": "warn"}]}]}`,
+			Error: "only support = and !=",
+		},
+		{
+			Name:  "unknown field",
+			JSON:  `{"checks": [{"id": "orders", "query": "SELECT 1"}]}`,
+			Error: "unknown field",
+		},
+		{
+			Name: "duplicate id",
+			JSON: `{"checks": [
+				{"id": "orders", "category": "app", "sql": "SELECT 1", "rules": [{"column": "x", "op": ">", "value": 1, "severity": "warn"}]},
+				{"id": "orders", "category": "app", "sql": "SELECT 2", "rules": [{"column": "x", "op": ">", "value": 1, "severity": "warn"}]}
+			]}`,
+			Error: "defined twice",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			_, err := custom.Load(strings.NewReader(tc.JSON))
+			require.ErrorContains(t, err, tc.Error)
+		})
+	}
+}
@@ -11,12 +11,282 @@ import (
 	"github.com/jackc/pgx/v5/pgtype"
 )
 
+const bgwriterStats = `-- name: BgwriterStats :one
+SELECT
+  c.buffers_written AS buffers_checkpoint
+  , b.buffers_clean
+  , b.maxwritten_clean
+  , COALESCE(io.writes, 0)::bigint AS buffers_backend
+  , COALESCE(io.fsyncs, 0)::bigint AS buffers_backend_fsync
+  , b.buffers_alloc
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , current_setting('bgwriter_lru_maxpages')::bigint AS bgwriter_lru_maxpages
+FROM pg_stat_bgwriter AS b
+CROSS JOIN pg_stat_checkpointer AS c
+CROSS JOIN (
+  SELECT
+    sum(writes) AS writes
+    , sum(fsyncs) AS fsyncs
+  FROM pg_stat_io
+  WHERE backend_type = 'client backend' AND object = 'relation'
+) AS io
+`
+
+type BgwriterStatsRow struct {
+	BuffersCheckpoint   int64
+	BuffersClean        int64
+	MaxwrittenClean     int64
+	BuffersBackend      int64
+	BuffersBackendFsync int64
+	BuffersAlloc        int64
+	SecondsSinceReset   int64
+	BgwriterLruMaxpages int64
+}
+
+// Buffers written by checkpoints, the background writer and backends since
+// statistics were reset (PostgreSQL 17+). Backend writes come from pg_stat_io.
+func (q *Queries) BgwriterStats(ctx context.Context) (BgwriterStatsRow, error) {
+	row := q.db.QueryRow(ctx, bgwriterStats)
+	var i BgwriterStatsRow
+	err := row.Scan(
+		&i.BuffersCheckpoint,
+		&i.BuffersClean,
+		&i.MaxwrittenClean,
+		&i.BuffersBackend,
+		&i.BuffersBackendFsync,
+		&i.BuffersAlloc,
+		&i.SecondsSinceReset,
+		&i.BgwriterLruMaxpages,
+	)
+	return i, err
+}
+
+const bgwriterStatsLegacy = `-- name: BgwriterStatsLegacy :one
+SELECT
+  b.buffers_checkpoint
+  , b.buffers_clean
+  , b.maxwritten_clean
+  , b.buffers_backend
+  , b.buffers_backend_fsync
+  , b.buffers_alloc
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , current_setting('bgwriter_lru_maxpages')::bigint AS bgwriter_lru_maxpages
+FROM pg_stat_bgwriter AS b
+`
+
+type BgwriterStatsLegacyRow struct {
+	BuffersCheckpoint   int64
+	BuffersClean        int64
+	MaxwrittenClean     int64
+	BuffersBackend      int64
+	BuffersBackendFsync int64
+	BuffersAlloc        int64
+	SecondsSinceReset   int64
+	BgwriterLruMaxpages int64
+}
+
+// Same as BgwriterStats, from pg_stat_bgwriter (PostgreSQL 16 and older).
+func (q *Queries) BgwriterStatsLegacy(ctx context.Context) (BgwriterStatsLegacyRow, error) {
+	row := q.db.QueryRow(ctx, bgwriterStatsLegacy)
+	var i BgwriterStatsLegacyRow
+	err := row.Scan(
+		&i.BuffersCheckpoint,
+		&i.BuffersClean,
+		&i.MaxwrittenClean,
+		&i.BuffersBackend,
+		&i.BuffersBackendFsync,
+		&i.BuffersAlloc,
+		&i.SecondsSinceReset,
+		&i.BgwriterLruMaxpages,
+	)
+	return i, err
+}
+
+const blockingLocks = `-- name: BlockingLocks :many
+WITH waited_locks AS (
+  SELECT DISTINCT ON (l.pid)
+    l.pid
+    , l.mode
+    , CASE
+      WHEN l.relation IS NOT NULL AND l.database = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+        THEN l.relation::regclass::text
+      ELSE l.locktype
+    END AS target
+  FROM pg_locks AS l
+  WHERE NOT l.granted
+  ORDER BY l.pid
+)
+
+SELECT
+  w.pid AS blocked_pid
+  , COALESCE(w.usename::text, '') AS blocked_user
+  , COALESCE(left(w.query, 200), '') AS blocked_query
+  , COALESCE(extract(EPOCH FROM (now() - w.query_start))::bigint, 0) AS wait_seconds
+  , COALESCE(wl.mode, '') AS lock_mode
+  , COALESCE(wl.target, '') AS lock_target
+  , bp.blocker_pid
+  , COALESCE(b.usename::text, '') AS blocker_user
+  , COALESCE(b.state, '') AS blocker_state
+  , COALESCE(left(b.query, 200), '') AS blocker_query
+FROM pg_stat_activity AS w
+CROSS JOIN LATERAL unnest(pg_blocking_pids(w.pid)) AS bp (blocker_pid)
+LEFT JOIN waited_locks AS wl ON w.pid = wl.pid
+LEFT JOIN pg_stat_activity AS b ON bp.blocker_pid = b.pid
+ORDER BY wait_seconds DESC, w.pid, bp.blocker_pid
+`
+
+type BlockingLocksRow struct {
+	BlockedPid   pgtype.Int4
+	BlockedUser  string
+	BlockedQuery string
+	WaitSeconds  int64
+	LockMode     string
+	LockTarget   string
+	BlockerPid   pgtype.Int4
+	BlockerUser  string
+	BlockerState string
+	BlockerQuery string
+}
+
+// One row per (blocked session, blocking session) pair from pg_blocking_pids(),
+// with the lock each blocked session waits for. Chains are assembled in Go.
+func (q *Queries) BlockingLocks(ctx context.Context) ([]BlockingLocksRow, error) {
+	rows, err := q.db.Query(ctx, blockingLocks)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []BlockingLocksRow
+	for rows.Next() {
+		var i BlockingLocksRow
+		if err := rows.Scan(
+			&i.BlockedPid,
+			&i.BlockedUser,
+			&i.BlockedQuery,
+			&i.WaitSeconds,
+			&i.LockMode,
+			&i.LockTarget,
+			&i.BlockerPid,
+			&i.BlockerUser,
+			&i.BlockerState,
+			&i.BlockerQuery,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const brinIndexes = `-- name: BrinIndexes :many
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , COALESCE(a.attname::text, '(expression)') AS column_name
+  , st.correlation::float8 AS correlation
+  , COALESCE(
+    (
+      SELECT o.option_value::int
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'pages_per_range'
+    )
+    , 128
+  ) AS pages_per_range
+  , COALESCE(
+    (
+      SELECT o.option_value IN ('on', 'true', 'yes', '1')
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'autosummarize'
+    )
+    , false
+  ) AS autosummarize
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , pg_table_size(t.oid) AS table_size_bytes
+  , GREATEST(t.reltuples, 0)::bigint AS estimated_rows
+  , t.relpages::bigint AS table_pages
+  , COALESCE(s.n_ins_since_vacuum, 0)::bigint AS n_ins_since_vacuum
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+LEFT JOIN pg_attribute AS a ON t.oid = a.attrelid AND i.indkey[0] = a.attnum AND i.indkey[0] <> 0
+LEFT JOIN pg_stats AS st
+  ON n.nspname = st.schemaname AND t.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN pg_stat_user_tables AS s ON t.oid = s.relid
+WHERE
+  am.amname = 'brin'
+  AND c.relkind = 'i'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY table_size_bytes DESC, n.nspname, c.relname
+`
+
+type BrinIndexesRow struct {
+	SchemaName      string
+	TableName       string
+	IndexName       string
+	ColumnName      string
+	Correlation     pgtype.Float8
+	PagesPerRange   int32
+	Autosummarize   bool
+	IndexSizeBytes  int64
+	TableSizeBytes  int64
+	EstimatedRows   int64
+	TablePages      int64
+	NInsSinceVacuum int64
+}
+
+// BRIN indexes with the planner's correlation between the first indexed
+// column and the physical row order, their pages_per_range and autosummarize
+// storage parameters, and what is needed to estimate how many block ranges
+// were added since the table was last vacuumed: inserts since then, and rows
+// per page. Expression columns have no correlation.
+func (q *Queries) BrinIndexes(ctx context.Context) ([]BrinIndexesRow, error) {
+	rows, err := q.db.Query(ctx, brinIndexes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []BrinIndexesRow
+	for rows.Next() {
+		var i BrinIndexesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.IndexName,
+			&i.ColumnName,
+			&i.Correlation,
+			&i.PagesPerRange,
+			&i.Autosummarize,
+			&i.IndexSizeBytes,
+			&i.TableSizeBytes,
+			&i.EstimatedRows,
+			&i.TablePages,
+			&i.NInsSinceVacuum,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const brokenIndexes = `-- name: BrokenIndexes :many
 SELECT
   n.nspname::text AS schema_name
   , tbl.relname::text AS table_name
   , idx.relname::text AS index_name
   , (idx.relname ~ '_cc(new|old)[0-9]*$') AS is_leftover
+  , (NOT i.indisready) AS is_not_ready
+  , pg_relation_size(idx.oid) AS size_bytes
+  , age(i.xmin) AS xid_age
 FROM pg_index AS i
 INNER JOIN pg_class AS idx ON i.indexrelid = idx.oid
 INNER JOIN pg_class AS tbl ON i.indrelid = tbl.oid
@@ -35,11 +305,17 @@ type BrokenIndexesRow struct {
 	TableName  string
 	IndexName  string
 	IsLeftover bool
+	IsNotReady bool
+	SizeBytes  int64
+	XidAge     int32
 }
 
 // Invalid indexes, flagging _ccnew/_ccold REINDEX CONCURRENTLY leftovers via
-// is_leftover. Excludes indexes a concurrent build is still working on (their
-// index_relid is in pg_stat_progress_create_index): in flight, not broken.
+// is_leftover and indexes never marked ready for inserts via is_not_ready.
+// Excludes indexes a concurrent build is still working on (their index_relid
+// is in pg_stat_progress_create_index): in flight, not broken.
+// xid_age is the number of transactions since the index's pg_index row was
+// last written, i.e. since the build failed: PostgreSQL keeps no timestamp.
 func (q *Queries) BrokenIndexes(ctx context.Context) ([]BrokenIndexesRow, error) {
 	rows, err := q.db.Query(ctx, brokenIndexes)
 	if err != nil {
@@ -54,6 +330,9 @@ func (q *Queries) BrokenIndexes(ctx context.Context) ([]BrokenIndexesRow, error)
 			&i.TableName,
 			&i.IndexName,
 			&i.IsLeftover,
+			&i.IsNotReady,
+			&i.SizeBytes,
+			&i.XidAge,
 		); err != nil {
 			return nil, err
 		}
@@ -65,125 +344,292 @@ func (q *Queries) BrokenIndexes(ctx context.Context) ([]BrokenIndexesRow, error)
 	return items, nil
 }
 
-const connectionStats = `-- name: ConnectionStats :one
+const canReadAuthid = `-- name: CanReadAuthid :one
+SELECT has_table_privilege('pg_catalog.pg_authid', 'SELECT') AS can_read_authid
+`
+
+// Whether stored passwords can be inspected. pg_authid is readable only by
+// superusers, which most managed services do not grant.
+func (q *Queries) CanReadAuthid(ctx context.Context) (bool, error) {
+	row := q.db.QueryRow(ctx, canReadAuthid)
+	var can_read_authid bool
+	err := row.Scan(&can_read_authid)
+	return can_read_authid, err
+}
+
+const catalogSettings = `-- name: CatalogSettings :many
 SELECT
-  current_setting('max_connections')::int AS max_connections
-  , current_setting('superuser_reserved_connections')::int AS reserved_connections
-  , count(*) AS total_connections
-  , count(*) FILTER (WHERE state = 'active') AS active_connections
-  , count(*) FILTER (WHERE state = 'idle') AS idle_connections
-  , count(*) FILTER (WHERE state = 'idle in transaction') AS idle_in_transaction
-  , count(*) FILTER (WHERE state = 'idle in transaction (aborted)') AS idle_in_transaction_aborted
-  , count(*) FILTER (WHERE wait_event_type IS NOT NULL AND state = 'active') AS waiting_connections
-FROM pg_stat_activity
-WHERE pid != pg_backend_pid()
+  name
+  , setting
+  , unit
+  , source
+  , boot_val
+  , reset_val
+  , context
+  , vartype
+  , pending_restart
+FROM pg_settings
+ORDER BY name
 `
 
-type ConnectionStatsRow struct {
-	MaxConnections           pgtype.Int4
-	ReservedConnections      pgtype.Int4
-	TotalConnections         pgtype.Int8
-	ActiveConnections        pgtype.Int8
-	IdleConnections          pgtype.Int8
-	IdleInTransaction        pgtype.Int8
-	IdleInTransactionAborted pgtype.Int8
-	WaitingConnections       pgtype.Int8
+type CatalogSettingsRow struct {
+	Name           pgtype.Text
+	Setting        pgtype.Text
+	Unit           pgtype.Text
+	Source         pgtype.Text
+	BootVal        pgtype.Text
+	ResetVal       pgtype.Text
+	Context        pgtype.Text
+	Vartype        pgtype.Text
+	PendingRestart pgtype.Bool
 }
 
-// Gets overall connection statistics including pool sizing metrics.
-func (q *Queries) ConnectionStats(ctx context.Context) (ConnectionStatsRow, error) {
-	row := q.db.QueryRow(ctx, connectionStats)
-	var i ConnectionStatsRow
+// Every server setting. Shared by the configuration checks and cached for the run.
+func (q *Queries) CatalogSettings(ctx context.Context) ([]CatalogSettingsRow, error) {
+	rows, err := q.db.Query(ctx, catalogSettings)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []CatalogSettingsRow
+	for rows.Next() {
+		var i CatalogSettingsRow
+		if err := rows.Scan(
+			&i.Name,
+			&i.Setting,
+			&i.Unit,
+			&i.Source,
+			&i.BootVal,
+			&i.ResetVal,
+			&i.Context,
+			&i.Vartype,
+			&i.PendingRestart,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const catalogTables = `-- name: CatalogTables :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , c.relkind::text AS relkind
+  , c.reltuples::bigint AS estimated_rows
+  , pg_table_size(c.oid) AS table_size_bytes
+  , pg_indexes_size(c.oid) AS indexes_size_bytes
+  , coalesce(s.seq_scan, 0) AS seq_scan
+  , coalesce(s.idx_scan, 0) AS idx_scan
+  , coalesce(s.n_live_tup, 0) AS n_live_tup
+  , coalesce(s.n_dead_tup, 0) AS n_dead_tup
+  , coalesce(s.n_tup_ins, 0) AS n_tup_ins
+  , coalesce(s.n_tup_upd, 0) AS n_tup_upd
+  , coalesce(s.n_tup_del, 0) AS n_tup_del
+  , coalesce(s.n_tup_hot_upd, 0) AS n_tup_hot_upd
+  , s.last_autovacuum
+  , s.last_autoanalyze
+  , (
+    SELECT o.option_value
+    FROM pg_options_to_table(c.reloptions) AS o
+    WHERE o.option_name = 'fillfactor'
+  )::int AS fillfactor
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+  AND n.nspname NOT LIKE 'pg\_toast\_temp\_%'
+ORDER BY
+  n.nspname
+  , c.relname
+`
+
+type CatalogTablesRow struct {
+	SchemaName       pgtype.Text
+	TableName        pgtype.Text
+	Relkind          pgtype.Text
+	EstimatedRows    pgtype.Int8
+	TableSizeBytes   pgtype.Int8
+	IndexesSizeBytes pgtype.Int8
+	SeqScan          pgtype.Int8
+	IdxScan          pgtype.Int8
+	NLiveTup         pgtype.Int8
+	NDeadTup         pgtype.Int8
+	NTupIns          pgtype.Int8
+	NTupUpd          pgtype.Int8
+	NTupDel          pgtype.Int8
+	NTupHotUpd       pgtype.Int8
+	LastAutovacuum   pgtype.Timestamptz
+	LastAutoanalyze  pgtype.Timestamptz
+	Fillfactor       pgtype.Int4
+}
+
+// Every user table with its size and pg_stat_user_tables counters. Shared by
+// several checks and cached for the run, so it is unfiltered: checks filter in Go.
+func (q *Queries) CatalogTables(ctx context.Context) ([]CatalogTablesRow, error) {
+	rows, err := q.db.Query(ctx, catalogTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []CatalogTablesRow
+	for rows.Next() {
+		var i CatalogTablesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.Relkind,
+			&i.EstimatedRows,
+			&i.TableSizeBytes,
+			&i.IndexesSizeBytes,
+			&i.SeqScan,
+			&i.IdxScan,
+			&i.NLiveTup,
+			&i.NDeadTup,
+			&i.NTupIns,
+			&i.NTupUpd,
+			&i.NTupDel,
+			&i.NTupHotUpd,
+			&i.LastAutovacuum,
+			&i.LastAutoanalyze,
+			&i.Fillfactor,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const checkpointStats = `-- name: CheckpointStats :one
+SELECT
+  c.num_timed AS checkpoints_timed
+  , c.num_requested AS checkpoints_requested
+  , c.write_time AS write_time_ms
+  , c.sync_time AS sync_time_ms
+  , c.buffers_written
+  , COALESCE(extract(EPOCH FROM (now() - c.stats_reset))::bigint, 0) AS seconds_since_reset
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_wal_size'), 0) AS max_wal_size_mb
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_timeout'), 0) AS checkpoint_timeout_seconds
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_warning'), 0) AS checkpoint_warning_seconds
+FROM pg_stat_checkpointer AS c
+`
+
+type CheckpointStatsRow struct {
+	CheckpointsTimed         int64
+	CheckpointsRequested     int64
+	WriteTimeMs              float64
+	SyncTimeMs               float64
+	BuffersWritten           int64
+	SecondsSinceReset        int64
+	MaxWalSizeMb             int64
+	CheckpointTimeoutSeconds int64
+	CheckpointWarningSeconds int64
+}
+
+// Checkpoint counters and timings since statistics were reset (PostgreSQL 17+),
+// with the settings that drive checkpoint frequency.
+func (q *Queries) CheckpointStats(ctx context.Context) (CheckpointStatsRow, error) {
+	row := q.db.QueryRow(ctx, checkpointStats)
+	var i CheckpointStatsRow
 	err := row.Scan(
-		&i.MaxConnections,
-		&i.ReservedConnections,
-		&i.TotalConnections,
-		&i.ActiveConnections,
-		&i.IdleConnections,
-		&i.IdleInTransaction,
-		&i.IdleInTransactionAborted,
-		&i.WaitingConnections,
+		&i.CheckpointsTimed,
+		&i.CheckpointsRequested,
+		&i.WriteTimeMs,
+		&i.SyncTimeMs,
+		&i.BuffersWritten,
+		&i.SecondsSinceReset,
+		&i.MaxWalSizeMb,
+		&i.CheckpointTimeoutSeconds,
+		&i.CheckpointWarningSeconds,
 	)
 	return i, err
 }
 
-const databaseCacheEfficiency = `-- name: DatabaseCacheEfficiency :one
+const checkpointStatsLegacy = `-- name: CheckpointStatsLegacy :one
 SELECT
-  blks_hit
-  , blks_read
-  , stats_reset
-  , CASE
-    WHEN blks_hit + blks_read = 0 THEN NULL
-    ELSE round(100.0 * blks_hit / (blks_hit + blks_read), 2)
-  END AS cache_hit_ratio
-  , coalesce(
-    extract(EPOCH FROM (now() - stats_reset)) / 86400
-    , 999
-  ) AS stats_age_days
-FROM pg_stat_database
-WHERE datname = current_database()
+  b.checkpoints_timed
+  , b.checkpoints_req AS checkpoints_requested
+  , b.checkpoint_write_time AS write_time_ms
+  , b.checkpoint_sync_time AS sync_time_ms
+  , b.buffers_checkpoint AS buffers_written
+  , COALESCE(extract(EPOCH FROM (now() - b.stats_reset))::bigint, 0) AS seconds_since_reset
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_wal_size'), 0) AS max_wal_size_mb
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_timeout'), 0) AS checkpoint_timeout_seconds
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'checkpoint_warning'), 0) AS checkpoint_warning_seconds
+FROM pg_stat_bgwriter AS b
 `
 
-type DatabaseCacheEfficiencyRow struct {
-	BlksHit       pgtype.Int8
-	BlksRead      pgtype.Int8
-	StatsReset    pgtype.Timestamptz
-	CacheHitRatio pgtype.Numeric
-	StatsAgeDays  pgtype.Numeric
+type CheckpointStatsLegacyRow struct {
+	CheckpointsTimed         int64
+	CheckpointsRequested     int64
+	WriteTimeMs              float64
+	SyncTimeMs               float64
+	BuffersWritten           int64
+	SecondsSinceReset        int64
+	MaxWalSizeMb             int64
+	CheckpointTimeoutSeconds int64
+	CheckpointWarningSeconds int64
 }
 
-// Returns database-wide buffer cache hit ratio.
-// Low ratios indicate shared_buffers too small or working set exceeds memory.
-func (q *Queries) DatabaseCacheEfficiency(ctx context.Context) (DatabaseCacheEfficiencyRow, error) {
-	row := q.db.QueryRow(ctx, databaseCacheEfficiency)
-	var i DatabaseCacheEfficiencyRow
+// Same as CheckpointStats, from pg_stat_bgwriter (PostgreSQL 16 and older).
+func (q *Queries) CheckpointStatsLegacy(ctx context.Context) (CheckpointStatsLegacyRow, error) {
+	row := q.db.QueryRow(ctx, checkpointStatsLegacy)
+	var i CheckpointStatsLegacyRow
 	err := row.Scan(
-		&i.BlksHit,
-		&i.BlksRead,
-		&i.StatsReset,
-		&i.CacheHitRatio,
-		&i.StatsAgeDays,
+		&i.CheckpointsTimed,
+		&i.CheckpointsRequested,
+		&i.WriteTimeMs,
+		&i.SyncTimeMs,
+		&i.BuffersWritten,
+		&i.SecondsSinceReset,
+		&i.MaxWalSizeMb,
+		&i.CheckpointTimeoutSeconds,
+		&i.CheckpointWarningSeconds,
 	)
 	return i, err
 }
 
-const databaseFreezeAge = `-- name: DatabaseFreezeAge :many
+const checksumFailures = `-- name: ChecksumFailures :many
 SELECT
-  datname::text AS database_name
-  , datfrozenxid::text AS frozen_xid
-  , age(datfrozenxid) AS freeze_age
-  , (
-    SELECT s.setting::bigint FROM pg_settings AS s
-    WHERE s.name = 'autovacuum_freeze_max_age'
-  ) AS freeze_max_age
-FROM pg_database
-WHERE datallowconn = true
-ORDER BY age(datfrozenxid) DESC
+  coalesce(d.datname, '')::text AS database_name
+  , d.checksum_failures::bigint AS checksum_failures
+  , d.checksum_last_failure AS last_failure
+FROM pg_catalog.pg_stat_database AS d
+WHERE d.checksum_failures > 0
+ORDER BY d.checksum_failures DESC, database_name
 `
 
-type DatabaseFreezeAgeRow struct {
-	DatabaseName pgtype.Text
-	FrozenXid    pgtype.Text
-	FreezeAge    pgtype.Int4
-	FreezeMaxAge pgtype.Int8
+type ChecksumFailuresRow struct {
+	DatabaseName     string
+	ChecksumFailures int64
+	LastFailure      pgtype.Timestamptz
 }
 
-// Gets transaction ID age for all databases.
-func (q *Queries) DatabaseFreezeAge(ctx context.Context) ([]DatabaseFreezeAgeRow, error) {
-	rows, err := q.db.Query(ctx, databaseFreezeAge)
+// Databases where a data checksum did not match when a page was read since
+// the statistics were reset (PostgreSQL 12+). The row with a NULL datname
+// counts failures in shared catalogs. checksum_failures is NULL when data
+// checksums are disabled.
+func (q *Queries) ChecksumFailures(ctx context.Context) ([]ChecksumFailuresRow, error) {
+	rows, err := q.db.Query(ctx, checksumFailures)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []DatabaseFreezeAgeRow
+	var items []ChecksumFailuresRow
 	for rows.Next() {
-		var i DatabaseFreezeAgeRow
-		if err := rows.Scan(
-			&i.DatabaseName,
-			&i.FrozenXid,
-			&i.FreezeAge,
-			&i.FreezeMaxAge,
-		); err != nil {
+		var i ChecksumFailuresRow
+		if err := rows.Scan(&i.DatabaseName, &i.ChecksumFailures, &i.LastFailure); err != nil {
 			return nil, err
 		}
 		items = append(items, i)
@@ -194,140 +640,56 @@ func (q *Queries) DatabaseFreezeAge(ctx context.Context) ([]DatabaseFreezeAgeRow
 	return items, nil
 }
 
-const duplicateIndexes = `-- name: DuplicateIndexes :many
-WITH index_columns AS (
-  SELECT
-    idx.indexrelid
-    , idx.indrelid
-    , i.relname AS index_name
-    , t.relname AS table_name
-    , n.nspname AS schema_name
-    , idx.indkey::int [] AS column_positions
-    , idx.indnkeyatts AS num_key_columns
-    -- Extract column list as array for prefix comparison
-    , pg_get_indexdef(idx.indexrelid) AS index_def
-    , pg_relation_size(i.oid) AS index_size_bytes
-    -- Detect expression/partial indexes (cannot reliably compare)
-    , (idx.indexprs IS NOT NULL) AS is_expression_index
-    , (idx.indpred IS NOT NULL) AS is_partial_index
-  FROM pg_index AS idx
-  INNER JOIN pg_class AS i ON idx.indexrelid = i.oid
-  INNER JOIN pg_class AS t ON idx.indrelid = t.oid
-  INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
-  WHERE
-    i.relkind = 'i'
-    AND idx.indisvalid
-    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'cron', 'pgpartman', 'debezium')
-)
-
-, exact_duplicates AS (
-  -- Find indexes with identical definitions (after removing index name)
-  SELECT
-    a.schema_name
-    , a.table_name
-    , a.index_name AS index_name_a
-    , b.index_name AS index_name_b
-    , a.index_size_bytes AS size_a
-    , b.index_size_bytes AS size_b
-    , a.index_def AS definition_a
-    , 'exact' AS duplicate_type
-  FROM index_columns AS a
-  INNER JOIN index_columns AS b ON
-    a.indrelid = b.indrelid
-    AND a.indexrelid < b.indexrelid
-    AND regexp_replace(a.index_def, 'INDEX \S+ ON', 'INDEX ON', 'g')
-    = regexp_replace(b.index_def, 'INDEX \S+ ON', 'INDEX ON', 'g')
-)
-
-, prefix_duplicates AS (
-  -- Find indexes where one is a left-prefix of another
-  -- e.g., (a) is prefix of (a, b)
-  SELECT
-    a.schema_name
-    , a.table_name
-    , a.index_name AS index_name_a
-    , b.index_name AS index_name_b
-    , a.index_size_bytes AS size_a
-    , b.index_size_bytes AS size_b
-    , a.index_def AS definition_a
-    , 'prefix' AS duplicate_type
-  FROM index_columns AS a
-  INNER JOIN index_columns AS b ON
-    a.indrelid = b.indrelid
-    AND a.indexrelid <> b.indexrelid
-    AND a.num_key_columns < b.num_key_columns
-    AND a.column_positions = b.column_positions[0:a.num_key_columns]
-    AND NOT a.is_expression_index
-    AND NOT b.is_expression_index
-    AND NOT a.is_partial_index
-    AND NOT b.is_partial_index
-)
-
+const clientConnectionSSL = `-- name: ClientConnectionSSL :many
 SELECT
-  (schema_name || '.' || table_name)::text AS table_name
-  , index_name_a::text
-  , index_name_b::text
-  , size_a
-  , size_b
-  , definition_a::text
-  , duplicate_type::text
-FROM (
-  SELECT
-    schema_name
-    , table_name
-    , index_name_a
-    , index_name_b
-    , size_a
-    , size_b
-    , definition_a
-    , duplicate_type
-  FROM exact_duplicates
-  UNION ALL
-  SELECT
-    schema_name
-    , table_name
-    , index_name_a
-    , index_name_b
-    , size_a
-    , size_b
-    , definition_a
-    , duplicate_type
-  FROM prefix_duplicates
-) AS all_duplicates
-ORDER BY
-  size_a + size_b DESC
+  coalesce(a.usename, '')::text AS user_name
+  , coalesce(host(a.client_addr), '')::text AS client_addr
+  , a.client_port IS NULL AS address_hidden
+  , coalesce(a.client_port = -1 OR a.client_addr <<= '127.0.0.0/8'::inet OR a.client_addr = '::1'::inet, false) AS is_local
+  , s.ssl
+  , coalesce(s.version, '')::text AS tls_version
+  , coalesce(s.cipher, '')::text AS cipher
+  , count(*)::bigint AS connections
+FROM pg_stat_activity AS a
+INNER JOIN pg_stat_ssl AS s ON a.pid = s.pid
+WHERE a.backend_type = 'client backend'
+GROUP BY 1, 2, 3, 4, 5, 6, 7
+ORDER BY connections DESC, user_name, client_addr
 `
 
-type DuplicateIndexesRow struct {
-	TableName     pgtype.Text
-	IndexNameA    pgtype.Text
-	IndexNameB    pgtype.Text
-	SizeA         pgtype.Int8
-	SizeB         pgtype.Int8
-	DefinitionA   pgtype.Text
-	DuplicateType pgtype.Text
+type ClientConnectionSSLRow struct {
+	UserName      string
+	ClientAddr    string
+	AddressHidden bool
+	IsLocal       bool
+	Ssl           bool
+	TlsVersion    string
+	Cipher        string
+	Connections   int64
 }
 
-// Identifies exact and prefix duplicate indexes on the same table.
-// Uses index column positions (indkey) for prefix detection.
-// Excludes: system schemas, invalid indexes, expression/partial indexes for prefix check.
-func (q *Queries) DuplicateIndexes(ctx context.Context) ([]DuplicateIndexesRow, error) {
-	rows, err := q.db.Query(ctx, duplicateIndexes)
+// Current client connections grouped by user, client address and TLS
+// parameters. client_port is -1 for Unix sockets and NULL when the connected
+// role may not see the address of another role's session (it needs
+// pg_read_all_stats); such connections are reported as not visible.
+func (q *Queries) ClientConnectionSSL(ctx context.Context) ([]ClientConnectionSSLRow, error) {
+	rows, err := q.db.Query(ctx, clientConnectionSSL)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []DuplicateIndexesRow
+	var items []ClientConnectionSSLRow
 	for rows.Next() {
-		var i DuplicateIndexesRow
+		var i ClientConnectionSSLRow
 		if err := rows.Scan(
-			&i.TableName,
-			&i.IndexNameA,
-			&i.IndexNameB,
-			&i.SizeA,
-			&i.SizeB,
-			&i.DefinitionA,
-			&i.DuplicateType,
+			&i.UserName,
+			&i.ClientAddr,
+			&i.AddressHidden,
+			&i.IsLocal,
+			&i.Ssl,
+			&i.TlsVersion,
+			&i.Cipher,
+			&i.Connections,
 		); err != nil {
 			return nil, err
 		}
@@ -339,84 +701,81 @@ func (q *Queries) DuplicateIndexes(ctx context.Context) ([]DuplicateIndexesRow,
 	return items, nil
 }
 
-const hasPgStatStatements = `-- name: HasPgStatStatements :one
-SELECT EXISTS(
-  SELECT 1 FROM pg_extension
-  WHERE extname = 'pg_stat_statements'
-)
-`
-
-// Checks if pg_stat_statements extension is installed.
-func (q *Queries) HasPgStatStatements(ctx context.Context) (bool, error) {
-	row := q.db.QueryRow(ctx, hasPgStatStatements)
-	var exists bool
-	err := row.Scan(&exists)
-	return exists, err
-}
-
-const highSeqScanTables = `-- name: HighSeqScanTables :many
-WITH table_indexes AS (
+const collationVersionMismatches = `-- name: CollationVersionMismatches :many
+WITH mismatched AS (
   SELECT
-    idx.indrelid AS table_oid
-    , count(*) AS index_count
-  FROM pg_index AS idx
-  WHERE idx.indisvalid
-  GROUP BY idx.indrelid
+    c.oid
+    , n.nspname AS schema_name
+    , c.collname AS collation_name
+    , c.collprovider
+    , c.collversion AS recorded_version
+    , COALESCE(pg_catalog.pg_collation_actual_version(c.oid), '') AS actual_version
+  FROM pg_catalog.pg_collation AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.collnamespace = n.oid
+  WHERE
+    c.collversion IS NOT NULL
+    AND pg_catalog.pg_collation_actual_version(c.oid) IS DISTINCT FROM c.collversion
 )
 
 SELECT
-  (n.nspname || '.' || c.relname)::text AS table_name
-  , coalesce(s.seq_scan, 0) AS seq_scan
-  , coalesce(s.idx_scan, 0) AS idx_scan
-  , CASE
-    WHEN coalesce(s.idx_scan, 0) = 0 THEN NULL
-    ELSE round(s.seq_scan::numeric / s.idx_scan, 2)
-  END AS seq_to_idx_ratio
-  , coalesce(s.n_live_tup, 0) AS estimated_rows
-  , pg_relation_size(c.oid) AS table_size_bytes
-  , coalesce(ti.index_count, 0) AS index_count
-FROM pg_class AS c
-INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
-LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
-LEFT JOIN table_indexes AS ti ON c.oid = ti.table_oid
+  m.schema_name::text AS schema_name
+  , m.collation_name::text AS collation_name
+  , m.collprovider::text AS provider
+  , m.recorded_version::text AS recorded_version
+  , m.actual_version::text AS actual_version
+  , (
+    SELECT count(*)
+    FROM pg_catalog.pg_attribute AS a
+    INNER JOIN pg_catalog.pg_class AS t ON a.attrelid = t.oid
+    WHERE
+      a.attcollation = m.oid
+      AND a.attnum > 0
+      AND NOT a.attisdropped
+      AND t.relkind IN ('r', 'p', 'm')
+  ) AS columns
+  , (
+    SELECT count(DISTINCT i.indexrelid)
+    FROM pg_catalog.pg_index AS i
+    WHERE m.oid = ANY(i.indcollation::oid [])
+  ) AS indexes
+FROM mismatched AS m
 WHERE
-  c.relkind IN ('r', 'p')
-  AND n.nspname = 'public'
-  AND coalesce(s.n_live_tup, 0) > 10000
-  AND coalesce(s.seq_scan, 0) > 100
-ORDER BY
-  coalesce(s.seq_scan, 0) DESC
+  EXISTS (SELECT 1 FROM pg_catalog.pg_attribute AS a WHERE a.attcollation = m.oid AND a.attnum > 0 AND NOT a.attisdropped)
+  OR EXISTS (SELECT 1 FROM pg_catalog.pg_index AS i WHERE m.oid = ANY(i.indcollation::oid []))
+ORDER BY m.schema_name, m.collation_name
 `
 
-type HighSeqScanTablesRow struct {
-	TableName      pgtype.Text
-	SeqScan        pgtype.Int8
-	IdxScan        pgtype.Int8
-	SeqToIdxRatio  pgtype.Numeric
-	EstimatedRows  pgtype.Int8
-	TableSizeBytes pgtype.Int8
-	IndexCount     pgtype.Int8
+type CollationVersionMismatchesRow struct {
+	SchemaName      string
+	CollationName   string
+	Provider        string
+	RecordedVersion string
+	ActualVersion   string
+	Columns         int64
+	Indexes         int64
 }
 
-// Identifies tables with excessive sequential scans relative to index scans.
-// Excludes: small tables, system schemas, tables with no indexes.
-func (q *Queries) HighSeqScanTables(ctx context.Context) ([]HighSeqScanTablesRow, error) {
-	rows, err := q.db.Query(ctx, highSeqScanTables)
+// Collations of the current database whose recorded version differs from
+// the one the provider reports now, limited to those used by a column or an
+// index: text indexes built with the old version may be ordered differently
+// from what lookups expect.
+func (q *Queries) CollationVersionMismatches(ctx context.Context) ([]CollationVersionMismatchesRow, error) {
+	rows, err := q.db.Query(ctx, collationVersionMismatches)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []HighSeqScanTablesRow
+	var items []CollationVersionMismatchesRow
 	for rows.Next() {
-		var i HighSeqScanTablesRow
+		var i CollationVersionMismatchesRow
 		if err := rows.Scan(
-			&i.TableName,
-			&i.SeqScan,
-			&i.IdxScan,
-			&i.SeqToIdxRatio,
-			&i.EstimatedRows,
-			&i.TableSizeBytes,
-			&i.IndexCount,
+			&i.SchemaName,
+			&i.CollationName,
+			&i.Provider,
+			&i.RecordedVersion,
+			&i.ActualVersion,
+			&i.Columns,
+			&i.Indexes,
 		); err != nil {
 			return nil, err
 		}
@@ -428,59 +787,49 @@ func (q *Queries) HighSeqScanTables(ctx context.Context) ([]HighSeqScanTablesRow
 	return items, nil
 }
 
-const idleInTransaction = `-- name: IdleInTransaction :many
+const columnCollations = `-- name: ColumnCollations :many
 SELECT
-  pg_stat_activity.pid
-  , pg_stat_activity.usename::text AS username
-  , pg_stat_activity.datname::text AS database_name
-  , pg_stat_activity.application_name::text AS application_name
-  , pg_stat_activity.state::text AS state
-  , extract(EPOCH FROM (now() - pg_stat_activity.xact_start))::bigint AS transaction_duration_seconds
-  , left(pg_stat_activity.query, 200)::text AS query_preview
-  , coalesce((
-    SELECT pg_settings.setting::bigint
-    FROM pg_settings
-    WHERE pg_settings.name = 'idle_in_transaction_session_timeout'
-  ), 0) AS timeout_ms
-FROM pg_stat_activity
+  CASE
+    WHEN a.attcollation = 100 THEN 'default'
+    ELSE pg_catalog.quote_ident(cn.nspname) || '.' || pg_catalog.quote_ident(co.collname)
+  END::text AS collation_name
+  , count(*) AS columns
+  , min(n.nspname || '.' || t.relname || '.' || a.attname)::text AS example_column
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS t ON a.attrelid = t.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON t.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_collation AS co ON a.attcollation = co.oid
+INNER JOIN pg_catalog.pg_namespace AS cn ON co.collnamespace = cn.oid
 WHERE
-  pg_stat_activity.state IN ('idle in transaction', 'idle in transaction (aborted)')
-  AND pg_stat_activity.pid != pg_backend_pid()
-ORDER BY pg_stat_activity.xact_start ASC
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND t.relkind IN ('r', 'p', 'm')
+  AND NOT t.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+GROUP BY 1
+ORDER BY count(*) DESC, 1
 `
 
-type IdleInTransactionRow struct {
-	Pid                        pgtype.Int4
-	Username                   pgtype.Text
-	DatabaseName               pgtype.Text
-	ApplicationName            pgtype.Text
-	State                      pgtype.Text
-	TransactionDurationSeconds pgtype.Int8
-	QueryPreview               pgtype.Text
-	TimeoutMs                  pgtype.Int8
+type ColumnCollationsRow struct {
+	CollationName string
+	Columns       int64
+	ExampleColumn string
 }
 
-// Identifies connections stuck in 'idle in transaction' state.
-// Includes the timeout setting (in ms) for threshold calculation in Go.
-func (q *Queries) IdleInTransaction(ctx context.Context) ([]IdleInTransactionRow, error) {
-	rows, err := q.db.Query(ctx, idleInTransaction)
+// Collations used by the collatable columns of user tables and materialized
+// views, with the number of columns and one example each. Columns without an
+// explicit collation use the database default (oid 100). Partitions repeat
+// their parent's columns and are left out.
+func (q *Queries) ColumnCollations(ctx context.Context) ([]ColumnCollationsRow, error) {
+	rows, err := q.db.Query(ctx, columnCollations)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []IdleInTransactionRow
+	var items []ColumnCollationsRow
 	for rows.Next() {
-		var i IdleInTransactionRow
-		if err := rows.Scan(
-			&i.Pid,
-			&i.Username,
-			&i.DatabaseName,
-			&i.ApplicationName,
-			&i.State,
-			&i.TransactionDurationSeconds,
-			&i.QueryPreview,
-			&i.TimeoutMs,
-		); err != nil {
+		var i ColumnCollationsRow
+		if err := rows.Scan(&i.CollationName, &i.Columns, &i.ExampleColumn); err != nil {
 			return nil, err
 		}
 		items = append(items, i)
@@ -491,142 +840,112 @@ func (q *Queries) IdleInTransaction(ctx context.Context) ([]IdleInTransactionRow
 	return items, nil
 }
 
-const indexBloat = `-- name: IndexBloat :many
-WITH index_info AS (
-  SELECT
-    n.nspname::text AS schemaname
-    , t.relname::text AS tablename
-    , i.relname::text AS indexname
-    , t.oid AS table_oid
-    , i.relpages AS actual_pages
-    , i.reltuples
-    , ix.indkey
-    , CURRENT_SETTING('block_size')::int AS bs
-    , COALESCE(
-      SUBSTRING(ARRAY_TO_STRING(i.reloptions, ' ') FROM 'fillfactor=([0-9]+)')::int
-      , 90
-    ) AS fill_factor
-  FROM pg_index AS ix
-  INNER JOIN pg_class AS i ON ix.indexrelid = i.oid
-  INNER JOIN pg_class AS t ON ix.indrelid = t.oid
-  INNER JOIN pg_namespace AS n ON i.relnamespace = n.oid
-  INNER JOIN pg_am AS am ON i.relam = am.oid
-  WHERE
-    am.amname = 'btree'
-    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
-    AND i.relpages > 100  -- Skip tiny indexes (<800KB)
-    AND ix.indisvalid
-    AND i.reltuples > 0
-)
-
-, index_columns AS (
-  SELECT
-    ii.schemaname
-    , ii.tablename
-    , ii.indexname
-    , ii.actual_pages
-    , ii.reltuples
-    , ii.fill_factor
-    , ii.bs
-    -- Sum avg_width from pg_stats for indexed columns
-    -- Fallback to 24 bytes if no stats (reasonable for UUID/timestamp)
-    , COALESCE(
-      (
-        SELECT SUM(COALESCE(s.avg_width, 8))
-        FROM UNNEST(ii.indkey) WITH ORDINALITY AS u (attnum, ord)
-        INNER JOIN pg_attribute AS a ON a.attrelid = ii.table_oid AND u.attnum = a.attnum
-        LEFT JOIN pg_stats AS s
-          ON
-            s.schemaname = ii.schemaname
-            AND s.tablename = ii.tablename
-            AND a.attname = s.attname
-        WHERE u.attnum > 0
-      )
-      , 24
-    ) AS data_width
-  FROM index_info AS ii
-)
+const columnStatisticsTargets = `-- name: ColumnStatisticsTargets :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , format_type(a.atttypid, a.atttypmod)::text AS data_type
+  , a.attstattarget::int AS stat_target
+FROM pg_attribute AS a
+INNER JOIN pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+  AND a.attstattarget >= 0
+ORDER BY n.nspname, c.relname, a.attnum
+`
 
-, bloat_calc AS (
-  SELECT
-    schemaname
-    , tablename
-    , indexname
-    , actual_pages
-    , reltuples
-    , bs
-    , data_width
-    -- Index tuple size: ItemPointer(6) + info(2) + data ≈ 8 + data_width
-    -- Simplified: skip per-column MAXALIGN, add ~20% padding estimate
-    , CEIL((8 + data_width) * 1.2) AS tuple_size
-    -- Usable space: block_size - PageHeader(24) - BTPageOpaque(16), apply fill_factor
-    , FLOOR((bs - 40) * fill_factor / 100.0) AS usable_space
-  FROM index_columns
-)
+type ColumnStatisticsTargetsRow struct {
+	SchemaName string
+	TableName  string
+	ColumnName string
+	DataType   string
+	StatTarget int32
+}
 
-, bloat_estimate AS (
-  SELECT
-    schemaname
-    , tablename
-    , indexname
-    , actual_pages
-    , bs
-    -- Expected pages = ceil(tuples / (usable_space / (line_pointer(4) + tuple_size)))
-    , GREATEST(1, CEIL(reltuples / FLOOR(usable_space / (4 + tuple_size))))::bigint AS est_pages
-    , (actual_pages::bigint * bs) AS actual_bytes
-  FROM bloat_calc
-  WHERE tuple_size > 0 AND usable_space > (4 + tuple_size)
-)
+// Columns with a per-column statistics target (ALTER TABLE ... ALTER COLUMN
+// ... SET STATISTICS). The default is -1 before PostgreSQL 17 and NULL since.
+func (q *Queries) ColumnStatisticsTargets(ctx context.Context) ([]ColumnStatisticsTargetsRow, error) {
+	rows, err := q.db.Query(ctx, columnStatisticsTargets)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []ColumnStatisticsTargetsRow
+	for rows.Next() {
+		var i ColumnStatisticsTargetsRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.ColumnName,
+			&i.DataType,
+			&i.StatTarget,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
 
+const columnStorageOverrides = `-- name: ColumnStorageOverrides :many
 SELECT
-  schemaname
-  , tablename
-  , indexname
-  , actual_pages
-  , est_pages
-  , actual_bytes
-  , ((actual_pages - est_pages)::bigint * bs) AS bloat_bytes
-  , CASE
-    WHEN actual_pages > 0 AND actual_pages > est_pages
-      THEN ROUND(100.0 * (actual_pages - est_pages) / actual_pages, 1)
-    ELSE 0
-  END AS bloat_percent
-FROM bloat_estimate
-WHERE actual_pages > est_pages
-ORDER BY bloat_percent DESC, bloat_bytes DESC
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , format_type(a.atttypid, a.atttypmod)::text AS data_type
+  , a.attstorage::text AS storage
+  , t.typstorage::text AS type_storage
+FROM pg_attribute AS a
+INNER JOIN pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_type AS t ON a.atttypid = t.oid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND NOT c.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+  AND t.typstorage <> 'p'
+  AND a.attstorage <> t.typstorage
+ORDER BY n.nspname, c.relname, a.attnum
 `
 
-type IndexBloatRow struct {
-	Schemaname   pgtype.Text
-	Tablename    pgtype.Text
-	Indexname    pgtype.Text
-	ActualPages  int32
-	EstPages     pgtype.Int8
-	ActualBytes  pgtype.Int8
-	BloatBytes   pgtype.Int8
-	BloatPercent pgtype.Numeric
+type ColumnStorageOverridesRow struct {
+	SchemaName  string
+	TableName   string
+	ColumnName  string
+	DataType    string
+	Storage     string
+	TypeStorage string
 }
 
-// Balanced B-tree index bloat estimation using pg_stats column widths
-// Accuracy: ±15% (good enough for health checks, not precision measurement)
-func (q *Queries) IndexBloat(ctx context.Context) ([]IndexBloatRow, error) {
-	rows, err := q.db.Query(ctx, indexBloat)
+// Columns of TOAST-able types whose storage strategy was changed from their
+// type's default with ALTER TABLE ... SET STORAGE. Partitions are left out:
+// they inherit the parent's strategy.
+func (q *Queries) ColumnStorageOverrides(ctx context.Context) ([]ColumnStorageOverridesRow, error) {
+	rows, err := q.db.Query(ctx, columnStorageOverrides)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []IndexBloatRow
+	var items []ColumnStorageOverridesRow
 	for rows.Next() {
-		var i IndexBloatRow
+		var i ColumnStorageOverridesRow
 		if err := rows.Scan(
-			&i.Schemaname,
-			&i.Tablename,
-			&i.Indexname,
-			&i.ActualPages,
-			&i.EstPages,
-			&i.ActualBytes,
-			&i.BloatBytes,
-			&i.BloatPercent,
+			&i.SchemaName,
+			&i.TableName,
+			&i.ColumnName,
+			&i.DataType,
+			&i.Storage,
+			&i.TypeStorage,
 		); err != nil {
 			return nil, err
 		}
@@ -638,85 +957,2423 @@ func (q *Queries) IndexBloat(ctx context.Context) ([]IndexBloatRow, error) {
 	return items, nil
 }
 
-const indexUsageStats = `-- name: IndexUsageStats :many
+const connectionStats = `-- name: ConnectionStats :one
 SELECT
-  (n.nspname || '.' || tbl.relname)::text AS table_name
-  , psai.indexrelname::text AS index_name
-  , c.reltuples::bigint AS num_rows
-  , x.indisprimary AS is_primary
-  , x.indisunique AS is_unique
-  , pg_relation_size(psai.indexrelid) AS index_size_bytes
-  , coalesce(psai.idx_scan, 0) AS idx_scan
-  , coalesce(psai.idx_tup_read, 0) AS idx_tup_read
-  , coalesce(psai.idx_tup_fetch, 0) AS idx_tup_fetch
-  , coalesce(ut.n_tup_ins, 0) + coalesce(ut.n_tup_upd, 0) + coalesce(ut.n_tup_del, 0) AS table_writes
-  , coalesce(psaio.idx_blks_hit, 0) AS idx_blks_hit
-  , coalesce(psaio.idx_blks_read, 0) AS idx_blks_read
+  current_setting('max_connections')::int AS max_connections
+  , current_setting('superuser_reserved_connections')::int AS reserved_connections
+  , count(*) AS total_connections
+  , count(*) FILTER (WHERE state = 'active') AS active_connections
+  , count(*) FILTER (WHERE state = 'idle') AS idle_connections
+  , count(*) FILTER (WHERE state = 'idle in transaction') AS idle_in_transaction
+  , count(*) FILTER (WHERE state = 'idle in transaction (aborted)') AS idle_in_transaction_aborted
+  , count(*) FILTER (WHERE wait_event_type IS NOT NULL AND state = 'active') AS waiting_connections
+FROM pg_stat_activity
+WHERE pid != pg_backend_pid()
+`
+
+type ConnectionStatsRow struct {
+	MaxConnections           pgtype.Int4
+	ReservedConnections      pgtype.Int4
+	TotalConnections         pgtype.Int8
+	ActiveConnections        pgtype.Int8
+	IdleConnections          pgtype.Int8
+	IdleInTransaction        pgtype.Int8
+	IdleInTransactionAborted pgtype.Int8
+	WaitingConnections       pgtype.Int8
+}
+
+// Gets overall connection statistics including pool sizing metrics.
+func (q *Queries) ConnectionStats(ctx context.Context) (ConnectionStatsRow, error) {
+	row := q.db.QueryRow(ctx, connectionStats)
+	var i ConnectionStatsRow
+	err := row.Scan(
+		&i.MaxConnections,
+		&i.ReservedConnections,
+		&i.TotalConnections,
+		&i.ActiveConnections,
+		&i.IdleConnections,
+		&i.IdleInTransaction,
+		&i.IdleInTransactionAborted,
+		&i.WaitingConnections,
+	)
+	return i, err
+}
+
+const databaseCacheEfficiency = `-- name: DatabaseCacheEfficiency :one
+SELECT
+  blks_hit
+  , blks_read
+  , stats_reset
   , CASE
-    WHEN coalesce(psaio.idx_blks_hit, 0) + coalesce(psaio.idx_blks_read, 0) = 0 THEN NULL
-    ELSE round(
-      100.0 * psaio.idx_blks_hit / (psaio.idx_blks_hit + psaio.idx_blks_read)
-      , 2
-    )
+    WHEN blks_hit + blks_read = 0 THEN NULL
+    ELSE round(100.0 * blks_hit / (blks_hit + blks_read), 2)
   END AS cache_hit_ratio
-  , pg_get_indexdef(psai.indexrelid) AS indexdef
-FROM pg_stat_user_indexes AS psai
-INNER JOIN pg_index AS x ON psai.indexrelid = x.indexrelid
-INNER JOIN pg_class AS tbl ON x.indrelid = tbl.oid
-INNER JOIN pg_namespace AS n ON tbl.relnamespace = n.oid
-LEFT JOIN pg_class AS c ON psai.relid = c.oid
-LEFT JOIN pg_stat_user_tables AS ut ON tbl.oid = ut.relid
-LEFT JOIN pg_statio_user_indexes AS psaio ON psai.indexrelid = psaio.indexrelid
+  , coalesce(
+    extract(EPOCH FROM (now() - stats_reset)) / 86400
+    , 999
+  ) AS stats_age_days
+  , pg_database_size(current_database()) AS database_size_bytes
+  , (
+    SELECT s.setting::bigint * current_setting('block_size')::bigint
+    FROM pg_settings AS s
+    WHERE s.name = 'shared_buffers'
+  ) AS shared_buffers_bytes
+FROM pg_stat_database
+WHERE datname = current_database()
+`
+
+type DatabaseCacheEfficiencyRow struct {
+	BlksHit            pgtype.Int8
+	BlksRead           pgtype.Int8
+	StatsReset         pgtype.Timestamptz
+	CacheHitRatio      pgtype.Numeric
+	StatsAgeDays       pgtype.Numeric
+	DatabaseSizeBytes  pgtype.Int8
+	SharedBuffersBytes pgtype.Int8
+}
+
+// Returns database-wide buffer cache hit ratio.
+// Low ratios indicate shared_buffers too small or working set exceeds memory.
+func (q *Queries) DatabaseCacheEfficiency(ctx context.Context) (DatabaseCacheEfficiencyRow, error) {
+	row := q.db.QueryRow(ctx, databaseCacheEfficiency)
+	var i DatabaseCacheEfficiencyRow
+	err := row.Scan(
+		&i.BlksHit,
+		&i.BlksRead,
+		&i.StatsReset,
+		&i.CacheHitRatio,
+		&i.StatsAgeDays,
+		&i.DatabaseSizeBytes,
+		&i.SharedBuffersBytes,
+	)
+	return i, err
+}
+
+const databaseCollationVersions = `-- name: DatabaseCollationVersions :many
+SELECT
+  d.datname::text AS database_name
+  , d.datcollversion::text AS recorded_version
+  , COALESCE(pg_catalog.pg_database_collation_actual_version(d.oid), '')::text AS actual_version
+FROM pg_catalog.pg_database AS d
 WHERE
-  n.nspname = 'public'
-ORDER BY
-  pg_relation_size(psai.indexrelid) DESC
+  d.datallowconn
+  AND NOT d.datistemplate
+  AND d.datcollversion IS NOT NULL
+  AND pg_catalog.pg_database_collation_actual_version(d.oid) IS DISTINCT FROM d.datcollversion
+ORDER BY d.datname
 `
 
-type IndexUsageStatsRow struct {
-	TableName      pgtype.Text
-	IndexName      pgtype.Text
-	NumRows        pgtype.Int8
-	IsPrimary      bool
-	IsUnique       bool
-	IndexSizeBytes pgtype.Int8
-	IdxScan        pgtype.Int8
-	IdxTupRead     pgtype.Int8
-	IdxTupFetch    pgtype.Int8
-	TableWrites    pgtype.Int8
+type DatabaseCollationVersionsRow struct {
+	DatabaseName    string
+	RecordedVersion string
+	ActualVersion   string
+}
+
+// Databases whose recorded default collation version differs from the one
+// the collation provider reports now (PostgreSQL 15+). An empty actual
+// version means the provider no longer reports one.
+func (q *Queries) DatabaseCollationVersions(ctx context.Context) ([]DatabaseCollationVersionsRow, error) {
+	rows, err := q.db.Query(ctx, databaseCollationVersions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DatabaseCollationVersionsRow
+	for rows.Next() {
+		var i DatabaseCollationVersionsRow
+		if err := rows.Scan(&i.DatabaseName, &i.RecordedVersion, &i.ActualVersion); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const databaseEncodings = `-- name: DatabaseEncodings :many
+SELECT
+  d.datname::text AS database_name
+  , pg_catalog.pg_encoding_to_char(d.encoding)::text AS encoding
+  , d.datcollate::text AS lc_collate
+  , d.datctype::text AS lc_ctype
+FROM pg_catalog.pg_database AS d
+WHERE
+  d.datallowconn
+  AND NOT d.datistemplate
+ORDER BY d.datname
+`
+
+type DatabaseEncodingsRow struct {
+	DatabaseName string
+	Encoding     string
+	LcCollate    string
+	LcCtype      string
+}
+
+// Server encoding and default locale of every database that accepts
+// connections, templates excluded.
+func (q *Queries) DatabaseEncodings(ctx context.Context) ([]DatabaseEncodingsRow, error) {
+	rows, err := q.db.Query(ctx, databaseEncodings)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DatabaseEncodingsRow
+	for rows.Next() {
+		var i DatabaseEncodingsRow
+		if err := rows.Scan(
+			&i.DatabaseName,
+			&i.Encoding,
+			&i.LcCollate,
+			&i.LcCtype,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const databaseFreezeAge = `-- name: DatabaseFreezeAge :many
+SELECT
+  datname::text AS database_name
+  , datfrozenxid::text AS frozen_xid
+  , age(datfrozenxid) AS freeze_age
+  , (
+    SELECT s.setting::bigint FROM pg_settings AS s
+    WHERE s.name = 'autovacuum_freeze_max_age'
+  ) AS freeze_max_age
+FROM pg_database
+WHERE datallowconn = true
+ORDER BY age(datfrozenxid) DESC
+`
+
+type DatabaseFreezeAgeRow struct {
+	DatabaseName pgtype.Text
+	FrozenXid    pgtype.Text
+	FreezeAge    pgtype.Int4
+	FreezeMaxAge pgtype.Int8
+}
+
+// Gets transaction ID age for all databases.
+func (q *Queries) DatabaseFreezeAge(ctx context.Context) ([]DatabaseFreezeAgeRow, error) {
+	rows, err := q.db.Query(ctx, databaseFreezeAge)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DatabaseFreezeAgeRow
+	for rows.Next() {
+		var i DatabaseFreezeAgeRow
+		if err := rows.Scan(
+			&i.DatabaseName,
+			&i.FrozenXid,
+			&i.FreezeAge,
+			&i.FreezeMaxAge,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const databaseMultixactAge = `-- name: DatabaseMultixactAge :many
+SELECT
+  datname::text AS database_name
+  , mxid_age(datminmxid)::bigint AS multixact_age
+  , COALESCE((
+    SELECT s.setting::bigint FROM pg_settings AS s
+    WHERE s.name = 'autovacuum_multixact_freeze_max_age'
+  ), 400000000)::bigint AS freeze_max_age
+FROM pg_database
+WHERE datallowconn = true
+ORDER BY mxid_age(datminmxid) DESC
+`
+
+type DatabaseMultixactAgeRow struct {
+	DatabaseName string
+	MultixactAge int64
+	FreezeMaxAge int64
+}
+
+// Gets multixact ID age for all databases.
+func (q *Queries) DatabaseMultixactAge(ctx context.Context) ([]DatabaseMultixactAgeRow, error) {
+	rows, err := q.db.Query(ctx, databaseMultixactAge)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DatabaseMultixactAgeRow
+	for rows.Next() {
+		var i DatabaseMultixactAgeRow
+		if err := rows.Scan(&i.DatabaseName, &i.MultixactAge, &i.FreezeMaxAge); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const deadlockStats = `-- name: DeadlockStats :many
+SELECT
+  d.datname::text AS database_name
+  , COALESCE(d.deadlocks, 0)::bigint AS deadlocks
+  , COALESCE(c.confl_snapshot, 0)::bigint AS confl_snapshot
+  , COALESCE(c.confl_lock, 0)::bigint AS confl_lock
+  , COALESCE(c.confl_bufferpin, 0)::bigint AS confl_bufferpin
+  , COALESCE(c.confl_deadlock, 0)::bigint AS confl_deadlock
+  , COALESCE(c.confl_tablespace, 0)::bigint AS confl_tablespace
+  , COALESCE(
+    extract(EPOCH FROM (now() - COALESCE(d.stats_reset, pg_postmaster_start_time())))::bigint, 0
+  ) AS seconds_since_reset
+  , pg_is_in_recovery() AS is_standby
+  , current_setting('log_lock_waits') AS log_lock_waits
+  , current_setting('deadlock_timeout') AS deadlock_timeout
+  , current_setting('hot_standby_feedback') AS hot_standby_feedback
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'max_standby_streaming_delay'), 0) AS max_standby_streaming_delay_ms
+FROM pg_stat_database AS d
+LEFT JOIN pg_stat_database_conflicts AS c ON d.datid = c.datid
+WHERE
+  d.datname IS NOT NULL
+  AND d.datname NOT IN ('template0', 'template1')
+ORDER BY d.deadlocks DESC, d.datname
+`
+
+type DeadlockStatsRow struct {
+	DatabaseName               string
+	Deadlocks                  int64
+	ConflSnapshot              int64
+	ConflLock                  int64
+	ConflBufferpin             int64
+	ConflDeadlock              int64
+	ConflTablespace            int64
+	SecondsSinceReset          int64
+	IsStandby                  bool
+	LogLockWaits               string
+	DeadlockTimeout            string
+	HotStandbyFeedback         string
+	MaxStandbyStreamingDelayMs int64
+}
+
+// Deadlocks and recovery conflicts per database since statistics were last reset.
+// Databases never reset count from server start.
+func (q *Queries) DeadlockStats(ctx context.Context) ([]DeadlockStatsRow, error) {
+	rows, err := q.db.Query(ctx, deadlockStats)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DeadlockStatsRow
+	for rows.Next() {
+		var i DeadlockStatsRow
+		if err := rows.Scan(
+			&i.DatabaseName,
+			&i.Deadlocks,
+			&i.ConflSnapshot,
+			&i.ConflLock,
+			&i.ConflBufferpin,
+			&i.ConflDeadlock,
+			&i.ConflTablespace,
+			&i.SecondsSinceReset,
+			&i.IsStandby,
+			&i.LogLockWaits,
+			&i.DeadlockTimeout,
+			&i.HotStandbyFeedback,
+			&i.MaxStandbyStreamingDelayMs,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const driftExtensions = `-- name: DriftExtensions :many
+SELECT
+  extname::text AS name
+  , extversion AS version
+FROM pg_extension
+ORDER BY extname
+`
+
+type DriftExtensionsRow struct {
+	Name    string
+	Version string
+}
+
+func (q *Queries) DriftExtensions(ctx context.Context) ([]DriftExtensionsRow, error) {
+	rows, err := q.db.Query(ctx, driftExtensions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DriftExtensionsRow
+	for rows.Next() {
+		var i DriftExtensionsRow
+		if err := rows.Scan(&i.Name, &i.Version); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const driftServerVersion = `-- name: DriftServerVersion :one
+SELECT
+  current_setting('server_version') AS server_version
+`
+
+func (q *Queries) DriftServerVersion(ctx context.Context) (string, error) {
+	row := q.db.QueryRow(ctx, driftServerVersion)
+	var server_version string
+	err := row.Scan(&server_version)
+	return server_version, err
+}
+
+const driftSettings = `-- name: DriftSettings :many
+SELECT
+  name
+  , reset_val AS value
+  , unit
+FROM pg_settings
+WHERE
+  name NOT IN (
+    'application_name'
+    , 'cluster_name'
+    , 'config_file'
+    , 'data_directory'
+    , 'external_pid_file'
+    , 'hba_file'
+    , 'ident_file'
+    , 'in_hot_standby'
+    , 'krb_server_keyfile'
+    , 'listen_addresses'
+    , 'log_directory'
+    , 'port'
+    , 'primary_conninfo'
+    , 'primary_slot_name'
+    , 'server_version'
+    , 'server_version_num'
+    , 'ssl_ca_file'
+    , 'ssl_cert_file'
+    , 'ssl_crl_dir'
+    , 'ssl_crl_file'
+    , 'ssl_key_file'
+    , 'transaction_read_only'
+    , 'unix_socket_directories'
+  )
+ORDER BY name
+`
+
+type DriftSettingsRow struct {
+	Name  pgtype.Text
+	Value pgtype.Text
+	Unit  pgtype.Text
+}
+
+// Server-level configuration. reset_val ignores SETs made by this session, and
+// settings that differ by design between hosts (paths, identity) are left out.
+func (q *Queries) DriftSettings(ctx context.Context) ([]DriftSettingsRow, error) {
+	rows, err := q.db.Query(ctx, driftSettings)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DriftSettingsRow
+	for rows.Next() {
+		var i DriftSettingsRow
+		if err := rows.Scan(&i.Name, &i.Value, &i.Unit); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const duplicateIndexes = `-- name: DuplicateIndexes :many
+WITH index_columns AS (
+  SELECT
+    idx.indexrelid
+    , idx.indrelid
+    , i.relname AS index_name
+    , t.relname AS table_name
+    , n.nspname AS schema_name
+    , am.amname AS access_method
+    -- Key columns only; slicing renumbers the arrays from 1 for comparison
+    , (idx.indkey::int [])[0:idx.indnkeyatts - 1] AS key_columns
+    , (idx.indclass::oid [])[0:idx.indnkeyatts - 1] AS opclasses
+    , (idx.indcollation::oid [])[0:idx.indnkeyatts - 1] AS collations
+    , (idx.indoption::int [])[0:idx.indnkeyatts - 1] AS sort_options
+    , idx.indnkeyatts AS num_key_columns
+    , idx.indnatts AS num_columns
+    , idx.indisunique AS is_unique
+    , pg_get_indexdef(idx.indexrelid) AS index_def
+    , pg_relation_size(i.oid) AS index_size_bytes
+    -- Indexes backing a primary key, unique or exclusion constraint cannot be
+    -- dropped on their own
+    , EXISTS (
+      SELECT 1 FROM pg_constraint AS con
+      WHERE con.conindid = idx.indexrelid AND con.contype IN ('p', 'u', 'x')
+    ) AS backs_constraint
+    -- Detect expression/partial indexes (cannot reliably compare)
+    , (idx.indexprs IS NOT NULL) AS is_expression_index
+    , (idx.indpred IS NOT NULL) AS is_partial_index
+  FROM pg_index AS idx
+  INNER JOIN pg_class AS i ON idx.indexrelid = i.oid
+  INNER JOIN pg_class AS t ON idx.indrelid = t.oid
+  INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+  INNER JOIN pg_am AS am ON i.relam = am.oid
+  WHERE
+    i.relkind = 'i'
+    AND idx.indisvalid
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'cron', 'pgpartman', 'debezium')
+)
+
+, exact_duplicates AS (
+  -- Find indexes with identical definitions (after removing index name)
+  SELECT
+    a.schema_name
+    , a.table_name
+    , a.index_name AS index_name_a
+    , b.index_name AS index_name_b
+    , a.index_size_bytes AS size_a
+    , b.index_size_bytes AS size_b
+    , a.index_def AS definition_a
+    , 'exact' AS duplicate_type
+    , a.backs_constraint AS constraint_a
+    , b.backs_constraint AS constraint_b
+  FROM index_columns AS a
+  INNER JOIN index_columns AS b ON
+    a.indrelid = b.indrelid
+    AND a.indexrelid < b.indexrelid
+    AND regexp_replace(a.index_def, 'INDEX \S+ ON', 'INDEX ON', 'g')
+    = regexp_replace(b.index_def, 'INDEX \S+ ON', 'INDEX ON', 'g')
+)
+
+, prefix_duplicates AS (
+  -- Find indexes where one is a left-prefix of another
+  -- e.g., (a) is prefix of (a, b). A unique prefix enforces something the
+  -- longer index does not, so it is never redundant.
+  SELECT
+    a.schema_name
+    , a.table_name
+    , a.index_name AS index_name_a
+    , b.index_name AS index_name_b
+    , a.index_size_bytes AS size_a
+    , b.index_size_bytes AS size_b
+    , a.index_def AS definition_a
+    , 'prefix' AS duplicate_type
+    , a.backs_constraint AS constraint_a
+    , b.backs_constraint AS constraint_b
+  FROM index_columns AS a
+  INNER JOIN index_columns AS b ON
+    a.indrelid = b.indrelid
+    AND a.indexrelid <> b.indexrelid
+    AND a.num_key_columns < b.num_key_columns
+    AND a.key_columns = b.key_columns[1:a.num_key_columns]
+    AND a.opclasses = b.opclasses[1:a.num_key_columns]
+    AND a.collations = b.collations[1:a.num_key_columns]
+    AND a.sort_options = b.sort_options[1:a.num_key_columns]
+    AND a.access_method = 'btree'
+    AND b.access_method = 'btree'
+    AND NOT a.is_unique
+    AND a.num_columns = a.num_key_columns
+    AND NOT a.is_expression_index
+    AND NOT b.is_expression_index
+    AND NOT a.is_partial_index
+    AND NOT b.is_partial_index
+)
+
+SELECT
+  (schema_name || '.' || table_name)::text AS table_name
+  , index_name_a::text
+  , index_name_b::text
+  , size_a
+  , size_b
+  , definition_a::text
+  , duplicate_type::text
+  , schema_name::text
+  , constraint_a
+  , constraint_b
+FROM (
+  SELECT
+    schema_name
+    , table_name
+    , index_name_a
+    , index_name_b
+    , size_a
+    , size_b
+    , definition_a
+    , duplicate_type
+    , constraint_a
+    , constraint_b
+  FROM exact_duplicates
+  UNION ALL
+  SELECT
+    schema_name
+    , table_name
+    , index_name_a
+    , index_name_b
+    , size_a
+    , size_b
+    , definition_a
+    , duplicate_type
+    , constraint_a
+    , constraint_b
+  FROM prefix_duplicates
+) AS all_duplicates
+ORDER BY
+  size_a + size_b DESC
+`
+
+type DuplicateIndexesRow struct {
+	TableName     pgtype.Text
+	IndexNameA    pgtype.Text
+	IndexNameB    pgtype.Text
+	SizeA         pgtype.Int8
+	SizeB         pgtype.Int8
+	DefinitionA   pgtype.Text
+	DuplicateType pgtype.Text
+	SchemaName    pgtype.Text
+	ConstraintA   pgtype.Bool
+	ConstraintB   pgtype.Bool
+}
+
+// Identifies exact and prefix duplicate indexes on the same table.
+// Exact: identical definitions apart from the name (columns, opclasses,
+// collations, predicate, INCLUDE columns, uniqueness and access method).
+// Prefix: a non-unique btree index whose key columns, opclasses, collations and
+// sort options are the leading key columns of another btree index.
+// Excludes: system schemas, invalid indexes, expression/partial indexes and
+// indexes with INCLUDE columns for the prefix check.
+func (q *Queries) DuplicateIndexes(ctx context.Context) ([]DuplicateIndexesRow, error) {
+	rows, err := q.db.Query(ctx, duplicateIndexes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []DuplicateIndexesRow
+	for rows.Next() {
+		var i DuplicateIndexesRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.IndexNameA,
+			&i.IndexNameB,
+			&i.SizeA,
+			&i.SizeB,
+			&i.DefinitionA,
+			&i.DuplicateType,
+			&i.SchemaName,
+			&i.ConstraintA,
+			&i.ConstraintB,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const ginPendingLists = `-- name: GinPendingLists :many
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , g.pending_pages::bigint * current_setting('block_size')::bigint AS pending_bytes
+  , g.pending_tuples::bigint AS pending_tuples
+  , COALESCE(
+    (
+      SELECT o.option_value::bigint
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'gin_pending_list_limit'
+    )
+    , (SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'gin_pending_list_limit')
+  ) * 1024 AS pending_list_limit_bytes
+  , COALESCE(
+    (
+      SELECT o.option_value
+      FROM pg_options_to_table(c.reloptions) AS o
+      WHERE o.option_name = 'fastupdate'
+    ) NOT IN ('off', 'false', 'no', '0')
+    , true
+  ) AS fastupdate
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+CROSS JOIN LATERAL pgstatginindex(c.oid) AS g
+WHERE
+  am.amname = 'gin'
+  AND c.relkind = 'i'
+  AND c.relpersistence <> 't'
+  AND i.indisvalid
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY pending_bytes DESC, n.nspname, c.relname
+`
+
+type GinPendingListsRow struct {
+	SchemaName            string
+	TableName             string
+	IndexName             string
+	IndexSizeBytes        int64
+	PendingBytes          int64
+	PendingTuples         int64
+	PendingListLimitBytes int64
+	Fastupdate            bool
+}
+
+// GIN indexes with the size of their pending list, from pgstattuple, and the
+// gin_pending_list_limit that applies to each: the index's storage parameter,
+// or the server setting. Both are in kB and returned in bytes. Temporary
+// indexes of other sessions cannot be read and are left out.
+func (q *Queries) GinPendingLists(ctx context.Context) ([]GinPendingListsRow, error) {
+	rows, err := q.db.Query(ctx, ginPendingLists)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []GinPendingListsRow
+	for rows.Next() {
+		var i GinPendingListsRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.IndexName,
+			&i.IndexSizeBytes,
+			&i.PendingBytes,
+			&i.PendingTuples,
+			&i.PendingListLimitBytes,
+			&i.Fastupdate,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const hasPgStatStatements = `-- name: HasPgStatStatements :one
+SELECT EXISTS(
+  SELECT 1 FROM pg_extension
+  WHERE extname = 'pg_stat_statements'
+)
+`
+
+// Checks if pg_stat_statements extension is installed.
+func (q *Queries) HasPgStatStatements(ctx context.Context) (bool, error) {
+	row := q.db.QueryRow(ctx, hasPgStatStatements)
+	var exists bool
+	err := row.Scan(&exists)
+	return exists, err
+}
+
+const highSeqScanTables = `-- name: HighSeqScanTables :many
+WITH table_indexes AS (
+  SELECT
+    idx.indrelid AS table_oid
+    , count(*) AS index_count
+  FROM pg_index AS idx
+  WHERE idx.indisvalid
+  GROUP BY idx.indrelid
+)
+
+SELECT
+  (n.nspname || '.' || c.relname)::text AS table_name
+  , coalesce(s.seq_scan, 0) AS seq_scan
+  , coalesce(s.seq_tup_read, 0) AS seq_tup_read
+  , coalesce(s.idx_scan, 0) AS idx_scan
+  , CASE
+    WHEN coalesce(s.idx_scan, 0) = 0 THEN NULL
+    ELSE round(s.seq_scan::numeric / s.idx_scan, 2)
+  END AS seq_to_idx_ratio
+  , coalesce(s.n_live_tup, 0) AS estimated_rows
+  , pg_relation_size(c.oid) AS table_size_bytes
+  , coalesce(ti.index_count, 0) AS index_count
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+LEFT JOIN table_indexes AS ti ON c.oid = ti.table_oid
+WHERE
+  c.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND coalesce(s.n_live_tup, 0) > 10000
+  AND coalesce(s.seq_scan, 0) > 100
+ORDER BY
+  coalesce(s.seq_tup_read, 0) DESC
+`
+
+type HighSeqScanTablesRow struct {
+	TableName      pgtype.Text
+	SeqScan        pgtype.Int8
+	SeqTupRead     pgtype.Int8
+	IdxScan        pgtype.Int8
+	SeqToIdxRatio  pgtype.Numeric
+	EstimatedRows  pgtype.Int8
+	TableSizeBytes pgtype.Int8
+	IndexCount     pgtype.Int8
+}
+
+// Identifies tables with excessive sequential scans relative to index scans.
+// Excludes: small tables, system schemas, tables with no indexes.
+// Ordered by rows read by sequential scans, the work an index could save.
+func (q *Queries) HighSeqScanTables(ctx context.Context) ([]HighSeqScanTablesRow, error) {
+	rows, err := q.db.Query(ctx, highSeqScanTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []HighSeqScanTablesRow
+	for rows.Next() {
+		var i HighSeqScanTablesRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.SeqScan,
+			&i.SeqTupRead,
+			&i.IdxScan,
+			&i.SeqToIdxRatio,
+			&i.EstimatedRows,
+			&i.TableSizeBytes,
+			&i.IndexCount,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const idleInTransaction = `-- name: IdleInTransaction :many
+SELECT
+  pg_stat_activity.pid
+  , pg_stat_activity.usename::text AS username
+  , pg_stat_activity.datname::text AS database_name
+  , pg_stat_activity.application_name::text AS application_name
+  , pg_stat_activity.state::text AS state
+  , extract(EPOCH FROM (now() - pg_stat_activity.xact_start))::bigint AS transaction_duration_seconds
+  , left(pg_stat_activity.query, 200)::text AS query_preview
+  , coalesce((
+    SELECT pg_settings.setting::bigint
+    FROM pg_settings
+    WHERE pg_settings.name = 'idle_in_transaction_session_timeout'
+  ), 0) AS timeout_ms
+  , extract(EPOCH FROM (now() - pg_stat_activity.state_change))::bigint AS idle_seconds
+  , (
+    SELECT count(*)
+    FROM pg_locks AS l
+    WHERE
+      l.pid = pg_stat_activity.pid
+      AND l.granted
+      AND l.locktype NOT IN ('virtualxid', 'transactionid')
+  ) AS locks_held
+  -- Relation names resolve only for the current database
+  , coalesce((
+    SELECT string_agg(DISTINCT l.relation::regclass::text, ', ')
+    FROM pg_locks AS l
+    WHERE
+      l.pid = pg_stat_activity.pid
+      AND l.granted
+      AND l.locktype = 'relation'
+      AND l.database = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+  ), '') AS locked_relations
+  , (
+    SELECT count(*)
+    FROM pg_stat_activity AS waiting
+    WHERE pg_stat_activity.pid = ANY(pg_blocking_pids(waiting.pid))
+  ) AS blocked_sessions
+FROM pg_stat_activity
+WHERE
+  pg_stat_activity.state IN ('idle in transaction', 'idle in transaction (aborted)')
+  AND pg_stat_activity.pid != pg_backend_pid()
+ORDER BY pg_stat_activity.xact_start ASC
+`
+
+type IdleInTransactionRow struct {
+	Pid                        pgtype.Int4
+	Username                   pgtype.Text
+	DatabaseName               pgtype.Text
+	ApplicationName            pgtype.Text
+	State                      pgtype.Text
+	TransactionDurationSeconds pgtype.Int8
+	QueryPreview               pgtype.Text
+	TimeoutMs                  pgtype.Int8
+	IdleSeconds                pgtype.Int8
+	LocksHeld                  int64
+	LockedRelations            string
+	BlockedSessions            int64
+}
+
+// Identifies connections stuck in 'idle in transaction' state, with the locks
+// they hold and how many sessions wait on them.
+// Includes the timeout setting (in ms) for threshold calculation in Go.
+func (q *Queries) IdleInTransaction(ctx context.Context) ([]IdleInTransactionRow, error) {
+	rows, err := q.db.Query(ctx, idleInTransaction)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []IdleInTransactionRow
+	for rows.Next() {
+		var i IdleInTransactionRow
+		if err := rows.Scan(
+			&i.Pid,
+			&i.Username,
+			&i.DatabaseName,
+			&i.ApplicationName,
+			&i.State,
+			&i.TransactionDurationSeconds,
+			&i.QueryPreview,
+			&i.TimeoutMs,
+			&i.IdleSeconds,
+			&i.LocksHeld,
+			&i.LockedRelations,
+			&i.BlockedSessions,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const impliedForeignKeys = `-- name: ImpliedForeignKeys :many
+WITH candidates AS (
+  SELECT
+    c.oid AS table_oid
+    , n.nspname AS schema_name
+    , c.relname AS table_name
+    , a.attnum
+    , a.attname AS column_name
+    , a.atttypid
+    , left(a.attname, -3) AS prefix
+  FROM pg_catalog.pg_attribute AS a
+  INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  WHERE
+    a.attnum > 0
+    AND NOT a.attisdropped
+    AND a.attname LIKE '_%\_id'
+    AND c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_constraint AS fk
+      WHERE
+        fk.conrelid = c.oid
+        AND fk.contype = 'f'
+        AND a.attnum = ANY(fk.conkey)
+    )
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+, referenced_keys AS (
+  SELECT
+    c.oid AS table_oid
+    , n.nspname AS schema_name
+    , c.relname AS table_name
+    , a.attname AS column_name
+    , a.atttypid
+    , con.contype = 'p' AS is_primary
+  FROM pg_catalog.pg_constraint AS con
+  INNER JOIN pg_catalog.pg_class AS c ON con.conrelid = c.oid
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  INNER JOIN pg_catalog.pg_attribute AS a ON con.conrelid = a.attrelid AND con.conkey[1] = a.attnum
+  WHERE
+    con.contype IN ('p', 'u')
+    AND cardinality(con.conkey) = 1
+    AND c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+)
+
+SELECT
+  cand.schema_name::text AS schema_name
+  , cand.table_name::text AS table_name
+  , cand.column_name::text AS column_name
+  , pg_catalog.format_type(cand.atttypid, NULL) AS column_type
+  , k.schema_name::text AS referenced_schema
+  , k.table_name::text AS referenced_table
+  , k.column_name::text AS referenced_column
+  , pg_catalog.format_type(k.atttypid, NULL) AS referenced_type
+  , k.is_primary
+FROM candidates AS cand
+INNER JOIN referenced_keys AS k
+  ON
+    k.table_name IN (
+      cand.prefix
+      , cand.prefix || 's'
+      , cand.prefix || 'es'
+      , regexp_replace(cand.prefix, 'y$', 'ies')
+    )
+    AND k.column_name IN ('id', cand.column_name)
+    AND NOT (k.table_oid = cand.table_oid AND k.column_name = cand.column_name)
+ORDER BY cand.schema_name, cand.table_name, cand.attnum, k.schema_name, k.table_name
+`
+
+type ImpliedForeignKeysRow struct {
+	SchemaName       string
+	TableName        string
+	ColumnName       string
+	ColumnType       string
+	ReferencedSchema string
+	ReferencedTable  string
+	ReferencedColumn string
+	ReferencedType   string
+	IsPrimary        bool
+}
+
+// Columns named <name>_id that are not part of any foreign key, paired with
+// the single-column primary keys and unique constraints they probably point
+// at: a table called <name>, <name>s, <name>es or <name> with a trailing y
+// turned into ies, keyed on id or on a column with the same name. A column
+// can match tables in several schemas; the checker keeps the best match.
+// Excludes: system schemas, partitions (covered by their parent), tables
+// owned by extensions, and a key matching itself.
+func (q *Queries) ImpliedForeignKeys(ctx context.Context) ([]ImpliedForeignKeysRow, error) {
+	rows, err := q.db.Query(ctx, impliedForeignKeys)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []ImpliedForeignKeysRow
+	for rows.Next() {
+		var i ImpliedForeignKeysRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.ColumnName,
+			&i.ColumnType,
+			&i.ReferencedSchema,
+			&i.ReferencedTable,
+			&i.ReferencedColumn,
+			&i.ReferencedType,
+			&i.IsPrimary,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const indexBloat = `-- name: IndexBloat :many
+WITH index_info AS (
+  SELECT
+    n.nspname::text AS schemaname
+    , t.relname::text AS tablename
+    , i.relname::text AS indexname
+    , t.oid AS table_oid
+    , i.relpages AS actual_pages
+    , i.reltuples
+    , ix.indkey
+    , CURRENT_SETTING('block_size')::int AS bs
+    , COALESCE(
+      SUBSTRING(ARRAY_TO_STRING(i.reloptions, ' ') FROM 'fillfactor=([0-9]+)')::int
+      , 90
+    ) AS fill_factor
+  FROM pg_index AS ix
+  INNER JOIN pg_class AS i ON ix.indexrelid = i.oid
+  INNER JOIN pg_class AS t ON ix.indrelid = t.oid
+  INNER JOIN pg_namespace AS n ON i.relnamespace = n.oid
+  INNER JOIN pg_am AS am ON i.relam = am.oid
+  WHERE
+    am.amname = 'btree'
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND i.relpages > 100  -- Skip tiny indexes (<800KB)
+    AND ix.indisvalid
+    AND i.reltuples > 0
+)
+
+, index_columns AS (
+  SELECT
+    ii.schemaname
+    , ii.tablename
+    , ii.indexname
+    , ii.actual_pages
+    , ii.reltuples
+    , ii.fill_factor
+    , ii.bs
+    -- Sum avg_width from pg_stats for indexed columns
+    -- Fallback to 24 bytes if no stats (reasonable for UUID/timestamp)
+    , COALESCE(
+      (
+        SELECT SUM(COALESCE(s.avg_width, 8))
+        FROM UNNEST(ii.indkey) WITH ORDINALITY AS u (attnum, ord)
+        INNER JOIN pg_attribute AS a ON a.attrelid = ii.table_oid AND u.attnum = a.attnum
+        LEFT JOIN pg_stats AS s
+          ON
+            s.schemaname = ii.schemaname
+            AND s.tablename = ii.tablename
+            AND a.attname = s.attname
+        WHERE u.attnum > 0
+      )
+      , 24
+    ) AS data_width
+  FROM index_info AS ii
+)
+
+, bloat_calc AS (
+  SELECT
+    schemaname
+    , tablename
+    , indexname
+    , actual_pages
+    , reltuples
+    , bs
+    , data_width
+    -- Index tuple size: ItemPointer(6) + info(2) + data ≈ 8 + data_width
+    -- Simplified: skip per-column MAXALIGN, add ~20% padding estimate
+    , CEIL((8 + data_width) * 1.2) AS tuple_size
+    -- Usable space: block_size - PageHeader(24) - BTPageOpaque(16), apply fill_factor
+    , FLOOR((bs - 40) * fill_factor / 100.0) AS usable_space
+  FROM index_columns
+)
+
+, bloat_estimate AS (
+  SELECT
+    schemaname
+    , tablename
+    , indexname
+    , actual_pages
+    , bs
+    -- Expected pages = ceil(tuples / (usable_space / (line_pointer(4) + tuple_size)))
+    , GREATEST(1, CEIL(reltuples / FLOOR(usable_space / (4 + tuple_size))))::bigint AS est_pages
+    , (actual_pages::bigint * bs) AS actual_bytes
+  FROM bloat_calc
+  WHERE tuple_size > 0 AND usable_space > (4 + tuple_size)
+)
+
+SELECT
+  schemaname
+  , tablename
+  , indexname
+  , actual_pages
+  , est_pages
+  , actual_bytes
+  , ((actual_pages - est_pages)::bigint * bs) AS bloat_bytes
+  , CASE
+    WHEN actual_pages > 0 AND actual_pages > est_pages
+      THEN ROUND(100.0 * (actual_pages - est_pages) / actual_pages, 1)
+    ELSE 0
+  END AS bloat_percent
+FROM bloat_estimate
+WHERE actual_pages > est_pages
+ORDER BY bloat_percent DESC, bloat_bytes DESC
+`
+
+type IndexBloatRow struct {
+	Schemaname   pgtype.Text
+	Tablename    pgtype.Text
+	Indexname    pgtype.Text
+	ActualPages  int32
+	EstPages     pgtype.Int8
+	ActualBytes  pgtype.Int8
+	BloatBytes   pgtype.Int8
+	BloatPercent pgtype.Numeric
+}
+
+// Balanced B-tree index bloat estimation using pg_stats column widths
+// Accuracy: ±15% (good enough for health checks, not precision measurement)
+func (q *Queries) IndexBloat(ctx context.Context) ([]IndexBloatRow, error) {
+	rows, err := q.db.Query(ctx, indexBloat)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []IndexBloatRow
+	for rows.Next() {
+		var i IndexBloatRow
+		if err := rows.Scan(
+			&i.Schemaname,
+			&i.Tablename,
+			&i.Indexname,
+			&i.ActualPages,
+			&i.EstPages,
+			&i.ActualBytes,
+			&i.BloatBytes,
+			&i.BloatPercent,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const indexUsageStats = `-- name: IndexUsageStats :many
+SELECT
+  (n.nspname || '.' || tbl.relname)::text AS table_name
+  , psai.indexrelname::text AS index_name
+  , c.reltuples::bigint AS num_rows
+  , x.indisprimary AS is_primary
+  , x.indisunique AS is_unique
+  , pg_relation_size(psai.indexrelid) AS index_size_bytes
+  , coalesce(psai.idx_scan, 0) AS idx_scan
+  , coalesce(psai.idx_tup_read, 0) AS idx_tup_read
+  , coalesce(psai.idx_tup_fetch, 0) AS idx_tup_fetch
+  , coalesce(ut.n_tup_ins, 0) + coalesce(ut.n_tup_upd, 0) + coalesce(ut.n_tup_del, 0) AS table_writes
+  , coalesce(psaio.idx_blks_hit, 0) AS idx_blks_hit
+  , coalesce(psaio.idx_blks_read, 0) AS idx_blks_read
+  , CASE
+    WHEN coalesce(psaio.idx_blks_hit, 0) + coalesce(psaio.idx_blks_read, 0) = 0 THEN NULL
+    ELSE round(
+      100.0 * psaio.idx_blks_hit / (psaio.idx_blks_hit + psaio.idx_blks_read)
+      , 2
+    )
+  END AS cache_hit_ratio
+  , pg_get_indexdef(psai.indexrelid) AS indexdef
+FROM pg_stat_user_indexes AS psai
+INNER JOIN pg_index AS x ON psai.indexrelid = x.indexrelid
+INNER JOIN pg_class AS tbl ON x.indrelid = tbl.oid
+INNER JOIN pg_namespace AS n ON tbl.relnamespace = n.oid
+LEFT JOIN pg_class AS c ON psai.relid = c.oid
+LEFT JOIN pg_stat_user_tables AS ut ON tbl.oid = ut.relid
+LEFT JOIN pg_statio_user_indexes AS psaio ON psai.indexrelid = psaio.indexrelid
+WHERE
+  n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY
+  pg_relation_size(psai.indexrelid) DESC
+`
+
+type IndexUsageStatsRow struct {
+	TableName      pgtype.Text
+	IndexName      pgtype.Text
+	NumRows        pgtype.Int8
+	IsPrimary      bool
+	IsUnique       bool
+	IndexSizeBytes pgtype.Int8
+	IdxScan        pgtype.Int8
+	IdxTupRead     pgtype.Int8
+	IdxTupFetch    pgtype.Int8
+	TableWrites    pgtype.Int8
 	IdxBlksHit     pgtype.Int8
 	IdxBlksRead    pgtype.Int8
 	CacheHitRatio  pgtype.Numeric
 	Indexdef       pgtype.Text
 }
 
-// Identifies indexes with usage statistics for health analysis.
-// Excludes: system schemas.
-// Returns data for subchecks: unused-indexes, low-usage-indexes, index-cache-ratio.
-func (q *Queries) IndexUsageStats(ctx context.Context) ([]IndexUsageStatsRow, error) {
-	rows, err := q.db.Query(ctx, indexUsageStats)
+// Identifies indexes with usage statistics for health analysis.
+// Excludes: system schemas.
+// Returns data for subchecks: unused-indexes, low-usage-indexes, index-cache-ratio.
+func (q *Queries) IndexUsageStats(ctx context.Context) ([]IndexUsageStatsRow, error) {
+	rows, err := q.db.Query(ctx, indexUsageStats)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []IndexUsageStatsRow
+	for rows.Next() {
+		var i IndexUsageStatsRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.IndexName,
+			&i.NumRows,
+			&i.IsPrimary,
+			&i.IsUnique,
+			&i.IndexSizeBytes,
+			&i.IdxScan,
+			&i.IdxTupRead,
+			&i.IdxTupFetch,
+			&i.TableWrites,
+			&i.IdxBlksHit,
+			&i.IdxBlksRead,
+			&i.CacheHitRatio,
+			&i.Indexdef,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const installedExtensions = `-- name: InstalledExtensions :many
+SELECT
+  e.extname::text AS extension_name
+  , n.nspname::text AS schema_name
+  , e.extversion::text AS installed_version
+  , COALESCE(a.default_version, '')::text AS default_version
+  , e.extrelocatable AS relocatable
+FROM pg_catalog.pg_extension AS e
+INNER JOIN pg_catalog.pg_namespace AS n ON e.extnamespace = n.oid
+LEFT JOIN pg_catalog.pg_available_extensions AS a ON e.extname = a.name
+ORDER BY e.extname
+`
+
+type InstalledExtensionsRow struct {
+	ExtensionName    string
+	SchemaName       string
+	InstalledVersion string
+	DefaultVersion   string
+	Relocatable      bool
+}
+
+// Extensions installed in the current database, with the version the
+// server's control file installs by default. default_version is empty when
+// the extension's files are no longer installed on the server.
+func (q *Queries) InstalledExtensions(ctx context.Context) ([]InstalledExtensionsRow, error) {
+	rows, err := q.db.Query(ctx, installedExtensions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []InstalledExtensionsRow
+	for rows.Next() {
+		var i InstalledExtensionsRow
+		if err := rows.Scan(
+			&i.ExtensionName,
+			&i.SchemaName,
+			&i.InstalledVersion,
+			&i.DefaultVersion,
+			&i.Relocatable,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const invalidPrimaryKeyTypes = `-- name: InvalidPrimaryKeyTypes :many
+WITH pk_tables AS (
+  SELECT
+    n.nspname::text AS schema_name
+    , c.relname::text AS table_name
+    , a.attname::text AS column_name
+    , a.attnum AS column_num
+    , t.typname::text AS column_type
+    , c.oid AS table_oid
+    , COALESCE(s.n_live_tup, 0)::bigint AS estimated_rows
+    , CASE t.typname
+      WHEN 'int2' THEN 32767::bigint
+      WHEN 'int4' THEN 2147483647::bigint
+    END AS type_max_value
+  FROM pg_catalog.pg_constraint AS con
+  INNER JOIN pg_catalog.pg_class AS c ON con.conrelid = c.oid
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  INNER JOIN pg_catalog.pg_attribute AS a
+    ON
+      con.conrelid = a.attrelid
+      AND a.attnum = ANY(con.conkey)
+  INNER JOIN pg_catalog.pg_type AS t ON a.atttypid = t.oid
+  LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+  WHERE
+    con.contype = 'p'
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pgpartman', 'pgjobmon', 'cron')
+    AND t.typname IN ('int2', 'int4')
+    AND NOT EXISTS (
+      SELECT 1 FROM pg_inherits AS inh
+      WHERE inh.inhrelid = c.oid
+    )
+)
+
+, sequence_values AS (
+  SELECT
+    d.refobjid AS table_oid
+    , d.refobjsubid AS column_num
+    , seq.last_value::bigint AS sequence_current
+  FROM pg_depend AS d
+  INNER JOIN pg_class AS seq_class ON d.objid = seq_class.oid
+  INNER JOIN pg_namespace AS seq_ns ON seq_class.relnamespace = seq_ns.oid
+  INNER JOIN pg_sequences AS seq
+    ON
+      seq_ns.nspname = seq.schemaname
+      AND seq_class.relname = seq.sequencename
+  WHERE
+    d.classid = 'pg_class'::regclass
+    AND d.refclassid = 'pg_class'::regclass
+    AND d.deptype IN ('a', 'i')
+    AND seq_class.relkind = 'S'
+)
+
+, column_values AS (
+  SELECT
+    p.table_oid
+    , p.column_num
+    , (
+      SELECT max(v.value)
+      FROM unnest(st.histogram_bounds::text::bigint []) AS v (value)
+    ) AS column_max
+  FROM pk_tables AS p
+  INNER JOIN pg_stats AS st
+    ON
+      p.schema_name = st.schemaname
+      AND p.table_name = st.tablename
+      AND p.column_name = st.attname
+  WHERE st.histogram_bounds IS NOT NULL
+)
+
+, pk_with_usage AS (
+  SELECT
+    (p.schema_name || '.' || p.table_name)::text AS table_name
+    , p.column_name
+    , p.column_type
+    , p.estimated_rows
+    , sv.sequence_current
+    , cv.column_max
+    , p.type_max_value
+    , CASE
+      WHEN COALESCE(sv.sequence_current, cv.column_max) IS NOT NULL AND p.type_max_value > 0
+        THEN GREATEST(sv.sequence_current, cv.column_max)::numeric / p.type_max_value::numeric
+      WHEN p.estimated_rows > 0 AND p.type_max_value > 0
+        THEN p.estimated_rows::numeric / p.type_max_value::numeric
+      ELSE
+        0::numeric
+    END AS usage_pct
+  FROM pk_tables AS p
+  LEFT JOIN sequence_values AS sv
+    ON
+      p.table_oid = sv.table_oid
+      AND p.column_num = sv.column_num
+  LEFT JOIN column_values AS cv
+    ON
+      p.table_oid = cv.table_oid
+      AND p.column_num = cv.column_num
+)
+
+SELECT
+  table_name
+  , column_name
+  , column_type
+  , estimated_rows
+  , sequence_current
+  , column_max
+  , type_max_value
+  , usage_pct
+FROM pk_with_usage
+ORDER BY
+  usage_pct DESC NULLS LAST
+  , estimated_rows DESC NULLS LAST
+`
+
+type InvalidPrimaryKeyTypesRow struct {
+	TableName       pgtype.Text
+	ColumnName      pgtype.Text
+	ColumnType      pgtype.Text
+	EstimatedRows   pgtype.Int8
+	SequenceCurrent pgtype.Int8
+	ColumnMax       pgtype.Int8
+	TypeMaxValue    pgtype.Int8
+	UsagePct        pgtype.Numeric
+}
+
+// Identifies tables with integer primary keys (int2/int4) that should use bigint.
+// Usage is the highest value the column is known to hold, from its owning
+// sequence (serial or identity) or the column statistics, against the type's
+// maximum: the column type is the ceiling, whatever the sequence allows.
+// Serial columns own their sequence (deptype 'a'), identity columns
+// internally (deptype 'i')
+// Highest value ANALYZE sampled, for keys set by the application or by a
+// sequence that was reset
+func (q *Queries) InvalidPrimaryKeyTypes(ctx context.Context) ([]InvalidPrimaryKeyTypesRow, error) {
+	rows, err := q.db.Query(ctx, invalidPrimaryKeyTypes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []InvalidPrimaryKeyTypesRow
+	for rows.Next() {
+		var i InvalidPrimaryKeyTypesRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.ColumnName,
+			&i.ColumnType,
+			&i.EstimatedRows,
+			&i.SequenceCurrent,
+			&i.ColumnMax,
+			&i.TypeMaxValue,
+			&i.UsagePct,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const largeTables = `-- name: LargeTables :many
+WITH inheritance_info AS (
+  SELECT DISTINCT ON (i.inhrelid)
+    i.inhrelid AS child_oid
+    , (pn.nspname || '.' || pc.relname)::text AS parent_table
+  FROM pg_inherits AS i
+  INNER JOIN pg_class AS pc ON i.inhparent = pc.oid
+  INNER JOIN pg_namespace AS pn ON pc.relnamespace = pn.oid
+  ORDER BY i.inhrelid, i.inhparent
+)
+
+SELECT
+  (n.nspname || '.' || c.relname)::text AS table_name
+  , ii.parent_table
+  , pg_catalog.pg_table_size(c.oid) AS table_size_bytes
+  , COALESCE(s.n_live_tup, 0) AS estimated_rows
+  , (c.relkind = 'p') AS is_partitioned
+  , (ii.parent_table IS NOT NULL) AS is_partition
+  , (c.relname ~ '(outbox|inbox|_jobs?$|^oban_|logs|events?$)') AS is_transient
+  , COALESCE(s.n_tup_ins, 0) AS n_tup_ins
+  , COALESCE(s.n_tup_upd, 0) AS n_tup_upd
+  , COALESCE(s.n_tup_del, 0) AS n_tup_del
+FROM pg_catalog.pg_class AS c
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+LEFT JOIN inheritance_info AS ii ON c.oid = ii.child_oid
+WHERE
+  c.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
+  AND COALESCE(s.n_live_tup, 0) >= 10000000
+`
+
+type LargeTablesRow struct {
+	TableName      pgtype.Text
+	ParentTable    pgtype.Text
+	TableSizeBytes pgtype.Int8
+	EstimatedRows  pgtype.Int8
+	IsPartitioned  pgtype.Bool
+	IsPartition    pgtype.Bool
+	IsTransient    pgtype.Bool
+	NTupIns        pgtype.Int8
+	NTupUpd        pgtype.Int8
+	NTupDel        pgtype.Int8
+}
+
+// Identifies all large tables (>= 10M rows) with partitioning and transient status.
+// Returns both regular and partitioned tables for unified analysis.
+// Includes activity metrics (inserts/updates/deletes) for activity-aware thresholds.
+func (q *Queries) LargeTables(ctx context.Context) ([]LargeTablesRow, error) {
+	rows, err := q.db.Query(ctx, largeTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LargeTablesRow
+	for rows.Next() {
+		var i LargeTablesRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.ParentTable,
+			&i.TableSizeBytes,
+			&i.EstimatedRows,
+			&i.IsPartitioned,
+			&i.IsPartition,
+			&i.IsTransient,
+			&i.NTupIns,
+			&i.NTupUpd,
+			&i.NTupDel,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const leftoverTables = `-- name: LeftoverTables :many
+WITH named AS (
+  SELECT
+    c.oid
+    , n.nspname::text AS schema_name
+    , c.relname::text AS table_name
+    , CASE
+      WHEN c.relname ~* '^(tmp|temp)_|_(tmp|temp)\d*$' THEN 'temporary'
+      WHEN c.relname ~* '_(backup|bak|bkp)(\d*$|_)' THEN 'backup'
+      WHEN c.relname ~* '_old(\d*$|_)' THEN 'old'
+      WHEN c.relname ~* '_copy(\d*$|_)' THEN 'copy'
+      WHEN c.relname ~* '_(19|20)\d{2}_?(0[1-9]|1[0-2])(_?(0[1-9]|[12]\d|3[01]))?$' THEN 'dated'
+    END AS pattern
+    , greatest(c.reltuples, 0)::bigint AS estimated_rows
+  FROM pg_catalog.pg_class AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  WHERE
+    c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+SELECT
+  t.schema_name
+  , t.table_name
+  , t.pattern::text AS pattern
+  , pg_total_relation_size(t.oid) AS total_size_bytes
+  , t.estimated_rows
+  , (coalesce(s.seq_scan, 0) + coalesce(s.idx_scan, 0))::bigint AS scans
+  , (coalesce(s.n_tup_ins, 0) + coalesce(s.n_tup_upd, 0) + coalesce(s.n_tup_del, 0))::bigint AS writes
+  , greatest(s.last_vacuum, s.last_autovacuum, s.last_analyze, s.last_autoanalyze) AS last_activity
+FROM named AS t
+LEFT JOIN pg_catalog.pg_stat_user_tables AS s ON t.oid = s.relid
+WHERE t.pattern IS NOT NULL
+ORDER BY total_size_bytes DESC, t.schema_name, t.table_name
+`
+
+type LeftoverTablesRow struct {
+	SchemaName     string
+	TableName      string
+	Pattern        string
+	TotalSizeBytes int64
+	EstimatedRows  int64
+	Scans          int64
+	Writes         int64
+	LastActivity   pgtype.Timestamptz
+}
+
+// User tables whose name looks like a temporary table, a backup or a dated
+// copy, with their size and activity since the statistics were reset.
+// last_activity is the latest vacuum or analyze, which follow writes.
+// Partitions, often named after dates, and extension tables are left out.
+func (q *Queries) LeftoverTables(ctx context.Context) ([]LeftoverTablesRow, error) {
+	rows, err := q.db.Query(ctx, leftoverTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LeftoverTablesRow
+	for rows.Next() {
+		var i LeftoverTablesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.Pattern,
+			&i.TotalSizeBytes,
+			&i.EstimatedRows,
+			&i.Scans,
+			&i.Writes,
+			&i.LastActivity,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const loginOwnedObjects = `-- name: LoginOwnedObjects :many
+WITH owned AS (
+  SELECT
+    c.relowner AS owner_oid
+    , c.relnamespace AS schema_oid
+    , 'table' AS object_kind
+    , c.relname::text AS object_name
+  FROM pg_catalog.pg_class AS c
+  WHERE
+    c.relkind IN ('r', 'p', 'v', 'm')
+    AND NOT c.relispartition
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+  UNION ALL
+  SELECT
+    p.proowner AS owner_oid
+    , p.pronamespace AS schema_oid
+    , 'function' AS object_kind
+    , p.proname::text AS object_name
+  FROM pg_catalog.pg_proc AS p
+  WHERE
+    p.prokind IN ('f', 'p')
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_proc'::regclass
+        AND p.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
+SELECT
+  r.rolname::text AS owner_name
+  , r.oid = 10 AS is_bootstrap
+  , r.rolsuper AS is_superuser
+  , r.rolcanlogin AS can_login
+  , n.nspname::text AS schema_name
+  , count(*) FILTER (WHERE o.object_kind = 'table')::bigint AS tables
+  , count(*) FILTER (WHERE o.object_kind = 'function')::bigint AS functions
+  , min(o.object_name)::text AS example_object
+FROM owned AS o
+INNER JOIN pg_catalog.pg_roles AS r ON o.owner_oid = r.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON o.schema_oid = n.oid
+WHERE
+  (r.rolcanlogin OR r.oid = 10)
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND n.nspname NOT LIKE 'pg\_%'
+GROUP BY r.rolname, r.oid, r.rolsuper, r.rolcanlogin, n.nspname
+ORDER BY r.rolname, n.nspname
+`
+
+type LoginOwnedObjectsRow struct {
+	OwnerName     string
+	IsBootstrap   bool
+	IsSuperuser   bool
+	CanLogin      bool
+	SchemaName    string
+	Tables        int64
+	Functions     int64
+	ExampleObject string
+}
+
+// Tables, views and functions owned by a role that can log in, or by the
+// bootstrap superuser (oid 10), counted per owner and schema. Partitions,
+// which follow their parent, and objects owned by extensions are left out.
+func (q *Queries) LoginOwnedObjects(ctx context.Context) ([]LoginOwnedObjectsRow, error) {
+	rows, err := q.db.Query(ctx, loginOwnedObjects)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LoginOwnedObjectsRow
+	for rows.Next() {
+		var i LoginOwnedObjectsRow
+		if err := rows.Scan(
+			&i.OwnerName,
+			&i.IsBootstrap,
+			&i.IsSuperuser,
+			&i.CanLogin,
+			&i.SchemaName,
+			&i.Tables,
+			&i.Functions,
+			&i.ExampleObject,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const loginRoleExpiry = `-- name: LoginRoleExpiry :many
+SELECT
+  r.rolname::text AS role_name
+  , r.rolvaliduntil AS valid_until
+  , r.rolvaliduntil IS NOT NULL AND r.rolvaliduntil <> 'infinity' AS has_expiry
+  , coalesce(r.rolvaliduntil < now(), false) AS expired
+FROM pg_catalog.pg_roles AS r
+WHERE r.rolcanlogin
+  AND r.rolname NOT LIKE 'pg\_%'
+ORDER BY r.rolname
+`
+
+type LoginRoleExpiryRow struct {
+	RoleName   string
+	ValidUntil pgtype.Timestamptz
+	HasExpiry  bool
+	Expired    bool
+}
+
+// Password expiry of login roles, from pg_roles, which any role can read.
+// rolvaliduntil of 'infinity' never expires, like NULL.
+func (q *Queries) LoginRoleExpiry(ctx context.Context) ([]LoginRoleExpiryRow, error) {
+	rows, err := q.db.Query(ctx, loginRoleExpiry)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LoginRoleExpiryRow
+	for rows.Next() {
+		var i LoginRoleExpiryRow
+		if err := rows.Scan(
+			&i.RoleName,
+			&i.ValidUntil,
+			&i.HasExpiry,
+			&i.Expired,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const longIdleConnections = `-- name: LongIdleConnections :many
+SELECT
+  pid
+  , usename::text AS username
+  , datname::text AS database_name
+  , application_name::text AS application_name
+  , client_addr::text AS client_address
+  , state::text AS state
+  , extract(EPOCH FROM (now() - state_change))::bigint AS idle_duration_seconds
+  , extract(EPOCH FROM (now() - backend_start))::bigint AS connection_age_seconds
+FROM pg_stat_activity
+WHERE
+  state = 'idle'
+  AND pid != pg_backend_pid()
+  AND (now() - state_change) > interval '30 minutes'
+ORDER BY state_change ASC
+`
+
+type LongIdleConnectionsRow struct {
+	Pid                  pgtype.Int4
+	Username             pgtype.Text
+	DatabaseName         pgtype.Text
+	ApplicationName      pgtype.Text
+	ClientAddress        pgtype.Text
+	State                pgtype.Text
+	IdleDurationSeconds  pgtype.Int8
+	ConnectionAgeSeconds pgtype.Int8
+}
+
+// Identifies connections that have been idle for too long (potential pool leak).
+func (q *Queries) LongIdleConnections(ctx context.Context) ([]LongIdleConnectionsRow, error) {
+	rows, err := q.db.Query(ctx, longIdleConnections)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LongIdleConnectionsRow
+	for rows.Next() {
+		var i LongIdleConnectionsRow
+		if err := rows.Scan(
+			&i.Pid,
+			&i.Username,
+			&i.DatabaseName,
+			&i.ApplicationName,
+			&i.ClientAddress,
+			&i.State,
+			&i.IdleDurationSeconds,
+			&i.ConnectionAgeSeconds,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const longRunningQueries = `-- name: LongRunningQueries :many
+SELECT
+  pid
+  , COALESCE(usename::text, '') AS username
+  , COALESCE(application_name::text, '') AS application_name
+  , COALESCE(backend_type::text, '') AS backend_type
+  , COALESCE(extract(EPOCH FROM (now() - query_start))::bigint, 0) AS duration_seconds
+  , COALESCE(wait_event_type::text, '') AS wait_event_type
+  , COALESCE(wait_event::text, '') AS wait_event
+  , COALESCE(left(query, 200), '') AS query_preview
+FROM pg_stat_activity
+WHERE
+  state = 'active'
+  AND query_start IS NOT NULL
+  AND pid != pg_backend_pid()
+ORDER BY query_start ASC
+`
+
+type LongRunningQueriesRow struct {
+	Pid             pgtype.Int4
+	Username        string
+	ApplicationName string
+	BackendType     string
+	DurationSeconds int64
+	WaitEventType   string
+	WaitEvent       string
+	QueryPreview    string
+}
+
+// Active statements with how long they have been running, longest first.
+// Thresholds and the maintenance/replication filter are applied in Go.
+func (q *Queries) LongRunningQueries(ctx context.Context) ([]LongRunningQueriesRow, error) {
+	rows, err := q.db.Query(ctx, longRunningQueries)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []LongRunningQueriesRow
+	for rows.Next() {
+		var i LongRunningQueriesRow
+		if err := rows.Scan(
+			&i.Pid,
+			&i.Username,
+			&i.ApplicationName,
+			&i.BackendType,
+			&i.DurationSeconds,
+			&i.WaitEventType,
+			&i.WaitEvent,
+			&i.QueryPreview,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const materializedViews = `-- name: MaterializedViews :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS view_name
+  , c.relispopulated AS is_populated
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(c.oid) AS total_size_bytes
+  , EXISTS (
+    SELECT 1
+    FROM pg_index AS i
+    WHERE
+      i.indrelid = c.oid
+      AND i.indisunique
+      AND i.indisvalid
+      AND i.indpred IS NULL
+      AND i.indexprs IS NULL
+  ) AS has_unique_index
+  , CASE
+    WHEN pg_catalog.has_function_privilege('pg_catalog.pg_stat_file(text, boolean)', 'EXECUTE')
+      THEN EXTRACT(EPOCH FROM now() - (pg_catalog.pg_stat_file(pg_catalog.pg_relation_filepath(c.oid), true)).modification)::bigint
+  END AS refresh_age_seconds
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE
+  c.relkind = 'm'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY total_size_bytes DESC, n.nspname, c.relname
+`
+
+type MaterializedViewsRow struct {
+	SchemaName        string
+	ViewName          string
+	IsPopulated       bool
+	EstimatedRows     int64
+	TotalSizeBytes    int64
+	HasUniqueIndex    bool
+	RefreshAgeSeconds pgtype.Int8
+}
+
+// Materialized views with their size, whether they hold data, and whether a
+// plain unique index allows REFRESH MATERIALIZED VIEW CONCURRENTLY.
+// PostgreSQL does not record when a view was refreshed: the age of its data
+// file is the closest thing, and later writes (hint bits, vacuum) can only
+// make it look fresher. Reading it needs superuser or EXECUTE on
+// pg_stat_file, so refresh_age_seconds is NULL without that privilege.
+func (q *Queries) MaterializedViews(ctx context.Context) ([]MaterializedViewsRow, error) {
+	rows, err := q.db.Query(ctx, materializedViews)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []MaterializedViewsRow
+	for rows.Next() {
+		var i MaterializedViewsRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.ViewName,
+			&i.IsPopulated,
+			&i.EstimatedRows,
+			&i.TotalSizeBytes,
+			&i.HasUniqueIndex,
+			&i.RefreshAgeSeconds,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const md5PasswordRoles = `-- name: Md5PasswordRoles :many
+SELECT
+  a.rolname::text AS role_name
+  , a.rolcanlogin AS can_login
+  , a.rolvaliduntil AS valid_until
+FROM pg_catalog.pg_authid AS a
+WHERE a.rolpassword LIKE 'md5%'
+ORDER BY a.rolcanlogin DESC, a.rolname
+`
+
+type Md5PasswordRolesRow struct {
+	RoleName   string
+	CanLogin   bool
+	ValidUntil pgtype.Timestamptz
+}
+
+// Roles whose stored password verifier is still an md5 hash. Requires SELECT
+// on pg_authid; only run when PasswordEncryption reports it is readable.
+func (q *Queries) Md5PasswordRoles(ctx context.Context) ([]Md5PasswordRolesRow, error) {
+	rows, err := q.db.Query(ctx, md5PasswordRoles)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []Md5PasswordRolesRow
+	for rows.Next() {
+		var i Md5PasswordRolesRow
+		if err := rows.Scan(&i.RoleName, &i.CanLogin, &i.ValidUntil); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const notValidConstraints = `-- name: NotValidConstraints :many
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , con.conname::text AS constraint_name
+  , con.contype::text AS constraint_type
+  , pg_get_constraintdef(con.oid)::text AS definition
+  , pg_total_relation_size(t.oid) AS table_size_bytes
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE
+  NOT con.convalidated
+  AND con.contype IN ('f', 'c', 'n')
+  AND con.conparentid = 0
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY n.nspname, t.relname, con.conname
+`
+
+type NotValidConstraintsRow struct {
+	SchemaName     string
+	TableName      string
+	ConstraintName string
+	ConstraintType string
+	Definition     string
+	TableSizeBytes int64
+}
+
+// Foreign key, check and not-null constraints added NOT VALID and never
+// validated: they apply to new and updated rows only, so existing rows may
+// violate them.
+// Excludes: system schemas and the copies of a partitioned table's constraint
+// on its partitions (conparentid), reported once on the parent.
+func (q *Queries) NotValidConstraints(ctx context.Context) ([]NotValidConstraintsRow, error) {
+	rows, err := q.db.Query(ctx, notValidConstraints)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []NotValidConstraintsRow
+	for rows.Next() {
+		var i NotValidConstraintsRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.ConstraintName,
+			&i.ConstraintType,
+			&i.Definition,
+			&i.TableSizeBytes,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const observabilityExtensions = `-- name: ObservabilityExtensions :many
+SELECT
+  a.name::text AS extension_name
+  , COALESCE(a.default_version, '')::text AS default_version
+  , COALESCE(a.installed_version, '')::text AS installed_version
+FROM pg_catalog.pg_available_extensions AS a
+WHERE a.name IN ('pg_stat_statements', 'pgstattuple', 'pg_buffercache')
+ORDER BY a.name
+`
+
+type ObservabilityExtensionsRow struct {
+	ExtensionName    string
+	DefaultVersion   string
+	InstalledVersion string
+}
+
+// The recommended observability extensions the server has packages for,
+// with the installed version in the current database (empty when not
+// installed). Extensions missing from the result are not available at all.
+func (q *Queries) ObservabilityExtensions(ctx context.Context) ([]ObservabilityExtensionsRow, error) {
+	rows, err := q.db.Query(ctx, observabilityExtensions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []ObservabilityExtensionsRow
+	for rows.Next() {
+		var i ObservabilityExtensionsRow
+		if err := rows.Scan(&i.ExtensionName, &i.DefaultVersion, &i.InstalledVersion); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const pGVersion = `-- name: PGVersion :one
+SELECT
+  current_setting('server_version_num')::integer / 10000 AS major
+  , current_setting('server_version_num')::integer % 100 AS minor
+`
+
+type PGVersionRow struct {
+	Major int32
+	Minor int32
+}
+
+func (q *Queries) PGVersion(ctx context.Context) (PGVersionRow, error) {
+	row := q.db.QueryRow(ctx, pGVersion)
+	var i PGVersionRow
+	err := row.Scan(&i.Major, &i.Minor)
+	return i, err
+}
+
+const partialIndexCandidates = `-- name: PartialIndexCandidates :many
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , c.relname::text AS index_name
+  , pg_get_indexdef(c.oid) AS index_def
+  , pg_relation_size(c.oid) AS index_size_bytes
+  , a.attname::text AS column_name
+  , COALESCE(st.null_frac, 0)::float8 AS null_frac
+  , (st.most_common_vals::text::text[])[1] AS top_value
+  , COALESCE(st.most_common_freqs[1], 0)::float8 AS top_value_frac
+  , sd.attname::text AS soft_delete_column
+  , sd.null_frac::float8 AS soft_delete_null_frac
+FROM pg_class AS c
+INNER JOIN pg_index AS i ON c.oid = i.indexrelid
+INNER JOIN pg_class AS t ON i.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_am AS am ON c.relam = am.oid
+INNER JOIN pg_attribute AS a ON t.oid = a.attrelid AND i.indkey[0] = a.attnum
+LEFT JOIN pg_stats AS st
+  ON n.nspname = st.schemaname AND t.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN LATERAL (
+  SELECT
+    s.attname
+    , s.null_frac
+  FROM pg_stats AS s
+  WHERE
+    s.schemaname = n.nspname
+    AND s.tablename = t.relname
+    AND NOT s.inherited
+    AND s.attname IN ('deleted_at', 'archived_at', 'discarded_at')
+    AND s.attname <> ALL (
+      SELECT ia.attname
+      FROM pg_attribute AS ia
+      WHERE ia.attrelid = t.oid AND ia.attnum = ANY (i.indkey::int2[])
+    )
+  ORDER BY s.attname
+  LIMIT 1
+) AS sd ON true
+WHERE
+  am.amname = 'btree'
+  AND c.relkind = 'i'
+  AND NOT i.indisunique
+  AND i.indpred IS NULL
+  AND i.indisvalid
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY index_size_bytes DESC, n.nspname, c.relname
+`
+
+type PartialIndexCandidatesRow struct {
+	SchemaName         string
+	TableName          string
+	IndexName          string
+	IndexDef           string
+	IndexSizeBytes     int64
+	ColumnName         string
+	NullFrac           float64
+	TopValue           pgtype.Text
+	TopValueFrac       float64
+	SoftDeleteColumn   pgtype.Text
+	SoftDeleteNullFrac pgtype.Float8
+}
+
+// Non-unique, non-partial B-tree indexes with the statistics of their first
+// column: the share of NULLs, and the most common value with its share. When
+// the table has a soft-delete column (deleted_at, archived_at, discarded_at)
+// that the index does not cover, its name and share of NULLs (rows not
+// deleted) are included too. Indexes on expressions are left out.
+func (q *Queries) PartialIndexCandidates(ctx context.Context) ([]PartialIndexCandidatesRow, error) {
+	rows, err := q.db.Query(ctx, partialIndexCandidates)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []PartialIndexCandidatesRow
+	for rows.Next() {
+		var i PartialIndexCandidatesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.IndexName,
+			&i.IndexDef,
+			&i.IndexSizeBytes,
+			&i.ColumnName,
+			&i.NullFrac,
+			&i.TopValue,
+			&i.TopValueFrac,
+			&i.SoftDeleteColumn,
+			&i.SoftDeleteNullFrac,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const partitionCandidates = `-- name: PartitionCandidates :many
+SELECT DISTINCT ON (c.oid)
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , pg_catalog.pg_table_size(c.oid) AS table_size_bytes
+  , COALESCE(s.n_live_tup, 0)::bigint AS estimated_rows
+  , COALESCE(s.n_tup_ins, 0)::bigint AS n_tup_ins
+  , COALESCE(s.n_tup_del, 0)::bigint AS n_tup_del
+  , a.attname::text AS key_column
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS key_type
+  , st.correlation::float8 AS correlation
+FROM pg_catalog.pg_class AS c
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_attribute AS a
+  ON c.oid = a.attrelid AND a.attnum > 0 AND NOT a.attisdropped
+INNER JOIN pg_catalog.pg_stats AS st
+  ON n.nspname = st.schemaname AND c.relname = st.tablename AND a.attname = st.attname AND NOT st.inherited
+LEFT JOIN pg_catalog.pg_attrdef AS d ON c.oid = d.adrelid AND a.attnum = d.adnum
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind = 'r'
+  AND NOT c.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
+  AND st.correlation >= 0.9
+  AND (
+    a.atttypid IN ('timestamptz'::regtype, 'timestamp'::regtype, 'date'::regtype)
+    OR a.attidentity <> ''
+    OR pg_catalog.pg_get_expr(d.adbin, d.adrelid) LIKE 'nextval(%'
+  )
+ORDER BY
+  c.oid
+  , (a.atttypid IN ('timestamptz'::regtype, 'timestamp'::regtype, 'date'::regtype)) DESC
+  , st.correlation DESC
+  , a.attnum
+`
+
+type PartitionCandidatesRow struct {
+	SchemaName     string
+	TableName      string
+	TableSizeBytes int64
+	EstimatedRows  int64
+	NTupIns        int64
+	NTupDel        int64
+	KeyColumn      string
+	KeyType        string
+	Correlation    float64
+}
+
+// Lists regular tables that are neither partitioned nor partitions, with the
+// column most likely to serve as a range partition key: a timestamp or date
+// column, or a sequence-backed integer, whose values follow the physical row
+// order (correlation near 1), as they do when rows are only ever appended.
+// Timestamp columns are preferred over sequences.
+func (q *Queries) PartitionCandidates(ctx context.Context) ([]PartitionCandidatesRow, error) {
+	rows, err := q.db.Query(ctx, partitionCandidates)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []PartitionCandidatesRow
+	for rows.Next() {
+		var i PartitionCandidatesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.TableSizeBytes,
+			&i.EstimatedRows,
+			&i.NTupIns,
+			&i.NTupDel,
+			&i.KeyColumn,
+			&i.KeyType,
+			&i.Correlation,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const partitionedTablesWithKeys = `-- name: PartitionedTablesWithKeys :many
+WITH partition_stats AS (
+  -- Single aggregation of all partition metrics from child tables
+  SELECT
+    i.inhparent
+    , COUNT(*)::bigint AS partition_count
+    , COALESCE(SUM(pg_catalog.pg_total_relation_size(i.inhrelid)), 0)::bigint AS total_size_bytes
+    , COALESCE(SUM(s.n_live_tup), 0)::bigint AS estimated_rows
+    , COALESCE(SUM(s.seq_scan), 0)::bigint AS total_seq_scans
+    , COALESCE(SUM(s.idx_scan), 0)::bigint AS total_idx_scans
+  FROM pg_inherits AS i
+  LEFT JOIN pg_stat_user_tables AS s ON i.inhrelid = s.relid
+  GROUP BY i.inhparent
+)
+
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , pt.partstrat::text AS partition_strategy
+  -- Get partition key column names as comma-separated string
+  , (
+    SELECT STRING_AGG(a.attname, ',' ORDER BY k.n)
+    FROM UNNEST(pt.partattrs) WITH ORDINALITY AS k (attnum, n)
+    INNER JOIN pg_attribute AS a ON a.attrelid = c.oid AND k.attnum = a.attnum
+    WHERE k.attnum > 0
+  )::text AS partition_key_columns
+  -- Check if partition key includes expressions (attnum = 0 means expression)
+  , (SELECT BOOL_OR(k.attnum = 0) FROM UNNEST(pt.partattrs) AS k (attnum)) AS has_expression_key
+  -- All partition metrics from pre-aggregated CTE
+  , COALESCE(ps.partition_count, 0) AS partition_count
+  , COALESCE(ps.total_size_bytes, 0) AS total_size_bytes
+  , COALESCE(ps.estimated_rows, 0) AS estimated_rows
+  , COALESCE(ps.total_seq_scans, 0) AS total_seq_scans
+  , COALESCE(ps.total_idx_scans, 0) AS total_idx_scans
+FROM pg_catalog.pg_class AS c
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_partitioned_table AS pt ON c.oid = pt.partrelid
+LEFT JOIN partition_stats AS ps ON c.oid = ps.inhparent
+WHERE
+  c.relkind = 'p'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
+ORDER BY ps.total_size_bytes DESC NULLS LAST
+`
+
+type PartitionedTablesWithKeysRow struct {
+	SchemaName          pgtype.Text
+	TableName           pgtype.Text
+	PartitionStrategy   pgtype.Text
+	PartitionKeyColumns pgtype.Text
+	HasExpressionKey    pgtype.Bool
+	PartitionCount      pgtype.Int8
+	TotalSizeBytes      pgtype.Int8
+	EstimatedRows       pgtype.Int8
+	TotalSeqScans       pgtype.Int8
+	TotalIdxScans       pgtype.Int8
+}
+
+// Gets partitioned tables and their partition key column(s).
+// Pre-aggregates all partition statistics in a single CTE for better performance
+// compared to multiple correlated subqueries.
+func (q *Queries) PartitionedTablesWithKeys(ctx context.Context) ([]PartitionedTablesWithKeysRow, error) {
+	rows, err := q.db.Query(ctx, partitionedTablesWithKeys)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []IndexUsageStatsRow
+	var items []PartitionedTablesWithKeysRow
 	for rows.Next() {
-		var i IndexUsageStatsRow
+		var i PartitionedTablesWithKeysRow
 		if err := rows.Scan(
+			&i.SchemaName,
 			&i.TableName,
-			&i.IndexName,
-			&i.NumRows,
-			&i.IsPrimary,
-			&i.IsUnique,
-			&i.IndexSizeBytes,
-			&i.IdxScan,
-			&i.IdxTupRead,
-			&i.IdxTupFetch,
-			&i.TableWrites,
-			&i.IdxBlksHit,
-			&i.IdxBlksRead,
-			&i.CacheHitRatio,
-			&i.Indexdef,
+			&i.PartitionStrategy,
+			&i.PartitionKeyColumns,
+			&i.HasExpressionKey,
+			&i.PartitionCount,
+			&i.TotalSizeBytes,
+			&i.EstimatedRows,
+			&i.TotalSeqScans,
+			&i.TotalIdxScans,
 		); err != nil {
 			return nil, err
 		}
@@ -728,118 +3385,59 @@ func (q *Queries) IndexUsageStats(ctx context.Context) ([]IndexUsageStatsRow, er
 	return items, nil
 }
 
-const invalidPrimaryKeyTypes = `-- name: InvalidPrimaryKeyTypes :many
-WITH pk_tables AS (
-  SELECT
-    n.nspname::text AS schema_name
-    , c.relname::text AS table_name
-    , a.attname::text AS column_name
-    , a.attnum AS column_num
-    , t.typname::text AS column_type
-    , c.oid AS table_oid
-    , COALESCE(s.n_live_tup, 0)::bigint AS estimated_rows
-    , CASE t.typname
-      WHEN 'int2' THEN 32767::bigint
-      WHEN 'int4' THEN 2147483647::bigint
-    END AS type_max_value
-  FROM pg_catalog.pg_constraint AS con
-  INNER JOIN pg_catalog.pg_class AS c ON con.conrelid = c.oid
-  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
-  INNER JOIN pg_catalog.pg_attribute AS a
-    ON
-      con.conrelid = a.attrelid
-      AND a.attnum = ANY(con.conkey)
-  INNER JOIN pg_catalog.pg_type AS t ON a.atttypid = t.oid
-  LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
-  WHERE
-    con.contype = 'p'
-    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pgpartman', 'pgjobmon', 'cron')
-    AND t.typname IN ('int2', 'int4')
-    AND NOT EXISTS (
-      SELECT 1 FROM pg_inherits AS inh
-      WHERE inh.inhrelid = c.oid
-    )
-)
+const passwordEncryption = `-- name: PasswordEncryption :one
+SELECT
+  current_setting('password_encryption')::text AS password_encryption
+  , has_table_privilege('pg_catalog.pg_authid', 'SELECT') AS can_read_authid
+`
 
-, sequence_values AS (
-  SELECT
-    d.refobjid AS table_oid
-    , d.refobjsubid AS column_num
-    , seq.last_value::bigint AS sequence_current
-  FROM pg_depend AS d
-  INNER JOIN pg_class AS seq_class ON d.objid = seq_class.oid
-  INNER JOIN pg_sequences AS seq ON seq_class.relname = seq.sequencename
-  WHERE
-    d.deptype = 'a'
-    AND seq_class.relkind = 'S'
-)
+type PasswordEncryptionRow struct {
+	PasswordEncryption string
+	CanReadAuthid      bool
+}
 
-, pk_with_usage AS (
-  SELECT
-    (p.schema_name || '.' || p.table_name)::text AS table_name
-    , p.column_name
-    , p.column_type
-    , p.estimated_rows
-    , sv.sequence_current
-    , p.type_max_value
-    , CASE
-      WHEN sv.sequence_current IS NOT NULL AND p.type_max_value > 0
-        THEN sv.sequence_current::numeric / p.type_max_value::numeric
-      WHEN p.estimated_rows > 0 AND p.type_max_value > 0
-        THEN p.estimated_rows::numeric / p.type_max_value::numeric
-      ELSE
-        0::numeric
-    END AS usage_pct
-  FROM pk_tables AS p
-  LEFT JOIN sequence_values AS sv
-    ON
-      p.table_oid = sv.table_oid
-      AND p.column_num = sv.column_num
-)
+// Hashing method for newly set passwords, and whether stored password
+// verifiers can be inspected. pg_authid is readable only by superusers, which
+// most managed services (RDS, Cloud SQL, Azure) do not grant.
+func (q *Queries) PasswordEncryption(ctx context.Context) (PasswordEncryptionRow, error) {
+	row := q.db.QueryRow(ctx, passwordEncryption)
+	var i PasswordEncryptionRow
+	err := row.Scan(&i.PasswordEncryption, &i.CanReadAuthid)
+	return i, err
+}
 
+const passwordlessLoginRoles = `-- name: PasswordlessLoginRoles :many
 SELECT
-  table_name
-  , column_name
-  , column_type
-  , estimated_rows
-  , sequence_current
-  , type_max_value
-  , usage_pct
-FROM pk_with_usage
-ORDER BY
-  usage_pct DESC NULLS LAST
-  , estimated_rows DESC NULLS LAST
+  a.rolname::text AS role_name
+  , a.oid = 10 AS is_bootstrap
+  , a.rolsuper AS superuser
+FROM pg_catalog.pg_authid AS a
+WHERE a.rolcanlogin
+  AND a.rolpassword IS NULL
+  AND a.rolname NOT LIKE 'pg\_%'
+ORDER BY a.rolname
 `
 
-type InvalidPrimaryKeyTypesRow struct {
-	TableName       pgtype.Text
-	ColumnName      pgtype.Text
-	ColumnType      pgtype.Text
-	EstimatedRows   pgtype.Int8
-	SequenceCurrent pgtype.Int8
-	TypeMaxValue    pgtype.Int8
-	UsagePct        pgtype.Numeric
+type PasswordlessLoginRolesRow struct {
+	RoleName    string
+	IsBootstrap bool
+	Superuser   bool
 }
 
-// Identifies tables with integer primary keys (int2/int4) that should use bigint.
-func (q *Queries) InvalidPrimaryKeyTypes(ctx context.Context) ([]InvalidPrimaryKeyTypesRow, error) {
-	rows, err := q.db.Query(ctx, invalidPrimaryKeyTypes)
+// Login roles without a stored password. They can only authenticate through
+// trust, peer, ident, cert, GSSAPI or another method in pg_hba.conf that does
+// not check a password. Requires SELECT on pg_authid; only run when
+// CanReadAuthid reports it is readable.
+func (q *Queries) PasswordlessLoginRoles(ctx context.Context) ([]PasswordlessLoginRolesRow, error) {
+	rows, err := q.db.Query(ctx, passwordlessLoginRoles)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []InvalidPrimaryKeyTypesRow
+	var items []PasswordlessLoginRolesRow
 	for rows.Next() {
-		var i InvalidPrimaryKeyTypesRow
-		if err := rows.Scan(
-			&i.TableName,
-			&i.ColumnName,
-			&i.ColumnType,
-			&i.EstimatedRows,
-			&i.SequenceCurrent,
-			&i.TypeMaxValue,
-			&i.UsagePct,
-		); err != nil {
+		var i PasswordlessLoginRolesRow
+		if err := rows.Scan(&i.RoleName, &i.IsBootstrap, &i.Superuser); err != nil {
 			return nil, err
 		}
 		items = append(items, i)
@@ -850,74 +3448,82 @@ func (q *Queries) InvalidPrimaryKeyTypes(ctx context.Context) ([]InvalidPrimaryK
 	return items, nil
 }
 
-const largeTables = `-- name: LargeTables :many
-WITH inheritance_info AS (
-  SELECT DISTINCT ON (i.inhrelid)
-    i.inhrelid AS child_oid
-    , (pn.nspname || '.' || pc.relname)::text AS parent_table
-  FROM pg_inherits AS i
-  INNER JOIN pg_class AS pc ON i.inhparent = pc.oid
-  INNER JOIN pg_namespace AS pn ON pc.relnamespace = pn.oid
-  ORDER BY i.inhrelid, i.inhparent
-)
+const publicCreateSchemas = `-- name: PublicCreateSchemas :many
+SELECT n.nspname::text AS schema_name
+FROM pg_catalog.pg_namespace AS n
+CROSS JOIN LATERAL pg_catalog.aclexplode(coalesce(n.nspacl, pg_catalog.acldefault('n', n.nspowner))) AS acl
+WHERE
+  acl.grantee = 0
+  AND acl.privilege_type = 'CREATE'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_%'
+ORDER BY n.nspname
+`
+
+// Schemas in which PUBLIC, that is every role, may create objects. Before
+// PostgreSQL 15 the public schema grants CREATE to PUBLIC by default. A NULL
+// ACL means the built-in defaults, which never include PUBLIC CREATE.
+func (q *Queries) PublicCreateSchemas(ctx context.Context) ([]string, error) {
+	rows, err := q.db.Query(ctx, publicCreateSchemas)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []string
+	for rows.Next() {
+		var items string
+		if err := rows.Scan(&items); err != nil {
+			return nil, err
+		}
+		items = append(items, items)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
 
+const publicDefaultPrivileges = `-- name: PublicDefaultPrivileges :many
 SELECT
-  (n.nspname || '.' || c.relname)::text AS table_name
-  , ii.parent_table
-  , pg_catalog.pg_table_size(c.oid) AS table_size_bytes
-  , COALESCE(s.n_live_tup, 0) AS estimated_rows
-  , (c.relkind = 'p') AS is_partitioned
-  , (ii.parent_table IS NOT NULL) AS is_partition
-  , (c.relname ~ '(outbox|inbox|_jobs?$|^oban_|logs|events?$)') AS is_transient
-  , COALESCE(s.n_tup_ins, 0) AS n_tup_ins
-  , COALESCE(s.n_tup_upd, 0) AS n_tup_upd
-  , COALESCE(s.n_tup_del, 0) AS n_tup_del
-FROM pg_catalog.pg_class AS c
-INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
-LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
-LEFT JOIN inheritance_info AS ii ON c.oid = ii.child_oid
+  r.rolname::text AS role_name
+  , coalesce(n.nspname, '')::text AS schema_name
+  , d.defaclobjtype::text AS object_type
+  , string_agg(acl.privilege_type, ', ' ORDER BY acl.privilege_type) AS privileges
+FROM pg_catalog.pg_default_acl AS d
+INNER JOIN pg_catalog.pg_roles AS r ON d.defaclrole = r.oid
+LEFT JOIN pg_catalog.pg_namespace AS n ON d.defaclnamespace = n.oid
+CROSS JOIN LATERAL pg_catalog.aclexplode(d.defaclacl) AS acl
 WHERE
-  c.relkind IN ('r', 'p')
-  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
-  AND COALESCE(s.n_live_tup, 0) >= 10000000
+  acl.grantee = 0
+  AND d.defaclobjtype IN ('r', 'S', 'n')
+GROUP BY r.rolname, n.nspname, d.defaclobjtype
+ORDER BY r.rolname, schema_name, object_type
 `
 
-type LargeTablesRow struct {
-	TableName      pgtype.Text
-	ParentTable    pgtype.Text
-	TableSizeBytes pgtype.Int8
-	EstimatedRows  pgtype.Int8
-	IsPartitioned  pgtype.Bool
-	IsPartition    pgtype.Bool
-	IsTransient    pgtype.Bool
-	NTupIns        pgtype.Int8
-	NTupUpd        pgtype.Int8
-	NTupDel        pgtype.Int8
+type PublicDefaultPrivilegesRow struct {
+	RoleName   string
+	SchemaName string
+	ObjectType string
+	Privileges string
 }
 
-// Identifies all large tables (>= 10M rows) with partitioning and transient status.
-// Returns both regular and partitioned tables for unified analysis.
-// Includes activity metrics (inserts/updates/deletes) for activity-aware thresholds.
-func (q *Queries) LargeTables(ctx context.Context) ([]LargeTablesRow, error) {
-	rows, err := q.db.Query(ctx, largeTables)
+// ALTER DEFAULT PRIVILEGES entries that grant PUBLIC access to tables,
+// sequences or schemas created in the future. schema_name is empty for
+// entries that apply to every schema.
+func (q *Queries) PublicDefaultPrivileges(ctx context.Context) ([]PublicDefaultPrivilegesRow, error) {
+	rows, err := q.db.Query(ctx, publicDefaultPrivileges)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []LargeTablesRow
+	var items []PublicDefaultPrivilegesRow
 	for rows.Next() {
-		var i LargeTablesRow
+		var i PublicDefaultPrivilegesRow
 		if err := rows.Scan(
-			&i.TableName,
-			&i.ParentTable,
-			&i.TableSizeBytes,
-			&i.EstimatedRows,
-			&i.IsPartitioned,
-			&i.IsPartition,
-			&i.IsTransient,
-			&i.NTupIns,
-			&i.NTupUpd,
-			&i.NTupDel,
+			&i.RoleName,
+			&i.SchemaName,
+			&i.ObjectType,
+			&i.Privileges,
 		); err != nil {
 			return nil, err
 		}
@@ -929,54 +3535,106 @@ func (q *Queries) LargeTables(ctx context.Context) ([]LargeTablesRow, error) {
 	return items, nil
 }
 
-const longIdleConnections = `-- name: LongIdleConnections :many
+const publicObjectGrants = `-- name: PublicObjectGrants :many
+WITH relation_grants AS (
+  SELECT
+    CASE c.relkind
+      WHEN 'v' THEN 'view'
+      WHEN 'm' THEN 'materialized view'
+      WHEN 'f' THEN 'foreign table'
+      WHEN 'S' THEN 'sequence'
+      ELSE 'table'
+    END AS object_kind
+    , n.nspname::text AS schema_name
+    , c.relname::text AS object_name
+    , '' AS arguments
+    , acl.privilege_type
+  FROM pg_catalog.pg_class AS c
+  INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+  CROSS JOIN LATERAL pg_catalog.aclexplode(c.relacl) AS acl
+  WHERE
+    acl.grantee = 0
+    AND c.relkind IN ('r', 'p', 'v', 'm', 'f', 'S')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_class'::regclass
+        AND c.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+),
+
+function_grants AS (
+  SELECT
+    'function' AS object_kind
+    , n.nspname::text AS schema_name
+    , p.proname::text AS object_name
+    , pg_catalog.pg_get_function_identity_arguments(p.oid) AS arguments
+    , acl.privilege_type
+  FROM pg_catalog.pg_proc AS p
+  INNER JOIN pg_catalog.pg_namespace AS n ON p.pronamespace = n.oid
+  CROSS JOIN LATERAL pg_catalog.aclexplode(coalesce(p.proacl, pg_catalog.acldefault('f', p.proowner))) AS acl
+  WHERE
+    acl.grantee = 0
+    AND p.prosecdef
+    AND p.prokind IN ('f', 'p')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg\_%'
+    AND NOT EXISTS (
+      SELECT 1
+      FROM pg_catalog.pg_depend AS ext
+      WHERE
+        ext.classid = 'pg_catalog.pg_proc'::regclass
+        AND p.oid = ext.objid
+        AND ext.deptype = 'e'
+    )
+)
+
 SELECT
-  pid
-  , usename::text AS username
-  , datname::text AS database_name
-  , application_name::text AS application_name
-  , client_addr::text AS client_address
-  , state::text AS state
-  , extract(EPOCH FROM (now() - state_change))::bigint AS idle_duration_seconds
-  , extract(EPOCH FROM (now() - backend_start))::bigint AS connection_age_seconds
-FROM pg_stat_activity
-WHERE
-  state = 'idle'
-  AND pid != pg_backend_pid()
-  AND (now() - state_change) > interval '30 minutes'
-ORDER BY state_change ASC
+  g.object_kind
+  , g.schema_name
+  , g.object_name
+  , g.arguments
+  , string_agg(g.privilege_type, ', ' ORDER BY g.privilege_type) AS privileges
+FROM (
+  SELECT * FROM relation_grants
+  UNION ALL
+  SELECT * FROM function_grants
+) AS g
+GROUP BY g.object_kind, g.schema_name, g.object_name, g.arguments
+ORDER BY g.schema_name, g.object_name, g.arguments
 `
 
-type LongIdleConnectionsRow struct {
-	Pid                  pgtype.Int4
-	Username             pgtype.Text
-	DatabaseName         pgtype.Text
-	ApplicationName      pgtype.Text
-	ClientAddress        pgtype.Text
-	State                pgtype.Text
-	IdleDurationSeconds  pgtype.Int8
-	ConnectionAgeSeconds pgtype.Int8
-}
-
-// Identifies connections that have been idle for too long (potential pool leak).
-func (q *Queries) LongIdleConnections(ctx context.Context) ([]LongIdleConnectionsRow, error) {
-	rows, err := q.db.Query(ctx, longIdleConnections)
+type PublicObjectGrantsRow struct {
+	ObjectKind string
+	SchemaName string
+	ObjectName string
+	Arguments  string
+	Privileges string
+}
+
+// User relations with privileges granted to PUBLIC, and SECURITY DEFINER
+// functions PUBLIC may execute. EXECUTE to PUBLIC is the default for every
+// function, so only those running with their owner's privileges are listed.
+// Objects owned by extensions are left out.
+func (q *Queries) PublicObjectGrants(ctx context.Context) ([]PublicObjectGrantsRow, error) {
+	rows, err := q.db.Query(ctx, publicObjectGrants)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []LongIdleConnectionsRow
+	var items []PublicObjectGrantsRow
 	for rows.Next() {
-		var i LongIdleConnectionsRow
+		var i PublicObjectGrantsRow
 		if err := rows.Scan(
-			&i.Pid,
-			&i.Username,
-			&i.DatabaseName,
-			&i.ApplicationName,
-			&i.ClientAddress,
-			&i.State,
-			&i.IdleDurationSeconds,
-			&i.ConnectionAgeSeconds,
+			&i.ObjectKind,
+			&i.SchemaName,
+			&i.ObjectName,
+			&i.Arguments,
+			&i.Privileges,
 		); err != nil {
 			return nil, err
 		}
@@ -988,104 +3646,49 @@ func (q *Queries) LongIdleConnections(ctx context.Context) ([]LongIdleConnection
 	return items, nil
 }
 
-const pGVersion = `-- name: PGVersion :one
-SELECT
-  current_setting('server_version_num')::integer / 10000 AS major
-  , current_setting('server_version_num')::integer % 100 AS minor
-`
-
-type PGVersionRow struct {
-	Major int32
-	Minor int32
-}
-
-func (q *Queries) PGVersion(ctx context.Context) (PGVersionRow, error) {
-	row := q.db.QueryRow(ctx, pGVersion)
-	var i PGVersionRow
-	err := row.Scan(&i.Major, &i.Minor)
-	return i, err
-}
-
-const partitionedTablesWithKeys = `-- name: PartitionedTablesWithKeys :many
-WITH partition_stats AS (
-  -- Single aggregation of all partition metrics from child tables
-  SELECT
-    i.inhparent
-    , COUNT(*)::bigint AS partition_count
-    , COALESCE(SUM(pg_catalog.pg_total_relation_size(i.inhrelid)), 0)::bigint AS total_size_bytes
-    , COALESCE(SUM(s.n_live_tup), 0)::bigint AS estimated_rows
-    , COALESCE(SUM(s.seq_scan), 0)::bigint AS total_seq_scans
-    , COALESCE(SUM(s.idx_scan), 0)::bigint AS total_idx_scans
-  FROM pg_inherits AS i
-  LEFT JOIN pg_stat_user_tables AS s ON i.inhrelid = s.relid
-  GROUP BY i.inhparent
-)
-
+const publications = `-- name: Publications :many
 SELECT
-  n.nspname::text AS schema_name
-  , c.relname::text AS table_name
-  , pt.partstrat::text AS partition_strategy
-  -- Get partition key column names as comma-separated string
+  p.pubname::text AS publication_name
+  , p.puballtables AS all_tables
+  , (SELECT count(*) FROM pg_publication_tables AS pt WHERE pt.pubname = p.pubname) AS table_count
   , (
-    SELECT STRING_AGG(a.attname, ',' ORDER BY k.n)
-    FROM UNNEST(pt.partattrs) WITH ORDINALITY AS k (attnum, n)
-    INNER JOIN pg_attribute AS a ON a.attrelid = c.oid AND k.attnum = a.attnum
-    WHERE k.attnum > 0
-  )::text AS partition_key_columns
-  -- Check if partition key includes expressions (attnum = 0 means expression)
-  , (SELECT BOOL_OR(k.attnum = 0) FROM UNNEST(pt.partattrs) AS k (attnum)) AS has_expression_key
-  -- All partition metrics from pre-aggregated CTE
-  , COALESCE(ps.partition_count, 0) AS partition_count
-  , COALESCE(ps.total_size_bytes, 0) AS total_size_bytes
-  , COALESCE(ps.estimated_rows, 0) AS estimated_rows
-  , COALESCE(ps.total_seq_scans, 0) AS total_seq_scans
-  , COALESCE(ps.total_idx_scans, 0) AS total_idx_scans
-FROM pg_catalog.pg_class AS c
-INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
-INNER JOIN pg_partitioned_table AS pt ON c.oid = pt.partrelid
-LEFT JOIN partition_stats AS ps ON c.oid = ps.inhparent
-WHERE
-  c.relkind = 'p'
-  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast', 'pgpartman', 'debezium', 'cron')
-ORDER BY ps.total_size_bytes DESC NULLS LAST
+    SELECT count(*) FROM pg_replication_slots AS sl
+    WHERE sl.database = current_database() AND sl.slot_type = 'logical' AND sl.plugin = 'pgoutput'
+  ) AS slot_count
+  , (
+    SELECT count(*) FROM pg_replication_slots AS sl
+    WHERE sl.database = current_database() AND sl.slot_type = 'logical' AND sl.plugin = 'pgoutput' AND sl.active
+  ) AS active_slot_count
+FROM pg_publication AS p
+ORDER BY p.pubname
 `
 
-type PartitionedTablesWithKeysRow struct {
-	SchemaName          pgtype.Text
-	TableName           pgtype.Text
-	PartitionStrategy   pgtype.Text
-	PartitionKeyColumns pgtype.Text
-	HasExpressionKey    pgtype.Bool
-	PartitionCount      pgtype.Int8
-	TotalSizeBytes      pgtype.Int8
-	EstimatedRows       pgtype.Int8
-	TotalSeqScans       pgtype.Int8
-	TotalIdxScans       pgtype.Int8
+type PublicationsRow struct {
+	PublicationName string
+	AllTables       bool
+	TableCount      int64
+	SlotCount       int64
+	ActiveSlotCount int64
 }
 
-// Gets partitioned tables and their partition key column(s).
-// Pre-aggregates all partition statistics in a single CTE for better performance
-// compared to multiple correlated subqueries.
-func (q *Queries) PartitionedTablesWithKeys(ctx context.Context) ([]PartitionedTablesWithKeysRow, error) {
-	rows, err := q.db.Query(ctx, partitionedTablesWithKeys)
+// Publications of the current database. Which publications a subscriber reads
+// is only known to its walsender, so subscribers are counted per database as
+// pgoutput slots.
+func (q *Queries) Publications(ctx context.Context) ([]PublicationsRow, error) {
+	rows, err := q.db.Query(ctx, publications)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []PartitionedTablesWithKeysRow
+	var items []PublicationsRow
 	for rows.Next() {
-		var i PartitionedTablesWithKeysRow
+		var i PublicationsRow
 		if err := rows.Scan(
-			&i.SchemaName,
-			&i.TableName,
-			&i.PartitionStrategy,
-			&i.PartitionKeyColumns,
-			&i.HasExpressionKey,
-			&i.PartitionCount,
-			&i.TotalSizeBytes,
-			&i.EstimatedRows,
-			&i.TotalSeqScans,
-			&i.TotalIdxScans,
+			&i.PublicationName,
+			&i.AllTables,
+			&i.TableCount,
+			&i.SlotCount,
+			&i.ActiveSlotCount,
 		); err != nil {
 			return nil, err
 		}
@@ -1156,6 +3759,58 @@ func (q *Queries) QueryStatsFromStatStatements(ctx context.Context) ([]QueryStat
 	return items, nil
 }
 
+const reloptionServerSettings = `-- name: ReloptionServerSettings :many
+SELECT
+  name::text AS name
+  , setting::text AS setting
+FROM pg_settings
+WHERE
+  name IN (
+    'autovacuum'
+    , 'autovacuum_vacuum_threshold'
+    , 'autovacuum_vacuum_scale_factor'
+    , 'autovacuum_vacuum_insert_threshold'
+    , 'autovacuum_vacuum_insert_scale_factor'
+    , 'autovacuum_analyze_threshold'
+    , 'autovacuum_analyze_scale_factor'
+    , 'autovacuum_vacuum_cost_delay'
+    , 'autovacuum_vacuum_cost_limit'
+    , 'autovacuum_freeze_max_age'
+    , 'autovacuum_multixact_freeze_max_age'
+    , 'vacuum_freeze_min_age'
+    , 'vacuum_freeze_table_age'
+    , 'vacuum_multixact_freeze_min_age'
+    , 'vacuum_multixact_freeze_table_age'
+    , 'log_autovacuum_min_duration'
+  )
+`
+
+type ReloptionServerSettingsRow struct {
+	Name    pgtype.Text
+	Setting pgtype.Text
+}
+
+// Server-wide settings that per-table autovacuum reloptions override.
+func (q *Queries) ReloptionServerSettings(ctx context.Context) ([]ReloptionServerSettingsRow, error) {
+	rows, err := q.db.Query(ctx, reloptionServerSettings)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []ReloptionServerSettingsRow
+	for rows.Next() {
+		var i ReloptionServerSettingsRow
+		if err := rows.Scan(&i.Name, &i.Setting); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const replicationLag = `-- name: ReplicationLag :many
 SELECT
   -- Consumer/replica identity
@@ -1261,6 +3916,7 @@ SELECT
     WHEN active THEN NULL
     ELSE EXTRACT(EPOCH FROM (NOW() - inactive_since))::BIGINT
   END AS inactive_seconds
+  , COALESCE((SELECT setting::BIGINT FROM pg_settings WHERE name = 'max_slot_wal_keep_size'), -1) AS max_slot_wal_keep_size_mb
 
 FROM pg_replication_slots
 ORDER BY
@@ -1288,6 +3944,7 @@ type ReplicationSlotsRow struct {
 	RestartLsnLagBytes        pgtype.Int8
 	ConfirmedFlushLsnLagBytes pgtype.Int8
 	InactiveSeconds           pgtype.Int8
+	MaxSlotWalKeepSizeMb      int64
 }
 
 // For PostgreSQL 17+: includes inactive_since, conflicting, invalidation_reason
@@ -1315,6 +3972,7 @@ func (q *Queries) ReplicationSlots(ctx context.Context) ([]ReplicationSlotsRow,
 			&i.RestartLsnLagBytes,
 			&i.ConfirmedFlushLsnLagBytes,
 			&i.InactiveSeconds,
+			&i.MaxSlotWalKeepSizeMb,
 		); err != nil {
 			return nil, err
 		}
@@ -1342,6 +4000,7 @@ SELECT
   , PG_WAL_LSN_DIFF(PG_CURRENT_WAL_LSN(), restart_lsn)::BIGINT AS restart_lsn_lag_bytes
   , PG_WAL_LSN_DIFF(PG_CURRENT_WAL_LSN(), confirmed_flush_lsn)::BIGINT AS confirmed_flush_lsn_lag_bytes
   , NULL::BIGINT AS inactive_seconds
+  , COALESCE((SELECT setting::BIGINT FROM pg_settings WHERE name = 'max_slot_wal_keep_size'), -1) AS max_slot_wal_keep_size_mb
 
 FROM pg_replication_slots
 ORDER BY
@@ -1369,6 +4028,7 @@ type ReplicationSlotsPG15Row struct {
 	RestartLsnLagBytes        pgtype.Int8
 	ConfirmedFlushLsnLagBytes pgtype.Int8
 	InactiveSeconds           pgtype.Int8
+	MaxSlotWalKeepSizeMb      int64
 }
 
 // For PostgreSQL 15/16: columns conflicting, invalidation_reason, inactive_since don't exist
@@ -1396,6 +4056,157 @@ func (q *Queries) ReplicationSlotsPG15(ctx context.Context) ([]ReplicationSlotsP
 			&i.RestartLsnLagBytes,
 			&i.ConfirmedFlushLsnLagBytes,
 			&i.InactiveSeconds,
+			&i.MaxSlotWalKeepSizeMb,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const roleAttributes = `-- name: RoleAttributes :many
+SELECT
+  r.rolname::text AS role_name
+  , r.oid = 10 AS is_bootstrap
+  , r.rolsuper AS superuser
+  , r.rolcreaterole AS create_role
+  , r.rolbypassrls AS bypass_rls
+  , r.rolcanlogin AS can_login
+  , r.rolconnlimit AS connection_limit
+FROM pg_catalog.pg_roles AS r
+WHERE r.rolname NOT LIKE 'pg\_%'
+ORDER BY r.rolname
+`
+
+type RoleAttributesRow struct {
+	RoleName        string
+	IsBootstrap     bool
+	Superuser       bool
+	CreateRole      bool
+	BypassRls       bool
+	CanLogin        bool
+	ConnectionLimit int32
+}
+
+// Privilege attributes and connection limit of every role, from pg_roles,
+// which any role can read. Predefined pg_* roles are excluded; oid 10 is the
+// bootstrap superuser created by initdb.
+func (q *Queries) RoleAttributes(ctx context.Context) ([]RoleAttributesRow, error) {
+	rows, err := q.db.Query(ctx, roleAttributes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []RoleAttributesRow
+	for rows.Next() {
+		var i RoleAttributesRow
+		if err := rows.Scan(
+			&i.RoleName,
+			&i.IsBootstrap,
+			&i.Superuser,
+			&i.CreateRole,
+			&i.BypassRls,
+			&i.CanLogin,
+			&i.ConnectionLimit,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const schemaObjects = `-- name: SchemaObjects :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , 'table'::text AS kind
+  , c.relname::text AS object_name
+  , MD5(
+    c.relkind::text || ':' || COALESCE((
+      SELECT
+        STRING_AGG(
+          a.attname || ' ' || FORMAT_TYPE(a.atttypid, a.atttypmod)
+          || CASE WHEN a.attnotnull THEN ' not null' ELSE '' END
+          || COALESCE(' default ' || PG_GET_EXPR(d.adbin, d.adrelid), '')
+          , ', ' ORDER BY a.attnum
+        )
+      FROM pg_attribute AS a
+      LEFT JOIN pg_attrdef AS d ON a.attrelid = d.adrelid AND a.attnum = d.adnum
+      WHERE a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+    ), '')
+  ) AS digest
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE c.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+UNION ALL
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , 'index'::text AS kind
+  , i.relname::text AS object_name
+  , MD5(PG_GET_INDEXDEF(i.oid)) AS digest
+FROM pg_index AS ix
+INNER JOIN pg_class AS i ON ix.indexrelid = i.oid
+INNER JOIN pg_class AS t ON ix.indrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE t.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+UNION ALL
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , 'constraint'::text AS kind
+  , con.conname::text AS object_name
+  , MD5(con.contype::text || ':' || PG_GET_CONSTRAINTDEF(con.oid)) AS digest
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+WHERE t.relkind IN ('r', 'p')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND n.nspname NOT LIKE 'pg\_temp\_%'
+ORDER BY schema_name, table_name, kind, object_name
+`
+
+type SchemaObjectsRow struct {
+	SchemaName string
+	TableName  string
+	Kind       string
+	ObjectName string
+	Digest     string
+}
+
+// One row per table, index and constraint in user schemas with an md5 of its
+// definition. Definitions are rebuilt by the catalog (format_type, pg_get_*def),
+// so cosmetic differences in the original DDL never register as changes.
+func (q *Queries) SchemaObjects(ctx context.Context) ([]SchemaObjectsRow, error) {
+	rows, err := q.db.Query(ctx, schemaObjects)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []SchemaObjectsRow
+	for rows.Next() {
+		var i SchemaObjectsRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.Kind,
+			&i.ObjectName,
+			&i.Digest,
 		); err != nil {
 			return nil, err
 		}
@@ -1413,16 +4224,11 @@ WITH sequence_info AS (
     s.schemaname::text AS schema_name
     , s.sequencename::text AS sequence_name
     , s.data_type::text AS seq_data_type
+    , s.min_value
     , s.max_value
     , s.increment_by
     , s.cycle AS is_cyclic
     , COALESCE(s.last_value, s.start_value) AS current_value
-    , CASE
-      WHEN s.max_value > 0 AND COALESCE(s.last_value, s.start_value) > 0
-        THEN (COALESCE(s.last_value, s.start_value)::numeric / s.max_value::numeric) * 100
-      ELSE 0
-    END AS usage_percent
-    , (s.max_value - COALESCE(s.last_value, s.start_value)) / NULLIF(s.increment_by, 0) AS remaining_values
   FROM pg_sequences AS s
   WHERE s.schemaname NOT IN ('pg_catalog', 'information_schema')
 )
@@ -1455,6 +4261,40 @@ WITH sequence_info AS (
   AND seq_ns.nspname NOT IN ('pg_catalog', 'information_schema')
 )
 
+, sequence_limits AS (
+  SELECT
+    si.*
+    , so.table_oid
+    , so.table_name
+    , so.column_name
+    , so.column_num
+    , so.column_type
+    , so.column_max_value
+    , CASE
+      WHEN si.increment_by > 0 THEN LEAST(si.max_value, COALESCE(so.column_max_value, si.max_value))
+      ELSE GREATEST(si.min_value, COALESCE(-so.column_max_value - 1, si.min_value))
+    END AS limit_value
+  FROM sequence_info AS si
+  LEFT JOIN sequence_owners AS so
+    ON
+      si.schema_name = so.seq_schema
+      AND si.sequence_name = so.seq_name
+)
+
+, sequence_usage AS (
+  SELECT
+    sl.*
+    , CASE
+      WHEN sl.increment_by > 0 AND sl.limit_value > 0 AND sl.current_value > 0
+        THEN (sl.current_value::numeric / sl.limit_value::numeric) * 100
+      WHEN sl.increment_by < 0 AND sl.limit_value < 0 AND sl.current_value < 0
+        THEN (sl.current_value::numeric / sl.limit_value::numeric) * 100
+      ELSE 0
+    END AS usage_percent
+    , (sl.limit_value - sl.current_value) / NULLIF(sl.increment_by, 0) AS remaining_values
+  FROM sequence_limits AS sl
+)
+
 , primary_keys AS (
   SELECT
     con.conrelid AS table_oid
@@ -1483,32 +4323,28 @@ SELECT
   , si.is_cyclic
   , si.remaining_values
   , ROUND(si.usage_percent::numeric, 2) AS usage_percent
-  , COALESCE(so.table_name, '') AS table_name
-  , COALESCE(so.column_name, '') AS column_name
-  , COALESCE(so.column_type, '') AS column_type
-  , COALESCE(so.column_max_value, 0) AS column_max_value
+  , COALESCE(si.table_name, '') AS table_name
+  , COALESCE(si.column_name, '') AS column_name
+  , COALESCE(si.column_type, '') AS column_type
+  , COALESCE(si.column_max_value, 0) AS column_max_value
   -- Flag if sequence can generate values that exceed column type
-  , (so.column_max_value IS NOT NULL AND si.max_value > so.column_max_value) AS sequence_exceeds_column
+  , (si.column_max_value IS NOT NULL AND si.max_value > si.column_max_value) AS sequence_exceeds_column
   -- Flag if this is an integer column that should probably be bigint
-  , (so.column_type != 'bigint' AND si.usage_percent > 50) AS should_be_bigint
+  , (si.column_type != 'bigint' AND si.usage_percent > 50) AS should_be_bigint
   -- Flag if column is a primary key
   , (pk.table_oid IS NOT NULL) AS is_primary_key
   -- Count of foreign keys referencing this column
   , COALESCE(fkr.fk_count, 0) AS fk_reference_count
 
-FROM sequence_info AS si
-LEFT JOIN sequence_owners AS so
-  ON
-    si.schema_name = so.seq_schema
-    AND si.sequence_name = so.seq_name
+FROM sequence_usage AS si
 LEFT JOIN primary_keys AS pk
   ON
-    so.table_oid = pk.table_oid
-    AND so.column_num = pk.column_num
+    si.table_oid = pk.table_oid
+    AND si.column_num = pk.column_num
 LEFT JOIN fk_references AS fkr
   ON
-    so.table_oid = fkr.referenced_table_oid
-    AND so.column_num = fkr.referenced_column_num
+    si.table_oid = fkr.referenced_table_oid
+    AND si.column_num = fkr.referenced_column_num
 ORDER BY si.usage_percent DESC, si.remaining_values ASC
 `
 
@@ -1532,8 +4368,12 @@ type SequenceHealthRow struct {
 	FkReferenceCount      pgtype.Int8
 }
 
-// Identifies sequences approaching their maximum values and integer columns that should be bigint
+// Identifies sequences approaching their maximum values and integer columns that should be bigint.
+// Usage is measured against the tighter of the sequence's own limit and the
+// owning column's type (an int4 column overflows long before a bigint
+// sequence), towards min_value for descending sequences.
 // Find columns that own sequences (SERIAL/BIGSERIAL columns)
+// The limit the sequence runs into first, in the direction it moves
 // Check if columns are primary keys
 // Count foreign keys referencing each column (as the referenced/target column)
 func (q *Queries) SequenceHealth(ctx context.Context) ([]SequenceHealthRow, error) {
@@ -1542,27 +4382,110 @@ func (q *Queries) SequenceHealth(ctx context.Context) ([]SequenceHealthRow, erro
 		return nil, err
 	}
 	defer rows.Close()
-	var items []SequenceHealthRow
+	var items []SequenceHealthRow
+	for rows.Next() {
+		var i SequenceHealthRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.SequenceName,
+			&i.SeqDataType,
+			&i.CurrentValue,
+			&i.MaxValue,
+			&i.IncrementBy,
+			&i.IsCyclic,
+			&i.RemainingValues,
+			&i.UsagePercent,
+			&i.TableName,
+			&i.ColumnName,
+			&i.ColumnType,
+			&i.ColumnMaxValue,
+			&i.SequenceExceedsColumn,
+			&i.ShouldBeBigint,
+			&i.IsPrimaryKey,
+			&i.FkReferenceCount,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const serialColumns = `-- name: SerialColumns :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type
+  , a.attnotnull AS not_null
+  , sn.nspname::text AS sequence_schema
+  , s.relname::text AS sequence_name
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_attrdef AS ad ON a.attrelid = ad.adrelid AND a.attnum = ad.adnum
+INNER JOIN pg_catalog.pg_depend AS d
+  ON
+    d.classid = 'pg_catalog.pg_class'::regclass
+    AND d.refclassid = 'pg_catalog.pg_class'::regclass
+    AND a.attrelid = d.refobjid
+    AND a.attnum = d.refobjsubid
+    AND d.deptype = 'a'
+INNER JOIN pg_catalog.pg_class AS s ON d.objid = s.oid AND s.relkind = 'S'
+INNER JOIN pg_catalog.pg_namespace AS sn ON s.relnamespace = sn.oid
+WHERE
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND a.attidentity = ''
+  AND c.relkind = 'r'
+  AND NOT c.relispartition
+  AND pg_catalog.pg_get_expr(ad.adbin, ad.adrelid) LIKE 'nextval(%'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND NOT EXISTS (
+    SELECT 1
+    FROM pg_catalog.pg_depend AS ext
+    WHERE
+      ext.classid = 'pg_catalog.pg_class'::regclass
+      AND c.oid = ext.objid
+      AND ext.deptype = 'e'
+  )
+ORDER BY n.nspname, c.relname, a.attnum
+`
+
+type SerialColumnsRow struct {
+	SchemaName     string
+	TableName      string
+	ColumnName     string
+	DataType       string
+	NotNull        bool
+	SequenceSchema string
+	SequenceName   string
+}
+
+// Columns whose default takes nextval() from a sequence the column owns, the
+// way serial and bigserial create them, instead of identity columns.
+// Partitioned tables and their partitions are left out, since identity
+// columns need PostgreSQL 17 there, and so are tables owned by extensions.
+func (q *Queries) SerialColumns(ctx context.Context) ([]SerialColumnsRow, error) {
+	rows, err := q.db.Query(ctx, serialColumns)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []SerialColumnsRow
 	for rows.Next() {
-		var i SequenceHealthRow
+		var i SerialColumnsRow
 		if err := rows.Scan(
 			&i.SchemaName,
-			&i.SequenceName,
-			&i.SeqDataType,
-			&i.CurrentValue,
-			&i.MaxValue,
-			&i.IncrementBy,
-			&i.IsCyclic,
-			&i.RemainingValues,
-			&i.UsagePercent,
 			&i.TableName,
 			&i.ColumnName,
-			&i.ColumnType,
-			&i.ColumnMaxValue,
-			&i.SequenceExceedsColumn,
-			&i.ShouldBeBigint,
-			&i.IsPrimaryKey,
-			&i.FkReferenceCount,
+			&i.DataType,
+			&i.NotNull,
+			&i.SequenceSchema,
+			&i.SequenceName,
 		); err != nil {
 			return nil, err
 		}
@@ -1793,6 +4716,60 @@ func (q *Queries) SessionStatistics(ctx context.Context) (SessionStatisticsRow,
 	return i, err
 }
 
+const spillingStatements = `-- name: SpillingStatements :many
+SELECT
+  COALESCE(s.queryid, 0)::bigint AS query_id
+  , LEFT(REGEXP_REPLACE(s.query, '\s+', ' ', 'g'), 120)::text AS query
+  , s.calls::bigint AS calls
+  , (s.temp_blks_written * current_setting('block_size')::bigint)::bigint AS temp_bytes_written
+  , s.total_exec_time::double precision AS total_exec_time_ms
+  , (SELECT st.setting::bigint * 1024 FROM pg_settings AS st WHERE st.name = 'work_mem')::bigint AS work_mem_bytes
+FROM pg_stat_statements AS s
+WHERE
+  s.dbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+  AND s.temp_blks_written > 0
+ORDER BY s.temp_blks_written DESC
+LIMIT 20
+`
+
+type SpillingStatementsRow struct {
+	QueryID          int64
+	Query            string
+	Calls            int64
+	TempBytesWritten int64
+	TotalExecTimeMs  float64
+	WorkMemBytes     int64
+}
+
+// The statements of the current database that wrote the most temporary file
+// data since pg_stat_statements was reset, with work_mem for comparison.
+func (q *Queries) SpillingStatements(ctx context.Context) ([]SpillingStatementsRow, error) {
+	rows, err := q.db.Query(ctx, spillingStatements)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []SpillingStatementsRow
+	for rows.Next() {
+		var i SpillingStatementsRow
+		if err := rows.Scan(
+			&i.QueryID,
+			&i.Query,
+			&i.Calls,
+			&i.TempBytesWritten,
+			&i.TotalExecTimeMs,
+			&i.WorkMemBytes,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const statisticsFreshness = `-- name: StatisticsFreshness :one
 SELECT
   stats_reset
@@ -1820,52 +4797,168 @@ func (q *Queries) StatisticsFreshness(ctx context.Context) (StatisticsFreshnessR
 	return i, err
 }
 
-const tableActivity = `-- name: TableActivity :many
+const subscriptionTablesNotReady = `-- name: SubscriptionTablesNotReady :many
 SELECT
-  schemaname
-  , relname
-  , n_tup_ins
-  , n_tup_upd
-  , n_tup_del
-  , n_tup_hot_upd
-  , n_live_tup
-  , pg_table_size(relid) AS table_size_bytes
-FROM pg_stat_user_tables
-WHERE n_tup_ins + n_tup_upd + n_tup_del > 0
-ORDER BY n_tup_ins + n_tup_upd + n_tup_del DESC
+  s.subname::text AS subscription_name
+  , n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , r.srsubstate::text AS sync_state
+FROM pg_subscription_rel AS r
+INNER JOIN pg_subscription AS s ON s.oid = r.srsubid
+INNER JOIN pg_class AS c ON c.oid = r.srrelid
+INNER JOIN pg_namespace AS n ON n.oid = c.relnamespace
+WHERE
+  r.srsubstate <> 'r'
+  AND s.subdbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+ORDER BY s.subname, n.nspname, c.relname
 `
 
-type TableActivityRow struct {
-	Schemaname     pgtype.Text
-	Relname        pgtype.Text
-	NTupIns        pgtype.Int8
-	NTupUpd        pgtype.Int8
-	NTupDel        pgtype.Int8
-	NTupHotUpd     pgtype.Int8
-	NLiveTup       pgtype.Int8
-	TableSizeBytes pgtype.Int8
+type SubscriptionTablesNotReadyRow struct {
+	SubscriptionName string
+	SchemaName       string
+	TableName        string
+	SyncState        string
 }
 
-// Retrieves table write activity metrics from pg_stat_user_tables
-// Used to identify high-churn tables and HOT update efficiency issues
-func (q *Queries) TableActivity(ctx context.Context) ([]TableActivityRow, error) {
-	rows, err := q.db.Query(ctx, tableActivity)
+// Subscribed tables still synchronizing (any state but 'r', ready).
+func (q *Queries) SubscriptionTablesNotReady(ctx context.Context) ([]SubscriptionTablesNotReadyRow, error) {
+	rows, err := q.db.Query(ctx, subscriptionTablesNotReady)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
-	var items []TableActivityRow
+	var items []SubscriptionTablesNotReadyRow
 	for rows.Next() {
-		var i TableActivityRow
+		var i SubscriptionTablesNotReadyRow
 		if err := rows.Scan(
-			&i.Schemaname,
-			&i.Relname,
-			&i.NTupIns,
-			&i.NTupUpd,
-			&i.NTupDel,
-			&i.NTupHotUpd,
-			&i.NLiveTup,
-			&i.TableSizeBytes,
+			&i.SubscriptionName,
+			&i.SchemaName,
+			&i.TableName,
+			&i.SyncState,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const subscriptions = `-- name: Subscriptions :many
+SELECT
+  s.subname::text AS subscription_name
+  , s.subenabled AS enabled
+  , w.pid AS worker_pid
+  , extract(EPOCH FROM (now() - w.latest_end_time))::bigint AS apply_lag_seconds
+  , (SELECT count(*) FROM pg_subscription_rel AS r WHERE r.srsubid = s.oid) AS table_count
+FROM pg_subscription AS s
+LEFT JOIN LATERAL (
+  SELECT
+    st.pid
+    , st.latest_end_time
+  FROM pg_stat_subscription AS st
+  WHERE st.subid = s.oid AND st.relid IS NULL
+  ORDER BY st.latest_end_time DESC NULLS LAST
+  LIMIT 1
+) AS w ON true
+WHERE s.subdbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+ORDER BY s.subname
+`
+
+type SubscriptionsRow struct {
+	SubscriptionName string
+	Enabled          bool
+	WorkerPid        pgtype.Int4
+	ApplyLagSeconds  pgtype.Int8
+	TableCount       int64
+}
+
+// Subscriptions of the current database with their apply worker, if running.
+// Parallel apply workers (PostgreSQL 16+) also have no relid; they report no
+// latest_end_time, so the leader sorts first.
+func (q *Queries) Subscriptions(ctx context.Context) ([]SubscriptionsRow, error) {
+	rows, err := q.db.Query(ctx, subscriptions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []SubscriptionsRow
+	for rows.Next() {
+		var i SubscriptionsRow
+		if err := rows.Scan(
+			&i.SubscriptionName,
+			&i.Enabled,
+			&i.WorkerPid,
+			&i.ApplyLagSeconds,
+			&i.TableCount,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const suspiciousColumnTypes = `-- name: SuspiciousColumnTypes :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , a.attname::text AS column_name
+  , t.typname::text AS type_name
+  , pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type
+  , CASE
+    WHEN t.typname IN ('bpchar', 'varchar') AND a.atttypmod > 4 THEN a.atttypmod - 4
+    ELSE -1
+  END AS max_length
+FROM pg_catalog.pg_attribute AS a
+INNER JOIN pg_catalog.pg_class AS c ON a.attrelid = c.oid
+INNER JOIN pg_catalog.pg_namespace AS n ON c.relnamespace = n.oid
+INNER JOIN pg_catalog.pg_type AS t ON a.atttypid = t.oid
+WHERE
+  a.attnum > 0
+  AND NOT a.attisdropped
+  AND c.relkind IN ('r', 'p')
+  AND NOT c.relispartition
+  AND t.typname IN ('bpchar', 'money', 'timestamp', 'timestamptz', 'varchar')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY n.nspname, c.relname, a.attnum
+`
+
+type SuspiciousColumnTypesRow struct {
+	SchemaName string
+	TableName  string
+	ColumnName string
+	TypeName   string
+	DataType   string
+	MaxLength  int32
+}
+
+// Columns of types that are often a design mistake: char(n), money,
+// timestamp and varchar(n), plus timestamptz to tell whether the database
+// mixes both timestamp types. Partitions repeat their parent's columns and
+// are left out. max_length is the declared length of char and varchar
+// columns, and -1 otherwise.
+func (q *Queries) SuspiciousColumnTypes(ctx context.Context) ([]SuspiciousColumnTypesRow, error) {
+	rows, err := q.db.Query(ctx, suspiciousColumnTypes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []SuspiciousColumnTypesRow
+	for rows.Next() {
+		var i SuspiciousColumnTypesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.ColumnName,
+			&i.TypeName,
+			&i.DataType,
+			&i.MaxLength,
 		); err != nil {
 			return nil, err
 		}
@@ -1951,6 +5044,78 @@ func (q *Queries) TableBloat(ctx context.Context) ([]TableBloatRow, error) {
 	return items, nil
 }
 
+const tableCacheEfficiency = `-- name: TableCacheEfficiency :many
+WITH table_io AS (
+  SELECT
+    s.relid
+    , s.schemaname::text AS schema_name
+    , s.relname::text AS table_name
+    , COALESCE(s.heap_blks_hit, 0) + COALESCE(s.toast_blks_hit, 0) AS heap_blks_hit
+    , COALESCE(s.heap_blks_read, 0) + COALESCE(s.toast_blks_read, 0) AS heap_blks_read
+    , COALESCE(s.idx_blks_hit, 0) + COALESCE(s.tidx_blks_hit, 0) AS idx_blks_hit
+    , COALESCE(s.idx_blks_read, 0) + COALESCE(s.tidx_blks_read, 0) AS idx_blks_read
+  FROM pg_statio_user_tables AS s
+)
+
+SELECT
+  t.schema_name
+  , t.table_name
+  , t.heap_blks_hit::bigint AS heap_blks_hit
+  , t.heap_blks_read::bigint AS heap_blks_read
+  , t.idx_blks_hit::bigint AS idx_blks_hit
+  , t.idx_blks_read::bigint AS idx_blks_read
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(t.relid) AS total_size_bytes
+FROM table_io AS t
+INNER JOIN pg_class AS c ON t.relid = c.oid
+WHERE t.heap_blks_read + t.idx_blks_read >= 10000
+ORDER BY t.heap_blks_read + t.idx_blks_read DESC
+`
+
+type TableCacheEfficiencyRow struct {
+	SchemaName     string
+	TableName      string
+	HeapBlksHit    int64
+	HeapBlksRead   int64
+	IdxBlksHit     int64
+	IdxBlksRead    int64
+	EstimatedRows  int64
+	TotalSizeBytes pgtype.Int8
+}
+
+// Returns the tables reading the most blocks from outside shared_buffers,
+// with heap (including TOAST) and index blocks counted separately.
+// Tables with fewer than 10,000 blocks read (80MB) are too cold to matter.
+// Not limited: the check applies the table scope and size floors before capping the list.
+func (q *Queries) TableCacheEfficiency(ctx context.Context) ([]TableCacheEfficiencyRow, error) {
+	rows, err := q.db.Query(ctx, tableCacheEfficiency)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TableCacheEfficiencyRow
+	for rows.Next() {
+		var i TableCacheEfficiencyRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.HeapBlksHit,
+			&i.HeapBlksRead,
+			&i.IdxBlksHit,
+			&i.IdxBlksRead,
+			&i.EstimatedRows,
+			&i.TotalSizeBytes,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const tableFreezeAge = `-- name: TableFreezeAge :many
 SELECT
   (n.nspname || '.' || c.relname)::text AS table_name
@@ -1966,10 +5131,9 @@ INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
 LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
 WHERE
   c.relkind = 'r'
-  AND n.nspname = 'public'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
   AND c.relfrozenxid != '0'
 ORDER BY age(c.relfrozenxid) DESC
-LIMIT 50
 `
 
 type TableFreezeAgeRow struct {
@@ -1983,7 +5147,9 @@ type TableFreezeAgeRow struct {
 	TableSizeBytes  pgtype.Int8
 }
 
-// Gets transaction ID age for tables with oldest frozen XIDs.
+// Gets transaction ID age for all tables, oldest first.
+// Not limited: the check applies the table scope (--schemas, --exclude-schemas,
+// --include-tables, --exclude-tables) before capping the list.
 func (q *Queries) TableFreezeAge(ctx context.Context) ([]TableFreezeAgeRow, error) {
 	rows, err := q.db.Query(ctx, tableFreezeAge)
 	if err != nil {
@@ -2013,6 +5179,172 @@ func (q *Queries) TableFreezeAge(ctx context.Context) ([]TableFreezeAgeRow, erro
 	return items, nil
 }
 
+const tableIndexSizes = `-- name: TableIndexSizes :many
+SELECT
+  s.schemaname::text AS schema_name
+  , s.relname::text AS table_name
+  , s.indexrelname::text AS index_name
+  , pg_relation_size(s.indexrelid) AS index_size_bytes
+  , s.idx_scan::bigint AS idx_scan
+  , i.indisunique AS is_unique
+  , i.indisprimary AS is_primary
+FROM pg_stat_user_indexes AS s
+INNER JOIN pg_index AS i ON s.indexrelid = i.indexrelid
+ORDER BY s.schemaname, s.relname, index_size_bytes DESC
+`
+
+type TableIndexSizesRow struct {
+	SchemaName     string
+	TableName      string
+	IndexName      string
+	IndexSizeBytes int64
+	IdxScan        int64
+	IsUnique       bool
+	IsPrimary      bool
+}
+
+// Every index on a user table with its size, how often it was scanned, and
+// whether it backs a primary key or unique constraint, to break down the
+// index size of the tables this check flags.
+func (q *Queries) TableIndexSizes(ctx context.Context) ([]TableIndexSizesRow, error) {
+	rows, err := q.db.Query(ctx, tableIndexSizes)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TableIndexSizesRow
+	for rows.Next() {
+		var i TableIndexSizesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.IndexName,
+			&i.IndexSizeBytes,
+			&i.IdxScan,
+			&i.IsUnique,
+			&i.IsPrimary,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const tableMultixactAge = `-- name: TableMultixactAge :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , mxid_age(c.relminmxid)::bigint AS multixact_age
+  , pg_total_relation_size(c.oid) AS table_size_bytes
+  , GREATEST(s.last_autovacuum, s.last_vacuum) AS last_vacuum
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND c.relminmxid != '0'
+ORDER BY mxid_age(c.relminmxid) DESC
+`
+
+type TableMultixactAgeRow struct {
+	SchemaName     string
+	TableName      string
+	MultixactAge   int64
+	TableSizeBytes int64
+	LastVacuum     pgtype.Timestamptz
+}
+
+// Gets multixact ID age for all tables, oldest first.
+// Not limited: the check applies the table scope (--schemas, --exclude-schemas,
+// --include-tables, --exclude-tables) before capping the list.
+func (q *Queries) TableMultixactAge(ctx context.Context) ([]TableMultixactAgeRow, error) {
+	rows, err := q.db.Query(ctx, tableMultixactAge)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TableMultixactAgeRow
+	for rows.Next() {
+		var i TableMultixactAgeRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.MultixactAge,
+			&i.TableSizeBytes,
+			&i.LastVacuum,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const tableReloptions = `-- name: TableReloptions :many
+SELECT
+  (n.nspname || '.' || c.relname)::text AS table_name
+  , ARRAY_TO_STRING(c.reloptions, ',') AS reloptions
+  , ARRAY_TO_STRING(t.reloptions, ',') AS toast_reloptions
+  , (c.reltoastrelid <> 0) AS has_toast
+  , COALESCE(s.n_live_tup, GREATEST(c.reltuples, 0)::bigint) AS estimated_rows
+  , COALESCE(s.n_dead_tup, 0) AS n_dead_tup
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_class AS t ON c.reltoastrelid = t.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relkind IN ('r', 'p', 'm')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND (c.reloptions IS NOT NULL OR t.reloptions IS NOT NULL)
+ORDER BY n.nspname, c.relname
+`
+
+type TableReloptionsRow struct {
+	TableName       pgtype.Text
+	Reloptions      pgtype.Text
+	ToastReloptions pgtype.Text
+	HasToast        bool
+	EstimatedRows   pgtype.Int8
+	NDeadTup        pgtype.Int8
+}
+
+// Tables with per-table storage parameters (reloptions) on the table or its
+// TOAST relation, with the stats needed to judge autovacuum overrides.
+func (q *Queries) TableReloptions(ctx context.Context) ([]TableReloptionsRow, error) {
+	rows, err := q.db.Query(ctx, tableReloptions)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TableReloptionsRow
+	for rows.Next() {
+		var i TableReloptionsRow
+		if err := rows.Scan(
+			&i.TableName,
+			&i.Reloptions,
+			&i.ToastReloptions,
+			&i.HasToast,
+			&i.EstimatedRows,
+			&i.NDeadTup,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const tableVacuumHealth = `-- name: TableVacuumHealth :many
 SELECT
   (n.nspname || '.' || c.relname)::text AS table_name
@@ -2029,12 +5361,17 @@ SELECT
   , COALESCE(s.autoanalyze_count, 0) AS autoanalyze_count
   -- PG14+ columns for insert tracking (will be 0 on older versions via COALESCE)
   , COALESCE(s.n_ins_since_vacuum, 0) AS n_ins_since_vacuum
+  -- Overrides on the TOAST relation (toast.autovacuum_enabled and friends)
+  , ARRAY_TO_STRING(t.reloptions, ',') AS toast_reloptions
+  , n.nspname::text AS schema_name
+  , c.relname::text AS relname
 FROM pg_class AS c
 INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_class AS t ON c.reltoastrelid = t.oid
 LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
 WHERE
   c.relkind IN ('r', 'p')
-  AND n.nspname = 'public'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
 ORDER BY COALESCE(s.n_live_tup, c.reltuples::bigint) DESC
 `
 
@@ -2051,6 +5388,9 @@ type TableVacuumHealthRow struct {
 	NModSinceAnalyze pgtype.Int8
 	AutoanalyzeCount pgtype.Int8
 	NInsSinceVacuum  pgtype.Int8
+	ToastReloptions  pgtype.Text
+	SchemaName       pgtype.Text
+	Relname          pgtype.Text
 }
 
 // Returns all tables with vacuum-related health metrics.
@@ -2077,6 +5417,60 @@ func (q *Queries) TableVacuumHealth(ctx context.Context) ([]TableVacuumHealthRow
 			&i.NModSinceAnalyze,
 			&i.AutoanalyzeCount,
 			&i.NInsSinceVacuum,
+			&i.ToastReloptions,
+			&i.SchemaName,
+			&i.Relname,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const tempFiles = `-- name: TempFiles :many
+SELECT
+  ts.spcname::text AS tablespace
+  , f.name::text AS file_name
+  , f.size AS size_bytes
+  , EXTRACT(EPOCH FROM (NOW() - f.modification))::bigint AS age_seconds
+  , (a.pid IS NOT NULL) AS backend_alive
+FROM pg_tablespace AS ts
+CROSS JOIN LATERAL pg_ls_tmpdir(ts.oid) AS f
+LEFT JOIN pg_stat_activity AS a
+  ON SUBSTRING(f.name FROM '^pgsql_tmp([0-9]+)')::integer = a.pid
+WHERE ts.spcname <> 'pg_global'
+ORDER BY f.size DESC
+`
+
+type TempFilesRow struct {
+	Tablespace   string
+	FileName     pgtype.Text
+	SizeBytes    pgtype.Int8
+	AgeSeconds   pgtype.Int8
+	BackendAlive bool
+}
+
+// Temporary files in every tablespace's pgsql_tmp directory, with whether the
+// backend that created them (pgsql_tmp<PID>.<N>) is still running.
+func (q *Queries) TempFiles(ctx context.Context) ([]TempFilesRow, error) {
+	rows, err := q.db.Query(ctx, tempFiles)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TempFilesRow
+	for rows.Next() {
+		var i TempFilesRow
+		if err := rows.Scan(
+			&i.Tablespace,
+			&i.FileName,
+			&i.SizeBytes,
+			&i.AgeSeconds,
+			&i.BackendAlive,
 		); err != nil {
 			return nil, err
 		}
@@ -2187,6 +5581,74 @@ func (q *Queries) TempUsage(ctx context.Context) (TempUsageRow, error) {
 	return i, err
 }
 
+const timezoneOverrides = `-- name: TimezoneOverrides :many
+SELECT
+  COALESCE(r.rolname, '')::text AS role_name
+  , COALESCE(d.datname, '')::text AS database_name
+  , substring(cfg FROM position('=' IN cfg) + 1)::text AS setting_value
+FROM pg_db_role_setting AS drs
+CROSS JOIN LATERAL unnest(coalesce(drs.setconfig, ARRAY[]::text [])) AS cfg
+LEFT JOIN pg_roles AS r ON drs.setrole = r.oid
+LEFT JOIN pg_database AS d ON drs.setdatabase = d.oid
+WHERE lower(split_part(cfg, '=', 1)) = 'timezone'
+ORDER BY database_name, role_name
+`
+
+type TimezoneOverridesRow struct {
+	RoleName     string
+	DatabaseName string
+	SettingValue string
+}
+
+// TimeZone set with ALTER ROLE or ALTER DATABASE, for any role and database.
+// role_name and database_name are empty when the setting applies to all
+// roles or to all databases. log_timezone can only be set server-wide.
+func (q *Queries) TimezoneOverrides(ctx context.Context) ([]TimezoneOverridesRow, error) {
+	rows, err := q.db.Query(ctx, timezoneOverrides)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TimezoneOverridesRow
+	for rows.Next() {
+		var i TimezoneOverridesRow
+		if err := rows.Scan(&i.RoleName, &i.DatabaseName, &i.SettingValue); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const toastCompressionSupport = `-- name: ToastCompressionSupport :one
+SELECT
+  coalesce(
+    (SELECT s.setting FROM pg_settings AS s WHERE s.name = 'default_toast_compression')
+    , ''
+  )::text AS default_compression
+  , coalesce(
+    (SELECT 'lz4' = ANY(s.enumvals) FROM pg_settings AS s WHERE s.name = 'default_toast_compression')
+    , false
+  ) AS lz4_available
+`
+
+type ToastCompressionSupportRow struct {
+	DefaultCompression string
+	Lz4Available       bool
+}
+
+// The default TOAST compression and whether the server was built with lz4.
+// default_toast_compression exists since PostgreSQL 14: earlier servers return '' and false.
+func (q *Queries) ToastCompressionSupport(ctx context.Context) (ToastCompressionSupportRow, error) {
+	row := q.db.QueryRow(ctx, toastCompressionSupport)
+	var i ToastCompressionSupportRow
+	err := row.Scan(&i.DefaultCompression, &i.Lz4Available)
+	return i, err
+}
+
 const toastStorage = `-- name: ToastStorage :many
 WITH toast_info AS (
   SELECT
@@ -2353,6 +5815,280 @@ func (q *Queries) ToastStorage(ctx context.Context) ([]ToastStorageRow, error) {
 	return items, nil
 }
 
+const topStatements = `-- name: TopStatements :many
+WITH statements AS (
+  SELECT
+    s.queryid
+    , s.query
+    , s.calls
+    , s.total_exec_time
+    , s.mean_exec_time
+    , s.rows
+    , SUM(s.total_exec_time) OVER () AS all_exec_time
+    , SUM(s.calls) OVER () AS all_calls
+    , RANK() OVER (ORDER BY s.total_exec_time DESC) AS total_rank
+    , RANK() OVER (ORDER BY CASE WHEN s.calls >= 10 THEN s.mean_exec_time END DESC NULLS LAST) AS mean_rank
+    , RANK() OVER (ORDER BY s.calls DESC) AS calls_rank
+  FROM pg_stat_statements AS s
+  WHERE s.dbid = (SELECT d.oid FROM pg_database AS d WHERE d.datname = current_database())
+)
+
+SELECT
+  COALESCE(queryid, 0)::bigint AS query_id
+  , LEFT(REGEXP_REPLACE(query, '\s+', ' ', 'g'), 120)::text AS query
+  , calls::bigint AS calls
+  , total_exec_time::double precision AS total_exec_time_ms
+  , mean_exec_time::double precision AS mean_exec_time_ms
+  , rows::bigint AS rows_returned
+  , all_exec_time::double precision AS all_exec_time_ms
+  , all_calls::bigint AS all_calls
+FROM statements
+WHERE total_rank <= 10 OR mean_rank <= 10 OR calls_rank <= 10
+ORDER BY total_exec_time DESC
+`
+
+type TopStatementsRow struct {
+	QueryID         int64
+	Query           string
+	Calls           int64
+	TotalExecTimeMs float64
+	MeanExecTimeMs  float64
+	RowsReturned    int64
+	AllExecTimeMs   float64
+	AllCalls        int64
+}
+
+// The statements of the current database ranking in the top 10 by total
+// execution time, mean execution time (among those called at least 10 times)
+// or calls, with the database-wide totals to compute each one's share.
+func (q *Queries) TopStatements(ctx context.Context) ([]TopStatementsRow, error) {
+	rows, err := q.db.Query(ctx, topStatements)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []TopStatementsRow
+	for rows.Next() {
+		var i TopStatementsRow
+		if err := rows.Scan(
+			&i.QueryID,
+			&i.Query,
+			&i.Calls,
+			&i.TotalExecTimeMs,
+			&i.MeanExecTimeMs,
+			&i.RowsReturned,
+			&i.AllExecTimeMs,
+			&i.AllCalls,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const unindexedForeignKeys = `-- name: UnindexedForeignKeys :many
+SELECT
+  n.nspname::text AS schema_name
+  , t.relname::text AS table_name
+  , t.relkind::text AS relkind
+  , con.conname::text AS constraint_name
+  , (rn.nspname || '.' || rt.relname)::text AS referenced_table
+  , (
+    SELECT array_agg(a.attname::text ORDER BY k.ord)
+    FROM unnest(con.conkey) WITH ORDINALITY AS k (attnum, ord)
+    INNER JOIN pg_attribute AS a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+  )::text [] AS columns
+  , greatest(t.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(t.oid) AS table_size_bytes
+FROM pg_constraint AS con
+INNER JOIN pg_class AS t ON con.conrelid = t.oid
+INNER JOIN pg_namespace AS n ON t.relnamespace = n.oid
+INNER JOIN pg_class AS rt ON con.confrelid = rt.oid
+INNER JOIN pg_namespace AS rn ON rt.relnamespace = rn.oid
+WHERE
+  con.contype = 'f'
+  AND con.conparentid = 0
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+  AND NOT EXISTS (
+    SELECT 1
+    FROM pg_index AS i
+    WHERE
+      i.indrelid = con.conrelid
+      AND i.indisvalid
+      AND i.indpred IS NULL
+      AND i.indnkeyatts >= cardinality(con.conkey)
+      AND (i.indkey::int2 [])[0:cardinality(con.conkey) - 1] @> con.conkey
+      AND (i.indkey::int2 [])[0:cardinality(con.conkey) - 1] <@ con.conkey
+  )
+ORDER BY table_size_bytes DESC, n.nspname, t.relname, con.conname
+`
+
+type UnindexedForeignKeysRow struct {
+	SchemaName      string
+	TableName       string
+	Relkind         string
+	ConstraintName  string
+	ReferencedTable string
+	Columns         []string
+	EstimatedRows   int64
+	TableSizeBytes  int64
+}
+
+// Foreign keys whose referencing columns are not the leading columns of any
+// valid, non-partial index on the referencing table (in any order).
+// Without one, every DELETE or key UPDATE on the referenced table scans the
+// referencing table to check or cascade, and so does ON DELETE CASCADE.
+// Excludes: system schemas and the copies of a partitioned table's foreign key
+// on its partitions (conparentid), reported once on the parent.
+func (q *Queries) UnindexedForeignKeys(ctx context.Context) ([]UnindexedForeignKeysRow, error) {
+	rows, err := q.db.Query(ctx, unindexedForeignKeys)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []UnindexedForeignKeysRow
+	for rows.Next() {
+		var i UnindexedForeignKeysRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.Relkind,
+			&i.ConstraintName,
+			&i.ReferencedTable,
+			&i.Columns,
+			&i.EstimatedRows,
+			&i.TableSizeBytes,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const unloggedTables = `-- name: UnloggedTables :many
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+  , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+  , pg_total_relation_size(c.oid) AS total_size_bytes
+  , (coalesce(s.seq_scan, 0) + coalesce(s.idx_scan, 0))::bigint AS scans
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+LEFT JOIN pg_stat_user_tables AS s ON c.oid = s.relid
+WHERE
+  c.relpersistence = 'u'
+  AND c.relkind = 'r'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY total_size_bytes DESC, n.nspname, c.relname
+`
+
+type UnloggedTablesRow struct {
+	SchemaName     string
+	TableName      string
+	EstimatedRows  int64
+	TotalSizeBytes int64
+	Scans          int64
+}
+
+// Unlogged tables with their size and how much they are read, to tell scratch
+// tables from ones the application depends on.
+func (q *Queries) UnloggedTables(ctx context.Context) ([]UnloggedTablesRow, error) {
+	rows, err := q.db.Query(ctx, unloggedTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []UnloggedTablesRow
+	for rows.Next() {
+		var i UnloggedTablesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.EstimatedRows,
+			&i.TotalSizeBytes,
+			&i.Scans,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const unusedDatabases = `-- name: UnusedDatabases :many
+SELECT
+  d.datname::text AS database_name
+  , (d.datname = current_database()) AS is_current
+  , CASE
+    WHEN has_database_privilege(d.oid, 'CONNECT') THEN pg_database_size(d.oid)
+  END AS size_bytes
+  , (s.xact_commit + s.xact_rollback)::bigint AS transactions
+  , s.sessions::bigint AS sessions
+  , s.numbackends::bigint AS connections
+  , (
+    EXTRACT(EPOCH FROM (now() - coalesce(s.stats_reset, pg_postmaster_start_time()))) / 86400
+  )::bigint AS stats_age_days
+FROM pg_database AS d
+INNER JOIN pg_stat_database AS s ON d.oid = s.datid
+WHERE d.datallowconn
+  AND NOT d.datistemplate
+  AND d.datname NOT IN ('postgres', 'rdsadmin', 'cloudsqladmin', 'azure_maintenance', 'azure_sys')
+ORDER BY size_bytes DESC NULLS LAST, d.datname
+`
+
+type UnusedDatabasesRow struct {
+	DatabaseName pgtype.Text
+	IsCurrent    pgtype.Bool
+	SizeBytes    pgtype.Int8
+	Transactions pgtype.Int8
+	Sessions     pgtype.Int8
+	Connections  pgtype.Int8
+	StatsAgeDays pgtype.Int8
+}
+
+// Per-database activity since the last statistics reset. Stats are kept per
+// database, so this sees every database in the cluster from one connection.
+// Sizes need CONNECT privilege on the database and are NULL otherwise.
+// Without a recorded reset, the stats window is measured from server start.
+func (q *Queries) UnusedDatabases(ctx context.Context) ([]UnusedDatabasesRow, error) {
+	rows, err := q.db.Query(ctx, unusedDatabases)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []UnusedDatabasesRow
+	for rows.Next() {
+		var i UnusedDatabasesRow
+		if err := rows.Scan(
+			&i.DatabaseName,
+			&i.IsCurrent,
+			&i.SizeBytes,
+			&i.Transactions,
+			&i.Sessions,
+			&i.Connections,
+			&i.StatsAgeDays,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const uuidColumnDefaults = `-- name: UuidColumnDefaults :many
 WITH indexed_columns AS (
   SELECT
@@ -2519,3 +6255,216 @@ func (q *Queries) VacuumSettings(ctx context.Context) ([]VacuumSettingsRow, erro
 	}
 	return items, nil
 }
+
+const walArchiverStatus = `-- name: WalArchiverStatus :one
+SELECT
+  current_setting('archive_mode') AS archive_mode
+  , current_setting('archive_command') AS archive_command
+  , COALESCE(current_setting('archive_library', true), '') AS archive_library
+  , pg_is_in_recovery() AS is_standby
+  , a.archived_count
+  , a.failed_count
+  , a.last_archived_wal
+  , extract(EPOCH FROM (now() - a.last_archived_time))::bigint AS seconds_since_archived
+  , a.last_failed_wal
+  , extract(EPOCH FROM (now() - a.last_failed_time))::bigint AS seconds_since_failed
+  , CASE
+    WHEN pg_is_in_recovery() THEN NULL
+    ELSE pg_walfile_name(pg_current_wal_lsn())
+  END AS current_wal_file
+  , COALESCE((SELECT s.setting::bigint FROM pg_settings AS s WHERE s.name = 'wal_segment_size'), 0) AS wal_segment_size_bytes
+FROM pg_stat_archiver AS a
+`
+
+type WalArchiverStatusRow struct {
+	ArchiveMode          string
+	ArchiveCommand       string
+	ArchiveLibrary       string
+	IsStandby            bool
+	ArchivedCount        int64
+	FailedCount          int64
+	LastArchivedWal      pgtype.Text
+	SecondsSinceArchived pgtype.Int8
+	LastFailedWal        pgtype.Text
+	SecondsSinceFailed   pgtype.Int8
+	CurrentWalFile       pgtype.Text
+	WalSegmentSizeBytes  int64
+}
+
+// Archiver counters from pg_stat_archiver with the archiving settings. The
+// current WAL file is only known on a primary (pg_walfile_name() fails during
+// recovery), and lets the check count segments waiting to be archived.
+func (q *Queries) WalArchiverStatus(ctx context.Context) (WalArchiverStatusRow, error) {
+	row := q.db.QueryRow(ctx, walArchiverStatus)
+	var i WalArchiverStatusRow
+	err := row.Scan(
+		&i.ArchiveMode,
+		&i.ArchiveCommand,
+		&i.ArchiveLibrary,
+		&i.IsStandby,
+		&i.ArchivedCount,
+		&i.FailedCount,
+		&i.LastArchivedWal,
+		&i.SecondsSinceArchived,
+		&i.LastFailedWal,
+		&i.SecondsSinceFailed,
+		&i.CurrentWalFile,
+		&i.WalSegmentSizeBytes,
+	)
+	return i, err
+}
+
+const walRetention = `-- name: WalRetention :one
+WITH position AS (
+  SELECT
+    CASE
+      WHEN pg_is_in_recovery() THEN pg_last_wal_replay_lsn()
+      ELSE pg_current_wal_lsn()
+    END AS lsn
+),
+
+oldest_slot AS (
+  SELECT
+    s.slot_name::text AS slot_name
+    , pg_wal_lsn_diff(p.lsn, s.restart_lsn)::bigint AS retained_bytes
+  FROM pg_replication_slots AS s
+  CROSS JOIN position AS p
+  WHERE
+    s.restart_lsn IS NOT NULL
+    AND s.wal_status IS DISTINCT FROM 'lost'
+  ORDER BY s.restart_lsn
+  LIMIT 1
+)
+
+SELECT
+  pg_is_in_recovery() AS is_standby
+  , pg_size_bytes(current_setting('max_wal_size')) AS max_wal_size_bytes
+  , pg_size_bytes(current_setting('wal_keep_size')) AS wal_keep_size_bytes
+  , pg_size_bytes(current_setting('wal_segment_size')) AS wal_segment_size_bytes
+  , coalesce((SELECT o.slot_name FROM oldest_slot AS o), '')::text AS oldest_slot_name
+  , coalesce((SELECT o.retained_bytes FROM oldest_slot AS o), 0)::bigint AS slot_retained_bytes
+  , CASE
+    WHEN has_function_privilege('pg_ls_waldir()', 'EXECUTE')
+      THEN (SELECT coalesce(sum(w.size), 0) FROM pg_ls_waldir() AS w)::bigint
+  END AS wal_dir_bytes
+  , CASE
+    WHEN has_function_privilege('pg_ls_archive_statusdir()', 'EXECUTE')
+      THEN (SELECT count(*) FROM pg_ls_archive_statusdir() AS a WHERE a.name LIKE '%.ready')::bigint
+  END AS ready_to_archive
+`
+
+type WalRetentionRow struct {
+	IsStandby           bool
+	MaxWalSizeBytes     int64
+	WalKeepSizeBytes    int64
+	WalSegmentSizeBytes int64
+	OldestSlotName      string
+	SlotRetainedBytes   int64
+	WalDirBytes         pgtype.Int8
+	ReadyToArchive      pgtype.Int8
+}
+
+// WAL held back by replication slots, wal_keep_size and segments waiting to
+// be archived, next to the size of pg_wal and max_wal_size. Listing pg_wal and
+// its archive_status directory needs pg_monitor (or superuser); without it
+// those sizes are NULL. On a standby, slots are measured from the last
+// replayed LSN.
+func (q *Queries) WalRetention(ctx context.Context) (WalRetentionRow, error) {
+	row := q.db.QueryRow(ctx, walRetention)
+	var i WalRetentionRow
+	err := row.Scan(
+		&i.IsStandby,
+		&i.MaxWalSizeBytes,
+		&i.WalKeepSizeBytes,
+		&i.WalSegmentSizeBytes,
+		&i.OldestSlotName,
+		&i.SlotRetainedBytes,
+		&i.WalDirBytes,
+		&i.ReadyToArchive,
+	)
+	return i, err
+}
+
+const wideTables = `-- name: WideTables :many
+WITH table_columns AS (
+  SELECT
+    c.oid
+    , n.nspname::text AS schema_name
+    , c.relname::text AS table_name
+    , GREATEST(c.reltuples, 0)::bigint AS estimated_rows
+    , a.attname::text AS column_name
+    , ps.avg_width
+  FROM pg_class AS c
+  INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+  INNER JOIN pg_attribute AS a ON c.oid = a.attrelid
+  LEFT JOIN pg_stats AS ps
+    ON
+      n.nspname = ps.schemaname
+      AND c.relname = ps.tablename
+      AND a.attname = ps.attname
+      AND ps.inherited = (c.relkind = 'p')
+  WHERE
+    c.relkind IN ('r', 'p')
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+    AND a.attnum > 0
+    AND NOT a.attisdropped
+)
+
+SELECT
+  tc.schema_name
+  , tc.table_name
+  , count(*)::bigint AS column_count
+  , coalesce(sum(tc.avg_width), 0)::bigint AS avg_row_width
+  , tc.estimated_rows
+  , pg_table_size(tc.oid) AS table_size_bytes
+  , coalesce(
+    (array_agg(tc.column_name || ':' || tc.avg_width::text ORDER BY tc.avg_width DESC)
+      FILTER (WHERE tc.avg_width IS NOT NULL))[1:3]
+    , ARRAY[]::text []
+  ) AS widest_columns
+FROM table_columns AS tc
+GROUP BY tc.oid, tc.schema_name, tc.table_name, tc.estimated_rows
+ORDER BY tc.schema_name, tc.table_name
+`
+
+type WideTablesRow struct {
+	SchemaName     string
+	TableName      string
+	ColumnCount    int64
+	AvgRowWidth    int64
+	EstimatedRows  int64
+	TableSizeBytes int64
+	WidestColumns  []string
+}
+
+// Column count and average row width (sum of the planner's per-column average
+// widths) for every table, with its three widest columns as name:width.
+// Partitions share their parent's columns and are left out.
+func (q *Queries) WideTables(ctx context.Context) ([]WideTablesRow, error) {
+	rows, err := q.db.Query(ctx, wideTables)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var items []WideTablesRow
+	for rows.Next() {
+		var i WideTablesRow
+		if err := rows.Scan(
+			&i.SchemaName,
+			&i.TableName,
+			&i.ColumnCount,
+			&i.AvgRowWidth,
+			&i.EstimatedRows,
+			&i.TableSizeBytes,
+			&i.WidestColumns,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
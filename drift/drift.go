@@ -0,0 +1,165 @@
+// Package drift captures the configuration of a PostgreSQL server and reports
+// how two servers differ: PostgreSQL version, server settings and installed
+// extension versions. It backs `pgdoctor compare`, e.g. to explain why staging
+// behaves differently from production.
+package drift
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strconv"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+// Kind classifies a difference.
+type Kind string
+
+const (
+	KindVersion   Kind = "version"
+	KindSetting   Kind = "setting"
+	KindExtension Kind = "extension"
+)
+
+// Queries is the subset of db.Queries needed to capture a server's configuration.
+type Queries interface {
+	DriftServerVersion(context.Context) (string, error)
+	DriftSettings(context.Context) ([]db.DriftSettingsRow, error)
+	DriftExtensions(context.Context) ([]db.DriftExtensionsRow, error)
+}
+
+// State is the configuration of one server.
+type State struct {
+	ServerVersion string
+	Settings      map[string]Setting // Keyed by setting name
+	Extensions    map[string]string  // Extension name -> installed version
+}
+
+// Setting is a raw pg_settings value and its unit (e.g. "16384" in "8kB" pages).
+type Setting struct {
+	Value string
+	Unit  string
+}
+
+// String renders the value in its natural unit, e.g. "128.0MiB" or "200ms".
+func (s Setting) String() string {
+	return formatSetting(s.Value, s.Unit)
+}
+
+// Difference is one value that is not the same on both servers.
+// Source or Target is empty when the item only exists on the other server.
+type Difference struct {
+	Kind   Kind
+	Name   string
+	Source string
+	Target string
+}
+
+// Capture reads the configuration of the server behind queries.
+func Capture(ctx context.Context, queries Queries) (*State, error) {
+	version, err := queries.DriftServerVersion(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("reading server version: %w", err)
+	}
+
+	settings, err := queries.DriftSettings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("reading settings: %w", err)
+	}
+
+	extensions, err := queries.DriftExtensions(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("reading extensions: %w", err)
+	}
+
+	state := &State{
+		ServerVersion: version,
+		Settings:      make(map[string]Setting, len(settings)),
+		Extensions:    make(map[string]string, len(extensions)),
+	}
+	for _, s := range settings {
+		state.Settings[s.Name.String] = Setting{Value: s.Value.String, Unit: s.Unit.String}
+	}
+	for _, e := range extensions {
+		state.Extensions[e.Name] = e.Version
+	}
+	return state, nil
+}
+
+// Compare returns every difference between source and target: the version
+// first, then settings and extensions sorted by name. Settings are compared on
+// their raw values, so rounding in the rendered sizes never hides a difference.
+func Compare(source, target *State) []Difference {
+	var diffs []Difference
+	if source.ServerVersion != target.ServerVersion {
+		diffs = append(diffs, Difference{Kind: KindVersion, Name: "server_version", Source: source.ServerVersion, Target: target.ServerVersion})
+	}
+
+	for _, name := range unionKeys(source.Settings, target.Settings) {
+		s, inSource := source.Settings[name]
+		t, inTarget := target.Settings[name]
+		if inSource && inTarget && s == t {
+			continue
+		}
+		diff := Difference{Kind: KindSetting, Name: name}
+		if inSource {
+			diff.Source = s.String()
+		}
+		if inTarget {
+			diff.Target = t.String()
+		}
+		diffs = append(diffs, diff)
+	}
+
+	for _, name := range unionKeys(source.Extensions, target.Extensions) {
+		if source.Extensions[name] != target.Extensions[name] {
+			diffs = append(diffs, Difference{Kind: KindExtension, Name: name, Source: source.Extensions[name], Target: target.Extensions[name]})
+		}
+	}
+	return diffs
+}
+
+// unionKeys returns the keys present in either map, sorted.
+func unionKeys[V any](a, b map[string]V) []string {
+	keys := make([]string, 0, len(a)+len(b))
+	for key := range a {
+		keys = append(keys, key)
+	}
+	for key := range b {
+		if _, ok := a[key]; !ok {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+// memoryUnits converts pg_settings memory units to bytes.
+var memoryUnits = map[string]int64{
+	"B":    1,
+	"kB":   check.KiB,
+	"8kB":  8 * check.KiB,
+	"16kB": 16 * check.KiB,
+	"32kB": 32 * check.KiB,
+	"64kB": 64 * check.KiB,
+	"MB":   check.MiB,
+	"16MB": 16 * check.MiB,
+	"GB":   check.GiB,
+}
+
+// formatSetting renders a pg_settings value with its unit, so "16384" in
+// 8kB pages reads as "128.0MiB" and the same size compares equal on both sides.
+func formatSetting(value, unit string) string {
+	if unit == "" {
+		return value
+	}
+	if factor, ok := memoryUnits[unit]; ok {
+		if n, err := strconv.ParseInt(value, 10, 64); err == nil && n >= 0 {
+			return check.FormatBytes(n * factor)
+		}
+		return value
+	}
+	return value + unit
+}
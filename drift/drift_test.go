@@ -0,0 +1,99 @@
+package drift_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/drift"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	version    string
+	settings   []db.DriftSettingsRow
+	extensions []db.DriftExtensionsRow
+	err        error
+}
+
+func (m *mockQueryer) DriftServerVersion(context.Context) (string, error) {
+	return m.version, m.err
+}
+
+func (m *mockQueryer) DriftSettings(context.Context) ([]db.DriftSettingsRow, error) {
+	return m.settings, nil
+}
+
+func (m *mockQueryer) DriftExtensions(context.Context) ([]db.DriftExtensionsRow, error) {
+	return m.extensions, nil
+}
+
+func setting(name, value, unit string) db.DriftSettingsRow {
+	return db.DriftSettingsRow{
+		Name:  pgtype.Text{String: name, Valid: true},
+		Value: pgtype.Text{String: value, Valid: true},
+		Unit:  pgtype.Text{String: unit, Valid: unit != ""},
+	}
+}
+
+func TestCompare(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	production, err := drift.Capture(ctx, &mockQueryer{
+		version: "16.4",
+		settings: []db.DriftSettingsRow{
+			setting("shared_buffers", "524288", "8kB"),
+			setting("work_mem", "16384", "kB"),
+			setting("statement_timeout", "0", "ms"),
+			setting("jit", "on", ""),
+		},
+		extensions: []db.DriftExtensionsRow{{Name: "pg_stat_statements", Version: "1.10"}, {Name: "postgis", Version: "3.4.2"}},
+	})
+	require.NoError(t, err)
+
+	staging, err := drift.Capture(ctx, &mockQueryer{
+		version: "16.2",
+		settings: []db.DriftSettingsRow{
+			setting("shared_buffers", "16384", "8kB"),
+			setting("work_mem", "16384", "kB"),
+			setting("statement_timeout", "30000", "ms"),
+			setting("jit", "on", ""),
+			setting("random_page_cost", "1.1", ""),
+		},
+		extensions: []db.DriftExtensionsRow{{Name: "pg_stat_statements", Version: "1.11"}},
+	})
+	require.NoError(t, err)
+
+	assert.Equal(t, []drift.Difference{
+		{Kind: drift.KindVersion, Name: "server_version", Source: "16.4", Target: "16.2"},
+		{Kind: drift.KindSetting, Name: "random_page_cost", Source: "", Target: "1.1"},
+		{Kind: drift.KindSetting, Name: "shared_buffers", Source: "4.0GiB", Target: "128.0MiB"},
+		{Kind: drift.KindSetting, Name: "statement_timeout", Source: "0ms", Target: "30000ms"},
+		{Kind: drift.KindExtension, Name: "pg_stat_statements", Source: "1.10", Target: "1.11"},
+		{Kind: drift.KindExtension, Name: "postgis", Source: "3.4.2", Target: ""},
+	}, drift.Compare(production, staging))
+
+	assert.Empty(t, drift.Compare(production, production))
+}
+
+func TestCompare_RawValuesNotRounded(t *testing.T) {
+	t.Parallel()
+
+	a := &drift.State{Settings: map[string]drift.Setting{"work_mem": {Value: "4096", Unit: "kB"}}}
+	b := &drift.State{Settings: map[string]drift.Setting{"work_mem": {Value: "4097", Unit: "kB"}}}
+
+	diffs := drift.Compare(a, b)
+	require.Len(t, diffs, 1)
+	assert.Equal(t, "work_mem", diffs[0].Name)
+}
+
+func TestCapture_Error(t *testing.T) {
+	t.Parallel()
+
+	_, err := drift.Capture(context.Background(), &mockQueryer{err: errors.New("connection refused")})
+	require.Error(t, err)
+}
@@ -0,0 +1,46 @@
+-- name: DriftServerVersion :one
+SELECT
+  current_setting('server_version') AS server_version;
+
+-- name: DriftSettings :many
+-- Server-level configuration. reset_val ignores SETs made by this session, and
+-- settings that differ by design between hosts (paths, identity) are left out.
+SELECT
+  name
+  , reset_val AS value
+  , unit
+FROM pg_settings
+WHERE
+  name NOT IN (
+    'application_name'
+    , 'cluster_name'
+    , 'config_file'
+    , 'data_directory'
+    , 'external_pid_file'
+    , 'hba_file'
+    , 'ident_file'
+    , 'in_hot_standby'
+    , 'krb_server_keyfile'
+    , 'listen_addresses'
+    , 'log_directory'
+    , 'port'
+    , 'primary_conninfo'
+    , 'primary_slot_name'
+    , 'server_version'
+    , 'server_version_num'
+    , 'ssl_ca_file'
+    , 'ssl_cert_file'
+    , 'ssl_crl_dir'
+    , 'ssl_crl_file'
+    , 'ssl_key_file'
+    , 'transaction_read_only'
+    , 'unix_socket_directories'
+  )
+ORDER BY name;
+
+-- name: DriftExtensions :many
+SELECT
+  extname::text AS name
+  , extversion AS version
+FROM pg_extension
+ORDER BY extname;
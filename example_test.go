@@ -0,0 +1,46 @@
+package pgdoctor_test
+
+import (
+	"context"
+	"fmt"
+	"os"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/jackc/pgx/v5"
+)
+
+// Embedding pgdoctor in another service, e.g. an internal admin endpoint:
+// pick the checks, run them on a connection and handle each report.
+func ExampleRun() {
+	ctx := context.Background()
+
+	conn, err := pgx.Connect(ctx, os.Getenv("DATABASE_URL"))
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "connect: %v\n", err)
+		return
+	}
+	defer conn.Close(ctx)
+
+	// Keep every query short; see DefaultStatementTimeoutMs.
+	if _, err := conn.Exec(ctx, fmt.Sprintf("SET statement_timeout = %d", pgdoctor.DefaultStatementTimeoutMs)); err != nil {
+		fmt.Fprintf(os.Stderr, "set statement_timeout: %v\n", err)
+		return
+	}
+
+	var reports []*check.Report
+	pgdoctor.Run(ctx, conn, pgdoctor.Options{
+		Checks:       pgdoctor.Filter(pgdoctor.AllChecks(), []string{"configs", "invalid-indexes"}, nil),
+		CheckTimeout: pgdoctor.DefaultCheckTimeout,
+		Retry:        pgdoctor.DefaultRetryPolicy,
+		OnReport:     pgdoctor.Collect(&reports),
+	})
+
+	for _, report := range reports {
+		for _, finding := range report.Results {
+			if finding.Severity >= check.SeverityWarn {
+				fmt.Fprintf(os.Stdout, "%s/%s: %s\n", report.CheckID, finding.ID, finding.Details)
+			}
+		}
+	}
+}
@@ -0,0 +1,19 @@
+// Package checktest holds helpers shared by the tests of the check packages.
+package checktest
+
+import (
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+)
+
+// Finding returns the finding with the given ID, failing the test when the
+// report has none.
+func Finding(t testing.TB, report *check.Report, id string) *check.Finding {
+	t.Helper()
+	finding := report.Finding(id)
+	if finding == nil {
+		t.Fatalf("finding %q not found", id)
+	}
+	return finding
+}
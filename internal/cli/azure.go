@@ -0,0 +1,132 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"os/exec"
+	"strings"
+	"time"
+)
+
+// azureDatabaseResource is the Entra ID resource whose tokens Azure Database for
+// PostgreSQL accepts as passwords.
+const azureDatabaseResource = "https://ossrdbms-aad.database.windows.net"
+
+// azureTokenSource obtains Entra ID (Azure AD) access tokens for Azure Database
+// for PostgreSQL. Tokens live about an hour, so the session asks for a new one on
+// every connection instead of reusing the first.
+type azureTokenSource struct {
+	client    *http.Client
+	authority string // Entra ID endpoint, e.g. https://login.microsoftonline.com
+	imds      string // Instance metadata endpoint for managed identities
+}
+
+func newAzureTokenSource() *azureTokenSource {
+	authority := os.Getenv("AZURE_AUTHORITY_HOST")
+	if authority == "" {
+		authority = "https://login.microsoftonline.com"
+	}
+	return &azureTokenSource{
+		client:    &http.Client{Timeout: 30 * time.Second},
+		authority: strings.TrimSuffix(authority, "/"),
+		imds:      "http://169.254.169.254",
+	}
+}
+
+// token returns an access token from the first credential available, in the
+// order of the Azure SDKs' DefaultAzureCredential: a client secret or workload
+// identity from the environment, a managed identity, then the Azure CLI login.
+func (a *azureTokenSource) token(ctx context.Context) (string, error) {
+	tenant, clientID := os.Getenv("AZURE_TENANT_ID"), os.Getenv("AZURE_CLIENT_ID")
+	if secret := os.Getenv("AZURE_CLIENT_SECRET"); secret != "" && tenant != "" && clientID != "" {
+		return a.clientCredentialsToken(ctx, tenant, url.Values{"client_id": {clientID}, "client_secret": {secret}})
+	}
+	if file := os.Getenv("AZURE_FEDERATED_TOKEN_FILE"); file != "" && tenant != "" && clientID != "" {
+		assertion, err := os.ReadFile(file)
+		if err != nil {
+			return "", fmt.Errorf("azure workload identity: %w", err)
+		}
+		return a.clientCredentialsToken(ctx, tenant, url.Values{
+			"client_id":             {clientID},
+			"client_assertion_type": {"urn:ietf:params:oauth:client-assertion-type:jwt-bearer"},
+			"client_assertion":      {strings.TrimSpace(string(assertion))},
+		})
+	}
+	if token, err := a.managedIdentityToken(ctx, clientID); err == nil {
+		return token, nil
+	}
+
+	out, err := exec.CommandContext(ctx, "az", "account", "get-access-token",
+		"--resource-type", "oss-rdbms", "--query", "accessToken", "--output", "tsv").Output()
+	if err != nil {
+		return "", fmt.Errorf("no Azure credentials: set AZURE_TENANT_ID, AZURE_CLIENT_ID and AZURE_CLIENT_SECRET, run with a managed identity or log in with az login (%w)", err)
+	}
+	return strings.TrimSpace(string(out)), nil
+}
+
+// clientCredentialsToken runs the OAuth2 client credentials flow for an app
+// registration, authenticated by a secret or a federated assertion.
+func (a *azureTokenSource) clientCredentialsToken(ctx context.Context, tenant string, credentials url.Values) (string, error) {
+	form := url.Values{
+		"grant_type": {"client_credentials"},
+		"scope":      {azureDatabaseResource + "/.default"},
+	}
+	for key, values := range credentials {
+		form[key] = values
+	}
+
+	endpoint := fmt.Sprintf("%s/%s/oauth2/v2.0/token", a.authority, url.PathEscape(tenant))
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, strings.NewReader(form.Encode()))
+	if err != nil {
+		return "", err
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	return a.fetch(req, "entra id")
+}
+
+// managedIdentityToken asks the instance metadata service for the token of the
+// VM's or container's managed identity; clientID picks a user-assigned one.
+func (a *azureTokenSource) managedIdentityToken(ctx context.Context, clientID string) (string, error) {
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+
+	query := url.Values{"api-version": {"2018-02-01"}, "resource": {azureDatabaseResource}}
+	if clientID != "" {
+		query.Set("client_id", clientID)
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, a.imds+"/metadata/identity/oauth2/token?"+query.Encode(), nil)
+	if err != nil {
+		return "", err
+	}
+	req.Header.Set("Metadata", "true")
+	return a.fetch(req, "managed identity")
+}
+
+// fetch sends a token request and returns the access_token of the response.
+func (a *azureTokenSource) fetch(req *http.Request, source string) (string, error) {
+	resp, err := a.client.Do(req)
+	if err != nil {
+		return "", fmt.Errorf("%s: %w", source, err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 1024))
+		return "", fmt.Errorf("%s: %s: %s", source, resp.Status, strings.TrimSpace(string(detail)))
+	}
+	var token struct {
+		AccessToken string `json:"access_token"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&token); err != nil {
+		return "", fmt.Errorf("%s: %w", source, err)
+	}
+	if token.AccessToken == "" {
+		return "", fmt.Errorf("%s: response has no access_token", source)
+	}
+	return token.AccessToken, nil
+}
@@ -0,0 +1,73 @@
+package cli
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestAzureTokenSource_ClientCredentials(t *testing.T) {
+	t.Parallel()
+
+	var form url.Values
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "/my-tenant/oauth2/v2.0/token", r.URL.Path)
+		if !assert.NoError(t, r.ParseForm()) {
+			return
+		}
+		form = r.PostForm
+		_, _ = w.Write([]byte(`{"token_type": "Bearer", "access_token": "entra-token"}`))
+	}))
+	t.Cleanup(server.Close)
+
+	source := &azureTokenSource{client: server.Client(), authority: server.URL}
+	token, err := source.clientCredentialsToken(context.Background(), "my-tenant", url.Values{"client_id": {"app"}, "client_secret": {"secret"}})
+	require.NoError(t, err)
+	assert.Equal(t, "entra-token", token)
+	assert.Equal(t, "client_credentials", form.Get("grant_type"))
+	assert.Equal(t, "https://ossrdbms-aad.database.windows.net/.default", form.Get("scope"))
+	assert.Equal(t, "secret", form.Get("client_secret"))
+}
+
+func TestAzureTokenSource_ManagedIdentity(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("Metadata") != "true" {
+			http.Error(w, "missing Metadata header", http.StatusBadRequest)
+			return
+		}
+		assert.Equal(t, "https://ossrdbms-aad.database.windows.net", r.URL.Query().Get("resource"))
+		if r.URL.Query().Get("client_id") == "unknown" {
+			http.Error(w, `{"error": "invalid_request", "error_description": "Identity not found"}`, http.StatusBadRequest)
+			return
+		}
+		_, _ = w.Write([]byte(`{"access_token": "msi-token", "expires_in": "3599"}`))
+	}))
+	t.Cleanup(server.Close)
+
+	source := &azureTokenSource{client: server.Client(), imds: server.URL}
+	token, err := source.managedIdentityToken(context.Background(), "")
+	require.NoError(t, err)
+	assert.Equal(t, "msi-token", token)
+
+	_, err = source.managedIdentityToken(context.Background(), "unknown")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "Identity not found")
+}
+
+func TestSession_ConnectFetchesPassword(t *testing.T) {
+	t.Parallel()
+
+	s := &session{
+		dsn:      "postgres://user@127.0.0.1:1/db",
+		password: func(context.Context) (string, error) { return "", assert.AnError },
+	}
+	_, err := s.connect(context.Background())
+	require.ErrorIs(t, err, assert.AnError, "no connection is attempted without a password")
+}
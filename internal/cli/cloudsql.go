@@ -0,0 +1,343 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"crypto/rand"
+	"crypto/rsa"
+	"crypto/tls"
+	"crypto/x509"
+	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"os"
+	"os/exec"
+	"strings"
+	"time"
+)
+
+// cloudSQLAPI is the Cloud SQL Admin API the connector handshake talks to.
+const cloudSQLAPI = "https://sqladmin.googleapis.com"
+
+// cloudSQLPort is where Cloud SQL instances accept connector (mTLS) connections.
+const cloudSQLPort = "3307"
+
+// cloudSQLOptions holds the --cloudsql-* flags.
+type cloudSQLOptions struct {
+	instance string // Instance connection name, project:region:instance
+	ipType   string // public or private
+	iamAuth  bool
+}
+
+// validate checks the instance connection name and IP type.
+func (c cloudSQLOptions) validate() error {
+	if c.instance == "" {
+		if c.iamAuth {
+			return fmt.Errorf("--cloudsql-iam-auth requires --cloudsql-instance")
+		}
+		return nil
+	}
+	if _, err := parseCloudSQLInstance(c.instance); err != nil {
+		return fmt.Errorf("--cloudsql-instance: %w", err)
+	}
+	if c.ipType != "public" && c.ipType != "private" {
+		return fmt.Errorf("--cloudsql-ip-type must be public or private")
+	}
+	return nil
+}
+
+// dialer returns the Cloud SQL dialer for the flags, or nil when no instance is set.
+func (c cloudSQLOptions) dialer(keepalive time.Duration) *cloudSQLDialer {
+	if c.instance == "" {
+		return nil
+	}
+	instance, _ := parseCloudSQLInstance(c.instance) // validated by validateConnection
+	return &cloudSQLDialer{
+		instance:  instance,
+		ipType:    c.ipType,
+		iamAuth:   c.iamAuth,
+		keepalive: keepalive,
+		api:       cloudSQLAPI,
+		port:      cloudSQLPort,
+		client:    &http.Client{Timeout: 30 * time.Second},
+		token:     googleAccessToken,
+	}
+}
+
+// cloudSQLInstance identifies an instance by its connection name.
+type cloudSQLInstance struct {
+	project string // May be domain-scoped, e.g. "example.com:project"
+	region  string
+	name    string
+}
+
+// parseCloudSQLInstance parses "project:region:instance", where project may be
+// domain-scoped ("example.com:project:region:instance").
+func parseCloudSQLInstance(connectionName string) (cloudSQLInstance, error) {
+	parts := strings.Split(connectionName, ":")
+	if len(parts) == 4 {
+		parts = []string{parts[0] + ":" + parts[1], parts[2], parts[3]}
+	}
+	if len(parts) != 3 || parts[0] == "" || parts[1] == "" || parts[2] == "" {
+		return cloudSQLInstance{}, fmt.Errorf("invalid instance connection name %q (expected project:region:instance)", connectionName)
+	}
+	return cloudSQLInstance{project: parts[0], region: parts[1], name: parts[2]}, nil
+}
+
+// cloudSQLDialer opens connections to a Cloud SQL instance the way the Cloud SQL
+// connectors and auth proxy do, so a one-shot run needs no proxy: it fetches the
+// instance's address and CA from the Admin API, has the API sign an ephemeral
+// client certificate, and dials the instance over mutual TLS on port 3307. With
+// IAM authentication the certificate carries the caller's OAuth2 token and the
+// database user (the IAM principal) logs in without a password. It talks to the
+// Admin API directly instead of using cloud.google.com/go/cloudsqlconn, to keep
+// the Google Cloud client libraries out of the build.
+type cloudSQLDialer struct {
+	instance  cloudSQLInstance
+	ipType    string
+	iamAuth   bool
+	keepalive time.Duration
+	api       string // Admin API base URL
+	port      string
+	client    *http.Client
+	token     func(context.Context, *http.Client) (string, error)
+}
+
+// connectSettings is the subset of the Admin API's connectSettings response used to dial.
+type connectSettings struct {
+	ServerCACert struct {
+		Cert string `json:"cert"`
+	} `json:"serverCaCert"`
+	IPAddresses []struct {
+		Type      string `json:"type"`
+		IPAddress string `json:"ipAddress"`
+	} `json:"ipAddresses"`
+	DNSName      string `json:"dnsName"`
+	ServerCAMode string `json:"serverCaMode"`
+}
+
+// address returns the instance IP of the requested type.
+func (s connectSettings) address(ipType string) (string, error) {
+	want := "PRIMARY"
+	if ipType == "private" {
+		want = "PRIVATE"
+	}
+	for _, ip := range s.IPAddresses {
+		if ip.Type == want {
+			return ip.IPAddress, nil
+		}
+	}
+	return "", fmt.Errorf("instance has no %s IP address (see --cloudsql-ip-type)", ipType)
+}
+
+// dial implements pgconn.DialFunc. The network and address pgx computed from the
+// DSN are ignored: the instance connection name decides where to connect.
+func (d *cloudSQLDialer) dial(ctx context.Context, _, _ string) (net.Conn, error) {
+	token, err := d.token(ctx, d.client)
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: %w", err)
+	}
+
+	var settings connectSettings
+	if err := d.call(ctx, http.MethodGet, "connectSettings", token, nil, &settings); err != nil {
+		return nil, err
+	}
+	addr, err := settings.address(d.ipType)
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: %w", err)
+	}
+
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: generating key: %w", err)
+	}
+	publicKey, err := x509.MarshalPKIXPublicKey(&key.PublicKey)
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: encoding public key: %w", err)
+	}
+	request := map[string]string{
+		"public_key": string(pem.EncodeToMemory(&pem.Block{Type: "PUBLIC KEY", Bytes: publicKey})),
+	}
+	if d.iamAuth {
+		request["access_token"] = token
+	}
+	var ephemeral struct {
+		EphemeralCert struct {
+			Cert string `json:"cert"`
+		} `json:"ephemeralCert"`
+	}
+	if err := d.call(ctx, http.MethodPost, ":generateEphemeralCert", token, request, &ephemeral); err != nil {
+		return nil, err
+	}
+
+	tlsConfig, err := d.tlsConfig(settings, ephemeral.EphemeralCert.Cert, key)
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: %w", err)
+	}
+
+	dialer := &net.Dialer{}
+	if d.keepalive > 0 {
+		dialer.KeepAliveConfig = net.KeepAliveConfig{Enable: true, Idle: d.keepalive, Interval: d.keepalive, Count: 3}
+	}
+	conn, err := dialer.DialContext(ctx, "tcp", net.JoinHostPort(addr, d.port))
+	if err != nil {
+		return nil, fmt.Errorf("cloud sql: %w", err)
+	}
+	tlsConn := tls.Client(conn, tlsConfig)
+	if err := tlsConn.HandshakeContext(ctx); err != nil {
+		_ = conn.Close()
+		return nil, fmt.Errorf("cloud sql: TLS handshake: %w", err)
+	}
+	return tlsConn, nil
+}
+
+// tlsConfig presents the ephemeral certificate and verifies the server against
+// the instance CA. Instances with a per-instance CA identify themselves as
+// "project:instance" rather than by host name, so their certificate is checked
+// by hand; instances on a CAS-based CA are verified against their DNS name.
+func (d *cloudSQLDialer) tlsConfig(settings connectSettings, certPEM string, key *rsa.PrivateKey) (*tls.Config, error) {
+	certBlock, _ := pem.Decode([]byte(certPEM))
+	if certBlock == nil {
+		return nil, errors.New("ephemeral certificate is not PEM")
+	}
+	roots := x509.NewCertPool()
+	if !roots.AppendCertsFromPEM([]byte(settings.ServerCACert.Cert)) {
+		return nil, errors.New("server CA certificate is not PEM")
+	}
+	clientCert := tls.Certificate{Certificate: [][]byte{certBlock.Bytes}, PrivateKey: key}
+
+	if strings.HasSuffix(settings.ServerCAMode, "_CAS_CA") && settings.DNSName != "" {
+		return &tls.Config{
+			Certificates: []tls.Certificate{clientCert},
+			RootCAs:      roots,
+			ServerName:   strings.TrimSuffix(settings.DNSName, "."),
+			MinVersion:   tls.VersionTLS12,
+		}, nil
+	}
+
+	wantCN := d.instance.project + ":" + d.instance.name
+	return &tls.Config{
+		Certificates: []tls.Certificate{clientCert},
+		MinVersion:   tls.VersionTLS12,
+		// The standard verification would check a host name the certificate does
+		// not have; VerifyPeerCertificate checks the chain and identity instead.
+		InsecureSkipVerify: true, //nolint:gosec // verified in VerifyPeerCertificate
+		VerifyPeerCertificate: func(rawCerts [][]byte, _ [][]*x509.Certificate) error {
+			return verifyInstanceCert(rawCerts, roots, wantCN)
+		},
+	}, nil
+}
+
+// verifyInstanceCert checks that the server certificate chains to the instance
+// CA and names the instance.
+func verifyInstanceCert(rawCerts [][]byte, roots *x509.CertPool, wantCN string) error {
+	if len(rawCerts) == 0 {
+		return errors.New("server sent no certificate")
+	}
+	cert, err := x509.ParseCertificate(rawCerts[0])
+	if err != nil {
+		return err
+	}
+	if _, err := cert.Verify(x509.VerifyOptions{Roots: roots}); err != nil {
+		return err
+	}
+	if cert.Subject.CommonName != wantCN {
+		return fmt.Errorf("server certificate is for %q, want %q", cert.Subject.CommonName, wantCN)
+	}
+	return nil
+}
+
+// call invokes an Admin API method on the instance and decodes the JSON response.
+func (d *cloudSQLDialer) call(ctx context.Context, method, action, token string, body, out any) error {
+	endpoint := fmt.Sprintf("%s/sql/v1beta4/projects/%s/instances/%s", d.api, d.instance.project, d.instance.name)
+	if strings.HasPrefix(action, ":") {
+		endpoint += action
+	} else {
+		endpoint += "/" + action
+	}
+
+	var reader io.Reader
+	if body != nil {
+		encoded, err := json.Marshal(body)
+		if err != nil {
+			return err
+		}
+		reader = bytes.NewReader(encoded)
+	}
+	req, err := http.NewRequestWithContext(ctx, method, endpoint, reader)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Authorization", "Bearer "+token)
+	req.Header.Set("Content-Type", "application/json")
+
+	resp, err := d.client.Do(req)
+	if err != nil {
+		return fmt.Errorf("cloud sql admin API: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 1024))
+		return fmt.Errorf("cloud sql admin API %s: %s: %s", strings.TrimPrefix(action, ":"), resp.Status, strings.TrimSpace(string(detail)))
+	}
+	if err := json.NewDecoder(resp.Body).Decode(out); err != nil {
+		return fmt.Errorf("cloud sql admin API %s: %w", strings.TrimPrefix(action, ":"), err)
+	}
+	return nil
+}
+
+// googleAccessToken returns an OAuth2 access token for the Admin API and IAM
+// login, from the first source available: CLOUDSDK_AUTH_ACCESS_TOKEN, the
+// metadata server (GCE, GKE, Cloud Run) or the gcloud CLI.
+func googleAccessToken(ctx context.Context, client *http.Client) (string, error) {
+	if token := os.Getenv("CLOUDSDK_AUTH_ACCESS_TOKEN"); token != "" {
+		return token, nil
+	}
+	if token, err := metadataAccessToken(ctx, client); err == nil {
+		return token, nil
+	}
+
+	out, err := exec.CommandContext(ctx, "gcloud", "auth", "print-access-token").Output()
+	if err != nil {
+		return "", fmt.Errorf("no Google credentials: set CLOUDSDK_AUTH_ACCESS_TOKEN, run on Google Cloud or log in with gcloud (%w)", err)
+	}
+	return strings.TrimSpace(string(out)), nil
+}
+
+// metadataAccessToken asks the metadata server for the default service account's token.
+func metadataAccessToken(ctx context.Context, client *http.Client) (string, error) {
+	host := os.Getenv("GCE_METADATA_HOST")
+	if host == "" {
+		host = "metadata.google.internal"
+	}
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet,
+		"http://"+host+"/computeMetadata/v1/instance/service-accounts/default/token", nil)
+	if err != nil {
+		return "", err
+	}
+	req.Header.Set("Metadata-Flavor", "Google")
+	resp, err := client.Do(req)
+	if err != nil {
+		return "", err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return "", fmt.Errorf("metadata server: %s", resp.Status)
+	}
+
+	var token struct {
+		AccessToken string `json:"access_token"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&token); err != nil {
+		return "", err
+	}
+	return token.AccessToken, nil
+}
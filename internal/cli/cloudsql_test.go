@@ -0,0 +1,238 @@
+package cli
+
+import (
+	"context"
+	"crypto/rand"
+	"crypto/rsa"
+	"crypto/tls"
+	"crypto/x509"
+	"crypto/x509/pkix"
+	"encoding/json"
+	"encoding/pem"
+	"math/big"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestParseCloudSQLInstance(t *testing.T) {
+	t.Parallel()
+
+	instance, err := parseCloudSQLInstance("my-project:us-central1:db")
+	require.NoError(t, err)
+	assert.Equal(t, cloudSQLInstance{project: "my-project", region: "us-central1", name: "db"}, instance)
+
+	instance, err = parseCloudSQLInstance("example.com:my-project:europe-west1:db")
+	require.NoError(t, err)
+	assert.Equal(t, "example.com:my-project", instance.project)
+
+	for _, invalid := range []string{"db", "my-project:db", "my-project::db", "a:b:c:d:e"} {
+		_, err := parseCloudSQLInstance(invalid)
+		assert.Error(t, err, invalid)
+	}
+}
+
+func TestCloudSQLOptions_Validate(t *testing.T) {
+	t.Parallel()
+
+	assert.NoError(t, cloudSQLOptions{ipType: "public"}.validate())
+	assert.NoError(t, cloudSQLOptions{instance: "p:r:i", ipType: "private", iamAuth: true}.validate())
+	assert.Error(t, cloudSQLOptions{instance: "p:r:i", ipType: "psc"}.validate())
+	assert.Error(t, cloudSQLOptions{ipType: "public", iamAuth: true}.validate(), "IAM auth without an instance")
+}
+
+// testCA is a certificate authority issuing the instance and client certificates.
+type testCA struct {
+	cert *x509.Certificate
+	key  *rsa.PrivateKey
+	pem  string
+}
+
+func newTestCA(t *testing.T) *testCA {
+	t.Helper()
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	require.NoError(t, err)
+	template := &x509.Certificate{
+		SerialNumber:          big.NewInt(1),
+		Subject:               pkix.Name{CommonName: "Google Cloud SQL Server CA"},
+		NotBefore:             time.Now().Add(-time.Hour),
+		NotAfter:              time.Now().Add(time.Hour),
+		IsCA:                  true,
+		BasicConstraintsValid: true,
+		KeyUsage:              x509.KeyUsageCertSign,
+	}
+	der, err := x509.CreateCertificate(rand.Reader, template, template, &key.PublicKey, key)
+	require.NoError(t, err)
+	cert, err := x509.ParseCertificate(der)
+	require.NoError(t, err)
+	return &testCA{cert: cert, key: key, pem: string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: der}))}
+}
+
+// issue signs a certificate for publicKey with the given common name.
+func (ca *testCA) issue(t *testing.T, commonName string, publicKey any, usage x509.ExtKeyUsage) []byte {
+	t.Helper()
+	template := &x509.Certificate{
+		SerialNumber: big.NewInt(time.Now().UnixNano()),
+		Subject:      pkix.Name{CommonName: commonName},
+		NotBefore:    time.Now().Add(-time.Hour),
+		NotAfter:     time.Now().Add(time.Hour),
+		KeyUsage:     x509.KeyUsageDigitalSignature | x509.KeyUsageKeyEncipherment,
+		ExtKeyUsage:  []x509.ExtKeyUsage{usage},
+	}
+	der, err := x509.CreateCertificate(rand.Reader, template, ca.cert, publicKey, ca.key)
+	require.NoError(t, err)
+	return der
+}
+
+// instanceCert issues the certificate an instance presents, named commonName.
+func (ca *testCA) instanceCert(t *testing.T, commonName string) tls.Certificate {
+	t.Helper()
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	require.NoError(t, err)
+	return tls.Certificate{
+		Certificate: [][]byte{ca.issue(t, commonName, &key.PublicKey, x509.ExtKeyUsageServerAuth)},
+		PrivateKey:  key,
+	}
+}
+
+// fakeCloudSQL starts an instance presenting serverCert and an Admin API that
+// hands out ca as the instance CA and signs client certificates with it. It
+// returns a dialer for my-project:us-central1:db and the last ephemeral
+// certificate request the API received.
+func fakeCloudSQL(t *testing.T, ca *testCA, serverCert tls.Certificate) (*cloudSQLDialer, *map[string]string) {
+	t.Helper()
+
+	// The instance: a TLS listener requiring a client certificate from the CA.
+	clientCAs := x509.NewCertPool()
+	clientCAs.AddCert(ca.cert)
+	listener, err := tls.Listen("tcp", "127.0.0.1:0", &tls.Config{
+		Certificates: []tls.Certificate{serverCert},
+		ClientAuth:   tls.RequireAndVerifyClientCert,
+		ClientCAs:    clientCAs,
+		MinVersion:   tls.VersionTLS12,
+	})
+	require.NoError(t, err)
+	t.Cleanup(func() { _ = listener.Close() })
+	go func() {
+		conn, err := listener.Accept()
+		if err != nil {
+			return
+		}
+		_ = conn.(*tls.Conn).Handshake()
+		_ = conn.Close()
+	}()
+	_, port, err := net.SplitHostPort(listener.Addr().String())
+	require.NoError(t, err)
+
+	// The Admin API: connect settings and an ephemeral certificate for the posted key.
+	var ephemeralRequest map[string]string
+	api := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "Bearer test-token", r.Header.Get("Authorization"))
+		switch r.URL.Path {
+		case "/sql/v1beta4/projects/my-project/instances/db/connectSettings":
+			_ = json.NewEncoder(w).Encode(map[string]any{
+				"serverCaCert": map[string]string{"cert": ca.pem},
+				"ipAddresses":  []map[string]string{{"type": "PRIMARY", "ipAddress": "127.0.0.1"}},
+				"serverCaMode": "GOOGLE_MANAGED_INTERNAL_CA",
+			})
+		case "/sql/v1beta4/projects/my-project/instances/db:generateEphemeralCert":
+			if !assert.NoError(t, json.NewDecoder(r.Body).Decode(&ephemeralRequest)) {
+				return
+			}
+			block, _ := pem.Decode([]byte(ephemeralRequest["public_key"]))
+			if !assert.NotNil(t, block) {
+				return
+			}
+			publicKey, err := x509.ParsePKIXPublicKey(block.Bytes)
+			if !assert.NoError(t, err) {
+				return
+			}
+			cert := ca.issue(t, "client", publicKey, x509.ExtKeyUsageClientAuth)
+			_ = json.NewEncoder(w).Encode(map[string]any{
+				"ephemeralCert": map[string]string{"cert": string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: cert}))},
+			})
+		default:
+			http.NotFound(w, r)
+		}
+	}))
+	t.Cleanup(api.Close)
+
+	return &cloudSQLDialer{
+		instance: cloudSQLInstance{project: "my-project", region: "us-central1", name: "db"},
+		ipType:   "public",
+		iamAuth:  true,
+		api:      api.URL,
+		port:     port,
+		client:   api.Client(),
+		token:    func(context.Context, *http.Client) (string, error) { return "test-token", nil },
+	}, &ephemeralRequest
+}
+
+func TestCloudSQLDialer_Dial(t *testing.T) {
+	t.Parallel()
+
+	ca := newTestCA(t)
+	dialer, ephemeralRequest := fakeCloudSQL(t, ca, ca.instanceCert(t, "my-project:db"))
+
+	conn, err := dialer.dial(context.Background(), "tcp", "ignored:5432")
+	require.NoError(t, err)
+	_ = conn.Close()
+	assert.Equal(t, "test-token", (*ephemeralRequest)["access_token"], "IAM auth embeds the token in the certificate")
+}
+
+// The per-instance CA path skips the standard host name verification, so these
+// dial instances impersonated by a certificate the dialer must refuse.
+func TestCloudSQLDialer_VerifiesServerIdentity(t *testing.T) {
+	t.Parallel()
+
+	t.Run("rejects a certificate for another instance", func(t *testing.T) {
+		t.Parallel()
+
+		ca := newTestCA(t)
+		dialer, _ := fakeCloudSQL(t, ca, ca.instanceCert(t, "my-project:other"))
+
+		_, err := dialer.dial(context.Background(), "tcp", "ignored:5432")
+		require.Error(t, err)
+		assert.Contains(t, err.Error(), `server certificate is for "my-project:other", want "my-project:db"`)
+	})
+
+	t.Run("rejects a certificate from another CA", func(t *testing.T) {
+		t.Parallel()
+
+		ca := newTestCA(t)
+		impostor := newTestCA(t)
+		dialer, _ := fakeCloudSQL(t, ca, impostor.instanceCert(t, "my-project:db"))
+
+		_, err := dialer.dial(context.Background(), "tcp", "ignored:5432")
+		require.Error(t, err)
+		var unknownAuthority x509.UnknownAuthorityError
+		assert.ErrorAs(t, err, &unknownAuthority)
+	})
+
+	t.Run("rejects an empty chain", func(t *testing.T) {
+		t.Parallel()
+
+		roots := x509.NewCertPool()
+		roots.AddCert(newTestCA(t).cert)
+		assert.EqualError(t, verifyInstanceCert(nil, roots, "my-project:db"), "server sent no certificate")
+	})
+}
+
+func TestConnectSettings_Address(t *testing.T) {
+	t.Parallel()
+
+	var settings connectSettings
+	require.NoError(t, json.Unmarshal([]byte(`{"ipAddresses": [{"type": "PRIVATE", "ipAddress": "10.0.0.3"}]}`), &settings))
+
+	addr, err := settings.address("private")
+	require.NoError(t, err)
+	assert.Equal(t, "10.0.0.3", addr)
+
+	_, err = settings.address("public")
+	assert.Error(t, err)
+}
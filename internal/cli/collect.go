@@ -0,0 +1,136 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+)
+
+func newCollectCommand() *cobra.Command {
+	opts := &runOptions{}
+	var outputPath string
+
+	cmd := &cobra.Command{
+		Use:   "collect <DSN> --output <file>",
+		Short: "Capture the catalog and statistics data checks need into a snapshot file",
+		Long: `Run every check's queries against a PostgreSQL database and save the raw
+results to a JSON snapshot, without evaluating anything.
+
+The snapshot can be reviewed and handed over, then evaluated offline with
+'pgdoctor analyze', so the analyst never needs access to the database.`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			dsn, err := opts.resolveDSN(args, "collect")
+			if err != nil {
+				return err
+			}
+			if outputPath == "" {
+				return fmt.Errorf("--output is required")
+			}
+			if err := opts.validateConnection(); err != nil {
+				return err
+			}
+			if opts.checkTimeout < 0 {
+				return fmt.Errorf("--check-timeout must not be negative")
+			}
+
+			ctx := cmd.Context()
+
+			sess := opts.newSession(dsn)
+			if err := sess.open(ctx); err != nil {
+				fmt.Fprintf(os.Stderr, "Error: failed to connect to database: %v\n", err)
+				return &SilentError{ExitCode: 2}
+			}
+			defer sess.close(ctx)
+
+			recorder := snapshot.NewRecorder(sess.conn)
+			// Recorded so that analyze phrases fixes for the same provider.
+			provider := detectProvider(ctx, recorder)
+			pgdoctor.Run(ctx, recorder, pgdoctor.Options{
+				Checks:       pgdoctor.AllChecks(),
+				CheckTimeout: opts.checkTimeout,
+				Retry:        sess.retry,
+				Provider:     provider,
+				Reconnect: func(ctx context.Context) (db.DBTX, error) {
+					conn, err := sess.reconnect(ctx)
+					if err != nil {
+						return nil, err
+					}
+					recorder.SetConn(conn)
+					return recorder, nil
+				},
+			})
+
+			// The snapshot holds raw catalog data; keep it private to the owner.
+			f, err := os.OpenFile(outputPath, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0o600)
+			if err != nil {
+				return fmt.Errorf("creating snapshot: %w", err)
+			}
+			defer f.Close()
+
+			snap := recorder.Snapshot()
+			if err := snap.Write(f); err != nil {
+				return fmt.Errorf("writing snapshot: %w", err)
+			}
+			if err := f.Close(); err != nil {
+				return fmt.Errorf("writing snapshot: %w", err)
+			}
+
+			fmt.Fprintf(cmd.ErrOrStderr(), "Captured %d queries to %s\n", len(snap.Queries), outputPath)
+			return nil
+		},
+	}
+
+	cmd.Flags().StringVar(&outputPath, "output", "", "Snapshot file to write (required)")
+	cmd.Flags().DurationVar(&opts.checkTimeout, "check-timeout", pgdoctor.DefaultCheckTimeout, "Maximum time for a single check before it is skipped (0 disables)")
+	addConnectionFlags(cmd, opts)
+
+	return cmd
+}
+
+func newAnalyzeCommand() *cobra.Command {
+	opts := &runOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "analyze <snapshot.json>",
+		Short: "Evaluate health checks offline against a snapshot",
+		Long: `Evaluate health checks against a snapshot captured with 'pgdoctor collect',
+without connecting to the database.
+
+Accepts the same check selection, scope and output flags as 'pgdoctor run'.
+Checks whose queries are missing from the snapshot (e.g. a snapshot taken by
+an older pgdoctor) are reported as skipped.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			runOpts, err := opts.prepare(cmd)
+			if err != nil {
+				return err
+			}
+
+			f, err := os.Open(args[0])
+			if err != nil {
+				return fmt.Errorf("opening snapshot: %w", err)
+			}
+			defer f.Close()
+
+			snap, err := snapshot.Read(f)
+			if err != nil {
+				return fmt.Errorf("reading %s: %w", args[0], err)
+			}
+
+			label := fmt.Sprintf("%s (captured %s)", args[0], snap.CapturedAt.Format(time.RFC3339))
+			return opts.execute(cmd, snapshot.NewReplayer(snap), runOpts, label)
+		},
+	}
+
+	addReportFlags(cmd, opts)
+
+	return cmd
+}
@@ -0,0 +1,223 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/drift"
+)
+
+// compareSide is one of the two servers being compared.
+type compareSide struct {
+	dsn     string
+	label   string
+	state   *drift.State
+	reports []*check.Report
+}
+
+type jsonCompareSide struct {
+	Label   string       `json:"label"`
+	Version string       `json:"server_version"`
+	Reports []jsonReport `json:"reports"`
+}
+
+type jsonDifference struct {
+	Kind   string `json:"kind"`
+	Name   string `json:"name"`
+	Source string `json:"source"`
+	Target string `json:"target"`
+}
+
+type jsonCompare struct {
+	Source jsonCompareSide  `json:"source"`
+	Target jsonCompareSide  `json:"target"`
+	Drift  []jsonDifference `json:"drift"`
+}
+
+func newCompareCommand() *cobra.Command {
+	opts := &runOptions{}
+	var against string
+
+	cmd := &cobra.Command{
+		Use:   "compare <DSN> --against <DSN>",
+		Short: "Compare the configuration of two PostgreSQL servers",
+		Long: `Run the settings-oriented checks on two servers and report configuration
+drift between them: PostgreSQL version, server settings and installed
+extension versions.
+
+Useful to find out why staging behaves differently from production, or
+whether a replica was provisioned like its primary. The configs category
+runs by default; use --only or --preset to choose other checks.`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			dsn, err := opts.resolveDSN(args, "compare")
+			if err != nil {
+				return err
+			}
+			if against == "" {
+				return fmt.Errorf("--against is required")
+			}
+
+			if err := opts.validateConnection(); err != nil {
+				return err
+			}
+			if opts.cloudSQL.instance != "" {
+				return fmt.Errorf("--cloudsql-instance connects to a single instance; compare needs two DSNs")
+			}
+			if !cmd.Flags().Changed("only") && !cmd.Flags().Changed("preset") {
+				opts.only = []string{string(check.CategoryConfigs)}
+			}
+			runOpts, err := opts.prepare(cmd)
+			if err != nil {
+				return err
+			}
+
+			source := &compareSide{dsn: dsn, label: parseDSNLabel(dsn)}
+			target := &compareSide{dsn: against, label: parseDSNLabel(against)}
+			if source.label == target.label {
+				source.label += " (source)"
+				target.label += " (target)"
+			}
+
+			for _, side := range []*compareSide{source, target} {
+				if err := opts.inspect(cmd, side, runOpts); err != nil {
+					return err
+				}
+			}
+
+			differences := drift.Compare(source.state, target.state)
+
+			w := cmd.OutOrStdout()
+			if opts.output == "json" {
+				if err := writeJSON(w, toJSONCompare(source, target, differences, opts.costPerGB, opts.byteUnits)); err != nil {
+					fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+					return &SilentError{ExitCode: 1}
+				}
+			} else {
+				printDrift(w, source.label, target.label, differences, opts)
+			}
+
+			for _, side := range []*compareSide{source, target} {
+				for _, report := range side.reports {
+					if report.Severity == check.SeverityFail {
+						return &SilentError{ExitCode: 1}
+					}
+				}
+			}
+			return nil
+		},
+	}
+
+	cmd.Flags().StringVar(&against, "against", "", "Connection string of the server to compare with")
+	addReportFlags(cmd, opts)
+	addConnectionFlags(cmd, opts)
+
+	return cmd
+}
+
+// inspect connects to one server, captures its configuration and runs the checks.
+// Text output streams the reports under a header naming the server.
+func (opts *runOptions) inspect(cmd *cobra.Command, side *compareSide, runOpts pgdoctor.Options) error {
+	ctx := cmd.Context()
+
+	sess := opts.newSession(side.dsn)
+	if err := sess.open(ctx); err != nil {
+		fmt.Fprintf(os.Stderr, "Error: failed to connect to %s: %v\n", side.label, err)
+		return &SilentError{ExitCode: 2}
+	}
+	defer sess.close(ctx)
+
+	state, err := drift.Capture(ctx, db.New(sess.conn))
+	if err != nil {
+		return fmt.Errorf("capturing configuration of %s: %w", side.label, err)
+	}
+	side.state = state
+
+	runOpts.Retry = sess.retry
+	runOpts.Reconnect = sess.reconnect
+	runOpts.Provider = detectProvider(ctx, sess.conn)
+
+	if opts.output == "json" {
+		runOpts.OnReport = pgdoctor.Collect(&side.reports)
+		pgdoctor.Run(ctx, sess.conn, runOpts)
+		return nil
+	}
+
+	w := cmd.OutOrStdout()
+	fmt.Fprintf(w, "Database Health Check: %s%s\n\n", side.label, providerSuffix(runOpts.Provider))
+
+	reporter := &textReporter{w: w, opts: opts}
+	runOpts.OnReport = reporter.report
+	pgdoctor.Run(ctx, sess.conn, runOpts)
+	reporter.finish()
+	fmt.Fprintln(w)
+
+	side.reports = reporter.reports
+	return nil
+}
+
+// printDrift renders the differences between the two servers as a table.
+func printDrift(w io.Writer, sourceLabel, targetLabel string, differences []drift.Difference, opts *runOptions) {
+	title := "CONFIGURATION DRIFT"
+	fmt.Fprintln(w, title)
+	fmt.Fprintln(w, strings.Repeat("─", len(title)))
+
+	if len(differences) == 0 {
+		fmt.Fprintf(w, "  %s\n\n", colorForSeverity(check.SeverityOK)("No differences in version, settings or extensions"))
+		return
+	}
+
+	table := &check.Table{
+		Headers: []string{"Kind", "Name", sourceLabel, targetLabel},
+		Rows:    make([]check.TableRow, 0, len(differences)),
+	}
+	for _, d := range differences {
+		table.Rows = append(table.Rows, check.TableRow{
+			Cells:    []string{string(d.Kind), d.Name, orMissing(d.Source), orMissing(d.Target)},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	printTable(w, table, 2, opts)
+	fmt.Fprintln(w)
+	fmt.Fprintf(w, "%d difference(s)\n\n", len(differences))
+}
+
+func orMissing(value string) string {
+	if value == "" {
+		return "(missing)"
+	}
+	return value
+}
+
+func toJSONCompare(source, target *compareSide, differences []drift.Difference, costPerGB float64, units check.ByteUnits) jsonCompare {
+	out := jsonCompare{
+		Source: jsonCompareSide{
+			Label:   source.label,
+			Version: source.state.ServerVersion,
+			Reports: toJSONReports(source.reports, costPerGB, units),
+		},
+		Target: jsonCompareSide{
+			Label:   target.label,
+			Version: target.state.ServerVersion,
+			Reports: toJSONReports(target.reports, costPerGB, units),
+		},
+		Drift: make([]jsonDifference, 0, len(differences)),
+	}
+	for _, d := range differences {
+		out.Drift = append(out.Drift, jsonDifference{
+			Kind:   string(d.Kind),
+			Name:   d.Name,
+			Source: d.Source,
+			Target: d.Target,
+		})
+	}
+	return out
+}
@@ -0,0 +1,40 @@
+package cli
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/emancu/pgdoctor/drift"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestPrintDrift(t *testing.T) {
+	t.Parallel()
+
+	t.Run("lists differences with missing values marked", func(t *testing.T) {
+		t.Parallel()
+
+		var buf bytes.Buffer
+		printDrift(&buf, "prod", "staging", []drift.Difference{
+			{Kind: drift.KindVersion, Name: "server_version", Source: "16.4", Target: "15.8"},
+			{Kind: drift.KindExtension, Name: "pg_stat_statements", Source: "1.10"},
+		}, &runOptions{detail: string(detailBrief)})
+
+		out := buf.String()
+		assert.Contains(t, out, "CONFIGURATION DRIFT")
+		assert.Contains(t, out, "prod")
+		assert.Contains(t, out, "staging")
+		assert.Contains(t, out, "16.4")
+		assert.Contains(t, out, "(missing)")
+		assert.Contains(t, out, "2 difference(s)")
+	})
+
+	t.Run("no differences", func(t *testing.T) {
+		t.Parallel()
+
+		var buf bytes.Buffer
+		printDrift(&buf, "prod", "staging", nil, &runOptions{detail: string(detailBrief)})
+
+		assert.Contains(t, buf.String(), "No differences in version, settings or extensions")
+	})
+}
@@ -0,0 +1,104 @@
+package cli
+
+import (
+	"fmt"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+
+	"golang.org/x/term"
+)
+
+// endpointOptions holds the discrete connection flags, an alternative to
+// spelling the connection out in a URL where special characters in user names
+// or passwords need escaping. Set flags override the DSN's own parameters.
+type endpointOptions struct {
+	host           string
+	port           string
+	user           string
+	dbname         string
+	socketDir      string
+	passwordPrompt bool
+}
+
+// isSet reports whether any flag describes the connection, so that no DSN is needed.
+func (e endpointOptions) isSet() bool {
+	return e.host != "" || e.port != "" || e.user != "" || e.dbname != "" || e.socketDir != ""
+}
+
+// validate checks the port and that TCP and Unix socket flags aren't mixed.
+func (e endpointOptions) validate() error {
+	if e.host != "" && e.socketDir != "" {
+		return fmt.Errorf("--host and --socket-dir cannot be combined")
+	}
+	if e.socketDir != "" && !strings.HasPrefix(e.socketDir, "/") {
+		return fmt.Errorf("--socket-dir must be an absolute path")
+	}
+	if e.port != "" {
+		if port, err := strconv.Atoi(e.port); err != nil || port < 1 || port > 65535 {
+			return fmt.Errorf("--port must be a number between 1 and 65535")
+		}
+	}
+	return nil
+}
+
+// params returns the libpq parameters set by the flags, in a stable order.
+// libpq and pgx take a host starting with a slash as the Unix socket directory.
+func (e endpointOptions) params() [][2]string {
+	host := e.host
+	if e.socketDir != "" {
+		host = e.socketDir
+	}
+	var params [][2]string
+	for _, p := range [][2]string{{"host", host}, {"port", e.port}, {"user", e.user}, {"dbname", e.dbname}} {
+		if p[1] != "" {
+			params = append(params, p)
+		}
+	}
+	return params
+}
+
+// readPassword prompts for a password on the terminal without echoing it.
+func readPassword() (string, error) {
+	fd := int(os.Stdin.Fd()) //nolint:gosec // file descriptors fit in an int
+	if !term.IsTerminal(fd) {
+		return "", fmt.Errorf("--password-prompt needs an interactive terminal; use PGPASSWORD or a .pgpass file instead")
+	}
+	fmt.Fprint(os.Stderr, "Password: ")
+	password, err := term.ReadPassword(fd)
+	fmt.Fprintln(os.Stderr)
+	if err != nil {
+		return "", fmt.Errorf("reading password: %w", err)
+	}
+	return string(password), nil
+}
+
+// applyParams adds libpq parameters to dsn, in URL or keyword/value form. Later
+// keywords win in libpq and pgx, so appending overrides the DSN's own.
+func applyParams(dsn string, params [][2]string) string {
+	if len(params) == 0 {
+		return dsn
+	}
+
+	if strings.HasPrefix(dsn, "postgres://") || strings.HasPrefix(dsn, "postgresql://") {
+		u, err := url.Parse(dsn)
+		if err != nil {
+			return dsn // pgx reports the parse error
+		}
+		query := u.Query()
+		for _, p := range params {
+			query.Set(p[0], p[1])
+		}
+		u.RawQuery = query.Encode()
+		return u.String()
+	}
+
+	var b strings.Builder
+	b.WriteString(dsn)
+	for _, p := range params {
+		value := strings.NewReplacer(`\`, `\\`, `'`, `\'`).Replace(p[1])
+		fmt.Fprintf(&b, " %s='%s'", p[0], value)
+	}
+	return strings.TrimPrefix(b.String(), " ")
+}
@@ -0,0 +1,64 @@
+package cli
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestResolveDSN_EndpointFlags(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		endpoint endpointOptions
+		args     []string
+		want     string
+	}{
+		{
+			name: "DSN without flags is unchanged",
+			args: []string{"postgres://u@db:5432/app"},
+			want: "postgres://u@db:5432/app",
+		},
+		{
+			name:     "flags alone compose a keyword/value DSN",
+			endpoint: endpointOptions{host: "db.internal", port: "6432", user: "o'brien", dbname: "app"},
+			args:     []string{""},
+			want:     `host='db.internal' port='6432' user='o\'brien' dbname='app'`,
+		},
+		{
+			name:     "socket directory becomes the host",
+			endpoint: endpointOptions{socketDir: "/var/run/postgresql", dbname: "app"},
+			args:     []string{""},
+			want:     "host='/var/run/postgresql' dbname='app'",
+		},
+		{
+			name:     "flags override URL parameters",
+			endpoint: endpointOptions{user: "admin@corp", dbname: "other"},
+			args:     []string{"postgres://u@db:5432/app"},
+			want:     "postgres://u@db:5432/app?dbname=other&user=admin%40corp",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+			opts := &runOptions{endpoint: tt.endpoint}
+			dsn, err := opts.resolveDSN(tt.args, "run")
+			require.NoError(t, err)
+			assert.Equal(t, tt.want, dsn)
+		})
+	}
+}
+
+func TestEndpointOptions_Validate(t *testing.T) {
+	t.Parallel()
+
+	assert.NoError(t, endpointOptions{}.validate())
+	assert.NoError(t, endpointOptions{socketDir: "/tmp", port: "5433"}.validate())
+	assert.Error(t, endpointOptions{host: "db", socketDir: "/tmp"}.validate())
+	assert.Error(t, endpointOptions{socketDir: "tmp"}.validate(), "relative socket directory")
+	assert.Error(t, endpointOptions{port: "postgres"}.validate())
+	assert.Error(t, endpointOptions{port: "70000"}.validate())
+}
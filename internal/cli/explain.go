@@ -3,7 +3,6 @@ package cli
 import (
 	"fmt"
 
-	"github.com/charmbracelet/glamour"
 	"github.com/fatih/color"
 	"github.com/spf13/cobra"
 
@@ -54,17 +53,9 @@ func newExplainCheckCommand(metadata check.Metadata, sqlOnly *bool) *cobra.Comma
 			if color.NoColor {
 				fmt.Fprintln(cmd.OutOrStdout(), doc)
 			} else {
-				r, err := glamour.NewTermRenderer(
-					glamour.WithAutoStyle(),
-					glamour.WithWordWrap(0),
-				)
+				rendered, err := renderMarkdown(doc)
 				if err != nil {
-					return fmt.Errorf("creating markdown renderer: %w", err)
-				}
-
-				rendered, err := r.Render(doc)
-				if err != nil {
-					return fmt.Errorf("rendering markdown: %w", err)
+					return err
 				}
 
 				fmt.Fprint(cmd.OutOrStdout(), rendered)
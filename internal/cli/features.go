@@ -0,0 +1,28 @@
+package cli
+
+import "strings"
+
+// Optional integrations are compiled in by default and can be stripped with
+// build tags, keeping minimal and static builds free of their dependencies:
+//
+//	go build -tags nomarkdown ./cmd/pgdoctor
+//
+// The library packages never import these integrations.
+
+// enabledFeatures lists the optional integrations compiled into this binary.
+func enabledFeatures() []string {
+	var features []string
+	if markdownRendering {
+		features = append(features, "markdown")
+	}
+	return features
+}
+
+// featureSummary renders enabledFeatures for the --version output.
+func featureSummary() string {
+	features := enabledFeatures()
+	if len(features) == 0 {
+		return "none"
+	}
+	return strings.Join(features, ", ")
+}
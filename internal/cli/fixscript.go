@@ -0,0 +1,164 @@
+package cli
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"os"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/emancu/pgdoctor/check"
+)
+
+func newFixScriptCommand() *cobra.Command {
+	opts := &runOptions{units: "binary"}
+	var outputPath string
+
+	cmd := &cobra.Command{
+		Use:   "fix-script <DSN> --output <file>",
+		Short: "Write the SQL that fixes the findings to a script for review",
+		Long: `Run the health checks and write the remediation statements of every
+warning and failure to an SQL script, failures first, each annotated with the
+check and severity it comes from.
+
+pgdoctor never executes the script. Review it, remove what does not apply and
+run it yourself, e.g. with psql -f. Findings without an SQL fix (a restart, an
+application change) are listed at the end as comments. Use --output - to
+print the script instead.`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			dsn, err := opts.resolveDSN(args, "fix-script")
+			if err != nil {
+				return err
+			}
+			if outputPath == "" {
+				return fmt.Errorf("--output is required")
+			}
+			if err := opts.validateConnection(); err != nil {
+				return err
+			}
+			runOpts, err := opts.prepare(cmd)
+			if err != nil {
+				return err
+			}
+
+			ctx := cmd.Context()
+
+			sess := opts.newSession(dsn)
+			if err := sess.open(ctx); err != nil {
+				fmt.Fprintf(os.Stderr, "Error: failed to connect to database: %v\n", err)
+				return &SilentError{ExitCode: 2}
+			}
+			defer sess.close(ctx)
+
+			var reports []*check.Report
+			runOpts.Retry = sess.retry
+			runOpts.Reconnect = sess.reconnect
+			runOpts.Provider = detectProvider(ctx, sess.conn)
+			runOpts.OnReport = pgdoctor.Collect(&reports)
+			pgdoctor.Run(ctx, sess.conn, runOpts)
+
+			var buf bytes.Buffer
+			statements := writeFixScript(&buf, reports, parseDSNLabel(dsn), time.Now())
+
+			if outputPath == "-" {
+				_, err = cmd.OutOrStdout().Write(buf.Bytes())
+				return err
+			}
+			if err := os.WriteFile(outputPath, buf.Bytes(), 0o644); err != nil {
+				return fmt.Errorf("writing fix script: %w", err)
+			}
+			fmt.Fprintf(cmd.ErrOrStderr(), "Wrote %d statement(s) to %s\n", statements, outputPath)
+			return nil
+		},
+	}
+
+	cmd.Flags().StringVar(&outputPath, "output", "", "SQL script to write, or - for stdout (required)")
+	addSelectionFlags(cmd, opts)
+	addConnectionFlags(cmd, opts)
+
+	return cmd
+}
+
+// fixEntry is one non-OK finding in the script.
+type fixEntry struct {
+	report  *check.Report
+	finding check.Finding
+}
+
+// writeFixScript renders the fixes of every warning and failure as a commented SQL
+// script and returns the number of statements written. Failures come first; within
+// a severity, the check order of the run is kept.
+func writeFixScript(w io.Writer, reports []*check.Report, label string, now time.Time) int {
+	var fixes, manual []fixEntry
+	for _, report := range reports {
+		for _, finding := range report.Results {
+			if finding.Severity <= check.SeverityOK {
+				continue
+			}
+			entry := fixEntry{report: report, finding: finding}
+			if strings.TrimSpace(finding.FixSQL) == "" {
+				manual = append(manual, entry)
+			} else {
+				fixes = append(fixes, entry)
+			}
+		}
+	}
+	bySeverity := func(entries []fixEntry) func(i, j int) bool {
+		return func(i, j int) bool { return entries[i].finding.Severity > entries[j].finding.Severity }
+	}
+	sort.SliceStable(fixes, bySeverity(fixes))
+	sort.SliceStable(manual, bySeverity(manual))
+
+	fmt.Fprintf(w, "-- pgdoctor fix script for %s, generated %s\n", label, now.UTC().Format(time.RFC3339))
+	fmt.Fprintln(w, "-- Review every statement before running it: pgdoctor never executes this script.")
+	fmt.Fprintln(w, "-- It is not wrapped in a transaction, since CONCURRENTLY and ALTER SYSTEM cannot run in one.")
+
+	if len(fixes) == 0 {
+		fmt.Fprintln(w)
+		fmt.Fprintln(w, "-- No finding has an SQL fix.")
+	}
+
+	statements := 0
+	for _, entry := range fixes {
+		fmt.Fprintln(w)
+		fmt.Fprintf(w, "-- [%s] %s\n", entry.finding.Severity, fixHeading(entry))
+		if entry.finding.Remediation != "" {
+			fmt.Fprintf(w, "-- %s\n", entry.finding.Remediation)
+		}
+		for _, line := range strings.Split(strings.TrimSpace(entry.finding.FixSQL), "\n") {
+			fmt.Fprintln(w, line)
+			if strings.HasSuffix(strings.TrimSpace(line), ";") {
+				statements++
+			}
+		}
+	}
+
+	if len(manual) > 0 {
+		fmt.Fprintln(w)
+		fmt.Fprintln(w, "-- Findings without an SQL fix (see pgdoctor explain <check-id>):")
+		for _, entry := range manual {
+			line := fmt.Sprintf("-- [%s] %s", entry.finding.Severity, fixHeading(entry))
+			if entry.finding.Remediation != "" {
+				line += ": " + entry.finding.Remediation
+			}
+			fmt.Fprintln(w, line)
+		}
+	}
+
+	return statements
+}
+
+// fixHeading names the finding as "check-id/finding-id (Finding Name)".
+func fixHeading(entry fixEntry) string {
+	id := entry.report.CheckID
+	if entry.finding.ID != entry.report.CheckID {
+		id += "/" + entry.finding.ID
+	}
+	return fmt.Sprintf("%s (%s)", id, entry.finding.Name)
+}
@@ -0,0 +1,64 @@
+package cli
+
+import (
+	"bytes"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestWriteFixScript(t *testing.T) {
+	t.Parallel()
+
+	indexes := check.NewReport(check.Metadata{CheckID: "invalid-indexes", Name: "Invalid Indexes"})
+	indexes.AddFinding(check.Finding{
+		ID:       "invalid-indexes",
+		Name:     "Invalid Indexes",
+		Severity: check.SeverityWarn,
+		FixSQL:   "REINDEX INDEX CONCURRENTLY \"public\".\"a\";\nDROP INDEX CONCURRENTLY IF EXISTS \"public\".\"b_ccnew\";",
+	})
+
+	security := check.NewReport(check.Metadata{CheckID: "password-encryption", Name: "Password Encryption"})
+	security.AddFinding(check.Finding{
+		ID:          "password-encryption-setting",
+		Name:        "Password Hashing Method",
+		Severity:    check.SeverityFail,
+		Remediation: "Set password_encryption to scram-sha-256",
+		FixSQL:      "ALTER SYSTEM SET password_encryption = 'scram-sha-256';",
+	})
+	security.AddFinding(check.Finding{ID: "md5-passwords", Name: "MD5 Password Verifiers", Severity: check.SeverityOK, FixSQL: "SELECT 1;"})
+
+	temp := check.NewReport(check.Metadata{CheckID: "temp-files", Name: "Temporary Files"})
+	temp.AddFinding(check.Finding{
+		ID:          "orphaned-temp-files",
+		Name:        "Orphaned Temporary Files",
+		Severity:    check.SeverityWarn,
+		Remediation: "Restart the server",
+	})
+
+	var buf bytes.Buffer
+	statements := writeFixScript(&buf, []*check.Report{indexes, security, temp}, "db.example.com/app", time.Date(2026, 1, 2, 3, 4, 5, 0, time.UTC))
+	require.Equal(t, 3, statements)
+
+	out := buf.String()
+	assert.Contains(t, out, "-- pgdoctor fix script for db.example.com/app, generated 2026-01-02T03:04:05Z\n")
+	assert.NotContains(t, out, "SELECT 1;", "passing findings are left out")
+	assert.Contains(t, out, "-- [fail] password-encryption/password-encryption-setting (Password Hashing Method)\n-- Set password_encryption to scram-sha-256\nALTER SYSTEM")
+	assert.Contains(t, out, "-- [warn] temp-files/orphaned-temp-files (Orphaned Temporary Files): Restart the server\n")
+
+	assert.Less(t, bytes.Index(buf.Bytes(), []byte("ALTER SYSTEM")), bytes.Index(buf.Bytes(), []byte("REINDEX")), "failures come first")
+}
+
+func TestWriteFixScript_NothingToFix(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "demo", Name: "Demo"})
+	report.AddFinding(check.Finding{ID: "demo", Name: "Demo", Severity: check.SeverityOK})
+
+	var buf bytes.Buffer
+	require.Zero(t, writeFixScript(&buf, []*check.Report{report}, "db", time.Now()))
+	assert.Contains(t, buf.String(), "-- No finding has an SQL fix.")
+}
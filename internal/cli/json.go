@@ -5,24 +5,60 @@ import (
 	"encoding/json"
 	"fmt"
 	"io"
+	"time"
 
 	"github.com/emancu/pgdoctor/check"
 )
 
 type jsonReport struct {
-	CheckID  string        `json:"check_id"`
-	Name     string        `json:"name"`
-	Category string        `json:"category"`
-	Severity string        `json:"severity"`
-	Results  []jsonFinding `json:"results"`
+	CheckID    string        `json:"check_id"`
+	Name       string        `json:"name"`
+	Category   string        `json:"category"`
+	Severity   string        `json:"severity"`
+	DurationMs float64       `json:"duration_ms"`
+	Results    []jsonFinding `json:"results"`
+	Queries    []jsonQuery   `json:"queries,omitempty"`
+}
+
+type jsonQuery struct {
+	SQL        string  `json:"sql"`
+	DurationMs float64 `json:"duration_ms"`
+	Error      string  `json:"error,omitempty"`
 }
 
 type jsonFinding struct {
-	ID       string     `json:"id"`
-	Name     string     `json:"name"`
-	Severity string     `json:"severity"`
-	Details  string     `json:"details,omitempty"`
-	Table    *jsonTable `json:"table,omitempty"`
+	ID               string          `json:"id"`
+	Name             string          `json:"name"`
+	Severity         string          `json:"severity"`
+	OriginalSeverity string          `json:"original_severity,omitempty"`
+	Details          string          `json:"details,omitempty"`
+	Object           *jsonObject     `json:"object,omitempty"`
+	Metrics          []jsonMetric    `json:"metrics,omitempty"`
+	Remediation      string          `json:"remediation,omitempty"`
+	FixSQL           string          `json:"fix_sql,omitempty"`
+	ReclaimableBytes int64           `json:"reclaimable_bytes,omitempty"`
+	MonthlySavings   float64         `json:"monthly_savings,omitempty"`
+	Comparison       *jsonComparison `json:"comparison,omitempty"`
+	Table            *jsonTable      `json:"table,omitempty"`
+}
+
+type jsonObject struct {
+	Kind   string `json:"kind"`
+	Schema string `json:"schema,omitempty"`
+	Name   string `json:"name"`
+}
+
+type jsonMetric struct {
+	Name  string  `json:"name"`
+	Value float64 `json:"value"`
+	Unit  string  `json:"unit"`
+}
+
+type jsonComparison struct {
+	Setting   string `json:"setting,omitempty"`
+	Actual    string `json:"actual"`
+	Expected  string `json:"expected"`
+	Threshold string `json:"threshold,omitempty"`
 }
 
 type jsonTable struct {
@@ -35,24 +71,69 @@ type jsonRow struct {
 	Severity string   `json:"severity"`
 }
 
-func formatJSON(w io.Writer, reports []*check.Report) error {
+// formatJSON renders reports as JSON. costPerGB, when positive, adds the
+// estimated monthly savings next to every finding with reclaimable bytes, with a
+// GB in the given units.
+func formatJSON(w io.Writer, reports []*check.Report, costPerGB float64, units check.ByteUnits) error {
+	return writeJSON(w, toJSONReports(reports, costPerGB, units))
+}
+
+// writeJSON encodes v as indented JSON.
+func writeJSON(w io.Writer, v any) error {
+	enc := json.NewEncoder(w)
+	enc.SetIndent("", "  ")
+	if err := enc.Encode(v); err != nil {
+		return fmt.Errorf("encoding JSON: %w", err)
+	}
+	return nil
+}
+
+func toJSONReports(reports []*check.Report, costPerGB float64, units check.ByteUnits) []jsonReport {
 	output := make([]jsonReport, 0, len(reports))
 
 	for _, report := range reports {
 		jr := jsonReport{
-			CheckID:  report.CheckID,
-			Name:     report.Name,
-			Category: string(report.Category),
-			Severity: report.Severity.String(),
-			Results:  make([]jsonFinding, 0, len(report.Results)),
+			CheckID:    report.CheckID,
+			Name:       report.Name,
+			Category:   string(report.Category),
+			Severity:   report.Severity.String(),
+			DurationMs: durationMs(report.Duration),
+			Results:    make([]jsonFinding, 0, len(report.Results)),
+		}
+		for _, query := range report.Queries {
+			jr.Queries = append(jr.Queries, jsonQuery{SQL: query.SQL, DurationMs: durationMs(query.Duration), Error: query.Error})
 		}
 
 		for _, result := range report.Results {
 			jf := jsonFinding{
-				ID:       result.ID,
-				Name:     result.Name,
-				Severity: result.Severity.String(),
-				Details:  result.Details,
+				ID:               result.ID,
+				Name:             result.Name,
+				Severity:         result.Severity.String(),
+				Details:          result.Details,
+				Remediation:      result.Remediation,
+				FixSQL:           result.FixSQL,
+				ReclaimableBytes: result.ReclaimableBytes,
+			}
+			if o := result.Object; o != nil {
+				jf.Object = &jsonObject{Kind: string(o.Kind), Schema: o.Schema, Name: o.Name}
+			}
+			for _, m := range result.Metrics {
+				jf.Metrics = append(jf.Metrics, jsonMetric{Name: m.Name, Value: m.Value, Unit: string(m.Unit)})
+			}
+			if result.OriginalSeverity != nil {
+				jf.OriginalSeverity = result.OriginalSeverity.String()
+			}
+			if costPerGB > 0 {
+				jf.MonthlySavings = monthlyCost(result.ReclaimableBytes, costPerGB, units)
+			}
+
+			if c := result.Comparison; c != nil {
+				jf.Comparison = &jsonComparison{
+					Setting:   c.Setting,
+					Actual:    c.Actual,
+					Expected:  c.Expected,
+					Threshold: c.Threshold,
+				}
 			}
 
 			if result.Table != nil {
@@ -75,11 +156,10 @@ func formatJSON(w io.Writer, reports []*check.Report) error {
 		output = append(output, jr)
 	}
 
-	enc := json.NewEncoder(w)
-	enc.SetIndent("", "  ")
-	if err := enc.Encode(output); err != nil {
-		return fmt.Errorf("encoding JSON: %w", err)
-	}
+	return output
+}
 
-	return nil
+// durationMs converts d to milliseconds with microsecond precision.
+func durationMs(d time.Duration) float64 {
+	return float64(d.Microseconds()) / 1000
 }
@@ -0,0 +1,170 @@
+package cli
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+)
+
+// keyringService names pgdoctor's entries in the OS keyring.
+const keyringService = "pgdoctor"
+
+// errNotInKeyring reports a target without a stored password.
+var errNotInKeyring = errors.New("no password stored")
+
+// keyring stores database passwords in the OS keyring through the platform's
+// own tool, so pgdoctor needs no keyring library: security(1) on macOS and
+// secret-tool(1) (libsecret, e.g. GNOME Keyring or KWallet) on Linux. Secrets
+// go over stdin, never on a command line other processes can read.
+type keyring struct {
+	goos string
+	// run executes a command with the given stdin and returns its stdout.
+	run func(ctx context.Context, stdin string, name string, args ...string) (string, error)
+}
+
+func newKeyring() keyring {
+	return keyring{goos: runtime.GOOS, run: runCommand}
+}
+
+func runCommand(ctx context.Context, stdin string, name string, args ...string) (string, error) {
+	cmd := exec.CommandContext(ctx, name, args...)
+	cmd.Stdin = strings.NewReader(stdin)
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+	out, err := cmd.Output()
+	if err != nil {
+		if msg := strings.TrimSpace(stderr.String()); msg != "" {
+			return "", fmt.Errorf("%s: %w: %s", name, err, msg)
+		}
+		return "", fmt.Errorf("%s: %w", name, err)
+	}
+	return string(out), nil
+}
+
+// get returns the password stored for target.
+func (k keyring) get(ctx context.Context, target string) (string, error) {
+	var out string
+	var err error
+	switch k.goos {
+	case "darwin":
+		out, err = k.run(ctx, "", "security", "find-generic-password", "-s", keyringService, "-a", target, "-w")
+	case "linux", "freebsd", "openbsd":
+		out, err = k.run(ctx, "", "secret-tool", "lookup", "service", keyringService, "target", target)
+	default:
+		return "", k.unsupported()
+	}
+	if errors.Is(err, exec.ErrNotFound) {
+		return "", fmt.Errorf("keyring: %w", err)
+	}
+	if err != nil {
+		// Both tools exit non-zero for a missing entry; secret-tool prints nothing.
+		return "", fmt.Errorf("keyring target %q: %w (%w)", target, errNotInKeyring, err)
+	}
+	if out = strings.TrimSuffix(out, "\n"); out == "" {
+		return "", fmt.Errorf("keyring target %q: %w", target, errNotInKeyring)
+	}
+	return out, nil
+}
+
+// set stores password for target, replacing any stored before.
+func (k keyring) set(ctx context.Context, target, password string) error {
+	switch k.goos {
+	case "darwin":
+		// security -i reads commands from stdin, keeping the password out of ps.
+		quote := strings.NewReplacer(`\`, `\\`, `"`, `\"`)
+		command := fmt.Sprintf("add-generic-password -U -s %s -a \"%s\" -l \"pgdoctor %s\" -w \"%s\"\n",
+			keyringService, quote.Replace(target), quote.Replace(target), quote.Replace(password))
+		_, err := k.run(ctx, command, "security", "-i")
+		return err
+	case "linux", "freebsd", "openbsd":
+		_, err := k.run(ctx, password, "secret-tool", "store", "--label", "pgdoctor "+target, "service", keyringService, "target", target)
+		return err
+	default:
+		return k.unsupported()
+	}
+}
+
+// delete removes the password stored for target.
+func (k keyring) delete(ctx context.Context, target string) error {
+	switch k.goos {
+	case "darwin":
+		_, err := k.run(ctx, "", "security", "delete-generic-password", "-s", keyringService, "-a", target)
+		return err
+	case "linux", "freebsd", "openbsd":
+		_, err := k.run(ctx, "", "secret-tool", "clear", "service", keyringService, "target", target)
+		return err
+	default:
+		return k.unsupported()
+	}
+}
+
+func (k keyring) unsupported() error {
+	return fmt.Errorf("the OS keyring is not supported on %s; use --password-prompt, PGPASSWORD or a .pgpass file", k.goos)
+}
+
+func newAuthCommand() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "auth",
+		Short: "Manage database passwords in the OS keyring",
+		Long: `Store database passwords in the OS keyring (macOS Keychain, or the
+Secret Service on Linux through secret-tool) under a target name of your
+choice, then connect with --keyring <target> instead of keeping the password in
+a DSN, an environment variable or a config file.`,
+	}
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "store <target>",
+		Short: "Store the password for a target",
+		Long: `Store the password for a target, replacing any stored before. It is
+read from the terminal without echo, or from the first line of stdin when
+stdin is not a terminal.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			password, err := readSecret()
+			if err != nil {
+				return err
+			}
+			if password == "" {
+				return fmt.Errorf("empty password")
+			}
+			if err := newKeyring().set(cmd.Context(), args[0], password); err != nil {
+				return err
+			}
+			fmt.Fprintf(cmd.ErrOrStderr(), "Stored the password for %s\n", args[0])
+			return nil
+		},
+	})
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "delete <target>",
+		Short: "Delete the password stored for a target",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return newKeyring().delete(cmd.Context(), args[0])
+		},
+	})
+
+	return cmd
+}
+
+// readSecret prompts on the terminal, or reads the first line of piped stdin
+// so that provisioning scripts can store passwords too.
+func readSecret() (string, error) {
+	if term.IsTerminal(int(os.Stdin.Fd())) { //nolint:gosec // file descriptors fit in an int
+		return readPassword()
+	}
+	line, err := bufio.NewReader(os.Stdin).ReadString('\n')
+	if err != nil && line == "" {
+		return "", fmt.Errorf("reading password from stdin: %w", err)
+	}
+	return strings.TrimRight(line, "\r\n"), nil
+}
@@ -0,0 +1,73 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// fakeKeyring records the commands run and answers lookups from a map.
+type fakeKeyring struct {
+	commands []string
+	stdin    []string
+	stored   map[string]string
+}
+
+func (f *fakeKeyring) run(_ context.Context, stdin string, name string, args ...string) (string, error) {
+	f.commands = append(f.commands, name+" "+strings.Join(args, " "))
+	f.stdin = append(f.stdin, stdin)
+	if password, ok := f.stored[args[len(args)-1]]; ok {
+		return password + "\n", nil
+	}
+	return "", errors.New("exit status 1")
+}
+
+func TestKeyring_Linux(t *testing.T) {
+	t.Parallel()
+
+	fake := &fakeKeyring{stored: map[string]string{"prod": "s3cr3t"}}
+	k := keyring{goos: "linux", run: fake.run}
+
+	password, err := k.get(context.Background(), "prod")
+	require.NoError(t, err)
+	assert.Equal(t, "s3cr3t", password)
+
+	_, err = k.get(context.Background(), "staging")
+	require.ErrorIs(t, err, errNotInKeyring)
+
+	_ = k.set(context.Background(), "staging", "p@ss word")
+	assert.Equal(t, []string{
+		"secret-tool lookup service pgdoctor target prod",
+		"secret-tool lookup service pgdoctor target staging",
+		"secret-tool store --label pgdoctor staging service pgdoctor target staging",
+	}, fake.commands)
+	assert.Equal(t, "p@ss word", fake.stdin[2], "the password goes over stdin")
+}
+
+func TestKeyring_Darwin(t *testing.T) {
+	t.Parallel()
+
+	fake := &fakeKeyring{stored: map[string]string{"-w": "s3cr3t"}}
+	k := keyring{goos: "darwin", run: fake.run}
+
+	password, err := k.get(context.Background(), "prod")
+	require.NoError(t, err)
+	assert.Equal(t, "s3cr3t", password)
+
+	_ = k.set(context.Background(), "prod", `say "hi"`)
+	assert.Equal(t, "security -i", fake.commands[1])
+	assert.Equal(t, "add-generic-password -U -s pgdoctor -a \"prod\" -l \"pgdoctor prod\" -w \"say \\\"hi\\\"\"\n", fake.stdin[1])
+}
+
+func TestKeyring_Unsupported(t *testing.T) {
+	t.Parallel()
+
+	k := keyring{goos: "windows"}
+	_, err := k.get(context.Background(), "prod")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "not supported on windows")
+}
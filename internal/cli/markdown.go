@@ -0,0 +1,29 @@
+//go:build !nomarkdown
+
+package cli
+
+import (
+	"fmt"
+
+	"github.com/charmbracelet/glamour"
+)
+
+// markdownRendering reports whether this binary renders markdown for the terminal.
+// Build with -tags nomarkdown to drop glamour and its dependencies.
+const markdownRendering = true
+
+func renderMarkdown(doc string) (string, error) {
+	r, err := glamour.NewTermRenderer(
+		glamour.WithAutoStyle(),
+		glamour.WithWordWrap(0),
+	)
+	if err != nil {
+		return "", fmt.Errorf("creating markdown renderer: %w", err)
+	}
+
+	rendered, err := r.Render(doc)
+	if err != nil {
+		return "", fmt.Errorf("rendering markdown: %w", err)
+	}
+	return rendered, nil
+}
@@ -0,0 +1,11 @@
+//go:build nomarkdown
+
+package cli
+
+// markdownRendering reports whether this binary renders markdown for the terminal.
+const markdownRendering = false
+
+// renderMarkdown prints the raw markdown: this build excludes the terminal renderer.
+func renderMarkdown(doc string) (string, error) {
+	return doc + "\n", nil
+}
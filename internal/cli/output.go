@@ -69,6 +69,7 @@ func printCheckReport(w io.Writer, report *check.Report, opts *runOptions) {
 			dimFunc(fmt.Sprintf("(%s)", report.CheckID)),
 			timingStr,
 			dimFunc(report.Results[0].Details))
+		printExecutedQueries(w, report, opts)
 		return
 	}
 
@@ -82,9 +83,19 @@ func printCheckReport(w io.Writer, report *check.Report, opts *runOptions) {
 			report.Name,
 			dimFunc(fmt.Sprintf("(%s)", report.CheckID)),
 			timingStr)
+		if note := overrideNote(result); note != "" {
+			fmt.Fprintf(w, "%s\n", indent(dimFunc(note), 2))
+		}
 		if result.Severity != check.SeverityOK && result.Details != "" {
 			fmt.Fprintf(w, "%s\n", indent(result.Details, 2))
 		}
+		if result.Severity != check.SeverityOK && result.Remediation != "" {
+			fmt.Fprintf(w, "%s\n", indent("Fix: "+result.Remediation, 2))
+		}
+		if result.Comparison != nil {
+			fmt.Fprintln(w)
+			printTable(w, comparisonTable(result), 2, opts)
+		}
 		if result.Table != nil {
 			fmt.Fprintln(w)
 			printTable(w, result.Table, 2, opts)
@@ -120,6 +131,40 @@ func printCheckReport(w io.Writer, report *check.Report, opts *runOptions) {
 		fmt.Fprintln(w, "  Query:")
 		fmt.Fprintf(w, "%s\n", indent(report.SQL, 4))
 	}
+	printExecutedQueries(w, report, opts)
+}
+
+// printExecutedQueries lists, in debug detail, the statements the check ran and
+// how long each took.
+func printExecutedQueries(w io.Writer, report *check.Report, opts *runOptions) {
+	if opts.detail != string(detailDebug) || len(report.Queries) == 0 {
+		return
+	}
+
+	dimFunc := dimColor()
+	fmt.Fprintln(w)
+	fmt.Fprintln(w, "  Executed:")
+	for _, query := range report.Queries {
+		line := fmt.Sprintf("%s %s", dimFunc(fmt.Sprintf("[%s]", check.FormatDurationMs(float64(query.Duration.Milliseconds())))), statementLabel(query.SQL))
+		if query.Error != "" {
+			line += " — " + query.Error
+		}
+		fmt.Fprintf(w, "    %s\n", line)
+	}
+}
+
+// statementLabel names a statement in one line: the sqlc query name when it has
+// one, else the statement with its whitespace collapsed, truncated to 80 runes.
+func statementLabel(sql string) string {
+	if rest, ok := strings.CutPrefix(sql, "-- name: "); ok {
+		name, _, _ := strings.Cut(rest, " ")
+		return name
+	}
+	label := strings.Join(strings.Fields(sql), " ")
+	if runes := []rune(label); len(runes) > 80 {
+		label = string(runes[:79]) + "…"
+	}
+	return label
 }
 
 func printSubcheck(w io.Writer, report *check.Report, result check.Finding, opts *runOptions) {
@@ -136,10 +181,23 @@ func printSubcheck(w io.Writer, report *check.Report, result check.Finding, opts
 		result.Name,
 		dimFunc(fmt.Sprintf("(%s)", fullID)))
 
+	if note := overrideNote(result); note != "" {
+		fmt.Fprintf(w, "%s\n", indent(dimFunc(note), 2))
+	}
+
 	if result.Severity != check.SeverityOK && result.Details != "" {
 		fmt.Fprintf(w, "%s\n", indent(result.Details, 2))
 	}
 
+	if result.Severity != check.SeverityOK && result.Remediation != "" {
+		fmt.Fprintf(w, "%s\n", indent("Fix: "+result.Remediation, 2))
+	}
+
+	if result.Comparison != nil {
+		fmt.Fprintln(w)
+		printTable(w, comparisonTable(result), 2, opts)
+	}
+
 	if result.Table != nil {
 		fmt.Fprintln(w)
 		printTable(w, result.Table, 2, opts)
@@ -152,6 +210,27 @@ func printSubcheck(w io.Writer, report *check.Report, result check.Finding, opts
 	}
 }
 
+// overrideNote explains a severity changed by --severity, so an overridden
+// result is never mistaken for what the check reported. Empty otherwise.
+func overrideNote(result check.Finding) string {
+	if result.OriginalSeverity == nil {
+		return ""
+	}
+	return fmt.Sprintf("Severity overridden (check reported %s)", *result.OriginalSeverity)
+}
+
+// comparisonTable renders a settings comparison in the shared three-column layout,
+// so every settings finding reads the same regardless of which check produced it.
+func comparisonTable(result check.Finding) *check.Table {
+	c := result.Comparison
+	return &check.Table{
+		Headers: []string{"Actual", "Expected", "Threshold"},
+		Rows: []check.TableRow{
+			{Cells: []string{c.Actual, c.Expected, c.Threshold}, Severity: result.Severity},
+		},
+	}
+}
+
 func printTable(w io.Writer, table *check.Table, indentSpaces int, opts *runOptions) {
 	if len(table.Rows) == 0 {
 		return
@@ -175,8 +254,10 @@ func printTable(w io.Writer, table *check.Table, indentSpaces int, opts *runOpti
 	}
 	for _, row := range table.Rows {
 		for i, cell := range row.Cells {
-			if i < len(widths) && len(cell) > widths[i] {
-				widths[i] = len(cell)
+			for _, line := range strings.Split(cell, "\n") {
+				if i < len(widths) && len(line) > widths[i] {
+					widths[i] = len(line)
+				}
 			}
 		}
 	}
@@ -196,11 +277,26 @@ func printTable(w io.Writer, table *check.Table, indentSpaces int, opts *runOpti
 	for _, row := range rowsToShow {
 		colorFunc := colorForSeverity(row.Severity)
 
-		fmt.Fprint(w, indentStr)
+		// Multi-line cells (e.g. merged reason lists) continue on the
+		// following lines, keeping the other columns blank.
+		cellLines := make([][]string, len(row.Cells))
+		height := 1
 		for i, cell := range row.Cells {
-			fmt.Fprintf(w, "%s  ", colorFunc(fmt.Sprintf("%-*s", widths[i], cell)))
+			cellLines[i] = strings.Split(cell, "\n")
+			height = max(height, len(cellLines[i]))
+		}
+
+		for line := range height {
+			fmt.Fprint(w, indentStr)
+			for i, lines := range cellLines {
+				text := ""
+				if line < len(lines) {
+					text = lines[line]
+				}
+				fmt.Fprintf(w, "%s  ", colorFunc(fmt.Sprintf("%-*s", widths[i], text)))
+			}
+			fmt.Fprintln(w)
 		}
-		fmt.Fprintln(w)
 	}
 
 	if truncated {
@@ -211,7 +307,7 @@ func printTable(w io.Writer, table *check.Table, indentSpaces int, opts *runOpti
 	}
 }
 
-func printSummary(w io.Writer, reports []*check.Report) {
+func printSummary(w io.Writer, reports []*check.Report, opts *runOptions) {
 	okCount, warnCount, failCount, skipCount := 0, 0, 0, 0
 	var totalDuration time.Duration
 	for _, report := range reports {
@@ -247,9 +343,90 @@ func printSummary(w io.Writer, reports []*check.Report) {
 	dimFunc := dimColor()
 	fmt.Fprintf(w, "Summary: %s %s\n", strings.Join(summaryParts, ", "),
 		dimFunc(fmt.Sprintf("(%d checks in %s)", len(reports), check.FormatDurationMs(float64(totalDuration.Milliseconds())))))
+
+	if reclaimable := totalReclaimable(reports); reclaimable > 0 {
+		line := fmt.Sprintf("Reclaimable storage: ~%s", check.FormatBytesAs(reclaimable, opts.byteUnits))
+		if opts.costPerGB > 0 {
+			line += fmt.Sprintf(" (est. %.2f/month at %.2f per GB)", monthlyCost(reclaimable, opts.costPerGB, opts.byteUnits), opts.costPerGB)
+		}
+		fmt.Fprintln(w, line)
+	}
+	if showTiming(opts) {
+		printSlowestChecks(w, reports)
+	}
 	fmt.Fprintln(w)
 }
 
+// maxSlowestChecks is how many checks the slowest-checks summary lists.
+const maxSlowestChecks = 5
+
+// printSlowestChecks lists the checks that took the longest, to explain slow runs.
+func printSlowestChecks(w io.Writer, reports []*check.Report) {
+	slowest := make([]*check.Report, 0, len(reports))
+	for _, report := range reports {
+		if report.Duration > 0 {
+			slowest = append(slowest, report)
+		}
+	}
+	if len(slowest) == 0 {
+		return
+	}
+	sort.SliceStable(slowest, func(i, j int) bool { return slowest[i].Duration > slowest[j].Duration })
+	if len(slowest) > maxSlowestChecks {
+		slowest = slowest[:maxSlowestChecks]
+	}
+
+	fmt.Fprintln(w, "Slowest checks:")
+	for _, report := range slowest {
+		fmt.Fprintf(w, "  %8s  %s (%d queries)\n",
+			check.FormatDurationMs(float64(report.Duration.Milliseconds())), report.CheckID, len(report.Queries))
+	}
+}
+
+// printExitSummary writes the single-line outcome wrapper scripts and cron mails grep for,
+// e.g. "pgdoctor: status=warn critical=0 warn=3 errors=0 duration=12.4s". It goes to
+// stderr whatever the output format, so it never pollutes JSON on stdout.
+// errors counts checks that could not run (skipped).
+func printExitSummary(w io.Writer, reports []*check.Report, elapsed time.Duration) {
+	status := check.SeverityOK
+	failCount, warnCount, skipCount := 0, 0, 0
+	for _, report := range reports {
+		switch report.Severity {
+		case check.SeverityFail:
+			failCount++
+		case check.SeverityWarn:
+			warnCount++
+		case check.SeveritySkip:
+			skipCount++
+		}
+		status = max(status, report.Severity)
+	}
+
+	fmt.Fprintf(w, "pgdoctor: status=%s critical=%d warn=%d errors=%d duration=%.1fs\n",
+		status, failCount, warnCount, skipCount, elapsed.Seconds())
+}
+
+// totalReclaimable sums the estimated reclaimable bytes across all non-passing findings.
+// Overlapping findings (an unused index that is also bloated) may count twice,
+// so the total is an upper bound.
+func totalReclaimable(reports []*check.Report) int64 {
+	var total int64
+	for _, report := range reports {
+		for _, result := range report.Results {
+			if result.Severity > check.SeverityOK {
+				total += result.ReclaimableBytes
+			}
+		}
+	}
+	return total
+}
+
+// monthlyCost converts bytes into a monthly storage cost at the given price per GB,
+// where a GB follows the selected --units.
+func monthlyCost(bytes int64, costPerGB float64, units check.ByteUnits) float64 {
+	return float64(bytes) / float64(units.GB()) * costPerGB
+}
+
 func severityDisplay(severity check.Severity) (string, func(string) string) {
 	switch severity {
 	case check.SeverityOK:
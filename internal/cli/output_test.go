@@ -2,10 +2,13 @@ package cli
 
 import (
 	"bytes"
+	"strings"
 	"testing"
+	"time"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 // singleFindingReport builds a report whose only finding has ID == CheckID,
@@ -41,3 +44,244 @@ func TestPrintCheckReport_SingleFinding_HidesDebugWithoutDebugDetail(t *testing.
 
 	assert.NotContains(t, buf.String(), "Debug:", "debug must stay hidden unless --detail debug")
 }
+
+func TestPrintCheckReport_RendersComparison(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "demo", Name: "Demo Check"})
+	report.AddFinding(check.Finding{
+		ID:       "work_mem",
+		Name:     "work_mem",
+		Severity: check.SeverityWarn,
+		Comparison: &check.Comparison{
+			Setting:   "work_mem",
+			Actual:    "2MB",
+			Expected:  ">= 4MB",
+			Threshold: "< 4MB",
+		},
+	})
+
+	var buf bytes.Buffer
+	printCheckReport(&buf, report, &runOptions{detail: string(detailBrief)})
+
+	out := buf.String()
+	for _, want := range []string{"Actual", "Expected", "Threshold", "2MB", ">= 4MB", "< 4MB"} {
+		assert.Contains(t, out, want)
+	}
+}
+
+func TestPrintCheckReport_NotesSeverityOverride(t *testing.T) {
+	t.Parallel()
+
+	report := singleFindingReport()
+	original := report.Results[0].Severity
+	report.Results[0].OriginalSeverity = &original
+	report.Results[0].Severity = check.SeverityOK
+
+	var buf bytes.Buffer
+	printCheckReport(&buf, report, &runOptions{detail: string(detailBrief)})
+
+	assert.Contains(t, buf.String(), "Severity overridden (check reported warn)")
+}
+
+func TestPrintCheckReport_ShowsRemediation(t *testing.T) {
+	t.Parallel()
+
+	report := singleFindingReport()
+	report.Results[0].Remediation = "ALTER SYSTEM SET work_mem = '16MB'"
+
+	var buf bytes.Buffer
+	printCheckReport(&buf, report, &runOptions{detail: string(detailBrief)})
+
+	assert.Contains(t, buf.String(), "Fix: ALTER SYSTEM SET work_mem = '16MB'")
+}
+
+func TestToJSONReports_StructuredFields(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "demo", Name: "Demo Check"})
+	report.AddFinding(check.Finding{
+		ID:          "bloat",
+		Name:        "Bloat",
+		Severity:    check.SeverityWarn,
+		Object:      &check.Object{Kind: check.ObjectTable, Schema: "public", Name: "orders"},
+		Metrics:     []check.Metric{{Name: "wasted", Value: 1024, Unit: check.UnitBytes}},
+		Remediation: "VACUUM FULL public.orders",
+	})
+
+	var buf bytes.Buffer
+	require.NoError(t, formatJSON(&buf, []*check.Report{report}, 0, check.UnitsBinary))
+
+	out := buf.String()
+	assert.Contains(t, out, `"object": {`)
+	assert.Contains(t, out, `"kind": "table"`)
+	assert.Contains(t, out, `"schema": "public"`)
+	assert.Contains(t, out, `"name": "wasted"`)
+	assert.Contains(t, out, `"unit": "bytes"`)
+	assert.Contains(t, out, `"remediation": "VACUUM FULL public.orders"`)
+}
+
+func TestPrintExitSummary(t *testing.T) {
+	t.Parallel()
+
+	newReport := func(id string, severity check.Severity) *check.Report {
+		report := check.NewReport(check.Metadata{CheckID: id})
+		report.Severity = severity
+		return report
+	}
+
+	testCases := []struct {
+		name    string
+		reports []*check.Report
+		want    string
+	}{
+		{
+			name:    "no checks",
+			reports: nil,
+			want:    "pgdoctor: status=pass critical=0 warn=0 errors=0 duration=12.4s\n",
+		},
+		{
+			name: "warnings and a skipped check",
+			reports: []*check.Report{
+				newReport("a", check.SeverityOK),
+				newReport("b", check.SeverityWarn),
+				newReport("c", check.SeverityWarn),
+				newReport("d", check.SeveritySkip),
+			},
+			want: "pgdoctor: status=warn critical=0 warn=2 errors=1 duration=12.4s\n",
+		},
+		{
+			name: "failure wins",
+			reports: []*check.Report{
+				newReport("a", check.SeverityFail),
+				newReport("b", check.SeverityWarn),
+			},
+			want: "pgdoctor: status=fail critical=1 warn=1 errors=0 duration=12.4s\n",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+
+			var buf bytes.Buffer
+			printExitSummary(&buf, tc.reports, 12400*time.Millisecond)
+			assert.Equal(t, tc.want, buf.String())
+		})
+	}
+}
+
+func TestPrintTable_MultiLineCells(t *testing.T) {
+	t.Parallel()
+
+	table := &check.Table{
+		Headers: []string{"Table", "Reasons"},
+		Rows: []check.TableRow{
+			{Cells: []string{"public.orders", "- autovacuum disabled\n- dead tuples > 20%"}, Severity: check.SeverityFail},
+		},
+	}
+
+	var buf bytes.Buffer
+	printTable(&buf, table, 2, &runOptions{detail: string(detailBrief)})
+
+	lines := strings.Split(strings.TrimRight(buf.String(), "\n"), "\n")
+	require.Len(t, lines, 4, "header, separator and one line per reason")
+	assert.Contains(t, lines[2], "public.orders")
+	assert.Contains(t, lines[2], "- autovacuum disabled")
+	assert.NotContains(t, lines[3], "public.orders")
+	assert.Contains(t, lines[3], "- dead tuples > 20%")
+}
+
+func TestPrintSummary_ReclaimableStorage(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "index-usage", Name: "Index Usage"})
+	report.AddFinding(check.Finding{ID: "unused-indexes", Name: "Unused Indexes", Severity: check.SeverityWarn, ReclaimableBytes: 20 * check.GiB})
+	report.AddFinding(check.Finding{ID: "passing", Name: "Passing", Severity: check.SeverityOK, ReclaimableBytes: 5 * check.GiB})
+	reports := []*check.Report{report}
+
+	t.Run("without cost", func(t *testing.T) {
+		t.Parallel()
+
+		var buf bytes.Buffer
+		printSummary(&buf, reports, &runOptions{})
+
+		assert.Contains(t, buf.String(), "Reclaimable storage: ~20.0GiB")
+		assert.NotContains(t, buf.String(), "/month")
+	})
+
+	t.Run("with cost per GB", func(t *testing.T) {
+		t.Parallel()
+
+		var buf bytes.Buffer
+		printSummary(&buf, reports, &runOptions{costPerGB: 0.10})
+
+		assert.Contains(t, buf.String(), "est. 2.00/month at 0.10 per GB")
+	})
+
+	t.Run("decimal units", func(t *testing.T) {
+		t.Parallel()
+
+		var buf bytes.Buffer
+		printSummary(&buf, reports, &runOptions{costPerGB: 0.10, byteUnits: check.UnitsDecimal})
+
+		assert.Contains(t, buf.String(), "Reclaimable storage: ~21.5GB")
+		assert.Contains(t, buf.String(), "est. 2.15/month at 0.10 per GB")
+	})
+}
+
+func TestPrintCheckReport_ExecutedQueriesUnderDebugDetail(t *testing.T) {
+	t.Parallel()
+
+	report := singleFindingReport()
+	report.Queries = []check.ExecutedQuery{
+		{SQL: "-- name: TableBloat :many\nSELECT 1", Duration: 1500 * time.Millisecond},
+		{SQL: "SELECT   count(*)\nFROM pg_class", Duration: 2 * time.Millisecond, Error: "canceling statement due to statement timeout"},
+	}
+
+	var buf bytes.Buffer
+	printCheckReport(&buf, report, &runOptions{detail: string(detailDebug)})
+	out := buf.String()
+	assert.Contains(t, out, "Executed:")
+	assert.Contains(t, out, "TableBloat")
+	assert.Contains(t, out, "SELECT count(*) FROM pg_class — canceling statement due to statement timeout")
+
+	buf.Reset()
+	printCheckReport(&buf, report, &runOptions{detail: string(detailVerbose)})
+	assert.NotContains(t, buf.String(), "Executed:")
+}
+
+func TestPrintSummary_SlowestChecks(t *testing.T) {
+	t.Parallel()
+
+	var reports []*check.Report
+	for i, id := range []string{"a", "b", "c", "d", "e", "f"} {
+		report := check.NewReport(check.Metadata{CheckID: id})
+		report.Duration = time.Duration(i+1) * time.Second
+		reports = append(reports, report)
+	}
+
+	var buf bytes.Buffer
+	printSummary(&buf, reports, &runOptions{detail: string(detailVerbose)})
+	out := buf.String()
+	require.Contains(t, out, "Slowest checks:")
+	assert.Less(t, strings.Index(out, " f ("), strings.Index(out, " e ("), "slowest first")
+	assert.NotContains(t, out, " a (", "only the five slowest are listed")
+
+	buf.Reset()
+	printSummary(&buf, reports, &runOptions{detail: string(detailBrief)})
+	assert.NotContains(t, buf.String(), "Slowest checks:")
+}
+
+func TestToJSONReports_TimingAndQueries(t *testing.T) {
+	t.Parallel()
+
+	report := check.NewReport(check.Metadata{CheckID: "demo", Name: "Demo Check"})
+	report.Duration = 1250 * time.Microsecond
+	report.Queries = []check.ExecutedQuery{{SQL: "SELECT 1", Duration: 500 * time.Microsecond}}
+
+	out := toJSONReports([]*check.Report{report}, 0, check.UnitsBinary)
+	require.Len(t, out, 1)
+	assert.InDelta(t, 1.25, out[0].DurationMs, 0.0001)
+	assert.Equal(t, []jsonQuery{{SQL: "SELECT 1", DurationMs: 0.5}}, out[0].Queries)
+}
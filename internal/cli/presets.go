@@ -1,30 +1,30 @@
 package cli
 
+import (
+	"slices"
+
+	"github.com/emancu/pgdoctor/check"
+)
+
 const (
 	presetAll    = "all"
-	presetTriage = "triage"
+	presetTriage = check.TagTriage
 )
 
-var triageChecks = []string{
-	"connection-health",
-	"connection-efficiency",
-	"replication-lag",
-	"replication-slots",
-	"table-bloat",
-	"table-vacuum-health",
-	"freeze-age",
-	"invalid-indexes",
-	"temp-usage",
-	"cache-efficiency",
-}
+// presets lists the accepted --preset values. Every preset except "all" is a
+// tag that checks declare in their Metadata.
+var presets = []string{presetAll, presetTriage}
 
-func getPresetChecks(preset string) []string {
-	switch preset {
-	case presetTriage:
-		return triageChecks
-	default:
-		return nil
+// getPresetChecks returns the IDs of the checks tagged with the preset.
+func getPresetChecks(checks []check.Package, preset string) []string {
+	var ids []string
+	for _, pkg := range checks {
+		metadata := pkg.Metadata()
+		if slices.Contains(metadata.Tags, preset) {
+			ids = append(ids, metadata.CheckID)
+		}
 	}
+	return ids
 }
 
 func intersect(a, b []string) []string {
@@ -39,7 +39,14 @@ all production databases should pass.`,
 	cmd.AddCommand(newRunCommand())
 	cmd.AddCommand(newListCommand())
 	cmd.AddCommand(newExplainCommand())
-
+	cmd.AddCommand(newCollectCommand())
+	cmd.AddCommand(newAnalyzeCommand())
+	cmd.AddCommand(newCompareCommand())
+	cmd.AddCommand(newFixScriptCommand())
+	cmd.AddCommand(newAuthCommand())
+	cmd.AddCommand(newVersionCommand(version))
+
+	cmd.SetVersionTemplate("pgdoctor {{.Version}}\nfeatures: " + featureSummary() + "\n")
 	cmd.SetHelpCommand(&cobra.Command{Hidden: true})
 
 	return cmd.Execute()
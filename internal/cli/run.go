@@ -1,17 +1,27 @@
 package cli
 
 import (
+	"context"
 	"fmt"
+	"io"
+	"net"
 	"net/url"
 	"os"
+	"slices"
 	"sort"
+	"strconv"
 	"strings"
+	"time"
 
 	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/jackc/pgx/v5/pgconn/ctxwatch"
 	"github.com/spf13/cobra"
 
 	"github.com/emancu/pgdoctor"
 	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/custom"
+	"github.com/emancu/pgdoctor/db"
 )
 
 type detailLevel string
@@ -23,13 +33,47 @@ const (
 	detailDebug   detailLevel = "debug"
 )
 
+// defaultKeepalive stays well below the 60-second idle timeout common to
+// corporate proxies (AWS NLBs allow 350 seconds).
+const defaultKeepalive = 30 * time.Second
+
+// outputFormats lists the values accepted by --output.
+var outputFormats = []string{"text", "json"}
+
 type runOptions struct {
-	ignored     []string
-	only        []string
-	preset      string
-	detail      string
-	hidePassing bool
-	output      string
+	ignored        []string
+	only           []string
+	preset         string
+	detail         string
+	hidePassing    bool
+	output         string
+	schemas        []string
+	excludeSchemas []string
+	includeTables  []string
+	excludeTables  []string
+
+	statementTimeout time.Duration
+	checkTimeout     time.Duration
+	costPerGB        float64
+	retries          int
+	retryDelay       time.Duration
+	units            string
+	byteUnits        check.ByteUnits // units, parsed by prepare
+	minRows          int64
+	minTableSize     string
+	systemMemory     string
+	settings         []string
+	severities       []string
+	replicas         []string
+	customChecks     string
+	keepalive        time.Duration
+	history          string
+	tls              tlsOptions
+	cloudSQL         cloudSQLOptions
+	azureAD          bool
+	endpoint         endpointOptions
+	keyringTarget    string
+	password         string // Read by --password-prompt or from the keyring
 }
 
 func newRunCommand() *cobra.Command {
@@ -45,152 +89,648 @@ By default, all checks are shown in summary mode. Use --detail to control
 the level of detail, and --hide-passing to only show failures and warnings.`,
 		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
-			// Resolve DSN: positional argument > environment variable
-			var dsn string
-			if len(args) > 0 {
-				dsn = args[0]
-			} else {
-				dsn = os.Getenv("PGDOCTOR_DSN")
+			dsn, err := opts.resolveDSN(args, "run")
+			if err != nil {
+				return err
 			}
-			if dsn == "" {
-				return fmt.Errorf("connection string required: pgdoctor run <DSN> or set PGDOCTOR_DSN environment variable")
+
+			if err := opts.validateConnection(); err != nil {
+				return err
+			}
+			runOpts, err := opts.prepare(cmd)
+			if err != nil {
+				return err
 			}
 
-			// Default to 'brief' detail when --only is used
-			if len(opts.only) > 0 && !cmd.Flags().Changed("detail") {
-				opts.detail = string(detailBrief)
+			// Track the schema per database in the history file; explicit --set wins.
+			if opts.history != "" {
+				settings := runOpts.Config["schema-changes"]
+				if settings == nil {
+					settings = map[string]string{}
+					runOpts.Config["schema-changes"] = settings
+				}
+				if _, ok := settings["history"]; !ok {
+					settings["history"] = opts.history
+				}
+				if _, ok := settings["database"]; !ok {
+					settings["database"] = parseDSNLabel(dsn)
+				}
 			}
 
 			ctx := cmd.Context()
 
-			conn, err := pgx.Connect(ctx, dsn)
-			if err != nil {
+			sess := opts.newSession(dsn)
+			if err := sess.open(ctx); err != nil {
 				fmt.Fprintf(os.Stderr, "Error: failed to connect to database: %v\n", err)
 				return &SilentError{ExitCode: 2}
 			}
-			defer conn.Close(ctx)
+			defer sess.close(ctx)
 
-			// Set statement_timeout so PostgreSQL kills individual slow queries.
-			if _, err := conn.Exec(ctx, fmt.Sprintf("SET statement_timeout = %d", pgdoctor.DefaultStatementTimeoutMs)); err != nil {
-				fmt.Fprintf(os.Stderr, "Error: failed to set statement_timeout: %v\n", err)
-				return &SilentError{ExitCode: 2}
-			}
-
-			allChecks := pgdoctor.AllChecks()
+			runOpts.Retry = sess.retry
+			runOpts.Reconnect = sess.reconnect
 
-			// Apply preset filter
-			if opts.preset != presetAll {
-				presetChecks := getPresetChecks(opts.preset)
-				if len(opts.only) == 0 {
-					opts.only = presetChecks
-				} else {
-					opts.only = intersect(opts.only, presetChecks)
+			var replicas []*session
+			defer func() {
+				for _, replica := range replicas {
+					replica.close(ctx)
+				}
+			}()
+			for _, replicaDSN := range opts.replicas {
+				replica := opts.newSession(replicaDSN)
+				if err := replica.open(ctx); err != nil {
+					fmt.Fprintf(os.Stderr, "Error: failed to connect to replica %s: %v\n", parseDSNLabel(replicaDSN), err)
+					return &SilentError{ExitCode: 2}
 				}
+				replicas = append(replicas, replica)
+				runOpts.Replicas = append(runOpts.Replicas, replica.conn)
 			}
 
-			// Validate and apply filters
-			validOnly, invalidOnly := pgdoctor.ValidateFilters(allChecks, opts.only)
-			validIgnored, invalidIgnored := pgdoctor.ValidateFilters(allChecks, opts.ignored)
+			return opts.execute(cmd, sess.conn, runOpts, parseDSNLabel(dsn))
+		},
+	}
 
-			var allInvalid []string
-			allInvalid = append(allInvalid, invalidOnly...)
-			allInvalid = append(allInvalid, invalidIgnored...)
+	addReportFlags(cmd, opts)
+	addConnectionFlags(cmd, opts)
+	cmd.Flags().StringVar(&opts.history, "history", "", "File keeping each database's schema digest between runs, so schema-changes reports what changed")
+	cmd.Flags().StringArrayVar(&opts.replicas, "replica", nil, "Streaming replica to run expensive catalog scans on (e.g. index-bloat), tried in order; repeatable")
 
-			if len(allInvalid) > 0 {
-				fmt.Fprintf(os.Stderr, "Warning: ignoring invalid filter(s): %v\n\n", allInvalid)
-			}
+	return cmd
+}
 
-			if len(opts.only) > 0 && len(validOnly) == 0 {
-				fmt.Fprintf(os.Stderr, "Error: no valid checks found for --only filter(s): %v\n", invalidOnly)
-				return &SilentError{ExitCode: 1}
-			}
+// addReportFlags registers the flags shared by run and analyze: check selection,
+// scope and output.
+func addReportFlags(cmd *cobra.Command, opts *runOptions) {
+	addSelectionFlags(cmd, opts)
+	cmd.Flags().StringVar(&opts.detail, "detail", string(detailBrief), "Detail level: summary, brief (default), verbose, debug")
+	cmd.Flags().BoolVar(&opts.hidePassing, "hide-passing", false, "Hide passing checks")
+	cmd.Flags().StringVar(&opts.output, "output", "text", "Output format: text (default), json")
+	cmd.Flags().StringVar(&opts.units, "units", "binary", "Byte units: binary (KiB, MiB, GiB) or decimal (kB, MB, GB, as cloud billing uses)")
+	cmd.Flags().Float64Var(&opts.costPerGB, "cost-per-gb", 0, "Monthly storage price per GB, used to estimate savings from reclaimable space (e.g. 0.10)")
+}
 
-			checks := pgdoctor.Filter(allChecks, validOnly, validIgnored)
-			sortChecksByCategory(checks)
+// addSelectionFlags registers the flags that decide which checks run and on which
+// objects, for commands that evaluate checks without rendering a report.
+func addSelectionFlags(cmd *cobra.Command, opts *runOptions) {
+	cmd.Flags().StringSliceVar(&opts.ignored, "ignore", nil, "Checks or categories to ignore (glob patterns allowed, e.g. 'index-*')")
+	cmd.Flags().StringSliceVar(&opts.only, "only", nil, "Only run these checks or categories (glob patterns allowed, e.g. 'table-*')")
+	cmd.Flags().StringVar(&opts.preset, "preset", presetAll, "Check preset: all (default), triage")
+	cmd.Flags().StringSliceVar(&opts.schemas, "schemas", nil, "Only report on objects in these schemas (glob patterns allowed)")
+	cmd.Flags().StringSliceVar(&opts.excludeSchemas, "exclude-schemas", nil, "Skip objects in these schemas (glob patterns allowed)")
+	cmd.Flags().StringSliceVar(&opts.includeTables, "include-tables", nil, "Only report on these tables (glob or /regex/; patterns with a dot match schema.table)")
+	cmd.Flags().StringSliceVar(&opts.excludeTables, "exclude-tables", nil, "Skip these tables (glob or /regex/; patterns with a dot match schema.table)")
+	cmd.Flags().Int64Var(&opts.minRows, "min-rows", 1000, "Skip tables with fewer estimated rows in per-table checks (0 disables)")
+	cmd.Flags().StringVar(&opts.minTableSize, "min-table-size", "10MB", "Skip tables smaller than this in per-table checks (0 disables)")
+	cmd.Flags().StringVar(&opts.systemMemory, "system-memory", "", "Total memory of the database server (e.g. 16GB), for memory sizing checks such as shared-buffers")
+	cmd.Flags().StringArrayVar(&opts.settings, "set", nil, "Per-check setting as check-id.key=value (e.g. table-bloat.min_size=100MB); repeatable")
+	cmd.Flags().StringVar(&opts.customChecks, "custom-checks", "", "JSON file of custom SQL checks to run alongside the built-in ones")
+	cmd.Flags().StringArrayVar(&opts.severities, "severity", nil, "Override the severity of a check's warnings and failures as check-id[/finding-id]=ok|warn|fail; repeatable")
+	cmd.Flags().DurationVar(&opts.checkTimeout, "check-timeout", pgdoctor.DefaultCheckTimeout, "Maximum time for a single check before it is skipped (0 disables)")
+}
 
-			runOpts := pgdoctor.Options{
-				Checks: checks,
-			}
+// addConnectionFlags registers the flags that only apply to a live connection.
+func addConnectionFlags(cmd *cobra.Command, opts *runOptions) {
+	cmd.Flags().DurationVar(&opts.statementTimeout, "statement-timeout", pgdoctor.DefaultStatementTimeoutMs*time.Millisecond, "PostgreSQL statement_timeout for each query (0 disables)")
+	cmd.Flags().IntVar(&opts.retries, "retries", pgdoctor.DefaultRetryPolicy.MaxRetries, "Retries for transient connection failures (admin shutdown, failover), with exponential backoff")
+	cmd.Flags().DurationVar(&opts.retryDelay, "retry-delay", pgdoctor.DefaultRetryPolicy.Delay, "Delay before the first retry; doubles on each subsequent retry")
+	cmd.Flags().DurationVar(&opts.keepalive, "keepalive", defaultKeepalive, "TCP keepalive interval in both directions, so load balancers and proxies don't drop the connection during slow checks (0 keeps the OS defaults)")
+	cmd.Flags().StringVar(&opts.tls.mode, "ssl-mode", "", "TLS mode as in libpq: disable, allow, prefer, require, verify-ca, verify-full (default: the DSN's sslmode, else prefer)")
+	cmd.Flags().StringVar(&opts.tls.rootCert, "ssl-root-cert", "", "CA certificate file used to verify the server (verify-ca, verify-full)")
+	cmd.Flags().StringVar(&opts.tls.cert, "ssl-cert", "", "Client certificate file, for certificate authentication (with --ssl-key)")
+	cmd.Flags().StringVar(&opts.tls.key, "ssl-key", "", "Private key file of the client certificate")
+	cmd.Flags().StringVar(&opts.cloudSQL.instance, "cloudsql-instance", "", "Connect to this Cloud SQL instance (project:region:instance) through the connector handshake instead of the DSN's host; no auth proxy needed")
+	cmd.Flags().StringVar(&opts.cloudSQL.ipType, "cloudsql-ip-type", "public", "Cloud SQL IP address to connect to: public or private")
+	cmd.Flags().BoolVar(&opts.cloudSQL.iamAuth, "cloudsql-iam-auth", false, "Log in with Cloud SQL IAM database authentication; the DSN user is the IAM principal and no password is needed")
+	cmd.Flags().StringVar(&opts.endpoint.host, "host", "", "Database host; with the other discrete flags, an alternative to the DSN (overrides the DSN's host)")
+	cmd.Flags().StringVar(&opts.endpoint.port, "port", "", "Database port")
+	cmd.Flags().StringVar(&opts.endpoint.user, "user", "", "Database user")
+	cmd.Flags().StringVar(&opts.endpoint.dbname, "dbname", "", "Database name")
+	cmd.Flags().StringVar(&opts.endpoint.socketDir, "socket-dir", "", "Connect through the Unix domain socket in this directory, e.g. /var/run/postgresql")
+	cmd.Flags().BoolVar(&opts.endpoint.passwordPrompt, "password-prompt", false, "Prompt for the password on the terminal and use it for every connection of the run")
+	cmd.Flags().StringVar(&opts.keyringTarget, "keyring", "", "Use the password stored in the OS keyring for this target (see pgdoctor auth store)")
+	cmd.Flags().BoolVar(&opts.azureAD, "azure-ad-auth", false, "Log in to Azure Database for PostgreSQL with a Microsoft Entra ID token, fetched for every connection from the environment, a managed identity or az login; the DSN user is the Entra principal")
+}
 
-			// JSON output: batch collect then render
-			if opts.output == "json" {
-				var reports []*check.Report
-				runOpts.OnReport = pgdoctor.Collect(&reports)
-				pgdoctor.Run(ctx, conn, runOpts)
+// validateConnection checks the connection flags registered by addConnectionFlags
+// and reads the password asked for by --password-prompt or --keyring.
+func (opts *runOptions) validateConnection() error {
+	if opts.statementTimeout < 0 {
+		return fmt.Errorf("--statement-timeout must not be negative")
+	}
+	if opts.retries < 0 || opts.retryDelay < 0 {
+		return fmt.Errorf("--retries and --retry-delay must not be negative")
+	}
+	if opts.keepalive < 0 {
+		return fmt.Errorf("--keepalive must not be negative")
+	}
+	if err := opts.tls.validate(); err != nil {
+		return err
+	}
+	if err := opts.cloudSQL.validate(); err != nil {
+		return err
+	}
+	if opts.cloudSQL.instance != "" && len(opts.replicas) > 0 {
+		return fmt.Errorf("--cloudsql-instance cannot be combined with --replica")
+	}
+	if opts.azureAD && opts.cloudSQL.iamAuth {
+		return fmt.Errorf("--azure-ad-auth cannot be combined with --cloudsql-iam-auth")
+	}
+	if opts.endpoint.passwordPrompt && opts.keyringTarget != "" {
+		return fmt.Errorf("--password-prompt and --keyring cannot be combined")
+	}
+	if (opts.endpoint.passwordPrompt || opts.keyringTarget != "") && (opts.azureAD || opts.cloudSQL.iamAuth) {
+		return fmt.Errorf("--password-prompt and --keyring cannot be combined with token authentication")
+	}
+	if err := opts.endpoint.validate(); err != nil {
+		return err
+	}
+	if opts.endpoint.passwordPrompt && opts.password == "" {
+		password, err := readPassword()
+		if err != nil {
+			return err
+		}
+		opts.password = password
+	}
+	if opts.keyringTarget != "" && opts.password == "" {
+		password, err := newKeyring().get(context.Background(), opts.keyringTarget)
+		if err != nil {
+			return err
+		}
+		opts.password = password
+	}
+	return nil
+}
 
-				w := cmd.OutOrStdout()
-				if err := formatJSON(w, reports); err != nil {
-					fmt.Fprintf(os.Stderr, "Error: %v\n", err)
-					return &SilentError{ExitCode: 1}
-				}
-				return nil
-			}
+// newSession returns a session configured by the connection flags.
+func (opts *runOptions) newSession(dsn string) *session {
+	s := &session{
+		dsn:              opts.tls.apply(dsn),
+		statementTimeout: opts.statementTimeout,
+		keepalive:        opts.keepalive,
+		retry:            pgdoctor.RetryPolicy{MaxRetries: opts.retries, Delay: opts.retryDelay},
+		cloudSQL:         opts.cloudSQL.dialer(opts.keepalive),
+	}
+	switch {
+	case opts.azureAD:
+		s.password = newAzureTokenSource().token
+	case opts.password != "":
+		password := opts.password
+		s.password = func(context.Context) (string, error) { return password, nil }
+	}
+	return s
+}
 
-			// Text output: stream results with category headers
-			w := cmd.OutOrStdout()
-			dbLabel := parseDSNLabel(dsn)
-			fmt.Fprintf(w, "Database Health Check: %s\n\n", dbLabel)
+// prepare validates the report flags and resolves the checks, scope and
+// per-check config to run.
+func (opts *runOptions) prepare(cmd *cobra.Command) (pgdoctor.Options, error) {
+	// Default to 'brief' detail when --only is used
+	if len(opts.only) > 0 && !cmd.Flags().Changed("detail") {
+		opts.detail = string(detailBrief)
+	}
 
-			var reports []*check.Report
-			var currentCategory string
-			maxSeverity := check.SeverityOK
+	if err := check.ValidatePatterns(opts.schemas); err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--schemas: %w", err)
+	}
+	if err := check.ValidatePatterns(opts.excludeSchemas); err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--exclude-schemas: %w", err)
+	}
+	if err := check.ValidatePatterns(opts.includeTables); err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--include-tables: %w", err)
+	}
+	if err := check.ValidatePatterns(opts.excludeTables); err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--exclude-tables: %w", err)
+	}
 
-			runOpts.OnReport = func(r *check.Report) {
-				reports = append(reports, r)
-				if r.Severity > maxSeverity {
-					maxSeverity = r.Severity
-				}
+	if opts.checkTimeout < 0 {
+		return pgdoctor.Options{}, fmt.Errorf("--check-timeout must not be negative")
+	}
+	if opts.costPerGB < 0 {
+		return pgdoctor.Options{}, fmt.Errorf("--cost-per-gb must not be negative")
+	}
 
-				// Print category header on transition
-				cat := string(r.Category)
-				if cat != currentCategory {
-					if currentCategory != "" {
-						fmt.Fprintln(w)
-					}
-					title := strings.ToUpper(cat)
-					fmt.Fprintln(w, title)
-					fmt.Fprintln(w, strings.Repeat("─", len(title)))
-					currentCategory = cat
-				}
+	units, err := check.ParseByteUnits(opts.units)
+	if err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--units: %w", err)
+	}
+	opts.byteUnits = units
 
-				if r.Severity == check.SeverityOK && opts.hidePassing {
-					return
-				}
+	if opts.minRows < 0 {
+		return pgdoctor.Options{}, fmt.Errorf("--min-rows must not be negative")
+	}
+	minSizeBytes, err := check.ParseBytes(opts.minTableSize)
+	if err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--min-table-size: %w", err)
+	}
+	instance, err := parseSystemMemory(opts.systemMemory)
+	if err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--system-memory: %w", err)
+	}
+	config, err := parseSettings(opts.settings)
+	if err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--set: %w", err)
+	}
 
-				if opts.detail == string(detailSummary) {
-					printCheckSummary(w, r, opts)
-				} else {
-					printCheckReport(w, r, opts)
-				}
-			}
-			pgdoctor.Run(ctx, conn, runOpts)
+	allChecks := pgdoctor.AllChecks()
+	if opts.customChecks != "" {
+		customChecks, err := loadCustomChecks(opts.customChecks, allChecks)
+		if err != nil {
+			return pgdoctor.Options{}, fmt.Errorf("--custom-checks: %w", err)
+		}
+		allChecks = append(allChecks, customChecks...)
+	}
 
-			fmt.Fprintln(w)
-			printSummary(w, reports)
+	severityOverrides, err := parseSeverityOverrides(opts.severities, allChecks)
+	if err != nil {
+		return pgdoctor.Options{}, fmt.Errorf("--severity: %w", err)
+	}
 
-			if opts.detail == string(detailSummary) || opts.detail == string(detailBrief) {
-				dimFunc := dimColor()
-				fmt.Fprintf(w, "%s\n", dimFunc("To see more: pgdoctor run ... --detail verbose"))
-				fmt.Fprintf(w, "%s\n", dimFunc("To see how to fix: pgdoctor explain <check-id>"))
-				fmt.Fprintln(w)
-			}
+	// Validate filters, expanding glob patterns to the checks they match
+	validOnly, invalidOnly := pgdoctor.ValidateFilters(allChecks, opts.only)
+	validIgnored, invalidIgnored := pgdoctor.ValidateFilters(allChecks, opts.ignored)
+
+	var allInvalid []string
+	allInvalid = append(allInvalid, invalidOnly...)
+	allInvalid = append(allInvalid, invalidIgnored...)
+
+	// A typo or stale pattern would otherwise silently run the wrong set of checks.
+	if len(allInvalid) > 0 {
+		fmt.Fprintf(os.Stderr, "Error: filter(s) match no check or category: %v\n", allInvalid)
+		fmt.Fprintf(os.Stderr, "Run 'pgdoctor list' to see available checks.\n")
+		return pgdoctor.Options{}, &SilentError{ExitCode: 1}
+	}
 
-			if maxSeverity == check.SeverityFail {
-				return &SilentError{ExitCode: 1}
+	// Apply preset filter
+	if !slices.Contains(presets, opts.preset) {
+		return pgdoctor.Options{}, fmt.Errorf("--preset: unknown preset %q (expected one of %s)", opts.preset, strings.Join(presets, ", "))
+	}
+	if opts.preset != presetAll {
+		presetChecks := getPresetChecks(allChecks, opts.preset)
+		if len(validOnly) == 0 {
+			validOnly = presetChecks
+		} else {
+			validOnly = intersect(validOnly, presetChecks)
+			if len(validOnly) == 0 {
+				fmt.Fprintf(os.Stderr, "Error: no checks match both --only and --preset %s\n", opts.preset)
+				return pgdoctor.Options{}, &SilentError{ExitCode: 1}
 			}
+		}
+	}
 
-			return nil
+	checks := pgdoctor.Filter(allChecks, validOnly, validIgnored)
+	sortChecksByCategory(checks)
+
+	return pgdoctor.Options{
+		Checks:            checks,
+		Config:            config,
+		CheckTimeout:      opts.checkTimeout,
+		SeverityOverrides: severityOverrides,
+		ByteUnits:         units,
+		Instance:          instance,
+		Scope: &check.Scope{
+			Schemas:        opts.schemas,
+			ExcludeSchemas: opts.excludeSchemas,
+			IncludeTables:  opts.includeTables,
+			ExcludeTables:  opts.excludeTables,
+			MinRows:        opts.minRows,
+			MinSizeBytes:   minSizeBytes,
 		},
+	}, nil
+}
+
+// execute runs the checks against conn and renders the reports.
+// label names the database in the text output header.
+func (opts *runOptions) execute(cmd *cobra.Command, conn db.DBTX, runOpts pgdoctor.Options, label string) error {
+	ctx := cmd.Context()
+	start := time.Now()
+	runOpts.Provider = detectProvider(ctx, conn)
+
+	// JSON output: batch collect then render
+	if opts.output == "json" {
+		var reports []*check.Report
+		runOpts.OnReport = pgdoctor.Collect(&reports)
+		pgdoctor.Run(ctx, conn, runOpts)
+		defer printExitSummary(cmd.ErrOrStderr(), reports, time.Since(start))
+
+		w := cmd.OutOrStdout()
+		if err := formatJSON(w, reports, opts.costPerGB, opts.byteUnits); err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			return &SilentError{ExitCode: 1}
+		}
+		return nil
 	}
 
-	cmd.Flags().StringSliceVar(&opts.ignored, "ignore", nil, "Checks or categories to ignore")
-	cmd.Flags().StringSliceVar(&opts.only, "only", nil, "Only run these checks or categories")
-	cmd.Flags().StringVar(&opts.preset, "preset", presetAll, "Check preset: all (default), triage")
-	cmd.Flags().StringVar(&opts.detail, "detail", string(detailBrief), "Detail level: summary, brief (default), verbose, debug")
-	cmd.Flags().BoolVar(&opts.hidePassing, "hide-passing", false, "Hide passing checks")
-	cmd.Flags().StringVar(&opts.output, "output", "text", "Output format: text (default), json")
+	// Text output: stream results with category headers
+	w := cmd.OutOrStdout()
+	fmt.Fprintf(w, "Database Health Check: %s%s\n\n", label, providerSuffix(runOpts.Provider))
+
+	reporter := &textReporter{w: w, opts: opts}
+	runOpts.OnReport = reporter.report
+	pgdoctor.Run(ctx, conn, runOpts)
+	reporter.finish()
+	defer printExitSummary(cmd.ErrOrStderr(), reporter.reports, time.Since(start))
+
+	if opts.detail == string(detailSummary) || opts.detail == string(detailBrief) {
+		dimFunc := dimColor()
+		fmt.Fprintf(w, "%s\n", dimFunc("To see more: pgdoctor run ... --detail verbose"))
+		fmt.Fprintf(w, "%s\n", dimFunc("To see how to fix: pgdoctor explain <check-id>"))
+		fmt.Fprintln(w)
+	}
 
-	return cmd
+	if reporter.maxSeverity == check.SeverityFail {
+		return &SilentError{ExitCode: 1}
+	}
+
+	return nil
+}
+
+// detectProvider identifies the managed service behind conn. Detection is best
+// effort: on error (e.g. a snapshot from an older pgdoctor) the server is treated
+// as self-managed.
+func detectProvider(ctx context.Context, conn db.DBTX) check.Provider {
+	provider, err := pgdoctor.DetectProvider(ctx, conn)
+	if err != nil {
+		return check.ProviderSelfManaged
+	}
+	return provider
+}
+
+// providerSuffix names a managed service in the report header, e.g. " (Amazon RDS)".
+func providerSuffix(provider check.Provider) string {
+	if !provider.Managed() {
+		return ""
+	}
+	return fmt.Sprintf(" (%s)", provider)
+}
+
+// textReporter prints reports as checks complete, under category headers,
+// and keeps them for the closing summary.
+type textReporter struct {
+	w           io.Writer
+	opts        *runOptions
+	reports     []*check.Report
+	category    string
+	maxSeverity check.Severity
+}
+
+func (t *textReporter) report(r *check.Report) {
+	t.reports = append(t.reports, r)
+	t.maxSeverity = max(t.maxSeverity, r.Severity)
+
+	// Print category header on transition
+	cat := string(r.Category)
+	if cat != t.category {
+		if t.category != "" {
+			fmt.Fprintln(t.w)
+		}
+		title := strings.ToUpper(cat)
+		fmt.Fprintln(t.w, title)
+		fmt.Fprintln(t.w, strings.Repeat("─", len(title)))
+		t.category = cat
+	}
+
+	if r.Severity == check.SeverityOK && t.opts.hidePassing {
+		return
+	}
+
+	if t.opts.detail == string(detailSummary) {
+		printCheckSummary(t.w, r, t.opts)
+	} else {
+		printCheckReport(t.w, r, t.opts)
+	}
+}
+
+// finish prints the summary of every report seen.
+func (t *textReporter) finish() {
+	fmt.Fprintln(t.w)
+	printSummary(t.w, t.reports, t.opts)
+}
+
+// resolveDSN returns the connection string: positional argument > environment
+// variable, with the discrete connection flags (--host, --user, ...) applied.
+// The flags alone are enough; anything they leave out comes from the PG*
+// environment variables, as with libpq.
+func (opts *runOptions) resolveDSN(args []string, command string) (string, error) {
+	dsn := os.Getenv("PGDOCTOR_DSN")
+	if len(args) > 0 {
+		dsn = args[0]
+	}
+	if dsn == "" && !opts.endpoint.isSet() {
+		return "", fmt.Errorf("connection string required: pgdoctor %s <DSN>, set PGDOCTOR_DSN environment variable or use --host/--socket-dir, --user and --dbname", command)
+	}
+	return applyParams(dsn, opts.endpoint.params()), nil
+}
+
+// parseSystemMemory turns the --system-memory size into instance metadata,
+// or nil when the flag is not set.
+func parseSystemMemory(value string) (*check.InstanceMetadata, error) {
+	if value == "" {
+		return nil, nil
+	}
+	bytes, err := check.ParseBytes(value)
+	if err != nil {
+		return nil, err
+	}
+	if bytes == 0 {
+		return nil, fmt.Errorf("must be greater than zero")
+	}
+	return &check.InstanceMetadata{MemoryGB: float64(bytes) / check.GiB}, nil
+}
+
+// parseSettings turns repeated "check-id.key=value" flags into a check.Config.
+func parseSettings(settings []string) (check.Config, error) {
+	config := check.Config{}
+	for _, setting := range settings {
+		name, value, ok := strings.Cut(setting, "=")
+		checkID, key, hasKey := strings.Cut(name, ".")
+		if !ok || !hasKey || checkID == "" || key == "" {
+			return nil, fmt.Errorf("invalid setting %q (expected check-id.key=value)", setting)
+		}
+		if err := validateFloorSetting(key, value); err != nil {
+			return nil, fmt.Errorf("%s: %w", name, err)
+		}
+		if config[checkID] == nil {
+			config[checkID] = map[string]string{}
+		}
+		config[checkID][key] = value
+	}
+	return config, nil
+}
+
+// validateFloorSetting rejects a "min_rows" or "min_size" override that
+// pgdoctor.Run could not parse, which would otherwise leave the run-wide floor
+// in place without a word. Other keys are validated by their check.
+func validateFloorSetting(key, value string) error {
+	switch key {
+	case "min_rows":
+		n, err := strconv.ParseInt(strings.TrimSpace(value), 10, 64)
+		if err != nil {
+			return fmt.Errorf("invalid row count %q", value)
+		}
+		if n < 0 {
+			return fmt.Errorf("must not be negative")
+		}
+	case "min_size":
+		if _, err := check.ParseBytes(value); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// loadCustomChecks reads user-defined checks; their IDs must not shadow a built-in check.
+func loadCustomChecks(path string, builtin []check.Package) ([]check.Package, error) {
+	defs, err := custom.LoadFile(path)
+	if err != nil {
+		return nil, err
+	}
+
+	known := map[string]struct{}{}
+	for _, pkg := range builtin {
+		known[pkg.Metadata().CheckID] = struct{}{}
+	}
+	for _, def := range defs {
+		if _, exists := known[def.ID]; exists {
+			return nil, fmt.Errorf("custom check %q has the ID of a built-in check", def.ID)
+		}
+	}
+	return custom.Packages(defs), nil
+}
+
+// parseSeverityOverrides parses "check-id[/finding-id]=severity" entries.
+// Check IDs must exist; finding IDs are only known once a check has run.
+func parseSeverityOverrides(entries []string, checks []check.Package) (map[string]check.Severity, error) {
+	if len(entries) == 0 {
+		return nil, nil
+	}
+
+	known := map[string]struct{}{}
+	for _, pkg := range checks {
+		known[pkg.Metadata().CheckID] = struct{}{}
+	}
+
+	overrides := map[string]check.Severity{}
+	for _, entry := range entries {
+		target, name, ok := strings.Cut(entry, "=")
+		checkID, _, _ := strings.Cut(target, "/")
+		if !ok || checkID == "" || strings.HasSuffix(target, "/") {
+			return nil, fmt.Errorf("invalid override %q (expected check-id[/finding-id]=ok|warn|fail)", entry)
+		}
+		if _, exists := known[checkID]; !exists {
+			return nil, fmt.Errorf("unknown check %q in %q", checkID, entry)
+		}
+		severity, err := check.ParseSeverity(name)
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", entry, err)
+		}
+		overrides[target] = severity
+	}
+	return overrides, nil
+}
+
+// session owns the run's connection and replaces it after transient failures.
+type session struct {
+	dsn              string
+	statementTimeout time.Duration
+	keepalive        time.Duration
+	retry            pgdoctor.RetryPolicy
+	cloudSQL         *cloudSQLDialer                       // Set by --cloudsql-instance
+	password         func(context.Context) (string, error) // Set by --azure-ad-auth
+	conn             *pgx.Conn
+}
+
+// open dials the database, retrying transient failures, and prepares the connection.
+func (s *session) open(ctx context.Context) error {
+	var conn *pgx.Conn
+	err := pgdoctor.Retry(ctx, s.retry, func(ctx context.Context) error {
+		var err error
+		conn, err = s.connect(ctx)
+		return err
+	})
+	if err != nil {
+		return err
+	}
+
+	// Set statement_timeout so PostgreSQL kills individual slow queries.
+	if _, err := conn.Exec(ctx, fmt.Sprintf("SET statement_timeout = %d", s.statementTimeout.Milliseconds())); err != nil {
+		_ = conn.Close(ctx)
+		return fmt.Errorf("setting statement_timeout: %w", err)
+	}
+
+	// A query that runs for minutes sends nothing over the wire, and NLBs or
+	// proxies with short idle timeouts drop the connection. Ask the server to
+	// send keepalive probes too. Best effort: unsupported platforms reject it.
+	if secs := int(s.keepalive.Seconds()); secs > 0 {
+		_, _ = conn.Exec(ctx, fmt.Sprintf("SET tcp_keepalives_idle = %d", secs))
+		_, _ = conn.Exec(ctx, fmt.Sprintf("SET tcp_keepalives_interval = %d", secs))
+	}
+
+	s.conn = conn
+	return nil
+}
+
+// reconnect drops the current connection and opens a new one (see pgdoctor.Options.Reconnect).
+func (s *session) reconnect(ctx context.Context) (db.DBTX, error) {
+	s.close(ctx)
+	if err := s.open(ctx); err != nil {
+		return nil, err
+	}
+	return s.conn, nil
+}
+
+func (s *session) close(ctx context.Context) {
+	if s.conn != nil {
+		_ = s.conn.Close(ctx)
+		s.conn = nil
+	}
+}
+
+// connect opens a single connection, sending TCP keepalive probes after keepalive of inactivity.
+// Context cancellation (e.g. a check timeout) sends a PostgreSQL cancel request
+// instead of closing the socket, so the remaining checks can reuse the connection.
+// With a Cloud SQL dialer, the DSN only supplies the user, database and options.
+// With a password source, every connection gets a fresh password, so reconnects
+// outlive short-lived tokens.
+func (s *session) connect(ctx context.Context) (*pgx.Conn, error) {
+	config, err := pgx.ParseConfig(s.dsn)
+	if err != nil {
+		return nil, err
+	}
+
+	// Every transaction is read-only, so PostgreSQL rejects writes even from a
+	// statement that gets past pgdoctor's own read-only guard.
+	config.RuntimeParams["default_transaction_read_only"] = "on"
+
+	if s.keepalive > 0 {
+		dialer := &net.Dialer{
+			KeepAliveConfig: net.KeepAliveConfig{Enable: true, Idle: s.keepalive, Interval: s.keepalive, Count: 3},
+		}
+		config.DialFunc = dialer.DialContext
+	}
+
+	if s.cloudSQL != nil {
+		// The dialer returns an mTLS connection to the instance: PostgreSQL's own
+		// TLS negotiation and the DSN's host are not used.
+		config.DialFunc = s.cloudSQL.dial
+		config.LookupFunc = func(_ context.Context, host string) ([]string, error) { return []string{host}, nil }
+		config.TLSConfig = nil
+		config.Fallbacks = nil
+	}
+
+	if s.password != nil {
+		password, err := s.password(ctx)
+		if err != nil {
+			return nil, fmt.Errorf("fetching password: %w", err)
+		}
+		config.Password = password
+	}
+
+	config.BuildContextWatcherHandler = func(pgConn *pgconn.PgConn) ctxwatch.Handler {
+		return &pgconn.CancelRequestContextWatcherHandler{
+			Conn:          pgConn,
+			DeadlineDelay: 5 * time.Second,
+		}
+	}
+
+	return pgx.ConnectConfig(ctx, config)
 }
 
 func sortChecksByCategory(checks []check.Package) {
@@ -0,0 +1,165 @@
+package cli
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestParseSettings(t *testing.T) {
+	t.Parallel()
+
+	config, err := parseSettings([]string{
+		"table-bloat.min_size=100MB",
+		"table-bloat.min_rows=0",
+		"table-seq-scans.min_rows=5000",
+	})
+	require.NoError(t, err)
+	assert.Equal(t, check.Config{
+		"table-bloat":     {"min_size": "100MB", "min_rows": "0"},
+		"table-seq-scans": {"min_rows": "5000"},
+	}, config)
+
+	for _, invalid := range []string{
+		"table-bloat=1", "min_rows=1", "table-bloat.min_rows", ".min_rows=1",
+		"table-bloat.min_rows=abc", "table-bloat.min_rows=-1", "table-bloat.min_size=10XB",
+	} {
+		_, err := parseSettings([]string{invalid})
+		require.Error(t, err, invalid)
+	}
+}
+
+func TestParseSystemMemory(t *testing.T) {
+	t.Parallel()
+
+	instance, err := parseSystemMemory("")
+	require.NoError(t, err)
+	assert.Nil(t, instance)
+
+	instance, err = parseSystemMemory("16GB")
+	require.NoError(t, err)
+	assert.InDelta(t, 16.0, instance.MemoryGB, 0.001)
+
+	instance, err = parseSystemMemory("512MB")
+	require.NoError(t, err)
+	assert.InDelta(t, 0.5, instance.MemoryGB, 0.001)
+
+	for _, invalid := range []string{"16 apples", "0", "-1GB"} {
+		_, err := parseSystemMemory(invalid)
+		require.Error(t, err, invalid)
+	}
+}
+
+func TestSystemMemoryKeepsServerVersion(t *testing.T) {
+	t.Parallel()
+
+	instance, err := parseSystemMemory("16GB")
+	require.NoError(t, err)
+
+	var seen *check.InstanceMetadata
+	meta := check.Metadata{CheckID: "reads-instance", Name: "reads-instance", Category: check.CategoryConfigs}
+	readsInstance := check.Package{
+		Metadata: func() check.Metadata { return meta },
+		New: func(db.DBTX, check.Config) check.Checker {
+			return checkFunc{meta: meta, run: func(ctx context.Context) (*check.Report, error) {
+				seen = check.InstanceMetadataFromContext(ctx)
+				return check.NewReport(meta), nil
+			}}
+		},
+	}
+
+	fx := snapshot.NewFixture().Add("SessionStatistics",
+		[]string{
+			"total_session_time_ms float8", "total_active_time_ms float8", "total_idle_in_txn_time_ms float8",
+			"total_sessions int8", "sessions_abandoned int8", "sessions_fatal int8", "sessions_killed int8",
+			"session_busy_ratio_percent float8",
+		},
+		[]any{3600000.0, 1080000.0, 36000.0, 1000, 5, 2, 3, 30.0},
+	)
+
+	var reports []*check.Report
+	pgdoctor.Run(context.Background(), fx, pgdoctor.Options{
+		Checks:        append(pgdoctor.Filter(pgdoctor.AllChecks(), []string{"connection-efficiency"}, nil), readsInstance),
+		ServerVersion: 170002,
+		Instance:      instance,
+		OnReport:      pgdoctor.Collect(&reports),
+	})
+
+	require.Len(t, reports, 2)
+	assert.Equal(t, check.SeverityOK, reports[0].Severity)
+	require.Len(t, reports[0].Results, 4, "every session subcheck ran")
+	assert.Equal(t, "busy-ratio", reports[0].Results[0].ID)
+
+	require.NotNil(t, seen)
+	assert.InDelta(t, 16.0, seen.MemoryGB, 0.001)
+	assert.Equal(t, 17, seen.EngineVersionMajor)
+	assert.Equal(t, "17.2", seen.EngineVersion)
+}
+
+// checkFunc is a check.Checker running a function.
+type checkFunc struct {
+	meta check.Metadata
+	run  func(context.Context) (*check.Report, error)
+}
+
+func (c checkFunc) Metadata() check.Metadata { return c.meta }
+
+func (c checkFunc) Check(ctx context.Context) (*check.Report, error) { return c.run(ctx) }
+
+func TestParseSeverityOverrides(t *testing.T) {
+	t.Parallel()
+
+	overrides, err := parseSeverityOverrides([]string{
+		"vacuum-settings/work-mem=ok",
+		"table-bloat=FAIL",
+	}, pgdoctor.AllChecks())
+	require.NoError(t, err)
+	assert.Equal(t, map[string]check.Severity{
+		"vacuum-settings/work-mem": check.SeverityOK,
+		"table-bloat":              check.SeverityFail,
+	}, overrides)
+
+	for _, invalid := range []string{"table-bloat", "=warn", "table-bloat/=ok", "no-such-check=ok", "table-bloat=skip", "table-bloat=critical"} {
+		_, err := parseSeverityOverrides([]string{invalid}, pgdoctor.AllChecks())
+		require.Error(t, err, invalid)
+	}
+}
+
+func TestLoadCustomChecks(t *testing.T) {
+	t.Parallel()
+
+	write := func(t *testing.T, id string) string {
+		t.Helper()
+		path := filepath.Join(t.TempDir(), "checks.json")
+		content := `{"checks": [{"id": "` + id + `", "category": "application", "sql": "SELECT 1 AS n", "rules": [{"column": "n", "op": ">", "value": 0, "severity": "warn"}]}]}`
+		require.NoError(t, os.WriteFile(path, []byte(content), 0o600))
+		return path
+	}
+
+	pkgs, err := loadCustomChecks(write(t, "orders-backlog"), pgdoctor.AllChecks())
+	require.NoError(t, err)
+	require.Len(t, pkgs, 1)
+	assert.Equal(t, "orders-backlog", pkgs[0].Metadata().CheckID)
+
+	_, err = loadCustomChecks(write(t, "table-bloat"), pgdoctor.AllChecks())
+	require.ErrorContains(t, err, "built-in check")
+}
+
+func TestGetPresetChecks(t *testing.T) {
+	t.Parallel()
+
+	triage := getPresetChecks(pgdoctor.AllChecks(), presetTriage)
+	assert.Contains(t, triage, "connection-health")
+	assert.Contains(t, triage, "table-bloat")
+	assert.NotContains(t, triage, "pk-types", "schema-design audits are not incident checks")
+
+	assert.Empty(t, getPresetChecks(pgdoctor.AllChecks(), "no-such-tag"))
+}
@@ -0,0 +1,49 @@
+package cli
+
+import (
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// sslModes are the libpq sslmode values accepted by --ssl-mode.
+var sslModes = []string{"disable", "allow", "prefer", "require", "verify-ca", "verify-full"}
+
+// tlsOptions holds the --ssl-* flags. They map to the libpq parameters of the
+// same name and override any the DSN sets; unset flags leave the DSN alone.
+type tlsOptions struct {
+	mode     string
+	rootCert string
+	cert     string
+	key      string
+}
+
+// validate checks the mode and that a client certificate comes with its key.
+func (t tlsOptions) validate() error {
+	if t.mode != "" && !slices.Contains(sslModes, t.mode) {
+		return fmt.Errorf("--ssl-mode must be one of %s", strings.Join(sslModes, ", "))
+	}
+	if (t.cert == "") != (t.key == "") {
+		return fmt.Errorf("--ssl-cert and --ssl-key must be given together")
+	}
+	if t.mode == "disable" && (t.rootCert != "" || t.cert != "") {
+		return fmt.Errorf("--ssl-root-cert, --ssl-cert and --ssl-key need an --ssl-mode other than disable")
+	}
+	return nil
+}
+
+// params returns the libpq parameters set by the flags, in a stable order.
+func (t tlsOptions) params() [][2]string {
+	var params [][2]string
+	for _, p := range [][2]string{{"sslmode", t.mode}, {"sslrootcert", t.rootCert}, {"sslcert", t.cert}, {"sslkey", t.key}} {
+		if p[1] != "" {
+			params = append(params, p)
+		}
+	}
+	return params
+}
+
+// apply adds the TLS parameters to dsn, overriding the DSN's own.
+func (t tlsOptions) apply(dsn string) string {
+	return applyParams(dsn, t.params())
+}
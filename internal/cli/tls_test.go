@@ -0,0 +1,55 @@
+package cli
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestTLSOptions_Apply(t *testing.T) {
+	t.Parallel()
+
+	verify := tlsOptions{mode: "verify-full", rootCert: "/etc/ssl/rds ca.pem"}
+
+	tests := []struct {
+		name string
+		tls  tlsOptions
+		dsn  string
+		want string
+	}{
+		{
+			name: "no flags leave the DSN alone",
+			dsn:  "postgres://u@db:5432/app?sslmode=disable",
+			want: "postgres://u@db:5432/app?sslmode=disable",
+		},
+		{
+			name: "URL parameters are overridden",
+			tls:  verify,
+			dsn:  "postgres://u@db:5432/app?sslmode=disable&application_name=x",
+			want: "postgres://u@db:5432/app?application_name=x&sslmode=verify-full&sslrootcert=%2Fetc%2Fssl%2Frds+ca.pem",
+		},
+		{
+			name: "keyword/value DSN gets quoted keywords appended",
+			tls:  tlsOptions{mode: "require", cert: `C:\certs\it's.crt`, key: "client.key"},
+			dsn:  "host=db dbname=app sslmode=disable",
+			want: `host=db dbname=app sslmode=disable sslmode='require' sslcert='C:\\certs\\it\'s.crt' sslkey='client.key'`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+			assert.Equal(t, tt.want, tt.tls.apply(tt.dsn))
+		})
+	}
+}
+
+func TestTLSOptions_Validate(t *testing.T) {
+	t.Parallel()
+
+	assert.NoError(t, tlsOptions{}.validate())
+	assert.NoError(t, tlsOptions{mode: "verify-full", rootCert: "ca.pem", cert: "c.crt", key: "c.key"}.validate())
+	assert.Error(t, tlsOptions{mode: "strict"}.validate())
+	assert.Error(t, tlsOptions{cert: "c.crt"}.validate(), "certificate without key")
+	assert.Error(t, tlsOptions{mode: "disable", rootCert: "ca.pem"}.validate())
+}
@@ -0,0 +1,114 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+	"runtime"
+	"runtime/debug"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/emancu/pgdoctor"
+)
+
+// buildInfo describes a pgdoctor binary, so automation can check what a
+// deployed binary supports before invoking it.
+type buildInfo struct {
+	Version       string   `json:"version"`
+	Commit        string   `json:"commit,omitempty"`
+	CommitTime    string   `json:"commit_time,omitempty"`
+	Modified      bool     `json:"modified,omitempty"`
+	GoVersion     string   `json:"go_version"`
+	Platform      string   `json:"platform"`
+	Features      []string `json:"features"`
+	OutputFormats []string `json:"output_formats"`
+	Checks        int      `json:"checks"`
+	Categories    []string `json:"categories"`
+}
+
+func newVersionCommand(version string) *cobra.Command {
+	var asJSON bool
+
+	cmd := &cobra.Command{
+		Use:   "version",
+		Short: "Show version, build and capability information",
+		Long: `Show the pgdoctor version together with the commit it was built from,
+the optional integrations compiled in, the supported output formats and the
+number of bundled checks.
+
+Use --json for a machine-readable form.`,
+		Args: cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			info := readBuildInfo(version)
+			w := cmd.OutOrStdout()
+
+			if asJSON {
+				enc := json.NewEncoder(w)
+				enc.SetIndent("", "  ")
+				if err := enc.Encode(info); err != nil {
+					return fmt.Errorf("encoding JSON: %w", err)
+				}
+				return nil
+			}
+
+			fmt.Fprintf(w, "pgdoctor %s\n", info.Version)
+			if info.Commit != "" {
+				commit := info.Commit
+				if info.Modified {
+					commit += " (modified)"
+				}
+				fmt.Fprintf(w, "commit:         %s\n", commit)
+			}
+			fmt.Fprintf(w, "go:             %s %s\n", info.GoVersion, info.Platform)
+			fmt.Fprintf(w, "features:       %s\n", featureSummary())
+			fmt.Fprintf(w, "output formats: %s\n", strings.Join(info.OutputFormats, ", "))
+			fmt.Fprintf(w, "checks:         %d (%s)\n", info.Checks, strings.Join(info.Categories, ", "))
+			return nil
+		},
+	}
+
+	cmd.Flags().BoolVar(&asJSON, "json", false, "Print version information as JSON")
+
+	return cmd
+}
+
+func readBuildInfo(version string) buildInfo {
+	features := enabledFeatures()
+	if features == nil {
+		features = []string{}
+	}
+
+	info := buildInfo{
+		Version:       version,
+		GoVersion:     runtime.Version(),
+		Platform:      runtime.GOOS + "/" + runtime.GOARCH,
+		Features:      features,
+		OutputFormats: outputFormats,
+	}
+
+	seen := map[string]struct{}{}
+	for _, pkg := range pgdoctor.AllChecks() {
+		info.Checks++
+		category := string(pkg.Metadata().Category)
+		if _, ok := seen[category]; !ok {
+			seen[category] = struct{}{}
+			info.Categories = append(info.Categories, category)
+		}
+	}
+
+	if build, ok := debug.ReadBuildInfo(); ok {
+		for _, setting := range build.Settings {
+			switch setting.Key {
+			case "vcs.revision":
+				info.Commit = setting.Value
+			case "vcs.time":
+				info.CommitTime = setting.Value
+			case "vcs.modified":
+				info.Modified = setting.Value == "true"
+			}
+		}
+	}
+
+	return info
+}
@@ -0,0 +1,29 @@
+package cli
+
+import (
+	"bytes"
+	"encoding/json"
+	"testing"
+
+	"github.com/emancu/pgdoctor"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestVersionCommand_JSON(t *testing.T) {
+	t.Parallel()
+
+	cmd := newVersionCommand("v1.2.3")
+	var buf bytes.Buffer
+	cmd.SetOut(&buf)
+	cmd.SetArgs([]string{"--json"})
+	require.NoError(t, cmd.Execute())
+
+	var info buildInfo
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &info))
+	assert.Equal(t, "v1.2.3", info.Version)
+	assert.Equal(t, len(pgdoctor.AllChecks()), info.Checks)
+	assert.Equal(t, []string{"text", "json"}, info.OutputFormats)
+	assert.Contains(t, info.Categories, "vacuum")
+	assert.NotNil(t, info.Features)
+}
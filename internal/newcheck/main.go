@@ -0,0 +1,228 @@
+// Package main scaffolds a new check package so every check starts from the same layout:
+//
+//	go run ./internal/newcheck --category indexes my-check
+//
+// It writes checks/<package>/{check.go,query.sql,README.md,check_test.go} and adds the
+// package to sqlc.yaml. Running sqlc and go generate afterwards registers the check.
+package main
+
+import (
+	"bytes"
+	"flag"
+	"fmt"
+	"go/format"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"text/template"
+)
+
+// categories mirrors the check.Category constants.
+var categories = map[string]string{
+	"configs":     "CategoryConfigs",
+	"indexes":     "CategoryIndexes",
+	"performance": "CategoryPerformance",
+	"schema":      "CategorySchema",
+	"security":    "CategorySecurity",
+	"vacuum":      "CategoryVacuum",
+}
+
+var checkIDPattern = regexp.MustCompile(`^[a-z][a-z0-9]*(-[a-z0-9]+)*$`)
+
+// scaffold holds the names derived from a check ID, e.g. "index-usage".
+type scaffold struct {
+	CheckID   string // index-usage
+	Package   string // indexusage
+	Name      string // Index Usage
+	Query     string // IndexUsage (sqlc query and db.<Query>Row)
+	Interface string // IndexUsageQueries
+	Category  string // check.CategoryIndexes
+	SQLCPath  string // checks/indexusage
+}
+
+func main() {
+	category := flag.String("category", "", "Check category: configs, indexes, performance, schema, security, vacuum")
+	flag.Usage = func() {
+		fmt.Fprintf(os.Stderr, "Usage: go run ./internal/newcheck --category <category> <check-id>\n")
+		flag.PrintDefaults()
+	}
+	flag.Parse()
+
+	if flag.NArg() != 1 || *category == "" {
+		flag.Usage()
+		os.Exit(2)
+	}
+
+	if err := run(flag.Arg(0), *category); err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+func run(checkID, category string) error {
+	s, err := newScaffold(checkID, category)
+	if err != nil {
+		return err
+	}
+
+	repoRoot, err := findRepoRoot()
+	if err != nil {
+		return fmt.Errorf("finding repo root: %w", err)
+	}
+
+	files, err := s.write(repoRoot)
+	if err != nil {
+		return err
+	}
+
+	_, _ = fmt.Fprintf(os.Stdout, "✓ Created check %s\n", s.CheckID)
+	for _, file := range files {
+		_, _ = fmt.Fprintf(os.Stdout, "  • %s\n", file)
+	}
+	_, _ = fmt.Fprintf(os.Stdout, `
+Next steps:
+  1. Write the query in %[1]s/query.sql and fill in the TODOs
+  2. sqlc generate
+  3. go generate ./...
+  4. go test ./%[1]s/...
+  5. Add %[2]s to the README "Available Checks" table and the CHANGELOG
+`, s.SQLCPath, "`"+s.CheckID+"`")
+
+	return nil
+}
+
+func newScaffold(checkID, category string) (*scaffold, error) {
+	if !checkIDPattern.MatchString(checkID) {
+		return nil, fmt.Errorf("invalid check ID %q (expected lowercase words separated by dashes, e.g. index-usage)", checkID)
+	}
+	constant, ok := categories[category]
+	if !ok {
+		return nil, fmt.Errorf("unknown category %q (expected configs, indexes, performance, schema, security or vacuum)", category)
+	}
+
+	words := strings.Split(checkID, "-")
+	titles := make([]string, len(words))
+	for i, word := range words {
+		titles[i] = strings.ToUpper(word[:1]) + word[1:]
+	}
+
+	pkg := strings.Join(words, "")
+	query := strings.Join(titles, "")
+	return &scaffold{
+		CheckID:   checkID,
+		Package:   pkg,
+		Name:      strings.Join(titles, " "),
+		Query:     query,
+		Interface: query + "Queries",
+		Category:  "check." + constant,
+		SQLCPath:  "checks/" + pkg,
+	}, nil
+}
+
+// write creates the check package under repoRoot and registers it in sqlc.yaml.
+// It refuses to touch an existing package.
+func (s *scaffold) write(repoRoot string) ([]string, error) {
+	dir := filepath.Join(repoRoot, s.SQLCPath)
+	if _, err := os.Stat(dir); err == nil {
+		return nil, fmt.Errorf("%s already exists", s.SQLCPath)
+	}
+
+	sqlcPath := filepath.Join(repoRoot, "sqlc.yaml")
+	sqlcConfig, err := os.ReadFile(sqlcPath)
+	if err != nil {
+		return nil, fmt.Errorf("reading sqlc.yaml: %w", err)
+	}
+	updated, err := addSQLCPath(string(sqlcConfig), s.SQLCPath)
+	if err != nil {
+		return nil, err
+	}
+
+	if err := os.MkdirAll(dir, 0o755); err != nil {
+		return nil, fmt.Errorf("creating %s: %w", s.SQLCPath, err)
+	}
+
+	var files []string
+	for _, t := range fileTemplates {
+		content, err := s.render(t.name, t.body)
+		if err != nil {
+			return nil, err
+		}
+		path := filepath.Join(dir, t.name)
+		if err := os.WriteFile(path, content, 0o644); err != nil {
+			return nil, fmt.Errorf("writing %s: %w", t.name, err)
+		}
+		files = append(files, filepath.Join(s.SQLCPath, t.name))
+	}
+
+	if err := os.WriteFile(sqlcPath, []byte(updated), 0o644); err != nil {
+		return nil, fmt.Errorf("writing sqlc.yaml: %w", err)
+	}
+	files = append(files, "sqlc.yaml")
+
+	return files, nil
+}
+
+// render executes a file template; Go sources are gofmt'ed.
+func (s *scaffold) render(name, body string) ([]byte, error) {
+	var buf bytes.Buffer
+	if err := template.Must(template.New(name).Parse(body)).Execute(&buf, s); err != nil {
+		return nil, fmt.Errorf("rendering %s: %w", name, err)
+	}
+	if !strings.HasSuffix(name, ".go") {
+		return buf.Bytes(), nil
+	}
+	formatted, err := format.Source(buf.Bytes())
+	if err != nil {
+		return nil, fmt.Errorf("formatting %s: %w", name, err)
+	}
+	return formatted, nil
+}
+
+// addSQLCPath inserts the package after the last checks/ entry in sqlc.yaml,
+// keeping the queries list in the order checks were added.
+func addSQLCPath(config, sqlcPath string) (string, error) {
+	lines := strings.Split(config, "\n")
+	last := -1
+	for i, line := range lines {
+		trimmed := strings.TrimSpace(line)
+		if trimmed == fmt.Sprintf("- %q", sqlcPath) {
+			return "", fmt.Errorf("%s is already listed in sqlc.yaml", sqlcPath)
+		}
+		if strings.HasPrefix(trimmed, `- "checks/`) {
+			last = i
+		}
+	}
+	if last < 0 {
+		return "", fmt.Errorf("no checks/ entries found in sqlc.yaml")
+	}
+
+	indent := lines[last][:len(lines[last])-len(strings.TrimLeft(lines[last], " "))]
+	entry := fmt.Sprintf("%s- %q", indent, sqlcPath)
+
+	out := make([]string, 0, len(lines)+1)
+	out = append(out, lines[:last+1]...)
+	out = append(out, entry)
+	out = append(out, lines[last+1:]...)
+	return strings.Join(out, "\n"), nil
+}
+
+// findRepoRoot finds the repository root by looking for go.mod.
+func findRepoRoot() (string, error) {
+	dir, err := os.Getwd()
+	if err != nil {
+		return "", err
+	}
+
+	for {
+		if _, err := os.Stat(filepath.Join(dir, "go.mod")); err == nil {
+			return dir, nil
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return "", fmt.Errorf("could not find go.mod in any parent directory")
+		}
+		dir = parent
+	}
+}
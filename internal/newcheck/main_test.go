@@ -0,0 +1,82 @@
+package main
+
+import (
+	"go/parser"
+	"go/token"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const testSQLCConfig = `version: "2"
+sql:
+  - engine: "postgresql"
+    queries:
+      - "checks/pgversion"
+      - "checks/tempfiles"
+      # Not a check
+      - "drift"
+`
+
+func TestNewScaffold(t *testing.T) {
+	t.Parallel()
+
+	s, err := newScaffold("fk-missing-index", "indexes")
+	require.NoError(t, err)
+
+	assert.Equal(t, "fkmissingindex", s.Package)
+	assert.Equal(t, "Fk Missing Index", s.Name)
+	assert.Equal(t, "FkMissingIndex", s.Query)
+	assert.Equal(t, "FkMissingIndexQueries", s.Interface)
+	assert.Equal(t, "check.CategoryIndexes", s.Category)
+	assert.Equal(t, "checks/fkmissingindex", s.SQLCPath)
+
+	_, err = newScaffold("Bad_ID", "indexes")
+	require.Error(t, err)
+
+	_, err = newScaffold("good-id", "storage")
+	require.ErrorContains(t, err, "unknown category")
+}
+
+func TestScaffoldWrite(t *testing.T) {
+	t.Parallel()
+
+	root := t.TempDir()
+	require.NoError(t, os.WriteFile(filepath.Join(root, "sqlc.yaml"), []byte(testSQLCConfig), 0o600))
+
+	s, err := newScaffold("lock-waits", "performance")
+	require.NoError(t, err)
+
+	files, err := s.write(root)
+	require.NoError(t, err)
+	assert.Contains(t, files, "checks/lockwaits/check.go")
+	assert.Contains(t, files, "sqlc.yaml")
+
+	// Generated Go sources must parse (they compile once sqlc generates the row type).
+	for _, name := range []string{"check.go", "check_test.go"} {
+		_, err := parser.ParseFile(token.NewFileSet(), filepath.Join(root, "checks/lockwaits", name), nil, 0)
+		require.NoError(t, err, name)
+	}
+
+	sqlcConfig, err := os.ReadFile(filepath.Join(root, "sqlc.yaml"))
+	require.NoError(t, err)
+	assert.Contains(t, string(sqlcConfig), "      - \"checks/tempfiles\"\n      - \"checks/lockwaits\"\n      # Not a check")
+
+	// Never overwrite an existing check.
+	_, err = s.write(root)
+	require.ErrorContains(t, err, "already exists")
+}
+
+func TestAddSQLCPath_Duplicate(t *testing.T) {
+	t.Parallel()
+
+	_, err := addSQLCPath(testSQLCConfig, "checks/tempfiles")
+	require.ErrorContains(t, err, "already listed")
+
+	_, err = addSQLCPath(strings.ReplaceAll(testSQLCConfig, "checks/", "other/"), "checks/x")
+	require.ErrorContains(t, err, "no checks/ entries")
+}
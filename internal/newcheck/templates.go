@@ -0,0 +1,203 @@
+package main
+
+// fileTemplates are rendered with a *scaffold into checks/<package>/.
+// They follow the layout of the existing checks (see checks/invalidindexes).
+var fileTemplates = []struct {
+	name string
+	body string
+}{
+	{name: "query.sql", body: queryTemplate},
+	{name: "README.md", body: readmeTemplate},
+	{name: "check.go", body: checkTemplate},
+	{name: "check_test.go", body: checkTestTemplate},
+}
+
+const queryTemplate = `-- name: {{.Query}} :many
+-- TODO: describe what the query returns and why. Read-only, pg_catalog only,
+-- cheap enough for production (see AGENTS.md "SQL Query Conventions").
+SELECT
+  n.nspname::text AS schema_name
+  , c.relname::text AS table_name
+FROM pg_class AS c
+INNER JOIN pg_namespace AS n ON c.relnamespace = n.oid
+WHERE c.relkind = 'r'
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+ORDER BY n.nspname, c.relname;
+`
+
+const readmeTemplate = `# {{.Name}} Check
+
+TODO: one sentence on what this check identifies.
+
+## What it checks
+
+- TODO
+
+## Why it matters
+
+TODO: the production impact of the problem.
+
+## How to Fix
+
+TODO: the steps to resolve each finding, with SQL where it helps.
+
+## References
+
+- TODO: PostgreSQL documentation links
+`
+
+const checkTemplate = `// Package {{.Package}} implements a check for TODO.
+package {{.Package}}
+
+import (
+	"context"
+	_ "embed"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+//go:embed query.sql
+var querySQL string
+
+//go:embed README.md
+var readme string
+
+type {{.Interface}} interface {
+	{{.Query}}(context.Context) ([]db.{{.Query}}Row, error)
+}
+
+type checker struct {
+	queries {{.Interface}}
+}
+
+func Metadata() check.Metadata {
+	return check.Metadata{
+		Category:    {{.Category}},
+		CheckID:     "{{.CheckID}}",
+		Name:        "{{.Name}}",
+		Description: "TODO: one line shown by pgdoctor list",
+		Readme:      readme,
+		SQL:         querySQL,
+	}
+}
+
+func New(queries {{.Interface}}, _ ...check.Config) check.Checker {
+	return &checker{
+		queries: queries,
+	}
+}
+
+func (c *checker) Metadata() check.Metadata {
+	return Metadata()
+}
+
+func (c *checker) Check(ctx context.Context) (*check.Report, error) {
+	report := check.NewReport(Metadata())
+
+	rows, err := c.queries.{{.Query}}(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("running %s/%s: %w", {{.Category}}, report.CheckID, err)
+	}
+	rows = check.FilterTables(ctx, rows, func(r db.{{.Query}}Row) string { return r.SchemaName + "." + r.TableName })
+
+	if len(rows) == 0 {
+		report.AddFinding(check.Finding{
+			ID:       report.CheckID,
+			Name:     report.Name,
+			Severity: check.SeverityOK,
+		})
+		return report, nil
+	}
+
+	tableRows := make([]check.TableRow, 0, len(rows))
+	for _, row := range rows {
+		tableRows = append(tableRows, check.TableRow{
+			Cells:    []string{row.SchemaName, row.TableName},
+			Severity: check.SeverityWarn,
+		})
+	}
+
+	report.AddFinding(check.Finding{
+		ID:       report.CheckID,
+		Name:     report.Name,
+		Severity: check.SeverityWarn,
+		Details:  fmt.Sprintf("%d table(s) TODO", len(rows)),
+		Table: &check.Table{
+			Headers: []string{"Schema", "Table"},
+			Rows:    tableRows,
+		},
+	})
+
+	return report, nil
+}
+`
+
+const checkTestTemplate = `package {{.Package}}_test
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/checks/{{.Package}}"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/stretchr/testify/require"
+)
+
+type mockQueryer struct {
+	rows []db.{{.Query}}Row
+	err  error
+}
+
+func (m *mockQueryer) {{.Query}}(context.Context) ([]db.{{.Query}}Row, error) {
+	return m.rows, m.err
+}
+
+func Test_{{.Query}}_Severity(t *testing.T) {
+	t.Parallel()
+
+	testCases := []struct {
+		Name     string
+		Rows     []db.{{.Query}}Row
+		Severity check.Severity
+	}{
+		{
+			Name:     "nothing to report - OK",
+			Rows:     []db.{{.Query}}Row{},
+			Severity: check.SeverityOK,
+		},
+		{
+			Name: "one table - WARN",
+			Rows: []db.{{.Query}}Row{
+				{SchemaName: "public", TableName: "events"},
+			},
+			Severity: check.SeverityWarn,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.Name, func(t *testing.T) {
+			t.Parallel()
+
+			checker := {{.Package}}.New(&mockQueryer{rows: tc.Rows})
+			report, err := checker.Check(context.Background())
+			require.NoError(t, err)
+
+			require.Len(t, report.Results, 1)
+			require.Equal(t, tc.Severity, report.Results[0].Severity)
+		})
+	}
+}
+
+func Test_{{.Query}}_QueryError(t *testing.T) {
+	t.Parallel()
+
+	checker := {{.Package}}.New(&mockQueryer{err: fmt.Errorf("connection refused")})
+	_, err := checker.Check(context.Background())
+	require.Error(t, err)
+	require.Contains(t, err.Error(), "{{.CheckID}}")
+}
+`
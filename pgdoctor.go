@@ -5,11 +5,16 @@ package pgdoctor
 import (
 	"context"
 	"errors"
+	"fmt"
+	"path"
+	"strconv"
 	"strings"
 	"time"
 
+	"github.com/emancu/pgdoctor/catalog"
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
 	"github.com/jackc/pgx/v5/pgconn"
 )
 
@@ -17,6 +22,10 @@ import (
 // Callers should SET this on the connection before calling Run().
 const DefaultStatementTimeoutMs = 2000
 
+// DefaultCheckTimeout bounds a single check, including all of its queries.
+// It backs up statement_timeout for checks that run several queries in a row.
+const DefaultCheckTimeout = 30 * time.Second
+
 // ReportHandler is called once per check after it completes.
 type ReportHandler func(*check.Report)
 
@@ -30,10 +39,54 @@ type Options struct {
 	Checks   []check.Package
 	Config   check.Config
 	OnReport ReportHandler
+	// Scope limits table-level checks to matching schemas and tables above the
+	// size floors. Nil checks everything. A check's floors can be overridden
+	// through Config with the "min_rows" and "min_size" keys (e.g. "50MB").
+	Scope *check.Scope
+	// CheckTimeout bounds how long each check may run. Zero means no limit.
+	// Checks that exceed it are reported as skipped with a "timeout" finding.
+	CheckTimeout time.Duration
+	// Retry re-runs a check that failed with a transient error (see IsTransient).
+	// The zero value disables retries.
+	Retry RetryPolicy
+	// Reconnect, if set, is called before each retry to replace a connection the
+	// failure may have dropped. The new connection is used for all later checks.
+	Reconnect func(context.Context) (db.DBTX, error)
+	// SeverityOverrides replaces the severity of warn and fail findings, keyed by
+	// "check-id" (every finding of the check) or "check-id/finding-id", which wins.
+	// Overridden findings keep the original in Finding.OriginalSeverity.
+	SeverityOverrides map[string]check.Severity
+	// Replicas run the checks whose Metadata.PreferReplica is set, keeping their
+	// expensive scans off the primary connection. Replicas are tried in order: a
+	// check that fails on one moves on to the next, and is reported as skipped if
+	// none can run it. The check never falls back to the primary.
+	Replicas []db.DBTX
+	// ServerVersion is the server_version_num of conn (e.g. 160004), used to skip
+	// checks whose Metadata.MinPGVersion/MaxPGVersion exclude the server. Checks
+	// read it with check.ServerVersionFromContext. Zero fetches it from conn the
+	// first time a check declares a version bound or asks for it.
+	ServerVersion int
+	// Provider is the managed service the server runs on (see DetectProvider).
+	// Checks read it with check.ProviderFromContext to suggest fixes that apply
+	// there, e.g. a parameter group change instead of ALTER SYSTEM.
+	Provider check.Provider
+	// ByteUnits selects how checks render sizes (check.FormatSize): binary
+	// (GiB, the default) or decimal (GB, as cloud consoles bill storage).
+	ByteUnits check.ByteUnits
+	// Instance describes the server's hardware, e.g. its memory for the
+	// shared-buffers check. Checks read it with check.InstanceMetadataFromContext
+	// and skip the comparisons that need it when it is nil.
+	Instance *check.InstanceMetadata
 }
 
 // Run executes checks sequentially against the given connection.
 //
+// Checks may only run queries (SELECT, WITH, SHOW, VALUES, TABLE); any other
+// statement fails with ErrNotReadOnly and the check is reported as skipped.
+// Checks whose Metadata.Requires are not met are skipped up front, with
+// instructions to satisfy them. The shared queries of package catalog run at most
+// once on conn: later checks read the cached result.
+//
 // Important: callers should SET statement_timeout on the connection before calling Run()
 // to prevent slow queries from blocking the database. See DefaultStatementTimeoutMs.
 func Run(ctx context.Context, conn db.DBTX, opts Options) {
@@ -42,36 +95,276 @@ func Run(ctx context.Context, conn db.DBTX, opts Options) {
 		onReport = func(*check.Report) {}
 	}
 
+	ctx = check.ContextWithProvider(ctx, opts.Provider)
+	ctx = check.ContextWithByteUnits(ctx, opts.ByteUnits)
+	cache := snapshot.NewCache(conn, catalog.Queries...)
+	conn = readOnlyConn{conn: cache}
+	replicas := make([]db.DBTX, 0, len(opts.Replicas))
+	for _, replica := range opts.Replicas {
+		replicas = append(replicas, readOnlyConn{conn: replica})
+	}
+	opts.Replicas = replicas
+	serverVersion, versionFetched := opts.ServerVersion, opts.ServerVersion != 0
+	version := func(ctx context.Context) int {
+		if !versionFetched {
+			serverVersion, versionFetched = fetchServerVersion(ctx, conn), true
+		}
+		return serverVersion
+	}
+	ctx = check.ContextWithServerVersionFunc(ctx, version)
+	if instance := opts.Instance; instance != nil {
+		if instance.EngineVersionMajor == 0 {
+			instance = withEngineVersion(instance, version(ctx))
+		}
+		ctx = check.ContextWithInstanceMetadata(ctx, instance)
+	}
+	prereqs := newPrerequisites(conn)
+
 	for _, pkg := range opts.Checks {
-		checker := pkg.New(conn, opts.Config)
+		if meta := pkg.Metadata(); meta.MinPGVersion > 0 || meta.MaxPGVersion > 0 {
+			if report := unsupportedVersionReport(meta, version(ctx)); report != nil {
+				onReport(report)
+				continue
+			}
+		}
+		if report := prereqs.unmetReport(ctx, pkg.Metadata()); report != nil {
+			onReport(report)
+			continue
+		}
 
-		start := time.Now()
-		report, err := checker.Check(ctx)
-		elapsed := time.Since(start)
+		checkCtx := ctx
+		if scope := checkScope(opts.Scope, opts.Config[pkg.Metadata().CheckID]); scope != nil {
+			checkCtx = check.ContextWithScope(ctx, scope)
+		}
 
-		if err != nil {
-			metadata := checker.Metadata()
-			report = check.NewReport(metadata)
-			report.Severity = check.SeveritySkip
+		if pkg.Metadata().PreferReplica && len(opts.Replicas) > 0 {
+			start := time.Now()
+			report := runOnReplicas(checkCtx, pkg, opts)
+			report.Duration = time.Since(start)
+			applySeverityOverrides(report, opts.SeverityOverrides)
+			onReport(report)
+			continue
+		}
 
-			detail := err.Error()
-			if isStatementTimeout(err) {
-				detail = "query cancelled by statement_timeout"
+		log := &queryLog{conn: conn}
+		checker := pkg.New(log, opts.Config)
+
+		start := time.Now()
+		report, timedOut, err := runCheck(checkCtx, checker, opts.CheckTimeout)
+		for retry := 1; err != nil && !timedOut && retry <= opts.Retry.MaxRetries && IsTransient(err); retry++ {
+			if !sleep(ctx, opts.Retry.backoff(retry)) {
+				break
+			}
+			if opts.Reconnect != nil {
+				newConn, reconnectErr := opts.Reconnect(ctx)
+				if reconnectErr != nil {
+					err = reconnectErr
+					continue
+				}
+				cache.SetConn(newConn)
+				checker = pkg.New(log, opts.Config)
 			}
+			report, timedOut, err = runCheck(checkCtx, checker, opts.CheckTimeout)
+		}
+		elapsed := time.Since(start)
 
-			report.AddFinding(check.Finding{
-				ID:       "error",
-				Name:     "Check Error",
-				Severity: check.SeveritySkip,
-				Details:  detail,
-			})
+		if err != nil {
+			report = skippedReport(checker.Metadata(), err, timedOut, opts.CheckTimeout)
 		}
 
 		report.Duration = elapsed
+		report.Queries = log.queries
+		applySeverityOverrides(report, opts.SeverityOverrides)
 		onReport(report)
 	}
 }
 
+// runOnReplicas runs a check on the first replica that completes it.
+// A timeout ends the attempt: the same scan would be as slow on the next replica.
+func runOnReplicas(ctx context.Context, pkg check.Package, opts Options) *check.Report {
+	var (
+		checker  check.Checker
+		timedOut bool
+		err      error
+	)
+	log := &queryLog{}
+	for i, replica := range opts.Replicas {
+		log.conn = replica
+		checker = pkg.New(log, opts.Config)
+
+		var report *check.Report
+		report, timedOut, err = runCheck(ctx, checker, opts.CheckTimeout)
+		if err == nil {
+			report.Queries = log.queries
+			return report
+		}
+		if timedOut {
+			break
+		}
+		err = fmt.Errorf("replica %d: %w", i+1, err)
+	}
+	report := skippedReport(checker.Metadata(), err, timedOut, opts.CheckTimeout)
+	report.Queries = log.queries
+	return report
+}
+
+// fetchServerVersion returns the server_version_num of conn, or 0 if it cannot be
+// read (e.g. a snapshot without it), in which case version-bound checks still run.
+func fetchServerVersion(ctx context.Context, conn db.DBTX) int {
+	var version int
+	if err := conn.QueryRow(ctx, "SELECT current_setting('server_version_num')::integer").Scan(&version); err != nil {
+		return 0
+	}
+	return version
+}
+
+// withEngineVersion returns a copy of instance with the engine version of the
+// server filled in. Metadata given by hand (--system-memory) lacks it, and checks
+// reading EngineVersionMajor would take the server for an ancient one.
+func withEngineVersion(instance *check.InstanceMetadata, serverVersion int) *check.InstanceMetadata {
+	if serverVersion == 0 {
+		return instance
+	}
+	filled := *instance
+	filled.EngineVersionMajor = serverVersion / 10000
+	filled.EngineVersionMinor = serverVersion % 100
+	filled.EngineVersion = fmt.Sprintf("%d.%d", filled.EngineVersionMajor, filled.EngineVersionMinor)
+	return &filled
+}
+
+// unsupportedVersionReport returns a skipped report when the server's major version
+// is outside the check's supported range, or nil if the check can run.
+func unsupportedVersionReport(meta check.Metadata, serverVersion int) *check.Report {
+	if serverVersion == 0 {
+		return nil
+	}
+
+	major := serverVersion / 10000
+	var requirement string
+	switch {
+	case meta.MinPGVersion > 0 && major < meta.MinPGVersion:
+		requirement = fmt.Sprintf(">= %d", meta.MinPGVersion)
+	case meta.MaxPGVersion > 0 && major > meta.MaxPGVersion:
+		requirement = fmt.Sprintf("<= %d", meta.MaxPGVersion)
+	default:
+		return nil
+	}
+
+	report := check.NewReport(meta)
+	report.Severity = check.SeveritySkip
+	report.AddFinding(check.Finding{
+		ID:       "unsupported-version",
+		Name:     "Unsupported Version",
+		Severity: check.SeveritySkip,
+		Details:  fmt.Sprintf("requires PostgreSQL %s (server is %d.%d)", requirement, major, serverVersion%100),
+	})
+	return report
+}
+
+// applySeverityOverrides remaps the severity of the report's warn and fail findings
+// and recomputes the report severity. Passing and skipped findings are left alone:
+// an override tunes how serious a problem is, it cannot invent or hide a check result.
+func applySeverityOverrides(report *check.Report, overrides map[string]check.Severity) {
+	if len(overrides) == 0 || report.Severity == check.SeveritySkip {
+		return
+	}
+
+	changed := false
+	for i := range report.Results {
+		finding := &report.Results[i]
+		if finding.Severity != check.SeverityWarn && finding.Severity != check.SeverityFail {
+			continue
+		}
+
+		severity, ok := overrides[report.CheckID+"/"+finding.ID]
+		if !ok {
+			severity, ok = overrides[report.CheckID]
+		}
+		if !ok || severity == finding.Severity {
+			continue
+		}
+
+		original := finding.Severity
+		finding.OriginalSeverity = &original
+		finding.Severity = severity
+		changed = true
+	}
+
+	if !changed {
+		return
+	}
+	report.Severity = check.SeverityOK
+	for _, finding := range report.Results {
+		report.Severity = max(report.Severity, finding.Severity)
+	}
+}
+
+// checkScope applies a check's "min_rows" and "min_size" overrides to the run scope.
+// Invalid values are ignored and the run-wide floors apply; the CLI rejects them
+// before the run.
+func checkScope(scope *check.Scope, config map[string]string) *check.Scope {
+	minRows, minSize := int64(-1), int64(-1)
+	if value, ok := config["min_rows"]; ok {
+		if n, err := strconv.ParseInt(strings.TrimSpace(value), 10, 64); err == nil && n >= 0 {
+			minRows = n
+		}
+	}
+	if value, ok := config["min_size"]; ok {
+		if n, err := check.ParseBytes(value); err == nil {
+			minSize = n
+		}
+	}
+
+	if minRows < 0 && minSize < 0 {
+		return scope
+	}
+	return scope.WithFloors(minRows, minSize)
+}
+
+// runCheck runs a single check under its own deadline.
+// timedOut reports whether the check ran out of time, as opposed to failing.
+func runCheck(ctx context.Context, checker check.Checker, timeout time.Duration) (report *check.Report, timedOut bool, err error) {
+	if timeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, timeout)
+		defer cancel()
+	}
+
+	report, err = checker.Check(ctx)
+	if err != nil && errors.Is(ctx.Err(), context.DeadlineExceeded) {
+		timedOut = true
+	}
+	return report, timedOut, err
+}
+
+// skippedReport builds the report for a check that could not complete.
+// Timeouts get their own finding ID so they stand apart from other errors.
+func skippedReport(metadata check.Metadata, err error, timedOut bool, timeout time.Duration) *check.Report {
+	report := check.NewReport(metadata)
+	report.Severity = check.SeveritySkip
+
+	finding := check.Finding{
+		ID:       "error",
+		Name:     "Check Error",
+		Severity: check.SeveritySkip,
+		Details:  err.Error(),
+	}
+
+	switch {
+	case timedOut:
+		finding.ID = "timeout"
+		finding.Name = "Check Timeout"
+		finding.Details = fmt.Sprintf("check did not finish within %s", timeout)
+	case isStatementTimeout(err):
+		finding.ID = "timeout"
+		finding.Name = "Check Timeout"
+		finding.Details = "query cancelled by statement_timeout"
+	}
+
+	report.AddFinding(finding)
+	return report
+}
+
 // Filter returns checks matching the only/ignored filters.
 // If only is non-empty, only checks matching those check IDs or categories are included.
 // Checks matching ignored check IDs or categories are excluded.
@@ -124,21 +417,35 @@ func toSet(items []string) map[string]struct{} {
 //   - "check-id" -> "check-id" (exact match)
 //   - "check-id/subcheck-id" -> "check-id" (extracts check ID from subcheck)
 //   - "category" -> "category" (exact match)
+//   - "index-*" -> every check ID and category matching the glob pattern
 //
-// Invalid filters are those that don't match any check ID or category.
+// Invalid filters are those that don't match any check ID or category,
+// including glob patterns that match nothing or are malformed.
 func ValidateFilters(checks []check.Package, filters []string) (valid, invalid []string) {
 	// Build set of valid check IDs and categories
 	validCheckIDs := map[string]struct{}{}
 	validCategories := map[string]struct{}{}
+	var candidates []string
 
 	for _, pkg := range checks {
 		metadata := pkg.Metadata()
 		validCheckIDs[metadata.CheckID] = struct{}{}
-		validCategories[string(metadata.Category)] = struct{}{}
+		candidates = append(candidates, metadata.CheckID)
+		category := string(metadata.Category)
+		if _, ok := validCategories[category]; !ok {
+			validCategories[category] = struct{}{}
+			candidates = append(candidates, category)
+		}
 	}
 
 	// Track seen filters to avoid duplicates
 	seen := map[string]struct{}{}
+	add := func(filter string) {
+		if _, alreadySeen := seen[filter]; !alreadySeen {
+			valid = append(valid, filter)
+			seen[filter] = struct{}{}
+		}
+	}
 
 	for _, filter := range filters {
 		// Normalize: extract check ID from subcheck format (check-id/subcheck-id)
@@ -148,20 +455,26 @@ func ValidateFilters(checks []check.Package, filters []string) (valid, invalid [
 			normalized = parts[0]
 		}
 
+		if isGlob(normalized) {
+			matches := matchGlob(normalized, candidates)
+			if len(matches) == 0 {
+				invalid = append(invalid, filter)
+				continue
+			}
+			for _, match := range matches {
+				add(match)
+			}
+			continue
+		}
+
 		// Check if normalized filter is valid (check ID or category)
 		if _, isCheckID := validCheckIDs[normalized]; isCheckID {
-			if _, alreadySeen := seen[normalized]; !alreadySeen {
-				valid = append(valid, normalized)
-				seen[normalized] = struct{}{}
-			}
+			add(normalized)
 			continue
 		}
 
 		if _, isCategory := validCategories[normalized]; isCategory {
-			if _, alreadySeen := seen[normalized]; !alreadySeen {
-				valid = append(valid, normalized)
-				seen[normalized] = struct{}{}
-			}
+			add(normalized)
 			continue
 		}
 
@@ -172,6 +485,25 @@ func ValidateFilters(checks []check.Package, filters []string) (valid, invalid [
 	return valid, invalid
 }
 
+func isGlob(filter string) bool {
+	return strings.ContainsAny(filter, "*?[")
+}
+
+// matchGlob returns the candidates matching pattern. A malformed pattern matches nothing.
+func matchGlob(pattern string, candidates []string) []string {
+	var matches []string
+	for _, candidate := range candidates {
+		ok, err := path.Match(pattern, candidate)
+		if err != nil {
+			return nil
+		}
+		if ok {
+			matches = append(matches, candidate)
+		}
+	}
+	return matches
+}
+
 // AllFilters returns all valid filter values (check IDs and categories).
 func AllFilters() []string {
 	checks := AllChecks()
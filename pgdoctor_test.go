@@ -4,9 +4,11 @@ import (
 	"context"
 	"fmt"
 	"testing"
+	"time"
 
 	"github.com/emancu/pgdoctor/check"
 	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
 	"github.com/jackc/pgx/v5/pgconn"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -69,6 +71,36 @@ func TestValidateFilters(t *testing.T) {
 			expectedValid: []string{"configs", "pg-version"},
 			expectedInval: nil,
 		},
+		{
+			name:          "glob expands to matching check IDs",
+			filters:       []string{"table-b*"},
+			expectedValid: []string{"table-bloat"},
+			expectedInval: nil,
+		},
+		{
+			name:          "glob matches check IDs and categories",
+			filters:       []string{"vac*"},
+			expectedValid: []string{"vacuum-settings", "vacuum"},
+			expectedInval: nil,
+		},
+		{
+			name:          "glob matching nothing is invalid",
+			filters:       []string{"*-nonexistent"},
+			expectedValid: nil,
+			expectedInval: []string{"*-nonexistent"},
+		},
+		{
+			name:          "malformed glob is invalid",
+			filters:       []string{"table-[bloat"},
+			expectedValid: nil,
+			expectedInval: []string{"table-[bloat"},
+		},
+		{
+			name:          "glob and exact filter are deduplicated",
+			filters:       []string{"table-bloat", "table-blo?t"},
+			expectedValid: []string{"table-bloat"},
+			expectedInval: nil,
+		},
 	}
 
 	for _, tt := range tests {
@@ -129,6 +161,7 @@ func TestRun_ContinuesAfterStatementTimeout(t *testing.T) {
 	assert.Equal(t, "slow-check", reports[0].CheckID)
 	require.Len(t, reports[0].Results, 1)
 	assert.Contains(t, reports[0].Results[0].Details, "statement_timeout")
+	assert.Equal(t, "timeout", reports[0].Results[0].ID)
 
 	assert.Equal(t, check.SeverityOK, reports[1].Severity)
 	assert.Equal(t, "fast-check", reports[1].CheckID)
@@ -158,3 +191,327 @@ func TestRun_ContinuesAfterCheckError(t *testing.T) {
 	assert.Equal(t, check.SeverityOK, reports[1].Severity)
 	assert.Equal(t, "good-check", reports[1].CheckID)
 }
+
+// funcChecker is a test double whose Check behavior is supplied by the test.
+type funcChecker struct {
+	metadata check.Metadata
+	run      func(context.Context) (*check.Report, error)
+}
+
+func (f *funcChecker) Metadata() check.Metadata { return f.metadata }
+
+func (f *funcChecker) Check(ctx context.Context) (*check.Report, error) {
+	return f.run(ctx)
+}
+
+func funcPackage(id string, run func(context.Context) (*check.Report, error)) check.Package {
+	meta := check.Metadata{CheckID: id, Name: id, Category: check.CategorySchema}
+	return check.Package{
+		Metadata: func() check.Metadata { return meta },
+		New: func(_ db.DBTX, _ check.Config) check.Checker {
+			return &funcChecker{metadata: meta, run: run}
+		},
+	}
+}
+
+func TestRun_AttachesScopeToContext(t *testing.T) {
+	t.Parallel()
+
+	var seen *check.Scope
+	pkg := funcPackage("scoped-check", func(ctx context.Context) (*check.Report, error) {
+		seen = check.ScopeFromContext(ctx)
+		return check.NewReport(check.Metadata{CheckID: "scoped-check"}), nil
+	})
+
+	scope := &check.Scope{Schemas: []string{"billing"}}
+	Run(context.Background(), nil, Options{Checks: []check.Package{pkg}, Scope: scope})
+
+	assert.Same(t, scope, seen)
+}
+
+func TestRun_AttachesByteUnitsToContext(t *testing.T) {
+	t.Parallel()
+
+	var size string
+	pkg := funcPackage("sized-check", func(ctx context.Context) (*check.Report, error) {
+		size = check.FormatSize(ctx, 5*check.GiB)
+		return check.NewReport(check.Metadata{CheckID: "sized-check"}), nil
+	})
+
+	Run(context.Background(), nil, Options{Checks: []check.Package{pkg}, ByteUnits: check.UnitsDecimal})
+	assert.Equal(t, "5.4GB", size)
+
+	Run(context.Background(), nil, Options{Checks: []check.Package{pkg}})
+	assert.Equal(t, "5.0GiB", size)
+}
+
+func TestRun_PerCheckFloorOverrides(t *testing.T) {
+	t.Parallel()
+
+	seen := map[string]*check.Scope{}
+	record := func(id string) check.Package {
+		return funcPackage(id, func(ctx context.Context) (*check.Report, error) {
+			seen[id] = check.ScopeFromContext(ctx)
+			return check.NewReport(check.Metadata{CheckID: id}), nil
+		})
+	}
+
+	scope := &check.Scope{MinRows: 1000, MinSizeBytes: 10 * check.MiB}
+	Run(context.Background(), nil, Options{
+		Checks: []check.Package{record("table-bloat"), record("table-seq-scans"), record("table-activity")},
+		Config: check.Config{
+			"table-bloat":     {"min_size": "100MB"},
+			"table-seq-scans": {"min_rows": "0"},
+			"table-activity":  {"min_rows": "lots"},
+		},
+		Scope: scope,
+	})
+
+	assert.Equal(t, int64(1000), seen["table-bloat"].MinRows)
+	assert.Equal(t, int64(100*check.MiB), seen["table-bloat"].MinSizeBytes)
+	assert.Equal(t, int64(0), seen["table-seq-scans"].MinRows)
+	assert.Equal(t, int64(10*check.MiB), seen["table-seq-scans"].MinSizeBytes)
+	assert.Same(t, scope, seen["table-activity"], "invalid overrides keep the run scope")
+}
+
+func TestRun_SeverityOverrides(t *testing.T) {
+	t.Parallel()
+
+	newReport := func(id string, findings ...check.Finding) *check.Report {
+		report := check.NewReport(check.Metadata{CheckID: id, Name: id})
+		for _, f := range findings {
+			report.AddFinding(f)
+		}
+		return report
+	}
+
+	settings := newReport("vacuum-settings",
+		check.Finding{ID: "work-mem", Severity: check.SeverityWarn},
+		check.Finding{ID: "maintenance-work-mem", Severity: check.SeverityWarn},
+		check.Finding{ID: "autovacuum", Severity: check.SeverityOK},
+	)
+	bloat := newReport("table-bloat", check.Finding{ID: "table-bloat", Severity: check.SeverityWarn})
+
+	var reports []*check.Report
+	Run(context.Background(), nil, Options{
+		Checks: []check.Package{
+			fakePackage("vacuum-settings", check.CategoryConfigs, settings, nil),
+			fakePackage("table-bloat", check.CategoryVacuum, bloat, nil),
+			fakePackage("pg-version", check.CategoryConfigs, nil, fmt.Errorf("permission denied")),
+		},
+		OnReport: Collect(&reports),
+		SeverityOverrides: map[string]check.Severity{
+			"vacuum-settings/work-mem": check.SeverityOK,
+			"table-bloat":              check.SeverityFail,
+			"pg-version":               check.SeverityFail,
+		},
+	})
+
+	require.Len(t, reports, 3)
+
+	assert.Equal(t, check.SeverityOK, reports[0].Results[0].Severity)
+	require.NotNil(t, reports[0].Results[0].OriginalSeverity)
+	assert.Equal(t, check.SeverityWarn, *reports[0].Results[0].OriginalSeverity)
+	assert.Equal(t, check.SeverityWarn, reports[0].Results[1].Severity, "other findings keep their severity")
+	assert.Nil(t, reports[0].Results[2].OriginalSeverity, "passing findings are never overridden")
+	assert.Equal(t, check.SeverityWarn, reports[0].Severity)
+
+	assert.Equal(t, check.SeverityFail, reports[1].Severity)
+	assert.Equal(t, check.SeverityFail, reports[1].Results[0].Severity)
+
+	assert.Equal(t, check.SeveritySkip, reports[2].Severity, "skipped checks are never overridden")
+}
+
+func TestRun_CheckTimeoutReportsSkip(t *testing.T) {
+	t.Parallel()
+
+	hanging := funcPackage("hanging-check", func(ctx context.Context) (*check.Report, error) {
+		<-ctx.Done()
+		return nil, ctx.Err()
+	})
+	fast := funcPackage("fast-check", func(_ context.Context) (*check.Report, error) {
+		return check.NewReport(check.Metadata{CheckID: "fast-check"}), nil
+	})
+
+	var reports []*check.Report
+	Run(context.Background(), nil, Options{
+		Checks:       []check.Package{hanging, fast},
+		OnReport:     Collect(&reports),
+		CheckTimeout: 10 * time.Millisecond,
+	})
+	require.Len(t, reports, 2)
+
+	assert.Equal(t, check.SeveritySkip, reports[0].Severity)
+	require.Len(t, reports[0].Results, 1)
+	assert.Equal(t, "timeout", reports[0].Results[0].ID)
+	assert.Contains(t, reports[0].Results[0].Details, "10ms")
+
+	assert.Equal(t, check.SeverityOK, reports[1].Severity, "later checks get a fresh deadline")
+}
+
+// namedConn identifies which connection a check was given: it answers every
+// QueryRow with its name.
+type namedConn struct {
+	db.DBTX
+	name string
+}
+
+func (c *namedConn) QueryRow(context.Context, string, ...any) pgx.Row {
+	return scanRow{value: c.name}
+}
+
+func TestRun_PreferReplicaChecks(t *testing.T) {
+	t.Parallel()
+
+	// run executes index-bloat (replica-preferred) and pg-version, returning the
+	// "check@connection" attempts in order.
+	run := func(checks []string, replicas ...string) ([]string, []*check.Report) {
+		var ranOn []string
+		var pkgs []check.Package
+		for _, id := range checks {
+			meta := check.Metadata{CheckID: id, Name: id, Category: check.CategoryIndexes, PreferReplica: id == "index-bloat"}
+			pkgs = append(pkgs, check.Package{
+				Metadata: func() check.Metadata { return meta },
+				New: func(conn db.DBTX, _ check.Config) check.Checker {
+					var name string
+					require.NoError(t, conn.QueryRow(context.Background(), "SELECT current_database()").Scan(&name))
+					return &funcChecker{metadata: meta, run: func(context.Context) (*check.Report, error) {
+						ranOn = append(ranOn, meta.CheckID+"@"+name)
+						if name == "broken" {
+							return nil, fmt.Errorf("connection refused")
+						}
+						return check.NewReport(meta), nil
+					}}
+				},
+			})
+		}
+
+		var replicaConns []db.DBTX
+		for _, name := range replicas {
+			replicaConns = append(replicaConns, &namedConn{name: name})
+		}
+
+		var reports []*check.Report
+		Run(context.Background(), &namedConn{name: "primary"}, Options{
+			Checks:   pkgs,
+			Replicas: replicaConns,
+			OnReport: Collect(&reports),
+		})
+		return ranOn, reports
+	}
+
+	t.Run("replica checks move to the next replica on failure", func(t *testing.T) {
+		t.Parallel()
+
+		ranOn, reports := run([]string{"index-bloat", "pg-version"}, "broken", "replica-2")
+
+		assert.Equal(t, []string{"index-bloat@broken", "index-bloat@replica-2", "pg-version@primary"}, ranOn)
+		require.Len(t, reports, 2)
+		assert.NotEqual(t, check.SeveritySkip, reports[0].Severity)
+	})
+
+	t.Run("skipped when no replica can run it", func(t *testing.T) {
+		t.Parallel()
+
+		ranOn, reports := run([]string{"index-bloat"}, "broken")
+
+		assert.Equal(t, []string{"index-bloat@broken"}, ranOn, "never falls back to the primary")
+		require.Len(t, reports, 1)
+		assert.Equal(t, check.SeveritySkip, reports[0].Severity)
+		assert.Contains(t, reports[0].Results[0].Details, "replica 1: connection refused")
+	})
+
+	t.Run("without replicas everything runs on the primary", func(t *testing.T) {
+		t.Parallel()
+
+		ranOn, _ := run([]string{"index-bloat", "pg-version"})
+
+		assert.Equal(t, []string{"index-bloat@primary", "pg-version@primary"}, ranOn)
+	})
+}
+
+func TestRun_SkipsChecksOutsideVersionRange(t *testing.T) {
+	t.Parallel()
+
+	versioned := func(id string, minVersion, maxVersion int) check.Package {
+		meta := check.Metadata{CheckID: id, Name: id, Category: check.CategoryConfigs, MinPGVersion: minVersion, MaxPGVersion: maxVersion}
+		return check.Package{
+			Metadata: func() check.Metadata { return meta },
+			New: func(_ db.DBTX, _ check.Config) check.Checker {
+				return &fakeChecker{metadata: meta, report: check.NewReport(meta)}
+			},
+		}
+	}
+
+	var reports []*check.Report
+	Run(context.Background(), nil, Options{
+		Checks: []check.Package{
+			versioned("needs-14", 14, 0),
+			versioned("needs-13", 13, 0),
+			versioned("up-to-12", 0, 12),
+			versioned("any-version", 0, 0),
+		},
+		ServerVersion: 130012,
+		OnReport:      Collect(&reports),
+	})
+	require.Len(t, reports, 4)
+
+	assert.Equal(t, check.SeveritySkip, reports[0].Severity)
+	require.Len(t, reports[0].Results, 1)
+	assert.Equal(t, "unsupported-version", reports[0].Results[0].ID)
+	assert.Equal(t, "requires PostgreSQL >= 14 (server is 13.12)", reports[0].Results[0].Details)
+
+	assert.Equal(t, check.SeverityOK, reports[1].Severity)
+
+	assert.Equal(t, check.SeveritySkip, reports[2].Severity)
+	assert.Equal(t, "requires PostgreSQL <= 12 (server is 13.12)", reports[2].Results[0].Details)
+
+	assert.Equal(t, check.SeverityOK, reports[3].Severity)
+}
+
+// versionConn answers the server version query and counts how often it is asked.
+type versionConn struct {
+	db.DBTX
+	queries int
+}
+
+func (c *versionConn) QueryRow(context.Context, string, ...any) pgx.Row {
+	c.queries++
+	return scanRow{value: 170002}
+}
+
+func TestRun_ServerVersionInContext(t *testing.T) {
+	t.Parallel()
+
+	var seen []int
+	reading := funcPackage("reads-version", func(ctx context.Context) (*check.Report, error) {
+		seen = append(seen, check.ServerVersionFromContext(ctx))
+		return check.NewReport(check.Metadata{CheckID: "reads-version"}), nil
+	})
+
+	t.Run("fetched once, when a check asks for it", func(t *testing.T) {
+		conn := &versionConn{}
+		seen = nil
+		Run(context.Background(), conn, Options{Checks: []check.Package{reading, reading}})
+
+		assert.Equal(t, []int{170002, 170002}, seen)
+		assert.Equal(t, 1, conn.queries)
+	})
+
+	t.Run("not fetched when nothing asks for it", func(t *testing.T) {
+		conn := &versionConn{}
+		report := check.NewReport(check.Metadata{CheckID: "any"})
+		Run(context.Background(), conn, Options{Checks: []check.Package{fakePackage("any", check.CategoryConfigs, report, nil)}})
+
+		assert.Zero(t, conn.queries)
+	})
+
+	t.Run("given in the options", func(t *testing.T) {
+		conn := &versionConn{}
+		seen = nil
+		Run(context.Background(), conn, Options{Checks: []check.Package{reading}, ServerVersion: 160004})
+
+		assert.Equal(t, []int{160004}, seen)
+		assert.Zero(t, conn.queries)
+	})
+}
@@ -0,0 +1,81 @@
+package pgdoctor
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+// prerequisiteSQL verifies one prerequisite kind; $1 is Prerequisite.Name. Each
+// query returns false rather than failing when the object does not exist.
+var prerequisiteSQL = map[check.PrerequisiteKind]string{
+	check.PrerequisiteExtension: "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = $1)",
+	check.PrerequisiteRole:      "SELECT COALESCE((SELECT pg_has_role(oid, 'USAGE') FROM pg_roles WHERE rolname = $1), false)",
+	check.PrerequisiteFunction:  "SELECT COALESCE(has_function_privilege(to_regprocedure($1), 'EXECUTE'), false)",
+	check.PrerequisiteRelation:  "SELECT COALESCE(has_table_privilege(to_regclass($1), 'SELECT'), false)",
+}
+
+// prerequisites verifies check prerequisites on a connection, querying each
+// distinct prerequisite once per run.
+type prerequisites struct {
+	conn db.DBTX
+	met  map[check.Prerequisite]bool
+	user string
+}
+
+func newPrerequisites(conn db.DBTX) *prerequisites {
+	return &prerequisites{conn: conn, met: map[check.Prerequisite]bool{}}
+}
+
+// unmetReport returns a skipped report listing the check's unmet prerequisites with
+// instructions to satisfy them, or nil if the check can run. A prerequisite that
+// cannot be verified counts as met: the check runs and reports its own error.
+func (p *prerequisites) unmetReport(ctx context.Context, meta check.Metadata) *check.Report {
+	var unmet []check.Prerequisite
+	for _, req := range meta.Requires {
+		if !p.isMet(ctx, req) {
+			unmet = append(unmet, req)
+		}
+	}
+	if len(unmet) == 0 {
+		return nil
+	}
+
+	if p.user == "" {
+		if err := p.conn.QueryRow(ctx, "SELECT current_user").Scan(&p.user); err != nil {
+			p.user = "<user>"
+		}
+	}
+
+	report := check.NewReport(meta)
+	report.Severity = check.SeveritySkip
+	for _, req := range unmet {
+		report.AddFinding(check.Finding{
+			ID:          "missing-prerequisite",
+			Name:        "Missing Prerequisite",
+			Severity:    check.SeveritySkip,
+			Details:     fmt.Sprintf("requires %s", req),
+			Remediation: req.Instructions(p.user),
+		})
+	}
+	return report
+}
+
+func (p *prerequisites) isMet(ctx context.Context, req check.Prerequisite) bool {
+	if met, ok := p.met[req]; ok {
+		return met
+	}
+
+	sql, ok := prerequisiteSQL[req.Kind]
+	if !ok {
+		return true
+	}
+	met := true
+	if err := p.conn.QueryRow(ctx, sql, req.Name).Scan(&met); err != nil {
+		met = true
+	}
+	p.met[req] = met
+	return met
+}
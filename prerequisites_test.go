@@ -0,0 +1,115 @@
+package pgdoctor
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// prereqConn answers prerequisite queries from met, keyed by the queried name,
+// and counts the queries it receives. Names missing from met fail to verify.
+type prereqConn struct {
+	met     map[string]bool
+	queries int
+}
+
+func (c *prereqConn) Exec(context.Context, string, ...any) (pgconn.CommandTag, error) {
+	return pgconn.CommandTag{}, errors.New("unexpected exec")
+}
+
+func (c *prereqConn) Query(context.Context, string, ...any) (pgx.Rows, error) {
+	return nil, errors.New("unexpected query")
+}
+
+func (c *prereqConn) QueryRow(_ context.Context, sql string, args ...any) pgx.Row {
+	if strings.Contains(sql, "current_user") {
+		return scanRow{value: "doctor"}
+	}
+	c.queries++
+	met, ok := c.met[fmt.Sprint(args[0])]
+	if !ok {
+		return scanRow{err: errors.New("permission denied")}
+	}
+	return scanRow{value: met}
+}
+
+type scanRow struct {
+	value any
+	err   error
+}
+
+func (r scanRow) Scan(dest ...any) error {
+	if r.err != nil {
+		return r.err
+	}
+	switch d := dest[0].(type) {
+	case *bool:
+		*d = r.value.(bool)
+	case *string:
+		*d = r.value.(string)
+	case *int:
+		*d = r.value.(int)
+	}
+	return nil
+}
+
+func TestRun_SkipsChecksWithUnmetPrerequisites(t *testing.T) {
+	t.Parallel()
+
+	requiring := func(id string, requires ...check.Prerequisite) check.Package {
+		meta := check.Metadata{CheckID: id, Name: id, Category: check.CategoryConfigs, Requires: requires}
+		return check.Package{
+			Metadata: func() check.Metadata { return meta },
+			New: func(_ db.DBTX, _ check.Config) check.Checker {
+				return &fakeChecker{metadata: meta, report: check.NewReport(meta)}
+			},
+		}
+	}
+
+	conn := &prereqConn{met: map[string]bool{
+		"pg_stat_statements": false,
+		"pg_monitor":         true,
+		"pg_ls_tmpdir(oid)":  false,
+	}}
+
+	var reports []*check.Report
+	Run(context.Background(), conn, Options{
+		Checks: []check.Package{
+			requiring("needs-extension", check.RequireExtension("pg_stat_statements")),
+			requiring("needs-role", check.RequireRole("pg_monitor")),
+			requiring("needs-function", check.RequireFunction("pg_ls_tmpdir(oid)", "GRANT pg_monitor to the user")),
+			requiring("unverifiable", check.RequireRelation("pg_stat_io", "")),
+			requiring("needs-extension-again", check.RequireExtension("pg_stat_statements"), check.RequireRole("pg_monitor")),
+		},
+		OnReport: Collect(&reports),
+	})
+	require.Len(t, reports, 5)
+
+	assert.Equal(t, check.SeveritySkip, reports[0].Severity)
+	require.Len(t, reports[0].Results, 1)
+	assert.Equal(t, "missing-prerequisite", reports[0].Results[0].ID)
+	assert.Equal(t, "requires the pg_stat_statements extension", reports[0].Results[0].Details)
+	assert.Contains(t, reports[0].Results[0].Remediation, "CREATE EXTENSION pg_stat_statements;")
+
+	assert.Equal(t, check.SeverityOK, reports[1].Severity)
+
+	assert.Equal(t, check.SeveritySkip, reports[2].Severity)
+	assert.Equal(t, "requires EXECUTE on pg_ls_tmpdir(oid)", reports[2].Results[0].Details)
+	assert.Equal(t, "GRANT pg_monitor to the user", reports[2].Results[0].Remediation)
+
+	assert.Equal(t, check.SeverityOK, reports[3].Severity, "a prerequisite that cannot be verified lets the check run")
+
+	assert.Equal(t, check.SeveritySkip, reports[4].Severity)
+	assert.Len(t, reports[4].Results, 1, "only the unmet prerequisite is reported")
+
+	assert.Equal(t, 4, conn.queries, "each distinct prerequisite is verified once")
+}
@@ -0,0 +1,32 @@
+package pgdoctor
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+)
+
+// detectProviderSQL looks for the roles, functions and settings each managed
+// service installs. Aurora also has rds_superuser, so it is tested first.
+const detectProviderSQL = `SELECT CASE
+  WHEN EXISTS (SELECT 1 FROM pg_proc WHERE proname = 'aurora_version') THEN 'aurora'
+  WHEN EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'rds_superuser')
+    OR EXISTS (SELECT 1 FROM pg_settings WHERE name LIKE 'rds.%') THEN 'rds'
+  WHEN EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'cloudsqlsuperuser')
+    OR EXISTS (SELECT 1 FROM pg_settings WHERE name LIKE 'cloudsql.%') THEN 'cloudsql'
+  WHEN EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'azure_pg_admin')
+    OR EXISTS (SELECT 1 FROM pg_settings WHERE name LIKE 'azure.%') THEN 'azure'
+  ELSE ''
+END`
+
+// DetectProvider identifies the managed service conn points to, or returns
+// check.ProviderSelfManaged. Pass the result as Options.Provider.
+func DetectProvider(ctx context.Context, conn db.DBTX) (check.Provider, error) {
+	var provider string
+	if err := conn.QueryRow(ctx, detectProviderSQL).Scan(&provider); err != nil {
+		return check.ProviderSelfManaged, fmt.Errorf("detecting provider: %w", err)
+	}
+	return check.Provider(provider), nil
+}
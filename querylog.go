@@ -0,0 +1,84 @@
+package pgdoctor
+
+import (
+	"context"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+// queryLog records every statement a check runs on conn, with its duration, for
+// Report.Queries.
+type queryLog struct {
+	conn    db.DBTX
+	queries []check.ExecutedQuery
+}
+
+func (l *queryLog) Exec(ctx context.Context, sql string, args ...any) (pgconn.CommandTag, error) {
+	start := time.Now()
+	tag, err := l.conn.Exec(ctx, sql, args...)
+	l.record(sql, start, err)
+	return tag, err
+}
+
+// Query records the statement once its rows are closed, so the duration covers
+// fetching them.
+func (l *queryLog) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	start := time.Now()
+	rows, err := l.conn.Query(ctx, sql, args...)
+	if err != nil {
+		l.record(sql, start, err)
+		return nil, err
+	}
+	return &loggedRows{Rows: rows, done: func() { l.record(sql, start, rows.Err()) }}, nil
+}
+
+func (l *queryLog) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	start := time.Now()
+	return &loggedRow{row: l.conn.QueryRow(ctx, sql, args...), done: func(err error) { l.record(sql, start, err) }}
+}
+
+func (l *queryLog) record(sql string, start time.Time, err error) {
+	query := check.ExecutedQuery{SQL: sql, Duration: time.Since(start)}
+	if err != nil {
+		query.Error = err.Error()
+	}
+	l.queries = append(l.queries, query)
+}
+
+// loggedRows calls done the first time the rows are closed.
+type loggedRows struct {
+	pgx.Rows
+	done func()
+}
+
+func (r *loggedRows) Next() bool {
+	if r.Rows.Next() {
+		return true
+	}
+	r.Close()
+	return false
+}
+
+func (r *loggedRows) Close() {
+	r.Rows.Close()
+	if r.done != nil {
+		r.done()
+		r.done = nil
+	}
+}
+
+// loggedRow calls done with the result of Scan, which is when pgx.Row runs.
+type loggedRow struct {
+	row  pgx.Row
+	done func(error)
+}
+
+func (r *loggedRow) Scan(dest ...any) error {
+	err := r.row.Scan(dest...)
+	r.done(err)
+	return err
+}
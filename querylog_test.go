@@ -0,0 +1,41 @@
+package pgdoctor
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestRun_RecordsExecutedQueries(t *testing.T) {
+	t.Parallel()
+
+	meta := check.Metadata{CheckID: "chatty", Name: "Chatty", Category: check.CategoryConfigs}
+	pkg := check.Package{
+		Metadata: func() check.Metadata { return meta },
+		New: func(conn db.DBTX, _ check.Config) check.Checker {
+			return &funcChecker{metadata: meta, run: func(ctx context.Context) (*check.Report, error) {
+				var name string
+				if err := conn.QueryRow(ctx, "SELECT current_database()").Scan(&name); err != nil {
+					return nil, err
+				}
+				_, _ = conn.Exec(ctx, "ANALYZE users")
+				return check.NewReport(meta), nil
+			}}
+		},
+	}
+
+	var reports []*check.Report
+	Run(context.Background(), &namedConn{name: "primary"}, Options{Checks: []check.Package{pkg}, OnReport: Collect(&reports)})
+
+	require.Len(t, reports, 1)
+	queries := reports[0].Queries
+	require.Len(t, queries, 2)
+	assert.Equal(t, "SELECT current_database()", queries[0].SQL)
+	assert.Empty(t, queries[0].Error)
+	assert.Equal(t, "ANALYZE users", queries[1].SQL)
+	assert.Contains(t, queries[1].Error, "not read-only")
+}
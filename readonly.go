@@ -0,0 +1,100 @@
+package pgdoctor
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"unicode"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+// ErrNotReadOnly is returned when a check tries to run a statement other than a query.
+var ErrNotReadOnly = errors.New("statement is not read-only")
+
+// readOnlyStatements are the leading keywords checks may send. It is a lexical
+// guard against programming mistakes, not a sandbox: a data-modifying CTE starts
+// with WITH too. The CLI also opens every connection with
+// default_transaction_read_only, so PostgreSQL rejects writes that slip through.
+var readOnlyStatements = map[string]struct{}{
+	"SELECT": {},
+	"WITH":   {},
+	"SHOW":   {},
+	"VALUES": {},
+	"TABLE":  {},
+}
+
+// readOnlyConn rejects anything but queries before it reaches the database.
+// Run wraps every connection it hands to checks with it.
+type readOnlyConn struct {
+	conn db.DBTX
+}
+
+func (c readOnlyConn) Exec(ctx context.Context, sql string, args ...any) (pgconn.CommandTag, error) {
+	if err := assertReadOnly(sql); err != nil {
+		return pgconn.CommandTag{}, err
+	}
+	return c.conn.Exec(ctx, sql, args...)
+}
+
+func (c readOnlyConn) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	if err := assertReadOnly(sql); err != nil {
+		return nil, err
+	}
+	return c.conn.Query(ctx, sql, args...)
+}
+
+func (c readOnlyConn) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	if err := assertReadOnly(sql); err != nil {
+		return errRow{err: err}
+	}
+	return c.conn.QueryRow(ctx, sql, args...)
+}
+
+// errRow is a pgx.Row whose Scan fails, like pgx's own QueryRow on error.
+type errRow struct {
+	err error
+}
+
+func (r errRow) Scan(...any) error {
+	return r.err
+}
+
+// assertReadOnly checks the first keyword of sql, skipping comments (sqlc
+// prefixes every query with "-- name: ...") and opening parentheses.
+func assertReadOnly(sql string) error {
+	keyword := strings.ToUpper(firstKeyword(sql))
+	if _, ok := readOnlyStatements[keyword]; !ok {
+		return fmt.Errorf("%w: %s", ErrNotReadOnly, keyword)
+	}
+	return nil
+}
+
+func firstKeyword(sql string) string {
+	for {
+		sql = strings.TrimLeftFunc(sql, func(r rune) bool { return unicode.IsSpace(r) || r == '(' })
+		switch {
+		case strings.HasPrefix(sql, "--"):
+			end := strings.IndexByte(sql, '\n')
+			if end < 0 {
+				return ""
+			}
+			sql = sql[end+1:]
+		case strings.HasPrefix(sql, "/*"):
+			end := strings.Index(sql, "*/")
+			if end < 0 {
+				return ""
+			}
+			sql = sql[end+2:]
+		default:
+			end := strings.IndexFunc(sql, func(r rune) bool { return !unicode.IsLetter(r) })
+			if end < 0 {
+				return sql
+			}
+			return sql[:end]
+		}
+	}
+}
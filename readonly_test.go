@@ -0,0 +1,91 @@
+package pgdoctor
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestAssertReadOnly(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		sql      string
+		readOnly bool
+	}{
+		{"SELECT 1", true},
+		{"-- name: IndexUsage :many\n-- Finds unused indexes\nSELECT relname FROM pg_class", true},
+		{"/* pg_stat */ with t AS (SELECT 1) SELECT * FROM t", true},
+		{"(SELECT 1) UNION (SELECT 2)", true},
+		{"SHOW server_version_num", true},
+		{"VALUES (1)", true},
+		{"DELETE FROM users", false},
+		{"-- SELECT\nUPDATE users SET name = 'x'", false},
+		{"SET statement_timeout = 0", false},
+		{"CREATE INDEX ON users (email)", false},
+		{"", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.sql, func(t *testing.T) {
+			t.Parallel()
+
+			err := assertReadOnly(tt.sql)
+			if tt.readOnly {
+				assert.NoError(t, err)
+			} else {
+				assert.ErrorIs(t, err, ErrNotReadOnly)
+			}
+		})
+	}
+}
+
+// execConn fails the test if a statement reaches the database.
+type execConn struct {
+	t *testing.T
+}
+
+func (c execConn) Exec(context.Context, string, ...any) (pgconn.CommandTag, error) {
+	c.t.Error("statement reached the database")
+	return pgconn.CommandTag{}, nil
+}
+
+func (c execConn) Query(context.Context, string, ...any) (pgx.Rows, error) {
+	c.t.Error("statement reached the database")
+	return nil, nil
+}
+
+func (c execConn) QueryRow(context.Context, string, ...any) pgx.Row {
+	c.t.Error("statement reached the database")
+	return nil
+}
+
+func TestRun_RejectsWrites(t *testing.T) {
+	t.Parallel()
+
+	meta := check.Metadata{CheckID: "sneaky", Name: "Sneaky", Category: check.CategorySchema}
+	pkg := check.Package{
+		Metadata: func() check.Metadata { return meta },
+		New: func(conn db.DBTX, _ check.Config) check.Checker {
+			return &funcChecker{metadata: meta, run: func(ctx context.Context) (*check.Report, error) {
+				if _, err := conn.Exec(ctx, "VACUUM FULL users"); err != nil {
+					return nil, err
+				}
+				return check.NewReport(meta), nil
+			}}
+		},
+	}
+
+	var reports []*check.Report
+	Run(context.Background(), execConn{t: t}, Options{Checks: []check.Package{pkg}, OnReport: Collect(&reports)})
+
+	require.Len(t, reports, 1)
+	assert.Equal(t, check.SeveritySkip, reports[0].Severity)
+	assert.Contains(t, reports[0].Results[0].Details, "statement is not read-only: VACUUM")
+}
@@ -0,0 +1,95 @@
+package pgdoctor
+
+import (
+	"context"
+	"errors"
+	"io"
+	"net"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+// maxBackoff caps the delay between retries.
+const maxBackoff = 30 * time.Second
+
+// RetryPolicy controls how transient failures (server restarts, failovers,
+// dropped connections) are retried. The zero value disables retries.
+type RetryPolicy struct {
+	MaxRetries int           // Retries after the first attempt
+	Delay      time.Duration // Wait before the first retry; doubles on each subsequent one
+}
+
+// DefaultRetryPolicy rides out short blips such as an RDS failover or a
+// maintenance-window restart without stretching a healthy run.
+var DefaultRetryPolicy = RetryPolicy{MaxRetries: 3, Delay: 500 * time.Millisecond}
+
+// backoff returns the wait before the given retry (1-based).
+func (p RetryPolicy) backoff(retry int) time.Duration {
+	delay := p.Delay
+	for i := 1; i < retry && delay < maxBackoff; i++ {
+		delay *= 2
+	}
+	return min(delay, maxBackoff)
+}
+
+// Retry calls fn until it succeeds, fails with a non-transient error, runs out
+// of retries, or ctx is done. It returns the last error.
+func Retry(ctx context.Context, policy RetryPolicy, fn func(context.Context) error) error {
+	err := fn(ctx)
+	for retry := 1; err != nil && retry <= policy.MaxRetries && IsTransient(err); retry++ {
+		if !sleep(ctx, policy.backoff(retry)) {
+			return err
+		}
+		err = fn(ctx)
+	}
+	return err
+}
+
+// IsTransient reports whether err is likely to go away on its own and is worth
+// retrying, typically after reconnecting:
+//   - 57P01 admin_shutdown, 57P02 crash_shutdown, 57P03 cannot_connect_now
+//   - SQLSTATE class 08 (connection exception)
+//   - network failures and connections dropped mid-query
+//
+// Context cancellation, timeouts and query errors are not transient.
+func IsTransient(err error) bool {
+	if err == nil || errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) {
+		return false
+	}
+
+	var pgErr *pgconn.PgError
+	if errors.As(err, &pgErr) {
+		switch pgErr.Code {
+		case "57P01", "57P02", "57P03":
+			return true
+		}
+		return len(pgErr.Code) == 5 && pgErr.Code[:2] == "08"
+	}
+
+	// A failed dial that never reached the server (refused, DNS, reset).
+	var connectErr *pgconn.ConnectError
+	if errors.As(err, &connectErr) {
+		return true
+	}
+
+	var netErr net.Error
+	if errors.As(err, &netErr) && !netErr.Timeout() {
+		return true
+	}
+
+	return errors.Is(err, io.EOF) || errors.Is(err, io.ErrUnexpectedEOF) || pgconn.SafeToRetry(err)
+}
+
+// sleep waits for d or until ctx is done. It reports whether the full wait elapsed.
+func sleep(ctx context.Context, d time.Duration) bool {
+	timer := time.NewTimer(d)
+	defer timer.Stop()
+
+	select {
+	case <-ctx.Done():
+		return false
+	case <-timer.C:
+		return true
+	}
+}
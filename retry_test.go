@@ -0,0 +1,109 @@
+package pgdoctor
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/emancu/pgdoctor/check"
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestIsTransient(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		err    error
+		expect bool
+	}{
+		{name: "admin shutdown", err: &pgconn.PgError{Code: "57P01"}, expect: true},
+		{name: "cannot connect now", err: &pgconn.PgError{Code: "57P03"}, expect: true},
+		{name: "connection failure class", err: &pgconn.PgError{Code: "08006"}, expect: true},
+		{name: "wrapped admin shutdown", err: fmt.Errorf("running configs/x: %w", &pgconn.PgError{Code: "57P01"}), expect: true},
+		{name: "statement timeout", err: &pgconn.PgError{Code: "57014"}, expect: false},
+		{name: "undefined table", err: &pgconn.PgError{Code: "42P01"}, expect: false},
+		{name: "context deadline", err: context.DeadlineExceeded, expect: false},
+		{name: "plain error", err: errors.New("boom"), expect: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			assert.Equal(t, tt.expect, IsTransient(tt.err))
+		})
+	}
+}
+
+func TestRetryPolicy_Backoff(t *testing.T) {
+	t.Parallel()
+
+	policy := RetryPolicy{MaxRetries: 10, Delay: time.Second}
+
+	assert.Equal(t, time.Second, policy.backoff(1))
+	assert.Equal(t, 2*time.Second, policy.backoff(2))
+	assert.Equal(t, 4*time.Second, policy.backoff(3))
+	assert.Equal(t, maxBackoff, policy.backoff(10))
+}
+
+func TestRetry_StopsOnNonTransientError(t *testing.T) {
+	t.Parallel()
+
+	calls := 0
+	err := Retry(context.Background(), RetryPolicy{MaxRetries: 3, Delay: time.Millisecond}, func(context.Context) error {
+		calls++
+		return errors.New("permission denied")
+	})
+
+	require.Error(t, err)
+	assert.Equal(t, 1, calls)
+}
+
+func TestRetry_GivesUpAfterMaxRetries(t *testing.T) {
+	t.Parallel()
+
+	calls := 0
+	err := Retry(context.Background(), RetryPolicy{MaxRetries: 2, Delay: time.Millisecond}, func(context.Context) error {
+		calls++
+		return &pgconn.PgError{Code: "57P01"}
+	})
+
+	require.Error(t, err)
+	assert.Equal(t, 3, calls, "first attempt plus two retries")
+}
+
+func TestRun_RetriesTransientCheckFailure(t *testing.T) {
+	t.Parallel()
+
+	attempts := 0
+	pkg := funcPackage("flaky-check", func(_ context.Context) (*check.Report, error) {
+		attempts++
+		if attempts == 1 {
+			return nil, &pgconn.PgError{Code: "57P01", Message: "terminating connection due to administrator command"}
+		}
+		return check.NewReport(check.Metadata{CheckID: "flaky-check"}), nil
+	})
+
+	reconnects := 0
+	var reports []*check.Report
+	Run(context.Background(), nil, Options{
+		Checks:   []check.Package{pkg},
+		OnReport: Collect(&reports),
+		Retry:    RetryPolicy{MaxRetries: 2, Delay: time.Millisecond},
+		Reconnect: func(context.Context) (db.DBTX, error) {
+			reconnects++
+			return nil, nil
+		},
+	})
+
+	require.Len(t, reports, 1)
+	assert.Equal(t, check.SeverityOK, reports[0].Severity)
+	assert.Equal(t, 2, attempts)
+	assert.Equal(t, 1, reconnects)
+}
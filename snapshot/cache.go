@@ -0,0 +1,69 @@
+package snapshot
+
+import (
+	"context"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+// Cache is a db.DBTX that runs each of the named sqlc queries once and serves
+// later calls with the same arguments from the first result. pgdoctor.Run uses
+// it so that checks sharing an expensive catalog query (sizes of every relation,
+// pg_settings) read it from the database once per run. Other queries and failed
+// ones are passed through unchanged.
+type Cache struct {
+	conn    db.DBTX
+	names   map[string]bool
+	results map[string]Query
+}
+
+// NewCache returns a Cache over conn for the given sqlc query names.
+func NewCache(conn db.DBTX, names ...string) *Cache {
+	cached := make(map[string]bool, len(names))
+	for _, name := range names {
+		cached[name] = true
+	}
+	return &Cache{conn: conn, names: cached, results: map[string]Query{}}
+}
+
+// SetConn replaces the underlying connection, e.g. after a reconnect. Cached
+// results are kept.
+func (c *Cache) SetConn(conn db.DBTX) {
+	c.conn = conn
+}
+
+// Exec forwards to the connection.
+func (c *Cache) Exec(ctx context.Context, sql string, args ...any) (pgconn.CommandTag, error) {
+	return c.conn.Exec(ctx, sql, args...)
+}
+
+// Query serves a cached query from memory after its first successful run.
+func (c *Cache) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	if !c.names[queryName(sql)] {
+		return c.conn.Query(ctx, sql, args...)
+	}
+
+	key := queryKey(sql, encodeArgs(args))
+	if query, ok := c.results[key]; ok {
+		return newRows(query), nil
+	}
+
+	rows, err := c.conn.Query(ctx, sql, args...)
+	if err != nil {
+		return nil, err
+	}
+	query := Query{SQL: sql}
+	if err := capture(rows, &query); err != nil {
+		return nil, err
+	}
+	c.results[key] = query
+	return newRows(query), nil
+}
+
+// QueryRow runs the query through Query so cached queries are served from memory.
+func (c *Cache) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	rows, err := c.Query(ctx, sql, args...)
+	return &row{rows: rows, err: err}
+}
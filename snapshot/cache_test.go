@@ -0,0 +1,66 @@
+package snapshot_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// countingConn counts the queries that reach the underlying connection.
+type countingConn struct {
+	db.DBTX
+	queries int
+}
+
+func (c *countingConn) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	c.queries++
+	return c.DBTX.Query(ctx, sql, args...)
+}
+
+func (c *countingConn) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	c.queries++
+	return c.DBTX.QueryRow(ctx, sql, args...)
+}
+
+func TestCache_RunsSharedQueriesOnce(t *testing.T) {
+	t.Parallel()
+
+	conn := &countingConn{DBTX: snapshot.NewFixture().
+		Add("CatalogSettings", []string{"name text", "setting text", "unit text", "source text", "boot_val text", "reset_val text", "context text", "vartype text", "pending_restart bool"},
+			[]any{"work_mem", "4096", "kB", "default", "4096", "4096", "user", "integer", "f"}).
+		Add("BrokenIndexes", brokenIndexesColumns)}
+	queries := db.New(snapshot.NewCache(conn, "CatalogSettings"))
+	ctx := context.Background()
+
+	first, err := queries.CatalogSettings(ctx)
+	require.NoError(t, err)
+	second, err := queries.CatalogSettings(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, first, second)
+	assert.Equal(t, "work_mem", second[0].Name.String)
+	assert.Equal(t, 1, conn.queries, "cached query reaches the database once")
+
+	_, err = queries.BrokenIndexes(ctx)
+	require.NoError(t, err)
+	_, err = queries.BrokenIndexes(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, 3, conn.queries, "other queries are not cached")
+}
+
+func TestCache_DoesNotCacheFailures(t *testing.T) {
+	t.Parallel()
+
+	conn := &countingConn{DBTX: snapshot.NewFixture().Fail("CatalogTables", "57014", "canceling statement due to statement timeout")}
+	queries := db.New(snapshot.NewCache(conn, "CatalogTables"))
+
+	for range 2 {
+		_, err := queries.CatalogTables(context.Background())
+		require.Error(t, err)
+	}
+	assert.Equal(t, 2, conn.queries, "a failed query is retried on the next call")
+}
@@ -0,0 +1,109 @@
+package snapshot
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/jackc/pgx/v5/pgtype"
+)
+
+// Fixture is a db.DBTX that serves canned results by sqlc query name, for unit
+// tests that run a check through the generated db.Queries instead of a mock
+// queryer. Values are decoded with pgx exactly as on a live connection, so the
+// test covers the SQL-to-struct mapping: column count, types and NULL handling.
+//
+//	fx := snapshot.NewFixture().
+//		Add("BrokenIndexes", []string{"schema_name text", "table_name text", "index_name text", "is_leftover bool"},
+//			[]any{"public", "users", "users_email_idx", "f"})
+//	report, err := invalidindexes.New(db.New(fx)).Check(ctx)
+type Fixture struct {
+	results map[string]Query
+}
+
+// NewFixture returns an empty Fixture. Queries without a registered result fail
+// with ErrNotCaptured.
+func NewFixture() *Fixture {
+	return &Fixture{results: map[string]Query{}}
+}
+
+// Add registers the rows returned by the sqlc query name (the "-- name:" line).
+// columns are "name type" pairs using PostgreSQL type names ("text", "int8",
+// "bool", "numeric", "timestamptz", ...). Row values are in PostgreSQL text
+// format (e.g. "t" for true); nil is NULL and other values are formatted with
+// fmt.Sprint. It panics on an unknown type or a row of the wrong width.
+func (f *Fixture) Add(name string, columns []string, rows ...[]any) *Fixture {
+	typeMap := pgtype.NewMap()
+	query := Query{}
+	for _, column := range columns {
+		fieldName, typeName, _ := strings.Cut(column, " ")
+		t, ok := typeMap.TypeForName(strings.TrimSpace(typeName))
+		if !ok {
+			panic(fmt.Sprintf("snapshot fixture %s: unknown type %q for column %s", name, typeName, fieldName))
+		}
+		query.Fields = append(query.Fields, Field{Name: fieldName, OID: t.OID, Format: pgtype.TextFormatCode})
+	}
+
+	for i, row := range rows {
+		if len(row) != len(columns) {
+			panic(fmt.Sprintf("snapshot fixture %s: row %d has %d values, want %d", name, i+1, len(row), len(columns)))
+		}
+		raw := make([][]byte, len(row))
+		for j, value := range row {
+			if value != nil {
+				raw[j] = []byte(fmt.Sprint(value))
+			}
+		}
+		query.Rows = append(query.Rows, raw)
+	}
+
+	f.results[name] = query
+	return f
+}
+
+// Fail makes the sqlc query name fail with a PostgreSQL error, e.g.
+// "42501" for missing privileges.
+func (f *Fixture) Fail(name, code, message string) *Fixture {
+	f.results[name] = Query{Error: &Error{Code: code, Message: message}}
+	return f
+}
+
+// Exec is not supported; checks only read.
+func (f *Fixture) Exec(_ context.Context, _ string, _ ...any) (pgconn.CommandTag, error) {
+	return pgconn.CommandTag{}, fmt.Errorf("exec: %w", ErrNotCaptured)
+}
+
+// Query returns the result registered for the query's sqlc name.
+func (f *Fixture) Query(ctx context.Context, sql string, _ ...any) (pgx.Rows, error) {
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+
+	name := queryName(sql)
+	query, ok := f.results[name]
+	if !ok {
+		return nil, fmt.Errorf("%s: %w", name, ErrNotCaptured)
+	}
+	if query.Error != nil {
+		return nil, &pgconn.PgError{Severity: "ERROR", Code: query.Error.Code, Message: query.Error.Message}
+	}
+	return newRows(query), nil
+}
+
+// QueryRow returns the first registered row.
+func (f *Fixture) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	rows, err := f.Query(ctx, sql, args...)
+	return &row{rows: rows, err: err}
+}
+
+// queryName extracts the name from sqlc's "-- name: BrokenIndexes :many" header.
+func queryName(sql string) string {
+	const prefix = "-- name: "
+	if !strings.HasPrefix(sql, prefix) {
+		return ""
+	}
+	name, _, _ := strings.Cut(sql[len(prefix):], " ")
+	return name
+}
@@ -0,0 +1,84 @@
+package snapshot_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+var brokenIndexesColumns = []string{"schema_name text", "table_name text", "index_name text", "is_leftover bool"}
+
+func TestFixture_ScansThroughGeneratedQueries(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BrokenIndexes", brokenIndexesColumns,
+		[]any{"public", "users", "users_email_idx", "f"},
+		[]any{"app", "posts", "posts_created_at_ccnew", "t"},
+	)
+
+	rows, err := db.New(fx).BrokenIndexes(context.Background())
+	require.NoError(t, err)
+	assert.Equal(t, []db.BrokenIndexesRow{
+		{SchemaName: "public", TableName: "users", IndexName: "users_email_idx", IsLeftover: false},
+		{SchemaName: "app", TableName: "posts", IndexName: "posts_created_at_ccnew", IsLeftover: true},
+	}, rows)
+}
+
+func TestFixture_NullIntoNonNullableField(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BrokenIndexes", brokenIndexesColumns,
+		[]any{"public", nil, "users_email_idx", "f"},
+	)
+
+	_, err := db.New(fx).BrokenIndexes(context.Background())
+	require.Error(t, err, "a NULL the generated struct cannot hold must fail the scan")
+}
+
+func TestFixture_WrongColumnCount(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("BrokenIndexes", brokenIndexesColumns[:3],
+		[]any{"public", "users", "users_email_idx"},
+	)
+
+	_, err := db.New(fx).BrokenIndexes(context.Background())
+	require.Error(t, err)
+}
+
+func TestFixture_Errors(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Fail("BrokenIndexes", "42501", "permission denied for table pg_index")
+
+	_, err := db.New(fx).BrokenIndexes(context.Background())
+	var pgErr *pgconn.PgError
+	require.ErrorAs(t, err, &pgErr)
+	assert.Equal(t, "42501", pgErr.Code)
+
+	_, err = db.New(fx).PasswordEncryption(context.Background())
+	require.ErrorIs(t, err, snapshot.ErrNotCaptured)
+}
+
+func TestFixture_QueryRowWithoutRows(t *testing.T) {
+	t.Parallel()
+
+	fx := snapshot.NewFixture().Add("PasswordEncryption", []string{"password_encryption text", "can_read_authid bool"})
+
+	_, err := db.New(fx).PasswordEncryption(context.Background())
+	require.ErrorIs(t, err, pgx.ErrNoRows)
+}
+
+func TestFixture_UnknownType(t *testing.T) {
+	t.Parallel()
+
+	require.Panics(t, func() {
+		snapshot.NewFixture().Add("BrokenIndexes", []string{"schema_name varchar2"})
+	})
+}
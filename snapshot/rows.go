@@ -0,0 +1,111 @@
+package snapshot
+
+import (
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/jackc/pgx/v5/pgtype"
+)
+
+// rows implements pgx.Rows over a captured result, decoding values with the
+// same type map pgx uses for a live connection.
+type rows struct {
+	query   Query
+	fields  []pgconn.FieldDescription
+	typeMap *pgtype.Map
+	current int
+	closed  bool
+}
+
+func newRows(query Query) *rows {
+	fields := make([]pgconn.FieldDescription, len(query.Fields))
+	for i, f := range query.Fields {
+		fields[i] = pgconn.FieldDescription{Name: f.Name, DataTypeOID: f.OID, Format: f.Format}
+	}
+	return &rows{query: query, fields: fields, typeMap: pgtype.NewMap(), current: -1}
+}
+
+func (r *rows) Close() {
+	r.closed = true
+}
+
+func (r *rows) Err() error {
+	return nil
+}
+
+func (r *rows) CommandTag() pgconn.CommandTag {
+	return pgconn.NewCommandTag(r.query.CommandTag)
+}
+
+func (r *rows) FieldDescriptions() []pgconn.FieldDescription {
+	return r.fields
+}
+
+func (r *rows) Next() bool {
+	if r.closed || r.current+1 >= len(r.query.Rows) {
+		r.closed = true
+		return false
+	}
+	r.current++
+	return true
+}
+
+func (r *rows) Scan(dest ...any) error {
+	return pgx.ScanRow(r.typeMap, r.fields, r.RawValues(), dest...)
+}
+
+func (r *rows) Values() ([]any, error) {
+	raw := r.RawValues()
+	values := make([]any, len(raw))
+	for i, buf := range raw {
+		if buf == nil {
+			continue
+		}
+		fd := r.fields[i]
+		if dt, ok := r.typeMap.TypeForOID(fd.DataTypeOID); ok {
+			value, err := dt.Codec.DecodeValue(r.typeMap, fd.DataTypeOID, fd.Format, buf)
+			if err != nil {
+				return nil, err
+			}
+			values[i] = value
+			continue
+		}
+		if fd.Format == pgtype.TextFormatCode {
+			values[i] = string(buf)
+		} else {
+			values[i] = buf
+		}
+	}
+	return values, nil
+}
+
+func (r *rows) RawValues() [][]byte {
+	if r.current < 0 || r.current >= len(r.query.Rows) {
+		return nil
+	}
+	return r.query.Rows[r.current]
+}
+
+func (r *rows) Conn() *pgx.Conn {
+	return nil
+}
+
+// row implements pgx.Row on top of rows, like pgx's own QueryRow.
+type row struct {
+	rows pgx.Rows
+	err  error
+}
+
+func (r *row) Scan(dest ...any) error {
+	if r.err != nil {
+		return r.err
+	}
+	defer r.rows.Close()
+
+	if !r.rows.Next() {
+		if err := r.rows.Err(); err != nil {
+			return err
+		}
+		return pgx.ErrNoRows
+	}
+	return r.rows.Scan(dest...)
+}
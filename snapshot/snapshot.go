@@ -0,0 +1,245 @@
+// Package snapshot captures the query results the checks depend on so they can
+// be evaluated later without access to the database.
+//
+// A Recorder wraps a live connection and stores every result the checks read.
+// The resulting Snapshot is plain JSON that a DBA can review and hand over.
+// A Replayer serves those results back through the same db.DBTX interface, so
+// checks run unchanged against the captured data. A Fixture serves hand-written
+// results the same way, for unit tests. A Cache keeps the results of shared
+// queries in memory so a run reads them from the database once.
+package snapshot
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/emancu/pgdoctor/db"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+)
+
+// FormatVersion is the snapshot format written by this version of pgdoctor.
+const FormatVersion = 1
+
+// ErrNotCaptured is returned when replaying a query that is not in the snapshot,
+// typically because the snapshot was collected by an older pgdoctor.
+var ErrNotCaptured = errors.New("query not captured in snapshot")
+
+// Snapshot holds the results of every query run during collection.
+type Snapshot struct {
+	Version    int       `json:"version"`
+	CapturedAt time.Time `json:"captured_at"`
+	Queries    []Query   `json:"queries"`
+}
+
+// Query is a single captured query and its result in PostgreSQL wire format.
+type Query struct {
+	SQL        string     `json:"sql"`
+	Args       string     `json:"args,omitempty"` // JSON-encoded arguments
+	Fields     []Field    `json:"fields,omitempty"`
+	Rows       [][][]byte `json:"rows,omitempty"` // Raw column values; nil is NULL
+	CommandTag string     `json:"command_tag,omitempty"`
+	Error      *Error     `json:"error,omitempty"`
+}
+
+// Field describes a result column.
+type Field struct {
+	Name   string `json:"name"`
+	OID    uint32 `json:"oid"`
+	Format int16  `json:"format"`
+}
+
+// Error is a captured query failure (e.g. missing privileges or extension),
+// replayed so checks react to it exactly as they did during collection.
+type Error struct {
+	Code    string `json:"code,omitempty"` // SQLSTATE; empty for non-server errors
+	Message string `json:"message"`
+}
+
+// Read decodes a snapshot and rejects formats this version does not understand.
+func Read(r io.Reader) (*Snapshot, error) {
+	var snap Snapshot
+	if err := json.NewDecoder(r).Decode(&snap); err != nil {
+		return nil, fmt.Errorf("decoding snapshot: %w", err)
+	}
+	if snap.Version != FormatVersion {
+		return nil, fmt.Errorf("unsupported snapshot version %d (expected %d)", snap.Version, FormatVersion)
+	}
+	return &snap, nil
+}
+
+// Write encodes the snapshot as indented JSON.
+func (s *Snapshot) Write(w io.Writer) error {
+	enc := json.NewEncoder(w)
+	enc.SetIndent("", "  ")
+	return enc.Encode(s)
+}
+
+// Recorder is a db.DBTX that forwards queries to a live connection and
+// captures their results.
+type Recorder struct {
+	conn     db.DBTX
+	snapshot *Snapshot
+	seen     map[string]int // Query key to its index in snapshot.Queries
+}
+
+// NewRecorder returns a Recorder capturing results from conn.
+func NewRecorder(conn db.DBTX) *Recorder {
+	return &Recorder{
+		conn:     conn,
+		snapshot: &Snapshot{Version: FormatVersion, CapturedAt: time.Now().UTC()},
+		seen:     map[string]int{},
+	}
+}
+
+// SetConn replaces the underlying connection, e.g. after a reconnect.
+func (r *Recorder) SetConn(conn db.DBTX) {
+	r.conn = conn
+}
+
+// Snapshot returns everything captured so far.
+func (r *Recorder) Snapshot() *Snapshot {
+	return r.snapshot
+}
+
+// Exec forwards to the connection. Statements are not captured.
+func (r *Recorder) Exec(ctx context.Context, sql string, args ...any) (pgconn.CommandTag, error) {
+	return r.conn.Exec(ctx, sql, args...)
+}
+
+// Query runs the query, captures the complete result and returns it for scanning.
+func (r *Recorder) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	query := Query{SQL: sql, Args: encodeArgs(args)}
+
+	rows, err := r.conn.Query(ctx, sql, args...)
+	if err == nil {
+		err = capture(rows, &query)
+	}
+	if err != nil {
+		// Cancellations say nothing about the database; don't bake them into the snapshot.
+		if ctx.Err() == nil {
+			query.Error = captureError(err)
+			r.add(query)
+		}
+		return nil, err
+	}
+
+	r.add(query)
+	return newRows(query), nil
+}
+
+// QueryRow runs the query through Query so its result is captured.
+func (r *Recorder) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	rows, err := r.Query(ctx, sql, args...)
+	return &row{rows: rows, err: err}
+}
+
+// add stores the first result of each distinct query and argument list.
+// A later success replaces an earlier failure, so retried queries keep their result.
+func (r *Recorder) add(query Query) {
+	key := queryKey(query.SQL, query.Args)
+	if i, ok := r.seen[key]; ok {
+		if r.snapshot.Queries[i].Error != nil && query.Error == nil {
+			r.snapshot.Queries[i] = query
+		}
+		return
+	}
+	r.seen[key] = len(r.snapshot.Queries)
+	r.snapshot.Queries = append(r.snapshot.Queries, query)
+}
+
+// capture reads all rows into query, copying the raw values pgx reuses between rows.
+func capture(rows pgx.Rows, query *Query) error {
+	defer rows.Close()
+
+	for _, fd := range rows.FieldDescriptions() {
+		query.Fields = append(query.Fields, Field{Name: fd.Name, OID: fd.DataTypeOID, Format: fd.Format})
+	}
+	for rows.Next() {
+		raw := rows.RawValues()
+		values := make([][]byte, len(raw))
+		for i, v := range raw {
+			if v != nil {
+				values[i] = append([]byte{}, v...)
+			}
+		}
+		query.Rows = append(query.Rows, values)
+	}
+	if err := rows.Err(); err != nil {
+		return err
+	}
+	query.CommandTag = rows.CommandTag().String()
+	return nil
+}
+
+func captureError(err error) *Error {
+	var pgErr *pgconn.PgError
+	if errors.As(err, &pgErr) {
+		return &Error{Code: pgErr.Code, Message: pgErr.Message}
+	}
+	return &Error{Message: err.Error()}
+}
+
+// Replayer is a db.DBTX that answers queries from a snapshot.
+type Replayer struct {
+	queries map[string]Query
+}
+
+// NewReplayer returns a Replayer serving the results captured in snap.
+func NewReplayer(snap *Snapshot) *Replayer {
+	queries := make(map[string]Query, len(snap.Queries))
+	for _, query := range snap.Queries {
+		queries[queryKey(query.SQL, query.Args)] = query
+	}
+	return &Replayer{queries: queries}
+}
+
+// Exec is not supported offline; checks only read.
+func (r *Replayer) Exec(_ context.Context, _ string, _ ...any) (pgconn.CommandTag, error) {
+	return pgconn.CommandTag{}, fmt.Errorf("exec: %w", ErrNotCaptured)
+}
+
+// Query returns the captured result, or the captured error.
+func (r *Replayer) Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error) {
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+
+	query, ok := r.queries[queryKey(sql, encodeArgs(args))]
+	if !ok {
+		return nil, ErrNotCaptured
+	}
+	if query.Error != nil {
+		if query.Error.Code != "" {
+			return nil, &pgconn.PgError{Severity: "ERROR", Code: query.Error.Code, Message: query.Error.Message}
+		}
+		return nil, errors.New(query.Error.Message)
+	}
+	return newRows(query), nil
+}
+
+// QueryRow returns the first captured row.
+func (r *Replayer) QueryRow(ctx context.Context, sql string, args ...any) pgx.Row {
+	rows, err := r.Query(ctx, sql, args...)
+	return &row{rows: rows, err: err}
+}
+
+func queryKey(sql, args string) string {
+	return sql + "\x00" + args
+}
+
+// encodeArgs renders query arguments as a stable lookup key.
+func encodeArgs(args []any) string {
+	if len(args) == 0 {
+		return ""
+	}
+	encoded, err := json.Marshal(args)
+	if err != nil {
+		return fmt.Sprintf("%v", args)
+	}
+	return string(encoded)
+}
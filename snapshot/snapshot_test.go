@@ -0,0 +1,163 @@
+package snapshot_test
+
+import (
+	"bytes"
+	"context"
+	"strings"
+	"testing"
+
+	"github.com/emancu/pgdoctor/snapshot"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const tablesSQL = "SELECT relname, n_live_tup FROM pg_stat_user_tables"
+
+func fixture() *snapshot.Snapshot {
+	return &snapshot.Snapshot{
+		Version: snapshot.FormatVersion,
+		Queries: []snapshot.Query{
+			{
+				SQL:        tablesSQL,
+				Fields:     []snapshot.Field{{Name: "relname", OID: pgtype.TextOID}, {Name: "n_live_tup", OID: pgtype.Int8OID}},
+				Rows:       [][][]byte{{[]byte("orders"), []byte("5000")}, {[]byte("users"), nil}},
+				CommandTag: "SELECT 2",
+			},
+			{
+				SQL:   "SELECT * FROM pg_stat_statements",
+				Error: &snapshot.Error{Code: "42P01", Message: `relation "pg_stat_statements" does not exist`},
+			},
+		},
+	}
+}
+
+func TestReplayer_ScansCapturedRows(t *testing.T) {
+	t.Parallel()
+
+	replayer := snapshot.NewReplayer(fixture())
+
+	rows, err := replayer.Query(context.Background(), tablesSQL)
+	require.NoError(t, err)
+	defer rows.Close()
+
+	var names []string
+	var tuples []pgtype.Int8
+	for rows.Next() {
+		var name pgtype.Text
+		var live pgtype.Int8
+		require.NoError(t, rows.Scan(&name, &live))
+		names = append(names, name.String)
+		tuples = append(tuples, live)
+	}
+	require.NoError(t, rows.Err())
+
+	assert.Equal(t, []string{"orders", "users"}, names)
+	assert.Equal(t, pgtype.Int8{Int64: 5000, Valid: true}, tuples[0])
+	assert.False(t, tuples[1].Valid, "NULL survives the round trip")
+}
+
+func TestReplayer_QueryRow(t *testing.T) {
+	t.Parallel()
+
+	replayer := snapshot.NewReplayer(fixture())
+
+	var name string
+	var live int64
+	require.NoError(t, replayer.QueryRow(context.Background(), tablesSQL).Scan(&name, &live))
+	assert.Equal(t, "orders", name)
+	assert.Equal(t, int64(5000), live)
+}
+
+func TestReplayer_Errors(t *testing.T) {
+	t.Parallel()
+
+	replayer := snapshot.NewReplayer(fixture())
+
+	_, err := replayer.Query(context.Background(), "SELECT * FROM pg_stat_statements")
+	var pgErr *pgconn.PgError
+	require.ErrorAs(t, err, &pgErr)
+	assert.Equal(t, "42P01", pgErr.Code)
+
+	_, err = replayer.Query(context.Background(), "SELECT 1")
+	require.ErrorIs(t, err, snapshot.ErrNotCaptured)
+
+	err = replayer.QueryRow(context.Background(), "SELECT 1").Scan(new(int))
+	require.ErrorIs(t, err, snapshot.ErrNotCaptured)
+}
+
+func TestRecorder_CapturesResultsAndErrors(t *testing.T) {
+	t.Parallel()
+
+	// Record from a replayer standing in for a live connection.
+	recorder := snapshot.NewRecorder(snapshot.NewReplayer(fixture()))
+	ctx := context.Background()
+
+	rows, err := recorder.Query(ctx, tablesSQL)
+	require.NoError(t, err)
+	count := 0
+	for rows.Next() {
+		count++
+	}
+	rows.Close()
+	assert.Equal(t, 2, count)
+
+	_, err = recorder.Query(ctx, "SELECT * FROM pg_stat_statements")
+	require.Error(t, err)
+
+	// Repeated queries keep the first result.
+	_, err = recorder.Query(ctx, tablesSQL)
+	require.NoError(t, err)
+
+	var buf bytes.Buffer
+	require.NoError(t, recorder.Snapshot().Write(&buf))
+
+	snap, err := snapshot.Read(&buf)
+	require.NoError(t, err)
+	require.Len(t, snap.Queries, 2)
+	assert.Equal(t, fixture().Queries[0].Rows, snap.Queries[0].Rows)
+	assert.Equal(t, "SELECT 2", snap.Queries[0].CommandTag)
+	assert.Equal(t, "42P01", snap.Queries[1].Error.Code)
+
+	var name string
+	require.NoError(t, snapshot.NewReplayer(snap).QueryRow(ctx, tablesSQL).Scan(&name, new(pgtype.Int8)))
+	assert.Equal(t, "orders", name)
+}
+
+func TestRecorder_SkipsCancelledQueries(t *testing.T) {
+	t.Parallel()
+
+	recorder := snapshot.NewRecorder(snapshot.NewReplayer(fixture()))
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+
+	_, err := recorder.Query(ctx, tablesSQL)
+	require.ErrorIs(t, err, context.Canceled)
+	assert.Empty(t, recorder.Snapshot().Queries)
+}
+
+func TestRead_RejectsUnknownVersion(t *testing.T) {
+	t.Parallel()
+
+	_, err := snapshot.Read(strings.NewReader(`{"version": 99, "queries": []}`))
+	require.Error(t, err)
+
+	_, err = snapshot.Read(strings.NewReader(`not json`))
+	require.Error(t, err)
+}
+
+func TestReplayer_ImplementsPgxRows(t *testing.T) {
+	t.Parallel()
+
+	rows, err := snapshot.NewReplayer(fixture()).Query(context.Background(), tablesSQL)
+	require.NoError(t, err)
+
+	values, err := pgx.CollectRows(rows, pgx.RowToMap)
+	require.NoError(t, err)
+	require.Len(t, values, 2)
+	assert.Equal(t, "orders", values[0]["relname"])
+	assert.Equal(t, int64(5000), values[0]["n_live_tup"])
+	assert.Nil(t, values[1]["n_live_tup"])
+}